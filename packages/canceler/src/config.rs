@@ -195,9 +195,16 @@ pub struct Config {
     /// that are still within their cancel window.
     pub pending_retry_ttl_secs: u64,
 
+    /// Persistent watcher state file (`CANCELER_STATE_PATH`, default
+    /// `canceler-state-<CANCELER_ID>.db` so multiple instances never share one).
+    /// Holds scan cursors, verdicts, pending retries and cancel attempts so a restart resumes
+    /// where it stopped. `:memory:` disables persistence.
+    pub state_path: String,
+
     /// Max blocks to look back on first EVM poll (default 5000).
-    /// On subsequent polls this is ignored — the watcher resumes from the last
-    /// polled block. opBNB and BSC publicnode cap eth_getLogs at 50 000 blocks.
+    /// On subsequent polls (and after a restart, via `state_path`) this is ignored —
+    /// the watcher resumes from the last polled block. opBNB and BSC publicnode cap
+    /// eth_getLogs at 50 000 blocks.
     /// NOTE: bsc-dataseed1.binance.org does NOT support eth_getLogs at all;
     /// use bsc-rpc.publicnode.com or a paid RPC for BSC.
     pub evm_poll_lookback_blocks: u64,
@@ -287,6 +294,7 @@ impl fmt::Debug for Config {
             )
             .field("pending_retry_max_size", &self.pending_retry_max_size)
             .field("pending_retry_ttl_secs", &self.pending_retry_ttl_secs)
+            .field("state_path", &self.state_path)
             .field("evm_poll_lookback_blocks", &self.evm_poll_lookback_blocks)
             .field("evm_poll_chunk_size", &self.evm_poll_chunk_size)
             .field("evm_confirmation_blocks", &self.evm_confirmation_blocks)
@@ -451,8 +459,14 @@ impl Config {
             .and_then(|v| v.parse().ok())
             .unwrap_or_else(|| default_evm_confirmation_blocks(evm_chain_id));
 
+        let canceler_id = env::var("CANCELER_ID").unwrap_or(default_id);
+        let state_path = env::var("CANCELER_STATE_PATH")
+            .ok()
+            .filter(|v| !v.trim().is_empty())
+            .unwrap_or_else(|| format!("canceler-state-{canceler_id}.db"));
+
         let config = Self {
-            canceler_id: env::var("CANCELER_ID").unwrap_or(default_id),
+            canceler_id,
 
             evm_rpc_url,
             evm_rpc_fallback_urls,
@@ -527,6 +541,8 @@ impl Config {
                 .and_then(|v| v.parse().ok())
                 .unwrap_or(7200),
 
+            state_path,
+
             evm_poll_lookback_blocks: env::var("EVM_POLL_LOOKBACK_BLOCKS")
                 .ok()
                 .and_then(|v| v.parse().ok())
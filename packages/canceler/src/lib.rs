@@ -10,6 +10,8 @@ pub mod hash;
 pub mod redact;
 pub mod server;
 pub mod solana_client;
+pub mod state_store;
 pub mod terra_client;
+pub mod token_cache;
 pub mod verifier;
 pub mod watcher;
@@ -3,8 +3,10 @@
 use borsh::BorshDeserialize;
 use eyre::Result;
 use multichain_rs::solana::{
-    get_signatures_for_program, get_transaction, parse_anchor_events, run_with_solana_rpc_fallback,
-    SolanaEvent, SolanaWithdrawApproveEvent,
+    chain_withdraw_delay_pda, effective_withdraw_delay, get_signatures_for_program,
+    get_transaction, large_transfer_delay_pda, parse_anchor_events, parse_chain_withdraw_delay,
+    parse_large_transfer_delay, parse_withdraw_sponsorship, run_with_solana_rpc_fallback,
+    withdraw_sponsorship_pda, SolanaEvent, SolanaWithdrawApproveEvent,
 };
 use solana_client::rpc_client::RpcClient;
 use solana_sdk::commitment_config::CommitmentConfig;
@@ -45,6 +47,67 @@ pub struct PendingWithdrawData {
     pub executed: bool,
 }
 
+/// Parse PendingWithdraw account data.
+/// Anchor layout (after 8-byte discriminator):
+///   transfer_hash: [u8; 32]  (offset 8)
+///   src_chain: [u8; 4]       (offset 40)
+///   src_account: [u8; 32]    (offset 44)
+///   dest_account: Pubkey     (offset 76)
+///   token: Pubkey            (offset 108)
+///   amount: u128             (offset 140, LE)
+///   nonce: u64               (offset 156, LE)
+///   src_decimals: u8         (offset 164)
+///   dest_decimals: u8        (offset 165)
+///   operator_gas: u64        (offset 166, LE)
+///   approved: bool           (offset 174)
+///   approved_at: i64         (offset 175, LE)
+///   cancelled: bool          (offset 183)
+///   executed: bool           (offset 184)
+pub fn parse_pending_withdraw(data: &[u8]) -> Result<PendingWithdrawData> {
+    if data.len() < 185 {
+        return Err(eyre::eyre!(
+            "PendingWithdraw account data too short: {} bytes (need 185)",
+            data.len()
+        ));
+    }
+
+    let mut th = [0u8; 32];
+    th.copy_from_slice(&data[8..40]);
+
+    let mut sc = [0u8; 4];
+    sc.copy_from_slice(&data[40..44]);
+
+    let mut sa = [0u8; 32];
+    sa.copy_from_slice(&data[44..76]);
+
+    let mut da = [0u8; 32];
+    da.copy_from_slice(&data[76..108]);
+
+    let mut tok = [0u8; 32];
+    tok.copy_from_slice(&data[108..140]);
+
+    let amount = u128::from_le_bytes(data[140..156].try_into().unwrap());
+    let nonce = u64::from_le_bytes(data[156..164].try_into().unwrap());
+    let approved = data[174] != 0;
+    let approved_at = i64::from_le_bytes(data[175..183].try_into().unwrap());
+    let cancelled = data[183] != 0;
+    let executed = data[184] != 0;
+
+    Ok(PendingWithdrawData {
+        transfer_hash: th,
+        src_chain: sc,
+        src_account: sa,
+        dest_account: da,
+        token: tok,
+        amount,
+        nonce,
+        approved,
+        approved_at,
+        cancelled,
+        executed,
+    })
+}
+
 pub struct SolanaCancelerClient {
     rpc_clients: Vec<RpcClient>,
     program_id: Pubkey,
@@ -145,38 +208,6 @@ impl SolanaCancelerClient {
         Ok(approvals)
     }
 
-    /// Verify a deposit exists on Solana by reading the DepositRecord PDA
-    pub fn verify_deposit(&self, nonce: u64) -> Result<bool> {
-        let nonce_bytes = nonce.to_le_bytes();
-        let (deposit_pda, _) =
-            Pubkey::find_program_address(&[b"deposit", &nonce_bytes], &self.program_id);
-
-        match run_with_solana_rpc_fallback(&self.rpc_clients, |c| c.get_account(&deposit_pda)) {
-            Ok(account) => Ok(!account.data.is_empty()),
-            Err(_) => Ok(false),
-        }
-    }
-
-    /// Verify a deposit by reading the PDA and checking the transfer hash
-    pub fn verify_deposit_hash(&self, nonce: u64, expected_hash: &[u8; 32]) -> Result<bool> {
-        let nonce_bytes = nonce.to_le_bytes();
-        let (deposit_pda, _) =
-            Pubkey::find_program_address(&[b"deposit", &nonce_bytes], &self.program_id);
-
-        match run_with_solana_rpc_fallback(&self.rpc_clients, |c| c.get_account(&deposit_pda)) {
-            Ok(account) => {
-                // Anchor account: 8 byte discriminator + data
-                // DepositRecord starts with transfer_hash: [u8; 32]
-                if account.data.len() < 8 + 32 {
-                    return Ok(false);
-                }
-                let stored_hash = &account.data[8..40];
-                Ok(stored_hash == expected_hash)
-            }
-            Err(_) => Ok(false),
-        }
-    }
-
     /// Read the PendingWithdraw PDA and extract the src_chain bytes.
     /// Anchor layout: 8-byte discriminator, then PendingWithdraw fields in order:
     ///   transfer_hash: [u8; 32], src_chain: [u8; 4], ...
@@ -197,19 +228,7 @@ impl SolanaCancelerClient {
         Ok(src_chain)
     }
 
-    /// Read the full PendingWithdraw PDA and parse all fields.
-    /// Anchor layout (after 8-byte discriminator):
-    ///   transfer_hash: [u8; 32]  (offset 8)
-    ///   src_chain: [u8; 4]       (offset 40)
-    ///   src_account: [u8; 32]    (offset 44)
-    ///   dest_account: Pubkey     (offset 76)
-    ///   token: Pubkey            (offset 108)
-    ///   amount: u128             (offset 140, LE)
-    ///   nonce: u64               (offset 156, LE)
-    ///   approved: bool           (offset 164)
-    ///   approved_at: i64         (offset 165, LE)
-    ///   cancelled: bool          (offset 173)
-    ///   executed: bool           (offset 174)
+    /// Read the full PendingWithdraw PDA and parse all fields (see [`parse_pending_withdraw`]).
     pub fn read_pending_withdraw_full(
         &self,
         transfer_hash: &[u8; 32],
@@ -220,49 +239,56 @@ impl SolanaCancelerClient {
         let account = run_with_solana_rpc_fallback(&self.rpc_clients, |c| c.get_account(&pda))
             .map_err(|e| eyre::eyre!("Failed to read PendingWithdraw PDA: {}", e))?;
 
-        let data = &account.data;
-        if data.len() < 175 {
-            return Err(eyre::eyre!(
-                "PendingWithdraw account data too short: {} bytes (need 175)",
-                data.len()
-            ));
+        parse_pending_withdraw(&account.data)
+    }
+
+    /// Relay fee (source decimals) an operator charges on a pending withdraw: the
+    /// `WithdrawSponsorship` `relay_fee`, or 0 when the recipient submitted it themselves.
+    pub fn read_relay_fee(&self, transfer_hash: &[u8; 32]) -> Result<u128> {
+        let pda = withdraw_sponsorship_pda(&self.program_id, transfer_hash);
+        let accounts =
+            run_with_solana_rpc_fallback(&self.rpc_clients, |c| c.get_multiple_accounts(&[pda]))
+                .map_err(|e| eyre::eyre!("Failed to read WithdrawSponsorship PDA: {}", e))?;
+        match accounts.into_iter().next().flatten() {
+            Some(account) if account.owner == self.program_id => {
+                Ok(parse_withdraw_sponsorship(&account.data)?.relay_fee)
+            }
+            _ => Ok(0),
         }
+    }
 
-        let mut th = [0u8; 32];
-        th.copy_from_slice(&data[8..40]);
-
-        let mut sc = [0u8; 4];
-        sc.copy_from_slice(&data[40..44]);
-
-        let mut sa = [0u8; 32];
-        sa.copy_from_slice(&data[44..76]);
-
-        let mut da = [0u8; 32];
-        da.copy_from_slice(&data[76..108]);
-
-        let mut tok = [0u8; 32];
-        tok.copy_from_slice(&data[108..140]);
-
-        let amount = u128::from_le_bytes(data[140..156].try_into().unwrap());
-        let nonce = u64::from_le_bytes(data[156..164].try_into().unwrap());
-        let approved = data[164] != 0;
-        let approved_at = i64::from_le_bytes(data[165..173].try_into().unwrap());
-        let cancelled = data[173] != 0;
-        let executed = data[174] != 0;
-
-        Ok(PendingWithdrawData {
-            transfer_hash: th,
-            src_chain: sc,
-            src_account: sa,
-            dest_account: da,
-            token: tok,
-            amount,
-            nonce,
-            approved,
-            approved_at,
-            cancelled,
-            executed,
+    /// Effective withdraw delay (seconds) for a pending withdraw: per-source-chain delay or the
+    /// bridge default, extended by the token's large-transfer delay when the amount qualifies.
+    pub fn read_effective_withdraw_delay_secs(&self, pw: &PendingWithdrawData) -> Result<u64> {
+        let (bridge_pda, _) = Pubkey::find_program_address(&[b"bridge"], &self.program_id);
+        let chain_pda = chain_withdraw_delay_pda(&self.program_id, &pw.src_chain);
+        let large_pda = large_transfer_delay_pda(
+            &self.program_id,
+            &pw.src_chain,
+            &Pubkey::new_from_array(pw.token),
+        );
+        let accounts = run_with_solana_rpc_fallback(&self.rpc_clients, |c| {
+            c.get_multiple_accounts(&[bridge_pda, chain_pda, large_pda])
         })
+        .map_err(|e| eyre::eyre!("Failed to read withdraw delay accounts: {}", e))?;
+
+        let bridge = accounts[0]
+            .as_ref()
+            .ok_or_else(|| eyre::eyre!("Bridge config account missing"))?;
+        if bridge.data.len() < 8 {
+            return Err(eyre::eyre!("Bridge account data too short"));
+        }
+        let cfg = BridgeConfigData::try_from_slice(&bridge.data[8..])
+            .map_err(|e| eyre::eyre!("Failed to decode bridge config: {}", e))?;
+
+        let chain = accounts[1]
+            .as_ref()
+            .and_then(|a| parse_chain_withdraw_delay(&a.data));
+        let large = accounts[2]
+            .as_ref()
+            .and_then(|a| parse_large_transfer_delay(&a.data));
+
+        Ok(effective_withdraw_delay(cfg.withdraw_delay, chain, large, pw.amount).max(0) as u64)
     }
 
     /// Submit a withdraw_cancel instruction
@@ -277,6 +303,22 @@ impl SolanaCancelerClient {
             &self.program_id,
         );
 
+        // Records this canceler as eligible for the bounty; the program only creates the
+        // record while the reward pool exists.
+        let (reward_pool_pda, _) =
+            Pubkey::find_program_address(&[b"canceler_reward"], &self.program_id);
+        let (cancel_record_pda, _) =
+            Pubkey::find_program_address(&[b"cancel_record", transfer_hash], &self.program_id);
+
+        // The program checks the cancel window against the same effective delay as execute.
+        let pw = self.read_pending_withdraw_full(transfer_hash)?;
+        let chain_delay_pda = chain_withdraw_delay_pda(&self.program_id, &pw.src_chain);
+        let large_delay_pda = large_transfer_delay_pda(
+            &self.program_id,
+            &pw.src_chain,
+            &Pubkey::new_from_array(pw.token),
+        );
+
         // Anchor discriminator for withdraw_cancel
         let discriminator = {
             use solana_sdk::hash::hash;
@@ -293,7 +335,12 @@ impl SolanaCancelerClient {
                     AccountMeta::new_readonly(bridge_pda, false),
                     AccountMeta::new(pending_withdraw_pda, false),
                     AccountMeta::new_readonly(canceler_entry_pda, false),
-                    AccountMeta::new_readonly(self.keypair.pubkey(), true),
+                    AccountMeta::new(reward_pool_pda, false),
+                    AccountMeta::new(cancel_record_pda, false),
+                    AccountMeta::new(self.keypair.pubkey(), true),
+                    AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
+                    AccountMeta::new_readonly(chain_delay_pda, false),
+                    AccountMeta::new_readonly(large_delay_pda, false),
                 ],
                 data: discriminator.to_vec(),
             };
@@ -321,3 +368,31 @@ impl SolanaCancelerClient {
         self.keypair.pubkey()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_pending_withdraw_skips_decimals_and_operator_gas() {
+        let mut data = vec![0u8; 186];
+        data[40..44].copy_from_slice(&[0, 0, 0, 1]);
+        data[140..156].copy_from_slice(&7_000u128.to_le_bytes());
+        data[156..164].copy_from_slice(&42u64.to_le_bytes());
+        data[164] = 18;
+        data[165] = 9;
+        data[166..174].copy_from_slice(&u64::MAX.to_le_bytes());
+        data[174] = 1;
+        data[175..183].copy_from_slice(&1_700_000_000i64.to_le_bytes());
+
+        let pw = parse_pending_withdraw(&data).unwrap();
+        assert_eq!(pw.src_chain, [0, 0, 0, 1]);
+        assert_eq!(pw.amount, 7_000);
+        assert_eq!(pw.nonce, 42);
+        assert!(pw.approved);
+        assert_eq!(pw.approved_at, 1_700_000_000);
+        assert!(!pw.cancelled);
+        assert!(!pw.executed);
+        assert!(parse_pending_withdraw(&data[..184]).is_err());
+    }
+}
@@ -0,0 +1,400 @@
+//! Persistent canceler state (SQLite file, `CANCELER_STATE_PATH`)
+//!
+//! The in-memory [`BoundedHashCache`](crate::bounded_cache::BoundedHashCache) /
+//! [`BoundedMapCache`](crate::bounded_cache::BoundedMapCache) stay the hot path; this store is
+//! written through on every change so a restart resumes exactly where the watcher stopped:
+//!
+//! - **cursors:** last polled block / height / signature per chain
+//! - **verdicts:** hashes verified valid or cancelled (with the fraud reason)
+//! - **pending retries:** approvals whose verification returned `Pending` (C12)
+//! - **cancel attempts:** per-hash submit count and last error
+//!
+//! Without it, approvals older than `EVM_POLL_LOOKBACK_BLOCKS` at restart were never checked.
+
+use std::path::Path;
+use std::sync::{Mutex, MutexGuard};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use eyre::{Result, WrapErr};
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::verifier::PendingApproval;
+
+/// Path that keeps the store in memory (tests, or explicitly opting out of persistence).
+pub const IN_MEMORY_PATH: &str = ":memory:";
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS cursors (
+    chain       TEXT PRIMARY KEY,
+    position    TEXT NOT NULL,
+    updated_at  INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS verdicts (
+    hash        BLOB PRIMARY KEY,
+    verdict     TEXT NOT NULL,
+    reason      TEXT,
+    recorded_at INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS pending_retries (
+    hash        BLOB PRIMARY KEY,
+    approval    TEXT NOT NULL,
+    queued_at   INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS cancel_attempts (
+    hash        BLOB PRIMARY KEY,
+    dest_chain  BLOB NOT NULL,
+    attempts    INTEGER NOT NULL,
+    succeeded   INTEGER NOT NULL,
+    last_error  TEXT,
+    updated_at  INTEGER NOT NULL
+);
+";
+
+/// Final outcome recorded for a hash
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    /// Deposit found on the source chain
+    Valid,
+    /// Fraud detected and the cancel transaction confirmed
+    Cancelled,
+}
+
+impl Verdict {
+    fn as_str(self) -> &'static str {
+        match self {
+            Verdict::Valid => "valid",
+            Verdict::Cancelled => "cancelled",
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "valid" => Some(Verdict::Valid),
+            "cancelled" => Some(Verdict::Cancelled),
+            _ => None,
+        }
+    }
+}
+
+/// Cancel submissions recorded for a hash
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CancelAttempt {
+    pub dest_chain: [u8; 4],
+    pub attempts: u32,
+    pub succeeded: bool,
+    pub last_error: Option<String>,
+}
+
+/// Embedded write-through store for watcher state
+pub struct StateStore {
+    conn: Mutex<Connection>,
+}
+
+fn now_secs() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+impl StateStore {
+    /// Open (or create) the store at `path`; [`IN_MEMORY_PATH`] keeps it in memory.
+    pub fn open(path: &str) -> Result<Self> {
+        let conn = if path == IN_MEMORY_PATH {
+            Connection::open_in_memory()?
+        } else {
+            if let Some(dir) = Path::new(path).parent() {
+                if !dir.as_os_str().is_empty() {
+                    std::fs::create_dir_all(dir).wrap_err_with(|| {
+                        format!("Failed to create state dir {}", dir.display())
+                    })?;
+                }
+            }
+            let conn = Connection::open(path)
+                .wrap_err_with(|| format!("Failed to open canceler state at {path}"))?;
+            // WAL + synchronous=NORMAL: durable across process crashes, cheap on SD cards.
+            conn.pragma_update(None, "journal_mode", "WAL")?;
+            conn.pragma_update(None, "synchronous", "NORMAL")?;
+            conn
+        };
+        conn.execute_batch(SCHEMA)
+            .wrap_err("Failed to initialize canceler state schema")?;
+        Ok(Self {
+            conn: Mutex::new(conn),
+        })
+    }
+
+    fn conn(&self) -> MutexGuard<'_, Connection> {
+        // A panic mid-statement leaves SQLite consistent; keep using the connection.
+        self.conn.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Stored cursor for `chain` (e.g. `evm:00000001`, `terra`, `solana`).
+    pub fn cursor(&self, chain: &str) -> Result<Option<String>> {
+        Ok(self
+            .conn()
+            .query_row(
+                "SELECT position FROM cursors WHERE chain = ?1",
+                params![chain],
+                |row| row.get(0),
+            )
+            .optional()?)
+    }
+
+    /// Stored numeric cursor (block or height); `None` when unset or unparsable.
+    pub fn block_cursor(&self, chain: &str) -> Result<Option<u64>> {
+        Ok(self.cursor(chain)?.and_then(|p| p.parse().ok()))
+    }
+
+    pub fn set_cursor(&self, chain: &str, position: &str) -> Result<()> {
+        self.conn().execute(
+            "INSERT INTO cursors (chain, position, updated_at) VALUES (?1, ?2, ?3)
+             ON CONFLICT(chain) DO UPDATE SET position = ?2, updated_at = ?3",
+            params![chain, position, now_secs()],
+        )?;
+        Ok(())
+    }
+
+    pub fn record_verdict(
+        &self,
+        hash: &[u8; 32],
+        verdict: Verdict,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        self.conn().execute(
+            "INSERT INTO verdicts (hash, verdict, reason, recorded_at) VALUES (?1, ?2, ?3, ?4)
+             ON CONFLICT(hash) DO UPDATE SET verdict = ?2, reason = ?3, recorded_at = ?4",
+            params![&hash[..], verdict.as_str(), reason, now_secs()],
+        )?;
+        Ok(())
+    }
+
+    pub fn verdict(&self, hash: &[u8; 32]) -> Result<Option<Verdict>> {
+        let v: Option<String> = self
+            .conn()
+            .query_row(
+                "SELECT verdict FROM verdicts WHERE hash = ?1",
+                params![&hash[..]],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(v.as_deref().and_then(Verdict::parse))
+    }
+
+    /// Verdicts recorded in the last `max_age_secs`, newest first, at most `limit`
+    /// (used to re-warm the dedupe caches on startup).
+    pub fn recent_verdicts(
+        &self,
+        max_age_secs: u64,
+        limit: usize,
+    ) -> Result<Vec<([u8; 32], Verdict)>> {
+        let since = now_secs().saturating_sub(max_age_secs as i64);
+        let conn = self.conn();
+        let mut stmt = conn.prepare(
+            "SELECT hash, verdict FROM verdicts WHERE recorded_at >= ?1
+             ORDER BY recorded_at DESC LIMIT ?2",
+        )?;
+        let rows = stmt.query_map(params![since, limit as i64], |row| {
+            Ok((row.get::<_, Vec<u8>>(0)?, row.get::<_, String>(1)?))
+        })?;
+        let mut out = Vec::new();
+        for row in rows {
+            let (hash, verdict) = row?;
+            if let (Ok(hash), Some(verdict)) =
+                (<[u8; 32]>::try_from(hash), Verdict::parse(&verdict))
+            {
+                out.push((hash, verdict));
+            }
+        }
+        Ok(out)
+    }
+
+    /// Drop all verdicts (chain reset: hashes may be reused).
+    pub fn clear_verdicts(&self) -> Result<()> {
+        self.conn().execute("DELETE FROM verdicts", [])?;
+        Ok(())
+    }
+
+    pub fn put_pending(&self, approval: &PendingApproval) -> Result<()> {
+        let json = serde_json::to_string(approval)?;
+        self.conn().execute(
+            "INSERT INTO pending_retries (hash, approval, queued_at) VALUES (?1, ?2, ?3)
+             ON CONFLICT(hash) DO UPDATE SET approval = ?2",
+            params![&approval.xchain_hash_id[..], json, now_secs()],
+        )?;
+        Ok(())
+    }
+
+    pub fn remove_pending(&self, hash: &[u8; 32]) -> Result<()> {
+        self.conn().execute(
+            "DELETE FROM pending_retries WHERE hash = ?1",
+            params![&hash[..]],
+        )?;
+        Ok(())
+    }
+
+    /// Pending approvals queued within `ttl_secs`; older rows are deleted (same TTL as the
+    /// in-memory retry queue).
+    pub fn load_pending(&self, ttl_secs: u64) -> Result<Vec<PendingApproval>> {
+        let since = now_secs().saturating_sub(ttl_secs as i64);
+        let conn = self.conn();
+        conn.execute(
+            "DELETE FROM pending_retries WHERE queued_at < ?1",
+            params![since],
+        )?;
+        let mut stmt = conn.prepare("SELECT approval FROM pending_retries ORDER BY queued_at")?;
+        let rows = stmt.query_map([], |row| row.get::<_, String>(0))?;
+        let mut out = Vec::new();
+        for row in rows {
+            match serde_json::from_str(&row?) {
+                Ok(approval) => out.push(approval),
+                Err(e) => tracing::warn!(error = %e, "Dropping unreadable persisted retry"),
+            }
+        }
+        Ok(out)
+    }
+
+    pub fn clear_pending(&self) -> Result<()> {
+        self.conn().execute("DELETE FROM pending_retries", [])?;
+        Ok(())
+    }
+
+    /// Record one cancel submission for `hash` (`error` is None when it succeeded).
+    pub fn record_cancel_attempt(
+        &self,
+        hash: &[u8; 32],
+        dest_chain: &[u8; 4],
+        error: Option<&str>,
+    ) -> Result<()> {
+        self.conn().execute(
+            "INSERT INTO cancel_attempts (hash, dest_chain, attempts, succeeded, last_error, updated_at)
+             VALUES (?1, ?2, 1, ?3, ?4, ?5)
+             ON CONFLICT(hash) DO UPDATE SET attempts = attempts + 1,
+                 succeeded = MAX(succeeded, ?3), last_error = ?4, updated_at = ?5",
+            params![
+                &hash[..],
+                &dest_chain[..],
+                error.is_none(),
+                error,
+                now_secs()
+            ],
+        )?;
+        Ok(())
+    }
+
+    pub fn cancel_attempt(&self, hash: &[u8; 32]) -> Result<Option<CancelAttempt>> {
+        Ok(self
+            .conn()
+            .query_row(
+                "SELECT dest_chain, attempts, succeeded, last_error FROM cancel_attempts
+                 WHERE hash = ?1",
+                params![&hash[..]],
+                |row| {
+                    let dest: Vec<u8> = row.get(0)?;
+                    let mut dest_chain = [0u8; 4];
+                    if dest.len() == 4 {
+                        dest_chain.copy_from_slice(&dest);
+                    }
+                    Ok(CancelAttempt {
+                        dest_chain,
+                        attempts: row.get(1)?,
+                        succeeded: row.get(2)?,
+                        last_error: row.get(3)?,
+                    })
+                },
+            )
+            .optional()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approval(hash: u8) -> PendingApproval {
+        PendingApproval {
+            xchain_hash_id: [hash; 32],
+            src_chain_id: [0, 0, 0, 1],
+            dest_chain_id: [0, 0, 0, 2],
+            src_account: [1; 32],
+            dest_account: [2; 32],
+            dest_token: [3; 32],
+            amount: u128::MAX,
+            nonce: 7,
+            approved_at_timestamp: 1_700_000_000,
+            cancel_window: 300,
+            relay_fee: 0,
+        }
+    }
+
+    #[test]
+    fn test_cursor_roundtrip() {
+        let store = StateStore::open(IN_MEMORY_PATH).unwrap();
+        assert_eq!(store.block_cursor("evm:00000001").unwrap(), None);
+        store.set_cursor("evm:00000001", "100").unwrap();
+        store.set_cursor("evm:00000001", "250").unwrap();
+        assert_eq!(store.block_cursor("evm:00000001").unwrap(), Some(250));
+        store.set_cursor("solana", "5Sig").unwrap();
+        assert_eq!(store.cursor("solana").unwrap().as_deref(), Some("5Sig"));
+    }
+
+    #[test]
+    fn test_verdicts_and_clear() {
+        let store = StateStore::open(IN_MEMORY_PATH).unwrap();
+        store
+            .record_verdict(&[1; 32], Verdict::Valid, None)
+            .unwrap();
+        store
+            .record_verdict(&[2; 32], Verdict::Cancelled, Some("no deposit"))
+            .unwrap();
+        assert_eq!(store.verdict(&[1; 32]).unwrap(), Some(Verdict::Valid));
+        assert_eq!(store.recent_verdicts(3600, 10).unwrap().len(), 2);
+        assert_eq!(store.recent_verdicts(3600, 1).unwrap().len(), 1);
+        store.clear_verdicts().unwrap();
+        assert_eq!(store.verdict(&[2; 32]).unwrap(), None);
+    }
+
+    #[test]
+    fn test_pending_roundtrip_keeps_u128_amount() {
+        let store = StateStore::open(IN_MEMORY_PATH).unwrap();
+        store.put_pending(&approval(1)).unwrap();
+        store.put_pending(&approval(2)).unwrap();
+        store.remove_pending(&[1; 32]).unwrap();
+        let loaded = store.load_pending(3600).unwrap();
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].xchain_hash_id, [2; 32]);
+        assert_eq!(loaded[0].amount, u128::MAX);
+    }
+
+    #[test]
+    fn test_cancel_attempts_accumulate() {
+        let store = StateStore::open(IN_MEMORY_PATH).unwrap();
+        let hash = [9; 32];
+        store
+            .record_cancel_attempt(&hash, &[0, 0, 0, 1], Some("nonce too low"))
+            .unwrap();
+        store
+            .record_cancel_attempt(&hash, &[0, 0, 0, 1], None)
+            .unwrap();
+        let attempt = store.cancel_attempt(&hash).unwrap().unwrap();
+        assert_eq!(attempt.attempts, 2);
+        assert!(attempt.succeeded);
+        assert_eq!(attempt.last_error, None);
+    }
+
+    #[test]
+    fn test_file_store_survives_reopen() {
+        let dir = std::env::temp_dir().join(format!("cl8y-canceler-state-{}", std::process::id()));
+        let path = dir.join("state.db");
+        let path = path.to_str().unwrap();
+        {
+            let store = StateStore::open(path).unwrap();
+            store.set_cursor("terra", "42").unwrap();
+            store.put_pending(&approval(3)).unwrap();
+        }
+        let store = StateStore::open(path).unwrap();
+        assert_eq!(store.block_cursor("terra").unwrap(), Some(42));
+        assert_eq!(store.load_pending(3600).unwrap().len(), 1);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
@@ -0,0 +1,80 @@
+//! Shared token metadata cache
+//!
+//! The canceler keeps the same [`TokenCache`] as the operator (see
+//! `multichain_rs::token_cache`) over the bridges it watches, so amount mismatches can be
+//! explained with both tokens' decimals.
+//!
+//! | Variable | Default | |
+//! |----------|---------|-|
+//! | `CANCELER_TOKEN_CACHE_PATH` | unset (memory only) | JSON file the cache is saved to and loaded from |
+//! | `CANCELER_TOKEN_CACHE_POLL_SECS` | 30 | How often registries are checked for changes |
+//! | `CANCELER_TOKEN_CACHE_FULL_REFRESH_SECS` | 600 | How often every registry is re-read regardless |
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use alloy::primitives::Address;
+use eyre::{eyre, Result, WrapErr};
+use multichain_rs::token_cache::{self, TokenCache};
+use multichain_rs::trace::TraceEndpoint;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::config::Config;
+
+/// Open the cache and start refreshing it in the background
+pub async fn start(config: &Config) -> Result<Arc<TokenCache>> {
+    let path = std::env::var("CANCELER_TOKEN_CACHE_PATH")
+        .ok()
+        .filter(|p| !p.is_empty())
+        .map(PathBuf::from);
+    let poll = secs_from_env("CANCELER_TOKEN_CACHE_POLL_SECS", 30)?;
+    let full_refresh = secs_from_env("CANCELER_TOKEN_CACHE_FULL_REFRESH_SECS", 600)?;
+    Ok(token_cache::start(&endpoints(config)?, path, poll, full_refresh).await)
+}
+
+fn endpoints(config: &Config) -> Result<Vec<TraceEndpoint>> {
+    let mut evm_bridges = vec![(
+        config.evm_rpc_url.clone(),
+        config.evm_bridge_address.clone(),
+    )];
+    if let Some(multi) = &config.multi_evm {
+        for chain in multi.enabled_chains() {
+            evm_bridges.push((chain.rpc_url.clone(), chain.bridge_address.clone()));
+        }
+    }
+
+    let mut seen = HashSet::new();
+    let mut endpoints = Vec::new();
+    for (rpc_url, bridge) in evm_bridges {
+        let bridge = Address::from_str(&bridge)
+            .wrap_err_with(|| format!("Invalid EVM bridge address {}", bridge))?;
+        if seen.insert(bridge) {
+            endpoints.push(TraceEndpoint::Evm { rpc_url, bridge });
+        }
+    }
+    endpoints.push(TraceEndpoint::Terra {
+        lcd_url: config.terra_lcd_url.clone(),
+        bridge: config.terra_bridge_address.clone(),
+    });
+    if let Some(solana) = &config.solana {
+        endpoints.push(TraceEndpoint::Solana {
+            rpc_url: solana.rpc_url.clone(),
+            program_id: Pubkey::from_str(&solana.program_id)
+                .wrap_err_with(|| format!("Invalid Solana program ID {}", solana.program_id))?,
+        });
+    }
+    Ok(endpoints)
+}
+
+fn secs_from_env(name: &str, default: u64) -> Result<Duration> {
+    match std::env::var(name) {
+        Ok(v) => match v.parse::<u64>() {
+            Ok(n) if n > 0 => Ok(Duration::from_secs(n)),
+            _ => Err(eyre!("{} must be a positive integer, got {:?}", name, v)),
+        },
+        Err(_) => Ok(Duration::from_secs(default)),
+    }
+}
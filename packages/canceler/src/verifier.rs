@@ -9,6 +9,10 @@
 //! 2. Verifier queries source chain for matching deposit:
 //!    - For EVM source: calls `deposits(xchainHashId)` on EVM bridge
 //!    - For Terra source: calls `VerifyDeposit` query on Terra bridge
+//!    - For Solana source: reads the `DepositRecord` PDA and compares every field
+//!    - For operator-relayed withdrawals: the depositor must have requested the relay on the
+//!      source chain (`requestRelay`, Terra `RequestRelay` or the Solana `RelayRequest` PDA)
+//!      with a maximum at least as large as the relay fee
 //! 3. If deposit exists and parameters match → Valid
 //! 4. If deposit missing or parameters mismatch → Invalid → Submit cancellation
 
@@ -19,6 +23,12 @@ use alloy::providers::ProviderBuilder;
 use alloy::sol;
 use base64::Engine;
 use eyre::Result;
+use multichain_rs::solana::{
+    deposit_hashes_pda, deposit_record_pda, parse_deposit_hash, parse_deposit_record,
+    parse_relay_request, relay_request_pda, SolanaDepositRecord,
+};
+use multichain_rs::token_cache::TokenCache;
+use multichain_rs::types::ChainId;
 use reqwest::Client;
 use std::str::FromStr;
 use std::sync::atomic::{AtomicU64, Ordering};
@@ -46,11 +56,14 @@ sol! {
             uint256 fee,
             uint256 timestamp
         );
+
+        /// Max relay fee the depositor accepts (0 = relay not requested)
+        function relayRequests(bytes32 xchainHashId) external view returns (uint256);
     }
 }
 
 /// Pending approval to verify (V2 - uses 4-byte chain IDs)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct PendingApproval {
     pub xchain_hash_id: [u8; 32],
     /// Source chain ID (4 bytes)
@@ -71,6 +84,9 @@ pub struct PendingApproval {
     /// Cancel window seconds (for time-based decisions)
     #[allow(dead_code)]
     pub cancel_window: u64,
+    /// Fee the relaying operator takes out of the amount (0 when the recipient submitted)
+    #[serde(default)]
+    pub relay_fee: u128,
 }
 
 /// Verification result
@@ -114,6 +130,8 @@ pub struct ApprovalVerifier {
     solana_config: Option<SolanaVerifierConfig>,
     /// C6: Counter for unknown source chain events (aids alerting)
     unknown_source_chain_count: AtomicU64,
+    /// Shared token metadata, used to explain amount mismatches
+    token_cache: Option<std::sync::Arc<TokenCache>>,
 }
 
 /// Solana configuration for the verifier to verify deposits on Solana source chain.
@@ -198,6 +216,7 @@ impl ApprovalVerifier {
             known_evm_chains,
             solana_config: None,
             unknown_source_chain_count: AtomicU64::new(0),
+            token_cache: None,
         }
     }
 
@@ -389,6 +408,28 @@ impl ApprovalVerifier {
                     });
                 }
 
+                if approval.relay_fee > 0 {
+                    let max_relay_fee = match contract.relayRequests(xchain_hash_id).call().await {
+                        Ok(r) => r._0.try_into().unwrap_or(u128::MAX),
+                        Err(e) => {
+                            warn!(
+                                error = %e,
+                                hash = %bytes32_to_hex(&approval.xchain_hash_id),
+                                "Failed to query relay request - will retry"
+                            );
+                            return Ok(VerificationResult::Pending);
+                        }
+                    };
+                    if let Some(reason) = relay_fee_mismatch(approval.relay_fee, max_relay_fee) {
+                        info!(
+                            hash = %bytes32_to_hex(&approval.xchain_hash_id),
+                            reason = %reason,
+                            "Relay fee not authorized by depositor"
+                        );
+                        return Ok(VerificationResult::Invalid { reason });
+                    }
+                }
+
                 info!(
                     hash = %bytes32_to_hex(&approval.xchain_hash_id),
                     nonce = approval.nonce,
@@ -515,6 +556,20 @@ impl ApprovalVerifier {
                     }
                 }
 
+                if approval.relay_fee > 0 {
+                    let Some(max_relay_fee) = self.terra_max_relay_fee(approval).await else {
+                        return Ok(VerificationResult::Pending);
+                    };
+                    if let Some(reason) = relay_fee_mismatch(approval.relay_fee, max_relay_fee) {
+                        info!(
+                            hash = %bytes32_to_hex(&approval.xchain_hash_id),
+                            reason = %reason,
+                            "Relay fee not authorized by depositor"
+                        );
+                        return Ok(VerificationResult::Invalid { reason });
+                    }
+                }
+
                 info!(
                     hash = %bytes32_to_hex(&approval.xchain_hash_id),
                     nonce = approval.nonce,
@@ -535,9 +590,14 @@ impl ApprovalVerifier {
 
     /// Verify a deposit exists on Solana source chain.
     ///
-    /// Queries the Solana RPC `getAccountInfo` for the DepositRecord PDA
-    /// derived from the deposit nonce. Verifies the stored transfer_hash
-    /// matches the expected hash.
+    /// Reads the `DepositRecord` PDA for the approval's nonce (the program keys deposits by
+    /// nonce) and decodes it fully. The record must carry the approval's `xchain_hash_id`,
+    /// and every hashed field — dest chain, src account, dest account, token, amount and
+    /// nonce — must match; each differing field is named in the `Invalid` reason.
+    ///
+    /// Records are closed after `DepositRecord::RETENTION`, keeping only their transfer hash
+    /// in a `DepositHashes` chunk. A missing record is `Valid` when that hash equals the
+    /// approval's and `Invalid` otherwise.
     async fn verify_solana_deposit(
         &self,
         approval: &PendingApproval,
@@ -556,15 +616,9 @@ impl ApprovalVerifier {
             "Querying Solana source chain for deposit"
         );
 
-        let nonce_bytes = approval.nonce.to_le_bytes();
-
         // Derive DepositRecord PDA: seeds = ["deposit", nonce.to_le_bytes()]
         let program_id = solana_sdk::pubkey::Pubkey::new_from_array(config.program_id);
-        let (deposit_pda, _bump) = solana_sdk::pubkey::Pubkey::find_program_address(
-            &[b"deposit", &nonce_bytes],
-            &program_id,
-        );
-        let deposit_pda_b58 = deposit_pda.to_string();
+        let deposit_pda_b58 = deposit_record_pda(&program_id, approval.nonce).to_string();
 
         let body = serde_json::json!({
             "jsonrpc": "2.0",
@@ -609,52 +663,87 @@ impl ApprovalVerifier {
                     let result = &json["result"]["value"];
 
                     if result.is_null() {
+                        // `close_deposit` moves the record's hash into `DepositHashes` after
+                        // `DepositRecord::RETENTION`; without it there was no such deposit
+                        let Some(kept) = self
+                            .solana_closed_deposit_hash(config, approval.nonce)
+                            .await
+                        else {
+                            return Ok(VerificationResult::Pending);
+                        };
+                        if kept != approval.xchain_hash_id {
+                            info!(
+                                hash = %bytes32_to_hex(&approval.xchain_hash_id),
+                                nonce = approval.nonce,
+                                pda = %deposit_pda_b58,
+                                "No deposit PDA found on Solana source chain"
+                            );
+                            return Ok(VerificationResult::Invalid {
+                                reason: "No deposit found with this nonce on Solana source chain"
+                                    .to_string(),
+                            });
+                        }
+                        // The hash commits to every deposit field; the relay request was
+                        // closed with the record, so only unrelayed withdrawals verify
+                        if let Some(verdict) = self.solana_relay_fee_verdict(config, approval).await
+                        {
+                            return Ok(verdict);
+                        }
                         info!(
                             hash = %bytes32_to_hex(&approval.xchain_hash_id),
                             nonce = approval.nonce,
-                            pda = %deposit_pda_b58,
-                            "No deposit PDA found on Solana source chain"
+                            "Deposit verified on Solana source chain from its closed record's hash"
                         );
-                        return Ok(VerificationResult::Invalid {
-                            reason: "No deposit found with this nonce on Solana source chain"
-                                .to_string(),
-                        });
+                        return Ok(VerificationResult::Valid);
                     }
 
-                    // Parse base64 account data: 8 (discriminator) + 32 (transfer_hash) + ...
-                    if let Some(data_arr) = result["data"].as_array() {
-                        if let Some(data_b64) = data_arr.first().and_then(|v| v.as_str()) {
-                            if let Ok(data_bytes) =
-                                base64::engine::general_purpose::STANDARD.decode(data_b64)
+                    let record = result["data"]
+                        .as_array()
+                        .and_then(|arr| arr.first())
+                        .and_then(|v| v.as_str())
+                        .and_then(|b64| base64::engine::general_purpose::STANDARD.decode(b64).ok())
+                        .and_then(|data| parse_deposit_record(&data).ok());
+                    if let Some(record) = record {
+                        let mut mismatches = solana_deposit_mismatches(approval, &record);
+                        if mismatches.iter().any(|m| m.starts_with("amount:")) {
+                            if let Some(note) = self
+                                .token_cache
+                                .as_deref()
+                                .and_then(|cache| token_decimals_note(cache, approval, &record))
                             {
-                                if data_bytes.len() >= 40 {
-                                    let mut stored_hash = [0u8; 32];
-                                    stored_hash.copy_from_slice(&data_bytes[8..40]);
-
-                                    if stored_hash != approval.xchain_hash_id {
-                                        info!(
-                                            expected = %bytes32_to_hex(&approval.xchain_hash_id),
-                                            got = %bytes32_to_hex(&stored_hash),
-                                            "Deposit exists on Solana but hash doesn't match"
-                                        );
-                                        return Ok(VerificationResult::Invalid {
-                                            reason: format!(
-                                                "Hash mismatch: expected {}, got {}",
-                                                bytes32_to_hex(&approval.xchain_hash_id),
-                                                bytes32_to_hex(&stored_hash)
-                                            ),
-                                        });
-                                    }
-
-                                    info!(
-                                        hash = %bytes32_to_hex(&approval.xchain_hash_id),
-                                        nonce = approval.nonce,
-                                        "Deposit verified on Solana source chain"
-                                    );
-                                    return Ok(VerificationResult::Valid);
-                                }
+                                mismatches.push(note);
                             }
                         }
+                        if !mismatches.is_empty() {
+                            info!(
+                                hash = %bytes32_to_hex(&approval.xchain_hash_id),
+                                nonce = approval.nonce,
+                                pda = %deposit_pda_b58,
+                                mismatches = %mismatches.join("; "),
+                                "Deposit exists on Solana but does not match the approval"
+                            );
+                            return Ok(VerificationResult::Invalid {
+                                reason: format!(
+                                    "Solana deposit {} does not match approval: {}",
+                                    approval.nonce,
+                                    mismatches.join("; ")
+                                ),
+                            });
+                        }
+
+                        if let Some(verdict) = self.solana_relay_fee_verdict(config, approval).await
+                        {
+                            return Ok(verdict);
+                        }
+
+                        info!(
+                            hash = %bytes32_to_hex(&approval.xchain_hash_id),
+                            nonce = approval.nonce,
+                            amount = approval.amount,
+                            dest_chain = %format!("0x{}", hex::encode(record.dest_chain)),
+                            "Deposit verified on Solana source chain"
+                        );
+                        return Ok(VerificationResult::Valid);
                     }
 
                     warn!(
@@ -684,6 +773,197 @@ impl ApprovalVerifier {
         Ok(VerificationResult::Pending)
     }
 
+    /// Max relay fee the depositor accepted on Terra (`RelayRequest` query; 0 when none),
+    /// or `None` when the query failed and verification should be retried.
+    async fn terra_max_relay_fee(&self, approval: &PendingApproval) -> Option<u128> {
+        let query = serde_json::json!({
+            "relay_request": {
+                "xchain_hash_id": base64::engine::general_purpose::STANDARD.encode(approval.xchain_hash_id)
+            }
+        });
+        let query_b64 =
+            base64::engine::general_purpose::STANDARD.encode(serde_json::to_string(&query).ok()?);
+        let url = format!(
+            "{}/cosmwasm/wasm/v1/contract/{}/smart/{}",
+            self.terra_lcd_url, self.terra_bridge_address, query_b64
+        );
+
+        let json: serde_json::Value = match self.client.get(&url).send().await {
+            Ok(resp) if resp.status().is_success() => match resp.json().await {
+                Ok(json) => json,
+                Err(e) => {
+                    warn!(
+                        error = %e,
+                        hash = %bytes32_to_hex(&approval.xchain_hash_id),
+                        "Failed to parse Terra relay request - will retry"
+                    );
+                    return None;
+                }
+            },
+            Ok(resp) => {
+                warn!(
+                    status = %resp.status(),
+                    hash = %bytes32_to_hex(&approval.xchain_hash_id),
+                    "Terra relay request query returned error status - will retry"
+                );
+                return None;
+            }
+            Err(e) => {
+                warn!(
+                    error = %e,
+                    hash = %bytes32_to_hex(&approval.xchain_hash_id),
+                    "Failed to query Terra relay request - will retry"
+                );
+                return None;
+            }
+        };
+
+        // RelayRequest returns Option<RelayRequestResponse>; null means never requested
+        let data = &json["data"];
+        if data.is_null() {
+            return Some(0);
+        }
+        let max_relay_fee = data["max_relay_fee"]
+            .as_str()
+            .and_then(|v| v.parse::<u128>().ok());
+        if max_relay_fee.is_none() {
+            warn!(
+                data = %data,
+                hash = %bytes32_to_hex(&approval.xchain_hash_id),
+                "Unexpected Terra relay request response - will retry"
+            );
+        }
+        max_relay_fee
+    }
+
+    /// Transfer hash `close_deposit` kept for `nonce` in its `DepositHashes` chunk (all zero
+    /// when the record was never closed), or `None` when no RPC endpoint answered and
+    /// verification should be retried.
+    async fn solana_closed_deposit_hash(
+        &self,
+        config: &SolanaVerifierConfig,
+        nonce: u64,
+    ) -> Option<[u8; 32]> {
+        let program_id = solana_sdk::pubkey::Pubkey::new_from_array(config.program_id);
+        let pda = deposit_hashes_pda(&program_id, nonce).to_string();
+        let body = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "getAccountInfo",
+            "params": [pda, {"encoding": "base64", "commitment": "finalized"}]
+        });
+
+        for rpc_url in &config.rpc_urls {
+            let json: serde_json::Value = match self.client.post(rpc_url).json(&body).send().await {
+                Ok(resp) if resp.status().is_success() => match resp.json().await {
+                    Ok(json) => json,
+                    Err(_) => continue,
+                },
+                _ => continue,
+            };
+            if !json["error"].is_null() {
+                continue;
+            }
+            let value = &json["result"]["value"];
+            if value.is_null() {
+                return Some([0u8; 32]);
+            }
+            let hash = value["data"]
+                .as_array()
+                .and_then(|arr| arr.first())
+                .and_then(|v| v.as_str())
+                .and_then(|b64| base64::engine::general_purpose::STANDARD.decode(b64).ok())
+                .and_then(|data| parse_deposit_hash(&data, nonce).ok());
+            if let Some(hash) = hash {
+                return Some(hash);
+            }
+        }
+
+        warn!(
+            nonce,
+            pda = %pda,
+            "Failed to read Solana deposit hashes from every RPC endpoint - will retry"
+        );
+        None
+    }
+
+    /// Early verdict from the relay fee of a Solana-sourced approval: `Invalid` when it
+    /// exceeds the depositor's `RelayRequest` maximum, `Pending` when that could not be read,
+    /// `None` when the fee is authorized (or there is none).
+    async fn solana_relay_fee_verdict(
+        &self,
+        config: &SolanaVerifierConfig,
+        approval: &PendingApproval,
+    ) -> Option<VerificationResult> {
+        if approval.relay_fee == 0 {
+            return None;
+        }
+        let Some(max_relay_fee) = self.solana_max_relay_fee(config, approval).await else {
+            return Some(VerificationResult::Pending);
+        };
+        let reason = relay_fee_mismatch(approval.relay_fee, max_relay_fee)?;
+        info!(
+            hash = %bytes32_to_hex(&approval.xchain_hash_id),
+            reason = %reason,
+            "Relay fee not authorized by depositor"
+        );
+        Some(VerificationResult::Invalid { reason })
+    }
+
+    /// Max relay fee the depositor accepted on Solana (`RelayRequest` PDA; 0 when absent),
+    /// or `None` when no RPC endpoint answered and verification should be retried.
+    async fn solana_max_relay_fee(
+        &self,
+        config: &SolanaVerifierConfig,
+        approval: &PendingApproval,
+    ) -> Option<u128> {
+        let program_id = solana_sdk::pubkey::Pubkey::new_from_array(config.program_id);
+        let pda = relay_request_pda(&program_id, &approval.xchain_hash_id).to_string();
+        let body = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "getAccountInfo",
+            "params": [pda, {"encoding": "base64", "commitment": "finalized"}]
+        });
+
+        for rpc_url in &config.rpc_urls {
+            let json: serde_json::Value = match self.client.post(rpc_url).json(&body).send().await {
+                Ok(resp) if resp.status().is_success() => match resp.json().await {
+                    Ok(json) => json,
+                    Err(_) => continue,
+                },
+                _ => continue,
+            };
+            if !json["error"].is_null() {
+                continue;
+            }
+            let value = &json["result"]["value"];
+            if value.is_null() {
+                return Some(0);
+            }
+            let request = value["data"]
+                .as_array()
+                .and_then(|arr| arr.first())
+                .and_then(|v| v.as_str())
+                .and_then(|b64| base64::engine::general_purpose::STANDARD.decode(b64).ok())
+                .and_then(|data| parse_relay_request(&data).ok());
+            match request {
+                // The PDA is seeded by the hash, so this only guards against a bad parse
+                Some((hash, max_relay_fee)) if hash == approval.xchain_hash_id => {
+                    return Some(max_relay_fee)
+                }
+                _ => continue,
+            }
+        }
+
+        warn!(
+            hash = %bytes32_to_hex(&approval.xchain_hash_id),
+            pda = %pda,
+            "Failed to read Solana relay request from every RPC endpoint - will retry"
+        );
+        None
+    }
+
     /// Register Solana chain configuration for deposit verification.
     pub fn register_solana(&mut self, config: SolanaVerifierConfig) {
         info!(
@@ -699,6 +979,11 @@ impl ApprovalVerifier {
         self.solana_config = Some(config);
     }
 
+    /// Register the shared token cache; amount mismatches then name both tokens' decimals.
+    pub fn register_token_cache(&mut self, cache: std::sync::Arc<TokenCache>) {
+        self.token_cache = Some(cache);
+    }
+
     /// Register EVM chain peers for multi-chain verification routing.
     ///
     /// Call this after construction with chains from `MultiEvmConfig`.
@@ -752,6 +1037,161 @@ impl ApprovalVerifier {
     }
 }
 
+/// Compare a Solana `DepositRecord` with the approval it should back, field by field.
+///
+/// Returns one entry per differing field (empty when they agree), plus one when the
+/// record's stored hash does not match its own fields under the approval's source chain
+/// (the Solana bridge's V2 chain ID). Amounts compare in source (Solana mint) units, which
+/// is what both sides hash; when they differ by a power of ten the entry says so, since
+/// that points at decimal normalization applied to the hashed amount.
+fn solana_deposit_mismatches(
+    approval: &PendingApproval,
+    record: &SolanaDepositRecord,
+) -> Vec<String> {
+    let mut mismatches = Vec::new();
+    let mut field = |name: &str, expected: String, got: String| {
+        if expected != got {
+            mismatches.push(format!("{}: expected {}, got {}", name, expected, got));
+        }
+    };
+    field(
+        "xchain_hash_id",
+        bytes32_to_hex(&approval.xchain_hash_id),
+        bytes32_to_hex(&record.transfer_hash),
+    );
+    field(
+        "dest_chain",
+        format!("0x{}", hex::encode(approval.dest_chain_id)),
+        format!("0x{}", hex::encode(record.dest_chain)),
+    );
+    field(
+        "src_account",
+        bytes32_to_hex(&approval.src_account),
+        bytes32_to_hex(&record.src_account),
+    );
+    field(
+        "dest_account",
+        bytes32_to_hex(&approval.dest_account),
+        bytes32_to_hex(&record.dest_account),
+    );
+    field(
+        "token",
+        bytes32_to_hex(&approval.dest_token),
+        bytes32_to_hex(&record.token),
+    );
+    field(
+        "nonce",
+        approval.nonce.to_string(),
+        record.nonce.to_string(),
+    );
+
+    if approval.amount != record.amount {
+        let mut entry = format!(
+            "amount: expected {}, got {}",
+            approval.amount, record.amount
+        );
+        if let Some(exp) = decimal_rescale(record.amount, approval.amount) {
+            entry.push_str(&format!(
+                " (approval is the deposit amount scaled by 10^{}; the hashed amount must stay in source decimals)",
+                exp
+            ));
+        }
+        mismatches.push(entry);
+    }
+
+    let rehashed = compute_xchain_hash_id(
+        &approval.src_chain_id,
+        &record.dest_chain,
+        &record.src_account,
+        &record.dest_account,
+        &record.token,
+        record.amount,
+        record.nonce,
+    );
+    if rehashed != record.transfer_hash {
+        mismatches.push(format!(
+            "deposit record hash {} does not match its own fields under src_chain 0x{} (computed {})",
+            bytes32_to_hex(&record.transfer_hash),
+            hex::encode(approval.src_chain_id),
+            bytes32_to_hex(&rehashed)
+        ));
+    }
+    mismatches
+}
+
+/// Why a relayed withdrawal's fee is not covered by the depositor's `requestRelay`, if it isn't
+///
+/// Both amounts are in the deposit's (source) decimals.
+fn relay_fee_mismatch(relay_fee: u128, max_relay_fee: u128) -> Option<String> {
+    if max_relay_fee == 0 {
+        Some(format!(
+            "Relay fee {} charged but the depositor never requested a relay",
+            relay_fee
+        ))
+    } else if relay_fee > max_relay_fee {
+        Some(format!(
+            "Relay fee {} exceeds the depositor's maximum of {}",
+            relay_fee, max_relay_fee
+        ))
+    } else {
+        None
+    }
+}
+
+/// Decimals the token cache records for the deposited Solana token (the one routed to
+/// `record.token`) and for the approved token, when it knows either
+fn token_decimals_note(
+    cache: &TokenCache,
+    approval: &PendingApproval,
+    record: &SolanaDepositRecord,
+) -> Option<String> {
+    let describe = |token: Option<multichain_rs::token_cache::TokenMetadata>| {
+        token.map(|t| {
+            format!(
+                "{} has {} decimals",
+                t.symbol.unwrap_or(t.token),
+                t.decimals
+                    .map_or_else(|| "unknown".to_string(), |d| d.to_string())
+            )
+        })
+    };
+    let src = describe(cache.get_by_remote(
+        ChainId::from_bytes(approval.src_chain_id),
+        ChainId::from_bytes(record.dest_chain),
+        &record.token,
+    ));
+    let dest = describe(cache.get(
+        ChainId::from_bytes(approval.dest_chain_id),
+        &approval.dest_token,
+    ));
+    if src.is_none() && dest.is_none() {
+        return None;
+    }
+    let unknown = || "not in token cache".to_string();
+    Some(format!(
+        "token decimals: source {}, destination {}",
+        src.unwrap_or_else(unknown),
+        dest.unwrap_or_else(unknown)
+    ))
+}
+
+/// Power of ten `k` (may be negative) with `scaled == base * 10^k`, for `k` in ±1..=30
+fn decimal_rescale(base: u128, scaled: u128) -> Option<i32> {
+    if base == 0 || scaled == 0 {
+        return None;
+    }
+    (1..=30u32).find_map(|k| {
+        let factor = 10u128.checked_pow(k)?;
+        if base.checked_mul(factor) == Some(scaled) {
+            Some(k as i32)
+        } else if scaled.checked_mul(factor) == Some(base) {
+            Some(-(k as i32))
+        } else {
+            None
+        }
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -997,6 +1437,7 @@ mod tests {
             nonce,
             approved_at_timestamp: 0,
             cancel_window: 300,
+            relay_fee: 0,
         };
 
         let result = verifier.verify(&approval).await.unwrap();
@@ -1048,6 +1489,7 @@ mod tests {
             nonce: 1,
             approved_at_timestamp: 0,
             cancel_window: 300,
+            relay_fee: 0,
         };
 
         let result = verifier.verify(&approval).await.unwrap();
@@ -1062,4 +1504,278 @@ mod tests {
             _ => panic!("Hash mismatch should return Invalid"),
         }
     }
+
+    fn solana_fixture() -> (PendingApproval, SolanaDepositRecord) {
+        let src_chain = [0, 0, 0, 5];
+        let dest_chain = [0, 0, 0, 1];
+        let (src_account, dest_account, token) = ([0x11; 32], [0x22; 32], [0x33; 32]);
+        let (amount, nonce) = (1_500_000_000u128, 7u64);
+        let hash = compute_xchain_hash_id(
+            &src_chain,
+            &dest_chain,
+            &src_account,
+            &dest_account,
+            &token,
+            amount,
+            nonce,
+        );
+        let approval = PendingApproval {
+            xchain_hash_id: hash,
+            src_chain_id: src_chain,
+            dest_chain_id: dest_chain,
+            src_account,
+            dest_account,
+            dest_token: token,
+            amount,
+            nonce,
+            approved_at_timestamp: 0,
+            cancel_window: 300,
+            relay_fee: 0,
+        };
+        let record = SolanaDepositRecord {
+            transfer_hash: hash,
+            src_account,
+            dest_chain,
+            dest_account,
+            token,
+            amount,
+            nonce,
+            timestamp: 1_700_000_000,
+        };
+        (approval, record)
+    }
+
+    #[test]
+    fn test_solana_deposit_matching_record_has_no_mismatches() {
+        let (approval, record) = solana_fixture();
+        assert!(solana_deposit_mismatches(&approval, &record).is_empty());
+    }
+
+    #[test]
+    fn test_solana_deposit_mismatches_name_each_field() {
+        let (mut approval, record) = solana_fixture();
+        approval.dest_account = [0x44; 32];
+        approval.dest_chain_id = [0, 0, 0, 2];
+        let mismatches = solana_deposit_mismatches(&approval, &record);
+        assert_eq!(mismatches.len(), 2, "{:?}", mismatches);
+        assert!(mismatches[0].starts_with("dest_chain: expected 0x00000002, got 0x00000001"));
+        assert!(mismatches[1].starts_with("dest_account: expected 0x4444"));
+    }
+
+    #[test]
+    fn test_solana_deposit_amount_mismatch_reports_rescale() {
+        let (mut approval, record) = solana_fixture();
+        approval.amount = record.amount * 1_000_000_000;
+        let mismatches = solana_deposit_mismatches(&approval, &record);
+        assert_eq!(mismatches.len(), 1, "{:?}", mismatches);
+        assert!(mismatches[0].starts_with("amount: expected 1500000000000000000, got 1500000000"));
+        assert!(mismatches[0].contains("scaled by 10^9"));
+    }
+
+    #[test]
+    fn test_token_decimals_note_uses_token_cache() {
+        use multichain_rs::token_cache::{CachedRoute, TokenMetadata};
+        use multichain_rs::types::TokenMode;
+
+        let (approval, record) = solana_fixture();
+        let cache = TokenCache::new();
+        assert_eq!(token_decimals_note(&cache, &approval, &record), None);
+
+        cache.replace_chain(
+            ChainId::from_bytes(approval.src_chain_id),
+            vec![TokenMetadata {
+                chain: ChainId::from_bytes(approval.src_chain_id),
+                token: "mint".to_string(),
+                id: [0x55; 32],
+                decimals: Some(9),
+                symbol: Some("wLUNC".to_string()),
+                native: false,
+                routes: vec![CachedRoute {
+                    remote_chain: ChainId::from_bytes(record.dest_chain),
+                    remote_token: Some(record.token),
+                    remote_decimals: Some(6),
+                    incoming_decimals: Some(6),
+                    mode: TokenMode::MintBurn,
+                }],
+            }],
+        );
+        assert_eq!(
+            token_decimals_note(&cache, &approval, &record).as_deref(),
+            Some("token decimals: source wLUNC has 9 decimals, destination not in token cache")
+        );
+    }
+
+    #[test]
+    fn test_solana_deposit_record_hashed_under_other_src_chain() {
+        let (mut approval, record) = solana_fixture();
+        approval.src_chain_id = [0, 0, 0, 6];
+        let mismatches = solana_deposit_mismatches(&approval, &record);
+        assert_eq!(mismatches.len(), 1, "{:?}", mismatches);
+        assert!(mismatches[0].contains("does not match its own fields under src_chain 0x00000006"));
+    }
+
+    #[test]
+    fn test_relay_fee_mismatch() {
+        assert_eq!(relay_fee_mismatch(100, 100), None);
+        let reason = relay_fee_mismatch(100, 0).unwrap();
+        assert!(reason.contains("never requested"), "{}", reason);
+        let reason = relay_fee_mismatch(101, 100).unwrap();
+        assert!(reason.contains("maximum of 100"), "{}", reason);
+    }
+
+    /// A Terra-sourced relayed withdrawal whose fee exceeds the depositor's `request_relay`
+    /// maximum is reported Invalid (and so cancelled); one within the maximum verifies.
+    #[tokio::test]
+    async fn test_terra_relay_fee_above_request_is_cancelled() {
+        use axum::{extract::Path, routing::get, Json, Router};
+
+        let terra_chain = 2u32.to_be_bytes();
+        let (src_account, dest_account, dest_token) = ([0x11; 32], [0x22; 32], [0x33; 32]);
+        let (amount, nonce) = (1_000_000u128, 9u64);
+        let hash = compute_xchain_hash_id(
+            &terra_chain,
+            &1u32.to_be_bytes(),
+            &src_account,
+            &dest_account,
+            &dest_token,
+            amount,
+            nonce,
+        );
+
+        // Mock LCD: the deposit exists and the depositor accepted a fee of at most 100
+        let lcd = Router::new().route(
+            "/cosmwasm/wasm/v1/contract/{contract}/smart/{query}",
+            get(move |Path((_, query)): Path<(String, String)>| async move {
+                let query = base64::engine::general_purpose::STANDARD
+                    .decode(query)
+                    .unwrap();
+                let query: serde_json::Value = serde_json::from_slice(&query).unwrap();
+                let data = if query.get("relay_request").is_some() {
+                    serde_json::json!({ "max_relay_fee": "100" })
+                } else {
+                    serde_json::json!({ "nonce": nonce, "amount": amount.to_string() })
+                };
+                Json(serde_json::json!({ "data": data }))
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let lcd_url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, lcd).await });
+
+        let verifier = ApprovalVerifier::new_v2(
+            "http://localhost:8545",
+            "0x0000000000000000000000000000000000000001",
+            &lcd_url,
+            "terra1bridge",
+            1u32.to_be_bytes(),
+            terra_chain,
+        );
+        let mut approval = PendingApproval {
+            xchain_hash_id: hash,
+            src_chain_id: terra_chain,
+            dest_chain_id: 1u32.to_be_bytes(),
+            src_account,
+            dest_account,
+            dest_token,
+            amount,
+            nonce,
+            approved_at_timestamp: 0,
+            cancel_window: 300,
+            relay_fee: 150,
+        };
+
+        match verifier.verify(&approval).await.unwrap() {
+            VerificationResult::Invalid { reason } => {
+                assert!(
+                    reason.contains("exceeds the depositor's maximum of 100"),
+                    "{}",
+                    reason
+                )
+            }
+            other => panic!(
+                "Relay fee above the request should be Invalid, got {:?}",
+                other
+            ),
+        }
+
+        approval.relay_fee = 100;
+        assert!(matches!(
+            verifier.verify(&approval).await.unwrap(),
+            VerificationResult::Valid
+        ));
+    }
+
+    /// Mock Solana RPC with no `DepositRecord` accounts, keeping `kept` as the closed record's
+    /// hash for `nonce` (no `DepositHashes` account when `None`)
+    async fn mock_solana_rpc(program_id: [u8; 32], nonce: u64, kept: Option<[u8; 32]>) -> String {
+        use axum::{routing::post, Json, Router};
+
+        let program = solana_sdk::pubkey::Pubkey::new_from_array(program_id);
+        let hashes_pda = deposit_hashes_pda(&program, nonce).to_string();
+        let mut data = vec![0u8; 1041];
+        let slot = 16 + 32 * (nonce % 32) as usize;
+        data[slot..slot + 32].copy_from_slice(&kept.unwrap_or_default());
+        let data = base64::engine::general_purpose::STANDARD.encode(data);
+
+        let rpc = Router::new().route(
+            "/",
+            post(move |Json(req): Json<serde_json::Value>| async move {
+                let value = if kept.is_some() && req["params"][0] == hashes_pda.as_str() {
+                    serde_json::json!({ "data": [data, "base64"] })
+                } else {
+                    serde_json::Value::Null
+                };
+                Json(serde_json::json!({ "jsonrpc": "2.0", "id": 1, "result": { "value": value } }))
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, rpc).await });
+        url
+    }
+
+    /// A closed `DepositRecord` verifies only against the hash `close_deposit` kept for its
+    /// nonce; with no kept hash, or a different one, the approval is cancelled.
+    #[tokio::test]
+    async fn test_solana_closed_deposit_record_checks_kept_hash() {
+        let (approval, _) = solana_fixture();
+        let program_id = [0x77; 32];
+
+        for (kept, valid) in [
+            (Some(approval.xchain_hash_id), true),
+            (Some([0x99; 32]), false),
+            (None, false),
+        ] {
+            let mut verifier = ApprovalVerifier::new_v2(
+                "http://localhost:8545",
+                "0x0000000000000000000000000000000000000001",
+                "http://localhost:1317",
+                "terra1...",
+                1u32.to_be_bytes(),
+                2u32.to_be_bytes(),
+            );
+            verifier.register_solana(SolanaVerifierConfig {
+                rpc_urls: vec![mock_solana_rpc(program_id, approval.nonce, kept).await],
+                program_id,
+                chain_ids: vec![approval.src_chain_id],
+            });
+
+            match verifier.verify(&approval).await.unwrap() {
+                VerificationResult::Valid => assert!(valid, "unbacked approval verified"),
+                VerificationResult::Invalid { reason } => {
+                    assert!(!valid, "kept hash not accepted: {}", reason);
+                    assert!(reason.contains("No deposit found"), "{}", reason);
+                }
+                VerificationResult::Pending => panic!("closed deposit record left pending"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_decimal_rescale() {
+        assert_eq!(decimal_rescale(15, 15_000), Some(3));
+        assert_eq!(decimal_rescale(15_000, 15), Some(-3));
+        assert_eq!(decimal_rescale(15, 16), None);
+        assert_eq!(decimal_rescale(0, 0), None);
+    }
 }
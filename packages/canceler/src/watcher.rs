@@ -30,6 +30,7 @@ use alloy::sol;
 use alloy::transports::http::{Client, Http};
 use base64::Engine as _;
 use eyre::{eyre, Result, WrapErr};
+use multichain_rs::solana::MAX_LARGE_TRANSFER_DELAY_SECS;
 use multichain_rs::{evm_consensus_latest_block, EvmRpcReadPolicy};
 use std::str::FromStr;
 use tokio::sync::mpsc;
@@ -39,7 +40,8 @@ use crate::config::Config;
 use crate::evm_client::EvmClient;
 use crate::hash::bytes32_to_hex;
 use crate::server::{SharedMetrics, SharedStats};
-use crate::solana_client::SolanaCancelerClient;
+use crate::solana_client::{PendingWithdrawData, SolanaCancelerClient};
+use crate::state_store::{StateStore, Verdict};
 use crate::terra_client::TerraClient;
 use crate::verifier::{ApprovalVerifier, PendingApproval, VerificationResult};
 
@@ -53,6 +55,17 @@ fn compute_event_topic(signature: &str) -> [u8; 32] {
     output
 }
 
+/// Attempts to resolve a Solana withdraw's effective delay before assuming the widest window.
+const SOLANA_DELAY_READ_ATTEMPTS: u32 = 3;
+
+/// Persistent cursor keys (see [`StateStore`]); EVM chains use [`evm_cursor_key`].
+const TERRA_CURSOR: &str = "terra";
+const SOLANA_CURSOR: &str = "solana";
+
+fn evm_cursor_key(v2_chain_id: &[u8; 4]) -> String {
+    format!("evm:{}", hex::encode(v2_chain_id))
+}
+
 /// Minimum block range when halving eth_getLogs chunks after provider limit errors.
 const EVM_GET_LOGS_MIN_CHUNK_BLOCKS: u64 = 100;
 
@@ -93,6 +106,9 @@ sol! {
         /// Get cancel window
         function getCancelWindow() external view returns (uint256);
 
+        /// Relayer and relay fee of an operator-submitted withdrawal (zero if user-submitted)
+        function getWithdrawRelay(bytes32 xchainHashId) external view returns (address relayer, uint256 fee);
+
         /// Get this chain's registered V2 chain ID (bytes4)
         function getThisChainId() external view returns (bytes4 chainId);
 
@@ -101,6 +117,31 @@ sol! {
     }
 }
 
+type BridgeReader<'a> = Bridge::BridgeInstance<Http<Client>, &'a RootProvider<Http<Client>>>;
+
+/// Fee the relaying operator takes out of the amount (0 if the recipient submitted).
+///
+/// `None` when the fee cannot be read. Callers skip the hash without marking it verified, so
+/// the next poll reads it again: an unknown fee must never pass as 0, which would skip the
+/// unauthorized-relay-fee check for good.
+async fn read_relay_fee(contract: &BridgeReader<'_>, xchain_hash_id: &[u8; 32]) -> Option<u128> {
+    match contract
+        .getWithdrawRelay(FixedBytes::from(*xchain_hash_id))
+        .call()
+        .await
+    {
+        Ok(r) => Some(r.fee.try_into().unwrap_or(u128::MAX)),
+        Err(e) => {
+            warn!(
+                error = %e,
+                xchain_hash_id = %bytes32_to_hex(xchain_hash_id),
+                "Failed to read relay fee, will retry on next poll"
+            );
+            None
+        }
+    }
+}
+
 /// Main watcher that monitors all chains for approvals (V2)
 /// Per-chain polling state for multi-EVM monitoring
 struct EvmChainPollState {
@@ -128,6 +169,7 @@ pub struct CancelerWatcher {
     /// V2 chain ID for Solana (if configured)
     solana_chain_id: Option<[u8; 4]>,
     /// Solana bridge `withdraw_delay` in seconds (from on-chain config; default 300).
+    /// Fallback when the per-withdraw delay cannot be resolved.
     solana_cancel_window_secs: u64,
     /// Last processed Solana signature for cursor-based pagination
     last_solana_signature: Option<solana_sdk::signature::Signature>,
@@ -155,6 +197,9 @@ pub struct CancelerWatcher {
     /// Without this, EVM approvals that fail verification transiently are lost forever
     /// because the block pointer advances past the event.
     pending_retry_queue: BoundedMapCache<PendingApproval>,
+    /// Write-through copy of cursors, verdicts, retries and cancel attempts so restarts
+    /// resume where the previous run stopped (`CANCELER_STATE_PATH`).
+    state: StateStore,
 }
 
 impl CancelerWatcher {
@@ -296,6 +341,8 @@ impl CancelerWatcher {
             (None, None)
         };
 
+        verifier.register_token_cache(crate::token_cache::start(config).await?);
+
         let mut solana_cancel_window_secs = 300u64;
         if let Some(ref sc) = solana_client {
             match sc.read_bridge_withdraw_delay_secs() {
@@ -327,7 +374,7 @@ impl CancelerWatcher {
         // Build chain poll states from multi-EVM config.
         // The configured base EVM endpoint is polled via poll_evm_approvals();
         // other EVM peers are polled via poll_additional_evm_approvals().
-        let additional_evm_chains: Vec<EvmChainPollState> =
+        let mut additional_evm_chains: Vec<EvmChainPollState> =
             if let Some(ref multi) = config.multi_evm {
                 multi
                     .enabled_chains()
@@ -350,6 +397,50 @@ impl CancelerWatcher {
                 vec![]
             };
 
+        // Restore the previous run's position so nothing between the last poll and the restart
+        // is skipped (the lookback window only applies to a fresh state file).
+        let state = StateStore::open(&config.state_path)?;
+        for chain in &mut additional_evm_chains {
+            chain.last_block = state
+                .block_cursor(&evm_cursor_key(&chain.v2_chain_id))?
+                .unwrap_or(0);
+        }
+        let last_evm_block = state
+            .block_cursor(&evm_cursor_key(&this_chain_id))?
+            .unwrap_or(0);
+        let last_terra_height = state.block_cursor(TERRA_CURSOR)?.unwrap_or(0);
+        let last_solana_signature = state
+            .cursor(SOLANA_CURSOR)?
+            .and_then(|sig| solana_sdk::signature::Signature::from_str(&sig).ok());
+
+        let mut verified_hashes =
+            BoundedHashCache::new(config.dedupe_cache_max_size, config.dedupe_cache_ttl_secs);
+        let mut cancelled_hashes =
+            BoundedHashCache::new(config.dedupe_cache_max_size, config.dedupe_cache_ttl_secs);
+        for (hash, verdict) in
+            state.recent_verdicts(config.dedupe_cache_ttl_secs, config.dedupe_cache_max_size)?
+        {
+            match verdict {
+                Verdict::Valid => verified_hashes.insert(hash),
+                Verdict::Cancelled => cancelled_hashes.insert(hash),
+            }
+        }
+        let mut pending_retry_queue =
+            BoundedMapCache::new(config.pending_retry_max_size, config.pending_retry_ttl_secs);
+        for approval in state.load_pending(config.pending_retry_ttl_secs)? {
+            pending_retry_queue.insert(approval.xchain_hash_id, approval);
+        }
+        info!(
+            state_path = %config.state_path,
+            last_evm_block,
+            last_terra_height,
+            last_solana_signature = ?last_solana_signature,
+            verified = verified_hashes.len(),
+            cancelled = cancelled_hashes.len(),
+            pending_retries = pending_retry_queue.len(),
+            "Restored canceler state"
+        );
+
         info!(
             canceler_id = %config.canceler_id,
             evm_canceler = %evm_client.address(),
@@ -367,30 +458,30 @@ impl CancelerWatcher {
             solana_client,
             solana_chain_id,
             solana_cancel_window_secs,
-            last_solana_signature: None,
-            verified_hashes: BoundedHashCache::new(
-                config.dedupe_cache_max_size,
-                config.dedupe_cache_ttl_secs,
-            ),
-            cancelled_hashes: BoundedHashCache::new(
-                config.dedupe_cache_max_size,
-                config.dedupe_cache_ttl_secs,
-            ),
-            last_evm_block: 0,
-            last_terra_height: 0,
+            last_solana_signature,
+            verified_hashes,
+            cancelled_hashes,
+            last_evm_block,
+            last_terra_height,
             this_chain_id,
             additional_evm_chains,
             stats,
             metrics,
             evm_precheck_consecutive_failures: AtomicU32::new(0),
             evm_precheck_circuit_open: AtomicBool::new(false),
-            pending_retry_queue: BoundedMapCache::new(
-                config.pending_retry_max_size,
-                config.pending_retry_ttl_secs,
-            ),
+            pending_retry_queue,
+            state,
         })
     }
 
+    /// Log a failed state write. Never fatal: the in-memory state stays authoritative for
+    /// this run, only resume-after-restart is degraded.
+    fn persist(&self, what: &str, result: Result<()>) {
+        if let Err(e) = result {
+            warn!(error = %e, what, "Failed to persist canceler state");
+        }
+    }
+
     /// HTTP provider at the RPC endpoint that agrees with the multi-endpoint `eth_blockNumber` quorum.
     async fn evm_read_provider_and_head(
         urls: &[String],
@@ -707,6 +798,10 @@ impl CancelerWatcher {
 
             new_count += 1;
 
+            let Some(relay_fee) = read_relay_fee(&contract, &xchain_hash_id).await else {
+                continue;
+            };
+
             let mut token_bytes32 = [0u8; 32];
             token_bytes32[12..32].copy_from_slice(info.token.as_slice());
 
@@ -728,6 +823,7 @@ impl CancelerWatcher {
                 nonce: info.nonce,
                 approved_at_timestamp: info.approvedAt.try_into().unwrap_or(0),
                 cancel_window,
+                relay_fee,
             };
 
             if let Err(e) = self.verify_and_cancel(&approval).await {
@@ -794,6 +890,8 @@ impl CancelerWatcher {
             self.verified_hashes.clear();
             self.cancelled_hashes.clear();
             self.pending_retry_queue.clear();
+            self.persist("verdicts", self.state.clear_verdicts());
+            self.persist("pending retries", self.state.clear_pending());
         }
 
         if safe_head <= self.last_evm_block {
@@ -914,6 +1012,11 @@ impl CancelerWatcher {
                             })
                             .unwrap_or(300);
 
+                        let Some(relay_fee) = read_relay_fee(&contract, &xchain_hash_id).await
+                        else {
+                            continue;
+                        };
+
                         let mut token_bytes32 = [0u8; 32];
                         token_bytes32[12..32].copy_from_slice(info.token.as_slice());
 
@@ -935,6 +1038,7 @@ impl CancelerWatcher {
                             nonce: info.nonce,
                             approved_at_timestamp: info.approvedAt.try_into().unwrap_or(0),
                             cancel_window,
+                            relay_fee,
                         };
 
                         info!(
@@ -971,6 +1075,13 @@ impl CancelerWatcher {
         }
 
         self.last_evm_block = last_successful_block;
+        self.persist(
+            "evm cursor",
+            self.state.set_cursor(
+                &evm_cursor_key(&self.this_chain_id),
+                &last_successful_block.to_string(),
+            ),
+        );
 
         {
             let mut stats = self.stats.write().await;
@@ -1063,6 +1174,10 @@ impl CancelerWatcher {
 
                 new_count += 1;
 
+                let Some(relay_fee) = read_relay_fee(&contract, &xchain_hash_id).await else {
+                    continue;
+                };
+
                 let mut token_bytes32 = [0u8; 32];
                 token_bytes32[12..32].copy_from_slice(info.token.as_slice());
 
@@ -1077,6 +1192,7 @@ impl CancelerWatcher {
                     nonce: info.nonce,
                     approved_at_timestamp: info.approvedAt.try_into().unwrap_or(0),
                     cancel_window,
+                    relay_fee,
                 });
             }
 
@@ -1244,6 +1360,11 @@ impl CancelerWatcher {
                                 .map(|w| w._0.try_into().unwrap_or(300))
                                 .unwrap_or(300);
 
+                            let Some(relay_fee) = read_relay_fee(&contract, &xchain_hash_id).await
+                            else {
+                                continue;
+                            };
+
                             let mut token_bytes32 = [0u8; 32];
                             token_bytes32[12..32].copy_from_slice(info.token.as_slice());
 
@@ -1258,6 +1379,7 @@ impl CancelerWatcher {
                                 nonce: info.nonce,
                                 approved_at_timestamp: info.approvedAt.try_into().unwrap_or(0),
                                 cancel_window,
+                                relay_fee,
                             });
                         }
                         Err(e) => {
@@ -1275,6 +1397,11 @@ impl CancelerWatcher {
 
         for (idx, block) in block_updates {
             self.additional_evm_chains[idx].last_block = block;
+            let key = evm_cursor_key(&self.additional_evm_chains[idx].v2_chain_id);
+            self.persist(
+                "evm cursor",
+                self.state.set_cursor(&key, &block.to_string()),
+            );
         }
 
         for approval in collected_approvals {
@@ -1489,6 +1616,11 @@ impl CancelerWatcher {
                     .or_else(|| withdrawal_json["cancel_window"].as_u64())
                     .unwrap_or(300);
 
+                let Some(relay_fee) = self.read_terra_relay_fee(&client, &xchain_hash_id).await
+                else {
+                    continue;
+                };
+
                 let approval = PendingApproval {
                     xchain_hash_id,
                     src_chain_id,
@@ -1500,6 +1632,7 @@ impl CancelerWatcher {
                     nonce,
                     approved_at_timestamp,
                     cancel_window,
+                    relay_fee,
                 };
 
                 all_approvals.push(approval);
@@ -1542,6 +1675,11 @@ impl CancelerWatcher {
 
         // Update last polled height
         self.last_terra_height = current_height;
+        self.persist(
+            "terra cursor",
+            self.state
+                .set_cursor(TERRA_CURSOR, &current_height.to_string()),
+        );
 
         // Update stats
         {
@@ -1629,19 +1767,36 @@ impl CancelerWatcher {
             );
 
             let solana_ref = self.solana_client.as_ref().unwrap();
+            // An unknown fee must never pass as 0: stop here and re-read this event next poll
+            let relay_fee = match solana_ref.read_relay_fee(xchain_hash_id) {
+                Ok(fee) => fee,
+                Err(e) => {
+                    warn!(
+                        xchain_hash_id = %bytes32_to_hex(xchain_hash_id),
+                        error = %e,
+                        "Failed to read relay fee, will retry on next poll"
+                    );
+                    break;
+                }
+            };
             let approval = match solana_ref.read_pending_withdraw_full(xchain_hash_id) {
-                Ok(pw) => PendingApproval {
-                    xchain_hash_id: *xchain_hash_id,
-                    src_chain_id: pw.src_chain,
-                    dest_chain_id: solana_chain_id,
-                    src_account: pw.src_account,
-                    dest_account: pw.dest_account,
-                    dest_token: pw.token,
-                    amount: pw.amount,
-                    nonce: pw.nonce,
-                    approved_at_timestamp: pw.approved_at as u64,
-                    cancel_window: self.solana_cancel_window_secs,
-                },
+                Ok(pw) => {
+                    // Per-chain delay and large-transfer extension (see docs/SOLANA_BRIDGE_INVARIANTS.md INV-W6)
+                    let cancel_window = self.solana_effective_cancel_window(&pw).await;
+                    PendingApproval {
+                        xchain_hash_id: *xchain_hash_id,
+                        src_chain_id: pw.src_chain,
+                        dest_chain_id: solana_chain_id,
+                        src_account: pw.src_account,
+                        dest_account: pw.dest_account,
+                        dest_token: pw.token,
+                        amount: pw.amount,
+                        nonce: pw.nonce,
+                        approved_at_timestamp: pw.approved_at as u64,
+                        cancel_window,
+                        relay_fee,
+                    }
+                }
                 Err(e) => {
                     warn!(
                         xchain_hash_id = %bytes32_to_hex(xchain_hash_id),
@@ -1665,10 +1820,70 @@ impl CancelerWatcher {
         }
 
         self.last_solana_signature = last_sig;
+        if let Some(sig) = last_sig {
+            self.persist(
+                "solana cursor",
+                self.state.set_cursor(SOLANA_CURSOR, &sig.to_string()),
+            );
+        }
 
         Ok(())
     }
 
+    /// Relay fee of a Terra withdrawal (`WithdrawRelay` query; 0 if the recipient submitted).
+    ///
+    /// `None` when the query fails; as with the Solana relay fee read, the caller skips the
+    /// hash so the next poll reads it again.
+    async fn read_terra_relay_fee(
+        &self,
+        client: &reqwest::Client,
+        xchain_hash_id: &[u8; 32],
+    ) -> Option<u128> {
+        let query = serde_json::json!({
+            "withdraw_relay": {
+                "xchain_hash_id": base64::engine::general_purpose::STANDARD.encode(xchain_hash_id)
+            }
+        });
+        let query_b64 =
+            base64::engine::general_purpose::STANDARD.encode(serde_json::to_string(&query).ok()?);
+        let url = format!(
+            "{}/cosmwasm/wasm/v1/contract/{}/smart/{}",
+            self.config.terra_lcd_url, self.config.terra_bridge_address, query_b64
+        );
+
+        let result = async {
+            let resp = client.get(&url).send().await?.error_for_status()?;
+            resp.json::<serde_json::Value>().await
+        }
+        .await;
+        let json = match result {
+            Ok(json) => json,
+            Err(e) => {
+                warn!(
+                    error = %e,
+                    xchain_hash_id = %bytes32_to_hex(xchain_hash_id),
+                    "Failed to read Terra relay fee, will retry on next poll"
+                );
+                return None;
+            }
+        };
+
+        // WithdrawRelay returns Option<WithdrawRelayResponse>; null means not relayed
+        let data = &json["data"];
+        if data.is_null() {
+            return Some(0);
+        }
+        let fee = data["fee"].as_str().and_then(|v| v.parse::<u128>().ok());
+        if fee.is_none() {
+            warn!(
+                data = %data,
+                xchain_hash_id = %bytes32_to_hex(xchain_hash_id),
+                "Unexpected Terra relay fee response, will retry on next poll"
+            );
+        }
+        fee
+    }
+
     /// Helper to parse bytes4 from JSON (base64 encoded).
     /// Returns None if the value is missing, empty, or cannot be decoded.
     fn parse_bytes4_from_json(&self, value: &serde_json::Value) -> Option<[u8; 4]> {
@@ -1724,6 +1939,10 @@ impl CancelerWatcher {
         if self.cancelled_hashes.contains(&approval.xchain_hash_id) {
             return Ok(());
         }
+        // Verdicts evicted from the bounded caches (or recorded before a restart) are final too.
+        if let Ok(Some(_)) = self.state.verdict(&approval.xchain_hash_id) {
+            return Ok(());
+        }
 
         info!(
             hash = %bytes32_to_hex(&approval.xchain_hash_id),
@@ -1746,6 +1965,15 @@ impl CancelerWatcher {
                 self.pending_retry_queue.remove(&approval.xchain_hash_id);
                 self.verified_hashes.insert(approval.xchain_hash_id);
                 self.maybe_warn_dedupe_capacity("verified");
+                self.persist(
+                    "verdict",
+                    self.state
+                        .record_verdict(&approval.xchain_hash_id, Verdict::Valid, None),
+                );
+                self.persist(
+                    "pending retries",
+                    self.state.remove_pending(&approval.xchain_hash_id),
+                );
 
                 // Update stats and metrics
                 {
@@ -1778,9 +2006,44 @@ impl CancelerWatcher {
                         error = %e,
                         "Failed to submit cancellation"
                     );
+                    // Keep the approval queued (and persisted) so the cancel is retried next
+                    // cycle, including after a restart.
+                    let err = e.to_string();
+                    self.persist(
+                        "cancel attempt",
+                        self.state.record_cancel_attempt(
+                            &approval.xchain_hash_id,
+                            &approval.dest_chain_id,
+                            Some(&err),
+                        ),
+                    );
+                    self.pending_retry_queue
+                        .insert(approval.xchain_hash_id, approval.clone());
+                    self.persist("pending retries", self.state.put_pending(approval));
                 } else {
+                    self.pending_retry_queue.remove(&approval.xchain_hash_id);
                     self.cancelled_hashes.insert(approval.xchain_hash_id);
                     self.maybe_warn_dedupe_capacity("cancelled");
+                    self.persist(
+                        "cancel attempt",
+                        self.state.record_cancel_attempt(
+                            &approval.xchain_hash_id,
+                            &approval.dest_chain_id,
+                            None,
+                        ),
+                    );
+                    self.persist(
+                        "verdict",
+                        self.state.record_verdict(
+                            &approval.xchain_hash_id,
+                            Verdict::Cancelled,
+                            Some(reason.as_str()),
+                        ),
+                    );
+                    self.persist(
+                        "pending retries",
+                        self.state.remove_pending(&approval.xchain_hash_id),
+                    );
 
                     // Update cancelled count and metrics
                     {
@@ -1794,6 +2057,7 @@ impl CancelerWatcher {
                 self.pending_retry_queue
                     .insert(approval.xchain_hash_id, approval.clone());
                 self.maybe_warn_dedupe_capacity("pending_retry");
+                self.persist("pending retries", self.state.put_pending(approval));
                 debug!(
                     hash = %bytes32_to_hex(&approval.xchain_hash_id),
                     retry_queue_size = self.pending_retry_queue.len(),
@@ -1806,6 +2070,37 @@ impl CancelerWatcher {
     }
 
     /// Submit cancel transaction to the appropriate chain (C4: EVM pre-check safety)
+    /// Effective cancel window for a Solana withdraw, retried on RPC errors. If it still cannot be
+    /// read, assume the largest window the program allows: a too-short window would skip
+    /// verification of a withdraw that can still be cancelled.
+    async fn solana_effective_cancel_window(&self, pw: &PendingWithdrawData) -> u64 {
+        let Some(solana_client) = self.solana_client.as_ref() else {
+            return self.solana_cancel_window_secs;
+        };
+        for attempt in 1..=SOLANA_DELAY_READ_ATTEMPTS {
+            match solana_client.read_effective_withdraw_delay_secs(pw) {
+                Ok(secs) => return secs,
+                Err(e) => {
+                    warn!(
+                        error = %e,
+                        attempt,
+                        "Failed to resolve Solana withdraw delay"
+                    );
+                    if attempt < SOLANA_DELAY_READ_ATTEMPTS {
+                        tokio::time::sleep(Duration::from_millis(500 * 2u64.pow(attempt - 1)))
+                            .await;
+                    }
+                }
+            }
+        }
+        let fallback = MAX_LARGE_TRANSFER_DELAY_SECS.max(self.solana_cancel_window_secs);
+        warn!(
+            cancel_window_secs = fallback,
+            "Using the largest Solana withdraw delay as cancel window"
+        );
+        fallback
+    }
+
     async fn submit_cancel(&self, approval: &PendingApproval) -> Result<()> {
         let xchain_hash_id = approval.xchain_hash_id;
         let dest_chain = approval.dest_chain_id;
@@ -2063,6 +2358,7 @@ mod tests {
             nonce,
             approved_at_timestamp: approved_at,
             cancel_window: 300,
+            relay_fee: 0,
         };
 
         let mut approvals = vec![
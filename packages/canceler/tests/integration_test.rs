@@ -498,6 +498,7 @@ mod pending_approval_tests {
             nonce,
             approved_at_timestamp: 1700000000,
             cancel_window: 300,
+            relay_fee: 0,
         };
 
         // Verify key fields
@@ -595,6 +596,7 @@ mod pending_approval_tests {
             nonce: withdrawal_json["nonce"].as_u64().unwrap_or(0),
             approved_at_timestamp: withdrawal_json["approved_at"].as_u64().unwrap_or(0),
             cancel_window: withdrawal_json["cancel_window"].as_u64().unwrap_or(300),
+            relay_fee: 0,
         };
 
         // Bug fix verification: src_account must NOT be all zeros
@@ -758,6 +760,7 @@ mod pending_approval_tests {
             nonce,
             approved_at_timestamp: 0,
             cancel_window: 300,
+            relay_fee: 0,
         };
 
         // Recompute and verify
@@ -50,6 +50,9 @@ contract Bridge is Initializable, UUPSUpgradeable, OwnableUpgradeable, PausableU
     /// @notice Maximum cancel window (24 hours, matching TerraClassic)
     uint256 public constant MAX_CANCEL_WINDOW = 24 hours;
 
+    /// @notice Maximum relay fee cap in basis points (5%)
+    uint256 public constant MAX_RELAY_FEE_BPS = 500;
+
     // ============================================================================
     // Storage - Registries
     // ============================================================================
@@ -117,8 +120,17 @@ contract Bridge is Initializable, UUPSUpgradeable, OwnableUpgradeable, PausableU
     /// @notice Set of pending (submitted but not yet executed/cancelled) withdrawal hashes
     EnumerableSet.Bytes32Set private _pendingWithdrawHashes;
 
+    /// @notice Max relay fee a depositor accepts, by deposit hash (0 = relay not requested)
+    mapping(bytes32 => uint256) public relayRequests;
+
+    /// @notice Relayer and relay fee (source decimals) of withdrawals submitted by an operator
+    mapping(bytes32 => WithdrawRelay) public withdrawRelays;
+
+    /// @notice Cap on relay fees as basis points of the withdrawn amount (0 = relaying disabled)
+    uint256 public relayFeeCapBps;
+
     /// @notice Reserved storage slots for future upgrades
-    uint256[34] private __gap;
+    uint256[31] private __gap;
 
     // ============================================================================
     // Modifiers
@@ -242,6 +254,15 @@ contract Bridge is Initializable, UUPSUpgradeable, OwnableUpgradeable, PausableU
         emit GuardBridgeUpdated(oldGuard, _guardBridge);
     }
 
+    /// @notice Set the relay fee cap for operator-submitted withdrawals
+    /// @param _relayFeeCapBps The cap in basis points of the withdrawn amount (0 disables relaying)
+    function setRelayFeeCap(uint256 _relayFeeCapBps) external onlyOwner {
+        if (_relayFeeCapBps > MAX_RELAY_FEE_BPS) revert FeeExceedsMax(_relayFeeCapBps, MAX_RELAY_FEE_BPS);
+        uint256 oldCap = relayFeeCapBps;
+        relayFeeCapBps = _relayFeeCapBps;
+        emit RelayFeeCapUpdated(oldCap, _relayFeeCapBps);
+    }
+
     /// @notice Recover stuck assets (admin only, when paused)
     /// @param token Token address (address(0) for native ETH)
     /// @param amount Amount to recover
@@ -609,6 +630,23 @@ contract Bridge is Initializable, UUPSUpgradeable, OwnableUpgradeable, PausableU
     // Withdraw Methods
     // ============================================================================
 
+    /// @notice Depositor asks operators to submit the withdrawal on the destination chain
+    /// @param xchainHashId Hash of a deposit made on this chain by msg.sender
+    /// @param maxRelayFee Highest relay fee (in this deposit's amount units) the depositor accepts
+    /// @dev The operator deducts its fee from the bridged amount, so the recipient needs no gas.
+    /// A request cannot be changed once made, since an operator may already have acted on it.
+    function requestRelay(bytes32 xchainHashId, uint256 maxRelayFee) external whenNotPaused {
+        DepositRecord storage d = deposits[xchainHashId];
+        if (d.timestamp == 0) revert DepositNotFound(xchainHashId);
+        if (d.srcAccount != HashLib.addressToBytes32(msg.sender)) revert Unauthorized();
+        if (maxRelayFee == 0 || maxRelayFee >= d.amount) revert InvalidAmount(maxRelayFee);
+        if (relayRequests[xchainHashId] != 0) revert RelayAlreadyRequested(xchainHashId);
+
+        relayRequests[xchainHashId] = maxRelayFee;
+
+        emit RelayRequested(xchainHashId, d.destChain, maxRelayFee);
+    }
+
     /// @notice User submits a withdrawal request
     /// @param srcChain Source chain ID where the deposit was made
     /// @param srcAccount Source account (depositor) encoded as bytes32
@@ -616,6 +654,8 @@ contract Bridge is Initializable, UUPSUpgradeable, OwnableUpgradeable, PausableU
     /// @param token Token address on this chain
     /// @param amount Amount to withdraw
     /// @param nonce Deposit nonce from source chain
+    /// @dev If an operator already submitted the withdrawal relayed, the recipient's own submit
+    /// drops the relay instead, so the recipient never pays a fee it can submit without.
     function withdrawSubmit(
         bytes4 srcChain,
         bytes32 srcAccount,
@@ -624,6 +664,77 @@ contract Bridge is Initializable, UUPSUpgradeable, OwnableUpgradeable, PausableU
         uint256 amount,
         uint64 nonce
     ) external payable whenNotPaused nonReentrant {
+        bytes32 xchainHashId = HashLib.computeXchainHashId(
+            srcChain, thisChainId, srcAccount, destAccount, HashLib.addressToBytes32(token), amount, nonce
+        );
+        if (withdrawRelays[xchainHashId].relayer != address(0)) {
+            _dropRelay(xchainHashId);
+            return;
+        }
+        _withdrawSubmit(srcChain, srcAccount, destAccount, token, amount, nonce, msg.value);
+    }
+
+    /// @notice Operator submits a withdrawal on behalf of a recipient whose depositor requested relaying
+    /// @param srcChain Source chain ID where the deposit was made
+    /// @param srcAccount Source account (depositor) encoded as bytes32
+    /// @param destAccount Destination account (recipient) encoded as bytes32
+    /// @param token Token address on this chain
+    /// @param amount Amount to withdraw (in source chain decimals)
+    /// @param nonce Deposit nonce from source chain
+    /// @param relayFee Fee (in source chain decimals) paid to the operator out of the amount on execution
+    /// @dev The fee is bounded by `relayFeeCapBps`, and a cap of 0 rejects every relay, even a free one.
+    /// Whether the depositor actually requested the relay (`RelayRequested` on the source chain) is
+    /// checked off-chain by operators and cancelers. The recipient can drop the relay, and its fee,
+    /// by calling `withdrawSubmit` until the withdrawal executes.
+    function withdrawSubmitRelayed(
+        bytes4 srcChain,
+        bytes32 srcAccount,
+        bytes32 destAccount,
+        address token,
+        uint256 amount,
+        uint64 nonce,
+        uint256 relayFee
+    ) external onlyOperator whenNotPaused nonReentrant {
+        if (relayFeeCapBps == 0) revert RelayingDisabled();
+        if (relayFee * FeeCalculatorLib.BPS_DENOMINATOR > amount * relayFeeCapBps) {
+            revert RelayFeeTooHigh(relayFee, amount * relayFeeCapBps / FeeCalculatorLib.BPS_DENOMINATOR);
+        }
+
+        bytes32 xchainHashId = _withdrawSubmit(srcChain, srcAccount, destAccount, token, amount, nonce, 0);
+        withdrawRelays[xchainHashId] = WithdrawRelay({relayer: msg.sender, fee: relayFee});
+
+        emit WithdrawRelayed(xchainHashId, msg.sender, relayFee);
+    }
+
+    /// @notice Recipient takes over a relayed withdrawal, dropping the operator's relay fee
+    /// @param xchainHashId The withdrawal hash
+    /// @dev The withdrawal keeps its state, so a relay cancelled for an unauthorized fee can be
+    /// uncancelled without it. A tip sent before approval is forwarded to the approving operator.
+    function _dropRelay(bytes32 xchainHashId) internal {
+        PendingWithdraw storage w = pendingWithdraws[xchainHashId];
+        if (msg.sender != w.recipient) revert Unauthorized();
+        if (w.executed) revert WithdrawAlreadyExecuted(xchainHashId);
+        if (w.approved && msg.value > 0) revert InvalidAmount(msg.value);
+
+        address relayer = withdrawRelays[xchainHashId].relayer;
+        delete withdrawRelays[xchainHashId];
+        w.operatorGas += msg.value;
+
+        emit RelayDropped(xchainHashId, relayer);
+    }
+
+    /// @notice Record a pending withdrawal
+    /// @param operatorGas Native tip forwarded to the approving operator
+    /// @return xchainHashId The withdrawal hash
+    function _withdrawSubmit(
+        bytes4 srcChain,
+        bytes32 srcAccount,
+        bytes32 destAccount,
+        address token,
+        uint256 amount,
+        uint64 nonce,
+        uint256 operatorGas
+    ) internal returns (bytes32 xchainHashId) {
         if (amount == 0) revert InvalidAmount(0);
         if (srcChain == thisChainId) revert SameChainTransfer(srcChain);
         if (!chainRegistry.isChainRegistered(srcChain)) revert ChainNotRegistered(srcChain);
@@ -638,7 +749,7 @@ contract Bridge is Initializable, UUPSUpgradeable, OwnableUpgradeable, PausableU
         uint8 srcDecimals = tokenRegistry.getSrcTokenDecimals(srcChain, token);
 
         // Compute unified cross-chain hash ID (same hash as deposit on source chain)
-        bytes32 xchainHashId = HashLib.computeXchainHashId(
+        xchainHashId = HashLib.computeXchainHashId(
             srcChain, thisChainId, srcAccount, destAccount, HashLib.addressToBytes32(token), amount, nonce
         );
 
@@ -664,7 +775,7 @@ contract Bridge is Initializable, UUPSUpgradeable, OwnableUpgradeable, PausableU
             nonce: nonce,
             srcDecimals: srcDecimals,
             destDecimals: localDecimals,
-            operatorGas: msg.value,
+            operatorGas: operatorGas,
             submittedAt: block.timestamp,
             approvedAt: 0,
             approved: false,
@@ -674,7 +785,7 @@ contract Bridge is Initializable, UUPSUpgradeable, OwnableUpgradeable, PausableU
 
         _pendingWithdrawHashes.add(xchainHashId);
 
-        emit WithdrawSubmit(xchainHashId, srcChain, srcAccount, destAccount, token, amount, nonce, msg.value);
+        emit WithdrawSubmit(xchainHashId, srcChain, srcAccount, destAccount, token, amount, nonce, operatorGas);
     }
 
     /// @notice Operator approves a pending withdrawal
@@ -766,10 +877,15 @@ contract Bridge is Initializable, UUPSUpgradeable, OwnableUpgradeable, PausableU
         w.executed = true;
         _pendingWithdrawHashes.remove(xchainHashId);
 
-        // Unlock tokens to recipient
-        lockUnlock.unlock(w.recipient, w.token, normalizedAmount);
+        // Unlock tokens to recipient, less any relay fee owed to the submitting operator
+        (address relayer, uint256 relayFee) = _relayFee(w, xchainHashId);
+        lockUnlock.unlock(w.recipient, w.token, normalizedAmount - relayFee);
+        if (relayFee > 0) {
+            lockUnlock.unlock(relayer, w.token, relayFee);
+            emit RelayFeePaid(xchainHashId, relayer, relayFee);
+        }
 
-        emit WithdrawExecute(xchainHashId, w.recipient, normalizedAmount);
+        emit WithdrawExecute(xchainHashId, w.recipient, normalizedAmount - relayFee);
     }
 
     /// @notice Execute an approved withdrawal (mint mode)
@@ -794,10 +910,15 @@ contract Bridge is Initializable, UUPSUpgradeable, OwnableUpgradeable, PausableU
         w.executed = true;
         _pendingWithdrawHashes.remove(xchainHashId);
 
-        // Mint tokens to recipient
-        mintBurn.mint(w.recipient, w.token, normalizedAmount);
+        // Mint tokens to recipient, less any relay fee owed to the submitting operator
+        (address relayer, uint256 relayFee) = _relayFee(w, xchainHashId);
+        mintBurn.mint(w.recipient, w.token, normalizedAmount - relayFee);
+        if (relayFee > 0) {
+            mintBurn.mint(relayer, w.token, relayFee);
+            emit RelayFeePaid(xchainHashId, relayer, relayFee);
+        }
 
-        emit WithdrawExecute(xchainHashId, w.recipient, normalizedAmount);
+        emit WithdrawExecute(xchainHashId, w.recipient, normalizedAmount - relayFee);
     }
 
     /// @notice Internal validation for withdrawal execution
@@ -815,6 +936,21 @@ contract Bridge is Initializable, UUPSUpgradeable, OwnableUpgradeable, PausableU
         if (block.timestamp <= windowEnd) revert CancelWindowActive(windowEnd);
     }
 
+    /// @notice Relay fee owed on a withdrawal, in local decimals
+    /// @param w Pending withdrawal storage reference
+    /// @param xchainHashId The withdrawal hash
+    /// @return relayer Operator that submitted the withdrawal (address(0) if not relayed)
+    /// @return fee Fee to pay the relayer out of the withdrawn amount
+    function _relayFee(PendingWithdraw storage w, bytes32 xchainHashId)
+        internal
+        view
+        returns (address relayer, uint256 fee)
+    {
+        WithdrawRelay storage r = withdrawRelays[xchainHashId];
+        if (r.fee == 0) return (r.relayer, 0);
+        return (r.relayer, _normalizeDecimals(r.fee, w.srcDecimals, w.destDecimals));
+    }
+
     /// @notice Normalize amount between different decimal precisions
     /// @param amount Amount in source decimals
     /// @param srcDecimals Source chain token decimals
@@ -900,6 +1036,13 @@ contract Bridge is Initializable, UUPSUpgradeable, OwnableUpgradeable, PausableU
         return deposits[xchainHashId];
     }
 
+    /// @notice Get the relayer and relay fee of an operator-submitted withdrawal
+    /// @param xchainHashId The withdrawal hash
+    /// @return relay The relayer and fee (zero values if the user submitted it)
+    function getWithdrawRelay(bytes32 xchainHashId) external view returns (WithdrawRelay memory relay) {
+        return withdrawRelays[xchainHashId];
+    }
+
     /// @notice Get all pending (submitted but not yet executed/cancelled) withdrawal hashes
     /// @return hashes Array of pending withdrawal hashes
     function getPendingWithdrawHashes() external view returns (bytes32[] memory hashes) {
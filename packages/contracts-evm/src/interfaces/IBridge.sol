@@ -39,6 +39,12 @@ interface IBridge {
         uint256 timestamp;
     }
 
+    /// @notice Operator that submitted a withdrawal on the recipient's behalf
+    struct WithdrawRelay {
+        address relayer;
+        uint256 fee;
+    }
+
     // ============================================================================
     // Events
     // ============================================================================
@@ -101,6 +107,21 @@ interface IBridge {
     /// @notice Emitted when the guard bridge is updated
     event GuardBridgeUpdated(address indexed oldGuard, address indexed newGuard);
 
+    /// @notice Emitted when a depositor asks operators to relay the withdrawal
+    event RelayRequested(bytes32 indexed xchainHashId, bytes4 indexed destChain, uint256 maxRelayFee);
+
+    /// @notice Emitted when an operator submits a withdrawal on the recipient's behalf
+    event WithdrawRelayed(bytes32 indexed xchainHashId, address indexed relayer, uint256 relayFee);
+
+    /// @notice Emitted when the recipient's own submit replaces an operator's relayed submit
+    event RelayDropped(bytes32 indexed xchainHashId, address indexed relayer);
+
+    /// @notice Emitted when a relayed withdrawal pays its relay fee
+    event RelayFeePaid(bytes32 indexed xchainHashId, address indexed relayer, uint256 amount);
+
+    /// @notice Emitted when the relay fee cap is updated
+    event RelayFeeCapUpdated(uint256 oldCapBps, uint256 newCapBps);
+
     // ============================================================================
     // Errors
     // ============================================================================
@@ -129,6 +150,10 @@ interface IBridge {
     error WrongTokenType(address token, string expected);
     error RecoveryTransferFailed();
     error WithdrawNonceAlreadyUsed(bytes4 srcChain, uint64 nonce);
+    error DepositNotFound(bytes32 hash);
+    error RelayAlreadyRequested(bytes32 hash);
+    error RelayFeeTooHigh(uint256 fee, uint256 max);
+    error RelayingDisabled();
 
     // ============================================================================
     // Deposit Methods
@@ -173,6 +198,29 @@ interface IBridge {
         uint64 nonce
     ) external payable;
 
+    /// @notice Depositor asks operators to submit the withdrawal on the destination chain
+    /// @param xchainHashId Hash of a deposit made on this chain by the caller
+    /// @param maxRelayFee Highest relay fee (in the deposit's amount units) the depositor accepts
+    function requestRelay(bytes32 xchainHashId, uint256 maxRelayFee) external;
+
+    /// @notice Operator submits a withdrawal on behalf of a recipient whose depositor requested relaying
+    /// @param srcChain Source chain ID
+    /// @param srcAccount Source account (depositor) encoded as bytes32
+    /// @param destAccount Destination account (recipient) encoded as bytes32
+    /// @param token Token address on this chain
+    /// @param amount Amount to withdraw (in source chain decimals)
+    /// @param nonce Deposit nonce from source chain
+    /// @param relayFee Fee (in source chain decimals) paid to the operator out of the amount on execution
+    function withdrawSubmitRelayed(
+        bytes4 srcChain,
+        bytes32 srcAccount,
+        bytes32 destAccount,
+        address token,
+        uint256 amount,
+        uint64 nonce,
+        uint256 relayFee
+    ) external;
+
     /// @notice Operator approves a pending withdrawal
     /// @param xchainHashId The withdrawal hash
     function withdrawApprove(bytes32 xchainHashId) external;
@@ -252,4 +300,9 @@ interface IBridge {
     /// @notice Get this chain's registered chain ID
     /// @return chainId This chain's 4-byte ID
     function getThisChainId() external view returns (bytes4 chainId);
+
+    /// @notice Get the relayer and relay fee of an operator-submitted withdrawal
+    /// @param xchainHashId The withdrawal hash
+    /// @return relay The relayer and fee (zero values if the user submitted it)
+    function getWithdrawRelay(bytes32 xchainHashId) external view returns (WithdrawRelay memory relay);
 }
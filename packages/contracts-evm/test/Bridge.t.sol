@@ -1392,6 +1392,225 @@ contract BridgeTest is Test {
         bridge.setGuardBridge(address(0x1));
     }
 
+    // ============================================================================
+    // Relay Tests
+    // ============================================================================
+
+    function _depositForRelay() internal returns (bytes32 depositHash) {
+        vm.startPrank(user);
+        token.approve(address(bridge), 100 ether);
+        bridge.depositERC20(address(token), 100 ether, destChainId, destAccount);
+        vm.stopPrank();
+
+        depositHash = keccak256(
+            abi.encode(
+                bytes32(thisChainId),
+                bytes32(destChainId),
+                bytes32(uint256(uint160(user))),
+                destAccount,
+                bytes32(uint256(1)),
+                uint256(99.5 ether),
+                uint256(1)
+            )
+        );
+    }
+
+    function _submitRelayed(uint256 relayFee) internal returns (bytes32 xchainHashId, address recipient) {
+        recipient = address(6);
+        bytes32 srcAccount = bytes32(uint256(uint160(address(0x7777))));
+        bytes32 recipientAccount = bytes32(uint256(uint160(recipient)));
+
+        vm.prank(operator);
+        bridge.withdrawSubmitRelayed(destChainId, srcAccount, recipientAccount, address(token), 50 ether, 1, relayFee);
+
+        xchainHashId = _computeXchainHashId(destChainId, srcAccount, recipientAccount, address(token), 50 ether, 1);
+    }
+
+    function test_RequestRelay() public {
+        bytes32 depositHash = _depositForRelay();
+
+        vm.prank(user);
+        vm.expectEmit(true, true, true, true);
+        emit IBridge.RelayRequested(depositHash, destChainId, 1 ether);
+        bridge.requestRelay(depositHash, 1 ether);
+
+        assertEq(bridge.relayRequests(depositHash), 1 ether);
+    }
+
+    function test_RequestRelay_RevertsIfNotDepositor() public {
+        bytes32 depositHash = _depositForRelay();
+
+        vm.prank(address(6));
+        vm.expectRevert(IBridge.Unauthorized.selector);
+        bridge.requestRelay(depositHash, 1 ether);
+    }
+
+    function test_RequestRelay_RevertsIfUnknownDeposit() public {
+        vm.prank(user);
+        vm.expectRevert(abi.encodeWithSelector(IBridge.DepositNotFound.selector, bytes32(uint256(42))));
+        bridge.requestRelay(bytes32(uint256(42)), 1 ether);
+    }
+
+    function test_RequestRelay_RevertsIfAlreadyRequested() public {
+        bytes32 depositHash = _depositForRelay();
+
+        vm.startPrank(user);
+        bridge.requestRelay(depositHash, 1 ether);
+        vm.expectRevert(abi.encodeWithSelector(IBridge.RelayAlreadyRequested.selector, depositHash));
+        bridge.requestRelay(depositHash, 2 ether);
+        vm.stopPrank();
+    }
+
+    function test_SetRelayFeeCap_RevertsAboveMax() public {
+        vm.prank(admin);
+        vm.expectRevert(abi.encodeWithSelector(IBridge.FeeExceedsMax.selector, 501, 500));
+        bridge.setRelayFeeCap(501);
+    }
+
+    function test_WithdrawSubmitRelayed_RevertsIfNotOperator() public {
+        vm.prank(admin);
+        bridge.setRelayFeeCap(100);
+
+        vm.prank(user);
+        vm.expectRevert(IBridge.Unauthorized.selector);
+        bridge.withdrawSubmitRelayed(destChainId, bytes32(uint256(1)), destAccount, address(token), 50 ether, 1, 0);
+    }
+
+    function test_WithdrawSubmitRelayed_RevertsAboveCap() public {
+        vm.prank(admin);
+        bridge.setRelayFeeCap(100); // 1% of 50 ether = 0.5 ether
+
+        vm.prank(operator);
+        vm.expectRevert(abi.encodeWithSelector(IBridge.RelayFeeTooHigh.selector, 0.6 ether, 0.5 ether));
+        bridge.withdrawSubmitRelayed(
+            destChainId, bytes32(uint256(1)), destAccount, address(token), 50 ether, 1, 0.6 ether
+        );
+    }
+
+    function test_WithdrawSubmitRelayed_RevertsWhenRelayingDisabled() public {
+        vm.startPrank(operator);
+        vm.expectRevert(IBridge.RelayingDisabled.selector);
+        bridge.withdrawSubmitRelayed(destChainId, bytes32(uint256(1)), destAccount, address(token), 50 ether, 1, 1);
+
+        // A zero fee is no loophole
+        vm.expectRevert(IBridge.RelayingDisabled.selector);
+        bridge.withdrawSubmitRelayed(destChainId, bytes32(uint256(1)), destAccount, address(token), 50 ether, 1, 0);
+        vm.stopPrank();
+    }
+
+    function test_WithdrawExecuteUnlock_PaysRelayFee() public {
+        _depositForRelay(); // funds LockUnlock
+        vm.prank(admin);
+        bridge.setRelayFeeCap(100);
+
+        (bytes32 xchainHashId, address recipient) = _submitRelayed(0.5 ether);
+        IBridge.WithdrawRelay memory relay = bridge.getWithdrawRelay(xchainHashId);
+        assertEq(relay.relayer, operator);
+        assertEq(relay.fee, 0.5 ether);
+        assertEq(bridge.getPendingWithdraw(xchainHashId).operatorGas, 0);
+
+        vm.prank(operator);
+        bridge.withdrawApprove(xchainHashId);
+        vm.warp(block.timestamp + 6 minutes);
+
+        vm.expectEmit(true, true, true, true);
+        emit IBridge.RelayFeePaid(xchainHashId, operator, 0.5 ether);
+        bridge.withdrawExecuteUnlock(xchainHashId);
+
+        assertEq(token.balanceOf(recipient), 49.5 ether);
+        assertEq(token.balanceOf(operator), 0.5 ether);
+    }
+
+    function test_WithdrawExecuteMint_PaysRelayFee() public {
+        vm.prank(admin);
+        bridge.setRelayFeeCap(100);
+
+        address recipient = address(6);
+        bytes32 srcAccount = bytes32(uint256(uint160(address(0x7777))));
+        bytes32 recipientAccount = bytes32(uint256(uint160(recipient)));
+        vm.prank(operator);
+        bridge.withdrawSubmitRelayed(
+            destChainId, srcAccount, recipientAccount, address(mintableToken), 50 ether, 1, 0.25 ether
+        );
+        bytes32 xchainHashId =
+            _computeXchainHashId(destChainId, srcAccount, recipientAccount, address(mintableToken), 50 ether, 1);
+
+        vm.prank(operator);
+        bridge.withdrawApprove(xchainHashId);
+        vm.warp(block.timestamp + 6 minutes);
+        bridge.withdrawExecuteMint(xchainHashId);
+
+        assertEq(mintableToken.balanceOf(recipient), 49.75 ether);
+        assertEq(mintableToken.balanceOf(operator), 0.25 ether);
+    }
+
+    function test_WithdrawSubmit_RecipientDropsFrontRunRelay() public {
+        _depositForRelay(); // funds LockUnlock
+        vm.prank(admin);
+        bridge.setRelayFeeCap(100);
+
+        // The operator front-runs the recipient with a fee-bearing relayed submit
+        (bytes32 xchainHashId, address recipient) = _submitRelayed(0.5 ether);
+        bytes32 srcAccount = bytes32(uint256(uint160(address(0x7777))));
+        bytes32 recipientAccount = bytes32(uint256(uint160(recipient)));
+
+        vm.prank(recipient);
+        vm.expectEmit(true, true, true, true);
+        emit IBridge.RelayDropped(xchainHashId, operator);
+        bridge.withdrawSubmit(destChainId, srcAccount, recipientAccount, address(token), 50 ether, 1);
+        assertEq(bridge.getWithdrawRelay(xchainHashId).relayer, address(0));
+        assertEq(bridge.getWithdrawRelay(xchainHashId).fee, 0);
+
+        vm.prank(operator);
+        bridge.withdrawApprove(xchainHashId);
+        vm.warp(block.timestamp + 6 minutes);
+        bridge.withdrawExecuteUnlock(xchainHashId);
+
+        assertEq(token.balanceOf(recipient), 50 ether);
+        assertEq(token.balanceOf(operator), 0);
+    }
+
+    function test_WithdrawSubmit_RecipientDropsCancelledRelay() public {
+        _depositForRelay(); // funds LockUnlock
+        vm.prank(admin);
+        bridge.setRelayFeeCap(100);
+
+        (bytes32 xchainHashId, address recipient) = _submitRelayed(0.5 ether);
+        bytes32 srcAccount = bytes32(uint256(uint160(address(0x7777))));
+        bytes32 recipientAccount = bytes32(uint256(uint160(recipient)));
+
+        // Approved before the recipient could act, then cancelled for the unauthorized fee
+        vm.prank(operator);
+        bridge.withdrawApprove(xchainHashId);
+        vm.prank(canceler);
+        bridge.withdrawCancel(xchainHashId);
+
+        vm.prank(recipient);
+        bridge.withdrawSubmit(destChainId, srcAccount, recipientAccount, address(token), 50 ether, 1);
+        assertTrue(bridge.getPendingWithdraw(xchainHashId).cancelled);
+
+        vm.prank(operator);
+        bridge.withdrawUncancel(xchainHashId);
+        vm.warp(block.timestamp + 6 minutes);
+        bridge.withdrawExecuteUnlock(xchainHashId);
+
+        assertEq(token.balanceOf(recipient), 50 ether);
+        assertEq(token.balanceOf(operator), 0);
+    }
+
+    function test_WithdrawSubmit_OnlyRecipientDropsRelay() public {
+        vm.prank(admin);
+        bridge.setRelayFeeCap(100);
+
+        (, address recipient) = _submitRelayed(0.5 ether);
+        bytes32 srcAccount = bytes32(uint256(uint160(address(0x7777))));
+        bytes32 recipientAccount = bytes32(uint256(uint160(recipient)));
+
+        vm.prank(user);
+        vm.expectRevert(IBridge.Unauthorized.selector);
+        bridge.withdrawSubmit(destChainId, srcAccount, recipientAccount, address(token), 50 ether, 1);
+    }
+
     // ============================================================================
     // Helper Functions
     // ============================================================================
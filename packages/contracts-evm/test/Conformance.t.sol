@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+pragma solidity ^0.8.30;
+
+import {Test} from "forge-std/Test.sol";
+import {stdJson} from "forge-std/StdJson.sol";
+import {HashLib} from "../src/lib/HashLib.sol";
+import {AddressCodecLib} from "../src/lib/AddressCodecLib.sol";
+
+/// @title Conformance Corpus Tests
+/// @notice Checks HashLib and AddressCodecLib against the shared corpus in
+///         `packages/multichain-rs/conformance/vectors.json`
+/// @dev Regenerate the corpus with `cargo run --features conformance --bin cl8y-conformance -- generate`
+///      from `packages/multichain-rs`; never edit it by hand.
+contract ConformanceTest is Test {
+    using stdJson for string;
+
+    /// @notice Corpus format this suite understands (`multichain_rs::conformance::CORPUS_VERSION`)
+    uint256 internal constant CORPUS_VERSION = 1;
+
+    string internal json;
+
+    function setUp() public {
+        json = vm.readFile(string.concat(vm.projectRoot(), "/../multichain-rs/conformance/vectors.json"));
+        assertEq(json.readUint(".version"), CORPUS_VERSION, "unsupported corpus version");
+    }
+
+    function test_XchainHashVectors() public view {
+        uint256 count;
+        for (uint256 i;; ++i) {
+            string memory base = string.concat(".xchain_hash[", vm.toString(i), "]");
+            if (!json.keyExists(base)) break;
+
+            bytes32 h = HashLib.computeXchainHashId(
+                bytes4(json.readBytes(string.concat(base, ".src_chain"))),
+                bytes4(json.readBytes(string.concat(base, ".dest_chain"))),
+                json.readBytes32(string.concat(base, ".src_account")),
+                json.readBytes32(string.concat(base, ".dest_account")),
+                json.readBytes32(string.concat(base, ".token")),
+                json.readUint(string.concat(base, ".amount")),
+                // forge-lint: disable-next-line(unsafe-typecast)
+                uint64(json.readUint(string.concat(base, ".nonce")))
+            );
+            assertEq(
+                h,
+                json.readBytes32(string.concat(base, ".xchain_hash_id")),
+                json.readString(string.concat(base, ".name"))
+            );
+            ++count;
+        }
+        assertGt(count, 0, "empty xchain_hash corpus");
+    }
+
+    function test_UniversalAddressVectors() public view {
+        uint256 count;
+        for (uint256 i;; ++i) {
+            string memory base = string.concat(".universal_address[", vm.toString(i), "]");
+            if (!json.keyExists(base)) break;
+
+            string memory name = json.readString(string.concat(base, ".name"));
+            // forge-lint: disable-next-line(unsafe-typecast)
+            uint32 chainType = uint32(json.readUint(string.concat(base, ".chain_type")));
+            bytes memory raw = json.readBytes(string.concat(base, ".raw"));
+            bytes32 hashBytes = json.readBytes32(string.concat(base, ".hash_bytes"));
+
+            if (raw.length == 20) {
+                bytes8 reserved = bytes8(json.readBytes(string.concat(base, ".reserved")));
+                bytes32 encoded = AddressCodecLib.encodeWithReserved(chainType, bytes20(raw), reserved);
+                assertEq(encoded, json.readBytes32(string.concat(base, ".bytes32")), name);
+
+                (uint32 decodedType, bytes20 decodedRaw, bytes8 decodedReserved) = AddressCodecLib.decode(encoded);
+                assertEq(decodedType, chainType, name);
+                assertEq(decodedRaw, bytes20(raw), name);
+                assertEq(decodedReserved, reserved, name);
+
+                // 20-byte accounts are left-padded in hash inputs
+                assertEq(bytes32(uint256(uint160(bytes20(raw)))), hashBytes, name);
+            } else if (chainType == AddressCodecLib.CHAIN_TYPE_SOLANA) {
+                assertEq(AddressCodecLib.encodeSolana(bytes32(raw)), hashBytes, name);
+            }
+            // 32-byte Bitcoin programs (P2WSH / P2TR) have no on-chain codec on EVM
+            ++count;
+        }
+        assertGt(count, 0, "empty universal_address corpus");
+    }
+}
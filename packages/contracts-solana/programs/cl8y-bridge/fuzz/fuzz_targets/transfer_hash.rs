@@ -1,3 +1,7 @@
+//! Input layout: src_chain (4) | dest_chain (4) | src_account | dest_account | token (32 each) |
+//! amount (u128 BE) | nonce (u64 BE). Seed from the shared conformance corpus with
+//! `cl8y-conformance fuzz-seeds fuzz/corpus/transfer_hash` (see `multichain_rs::conformance`).
+
 #![no_main]
 
 use cl8y_bridge::hash::compute_transfer_hash;
@@ -1,6 +1,9 @@
-use anchor_lang::prelude::Pubkey;
+use anchor_lang::prelude::*;
+
+use crate::error::BridgeError;
 
 pub const CHAIN_TYPE_SOLANA: u32 = 3;
+pub const CHAIN_TYPE_BITCOIN: u32 = 4;
 
 /// Convert a Solana Pubkey to a 32-byte representation for use in transfer hashes.
 /// Solana pubkeys are already 32 bytes, so no padding needed.
@@ -17,3 +20,117 @@ pub fn bytes32_to_pubkey(bytes: &[u8; 32]) -> Pubkey {
 pub fn encode_chain_id(chain_id: u32) -> [u8; 4] {
     chain_id.to_be_bytes()
 }
+
+/// Bitcoin output types a deposit may name as recipient.
+///
+/// Matches `BitcoinAddressKind` in `multichain-rs` and the Terra `address_codec.rs`;
+/// bech32 / bech32m string handling lives off-chain in those codecs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitcoinAddressKind {
+    /// Witness v0, 20-byte pubkey hash
+    P2wpkh,
+    /// Witness v0, 32-byte script hash
+    P2wsh,
+    /// Witness v1, 32-byte taproot output key
+    P2tr,
+}
+
+impl BitcoinAddressKind {
+    pub fn witness_version(&self) -> u8 {
+        match self {
+            Self::P2wpkh | Self::P2wsh => 0,
+            Self::P2tr => 1,
+        }
+    }
+
+    pub fn program_len(&self) -> usize {
+        match self {
+            Self::P2wpkh => 20,
+            Self::P2wsh | Self::P2tr => 32,
+        }
+    }
+}
+
+/// Convert a Bitcoin witness program to the 32-byte account used in transfer hashes.
+/// P2WPKH is left-padded with 12 zero bytes; P2WSH and P2TR programs are used directly.
+pub fn bitcoin_program_to_bytes32(kind: BitcoinAddressKind, program: &[u8]) -> Result<[u8; 32]> {
+    require!(
+        program.len() == kind.program_len(),
+        BridgeError::InvalidDestAccount
+    );
+    let mut result = [0u8; 32];
+    result[32 - program.len()..].copy_from_slice(program);
+    Ok(result)
+}
+
+/// Recover the witness program from a 32-byte transfer-hash account.
+pub fn bytes32_to_bitcoin_program(kind: BitcoinAddressKind, bytes: &[u8; 32]) -> Result<Vec<u8>> {
+    let pad = 32 - kind.program_len();
+    require!(
+        bytes[..pad].iter().all(|b| *b == 0),
+        BridgeError::InvalidDestAccount
+    );
+    Ok(bytes[pad..].to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex_decode(s: &str) -> Vec<u8> {
+        let s = s.trim_start_matches("0x");
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    /// Solana and Bitcoin vectors from the shared corpus written by `cl8y-conformance`
+    /// (`packages/multichain-rs/conformance/vectors.json`): the transfer-hash account
+    /// (`hash_bytes`) must match what deposits encode on-chain.
+    #[test]
+    fn conformance_corpus_account_vectors() {
+        let corpus: serde_json::Value = serde_json::from_str(include_str!(
+            "../../../../multichain-rs/conformance/vectors.json"
+        ))
+        .unwrap();
+        assert_eq!(corpus["version"], 1, "unsupported corpus version");
+
+        let mut checked = 0;
+        for v in corpus["universal_address"].as_array().unwrap() {
+            let field = |k: &str| v[k].as_str().unwrap();
+            let raw = hex_decode(field("raw"));
+            let hash_bytes: [u8; 32] = hex_decode(field("hash_bytes")).try_into().unwrap();
+            match v["chain_type"].as_u64().unwrap() as u32 {
+                CHAIN_TYPE_SOLANA => {
+                    let pubkey = Pubkey::try_from(raw.as_slice()).unwrap();
+                    assert_eq!(pubkey.to_string(), field("address"), "{}", field("name"));
+                    assert_eq!(pubkey_to_bytes32(&pubkey), hash_bytes, "{}", field("name"));
+                    assert_eq!(bytes32_to_pubkey(&hash_bytes), pubkey, "{}", field("name"));
+                }
+                CHAIN_TYPE_BITCOIN => {
+                    let bytes = hex_decode(field("bytes"));
+                    let kind = match (raw.len(), bytes.get(4)) {
+                        (20, _) => BitcoinAddressKind::P2wpkh,
+                        (32, Some(0)) => BitcoinAddressKind::P2wsh,
+                        (32, Some(1)) => BitcoinAddressKind::P2tr,
+                        other => panic!("{}: unexpected program {:?}", field("name"), other),
+                    };
+                    let bytes32 = bitcoin_program_to_bytes32(kind, &raw).unwrap();
+                    assert_eq!(bytes32, hash_bytes, "{}", field("name"));
+                    assert_eq!(bytes32_to_bitcoin_program(kind, &bytes32).unwrap(), raw);
+                }
+                _ => continue,
+            }
+            checked += 1;
+        }
+        assert!(checked > 0);
+    }
+
+    #[test]
+    fn bitcoin_rejects_wrong_program_length() {
+        assert!(bitcoin_program_to_bytes32(BitcoinAddressKind::P2wpkh, &[1u8; 32]).is_err());
+        assert!(bitcoin_program_to_bytes32(BitcoinAddressKind::P2tr, &[1u8; 20]).is_err());
+        assert!(bytes32_to_bitcoin_program(BitcoinAddressKind::P2wpkh, &[1u8; 32]).is_err());
+    }
+}
@@ -0,0 +1,163 @@
+//! Canceler reward bookkeeping for `withdraw_cancel` / `withdraw_reenable`
+//! (TerraClassic `record_cancel` / `revert_cancel_record` parity).
+//!
+//! A [`CancelRecord`] is only created while the [`CancelerRewardPool`] exists, so cancelers do not
+//! pay record rent on a bridge without bounties. Both PDAs are passed seed-checked and may be
+//! uninitialized; an empty or foreign-owned account is treated as absent.
+
+use crate::error::BridgeError;
+use crate::state::{CancelRecord, CancelerRewardPool};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+fn load<T: AccountDeserialize>(info: &AccountInfo) -> Result<Option<T>> {
+    if info.data_is_empty() || *info.owner != crate::ID {
+        return Ok(None);
+    }
+    let data = info.try_borrow_data()?;
+    Ok(Some(T::try_deserialize(&mut &data[..])?))
+}
+
+fn store<T: AccountSerialize>(info: &AccountInfo, value: &T) -> Result<()> {
+    let mut data = info.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data;
+    value.try_serialize(&mut writer)
+}
+
+/// Allocate the cancel record PDA, paid by the canceler. Mirrors Anchor's `init` so lamports
+/// pre-sent to the address cannot block creation.
+fn create_record_account<'info>(
+    cancel_record: &AccountInfo<'info>,
+    canceler: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    transfer_hash: &[u8; 32],
+    bump: u8,
+) -> Result<()> {
+    let space = 8 + CancelRecord::INIT_SPACE;
+    let rent = Rent::get()?.minimum_balance(space);
+    let bump = [bump];
+    let seeds: &[&[u8]] = &[CancelRecord::SEED, transfer_hash.as_ref(), &bump];
+    let signer = &[seeds];
+
+    let current = cancel_record.lamports();
+    if current == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::CreateAccount {
+                    from: canceler.clone(),
+                    to: cancel_record.clone(),
+                },
+                signer,
+            ),
+            rent,
+            space as u64,
+            &crate::ID,
+        );
+    }
+
+    let top_up = rent.saturating_sub(current);
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: canceler.clone(),
+                    to: cancel_record.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Allocate {
+                account_to_allocate: cancel_record.clone(),
+            },
+            signer,
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Assign {
+                account_to_assign: cancel_record.clone(),
+            },
+            signer,
+        ),
+        &crate::ID,
+    )
+}
+
+/// Record `canceler` as eligible for the bounty on `transfer_hash`. No-op without a reward pool
+/// or when the existing record was already claimed.
+pub(crate) fn record_cancel<'info>(
+    reward_pool: &AccountInfo<'info>,
+    cancel_record: &AccountInfo<'info>,
+    canceler: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    transfer_hash: [u8; 32],
+    record_bump: u8,
+    now: i64,
+) -> Result<()> {
+    let Some(mut pool) = load::<CancelerRewardPool>(reward_pool)? else {
+        return Ok(());
+    };
+
+    match load::<CancelRecord>(cancel_record)? {
+        Some(existing) if existing.claimed => return Ok(()),
+        Some(_) => {}
+        None => create_record_account(
+            cancel_record,
+            canceler,
+            system_program,
+            &transfer_hash,
+            record_bump,
+        )?,
+    }
+
+    store(
+        cancel_record,
+        &CancelRecord {
+            transfer_hash,
+            canceler: canceler.key(),
+            cancelled_at: now,
+            claimed: false,
+            reverted: false,
+            bump: record_bump,
+        },
+    )?;
+
+    pool.total_cancels = pool
+        .total_cancels
+        .checked_add(1)
+        .ok_or(BridgeError::ArithmeticOverflow)?;
+    store(reward_pool, &pool)
+}
+
+/// Mark an unclaimed record reverted when its cancellation is undone. Claimed records are kept
+/// as-is so the same withdraw cannot pay a second bounty.
+pub(crate) fn revert_cancel_record(
+    reward_pool: &AccountInfo,
+    cancel_record: &AccountInfo,
+) -> Result<()> {
+    let Some(mut record) = load::<CancelRecord>(cancel_record)? else {
+        return Ok(());
+    };
+    if record.claimed || record.reverted {
+        return Ok(());
+    }
+    record.reverted = true;
+    store(cancel_record, &record)?;
+
+    if let Some(mut pool) = load::<CancelerRewardPool>(reward_pool)? {
+        pool.total_reverted = pool
+            .total_reverted
+            .checked_add(1)
+            .ok_or(BridgeError::ArithmeticOverflow)?;
+        store(reward_pool, &pool)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,109 @@
+//! Effective withdraw delay (cancel window) for `withdraw_execute` / `withdraw_execute_native`
+//! and `withdraw_cancel`:
+//!
+//! 1. [`ChainWithdrawDelay`] for the withdraw's `src_chain`, else `BridgeConfig::withdraw_delay`
+//! 2. raised to [`LargeTransferDelay::withdraw_delay`] when the amount reaches its threshold
+//!
+//! Both accounts are always passed (seed-checked) so neither can be skipped. Mirrored off-chain by
+//! `multichain_rs::solana::effective_withdraw_delay` (canceler `cancel_window`).
+
+use crate::state::{BridgeConfig, ChainWithdrawDelay, LargeTransferDelay};
+use anchor_lang::prelude::*;
+
+/// Bounds for `BridgeConfig::withdraw_delay` and [`ChainWithdrawDelay`] (seconds).
+pub(crate) const MIN_WITHDRAW_DELAY: i64 = 15;
+pub(crate) const MAX_WITHDRAW_DELAY: i64 = 86_400;
+/// Upper bound for [`LargeTransferDelay`] (7 days).
+pub(crate) const MAX_LARGE_TRANSFER_DELAY: i64 = 604_800;
+
+/// Effective delay from already-loaded inputs; a large-transfer delay never shortens the window.
+pub(crate) fn effective_withdraw_delay(
+    bridge_delay: i64,
+    chain_delay: Option<i64>,
+    large_transfer: Option<(u128, i64)>,
+    amount: u128,
+) -> i64 {
+    let base = chain_delay.unwrap_or(bridge_delay);
+    match large_transfer {
+        Some((threshold, delay)) if amount >= threshold => base.max(delay),
+        _ => base,
+    }
+}
+
+/// Deserialize a seed-checked optional config PDA; `None` while uninitialized.
+fn load<T: AccountDeserialize>(info: &AccountInfo) -> Result<Option<T>> {
+    if info.data_is_empty() || *info.owner != crate::ID {
+        return Ok(None);
+    }
+    let data = info.try_borrow_data()?;
+    Ok(Some(T::try_deserialize(&mut &data[..])?))
+}
+
+/// Resolve the delay for a pending withdraw of `amount` from the accounts passed to execute.
+pub(crate) fn resolve_withdraw_delay(
+    bridge: &BridgeConfig,
+    chain_withdraw_delay: &AccountInfo,
+    large_transfer_delay: &AccountInfo,
+    amount: u128,
+) -> Result<i64> {
+    let chain = load::<ChainWithdrawDelay>(chain_withdraw_delay)?.map(|c| c.withdraw_delay);
+    let large =
+        load::<LargeTransferDelay>(large_transfer_delay)?.map(|l| (l.threshold, l.withdraw_delay));
+    Ok(effective_withdraw_delay(
+        bridge.withdraw_delay,
+        chain,
+        large,
+        amount,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn bridge_delay_without_overrides() {
+        assert_eq!(effective_withdraw_delay(300, None, None, u128::MAX), 300);
+    }
+
+    #[test]
+    fn chain_delay_replaces_bridge_delay() {
+        assert_eq!(effective_withdraw_delay(300, Some(60), None, 1), 60);
+        assert_eq!(effective_withdraw_delay(300, Some(900), None, 1), 900);
+    }
+
+    #[test]
+    fn large_transfer_extends_at_threshold() {
+        let large = Some((1_000u128, 3_600));
+        assert_eq!(effective_withdraw_delay(300, None, large, 999), 300);
+        assert_eq!(effective_withdraw_delay(300, None, large, 1_000), 3_600);
+        assert_eq!(effective_withdraw_delay(300, Some(60), large, 5_000), 3_600);
+    }
+
+    #[test]
+    fn large_transfer_never_shortens() {
+        assert_eq!(
+            effective_withdraw_delay(300, Some(7_200), Some((1, 3_600)), 10),
+            7_200
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn effective_at_least_base(
+            bridge in MIN_WITHDRAW_DELAY..=MAX_WITHDRAW_DELAY,
+            chain in proptest::option::of(MIN_WITHDRAW_DELAY..=MAX_WITHDRAW_DELAY),
+            threshold in 1u128..u128::MAX,
+            large in MIN_WITHDRAW_DELAY..=MAX_LARGE_TRANSFER_DELAY,
+            amount: u128,
+        ) {
+            let base = chain.unwrap_or(bridge);
+            let d = effective_withdraw_delay(bridge, chain, Some((threshold, large)), amount);
+            prop_assert!(d >= base);
+            if amount < threshold {
+                prop_assert_eq!(d, base);
+            }
+        }
+    }
+}
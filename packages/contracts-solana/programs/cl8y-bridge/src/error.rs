@@ -76,4 +76,54 @@ pub enum BridgeError {
     RateLimitExceededPerTx,
     #[msg("Withdraw would exceed configured maximum per 24h window")]
     RateLimitExceededPerPeriod,
+    #[msg("Invalid canceler review period (1 to 2592000 seconds)")]
+    InvalidReviewPeriod,
+    #[msg("Caller is not the canceler recorded for this withdrawal")]
+    NotRecordedCanceler,
+    #[msg("Canceler reward already claimed")]
+    CancelerRewardAlreadyClaimed,
+    #[msg("Cancellation was reverted or the withdrawal was executed")]
+    CancelReverted,
+    #[msg("Canceler review period has not elapsed")]
+    ReviewPeriodActive,
+    #[msg("Canceler reward pool balance is insufficient")]
+    InsufficientCancelerRewardPool,
+    #[msg("CL8Y token account does not match the fee schedule mint or depositor")]
+    InvalidCl8yTokenAccount,
+    #[msg("No finished withdraw account was passed to close")]
+    NothingToClose,
+    #[msg("Withdraw nonce is not recorded in the nonce bitmap")]
+    NonceNotRecorded,
+    #[msg("Only cancelled withdrawals can be closed while pending")]
+    WithdrawNotFinished,
+    #[msg("Retention period has not elapsed")]
+    RetentionNotElapsed,
+    #[msg("Recipient must sign to close its executed-hash receipt")]
+    RecipientSignatureRequired,
+    #[msg("Mint has an unsupported Token-2022 extension")]
+    UnsupportedMintExtension,
+    #[msg("Large-transfer threshold must be greater than zero")]
+    InvalidLargeTransferThreshold,
+    #[msg("Cancel record is still claimable")]
+    CancelRecordActive,
+    #[msg("Rent sponsor balance is insufficient")]
+    InsufficientRentSponsor,
+    #[msg("Rent source account missing or not expected")]
+    InvalidRentSource,
+    #[msg("Sponsorship account does not belong to this withdraw")]
+    InvalidSponsorship,
+    #[msg("Withdraw is still pending")]
+    WithdrawStillPending,
+    #[msg("Relaying is disabled (relay fee cap is 0)")]
+    RelayingDisabled,
+    #[msg("Relay fee exceeds the cap")]
+    RelayFeeTooHigh,
+    #[msg("Relay fee cap exceeds maximum (500 bps)")]
+    RelayFeeCapExceedsMax,
+    #[msg("Caller did not make this deposit")]
+    NotDepositor,
+    #[msg("Max relay fee must be non-zero and below the deposit amount")]
+    InvalidMaxRelayFee,
+    #[msg("Relayer token account missing or not owned by the relayer")]
+    InvalidRelayerTokenAccount,
 }
@@ -0,0 +1,243 @@
+//! Shared steps of `withdraw_execute` / `withdraw_execute_native` and their operator-submitted
+//! variants (`withdraw_execute_by_operator` / `withdraw_execute_native_by_operator`).
+//!
+//! The variants differ only in who signs and pays rent for the `ExecutedHash` and rate-limit
+//! accounts: the recipient, or the bridge operator executing on the recipient's behalf once the
+//! cancel window has passed. Funds always go to `PendingWithdraw::dest_account`; the
+//! `PendingWithdraw` rent goes there too unless the operator paid it
+//! (`withdraw_submit_and_approve`, see [`settle_sponsorship`]), whoever executes. A relayed
+//! withdraw also pays its relay fee to that operator ([`relay_fee_amount`]).
+
+use crate::decimal::normalize_decimals;
+use crate::delay::resolve_withdraw_delay;
+use crate::error::BridgeError;
+use crate::hash::compute_transfer_hash;
+use crate::state::{
+    BridgeConfig, PendingWithdraw, TokenMode, WithdrawRateLimit, WithdrawSponsorship,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, MintTo, TokenAccount, TransferChecked};
+
+/// Reject a withdraw that is paused, cancelled, unapproved, executed, for another recipient,
+/// tampered with, or still inside its cancel window.
+pub fn check_executable(
+    bridge: &BridgeConfig,
+    pw: &PendingWithdraw,
+    recipient: &Pubkey,
+    chain_withdraw_delay: &AccountInfo,
+    large_transfer_delay: &AccountInfo,
+) -> Result<()> {
+    require!(!bridge.paused, BridgeError::BridgePaused);
+    require!(!pw.cancelled, BridgeError::WithdrawalCancelled);
+    require!(pw.approved, BridgeError::NotApproved);
+    require!(!pw.executed, BridgeError::AlreadyExecuted);
+    require!(pw.dest_account == *recipient, BridgeError::WrongRecipient);
+
+    let recomputed = compute_transfer_hash(
+        &pw.src_chain,
+        &bridge.chain_id,
+        &pw.src_account,
+        &pw.dest_account.to_bytes(),
+        &pw.token.to_bytes(),
+        pw.amount,
+        pw.nonce,
+    );
+    require!(recomputed == pw.transfer_hash, BridgeError::HashMismatch);
+
+    let withdraw_delay = resolve_withdraw_delay(
+        bridge,
+        chain_withdraw_delay,
+        large_transfer_delay,
+        pw.amount,
+    )?;
+    let clock = Clock::get()?;
+    let window_end = pw
+        .approved_at
+        .checked_add(withdraw_delay)
+        .ok_or(BridgeError::ArithmeticOverflow)?;
+    require!(
+        clock.unix_timestamp > window_end,
+        BridgeError::DelayNotElapsed
+    );
+    Ok(())
+}
+
+/// Charge `amount` against the mint's rolling withdraw window (`supply` 0 for native SOL).
+pub fn consume_withdraw_rate_limit(
+    wr: &mut WithdrawRateLimit,
+    bump: u8,
+    supply: u128,
+    amount: u128,
+) -> Result<()> {
+    wr.bump = bump;
+    let (min_tx, max_tx, max_period) = crate::rate_limit::resolve_effective_limits(
+        wr.explicit_config,
+        wr.min_per_transaction,
+        wr.max_per_transaction,
+        wr.max_per_period,
+        supply,
+    );
+    let mut window_start = wr.window_start;
+    let mut used = wr.used;
+    crate::rate_limit::check_and_update_withdraw_rate_limit(
+        Clock::get()?.unix_timestamp,
+        amount,
+        min_tx,
+        max_tx,
+        max_period,
+        &mut window_start,
+        &mut used,
+    )?;
+    wr.window_start = window_start;
+    wr.used = used;
+    Ok(())
+}
+
+/// Unlock or mint `amount` of `mint` into the recipient's token account, signed by the bridge PDA.
+#[allow(clippy::too_many_arguments)]
+pub fn release_spl<'info>(
+    mode: TokenMode,
+    token_program: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    bridge_token_account: AccountInfo<'info>,
+    recipient_token_account: AccountInfo<'info>,
+    bridge: AccountInfo<'info>,
+    bridge_bump: u8,
+    amount: u64,
+    decimals: u8,
+) -> Result<()> {
+    let bridge_seeds: &[&[u8]] = &[BridgeConfig::SEED, &[bridge_bump]];
+    match mode {
+        TokenMode::LockUnlock => token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                token_program,
+                TransferChecked {
+                    from: bridge_token_account,
+                    mint,
+                    to: recipient_token_account,
+                    authority: bridge,
+                },
+                &[bridge_seeds],
+            ),
+            amount,
+            decimals,
+        ),
+        TokenMode::MintBurn => token_interface::mint_to(
+            CpiContext::new_with_signer(
+                token_program,
+                MintTo {
+                    mint,
+                    to: recipient_token_account,
+                    authority: bridge,
+                },
+                &[bridge_seeds],
+            ),
+            amount,
+        ),
+    }
+}
+
+/// Move `amount` lamports from the bridge PDA to the recipient, keeping the PDA rent-exempt.
+pub fn release_native(bridge: &AccountInfo, recipient: &AccountInfo, amount: u64) -> Result<()> {
+    let rent_exempt = Rent::get()?.minimum_balance(8 + BridgeConfig::INIT_SPACE);
+    let available = bridge.lamports().saturating_sub(rent_exempt);
+    require!(available >= amount, BridgeError::InsufficientBridgeBalance);
+    **bridge.try_borrow_mut_lamports()? = bridge
+        .lamports()
+        .checked_sub(amount)
+        .ok_or(BridgeError::ArithmeticOverflow)?;
+    **recipient.try_borrow_mut_lamports()? = recipient
+        .lamports()
+        .checked_add(amount)
+        .ok_or(BridgeError::ArithmeticOverflow)?;
+    Ok(())
+}
+
+/// Read the [`WithdrawSponsorship`] of `transfer_hash`, if `withdraw_submit_and_approve`
+/// created one. `sponsorship` is its seeds-fixed address; it is only read when owned by this
+/// program.
+pub fn load_sponsorship(
+    sponsorship: &AccountInfo,
+    transfer_hash: &[u8; 32],
+) -> Result<Option<WithdrawSponsorship>> {
+    if sponsorship.data_is_empty() || *sponsorship.owner != crate::ID {
+        return Ok(None);
+    }
+    let record = {
+        let data = sponsorship.try_borrow_data()?;
+        WithdrawSponsorship::try_deserialize(&mut &data[..])?
+    };
+    require!(
+        record.transfer_hash == *transfer_hash,
+        BridgeError::InvalidSponsorship
+    );
+    Ok(Some(record))
+}
+
+/// Relay fee owed to the sponsoring operator, in destination decimals (0 when not relayed).
+pub fn relay_fee_amount(record: Option<&WithdrawSponsorship>, pw: &PendingWithdraw) -> Result<u64> {
+    let Some(record) = record.filter(|r| r.relay_fee > 0) else {
+        return Ok(0);
+    };
+    normalize_decimals(record.relay_fee, pw.src_decimals, pw.dest_decimals)?
+        .try_into()
+        .map_err(|_| error!(BridgeError::AmountExceedsU64))
+}
+
+/// Repay the operator for `PendingWithdraw` rent it paid under [`crate::state::RentSource::Withdrawal`].
+///
+/// When `operator` is the operator that paid the rent, the `PendingWithdraw` lamports and
+/// everything the sponsorship holds go straight to it. Otherwise (the recipient executes) the
+/// `PendingWithdraw` lamports move into the sponsorship and `close_withdraw_sponsorship` returns
+/// them. Either way the account `close` that follows moves nothing to the recipient.
+pub fn settle_sponsorship(
+    sponsorship: &AccountInfo,
+    record: Option<&WithdrawSponsorship>,
+    pending_withdraw: &AccountInfo,
+    operator: Option<&AccountInfo>,
+) -> Result<()> {
+    let Some(record) = record else {
+        return Ok(());
+    };
+
+    let rent = pending_withdraw.lamports();
+    match operator.filter(|op| op.key() == record.operator) {
+        Some(operator) => {
+            let refund = rent
+                .checked_add(sponsorship.lamports())
+                .ok_or(BridgeError::ArithmeticOverflow)?;
+            **operator.try_borrow_mut_lamports()? = operator
+                .lamports()
+                .checked_add(refund)
+                .ok_or(BridgeError::ArithmeticOverflow)?;
+            **sponsorship.try_borrow_mut_lamports()? = 0;
+            sponsorship.assign(&anchor_lang::system_program::ID);
+            sponsorship.resize(0)?;
+        }
+        None => {
+            **sponsorship.try_borrow_mut_lamports()? = sponsorship
+                .lamports()
+                .checked_add(rent)
+                .ok_or(BridgeError::ArithmeticOverflow)?;
+        }
+    }
+    **pending_withdraw.try_borrow_mut_lamports()? = 0;
+    Ok(())
+}
+
+/// Check that an SPL relay fee goes to a token account for `mint` owned by the operator that
+/// relayed the withdraw.
+pub fn relayer_token_account<'a, 'info>(
+    record: Option<&WithdrawSponsorship>,
+    account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    mint: &Pubkey,
+) -> Result<&'a InterfaceAccount<'info, TokenAccount>> {
+    let (Some(record), Some(account)) = (record, account) else {
+        return err!(BridgeError::InvalidRelayerTokenAccount);
+    };
+    require!(
+        account.owner == record.operator && account.mint == *mint,
+        BridgeError::InvalidRelayerTokenAccount
+    );
+    Ok(account)
+}
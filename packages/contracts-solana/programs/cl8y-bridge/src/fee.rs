@@ -2,9 +2,95 @@
 //! Used by [`instructions::deposit_native`](crate::instructions::deposit_native) and
 //! [`instructions::deposit_spl`](crate::instructions::deposit_spl). On-chain `fee_bps` is capped at 100
 //! in [`initialize`](crate::instructions::initialize) and [`set_config`](crate::instructions::set_config).
+//!
+//! The rate is resolved per deposit by [`resolve_deposit_fee_bps`] (TerraClassic `fee_manager` parity):
+//!
+//! 1. [`CustomAccountFee`] for the depositor (always passed, so it cannot be skipped)
+//! 2. CL8Y holder discount from [`FeeSchedule`], if the depositor's CL8Y token account is passed and
+//!    holds at least the threshold (never above the standard rate)
+//! 3. [`TokenFeeOverride`] for the token mapping (always passed, so it cannot be skipped)
+//! 4. `BridgeConfig::fee_bps`
+//!
+//! Mirrored off-chain by `multichain_rs::solana::SolanaFeeCalculator`.
 
 use crate::error::BridgeError;
+use crate::state::{BridgeConfig, CustomAccountFee, FeeSchedule, TokenFeeOverride};
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+/// Cap for every fee tier (EVM / Terra `MAX_FEE_BPS`).
+pub(crate) const MAX_FEE_BPS: u16 = 100;
+
+/// Effective fee rate from already-validated inputs. See module docs for the priority order.
+pub(crate) fn effective_fee_bps(
+    bridge_fee_bps: u16,
+    token_override: Option<u16>,
+    custom_fee_bps: Option<u16>,
+    discounted_fee_bps: Option<u16>,
+) -> u16 {
+    if let Some(custom) = custom_fee_bps {
+        return custom;
+    }
+    let standard = token_override.unwrap_or(bridge_fee_bps);
+    match discounted_fee_bps {
+        Some(discounted) => discounted.min(standard),
+        None => standard,
+    }
+}
+
+/// Read the per-mapping override from its (possibly uninitialized) PDA. The address is
+/// checked by the accounts struct; an empty or foreign-owned account means no override.
+pub(crate) fn token_fee_override_bps(token_fee_override: &AccountInfo) -> Result<Option<u16>> {
+    if token_fee_override.data_is_empty() || *token_fee_override.owner != crate::ID {
+        return Ok(None);
+    }
+    let data = token_fee_override.try_borrow_data()?;
+    let over = TokenFeeOverride::try_deserialize(&mut &data[..])?;
+    Ok(Some(over.fee_bps))
+}
+
+/// Read the depositor's custom fee from its (possibly uninitialized) PDA, like
+/// [`token_fee_override_bps`].
+pub(crate) fn custom_account_fee_bps(custom_account_fee: &AccountInfo) -> Result<Option<u16>> {
+    if custom_account_fee.data_is_empty() || *custom_account_fee.owner != crate::ID {
+        return Ok(None);
+    }
+    let data = custom_account_fee.try_borrow_data()?;
+    let custom = CustomAccountFee::try_deserialize(&mut &data[..])?;
+    Ok(Some(custom.fee_bps))
+}
+
+/// Resolve the deposit fee rate from the fee accounts passed to a deposit instruction.
+/// PDA addresses are checked by the accounts struct; this checks the CL8Y token account.
+pub(crate) fn resolve_deposit_fee_bps(
+    bridge: &BridgeConfig,
+    token_fee_override: &AccountInfo,
+    custom_account_fee: &AccountInfo,
+    fee_schedule: Option<&FeeSchedule>,
+    cl8y_token_account: Option<&InterfaceAccount<TokenAccount>>,
+    depositor: &Pubkey,
+) -> Result<u16> {
+    let discounted = match (fee_schedule, cl8y_token_account) {
+        (Some(schedule), Some(account)) => {
+            require!(
+                schedule.cl8y_mint != Pubkey::default()
+                    && account.mint == schedule.cl8y_mint
+                    && account.owner == *depositor,
+                BridgeError::InvalidCl8yTokenAccount
+            );
+            (account.amount >= schedule.cl8y_threshold).then_some(schedule.discounted_fee_bps)
+        }
+        (None, Some(_)) => return err!(BridgeError::InvalidCl8yTokenAccount),
+        _ => None,
+    };
+
+    Ok(effective_fee_bps(
+        bridge.fee_bps,
+        token_fee_override_bps(token_fee_override)?,
+        custom_account_fee_bps(custom_account_fee)?,
+        discounted,
+    ))
+}
 
 /// Returns `(fee, net_amount)` for a deposit gross `amount`.
 pub(crate) fn deposit_fee_and_net(amount: u64, fee_bps: u16) -> Result<(u64, u64)> {
@@ -43,5 +129,29 @@ mod tests {
             let expected_fee = ((amount as u128) * (fee_bps as u128) / 10000) as u64;
             prop_assert_eq!(fee, expected_fee);
         }
+
+        #[test]
+        fn effective_fee_priority(
+            bridge_bps in 0u16..=100u16,
+            token_override in proptest::option::of(0u16..=100u16),
+            custom in proptest::option::of(0u16..=100u16),
+            discounted in proptest::option::of(0u16..=100u16),
+        ) {
+            let bps = effective_fee_bps(bridge_bps, token_override, custom, discounted);
+            let standard = token_override.unwrap_or(bridge_bps);
+            match (custom, discounted) {
+                (Some(c), _) => prop_assert_eq!(bps, c),
+                (None, Some(_)) => prop_assert!(bps <= standard),
+                (None, None) => prop_assert_eq!(bps, standard),
+            }
+            prop_assert!(bps <= MAX_FEE_BPS);
+        }
+    }
+
+    #[test]
+    fn discount_never_raises_token_override() {
+        assert_eq!(effective_fee_bps(50, Some(5), None, Some(10)), 5);
+        assert_eq!(effective_fee_bps(50, Some(80), None, Some(10)), 10);
+        assert_eq!(effective_fee_bps(50, None, Some(70), Some(10)), 70);
     }
 }
@@ -7,7 +7,8 @@ use solana_program::keccak;
 ///
 /// **Docs:** `docs/crosschain-parity.md`, `docs/SOLANA_BRIDGE_INVARIANTS.md` (INV-H1).
 ///
-/// **Tests:** Vectors in `#[cfg(test)]` below vs `packages/contracts-evm/test/HashLib.t.sol`;
+/// **Tests:** Shared conformance corpus (`packages/multichain-rs/conformance/vectors.json`,
+/// also run by `packages/contracts-evm/test/Conformance.t.sol`) in `#[cfg(test)]` below;
 /// TS `packages/contracts-solana/tests/hash_parity.test.ts`; E2E
 /// `packages/e2e/tests/test_solana_flows.rs`; CosmWasm parity
 /// `packages/multichain-rs/tests/hash_agrees_with_cosmwasm_bridge.rs`.
@@ -92,101 +93,47 @@ mod tests {
     use super::*;
     use proptest::prelude::*;
 
-    fn hex_bytes32(s: &str) -> [u8; 32] {
+    fn hex_bytes(s: &str) -> Vec<u8> {
         let s = s.trim_start_matches("0x");
-        assert_eq!(s.len(), 64, "expected 32-byte hex");
-        let mut out = [0u8; 32];
-        for i in 0..32 {
-            out[i] = u8::from_str_radix(&s[i * 2..i * 2 + 2], 16).expect("hex digit");
-        }
-        out
-    }
-
-    /// Golden vectors from `packages/contracts-evm/test/HashLib.t.sol` (must match `main` / EVM).
-    #[test]
-    fn evm_vector_evm_to_evm_erc20() {
-        let h = compute_transfer_hash(
-            &1u32.to_be_bytes(),
-            &56u32.to_be_bytes(),
-            &hex_bytes32("000000000000000000000000f39fd6e51aad88f6f4ce6ab8827279cfffb92266"),
-            &hex_bytes32("00000000000000000000000070997970c51812dc3a010c7d01b50e0d17dc79c8"),
-            &hex_bytes32("0000000000000000000000005fbdb2315678afecb367f032d93f642f64180aa3"),
-            1_000_000_000_000_000_000u128,
-            42,
-        );
-        assert_eq!(
-            h,
-            hex_bytes32("11c90f88a3d48e75a39bc219d261069075a136436ae06b2b571b66a9a600aa54")
-        );
-    }
-
-    #[test]
-    fn evm_vector_evm_to_terra_uluna() {
-        let h = compute_transfer_hash(
-            &1u32.to_be_bytes(),
-            &2u32.to_be_bytes(),
-            &hex_bytes32("000000000000000000000000f39fd6e51aad88f6f4ce6ab8827279cfffb92266"),
-            &hex_bytes32("00000000000000000000000035743074956c710800e83198011ccbd4ddf1556d"),
-            &hex_bytes32("56fa6c6fbc36d8c245b0a852a43eb5d644e8b4c477b27bfab9537c10945939da"),
-            995_000,
-            1,
-        );
-        assert_eq!(
-            h,
-            hex_bytes32("92b16cdec59cb405996f66a9153c364ed635f40f922b518885aa76e5e9c23453")
-        );
-    }
-
-    #[test]
-    fn evm_vector_evm_to_terra_cw20_token_field() {
-        let cw20 = hex_bytes32("00000000000000000000000035743074956c710800e83198011ccbd4ddf1556d");
-        let h = compute_transfer_hash(
-            &1u32.to_be_bytes(),
-            &2u32.to_be_bytes(),
-            &hex_bytes32("000000000000000000000000f39fd6e51aad88f6f4ce6ab8827279cfffb92266"),
-            &cw20,
-            &cw20,
-            1_000_000,
-            5,
-        );
-        assert_eq!(
-            h,
-            hex_bytes32("1ec7d94b0f068682032903f83c88fd643d03969e04875ec7ea70f02d1a74db7b")
-        );
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).expect("hex digit"))
+            .collect()
     }
 
-    #[test]
-    fn evm_vector_terra_to_evm_native_to_erc20() {
-        let h = compute_transfer_hash(
-            &2u32.to_be_bytes(),
-            &1u32.to_be_bytes(),
-            &hex_bytes32("00000000000000000000000035743074956c710800e83198011ccbd4ddf1556d"),
-            &hex_bytes32("000000000000000000000000f39fd6e51aad88f6f4ce6ab8827279cfffb92266"),
-            &hex_bytes32("0000000000000000000000005fbdb2315678afecb367f032d93f642f64180aa3"),
-            500_000,
-            3,
-        );
-        assert_eq!(
-            h,
-            hex_bytes32("076a0951bf01eaaf385807d46f1bdfaa4e3f88d7ba77aae03c65871f525a7438")
-        );
+    fn hex_bytes32(s: &str) -> [u8; 32] {
+        hex_bytes(s).try_into().expect("expected 32-byte hex")
     }
 
+    /// Shared corpus written by `cl8y-conformance` (`packages/multichain-rs/conformance/`);
+    /// the same vectors run against multichain-rs, CosmWasm, `HashLib.sol` and the TS helpers.
     #[test]
-    fn evm_vector_terra_to_evm_cw20_to_erc20() {
-        let h = compute_transfer_hash(
-            &2u32.to_be_bytes(),
-            &1u32.to_be_bytes(),
-            &hex_bytes32("00000000000000000000000035743074956c710800e83198011ccbd4ddf1556d"),
-            &hex_bytes32("00000000000000000000000070997970c51812dc3a010c7d01b50e0d17dc79c8"),
-            &hex_bytes32("000000000000000000000000e7f1725e7734ce288f8367e1bb143e90bb3f0512"),
-            2_500_000,
-            7,
-        );
-        assert_eq!(
-            h,
-            hex_bytes32("f1ab14494f74acdd3a622cd214e6d0ebde29121309203a6bd7509bf3025c22ab")
-        );
+    fn conformance_corpus_xchain_hash_vectors() {
+        let corpus: serde_json::Value = serde_json::from_str(include_str!(
+            "../../../../multichain-rs/conformance/vectors.json"
+        ))
+        .expect("valid corpus");
+        assert_eq!(corpus["version"], 1, "unsupported corpus version");
+
+        let vectors = corpus["xchain_hash"].as_array().expect("xchain_hash");
+        assert!(!vectors.is_empty());
+        for v in vectors {
+            let field = |k: &str| v[k].as_str().expect(k);
+            let chain = |k: &str| {
+                let b = hex_bytes(field(k));
+                [b[0], b[1], b[2], b[3]]
+            };
+            let h = compute_transfer_hash(
+                &chain("src_chain"),
+                &chain("dest_chain"),
+                &hex_bytes32(field("src_account")),
+                &hex_bytes32(field("dest_account")),
+                &hex_bytes32(field("token")),
+                field("amount").parse().expect("u128 amount"),
+                field("nonce").parse().expect("u64 nonce"),
+            );
+            assert_eq!(h, hex_bytes32(field("xchain_hash_id")), "{}", field("name"));
+        }
     }
 
     #[test]
@@ -195,7 +142,8 @@ mod tests {
         let n = u64::MAX;
         let z = [0u8; 32];
         let got = compute_transfer_hash(&[1, 2, 3, 4], &[5, 6, 7, 8], &z, &z, &z, a, n);
-        let want = reference_xchain_hash_tiny_keccak(&[1, 2, 3, 4], &[5, 6, 7, 8], &z, &z, &z, a, n);
+        let want =
+            reference_xchain_hash_tiny_keccak(&[1, 2, 3, 4], &[5, 6, 7, 8], &z, &z, &z, a, n);
         assert_eq!(got, want);
     }
 
@@ -0,0 +1,108 @@
+use crate::error::BridgeError;
+use crate::state::{CancelRecord, CancelerEntry, CancelerRewardPool, PendingWithdraw};
+use anchor_lang::prelude::*;
+
+/// Recorded canceler claims the bounty once the review period has passed and the
+/// cancel was not reverted by `withdraw_reenable` (Terra `ClaimCancelerReward` parity).
+#[derive(Accounts)]
+pub struct ClaimCancelerReward<'info> {
+    #[account(
+        mut,
+        seeds = [CancelerRewardPool::SEED],
+        bump = reward_pool.bump,
+    )]
+    pub reward_pool: Account<'info, CancelerRewardPool>,
+
+    #[account(
+        seeds = [PendingWithdraw::SEED, pending_withdraw.transfer_hash.as_ref()],
+        bump = pending_withdraw.bump,
+    )]
+    pub pending_withdraw: Account<'info, PendingWithdraw>,
+
+    #[account(
+        mut,
+        seeds = [CancelRecord::SEED, pending_withdraw.transfer_hash.as_ref()],
+        bump = cancel_record.bump,
+    )]
+    pub cancel_record: Account<'info, CancelRecord>,
+
+    #[account(
+        seeds = [CancelerEntry::SEED, canceler.key().as_ref()],
+        bump = canceler_entry.bump,
+    )]
+    pub canceler_entry: Account<'info, CancelerEntry>,
+
+    #[account(mut)]
+    pub canceler: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ClaimCancelerReward>) -> Result<()> {
+    let canceler_key = ctx.accounts.canceler.key();
+    let record = &mut ctx.accounts.cancel_record;
+    require!(
+        record.canceler == canceler_key,
+        BridgeError::NotRecordedCanceler
+    );
+    require!(!record.claimed, BridgeError::CancelerRewardAlreadyClaimed);
+    require!(!record.reverted, BridgeError::CancelReverted);
+    // A canceler deactivated by admin forfeits unclaimed rewards.
+    require!(
+        ctx.accounts.canceler_entry.active,
+        BridgeError::UnauthorizedCanceler
+    );
+
+    let pw = &ctx.accounts.pending_withdraw;
+    require!(pw.cancelled && !pw.executed, BridgeError::CancelReverted);
+
+    let pool = &mut ctx.accounts.reward_pool;
+    let now = Clock::get()?.unix_timestamp;
+    let claimable_at = record
+        .cancelled_at
+        .checked_add(pool.review_period)
+        .ok_or(BridgeError::ArithmeticOverflow)?;
+    require!(now >= claimable_at, BridgeError::ReviewPeriodActive);
+
+    let reward = pool.reward_lamports;
+    let pool_info = pool.to_account_info();
+    let rent_exempt = Rent::get()?.minimum_balance(8 + CancelerRewardPool::INIT_SPACE);
+    let available = pool_info.lamports().saturating_sub(rent_exempt);
+    require!(
+        available >= reward,
+        BridgeError::InsufficientCancelerRewardPool
+    );
+
+    let canceler_info = ctx.accounts.canceler.to_account_info();
+    **pool_info.try_borrow_mut_lamports()? = pool_info
+        .lamports()
+        .checked_sub(reward)
+        .ok_or(BridgeError::ArithmeticOverflow)?;
+    **canceler_info.try_borrow_mut_lamports()? = canceler_info
+        .lamports()
+        .checked_add(reward)
+        .ok_or(BridgeError::ArithmeticOverflow)?;
+
+    record.claimed = true;
+    pool.total_paid = pool
+        .total_paid
+        .checked_add(reward)
+        .ok_or(BridgeError::ArithmeticOverflow)?;
+    pool.total_claims = pool
+        .total_claims
+        .checked_add(1)
+        .ok_or(BridgeError::ArithmeticOverflow)?;
+
+    emit!(ClaimCancelerRewardEvent {
+        transfer_hash: record.transfer_hash,
+        canceler: canceler_key,
+        amount: reward,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ClaimCancelerRewardEvent {
+    pub transfer_hash: [u8; 32],
+    pub canceler: Pubkey,
+    pub amount: u64,
+}
@@ -0,0 +1,49 @@
+use crate::error::BridgeError;
+use crate::state::{CancelRecord, PendingWithdraw};
+use anchor_lang::prelude::*;
+
+/// Recorded canceler reclaims [`CancelRecord`] rent once the record is settled: reverted by
+/// `withdraw_reenable`, or its PendingWithdraw is gone (executed or closed by `close_withdraw`).
+/// Claimed records stay while the withdraw exists so a re-cancel cannot pay a second bounty.
+#[derive(Accounts)]
+pub struct CloseCancelRecord<'info> {
+    #[account(
+        mut,
+        seeds = [CancelRecord::SEED, cancel_record.transfer_hash.as_ref()],
+        bump = cancel_record.bump,
+        has_one = canceler @ BridgeError::NotRecordedCanceler,
+        close = canceler,
+    )]
+    pub cancel_record: Account<'info, CancelRecord>,
+
+    /// CHECK: address fixed by seeds; only checked for existence
+    #[account(
+        seeds = [PendingWithdraw::SEED, cancel_record.transfer_hash.as_ref()],
+        bump,
+    )]
+    pub pending_withdraw: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub canceler: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CloseCancelRecord>) -> Result<()> {
+    let record = &ctx.accounts.cancel_record;
+    require!(
+        record.reverted || ctx.accounts.pending_withdraw.data_is_empty(),
+        BridgeError::CancelRecordActive
+    );
+
+    emit!(CloseCancelRecordEvent {
+        transfer_hash: record.transfer_hash,
+        canceler: record.canceler,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct CloseCancelRecordEvent {
+    pub transfer_hash: [u8; 32],
+    pub canceler: Pubkey,
+}
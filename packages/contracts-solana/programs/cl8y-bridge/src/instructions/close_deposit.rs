@@ -0,0 +1,75 @@
+use crate::error::BridgeError;
+use crate::state::{DepositHashes, DepositRecord, RelayRequest, DEPOSIT_HASHES_PER_CHUNK};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CloseDeposit<'info> {
+    #[account(
+        mut,
+        close = depositor,
+        seeds = [DepositRecord::SEED, deposit_record.nonce.to_le_bytes().as_ref()],
+        bump = deposit_record.bump,
+    )]
+    pub deposit_record: Account<'info, DepositRecord>,
+
+    /// CHECK: rent goes back to the depositor that paid for the record
+    #[account(mut, address = deposit_record.src_account)]
+    pub depositor: AccountInfo<'info>,
+
+    /// The deposit's relay opt-in, when `request_relay` created one; omit otherwise.
+    #[account(
+        mut,
+        close = depositor,
+        seeds = [RelayRequest::SEED, deposit_record.transfer_hash.as_ref()],
+        bump = relay_request.bump,
+    )]
+    pub relay_request: Option<Account<'info, RelayRequest>>,
+
+    /// Keeps the record's transfer hash so cancelers can still verify the nonce.
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + DepositHashes::INIT_SPACE,
+        seeds = [DepositHashes::SEED, &DepositHashes::chunk_seed(deposit_record.nonce)],
+        bump,
+    )]
+    pub deposit_hashes: Box<Account<'info, DepositHashes>>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Permissionless: close a deposit record after [`DepositRecord::RETENTION`].
+/// Deposit nonces only increase, so a closed record's PDA is never re-created. Its transfer
+/// hash moves into the nonce's [`DepositHashes`] chunk.
+pub fn handler(ctx: Context<CloseDeposit>) -> Result<()> {
+    let deposit = &ctx.accounts.deposit_record;
+    let closable_at = deposit
+        .timestamp
+        .checked_add(DepositRecord::RETENTION)
+        .ok_or(BridgeError::ArithmeticOverflow)?;
+    require!(
+        Clock::get()?.unix_timestamp > closable_at,
+        BridgeError::RetentionNotElapsed
+    );
+
+    let hashes = &mut ctx.accounts.deposit_hashes;
+    hashes.chunk = deposit.nonce / DEPOSIT_HASHES_PER_CHUNK as u64;
+    hashes.bump = ctx.bumps.deposit_hashes;
+    hashes.set(deposit.nonce, deposit.transfer_hash);
+
+    emit!(CloseDepositEvent {
+        transfer_hash: deposit.transfer_hash,
+        nonce: deposit.nonce,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct CloseDepositEvent {
+    pub transfer_hash: [u8; 32],
+    pub nonce: u64,
+}
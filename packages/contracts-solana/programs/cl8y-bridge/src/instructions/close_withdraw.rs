@@ -0,0 +1,158 @@
+use crate::error::BridgeError;
+use crate::hash::compute_transfer_hash;
+use crate::state::{BridgeConfig, ExecutedHash, NonceBitmap, NonceUsed, PendingWithdraw};
+use anchor_lang::prelude::*;
+
+/// Withdraw fields, re-hashed to prove the accounts belong to `transfer_hash`
+/// (the `PendingWithdraw` is usually gone once executed).
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CloseWithdrawParams {
+    pub transfer_hash: [u8; 32],
+    pub src_chain: [u8; 4],
+    pub src_account: [u8; 32],
+    pub dest_account: Pubkey,
+    pub token: Pubkey,
+    pub amount: u128,
+    pub nonce: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(params: CloseWithdrawParams)]
+pub struct CloseWithdraw<'info> {
+    #[account(
+        seeds = [BridgeConfig::SEED],
+        bump = bridge.bump,
+    )]
+    pub bridge: Account<'info, BridgeConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + NonceBitmap::INIT_SPACE,
+        seeds = [
+            NonceBitmap::SEED,
+            params.src_chain.as_ref(),
+            &NonceBitmap::chunk_seed(params.nonce),
+        ],
+        bump,
+    )]
+    pub nonce_bitmap: Box<Account<'info, NonceBitmap>>,
+
+    /// Cancelled withdraw past [`PendingWithdraw::CANCELLED_RETENTION`].
+    #[account(
+        mut,
+        close = recipient,
+        seeds = [PendingWithdraw::SEED, params.transfer_hash.as_ref()],
+        bump = pending_withdraw.bump,
+    )]
+    pub pending_withdraw: Option<Account<'info, PendingWithdraw>>,
+
+    /// Execution receipt; closing it requires the recipient's signature.
+    #[account(
+        mut,
+        close = recipient,
+        seeds = [ExecutedHash::SEED, params.transfer_hash.as_ref()],
+        bump = executed_hash.bump,
+    )]
+    pub executed_hash: Option<Account<'info, ExecutedHash>>,
+
+    /// Legacy per-nonce marker; migrated into `nonce_bitmap` before closing.
+    #[account(
+        mut,
+        close = operator,
+        seeds = [NonceUsed::SEED, params.src_chain.as_ref(), &params.nonce.to_le_bytes()],
+        bump = nonce_used.bump,
+    )]
+    pub nonce_used: Option<Account<'info, NonceUsed>>,
+
+    /// Receives the `ExecutedHash` rent it paid on execute and the rent of a cancelled
+    /// `PendingWithdraw`. Submit may be paid by any account, but `PendingWithdraw` (a deployed
+    /// layout) does not record its payer, so that refund also goes to the recipient.
+    /// CHECK: address checked against the re-hashed withdraw fields
+    #[account(mut, address = params.dest_account)]
+    pub recipient: AccountInfo<'info>,
+
+    /// Paid the legacy `NonceUsed` rent on approval.
+    /// CHECK: address checked against the bridge config
+    #[account(mut, address = bridge.operator)]
+    pub operator: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Close finished withdraw accounts and refund their rent (see `recipient` / `operator`).
+/// Replay protection moves to the nonce bitmap, which must hold the nonce before anything closes.
+pub fn handler(ctx: Context<CloseWithdraw>, params: CloseWithdrawParams) -> Result<()> {
+    let recomputed = compute_transfer_hash(
+        &params.src_chain,
+        &ctx.accounts.bridge.chain_id,
+        &params.src_account,
+        &params.dest_account.to_bytes(),
+        &params.token.to_bytes(),
+        params.amount,
+        params.nonce,
+    );
+    require!(
+        recomputed == params.transfer_hash,
+        BridgeError::HashMismatch
+    );
+
+    let closed_pending = ctx.accounts.pending_withdraw.is_some();
+    let closed_executed = ctx.accounts.executed_hash.is_some();
+    let migrated_nonce = ctx.accounts.nonce_used.is_some();
+    require!(
+        closed_pending || closed_executed || migrated_nonce,
+        BridgeError::NothingToClose
+    );
+
+    let bitmap = &mut ctx.accounts.nonce_bitmap;
+    bitmap.src_chain = params.src_chain;
+    bitmap.chunk = params.nonce / NonceBitmap::NONCES_PER_CHUNK;
+    bitmap.bump = ctx.bumps.nonce_bitmap;
+    if migrated_nonce {
+        bitmap.set(params.nonce);
+    }
+    require!(bitmap.is_set(params.nonce), BridgeError::NonceNotRecorded);
+
+    if let Some(pw) = &ctx.accounts.pending_withdraw {
+        require!(
+            pw.cancelled && !pw.executed,
+            BridgeError::WithdrawNotFinished
+        );
+        let closable_at = pw
+            .approved_at
+            .checked_add(PendingWithdraw::CANCELLED_RETENTION)
+            .ok_or(BridgeError::ArithmeticOverflow)?;
+        require!(
+            Clock::get()?.unix_timestamp > closable_at,
+            BridgeError::RetentionNotElapsed
+        );
+    }
+
+    if closed_executed {
+        require!(
+            ctx.accounts.recipient.is_signer,
+            BridgeError::RecipientSignatureRequired
+        );
+    }
+
+    emit!(CloseWithdrawEvent {
+        transfer_hash: params.transfer_hash,
+        closed_pending,
+        closed_executed,
+        migrated_nonce,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct CloseWithdrawEvent {
+    pub transfer_hash: [u8; 32],
+    pub closed_pending: bool,
+    pub closed_executed: bool,
+    pub migrated_nonce: bool,
+}
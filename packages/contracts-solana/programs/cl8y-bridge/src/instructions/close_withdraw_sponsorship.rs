@@ -0,0 +1,46 @@
+use crate::error::BridgeError;
+use crate::state::{BridgeConfig, PendingWithdraw, WithdrawSponsorship};
+use anchor_lang::prelude::*;
+
+/// Close a [`WithdrawSponsorship`] left behind when its withdraw was not executed by the
+/// operator (executed by the recipient, or cancelled and closed). A recipient execute parks the
+/// `PendingWithdraw` rent (and a native relay fee) in the sponsorship, so it comes back here with
+/// the sponsorship rent.
+#[derive(Accounts)]
+pub struct CloseWithdrawSponsorship<'info> {
+    #[account(
+        seeds = [BridgeConfig::SEED],
+        bump = bridge.bump,
+    )]
+    pub bridge: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        close = operator,
+        seeds = [WithdrawSponsorship::SEED, sponsorship.transfer_hash.as_ref()],
+        bump = sponsorship.bump,
+    )]
+    pub sponsorship: Account<'info, WithdrawSponsorship>,
+
+    /// Must be closed.
+    /// CHECK: We only verify this account does not exist (data is empty)
+    #[account(
+        seeds = [PendingWithdraw::SEED, sponsorship.transfer_hash.as_ref()],
+        bump,
+    )]
+    pub pending_withdraw: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        address = bridge.operator @ BridgeError::UnauthorizedOperator,
+    )]
+    pub operator: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CloseWithdrawSponsorship>) -> Result<()> {
+    require!(
+        ctx.accounts.pending_withdraw.data_is_empty(),
+        BridgeError::WithdrawStillPending
+    );
+    Ok(())
+}
@@ -1,10 +1,14 @@
 use crate::address_codec::pubkey_to_bytes32;
 use crate::error::BridgeError;
-use crate::fee::deposit_fee_and_net;
+use crate::fee::{deposit_fee_and_net, resolve_deposit_fee_bps};
 use crate::hash::compute_transfer_hash;
-use crate::state::{BridgeConfig, ChainEntry, DepositRecord, TokenMapping};
+use crate::state::{
+    BridgeConfig, ChainEntry, CustomAccountFee, DepositRecord, FeeSchedule, TokenFeeOverride,
+    TokenMapping,
+};
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
+use anchor_spl::token_interface::TokenAccount;
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct DepositNativeParams {
@@ -53,16 +57,49 @@ pub struct DepositNative<'info> {
     pub depositor: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// Per-mapping fee override; uninitialized when the mapping uses `BridgeConfig::fee_bps`.
+    /// CHECK: address fixed by seeds; only read when owned by this program (`fee::token_fee_override_bps`)
+    #[account(
+        seeds = [TokenFeeOverride::SEED, token_mapping.key().as_ref()],
+        bump,
+    )]
+    pub token_fee_override: AccountInfo<'info>,
+
+    /// Depositor's custom fee; uninitialized when none is set. Always passed so a custom fee
+    /// above the standard rate cannot be skipped.
+    /// CHECK: address fixed by seeds; only read when owned by this program (`fee::custom_account_fee_bps`)
+    #[account(
+        seeds = [CustomAccountFee::SEED, depositor.key().as_ref()],
+        bump,
+    )]
+    pub custom_account_fee: AccountInfo<'info>,
+
+    /// Optional fee tier accounts (see [`crate::fee`]); omit or pass the program id for `None`.
+    #[account(seeds = [FeeSchedule::SEED], bump = fee_schedule.bump)]
+    pub fee_schedule: Option<Box<Account<'info, FeeSchedule>>>,
+
+    /// Depositor's CL8Y token account for the holder discount.
+    pub cl8y_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 }
 
 pub fn handler(ctx: Context<DepositNative>, params: DepositNativeParams) -> Result<()> {
+    let fee_bps = resolve_deposit_fee_bps(
+        &ctx.accounts.bridge,
+        &ctx.accounts.token_fee_override,
+        &ctx.accounts.custom_account_fee,
+        ctx.accounts.fee_schedule.as_deref().map(|a| &**a),
+        ctx.accounts.cl8y_token_account.as_deref(),
+        &ctx.accounts.depositor.key(),
+    )?;
+
     let bridge = &mut ctx.accounts.bridge;
     require!(!bridge.paused, BridgeError::BridgePaused);
     require!(params.amount > 0, BridgeError::ZeroAmount);
 
     let dest_token = ctx.accounts.token_mapping.dest_token;
 
-    let (fee, net_amount) = deposit_fee_and_net(params.amount, bridge.fee_bps)?;
+    let (fee, net_amount) = deposit_fee_and_net(params.amount, fee_bps)?;
 
     system_program::transfer(
         CpiContext::new(
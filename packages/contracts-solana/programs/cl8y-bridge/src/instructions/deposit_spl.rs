@@ -1,8 +1,12 @@
 use crate::address_codec::pubkey_to_bytes32;
 use crate::error::BridgeError;
-use crate::fee::deposit_fee_and_net;
+use crate::fee::{deposit_fee_and_net, resolve_deposit_fee_bps};
 use crate::hash::compute_transfer_hash;
-use crate::state::{BridgeConfig, ChainEntry, DepositRecord, TokenMapping, TokenMode};
+use crate::state::{
+    BridgeConfig, ChainEntry, CustomAccountFee, DepositRecord, FeeSchedule, TokenFeeOverride,
+    TokenMapping, TokenMode,
+};
+use crate::token_ext::transfer_fee;
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{
     self, Burn, Mint, TokenAccount, TokenInterface, TransferChecked,
@@ -75,18 +79,71 @@ pub struct DepositSpl<'info> {
 
     pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
+
+    /// Per-mapping fee override; uninitialized when the mapping uses `BridgeConfig::fee_bps`.
+    /// CHECK: address fixed by seeds; only read when owned by this program (`fee::token_fee_override_bps`)
+    #[account(
+        seeds = [TokenFeeOverride::SEED, token_mapping.key().as_ref()],
+        bump,
+    )]
+    pub token_fee_override: AccountInfo<'info>,
+
+    /// Depositor's custom fee; uninitialized when none is set. Always passed so a custom fee
+    /// above the standard rate cannot be skipped.
+    /// CHECK: address fixed by seeds; only read when owned by this program (`fee::custom_account_fee_bps`)
+    #[account(
+        seeds = [CustomAccountFee::SEED, depositor.key().as_ref()],
+        bump,
+    )]
+    pub custom_account_fee: AccountInfo<'info>,
+
+    /// Optional fee tier accounts (see [`crate::fee`]); omit or pass the program id for `None`.
+    #[account(seeds = [FeeSchedule::SEED], bump = fee_schedule.bump)]
+    pub fee_schedule: Option<Box<Account<'info, FeeSchedule>>>,
+
+    /// Depositor's CL8Y token account for the holder discount.
+    pub cl8y_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 }
 
 /// SPL deposit: lock/unlock or mint/burn per [`TokenMode`]. See `docs/SOLANA_BRIDGE_INVARIANTS.md` (INV-D1, INV-D3).
 pub fn handler(ctx: Context<DepositSpl>, params: DepositSplParams) -> Result<()> {
+    let fee_bps = resolve_deposit_fee_bps(
+        &ctx.accounts.bridge,
+        &ctx.accounts.token_fee_override,
+        &ctx.accounts.custom_account_fee,
+        ctx.accounts.fee_schedule.as_deref().map(|a| &**a),
+        ctx.accounts.cl8y_token_account.as_deref(),
+        &ctx.accounts.depositor.key(),
+    )?;
+
     let bridge = &mut ctx.accounts.bridge;
     let token_mapping = &mut ctx.accounts.token_mapping;
     require!(!bridge.paused, BridgeError::BridgePaused);
     require!(params.amount > 0, BridgeError::ZeroAmount);
 
-    let (fee, net_amount) = deposit_fee_and_net(params.amount, bridge.fee_bps)?;
-
     let decimals = ctx.accounts.mint.decimals;
+    let mint_info = ctx.accounts.mint.to_account_info();
+    let epoch = Clock::get()?.epoch;
+
+    // Token-2022 transfer fees are withheld from what the bridge receives, so the bridge fee and
+    // transfer hash are taken from the post-fee amount (INV-D3).
+    let (fee, net_amount, fee_received) = match token_mapping.mode {
+        TokenMode::LockUnlock => {
+            let received = params
+                .amount
+                .checked_sub(transfer_fee(&mint_info, epoch, params.amount)?)
+                .ok_or(BridgeError::ArithmeticOverflow)?;
+            let (fee, net_amount) = deposit_fee_and_net(received, fee_bps)?;
+            (fee, net_amount, fee)
+        }
+        TokenMode::MintBurn => {
+            let (fee, net_amount) = deposit_fee_and_net(params.amount, fee_bps)?;
+            let fee_received = fee
+                .checked_sub(transfer_fee(&mint_info, epoch, fee)?)
+                .ok_or(BridgeError::ArithmeticOverflow)?;
+            (fee, net_amount, fee_received)
+        }
+    };
 
     match token_mapping.mode {
         TokenMode::LockUnlock => {
@@ -138,7 +195,7 @@ pub fn handler(ctx: Context<DepositSpl>, params: DepositSplParams) -> Result<()>
 
     token_mapping.accrued_fees = token_mapping
         .accrued_fees
-        .checked_add(fee)
+        .checked_add(fee_received)
         .ok_or(BridgeError::ArithmeticOverflow)?;
 
     bridge.deposit_nonce = bridge
@@ -178,7 +235,8 @@ pub fn handler(ctx: Context<DepositSpl>, params: DepositSplParams) -> Result<()>
         dest_account: params.dest_account,
         token: token_mapping.dest_token,
         amount: net_amount_u128,
-        fee: fee as u128,
+        // What the bridge account actually holds (net of any Token-2022 transfer fee).
+        fee: fee_received as u128,
         nonce,
     });
 
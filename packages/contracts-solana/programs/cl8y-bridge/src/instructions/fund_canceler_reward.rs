@@ -0,0 +1,59 @@
+use crate::error::BridgeError;
+use crate::state::CancelerRewardPool;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+/// Anyone tops up the canceler reward pool with lamports.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct FundCancelerRewardParams {
+    pub amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct FundCancelerReward<'info> {
+    #[account(
+        mut,
+        seeds = [CancelerRewardPool::SEED],
+        bump = reward_pool.bump,
+    )]
+    pub reward_pool: Account<'info, CancelerRewardPool>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<FundCancelerReward>, params: FundCancelerRewardParams) -> Result<()> {
+    require!(params.amount > 0, BridgeError::ZeroAmount);
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.funder.to_account_info(),
+                to: ctx.accounts.reward_pool.to_account_info(),
+            },
+        ),
+        params.amount,
+    )?;
+
+    let pool = &mut ctx.accounts.reward_pool;
+    pool.total_funded = pool
+        .total_funded
+        .checked_add(params.amount)
+        .ok_or(BridgeError::ArithmeticOverflow)?;
+
+    emit!(FundCancelerRewardEvent {
+        funder: ctx.accounts.funder.key(),
+        amount: params.amount,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct FundCancelerRewardEvent {
+    pub funder: Pubkey,
+    pub amount: u64,
+}
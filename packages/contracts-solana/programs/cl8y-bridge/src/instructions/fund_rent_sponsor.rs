@@ -0,0 +1,63 @@
+use crate::error::BridgeError;
+use crate::state::RentSponsor;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+/// Anyone tops up the vault that repays operator-paid `PendingWithdraw` rent
+/// (`withdraw_submit_and_approve` with [`crate::state::RentSource::Sponsor`]).
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct FundRentSponsorParams {
+    pub amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct FundRentSponsor<'info> {
+    #[account(
+        init_if_needed,
+        payer = funder,
+        space = 8 + RentSponsor::INIT_SPACE,
+        seeds = [RentSponsor::SEED],
+        bump,
+    )]
+    pub rent_sponsor: Account<'info, RentSponsor>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<FundRentSponsor>, params: FundRentSponsorParams) -> Result<()> {
+    require!(params.amount > 0, BridgeError::ZeroAmount);
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.funder.to_account_info(),
+                to: ctx.accounts.rent_sponsor.to_account_info(),
+            },
+        ),
+        params.amount,
+    )?;
+
+    let sponsor = &mut ctx.accounts.rent_sponsor;
+    sponsor.bump = ctx.bumps.rent_sponsor;
+    sponsor.total_funded = sponsor
+        .total_funded
+        .checked_add(params.amount)
+        .ok_or(BridgeError::ArithmeticOverflow)?;
+
+    emit!(FundRentSponsorEvent {
+        funder: ctx.accounts.funder.key(),
+        amount: params.amount,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct FundRentSponsorEvent {
+    pub funder: Pubkey,
+    pub amount: u64,
+}
@@ -1,31 +1,75 @@
 pub mod add_canceler;
+pub mod claim_canceler_reward;
+pub mod close_cancel_record;
+pub mod close_deposit;
+pub mod close_withdraw;
+pub mod close_withdraw_sponsorship;
 pub mod deposit_native;
 pub mod deposit_spl;
+pub mod fund_canceler_reward;
+pub mod fund_rent_sponsor;
 pub mod initialize;
 pub mod register_chain;
 pub mod register_token;
+pub mod remove_chain_withdraw_delay;
+pub mod remove_custom_account_fee;
+pub mod remove_large_transfer_delay;
+pub mod remove_token_fee;
+pub mod request_relay;
+pub mod set_canceler_reward;
+pub mod set_chain_withdraw_delay;
 pub mod set_config;
+pub mod set_custom_account_fee;
+pub mod set_fee_schedule;
+pub mod set_large_transfer_delay;
 pub mod set_rate_limit;
+pub mod set_relay_fee_cap;
+pub mod set_token_fee;
 pub mod withdraw_approve;
 pub mod withdraw_cancel;
 pub mod withdraw_execute;
+pub mod withdraw_execute_by_operator;
 pub mod withdraw_execute_native;
+pub mod withdraw_execute_native_by_operator;
 pub mod withdraw_fees;
 pub mod withdraw_reenable;
 pub mod withdraw_submit;
+pub mod withdraw_submit_and_approve;
 
 pub use add_canceler::*;
+pub use claim_canceler_reward::*;
+pub use close_cancel_record::*;
+pub use close_deposit::*;
+pub use close_withdraw::*;
+pub use close_withdraw_sponsorship::*;
 pub use deposit_native::*;
 pub use deposit_spl::*;
+pub use fund_canceler_reward::*;
+pub use fund_rent_sponsor::*;
 pub use initialize::*;
 pub use register_chain::*;
 pub use register_token::*;
+pub use remove_chain_withdraw_delay::*;
+pub use remove_custom_account_fee::*;
+pub use remove_large_transfer_delay::*;
+pub use remove_token_fee::*;
+pub use request_relay::*;
+pub use set_canceler_reward::*;
+pub use set_chain_withdraw_delay::*;
 pub use set_config::*;
+pub use set_custom_account_fee::*;
+pub use set_fee_schedule::*;
+pub use set_large_transfer_delay::*;
 pub use set_rate_limit::*;
+pub use set_relay_fee_cap::*;
+pub use set_token_fee::*;
 pub use withdraw_approve::*;
 pub use withdraw_cancel::*;
 pub use withdraw_execute::*;
+pub use withdraw_execute_by_operator::*;
 pub use withdraw_execute_native::*;
+pub use withdraw_execute_native_by_operator::*;
 pub use withdraw_fees::*;
 pub use withdraw_reenable::*;
 pub use withdraw_submit::*;
+pub use withdraw_submit_and_approve::*;
@@ -1,5 +1,6 @@
 use crate::error::BridgeError;
 use crate::state::{BridgeConfig, TokenMapping, TokenMode};
+use crate::token_ext::check_mint_extensions;
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::Mint;
 
@@ -69,6 +70,7 @@ pub fn handler(ctx: Context<RegisterToken>, params: RegisterTokenParams) -> Resu
             params.decimals == mint.decimals,
             BridgeError::InvalidDecimals
         );
+        check_mint_extensions(&mint.to_account_info())?;
 
         if params.mode == TokenMode::MintBurn {
             let bridge_pda = ctx.accounts.bridge.key();
@@ -89,5 +91,24 @@ pub fn handler(ctx: Context<RegisterToken>, params: RegisterTokenParams) -> Resu
     mapping.accrued_fees = 0;
     mapping.bump = ctx.bumps.token_mapping;
 
+    emit!(RegisterTokenEvent {
+        local_mint: params.local_mint,
+        dest_chain: params.dest_chain,
+        dest_token: params.dest_token,
+        mode: params.mode,
+        decimals: params.decimals,
+        src_decimals: params.src_decimals,
+    });
+
     Ok(())
 }
+
+#[event]
+pub struct RegisterTokenEvent {
+    pub local_mint: Pubkey,
+    pub dest_chain: [u8; 4],
+    pub dest_token: [u8; 32],
+    pub mode: TokenMode,
+    pub decimals: u8,
+    pub src_decimals: u8,
+}
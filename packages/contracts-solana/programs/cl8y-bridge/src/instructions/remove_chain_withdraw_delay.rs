@@ -0,0 +1,39 @@
+use super::set_chain_withdraw_delay::ChainWithdrawDelayEvent;
+use crate::error::BridgeError;
+use crate::state::{BridgeConfig, ChainWithdrawDelay};
+use anchor_lang::prelude::*;
+
+/// Admin removes a per-chain withdraw delay and reclaims its rent.
+#[derive(Accounts)]
+pub struct RemoveChainWithdrawDelay<'info> {
+    #[account(
+        seeds = [BridgeConfig::SEED],
+        bump = bridge.bump,
+    )]
+    pub bridge: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [ChainWithdrawDelay::SEED, chain_withdraw_delay.chain_id.as_ref()],
+        bump = chain_withdraw_delay.bump,
+    )]
+    pub chain_withdraw_delay: Account<'info, ChainWithdrawDelay>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RemoveChainWithdrawDelay>) -> Result<()> {
+    require!(
+        ctx.accounts.admin.key() == ctx.accounts.bridge.admin,
+        BridgeError::UnauthorizedAdmin
+    );
+
+    emit!(ChainWithdrawDelayEvent {
+        chain_id: ctx.accounts.chain_withdraw_delay.chain_id,
+        withdraw_delay: None,
+    });
+
+    Ok(())
+}
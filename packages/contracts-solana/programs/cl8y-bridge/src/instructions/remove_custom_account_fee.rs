@@ -0,0 +1,39 @@
+use super::set_custom_account_fee::CustomAccountFeeEvent;
+use crate::error::BridgeError;
+use crate::state::{BridgeConfig, CustomAccountFee};
+use anchor_lang::prelude::*;
+
+/// Admin removes a per-depositor fee and reclaims its rent (Terra `RemoveCustomAccountFee` parity).
+#[derive(Accounts)]
+pub struct RemoveCustomAccountFee<'info> {
+    #[account(
+        seeds = [BridgeConfig::SEED],
+        bump = bridge.bump,
+    )]
+    pub bridge: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [CustomAccountFee::SEED, custom_account_fee.account.as_ref()],
+        bump = custom_account_fee.bump,
+    )]
+    pub custom_account_fee: Account<'info, CustomAccountFee>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RemoveCustomAccountFee>) -> Result<()> {
+    require!(
+        ctx.accounts.admin.key() == ctx.accounts.bridge.admin,
+        BridgeError::UnauthorizedAdmin
+    );
+
+    emit!(CustomAccountFeeEvent {
+        account: ctx.accounts.custom_account_fee.account,
+        fee_bps: None,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use super::set_large_transfer_delay::LargeTransferDelayEvent;
+use crate::error::BridgeError;
+use crate::state::{BridgeConfig, LargeTransferDelay, TokenMapping};
+use anchor_lang::prelude::*;
+
+/// Admin removes a large-transfer delay and reclaims its rent.
+#[derive(Accounts)]
+pub struct RemoveLargeTransferDelay<'info> {
+    #[account(
+        seeds = [BridgeConfig::SEED],
+        bump = bridge.bump,
+    )]
+    pub bridge: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [TokenMapping::SEED, token_mapping.dest_chain.as_ref(), token_mapping.dest_token.as_ref()],
+        bump = token_mapping.bump,
+    )]
+    pub token_mapping: Account<'info, TokenMapping>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [
+            LargeTransferDelay::SEED,
+            token_mapping.dest_chain.as_ref(),
+            token_mapping.local_mint.as_ref(),
+        ],
+        bump = large_transfer_delay.bump,
+    )]
+    pub large_transfer_delay: Account<'info, LargeTransferDelay>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RemoveLargeTransferDelay>) -> Result<()> {
+    require!(
+        ctx.accounts.admin.key() == ctx.accounts.bridge.admin,
+        BridgeError::UnauthorizedAdmin
+    );
+
+    emit!(LargeTransferDelayEvent {
+        token_mapping: ctx.accounts.token_mapping.key(),
+        threshold: ctx.accounts.large_transfer_delay.threshold,
+        withdraw_delay: None,
+    });
+
+    Ok(())
+}
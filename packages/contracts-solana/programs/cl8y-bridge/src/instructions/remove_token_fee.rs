@@ -0,0 +1,39 @@
+use super::set_token_fee::TokenFeeEvent;
+use crate::error::BridgeError;
+use crate::state::{BridgeConfig, TokenFeeOverride};
+use anchor_lang::prelude::*;
+
+/// Admin removes a per-mapping fee and reclaims its rent.
+#[derive(Accounts)]
+pub struct RemoveTokenFee<'info> {
+    #[account(
+        seeds = [BridgeConfig::SEED],
+        bump = bridge.bump,
+    )]
+    pub bridge: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [TokenFeeOverride::SEED, token_fee_override.token_mapping.as_ref()],
+        bump = token_fee_override.bump,
+    )]
+    pub token_fee_override: Account<'info, TokenFeeOverride>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RemoveTokenFee>) -> Result<()> {
+    require!(
+        ctx.accounts.admin.key() == ctx.accounts.bridge.admin,
+        BridgeError::UnauthorizedAdmin
+    );
+
+    emit!(TokenFeeEvent {
+        token_mapping: ctx.accounts.token_fee_override.token_mapping,
+        fee_bps: None,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use crate::error::BridgeError;
+use crate::state::{BridgeConfig, DepositRecord, RelayRequest};
+use anchor_lang::prelude::*;
+
+/// Depositor asks operators to submit the destination withdrawal of one of its deposits, paying
+/// at most `max_relay_fee` (in the deposit's amount units) out of the withdrawn amount
+/// (EVM `requestRelay` parity). A deposit can only be opted in once.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RequestRelayParams {
+    pub nonce: u64,
+    pub max_relay_fee: u128,
+}
+
+#[derive(Accounts)]
+#[instruction(params: RequestRelayParams)]
+pub struct RequestRelay<'info> {
+    #[account(
+        seeds = [BridgeConfig::SEED],
+        bump = bridge.bump,
+    )]
+    pub bridge: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [DepositRecord::SEED, params.nonce.to_le_bytes().as_ref()],
+        bump = deposit_record.bump,
+        constraint = deposit_record.src_account == depositor.key() @ BridgeError::NotDepositor,
+    )]
+    pub deposit_record: Account<'info, DepositRecord>,
+
+    #[account(
+        init,
+        payer = depositor,
+        space = 8 + RelayRequest::INIT_SPACE,
+        seeds = [RelayRequest::SEED, deposit_record.transfer_hash.as_ref()],
+        bump,
+    )]
+    pub relay_request: Account<'info, RelayRequest>,
+
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RequestRelay>, params: RequestRelayParams) -> Result<()> {
+    require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
+    let deposit = &ctx.accounts.deposit_record;
+    require!(
+        params.max_relay_fee > 0 && params.max_relay_fee < deposit.amount,
+        BridgeError::InvalidMaxRelayFee
+    );
+
+    let request = &mut ctx.accounts.relay_request;
+    request.transfer_hash = deposit.transfer_hash;
+    request.max_relay_fee = params.max_relay_fee;
+    request.bump = ctx.bumps.relay_request;
+
+    emit!(RelayRequestedEvent {
+        transfer_hash: deposit.transfer_hash,
+        depositor: ctx.accounts.depositor.key(),
+        dest_chain: deposit.dest_chain,
+        max_relay_fee: params.max_relay_fee,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct RelayRequestedEvent {
+    pub transfer_hash: [u8; 32],
+    pub depositor: Pubkey,
+    pub dest_chain: [u8; 4],
+    pub max_relay_fee: u128,
+}
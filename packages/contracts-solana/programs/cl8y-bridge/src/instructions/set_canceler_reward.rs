@@ -0,0 +1,62 @@
+use crate::error::BridgeError;
+use crate::state::{BridgeConfig, CancelerRewardPool};
+use anchor_lang::prelude::*;
+
+/// Admin configures the canceler bounty (Terra `SetCancelerRewardConfig` parity).
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetCancelerRewardParams {
+    pub reward_lamports: u64,
+    pub review_period: i64,
+}
+
+#[derive(Accounts)]
+pub struct SetCancelerReward<'info> {
+    #[account(
+        seeds = [BridgeConfig::SEED],
+        bump = bridge.bump,
+    )]
+    pub bridge: Account<'info, BridgeConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + CancelerRewardPool::INIT_SPACE,
+        seeds = [CancelerRewardPool::SEED],
+        bump,
+    )]
+    pub reward_pool: Account<'info, CancelerRewardPool>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetCancelerReward>, params: SetCancelerRewardParams) -> Result<()> {
+    require!(
+        ctx.accounts.admin.key() == ctx.accounts.bridge.admin,
+        BridgeError::UnauthorizedAdmin
+    );
+    require!(
+        params.review_period > 0 && params.review_period <= CancelerRewardPool::MAX_REVIEW_PERIOD,
+        BridgeError::InvalidReviewPeriod
+    );
+
+    let pool = &mut ctx.accounts.reward_pool;
+    pool.reward_lamports = params.reward_lamports;
+    pool.review_period = params.review_period;
+    pool.bump = ctx.bumps.reward_pool;
+
+    emit!(SetCancelerRewardEvent {
+        reward_lamports: params.reward_lamports,
+        review_period: params.review_period,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct SetCancelerRewardEvent {
+    pub reward_lamports: u64,
+    pub review_period: i64,
+}
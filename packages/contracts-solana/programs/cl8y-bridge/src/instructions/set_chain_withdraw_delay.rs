@@ -0,0 +1,73 @@
+use crate::delay::{MAX_WITHDRAW_DELAY, MIN_WITHDRAW_DELAY};
+use crate::error::BridgeError;
+use crate::state::{BridgeConfig, ChainEntry, ChainWithdrawDelay};
+use anchor_lang::prelude::*;
+
+/// Admin sets the withdraw delay for withdrawals from one registered source chain.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetChainWithdrawDelayParams {
+    pub withdraw_delay: i64,
+}
+
+#[derive(Accounts)]
+pub struct SetChainWithdrawDelay<'info> {
+    #[account(
+        seeds = [BridgeConfig::SEED],
+        bump = bridge.bump,
+    )]
+    pub bridge: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [ChainEntry::SEED, chain_entry.chain_id.as_ref()],
+        bump = chain_entry.bump,
+    )]
+    pub chain_entry: Account<'info, ChainEntry>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + ChainWithdrawDelay::INIT_SPACE,
+        seeds = [ChainWithdrawDelay::SEED, chain_entry.chain_id.as_ref()],
+        bump,
+    )]
+    pub chain_withdraw_delay: Account<'info, ChainWithdrawDelay>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<SetChainWithdrawDelay>,
+    params: SetChainWithdrawDelayParams,
+) -> Result<()> {
+    require!(
+        ctx.accounts.admin.key() == ctx.accounts.bridge.admin,
+        BridgeError::UnauthorizedAdmin
+    );
+    require!(
+        (MIN_WITHDRAW_DELAY..=MAX_WITHDRAW_DELAY).contains(&params.withdraw_delay),
+        BridgeError::InvalidWithdrawDelay
+    );
+
+    let chain_id = ctx.accounts.chain_entry.chain_id;
+    let delay = &mut ctx.accounts.chain_withdraw_delay;
+    delay.chain_id = chain_id;
+    delay.withdraw_delay = params.withdraw_delay;
+    delay.bump = ctx.bumps.chain_withdraw_delay;
+
+    emit!(ChainWithdrawDelayEvent {
+        chain_id,
+        withdraw_delay: Some(params.withdraw_delay),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ChainWithdrawDelayEvent {
+    pub chain_id: [u8; 4],
+    /// `None` when removed (chain falls back to `BridgeConfig::withdraw_delay`).
+    pub withdraw_delay: Option<i64>,
+}
@@ -0,0 +1,62 @@
+use crate::error::BridgeError;
+use crate::fee::MAX_FEE_BPS;
+use crate::state::{BridgeConfig, CustomAccountFee};
+use anchor_lang::prelude::*;
+
+/// Admin sets a per-depositor fee (Terra `SetCustomAccountFee` parity).
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetCustomAccountFeeParams {
+    pub account: Pubkey,
+    pub fee_bps: u16,
+}
+
+#[derive(Accounts)]
+#[instruction(params: SetCustomAccountFeeParams)]
+pub struct SetCustomAccountFee<'info> {
+    #[account(
+        seeds = [BridgeConfig::SEED],
+        bump = bridge.bump,
+    )]
+    pub bridge: Account<'info, BridgeConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + CustomAccountFee::INIT_SPACE,
+        seeds = [CustomAccountFee::SEED, params.account.as_ref()],
+        bump,
+    )]
+    pub custom_account_fee: Account<'info, CustomAccountFee>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetCustomAccountFee>, params: SetCustomAccountFeeParams) -> Result<()> {
+    require!(
+        ctx.accounts.admin.key() == ctx.accounts.bridge.admin,
+        BridgeError::UnauthorizedAdmin
+    );
+    require!(params.fee_bps <= MAX_FEE_BPS, BridgeError::InvalidFeeBps);
+
+    let custom = &mut ctx.accounts.custom_account_fee;
+    custom.account = params.account;
+    custom.fee_bps = params.fee_bps;
+    custom.bump = ctx.bumps.custom_account_fee;
+
+    emit!(CustomAccountFeeEvent {
+        account: params.account,
+        fee_bps: Some(params.fee_bps),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct CustomAccountFeeEvent {
+    pub account: Pubkey,
+    /// `None` when removed.
+    pub fee_bps: Option<u16>,
+}
@@ -0,0 +1,68 @@
+use crate::error::BridgeError;
+use crate::fee::MAX_FEE_BPS;
+use crate::state::{BridgeConfig, FeeSchedule};
+use anchor_lang::prelude::*;
+
+/// Admin configures the CL8Y holder discount (Terra `SetFeeParams` discount fields parity).
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetFeeScheduleParams {
+    pub discounted_fee_bps: u16,
+    /// `Pubkey::default()` disables the discount.
+    pub cl8y_mint: Pubkey,
+    pub cl8y_threshold: u64,
+}
+
+#[derive(Accounts)]
+pub struct SetFeeSchedule<'info> {
+    #[account(
+        seeds = [BridgeConfig::SEED],
+        bump = bridge.bump,
+    )]
+    pub bridge: Account<'info, BridgeConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + FeeSchedule::INIT_SPACE,
+        seeds = [FeeSchedule::SEED],
+        bump,
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetFeeSchedule>, params: SetFeeScheduleParams) -> Result<()> {
+    require!(
+        ctx.accounts.admin.key() == ctx.accounts.bridge.admin,
+        BridgeError::UnauthorizedAdmin
+    );
+    require!(
+        params.discounted_fee_bps <= MAX_FEE_BPS,
+        BridgeError::InvalidFeeBps
+    );
+
+    let schedule = &mut ctx.accounts.fee_schedule;
+    schedule.discounted_fee_bps = params.discounted_fee_bps;
+    schedule.cl8y_mint = params.cl8y_mint;
+    schedule.cl8y_threshold = params.cl8y_threshold;
+    schedule.bump = ctx.bumps.fee_schedule;
+
+    emit!(SetFeeScheduleEvent {
+        discounted_fee_bps: params.discounted_fee_bps,
+        cl8y_mint: params.cl8y_mint,
+        cl8y_threshold: params.cl8y_threshold,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct SetFeeScheduleEvent {
+    pub discounted_fee_bps: u16,
+    pub cl8y_mint: Pubkey,
+    pub cl8y_threshold: u64,
+}
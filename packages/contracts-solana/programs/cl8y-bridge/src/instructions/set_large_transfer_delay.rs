@@ -0,0 +1,86 @@
+use crate::delay::{MAX_LARGE_TRANSFER_DELAY, MIN_WITHDRAW_DELAY};
+use crate::error::BridgeError;
+use crate::state::{BridgeConfig, LargeTransferDelay, TokenMapping};
+use anchor_lang::prelude::*;
+
+/// Admin sets the extended withdraw delay for large transfers of one token mapping.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetLargeTransferDelayParams {
+    /// Minimum `PendingWithdraw.amount` (source-chain decimals) that gets `withdraw_delay`.
+    pub threshold: u128,
+    pub withdraw_delay: i64,
+}
+
+#[derive(Accounts)]
+pub struct SetLargeTransferDelay<'info> {
+    #[account(
+        seeds = [BridgeConfig::SEED],
+        bump = bridge.bump,
+    )]
+    pub bridge: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [TokenMapping::SEED, token_mapping.dest_chain.as_ref(), token_mapping.dest_token.as_ref()],
+        bump = token_mapping.bump,
+    )]
+    pub token_mapping: Account<'info, TokenMapping>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + LargeTransferDelay::INIT_SPACE,
+        seeds = [
+            LargeTransferDelay::SEED,
+            token_mapping.dest_chain.as_ref(),
+            token_mapping.local_mint.as_ref(),
+        ],
+        bump,
+    )]
+    pub large_transfer_delay: Account<'info, LargeTransferDelay>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<SetLargeTransferDelay>,
+    params: SetLargeTransferDelayParams,
+) -> Result<()> {
+    require!(
+        ctx.accounts.admin.key() == ctx.accounts.bridge.admin,
+        BridgeError::UnauthorizedAdmin
+    );
+    require!(
+        params.threshold > 0,
+        BridgeError::InvalidLargeTransferThreshold
+    );
+    require!(
+        (MIN_WITHDRAW_DELAY..=MAX_LARGE_TRANSFER_DELAY).contains(&params.withdraw_delay),
+        BridgeError::InvalidWithdrawDelay
+    );
+
+    let token_mapping = ctx.accounts.token_mapping.key();
+    let delay = &mut ctx.accounts.large_transfer_delay;
+    delay.token_mapping = token_mapping;
+    delay.threshold = params.threshold;
+    delay.withdraw_delay = params.withdraw_delay;
+    delay.bump = ctx.bumps.large_transfer_delay;
+
+    emit!(LargeTransferDelayEvent {
+        token_mapping,
+        threshold: params.threshold,
+        withdraw_delay: Some(params.withdraw_delay),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct LargeTransferDelayEvent {
+    pub token_mapping: Pubkey,
+    pub threshold: u128,
+    /// `None` when removed.
+    pub withdraw_delay: Option<i64>,
+}
@@ -49,5 +49,20 @@ pub fn handler(ctx: Context<SetRateLimit>, params: SetRateLimitParams) -> Result
     wr.max_per_period = params.max_per_period;
     wr.bump = ctx.bumps.withdraw_rate_limit;
 
+    emit!(SetRateLimitEvent {
+        local_mint: params.local_mint,
+        min_per_transaction: params.min_per_transaction,
+        max_per_transaction: params.max_per_transaction,
+        max_per_period: params.max_per_period,
+    });
+
     Ok(())
 }
+
+#[event]
+pub struct SetRateLimitEvent {
+    pub local_mint: Pubkey,
+    pub min_per_transaction: u128,
+    pub max_per_transaction: u128,
+    pub max_per_period: u128,
+}
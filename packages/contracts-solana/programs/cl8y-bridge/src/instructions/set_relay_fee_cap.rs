@@ -0,0 +1,58 @@
+use crate::error::BridgeError;
+use crate::state::{BridgeConfig, RelayConfig};
+use anchor_lang::prelude::*;
+
+/// Admin caps relay fees (Terra `SetRelayFeeCap` parity); 0 disables relaying.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetRelayFeeCapParams {
+    pub relay_fee_cap_bps: u16,
+}
+
+#[derive(Accounts)]
+pub struct SetRelayFeeCap<'info> {
+    #[account(
+        seeds = [BridgeConfig::SEED],
+        bump = bridge.bump,
+    )]
+    pub bridge: Account<'info, BridgeConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + RelayConfig::INIT_SPACE,
+        seeds = [RelayConfig::SEED],
+        bump,
+    )]
+    pub relay_config: Account<'info, RelayConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetRelayFeeCap>, params: SetRelayFeeCapParams) -> Result<()> {
+    require!(
+        ctx.accounts.admin.key() == ctx.accounts.bridge.admin,
+        BridgeError::UnauthorizedAdmin
+    );
+    require!(
+        params.relay_fee_cap_bps <= RelayConfig::MAX_RELAY_FEE_BPS,
+        BridgeError::RelayFeeCapExceedsMax
+    );
+
+    let config = &mut ctx.accounts.relay_config;
+    config.relay_fee_cap_bps = params.relay_fee_cap_bps;
+    config.bump = ctx.bumps.relay_config;
+
+    emit!(SetRelayFeeCapEvent {
+        relay_fee_cap_bps: params.relay_fee_cap_bps,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct SetRelayFeeCapEvent {
+    pub relay_fee_cap_bps: u16,
+}
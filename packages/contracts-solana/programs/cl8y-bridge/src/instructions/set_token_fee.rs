@@ -0,0 +1,67 @@
+use crate::error::BridgeError;
+use crate::fee::MAX_FEE_BPS;
+use crate::state::{BridgeConfig, TokenFeeOverride, TokenMapping};
+use anchor_lang::prelude::*;
+
+/// Admin sets the standard fee for one token mapping.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetTokenFeeParams {
+    pub fee_bps: u16,
+}
+
+#[derive(Accounts)]
+pub struct SetTokenFee<'info> {
+    #[account(
+        seeds = [BridgeConfig::SEED],
+        bump = bridge.bump,
+    )]
+    pub bridge: Account<'info, BridgeConfig>,
+
+    #[account(
+        seeds = [TokenMapping::SEED, token_mapping.dest_chain.as_ref(), token_mapping.dest_token.as_ref()],
+        bump = token_mapping.bump,
+    )]
+    pub token_mapping: Account<'info, TokenMapping>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + TokenFeeOverride::INIT_SPACE,
+        seeds = [TokenFeeOverride::SEED, token_mapping.key().as_ref()],
+        bump,
+    )]
+    pub token_fee_override: Account<'info, TokenFeeOverride>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetTokenFee>, params: SetTokenFeeParams) -> Result<()> {
+    require!(
+        ctx.accounts.admin.key() == ctx.accounts.bridge.admin,
+        BridgeError::UnauthorizedAdmin
+    );
+    require!(params.fee_bps <= MAX_FEE_BPS, BridgeError::InvalidFeeBps);
+
+    let token_mapping = ctx.accounts.token_mapping.key();
+    let over = &mut ctx.accounts.token_fee_override;
+    over.token_mapping = token_mapping;
+    over.fee_bps = params.fee_bps;
+    over.bump = ctx.bumps.token_fee_override;
+
+    emit!(TokenFeeEvent {
+        token_mapping,
+        fee_bps: Some(params.fee_bps),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct TokenFeeEvent {
+    pub token_mapping: Pubkey,
+    /// `None` when removed (mapping falls back to `BridgeConfig::fee_bps`).
+    pub fee_bps: Option<u16>,
+}
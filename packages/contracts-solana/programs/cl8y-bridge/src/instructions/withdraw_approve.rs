@@ -1,5 +1,5 @@
 use crate::error::BridgeError;
-use crate::state::{BridgeConfig, NonceUsed, PendingWithdraw};
+use crate::state::{BridgeConfig, NonceBitmap, NonceUsed, PendingWithdraw};
 use anchor_lang::prelude::*;
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -24,10 +24,9 @@ pub struct WithdrawApprove<'info> {
     )]
     pub pending_withdraw: Account<'info, PendingWithdraw>,
 
+    /// Must not exist -- legacy per-nonce marker from approvals before [`NonceBitmap`]
+    /// CHECK: We only verify this account does not exist (data is empty)
     #[account(
-        init,
-        payer = operator,
-        space = 8 + NonceUsed::INIT_SPACE,
         seeds = [
             NonceUsed::SEED,
             pending_withdraw.src_chain.as_ref(),
@@ -35,7 +34,20 @@ pub struct WithdrawApprove<'info> {
         ],
         bump,
     )]
-    pub nonce_used: Account<'info, NonceUsed>,
+    pub nonce_used: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = operator,
+        space = 8 + NonceBitmap::INIT_SPACE,
+        seeds = [
+            NonceBitmap::SEED,
+            pending_withdraw.src_chain.as_ref(),
+            &NonceBitmap::chunk_seed(pending_withdraw.nonce),
+        ],
+        bump,
+    )]
+    pub nonce_bitmap: Box<Account<'info, NonceBitmap>>,
 
     #[account(mut)]
     pub operator: Signer<'info>,
@@ -51,12 +63,8 @@ pub fn handler(ctx: Context<WithdrawApprove>, params: WithdrawApproveParams) ->
         BridgeError::UnauthorizedOperator
     );
 
-    let pw = &mut ctx.accounts.pending_withdraw;
-    require!(!pw.approved, BridgeError::AlreadyApproved);
-    require!(!pw.cancelled, BridgeError::WithdrawalCancelled);
-
     // Forward operator gas (EVM: msg.sender.call{value: operatorGas})
-    let gas = pw.operator_gas;
+    let gas = ctx.accounts.pending_withdraw.operator_gas;
     if gas > 0 {
         let bridge_info = ctx.accounts.bridge.to_account_info();
         let operator_info = ctx.accounts.operator.to_account_info();
@@ -76,16 +84,44 @@ pub fn handler(ctx: Context<WithdrawApprove>, params: WithdrawApproveParams) ->
             .ok_or(BridgeError::ArithmeticOverflow)?;
     }
 
+    record_approval(
+        params.transfer_hash,
+        &mut ctx.accounts.pending_withdraw,
+        &ctx.accounts.nonce_used,
+        &mut ctx.accounts.nonce_bitmap,
+        ctx.bumps.nonce_bitmap,
+    )?;
+
+    Ok(())
+}
+
+/// Approve `pw` and record its nonce in `bitmap` (shared with `withdraw_submit_and_approve`).
+pub(crate) fn record_approval(
+    transfer_hash: [u8; 32],
+    pw: &mut PendingWithdraw,
+    nonce_used: &AccountInfo,
+    bitmap: &mut NonceBitmap,
+    bitmap_bump: u8,
+) -> Result<()> {
+    require!(!pw.approved, BridgeError::AlreadyApproved);
+    require!(!pw.cancelled, BridgeError::WithdrawalCancelled);
+    require!(
+        nonce_used.data_is_empty() && !bitmap.is_set(pw.nonce),
+        BridgeError::NonceAlreadyApproved
+    );
+
     pw.approved = true;
     pw.approved_at = Clock::get()?.unix_timestamp;
 
-    ctx.accounts.nonce_used.bump = ctx.bumps.nonce_used;
+    bitmap.src_chain = pw.src_chain;
+    bitmap.chunk = pw.nonce / NonceBitmap::NONCES_PER_CHUNK;
+    bitmap.bump = bitmap_bump;
+    bitmap.set(pw.nonce);
 
     emit!(WithdrawApproveEvent {
-        transfer_hash: params.transfer_hash,
+        transfer_hash,
         approved_at: pw.approved_at,
     });
-
     Ok(())
 }
 
@@ -1,5 +1,10 @@
+use crate::canceler_reward::record_cancel;
+use crate::delay::resolve_withdraw_delay;
 use crate::error::BridgeError;
-use crate::state::{BridgeConfig, CancelerEntry, PendingWithdraw};
+use crate::state::{
+    BridgeConfig, CancelRecord, CancelerEntry, CancelerRewardPool, ChainWithdrawDelay,
+    LargeTransferDelay, PendingWithdraw,
+};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
@@ -23,7 +28,49 @@ pub struct WithdrawCancel<'info> {
     )]
     pub canceler_entry: Account<'info, CancelerEntry>,
 
+    /// Canceler bounty pool; uninitialized when rewards are not configured.
+    /// CHECK: address fixed by seeds; only read when owned by this program (`canceler_reward::record_cancel`)
+    #[account(
+        mut,
+        seeds = [CancelerRewardPool::SEED],
+        bump,
+    )]
+    pub reward_pool: AccountInfo<'info>,
+
+    /// Reward eligibility for the canceler (see `claim_canceler_reward`); created only while the
+    /// reward pool exists.
+    /// CHECK: address fixed by seeds; created and written by `canceler_reward::record_cancel`
+    #[account(
+        mut,
+        seeds = [CancelRecord::SEED, pending_withdraw.transfer_hash.as_ref()],
+        bump,
+    )]
+    pub cancel_record: AccountInfo<'info>,
+
+    #[account(mut)]
     pub canceler: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Per-source-chain delay; the cancel window matches the one `withdraw_execute` enforces.
+    /// CHECK: address fixed by seeds; only read when owned by this program (`delay::resolve_withdraw_delay`)
+    #[account(
+        seeds = [ChainWithdrawDelay::SEED, pending_withdraw.src_chain.as_ref()],
+        bump,
+    )]
+    pub chain_withdraw_delay: AccountInfo<'info>,
+
+    /// Large-transfer delay for this source chain and token; uninitialized when unset.
+    /// CHECK: address fixed by seeds; only read when owned by this program (`delay::resolve_withdraw_delay`)
+    #[account(
+        seeds = [
+            LargeTransferDelay::SEED,
+            pending_withdraw.src_chain.as_ref(),
+            pending_withdraw.token.as_ref(),
+        ],
+        bump,
+    )]
+    pub large_transfer_delay: AccountInfo<'info>,
 }
 
 pub fn handler(ctx: Context<WithdrawCancel>) -> Result<()> {
@@ -38,15 +85,33 @@ pub fn handler(ctx: Context<WithdrawCancel>) -> Result<()> {
     require!(!pw.cancelled, BridgeError::WithdrawalCancelled);
     require!(pw.approved, BridgeError::NotApproved);
 
+    let withdraw_delay = resolve_withdraw_delay(
+        bridge,
+        &ctx.accounts.chain_withdraw_delay,
+        &ctx.accounts.large_transfer_delay,
+        pw.amount,
+    )?;
     let now = Clock::get()?.unix_timestamp;
     let window_end = pw
         .approved_at
-        .checked_add(bridge.withdraw_delay)
+        .checked_add(withdraw_delay)
         .ok_or(BridgeError::ArithmeticOverflow)?;
     require!(now <= window_end, BridgeError::CancelWindowExpired);
 
     pw.cancelled = true;
 
+    // An existing unclaimed record belongs to a cancel that `withdraw_reenable` reverted
+    // (the withdraw was not cancelled when we got here), so this canceler takes it over.
+    record_cancel(
+        &ctx.accounts.reward_pool,
+        &ctx.accounts.cancel_record,
+        &ctx.accounts.canceler.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        pw.transfer_hash,
+        ctx.bumps.cancel_record,
+        now,
+    )?;
+
     emit!(WithdrawCancelEvent {
         transfer_hash: pw.transfer_hash,
         canceler: ctx.accounts.canceler.key(),
@@ -1,13 +1,15 @@
 use crate::decimal::normalize_decimals;
 use crate::error::BridgeError;
-use crate::hash::compute_transfer_hash;
+use crate::execute::{
+    check_executable, consume_withdraw_rate_limit, load_sponsorship, relay_fee_amount,
+    relayer_token_account, release_spl, settle_sponsorship,
+};
 use crate::state::{
-    BridgeConfig, ExecutedHash, PendingWithdraw, TokenMapping, TokenMode, WithdrawRateLimit,
+    BridgeConfig, ChainWithdrawDelay, ExecutedHash, LargeTransferDelay, PendingWithdraw,
+    TokenMapping, WithdrawRateLimit, WithdrawSponsorship,
 };
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{
-    self, Mint, MintTo, TokenAccount, TokenInterface, TransferChecked,
-};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 /// SPL (or Token-2022) withdrawal execution: lock/unlock transfer or mint. See `docs/SOLANA_BRIDGE_INVARIANTS.md` (INV-W2, INV-D1).
 #[derive(Accounts)]
@@ -78,48 +80,54 @@ pub struct WithdrawExecute<'info> {
 
     pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
+
+    /// Per-source-chain delay; uninitialized when the chain uses `BridgeConfig::withdraw_delay`.
+    /// CHECK: address fixed by seeds; only read when owned by this program (`delay::resolve_withdraw_delay`)
+    #[account(
+        seeds = [ChainWithdrawDelay::SEED, pending_withdraw.src_chain.as_ref()],
+        bump,
+    )]
+    pub chain_withdraw_delay: AccountInfo<'info>,
+
+    /// Large-transfer delay for this source chain and token; uninitialized when unset.
+    /// CHECK: address fixed by seeds; only read when owned by this program (`delay::resolve_withdraw_delay`)
+    #[account(
+        seeds = [
+            LargeTransferDelay::SEED,
+            pending_withdraw.src_chain.as_ref(),
+            pending_withdraw.token.as_ref(),
+        ],
+        bump,
+    )]
+    pub large_transfer_delay: AccountInfo<'info>,
+
+    /// Operator rent record from `withdraw_submit_and_approve`; uninitialized otherwise.
+    /// CHECK: address fixed by seeds; only read when owned by this program (`execute::load_sponsorship`)
+    #[account(
+        mut,
+        seeds = [WithdrawSponsorship::SEED, pending_withdraw.transfer_hash.as_ref()],
+        bump,
+    )]
+    pub sponsorship: AccountInfo<'info>,
+
+    /// Relaying operator's token account for `mint`; required when the withdraw was relayed
+    /// with a fee (`execute::relayer_token_account`), omit otherwise.
+    #[account(mut)]
+    pub relayer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 }
 
 pub fn handler(ctx: Context<WithdrawExecute>) -> Result<()> {
-    let bridge = &ctx.accounts.bridge;
-
-    require!(!bridge.paused, BridgeError::BridgePaused);
-
-    {
-        let pw = &ctx.accounts.pending_withdraw;
-        require!(!pw.cancelled, BridgeError::WithdrawalCancelled);
-        require!(pw.approved, BridgeError::NotApproved);
-        require!(!pw.executed, BridgeError::AlreadyExecuted);
-        require!(
-            pw.dest_account == ctx.accounts.recipient.key(),
-            BridgeError::WrongRecipient
-        );
-        require!(
-            pw.token == ctx.accounts.mint.key(),
-            BridgeError::TokenMintMismatch
-        );
-
-        let recomputed = compute_transfer_hash(
-            &pw.src_chain,
-            &bridge.chain_id,
-            &pw.src_account,
-            &pw.dest_account.to_bytes(),
-            &pw.token.to_bytes(),
-            pw.amount,
-            pw.nonce,
-        );
-        require!(recomputed == pw.transfer_hash, BridgeError::HashMismatch);
-
-        let clock = Clock::get()?;
-        let window_end = pw
-            .approved_at
-            .checked_add(bridge.withdraw_delay)
-            .ok_or(BridgeError::ArithmeticOverflow)?;
-        require!(
-            clock.unix_timestamp > window_end,
-            BridgeError::DelayNotElapsed
-        );
-    }
+    check_executable(
+        &ctx.accounts.bridge,
+        &ctx.accounts.pending_withdraw,
+        &ctx.accounts.recipient.key(),
+        &ctx.accounts.chain_withdraw_delay,
+        &ctx.accounts.large_transfer_delay,
+    )?;
+    require!(
+        ctx.accounts.pending_withdraw.token == ctx.accounts.mint.key(),
+        BridgeError::TokenMintMismatch
+    );
 
     let amount_u128 = normalize_decimals(
         ctx.accounts.pending_withdraw.amount,
@@ -130,31 +138,12 @@ pub fn handler(ctx: Context<WithdrawExecute>) -> Result<()> {
         .try_into()
         .map_err(|_| BridgeError::AmountExceedsU64)?;
 
-    {
-        let wr = &mut ctx.accounts.withdraw_rate_limit;
-        wr.bump = ctx.bumps.withdraw_rate_limit;
-        let supply = ctx.accounts.mint.supply as u128;
-        let (min_tx, max_tx, max_period) = crate::rate_limit::resolve_effective_limits(
-            wr.explicit_config,
-            wr.min_per_transaction,
-            wr.max_per_transaction,
-            wr.max_per_period,
-            supply,
-        );
-        let mut window_start = wr.window_start;
-        let mut used = wr.used;
-        crate::rate_limit::check_and_update_withdraw_rate_limit(
-            Clock::get()?.unix_timestamp,
-            amount_u128,
-            min_tx,
-            max_tx,
-            max_period,
-            &mut window_start,
-            &mut used,
-        )?;
-        wr.window_start = window_start;
-        wr.used = used;
-    }
+    consume_withdraw_rate_limit(
+        &mut ctx.accounts.withdraw_rate_limit,
+        ctx.bumps.withdraw_rate_limit,
+        ctx.accounts.mint.supply as u128,
+        amount_u128,
+    )?;
 
     let pw = &mut ctx.accounts.pending_withdraw;
     pw.executed = true;
@@ -163,46 +152,53 @@ pub fn handler(ctx: Context<WithdrawExecute>) -> Result<()> {
 
     ctx.accounts.executed_hash.bump = ctx.bumps.executed_hash;
 
-    let bridge_seeds: &[&[u8]] = &[BridgeConfig::SEED, &[bridge.bump]];
-    let decimals = ctx.accounts.mint.decimals;
-
-    match ctx.accounts.token_mapping.mode {
-        TokenMode::LockUnlock => {
-            token_interface::transfer_checked(
-                CpiContext::new_with_signer(
-                    ctx.accounts.token_program.to_account_info(),
-                    TransferChecked {
-                        from: ctx.accounts.bridge_token_account.to_account_info(),
-                        mint: ctx.accounts.mint.to_account_info(),
-                        to: ctx.accounts.recipient_token_account.to_account_info(),
-                        authority: bridge.to_account_info(),
-                    },
-                    &[bridge_seeds],
-                ),
-                amount,
-                decimals,
-            )?;
-        }
-        TokenMode::MintBurn => {
-            token_interface::mint_to(
-                CpiContext::new_with_signer(
-                    ctx.accounts.token_program.to_account_info(),
-                    MintTo {
-                        mint: ctx.accounts.mint.to_account_info(),
-                        to: ctx.accounts.recipient_token_account.to_account_info(),
-                        authority: bridge.to_account_info(),
-                    },
-                    &[bridge_seeds],
-                ),
-                amount,
-            )?;
-        }
+    let sponsorship = load_sponsorship(&ctx.accounts.sponsorship, &transfer_hash)?;
+    let relay_fee = relay_fee_amount(sponsorship.as_ref(), &ctx.accounts.pending_withdraw)?;
+    let payout = amount
+        .checked_sub(relay_fee)
+        .ok_or(BridgeError::ArithmeticOverflow)?;
+    settle_sponsorship(
+        &ctx.accounts.sponsorship,
+        sponsorship.as_ref(),
+        &ctx.accounts.pending_withdraw.to_account_info(),
+        None,
+    )?;
+    if relay_fee > 0 {
+        let relayer_token_account = relayer_token_account(
+            sponsorship.as_ref(),
+            ctx.accounts.relayer_token_account.as_deref(),
+            &ctx.accounts.mint.key(),
+        )?;
+        release_spl(
+            ctx.accounts.token_mapping.mode,
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.bridge_token_account.to_account_info(),
+            relayer_token_account.to_account_info(),
+            ctx.accounts.bridge.to_account_info(),
+            ctx.accounts.bridge.bump,
+            relay_fee,
+            ctx.accounts.mint.decimals,
+        )?;
+        emit_relay_fee_paid(sponsorship.as_ref(), transfer_hash, relay_fee);
     }
 
+    release_spl(
+        ctx.accounts.token_mapping.mode,
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.bridge_token_account.to_account_info(),
+        ctx.accounts.recipient_token_account.to_account_info(),
+        ctx.accounts.bridge.to_account_info(),
+        ctx.accounts.bridge.bump,
+        payout,
+        ctx.accounts.mint.decimals,
+    )?;
+
     emit!(WithdrawExecuteEvent {
         transfer_hash,
         recipient: dest_account,
-        amount: amount_u128,
+        amount: u128::from(payout),
     });
 
     Ok(())
@@ -214,3 +210,27 @@ pub struct WithdrawExecuteEvent {
     pub recipient: Pubkey,
     pub amount: u128,
 }
+
+/// Relay fee paid to the operator that relayed the withdraw, in destination decimals
+/// (EVM `RelayFeePaid`). `WithdrawExecuteEvent` reports only the recipient's share.
+#[event]
+pub struct RelayFeePaidEvent {
+    pub transfer_hash: [u8; 32],
+    pub relayer: Pubkey,
+    pub amount: u128,
+}
+
+/// Emit [`RelayFeePaidEvent`] for a relay fee paid out on execute
+pub(crate) fn emit_relay_fee_paid(
+    sponsorship: Option<&WithdrawSponsorship>,
+    transfer_hash: [u8; 32],
+    relay_fee: u64,
+) {
+    if let Some(record) = sponsorship.filter(|_| relay_fee > 0) {
+        emit!(RelayFeePaidEvent {
+            transfer_hash,
+            relayer: record.operator,
+            amount: u128::from(relay_fee),
+        });
+    }
+}
@@ -0,0 +1,223 @@
+use crate::decimal::normalize_decimals;
+use crate::error::BridgeError;
+use crate::execute::{
+    check_executable, consume_withdraw_rate_limit, load_sponsorship, relay_fee_amount,
+    relayer_token_account, release_spl, settle_sponsorship,
+};
+use crate::instructions::withdraw_execute::{emit_relay_fee_paid, WithdrawExecuteEvent};
+use crate::state::{
+    BridgeConfig, ChainWithdrawDelay, ExecutedHash, LargeTransferDelay, PendingWithdraw,
+    TokenMapping, WithdrawRateLimit, WithdrawSponsorship,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// [`super::WithdrawExecute`] submitted by the bridge operator after the cancel window, so
+/// recipients do not have to execute themselves. The operator pays rent for the `ExecutedHash`,
+/// rate-limit and (when missing) recipient token accounts; tokens and the `PendingWithdraw` rent
+/// still go to the recipient, except that rent the operator paid itself
+/// (`withdraw_submit_and_approve`) goes back to it through the `WithdrawSponsorship`.
+#[derive(Accounts)]
+pub struct WithdrawExecuteByOperator<'info> {
+    #[account(
+        seeds = [BridgeConfig::SEED],
+        bump = bridge.bump,
+    )]
+    pub bridge: Box<Account<'info, BridgeConfig>>,
+
+    #[account(
+        mut,
+        seeds = [PendingWithdraw::SEED, pending_withdraw.transfer_hash.as_ref()],
+        bump = pending_withdraw.bump,
+        close = recipient,
+    )]
+    pub pending_withdraw: Box<Account<'info, PendingWithdraw>>,
+
+    #[account(
+        init,
+        payer = operator,
+        space = 8 + ExecutedHash::INIT_SPACE,
+        seeds = [ExecutedHash::SEED, pending_withdraw.transfer_hash.as_ref()],
+        bump,
+    )]
+    pub executed_hash: Box<Account<'info, ExecutedHash>>,
+
+    #[account(
+        mut,
+        constraint = mint.key() == token_mapping.local_mint @ BridgeError::TokenNotRegistered
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = operator,
+        associated_token::mint = mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program,
+    )]
+    pub recipient_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Bridge-owned token account for lock/unlock mode
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = bridge,
+        associated_token::token_program = token_program,
+    )]
+    pub bridge_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [TokenMapping::SEED, pending_withdraw.src_chain.as_ref(), token_mapping.dest_token.as_ref()],
+        bump = token_mapping.bump,
+    )]
+    pub token_mapping: Box<Account<'info, TokenMapping>>,
+
+    #[account(
+        init_if_needed,
+        payer = operator,
+        space = 8 + WithdrawRateLimit::INIT_SPACE,
+        seeds = [WithdrawRateLimit::SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub withdraw_rate_limit: Box<Account<'info, WithdrawRateLimit>>,
+
+    /// CHECK: must be the withdraw's `dest_account`; receives the `PendingWithdraw` rent
+    #[account(
+        mut,
+        address = pending_withdraw.dest_account @ BridgeError::WrongRecipient,
+    )]
+    pub recipient: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = bridge.operator @ BridgeError::UnauthorizedOperator,
+    )]
+    pub operator: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+
+    /// Per-source-chain delay; uninitialized when the chain uses `BridgeConfig::withdraw_delay`.
+    /// CHECK: address fixed by seeds; only read when owned by this program (`delay::resolve_withdraw_delay`)
+    #[account(
+        seeds = [ChainWithdrawDelay::SEED, pending_withdraw.src_chain.as_ref()],
+        bump,
+    )]
+    pub chain_withdraw_delay: AccountInfo<'info>,
+
+    /// Large-transfer delay for this source chain and token; uninitialized when unset.
+    /// CHECK: address fixed by seeds; only read when owned by this program (`delay::resolve_withdraw_delay`)
+    #[account(
+        seeds = [
+            LargeTransferDelay::SEED,
+            pending_withdraw.src_chain.as_ref(),
+            pending_withdraw.token.as_ref(),
+        ],
+        bump,
+    )]
+    pub large_transfer_delay: AccountInfo<'info>,
+
+    /// Operator rent record from `withdraw_submit_and_approve`; uninitialized otherwise.
+    /// CHECK: address fixed by seeds; only read when owned by this program (`execute::load_sponsorship`)
+    #[account(
+        mut,
+        seeds = [WithdrawSponsorship::SEED, pending_withdraw.transfer_hash.as_ref()],
+        bump,
+    )]
+    pub sponsorship: AccountInfo<'info>,
+
+    /// Relaying operator's token account for `mint`; required when the withdraw was relayed
+    /// with a fee (`execute::relayer_token_account`), omit otherwise.
+    #[account(mut)]
+    pub relayer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+}
+
+pub fn handler(ctx: Context<WithdrawExecuteByOperator>) -> Result<()> {
+    check_executable(
+        &ctx.accounts.bridge,
+        &ctx.accounts.pending_withdraw,
+        &ctx.accounts.recipient.key(),
+        &ctx.accounts.chain_withdraw_delay,
+        &ctx.accounts.large_transfer_delay,
+    )?;
+    require!(
+        ctx.accounts.pending_withdraw.token == ctx.accounts.mint.key(),
+        BridgeError::TokenMintMismatch
+    );
+
+    let amount_u128 = normalize_decimals(
+        ctx.accounts.pending_withdraw.amount,
+        ctx.accounts.pending_withdraw.src_decimals,
+        ctx.accounts.pending_withdraw.dest_decimals,
+    )?;
+    let amount: u64 = amount_u128
+        .try_into()
+        .map_err(|_| BridgeError::AmountExceedsU64)?;
+
+    consume_withdraw_rate_limit(
+        &mut ctx.accounts.withdraw_rate_limit,
+        ctx.bumps.withdraw_rate_limit,
+        ctx.accounts.mint.supply as u128,
+        amount_u128,
+    )?;
+
+    let pw = &mut ctx.accounts.pending_withdraw;
+    pw.executed = true;
+    let transfer_hash = pw.transfer_hash;
+    let dest_account = pw.dest_account;
+
+    ctx.accounts.executed_hash.bump = ctx.bumps.executed_hash;
+
+    let sponsorship = load_sponsorship(&ctx.accounts.sponsorship, &transfer_hash)?;
+    let relay_fee = relay_fee_amount(sponsorship.as_ref(), &ctx.accounts.pending_withdraw)?;
+    let payout = amount
+        .checked_sub(relay_fee)
+        .ok_or(BridgeError::ArithmeticOverflow)?;
+    settle_sponsorship(
+        &ctx.accounts.sponsorship,
+        sponsorship.as_ref(),
+        &ctx.accounts.pending_withdraw.to_account_info(),
+        Some(&ctx.accounts.operator.to_account_info()),
+    )?;
+    if relay_fee > 0 {
+        let relayer_token_account = relayer_token_account(
+            sponsorship.as_ref(),
+            ctx.accounts.relayer_token_account.as_deref(),
+            &ctx.accounts.mint.key(),
+        )?;
+        release_spl(
+            ctx.accounts.token_mapping.mode,
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.bridge_token_account.to_account_info(),
+            relayer_token_account.to_account_info(),
+            ctx.accounts.bridge.to_account_info(),
+            ctx.accounts.bridge.bump,
+            relay_fee,
+            ctx.accounts.mint.decimals,
+        )?;
+        emit_relay_fee_paid(sponsorship.as_ref(), transfer_hash, relay_fee);
+    }
+
+    release_spl(
+        ctx.accounts.token_mapping.mode,
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.bridge_token_account.to_account_info(),
+        ctx.accounts.recipient_token_account.to_account_info(),
+        ctx.accounts.bridge.to_account_info(),
+        ctx.accounts.bridge.bump,
+        payout,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    emit!(WithdrawExecuteEvent {
+        transfer_hash,
+        recipient: dest_account,
+        amount: u128::from(payout),
+    });
+
+    Ok(())
+}
@@ -1,8 +1,13 @@
 use crate::decimal::normalize_decimals;
 use crate::error::BridgeError;
-use crate::hash::compute_transfer_hash;
+use crate::execute::{
+    check_executable, consume_withdraw_rate_limit, load_sponsorship, relay_fee_amount,
+    release_native, settle_sponsorship,
+};
+use crate::instructions::withdraw_execute::emit_relay_fee_paid;
 use crate::state::{
-    BridgeConfig, ExecutedHash, PendingWithdraw, WithdrawRateLimit, NATIVE_SOL_TOKEN,
+    BridgeConfig, ChainWithdrawDelay, ExecutedHash, LargeTransferDelay, PendingWithdraw,
+    WithdrawRateLimit, WithdrawSponsorship, NATIVE_SOL_TOKEN,
 };
 use anchor_lang::prelude::*;
 
@@ -45,48 +50,49 @@ pub struct WithdrawExecuteNative<'info> {
     pub recipient: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// Per-source-chain delay; uninitialized when the chain uses `BridgeConfig::withdraw_delay`.
+    /// CHECK: address fixed by seeds; only read when owned by this program (`delay::resolve_withdraw_delay`)
+    #[account(
+        seeds = [ChainWithdrawDelay::SEED, pending_withdraw.src_chain.as_ref()],
+        bump,
+    )]
+    pub chain_withdraw_delay: AccountInfo<'info>,
+
+    /// Large-transfer delay for this source chain and token; uninitialized when unset.
+    /// CHECK: address fixed by seeds; only read when owned by this program (`delay::resolve_withdraw_delay`)
+    #[account(
+        seeds = [
+            LargeTransferDelay::SEED,
+            pending_withdraw.src_chain.as_ref(),
+            pending_withdraw.token.as_ref(),
+        ],
+        bump,
+    )]
+    pub large_transfer_delay: AccountInfo<'info>,
+
+    /// Operator rent record from `withdraw_submit_and_approve`; uninitialized otherwise.
+    /// CHECK: address fixed by seeds; only read when owned by this program (`execute::load_sponsorship`)
+    #[account(
+        mut,
+        seeds = [WithdrawSponsorship::SEED, pending_withdraw.transfer_hash.as_ref()],
+        bump,
+    )]
+    pub sponsorship: AccountInfo<'info>,
 }
 
 pub fn handler(ctx: Context<WithdrawExecuteNative>) -> Result<()> {
-    let bridge = &ctx.accounts.bridge;
-
-    require!(!bridge.paused, BridgeError::BridgePaused);
-
-    {
-        let pw = &ctx.accounts.pending_withdraw;
-        require!(!pw.cancelled, BridgeError::WithdrawalCancelled);
-        require!(pw.approved, BridgeError::NotApproved);
-        require!(!pw.executed, BridgeError::AlreadyExecuted);
-        require!(
-            pw.dest_account == ctx.accounts.recipient.key(),
-            BridgeError::WrongRecipient
-        );
-        require!(
-            pw.token == NATIVE_SOL_TOKEN,
-            BridgeError::NotNativeToken
-        );
-
-        let recomputed = compute_transfer_hash(
-            &pw.src_chain,
-            &bridge.chain_id,
-            &pw.src_account,
-            &pw.dest_account.to_bytes(),
-            &pw.token.to_bytes(),
-            pw.amount,
-            pw.nonce,
-        );
-        require!(recomputed == pw.transfer_hash, BridgeError::HashMismatch);
-
-        let clock = Clock::get()?;
-        let window_end = pw
-            .approved_at
-            .checked_add(bridge.withdraw_delay)
-            .ok_or(BridgeError::ArithmeticOverflow)?;
-        require!(
-            clock.unix_timestamp > window_end,
-            BridgeError::DelayNotElapsed
-        );
-    }
+    check_executable(
+        &ctx.accounts.bridge,
+        &ctx.accounts.pending_withdraw,
+        &ctx.accounts.recipient.key(),
+        &ctx.accounts.chain_withdraw_delay,
+        &ctx.accounts.large_transfer_delay,
+    )?;
+    require!(
+        ctx.accounts.pending_withdraw.token == NATIVE_SOL_TOKEN,
+        BridgeError::NotNativeToken
+    );
 
     let amount_u128 = normalize_decimals(
         ctx.accounts.pending_withdraw.amount,
@@ -97,32 +103,13 @@ pub fn handler(ctx: Context<WithdrawExecuteNative>) -> Result<()> {
         .try_into()
         .map_err(|_| BridgeError::AmountExceedsU64)?;
 
-    {
-        let wr = &mut ctx.accounts.withdraw_rate_limit;
-        wr.bump = ctx.bumps.withdraw_rate_limit;
-        // No on-chain total supply for native SOL; Terra uses bank supply — match implicit-default path (zero supply).
-        let supply = 0u128;
-        let (min_tx, max_tx, max_period) = crate::rate_limit::resolve_effective_limits(
-            wr.explicit_config,
-            wr.min_per_transaction,
-            wr.max_per_transaction,
-            wr.max_per_period,
-            supply,
-        );
-        let mut window_start = wr.window_start;
-        let mut used = wr.used;
-        crate::rate_limit::check_and_update_withdraw_rate_limit(
-            Clock::get()?.unix_timestamp,
-            amount_u128,
-            min_tx,
-            max_tx,
-            max_period,
-            &mut window_start,
-            &mut used,
-        )?;
-        wr.window_start = window_start;
-        wr.used = used;
-    }
+    // No on-chain total supply for native SOL; Terra uses bank supply — match implicit-default path (zero supply).
+    consume_withdraw_rate_limit(
+        &mut ctx.accounts.withdraw_rate_limit,
+        ctx.bumps.withdraw_rate_limit,
+        0,
+        amount_u128,
+    )?;
 
     let pw = &mut ctx.accounts.pending_withdraw;
     pw.executed = true;
@@ -131,25 +118,38 @@ pub fn handler(ctx: Context<WithdrawExecuteNative>) -> Result<()> {
 
     ctx.accounts.executed_hash.bump = ctx.bumps.executed_hash;
 
-    // Transfer native SOL from bridge PDA to recipient
-    let bridge_info = ctx.accounts.bridge.to_account_info();
-    let recipient_info = ctx.accounts.recipient.to_account_info();
-    let rent_exempt = Rent::get()?.minimum_balance(8 + BridgeConfig::INIT_SPACE);
-    let available = bridge_info.lamports().saturating_sub(rent_exempt);
-    require!(available >= amount, BridgeError::InsufficientBridgeBalance);
-    **bridge_info.try_borrow_mut_lamports()? = bridge_info
-        .lamports()
-        .checked_sub(amount)
-        .ok_or(BridgeError::ArithmeticOverflow)?;
-    **recipient_info.try_borrow_mut_lamports()? = recipient_info
-        .lamports()
-        .checked_add(amount)
+    let sponsorship = load_sponsorship(&ctx.accounts.sponsorship, &transfer_hash)?;
+    let relay_fee = relay_fee_amount(sponsorship.as_ref(), &ctx.accounts.pending_withdraw)?;
+    let payout = amount
+        .checked_sub(relay_fee)
         .ok_or(BridgeError::ArithmeticOverflow)?;
+    if relay_fee > 0 {
+        // Paid to the operator together with the rent it is owed (see `settle_sponsorship`)
+        release_native(
+            &ctx.accounts.bridge.to_account_info(),
+            &ctx.accounts.sponsorship,
+            relay_fee,
+        )?;
+        emit_relay_fee_paid(sponsorship.as_ref(), transfer_hash, relay_fee);
+    }
+    settle_sponsorship(
+        &ctx.accounts.sponsorship,
+        sponsorship.as_ref(),
+        &ctx.accounts.pending_withdraw.to_account_info(),
+        None,
+    )?;
+
+    // Transfer native SOL from bridge PDA to recipient
+    release_native(
+        &ctx.accounts.bridge.to_account_info(),
+        &ctx.accounts.recipient.to_account_info(),
+        payout,
+    )?;
 
     emit!(WithdrawExecuteNativeEvent {
         transfer_hash,
         recipient: dest_account,
-        amount: amount_u128,
+        amount: u128::from(payout),
     });
 
     Ok(())
@@ -0,0 +1,169 @@
+use crate::decimal::normalize_decimals;
+use crate::error::BridgeError;
+use crate::execute::{
+    check_executable, consume_withdraw_rate_limit, load_sponsorship, relay_fee_amount,
+    release_native, settle_sponsorship,
+};
+use crate::instructions::withdraw_execute::emit_relay_fee_paid;
+use crate::instructions::withdraw_execute_native::WithdrawExecuteNativeEvent;
+use crate::state::{
+    BridgeConfig, ChainWithdrawDelay, ExecutedHash, LargeTransferDelay, PendingWithdraw,
+    WithdrawRateLimit, WithdrawSponsorship, NATIVE_SOL_TOKEN,
+};
+use anchor_lang::prelude::*;
+
+/// [`super::WithdrawExecuteNative`] submitted by the bridge operator after the cancel window.
+/// The operator pays rent for the `ExecutedHash` and rate-limit accounts; lamports and the
+/// `PendingWithdraw` rent go to the recipient (rent the operator paid itself is settled as in
+/// [`super::WithdrawExecuteByOperator`]).
+#[derive(Accounts)]
+pub struct WithdrawExecuteNativeByOperator<'info> {
+    #[account(
+        mut,
+        seeds = [BridgeConfig::SEED],
+        bump = bridge.bump,
+    )]
+    pub bridge: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        seeds = [PendingWithdraw::SEED, pending_withdraw.transfer_hash.as_ref()],
+        bump = pending_withdraw.bump,
+        close = recipient,
+    )]
+    pub pending_withdraw: Account<'info, PendingWithdraw>,
+
+    #[account(
+        init,
+        payer = operator,
+        space = 8 + ExecutedHash::INIT_SPACE,
+        seeds = [ExecutedHash::SEED, pending_withdraw.transfer_hash.as_ref()],
+        bump,
+    )]
+    pub executed_hash: Account<'info, ExecutedHash>,
+
+    #[account(
+        init_if_needed,
+        payer = operator,
+        space = 8 + WithdrawRateLimit::INIT_SPACE,
+        seeds = [WithdrawRateLimit::SEED, NATIVE_SOL_TOKEN.as_ref()],
+        bump,
+    )]
+    pub withdraw_rate_limit: Account<'info, WithdrawRateLimit>,
+
+    /// CHECK: must be the withdraw's `dest_account`; receives the lamports and `PendingWithdraw` rent
+    #[account(
+        mut,
+        address = pending_withdraw.dest_account @ BridgeError::WrongRecipient,
+    )]
+    pub recipient: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = bridge.operator @ BridgeError::UnauthorizedOperator,
+    )]
+    pub operator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Per-source-chain delay; uninitialized when the chain uses `BridgeConfig::withdraw_delay`.
+    /// CHECK: address fixed by seeds; only read when owned by this program (`delay::resolve_withdraw_delay`)
+    #[account(
+        seeds = [ChainWithdrawDelay::SEED, pending_withdraw.src_chain.as_ref()],
+        bump,
+    )]
+    pub chain_withdraw_delay: AccountInfo<'info>,
+
+    /// Large-transfer delay for this source chain and token; uninitialized when unset.
+    /// CHECK: address fixed by seeds; only read when owned by this program (`delay::resolve_withdraw_delay`)
+    #[account(
+        seeds = [
+            LargeTransferDelay::SEED,
+            pending_withdraw.src_chain.as_ref(),
+            pending_withdraw.token.as_ref(),
+        ],
+        bump,
+    )]
+    pub large_transfer_delay: AccountInfo<'info>,
+
+    /// Operator rent record from `withdraw_submit_and_approve`; uninitialized otherwise.
+    /// CHECK: address fixed by seeds; only read when owned by this program (`execute::load_sponsorship`)
+    #[account(
+        mut,
+        seeds = [WithdrawSponsorship::SEED, pending_withdraw.transfer_hash.as_ref()],
+        bump,
+    )]
+    pub sponsorship: AccountInfo<'info>,
+}
+
+pub fn handler(ctx: Context<WithdrawExecuteNativeByOperator>) -> Result<()> {
+    check_executable(
+        &ctx.accounts.bridge,
+        &ctx.accounts.pending_withdraw,
+        &ctx.accounts.recipient.key(),
+        &ctx.accounts.chain_withdraw_delay,
+        &ctx.accounts.large_transfer_delay,
+    )?;
+    require!(
+        ctx.accounts.pending_withdraw.token == NATIVE_SOL_TOKEN,
+        BridgeError::NotNativeToken
+    );
+
+    let amount_u128 = normalize_decimals(
+        ctx.accounts.pending_withdraw.amount,
+        ctx.accounts.pending_withdraw.src_decimals,
+        ctx.accounts.pending_withdraw.dest_decimals,
+    )?;
+    let amount: u64 = amount_u128
+        .try_into()
+        .map_err(|_| BridgeError::AmountExceedsU64)?;
+
+    consume_withdraw_rate_limit(
+        &mut ctx.accounts.withdraw_rate_limit,
+        ctx.bumps.withdraw_rate_limit,
+        0,
+        amount_u128,
+    )?;
+
+    let pw = &mut ctx.accounts.pending_withdraw;
+    pw.executed = true;
+    let transfer_hash = pw.transfer_hash;
+    let dest_account = pw.dest_account;
+
+    ctx.accounts.executed_hash.bump = ctx.bumps.executed_hash;
+
+    let sponsorship = load_sponsorship(&ctx.accounts.sponsorship, &transfer_hash)?;
+    let relay_fee = relay_fee_amount(sponsorship.as_ref(), &ctx.accounts.pending_withdraw)?;
+    let payout = amount
+        .checked_sub(relay_fee)
+        .ok_or(BridgeError::ArithmeticOverflow)?;
+    if relay_fee > 0 {
+        // Paid to the operator together with the rent it is owed (see `settle_sponsorship`)
+        release_native(
+            &ctx.accounts.bridge.to_account_info(),
+            &ctx.accounts.sponsorship,
+            relay_fee,
+        )?;
+        emit_relay_fee_paid(sponsorship.as_ref(), transfer_hash, relay_fee);
+    }
+    settle_sponsorship(
+        &ctx.accounts.sponsorship,
+        sponsorship.as_ref(),
+        &ctx.accounts.pending_withdraw.to_account_info(),
+        Some(&ctx.accounts.operator.to_account_info()),
+    )?;
+
+    release_native(
+        &ctx.accounts.bridge.to_account_info(),
+        &ctx.accounts.recipient.to_account_info(),
+        payout,
+    )?;
+
+    emit!(WithdrawExecuteNativeEvent {
+        transfer_hash,
+        recipient: dest_account,
+        amount: u128::from(payout),
+    });
+
+    Ok(())
+}
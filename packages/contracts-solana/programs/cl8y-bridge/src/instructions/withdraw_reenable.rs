@@ -1,5 +1,6 @@
+use crate::canceler_reward::revert_cancel_record;
 use crate::error::BridgeError;
-use crate::state::{BridgeConfig, PendingWithdraw};
+use crate::state::{BridgeConfig, CancelRecord, CancelerRewardPool, PendingWithdraw};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
@@ -19,6 +20,23 @@ pub struct WithdrawReenable<'info> {
 
     /// Operator or admin (EVM: operator-only uncancel; we allow admin too for ops).
     pub authority: Signer<'info>,
+
+    /// Record of the reverted cancel; uninitialized when no reward pool existed at cancel time.
+    /// CHECK: address fixed by seeds; only written when owned by this program (`canceler_reward::revert_cancel_record`)
+    #[account(
+        mut,
+        seeds = [CancelRecord::SEED, pending_withdraw.transfer_hash.as_ref()],
+        bump,
+    )]
+    pub cancel_record: AccountInfo<'info>,
+
+    /// CHECK: address fixed by seeds; only written when owned by this program (`canceler_reward::revert_cancel_record`)
+    #[account(
+        mut,
+        seeds = [CancelerRewardPool::SEED],
+        bump,
+    )]
+    pub reward_pool: AccountInfo<'info>,
 }
 
 pub fn handler(ctx: Context<WithdrawReenable>) -> Result<()> {
@@ -39,6 +57,8 @@ pub fn handler(ctx: Context<WithdrawReenable>) -> Result<()> {
     pw.approved = true;
     pw.approved_at = Clock::get()?.unix_timestamp;
 
+    revert_cancel_record(&ctx.accounts.reward_pool, &ctx.accounts.cancel_record)?;
+
     emit!(WithdrawReenableEvent {
         transfer_hash: pw.transfer_hash,
     });
@@ -1,6 +1,8 @@
 use crate::error::BridgeError;
 use crate::hash::compute_transfer_hash;
-use crate::state::{BridgeConfig, ChainEntry, ExecutedHash, PendingWithdraw, TokenMapping};
+use crate::state::{
+    BridgeConfig, ChainEntry, ExecutedHash, NonceBitmap, PendingWithdraw, TokenMapping,
+};
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
 
@@ -83,41 +85,47 @@ pub struct WithdrawSubmit<'info> {
     pub payer: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// Nonce bitmap chunk for `params.nonce`; a set bit means the nonce was approved, which still
+    /// holds after `close_withdraw` has closed the `ExecutedHash` above.
+    /// CHECK: address fixed by seeds; only read when owned by this program
+    #[account(
+        seeds = [
+            NonceBitmap::SEED,
+            params.src_chain.as_ref(),
+            &NonceBitmap::chunk_seed(params.nonce),
+        ],
+        bump,
+    )]
+    pub nonce_bitmap_check: AccountInfo<'info>,
 }
 
 pub fn handler(ctx: Context<WithdrawSubmit>, params: WithdrawSubmitParams) -> Result<()> {
     let bridge = &ctx.accounts.bridge;
-    require!(!bridge.paused, BridgeError::BridgePaused);
-    require!(params.amount > 0, BridgeError::ZeroAmount);
-    require!(
-        params.src_chain != bridge.chain_id,
-        BridgeError::SameChainTransfer
-    );
-    require!(
-        params.dest_account != Pubkey::default(),
-        BridgeError::InvalidDestAccount
-    );
-
-    // Reject if this transfer hash was already executed (close+reinit protection)
-    require!(
-        ctx.accounts.executed_hash_check.data_is_empty(),
-        BridgeError::AlreadyExecutedHash
-    );
-
-    let dest_account = params.dest_account.to_bytes();
-    let token_bytes = params.dest_token.to_bytes();
+    check_submit(bridge, &params, &ctx.accounts.executed_hash_check)?;
+
+    // Reject an already-approved nonce: it could never be approved again, leaving a stuck
+    // PendingWithdraw (and its operator gas) in the operator's queue.
+    let bitmap_info = &ctx.accounts.nonce_bitmap_check;
+    if !bitmap_info.data_is_empty() && *bitmap_info.owner == crate::ID {
+        let data = bitmap_info.try_borrow_data()?;
+        let bitmap = NonceBitmap::try_deserialize(&mut &data[..])?;
+        require!(
+            !bitmap.is_set(params.nonce),
+            BridgeError::NonceAlreadyApproved
+        );
+    }
 
     let transfer_hash = compute_transfer_hash(
         &params.src_chain,
         &bridge.chain_id,
         &params.src_account,
-        &dest_account,
-        &token_bytes,
+        &params.dest_account.to_bytes(),
+        &params.dest_token.to_bytes(),
         params.amount,
         params.nonce,
     );
 
-    let tm = &ctx.accounts.token_mapping;
     if params.operator_gas > 0 {
         system_program::transfer(
             CpiContext::new(
@@ -131,7 +139,50 @@ pub fn handler(ctx: Context<WithdrawSubmit>, params: WithdrawSubmitParams) -> Re
         )?;
     }
 
-    let pw = &mut ctx.accounts.pending_withdraw;
+    init_pending_withdraw(
+        &mut ctx.accounts.pending_withdraw,
+        transfer_hash,
+        &params,
+        &ctx.accounts.token_mapping,
+        ctx.bumps.pending_withdraw,
+    );
+
+    Ok(())
+}
+
+/// Checks shared with `withdraw_submit_and_approve`; the nonce is checked separately.
+pub(crate) fn check_submit(
+    bridge: &BridgeConfig,
+    params: &WithdrawSubmitParams,
+    executed_hash_check: &AccountInfo,
+) -> Result<()> {
+    require!(!bridge.paused, BridgeError::BridgePaused);
+    require!(params.amount > 0, BridgeError::ZeroAmount);
+    require!(
+        params.src_chain != bridge.chain_id,
+        BridgeError::SameChainTransfer
+    );
+    require!(
+        params.dest_account != Pubkey::default(),
+        BridgeError::InvalidDestAccount
+    );
+
+    // Reject if this transfer hash was already executed (close+reinit protection)
+    require!(
+        executed_hash_check.data_is_empty(),
+        BridgeError::AlreadyExecutedHash
+    );
+    Ok(())
+}
+
+/// Fill a freshly created `PendingWithdraw` and emit [`WithdrawSubmitEvent`].
+pub(crate) fn init_pending_withdraw(
+    pw: &mut PendingWithdraw,
+    transfer_hash: [u8; 32],
+    params: &WithdrawSubmitParams,
+    tm: &TokenMapping,
+    bump: u8,
+) {
     pw.transfer_hash = transfer_hash;
     pw.src_chain = params.src_chain;
     pw.src_account = params.src_account;
@@ -146,19 +197,17 @@ pub fn handler(ctx: Context<WithdrawSubmit>, params: WithdrawSubmitParams) -> Re
     pw.approved_at = 0;
     pw.cancelled = false;
     pw.executed = false;
-    pw.bump = ctx.bumps.pending_withdraw;
+    pw.bump = bump;
 
     emit!(WithdrawSubmitEvent {
         transfer_hash,
         src_chain: params.src_chain,
-        dest_account,
-        token: token_bytes,
+        dest_account: params.dest_account.to_bytes(),
+        token: params.dest_token.to_bytes(),
         amount: params.amount,
         nonce: params.nonce,
         operator_gas: params.operator_gas,
     });
-
-    Ok(())
 }
 
 #[event]
@@ -0,0 +1,278 @@
+use crate::error::BridgeError;
+use crate::hash::compute_transfer_hash;
+use crate::instructions::withdraw_approve::record_approval;
+use crate::instructions::withdraw_submit::{check_submit, init_pending_withdraw};
+use crate::state::{
+    BridgeConfig, ChainEntry, ExecutedHash, NonceBitmap, NonceUsed, PendingWithdraw, RelayConfig,
+    RentSource, RentSponsor, TokenMapping, WithdrawSponsorship,
+};
+use anchor_lang::prelude::*;
+
+/// [`super::WithdrawSubmitParams`] without operator gas: the operator submits on the
+/// recipient's behalf and pays the `PendingWithdraw` rent itself.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct WithdrawSubmitAndApproveParams {
+    pub src_chain: [u8; 4],
+    pub src_account: [u8; 32],
+    /// Remote token identifier (bytes32); must match registered [`TokenMapping::dest_token`].
+    pub src_token: [u8; 32],
+    pub dest_token: Pubkey,
+    pub dest_account: Pubkey,
+    pub amount: u128,
+    pub nonce: u64,
+    pub rent_source: RentSource,
+    /// Fee the operator takes out of the withdrawal for relaying it, in source chain decimals
+    /// (EVM `withdrawSubmitRelayed` parity). Non-zero requires [`RentSource::Withdrawal`] and
+    /// is bounded by [`RelayConfig::relay_fee_cap_bps`].
+    pub relay_fee: u128,
+}
+
+impl WithdrawSubmitAndApproveParams {
+    fn transfer_hash(&self, this_chain: &[u8; 4]) -> [u8; 32] {
+        compute_transfer_hash(
+            &self.src_chain,
+            this_chain,
+            &self.src_account,
+            &self.dest_account.to_bytes(),
+            &self.dest_token.to_bytes(),
+            self.amount,
+            self.nonce,
+        )
+    }
+
+    fn as_submit(&self) -> super::WithdrawSubmitParams {
+        super::WithdrawSubmitParams {
+            src_chain: self.src_chain,
+            src_account: self.src_account,
+            src_token: self.src_token,
+            dest_token: self.dest_token,
+            dest_account: self.dest_account,
+            amount: self.amount,
+            nonce: self.nonce,
+            operator_gas: 0,
+        }
+    }
+}
+
+/// [`super::WithdrawSubmit`] and [`super::WithdrawApprove`] in one step, for recipients without
+/// SOL for rent and fees. Only the operator may call it, after verifying the source deposit as
+/// for `withdraw_approve`; the cancel window starts now.
+///
+/// The operator pays the `PendingWithdraw` rent and is reimbursed according to
+/// [`RentSource`]: from `rent_sponsor` immediately, or on execute by keeping the
+/// `PendingWithdraw` rent refund that would otherwise go to the recipient (`sponsorship`
+/// records that). The bridged tokens are not touched. Pass only the account the rent source
+/// needs.
+/// `relay_config` is only needed for a non-zero relay fee.
+#[derive(Accounts)]
+#[instruction(params: WithdrawSubmitAndApproveParams)]
+pub struct WithdrawSubmitAndApprove<'info> {
+    #[account(
+        seeds = [BridgeConfig::SEED],
+        bump = bridge.bump,
+    )]
+    pub bridge: Box<Account<'info, BridgeConfig>>,
+
+    #[account(
+        seeds = [ChainEntry::SEED, params.src_chain.as_ref()],
+        bump = src_chain_entry.bump,
+    )]
+    pub src_chain_entry: Box<Account<'info, ChainEntry>>,
+
+    #[account(
+        seeds = [
+            TokenMapping::SEED,
+            params.src_chain.as_ref(),
+            params.src_token.as_ref(),
+        ],
+        bump = token_mapping.bump,
+        constraint = token_mapping.local_mint == params.dest_token @ BridgeError::TokenMappingMismatch
+    )]
+    pub token_mapping: Box<Account<'info, TokenMapping>>,
+
+    #[account(
+        init,
+        payer = operator,
+        space = 8 + PendingWithdraw::INIT_SPACE,
+        seeds = [PendingWithdraw::SEED, &params.transfer_hash(&bridge.chain_id)],
+        bump,
+    )]
+    pub pending_withdraw: Box<Account<'info, PendingWithdraw>>,
+
+    /// Must not exist -- proves this transfer hash has never been executed
+    /// CHECK: We only verify this account does not exist (data is empty)
+    #[account(
+        seeds = [ExecutedHash::SEED, &params.transfer_hash(&bridge.chain_id)],
+        bump,
+    )]
+    pub executed_hash_check: AccountInfo<'info>,
+
+    /// Must not exist -- legacy per-nonce marker from approvals before [`NonceBitmap`]
+    /// CHECK: We only verify this account does not exist (data is empty)
+    #[account(
+        seeds = [NonceUsed::SEED, params.src_chain.as_ref(), &params.nonce.to_le_bytes()],
+        bump,
+    )]
+    pub nonce_used: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = operator,
+        space = 8 + NonceBitmap::INIT_SPACE,
+        seeds = [
+            NonceBitmap::SEED,
+            params.src_chain.as_ref(),
+            &NonceBitmap::chunk_seed(params.nonce),
+        ],
+        bump,
+    )]
+    pub nonce_bitmap: Box<Account<'info, NonceBitmap>>,
+
+    /// Required for [`RentSource::Sponsor`].
+    #[account(
+        mut,
+        seeds = [RentSponsor::SEED],
+        bump = rent_sponsor.bump,
+    )]
+    pub rent_sponsor: Option<Box<Account<'info, RentSponsor>>>,
+
+    /// Required for [`RentSource::Withdrawal`].
+    #[account(
+        init,
+        payer = operator,
+        space = 8 + WithdrawSponsorship::INIT_SPACE,
+        seeds = [WithdrawSponsorship::SEED, &params.transfer_hash(&bridge.chain_id)],
+        bump,
+    )]
+    pub sponsorship: Option<Box<Account<'info, WithdrawSponsorship>>>,
+
+    #[account(
+        mut,
+        address = bridge.operator @ BridgeError::UnauthorizedOperator,
+    )]
+    pub operator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(seeds = [RelayConfig::SEED], bump = relay_config.bump)]
+    pub relay_config: Option<Box<Account<'info, RelayConfig>>>,
+}
+
+pub fn handler(
+    ctx: Context<WithdrawSubmitAndApprove>,
+    params: WithdrawSubmitAndApproveParams,
+) -> Result<()> {
+    let submit = params.as_submit();
+    check_submit(
+        &ctx.accounts.bridge,
+        &submit,
+        &ctx.accounts.executed_hash_check,
+    )?;
+
+    if params.relay_fee > 0 {
+        let cap_bps = ctx
+            .accounts
+            .relay_config
+            .as_ref()
+            .map_or(0, |c| c.relay_fee_cap_bps);
+        require!(cap_bps > 0, BridgeError::RelayingDisabled);
+        let max_fee = params
+            .amount
+            .checked_mul(cap_bps as u128)
+            .ok_or(BridgeError::ArithmeticOverflow)?
+            / 10_000;
+        require!(params.relay_fee <= max_fee, BridgeError::RelayFeeTooHigh);
+        require!(
+            params.rent_source == RentSource::Withdrawal,
+            BridgeError::InvalidRentSource
+        );
+    }
+
+    let transfer_hash = params.transfer_hash(&ctx.accounts.bridge.chain_id);
+    init_pending_withdraw(
+        &mut ctx.accounts.pending_withdraw,
+        transfer_hash,
+        &submit,
+        &ctx.accounts.token_mapping,
+        ctx.bumps.pending_withdraw,
+    );
+    record_approval(
+        transfer_hash,
+        &mut ctx.accounts.pending_withdraw,
+        &ctx.accounts.nonce_used,
+        &mut ctx.accounts.nonce_bitmap,
+        ctx.bumps.nonce_bitmap,
+    )?;
+
+    let rent = Rent::get()?.minimum_balance(8 + PendingWithdraw::INIT_SPACE);
+    match params.rent_source {
+        RentSource::Sponsor => {
+            require!(
+                ctx.accounts.sponsorship.is_none(),
+                BridgeError::InvalidRentSource
+            );
+            let sponsor = ctx
+                .accounts
+                .rent_sponsor
+                .as_mut()
+                .ok_or(BridgeError::InvalidRentSource)?;
+            let sponsor_info = sponsor.to_account_info();
+            let operator_info = ctx.accounts.operator.to_account_info();
+            let reserve = Rent::get()?.minimum_balance(8 + RentSponsor::INIT_SPACE);
+            require!(
+                sponsor_info.lamports().saturating_sub(reserve) >= rent,
+                BridgeError::InsufficientRentSponsor
+            );
+            **sponsor_info.try_borrow_mut_lamports()? = sponsor_info
+                .lamports()
+                .checked_sub(rent)
+                .ok_or(BridgeError::ArithmeticOverflow)?;
+            **operator_info.try_borrow_mut_lamports()? = operator_info
+                .lamports()
+                .checked_add(rent)
+                .ok_or(BridgeError::ArithmeticOverflow)?;
+            sponsor.total_paid = sponsor
+                .total_paid
+                .checked_add(rent)
+                .ok_or(BridgeError::ArithmeticOverflow)?;
+        }
+        RentSource::Withdrawal => {
+            require!(
+                ctx.accounts.rent_sponsor.is_none(),
+                BridgeError::InvalidRentSource
+            );
+            let sponsorship = ctx
+                .accounts
+                .sponsorship
+                .as_mut()
+                .ok_or(BridgeError::InvalidRentSource)?;
+            sponsorship.transfer_hash = transfer_hash;
+            sponsorship.operator = ctx.accounts.operator.key();
+            sponsorship.bump = ctx
+                .bumps
+                .sponsorship
+                .ok_or(BridgeError::InvalidRentSource)?;
+            sponsorship.relay_fee = params.relay_fee;
+        }
+    }
+
+    emit!(WithdrawSubmitAndApproveEvent {
+        transfer_hash,
+        operator: ctx.accounts.operator.key(),
+        rent_source: params.rent_source,
+        rent_lamports: rent,
+        relay_fee: params.relay_fee,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct WithdrawSubmitAndApproveEvent {
+    pub transfer_hash: [u8; 32],
+    pub operator: Pubkey,
+    pub rent_source: RentSource,
+    /// `PendingWithdraw` rent paid by the operator.
+    pub rent_lamports: u64,
+    pub relay_fee: u128,
+}
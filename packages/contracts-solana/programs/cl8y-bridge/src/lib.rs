@@ -1,13 +1,17 @@
 use anchor_lang::prelude::*;
 
 pub mod address_codec;
+pub mod canceler_reward;
 pub mod decimal;
+pub mod delay;
 pub mod error;
+pub mod execute;
 pub mod fee;
 pub mod rate_limit;
 pub mod hash;
 pub mod instructions;
 pub mod state;
+pub mod token_ext;
 
 use instructions::*;
 
@@ -43,6 +47,13 @@ pub mod cl8y_bridge {
         instructions::withdraw_approve::handler(ctx, params)
     }
 
+    pub fn withdraw_submit_and_approve(
+        ctx: Context<WithdrawSubmitAndApprove>,
+        params: WithdrawSubmitAndApproveParams,
+    ) -> Result<()> {
+        instructions::withdraw_submit_and_approve::handler(ctx, params)
+    }
+
     pub fn withdraw_execute(ctx: Context<WithdrawExecute>) -> Result<()> {
         instructions::withdraw_execute::handler(ctx)
     }
@@ -51,6 +62,16 @@ pub mod cl8y_bridge {
         instructions::withdraw_execute_native::handler(ctx)
     }
 
+    pub fn withdraw_execute_by_operator(ctx: Context<WithdrawExecuteByOperator>) -> Result<()> {
+        instructions::withdraw_execute_by_operator::handler(ctx)
+    }
+
+    pub fn withdraw_execute_native_by_operator(
+        ctx: Context<WithdrawExecuteNativeByOperator>,
+    ) -> Result<()> {
+        instructions::withdraw_execute_native_by_operator::handler(ctx)
+    }
+
     pub fn withdraw_cancel(ctx: Context<WithdrawCancel>) -> Result<()> {
         instructions::withdraw_cancel::handler(ctx)
     }
@@ -82,4 +103,104 @@ pub mod cl8y_bridge {
     pub fn withdraw_fees(ctx: Context<WithdrawFees>, params: WithdrawFeesParams) -> Result<()> {
         instructions::withdraw_fees::handler(ctx, params)
     }
+
+    pub fn set_canceler_reward(
+        ctx: Context<SetCancelerReward>,
+        params: SetCancelerRewardParams,
+    ) -> Result<()> {
+        instructions::set_canceler_reward::handler(ctx, params)
+    }
+
+    pub fn fund_canceler_reward(
+        ctx: Context<FundCancelerReward>,
+        params: FundCancelerRewardParams,
+    ) -> Result<()> {
+        instructions::fund_canceler_reward::handler(ctx, params)
+    }
+
+    pub fn claim_canceler_reward(ctx: Context<ClaimCancelerReward>) -> Result<()> {
+        instructions::claim_canceler_reward::handler(ctx)
+    }
+
+    pub fn set_fee_schedule(
+        ctx: Context<SetFeeSchedule>,
+        params: SetFeeScheduleParams,
+    ) -> Result<()> {
+        instructions::set_fee_schedule::handler(ctx, params)
+    }
+
+    pub fn set_token_fee(ctx: Context<SetTokenFee>, params: SetTokenFeeParams) -> Result<()> {
+        instructions::set_token_fee::handler(ctx, params)
+    }
+
+    pub fn remove_token_fee(ctx: Context<RemoveTokenFee>) -> Result<()> {
+        instructions::remove_token_fee::handler(ctx)
+    }
+
+    pub fn set_custom_account_fee(
+        ctx: Context<SetCustomAccountFee>,
+        params: SetCustomAccountFeeParams,
+    ) -> Result<()> {
+        instructions::set_custom_account_fee::handler(ctx, params)
+    }
+
+    pub fn remove_custom_account_fee(ctx: Context<RemoveCustomAccountFee>) -> Result<()> {
+        instructions::remove_custom_account_fee::handler(ctx)
+    }
+
+    pub fn close_withdraw(ctx: Context<CloseWithdraw>, params: CloseWithdrawParams) -> Result<()> {
+        instructions::close_withdraw::handler(ctx, params)
+    }
+
+    pub fn close_deposit(ctx: Context<CloseDeposit>) -> Result<()> {
+        instructions::close_deposit::handler(ctx)
+    }
+
+    pub fn set_chain_withdraw_delay(
+        ctx: Context<SetChainWithdrawDelay>,
+        params: SetChainWithdrawDelayParams,
+    ) -> Result<()> {
+        instructions::set_chain_withdraw_delay::handler(ctx, params)
+    }
+
+    pub fn remove_chain_withdraw_delay(ctx: Context<RemoveChainWithdrawDelay>) -> Result<()> {
+        instructions::remove_chain_withdraw_delay::handler(ctx)
+    }
+
+    pub fn set_large_transfer_delay(
+        ctx: Context<SetLargeTransferDelay>,
+        params: SetLargeTransferDelayParams,
+    ) -> Result<()> {
+        instructions::set_large_transfer_delay::handler(ctx, params)
+    }
+
+    pub fn remove_large_transfer_delay(ctx: Context<RemoveLargeTransferDelay>) -> Result<()> {
+        instructions::remove_large_transfer_delay::handler(ctx)
+    }
+
+    pub fn close_cancel_record(ctx: Context<CloseCancelRecord>) -> Result<()> {
+        instructions::close_cancel_record::handler(ctx)
+    }
+
+    pub fn fund_rent_sponsor(
+        ctx: Context<FundRentSponsor>,
+        params: FundRentSponsorParams,
+    ) -> Result<()> {
+        instructions::fund_rent_sponsor::handler(ctx, params)
+    }
+
+    pub fn close_withdraw_sponsorship(ctx: Context<CloseWithdrawSponsorship>) -> Result<()> {
+        instructions::close_withdraw_sponsorship::handler(ctx)
+    }
+
+    pub fn set_relay_fee_cap(
+        ctx: Context<SetRelayFeeCap>,
+        params: SetRelayFeeCapParams,
+    ) -> Result<()> {
+        instructions::set_relay_fee_cap::handler(ctx, params)
+    }
+
+    pub fn request_relay(ctx: Context<RequestRelay>, params: RequestRelayParams) -> Result<()> {
+        instructions::request_relay::handler(ctx, params)
+    }
 }
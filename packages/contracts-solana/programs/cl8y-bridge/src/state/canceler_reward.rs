@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+/// Canceler bounty pool (TerraClassic `CANCELER_REWARD_CONFIG` + `CANCELER_REWARD_POOL` parity).
+/// Reward lamports are held by this PDA on top of its rent-exempt reserve.
+#[account]
+#[derive(InitSpace)]
+pub struct CancelerRewardPool {
+    /// Lamports paid per successful cancellation.
+    pub reward_lamports: u64,
+    /// Seconds after a cancel during which `withdraw_reenable` may still revert it.
+    pub review_period: i64,
+    pub total_funded: u64,
+    pub total_paid: u64,
+    pub total_claims: u64,
+    /// Cancels recorded while the pool existed.
+    pub total_cancels: u64,
+    /// Recorded cancels reverted by `withdraw_reenable` before being claimed.
+    pub total_reverted: u64,
+    pub bump: u8,
+}
+
+impl CancelerRewardPool {
+    pub const SEED: &'static [u8] = b"canceler_reward";
+    /// 30 days (Terra `MAX_CANCELER_REVIEW_PERIOD`).
+    pub const MAX_REVIEW_PERIOD: i64 = 2_592_000;
+}
+
+/// Canceler that cancelled a pending withdraw (Terra `CANCEL_RECORDS` parity).
+/// Created only while a [`CancelerRewardPool`] exists; rewritten on a later cancel only if the
+/// previous one was never claimed. Closed by the canceler via `close_cancel_record`.
+#[account]
+#[derive(InitSpace)]
+pub struct CancelRecord {
+    pub transfer_hash: [u8; 32],
+    pub canceler: Pubkey,
+    pub cancelled_at: i64,
+    pub claimed: bool,
+    /// Set by `withdraw_reenable`; a reverted record can no longer be claimed.
+    pub reverted: bool,
+    pub bump: u8,
+}
+
+impl CancelRecord {
+    pub const SEED: &'static [u8] = b"cancel_record";
+}
@@ -16,4 +16,8 @@ pub struct DepositRecord {
 
 impl DepositRecord {
     pub const SEED: &'static [u8] = b"deposit";
+    /// Seconds after the deposit before `close_deposit` may reclaim its rent. Cancelers verify
+    /// withdrawals against this record, then against the hash kept in
+    /// [`DepositHashes`](crate::state::DepositHashes).
+    pub const RETENTION: i64 = 31_536_000;
 }
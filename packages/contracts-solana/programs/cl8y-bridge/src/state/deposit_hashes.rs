@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+/// Transfer hashes per [`DepositHashes`] chunk.
+pub const DEPOSIT_HASHES_PER_CHUNK: usize = 32;
+
+/// Transfer hashes of closed deposit records, [`DEPOSIT_HASHES_PER_CHUNK`] nonces per account.
+/// `close_deposit` moves a record's hash here so cancelers can still check withdrawals of old
+/// nonces; an all-zero slot means that nonce's record was never closed.
+#[account]
+#[derive(InitSpace)]
+pub struct DepositHashes {
+    pub chunk: u64,
+    pub hashes: [[u8; 32]; DEPOSIT_HASHES_PER_CHUNK],
+    pub bump: u8,
+}
+
+impl DepositHashes {
+    pub const SEED: &'static [u8] = b"deposit_hashes";
+
+    /// Chunk index seed for `nonce` (little-endian, like other nonce seeds).
+    pub fn chunk_seed(nonce: u64) -> [u8; 8] {
+        (nonce / DEPOSIT_HASHES_PER_CHUNK as u64).to_le_bytes()
+    }
+
+    pub fn get(&self, nonce: u64) -> [u8; 32] {
+        self.hashes[(nonce % DEPOSIT_HASHES_PER_CHUNK as u64) as usize]
+    }
+
+    pub fn set(&mut self, nonce: u64, transfer_hash: [u8; 32]) {
+        self.hashes[(nonce % DEPOSIT_HASHES_PER_CHUNK as u64) as usize] = transfer_hash;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nonces_map_into_their_slot() {
+        let n = DEPOSIT_HASHES_PER_CHUNK as u64 * 4 + 7;
+        assert_eq!(DepositHashes::chunk_seed(n), 4u64.to_le_bytes());
+        let mut chunk = DepositHashes {
+            chunk: 4,
+            hashes: [[0u8; 32]; DEPOSIT_HASHES_PER_CHUNK],
+            bump: 0,
+        };
+        chunk.set(n, [0xab; 32]);
+        assert_eq!(chunk.get(n), [0xab; 32]);
+        assert_eq!(chunk.hashes[7], [0xab; 32]);
+        assert_eq!(chunk.get(n + 1), [0u8; 32]);
+    }
+}
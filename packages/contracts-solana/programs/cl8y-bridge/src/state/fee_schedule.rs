@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+/// CL8Y holder discount tier (TerraClassic `FeeConfig.discounted_fee_bps` + `cl8y_token` + `cl8y_threshold`).
+/// The standard rate stays in [`BridgeConfig::fee_bps`](crate::state::BridgeConfig).
+#[account]
+#[derive(InitSpace)]
+pub struct FeeSchedule {
+    /// Fee charged to depositors holding at least `cl8y_threshold` of `cl8y_mint`.
+    pub discounted_fee_bps: u16,
+    /// CL8Y SPL mint; `Pubkey::default()` disables the discount.
+    pub cl8y_mint: Pubkey,
+    /// Minimum raw CL8Y balance (mint decimals) for the discount.
+    pub cl8y_threshold: u64,
+    pub bump: u8,
+}
+
+impl FeeSchedule {
+    pub const SEED: &'static [u8] = b"fee_schedule";
+}
+
+/// Per-mapping standard fee (replaces [`BridgeConfig::fee_bps`](crate::state::BridgeConfig) for one
+/// `TokenMapping`). Deposits always pass this address, so the override cannot be skipped.
+#[account]
+#[derive(InitSpace)]
+pub struct TokenFeeOverride {
+    pub token_mapping: Pubkey,
+    pub fee_bps: u16,
+    pub bump: u8,
+}
+
+impl TokenFeeOverride {
+    pub const SEED: &'static [u8] = b"token_fee";
+}
+
+/// Per-depositor fee override (TerraClassic `CUSTOM_ACCOUNT_FEES` parity).
+/// Deposits only see it when the depositor passes the PDA, so it is meant for partner discounts.
+#[account]
+#[derive(InitSpace)]
+pub struct CustomAccountFee {
+    pub account: Pubkey,
+    pub fee_bps: u16,
+    pub bump: u8,
+}
+
+impl CustomAccountFee {
+    pub const SEED: &'static [u8] = b"custom_fee";
+}
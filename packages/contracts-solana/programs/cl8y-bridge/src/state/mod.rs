@@ -1,19 +1,33 @@
 pub mod bridge;
 pub mod canceler_entry;
+pub mod canceler_reward;
 pub mod chain_registry;
 pub mod deposit;
+pub mod deposit_hashes;
 pub mod executed_hash;
+pub mod fee_schedule;
+pub mod nonce_bitmap;
 pub mod nonce_used;
 pub mod pending_withdraw;
+pub mod relay;
 pub mod token_registry;
+pub mod withdraw_delay;
 pub mod withdraw_rate_limit;
+pub mod withdraw_sponsorship;
 
 pub use bridge::*;
 pub use canceler_entry::*;
+pub use canceler_reward::*;
 pub use chain_registry::*;
 pub use deposit::*;
+pub use deposit_hashes::*;
 pub use executed_hash::*;
+pub use fee_schedule::*;
+pub use nonce_bitmap::*;
 pub use nonce_used::*;
 pub use pending_withdraw::*;
+pub use relay::*;
 pub use token_registry::*;
+pub use withdraw_delay::*;
 pub use withdraw_rate_limit::*;
+pub use withdraw_sponsorship::*;
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+/// Bytes of bitmap per chunk; one bit per `(src_chain, nonce)`.
+pub const NONCE_BITMAP_BYTES: usize = 1024;
+
+/// Approved withdraw nonces for one source chain, [`NonceBitmap::NONCES_PER_CHUNK`] per account.
+/// Replaces one [`NonceUsed`](crate::state::NonceUsed) PDA per approval so that finished
+/// withdraw accounts can be closed without weakening replay protection (`close_withdraw`).
+#[account]
+#[derive(InitSpace)]
+pub struct NonceBitmap {
+    pub src_chain: [u8; 4],
+    pub chunk: u64,
+    pub bits: [u8; NONCE_BITMAP_BYTES],
+    pub bump: u8,
+}
+
+impl NonceBitmap {
+    pub const SEED: &'static [u8] = b"nonce_bitmap";
+    pub const NONCES_PER_CHUNK: u64 = (NONCE_BITMAP_BYTES * 8) as u64;
+
+    /// Chunk index seed for `nonce` (little-endian, like other nonce seeds).
+    pub fn chunk_seed(nonce: u64) -> [u8; 8] {
+        (nonce / Self::NONCES_PER_CHUNK).to_le_bytes()
+    }
+
+    fn position(nonce: u64) -> (usize, u8) {
+        let bit = (nonce % Self::NONCES_PER_CHUNK) as usize;
+        (bit / 8, 1u8 << (bit % 8))
+    }
+
+    pub fn is_set(&self, nonce: u64) -> bool {
+        let (byte, mask) = Self::position(nonce);
+        self.bits[byte] & mask != 0
+    }
+
+    pub fn set(&mut self, nonce: u64) {
+        let (byte, mask) = Self::position(nonce);
+        self.bits[byte] |= mask;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty(chunk: u64) -> NonceBitmap {
+        NonceBitmap {
+            src_chain: [0, 0, 0, 1],
+            chunk,
+            bits: [0u8; NONCE_BITMAP_BYTES],
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn set_marks_only_that_nonce() {
+        let mut bm = empty(0);
+        bm.set(9);
+        assert!(bm.is_set(9));
+        assert!(!bm.is_set(8));
+        assert!(!bm.is_set(10));
+    }
+
+    #[test]
+    fn nonces_map_into_their_chunk() {
+        let n = NonceBitmap::NONCES_PER_CHUNK * 3 + 5;
+        assert_eq!(NonceBitmap::chunk_seed(n), 3u64.to_le_bytes());
+        let mut bm = empty(3);
+        bm.set(n);
+        assert!(bm.is_set(n));
+        assert_eq!(bm.bits[0], 1 << 5);
+        assert_eq!(
+            NonceBitmap::chunk_seed(NonceBitmap::NONCES_PER_CHUNK - 1),
+            0u64.to_le_bytes()
+        );
+    }
+}
@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 
 /// Marks `(src_chain, nonce)` as consumed by an approval (parity with EVM `withdrawNonceUsed`).
+/// Legacy: approvals now set a bit in [`NonceBitmap`](crate::state::NonceBitmap); existing accounts
+/// still block re-approval until `close_withdraw` migrates them into the bitmap.
 #[account]
 #[derive(InitSpace)]
 pub struct NonceUsed {
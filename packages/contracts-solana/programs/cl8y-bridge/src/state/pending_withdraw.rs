@@ -25,4 +25,7 @@ pub struct PendingWithdraw {
 
 impl PendingWithdraw {
     pub const SEED: &'static [u8] = b"withdraw";
+    /// Seconds after approval before a cancelled withdraw can be closed (`close_withdraw`).
+    /// Longer than `CancelerRewardPool::MAX_REVIEW_PERIOD` so reenable and reward claims stay possible.
+    pub const CANCELLED_RETENTION: i64 = 7_776_000;
 }
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+/// Relay fee cap (EVM `relayFeeCapBps` / Terra `RELAY_FEE_CAP_BPS` parity). Until this account
+/// exists, or while the cap is 0, `withdraw_submit_and_approve` rejects every relay fee.
+#[account]
+#[derive(InitSpace)]
+pub struct RelayConfig {
+    /// Highest relay fee, in basis points of the withdrawn amount.
+    pub relay_fee_cap_bps: u16,
+    pub bump: u8,
+}
+
+impl RelayConfig {
+    pub const SEED: &'static [u8] = b"relay_config";
+    /// 5% (EVM / Terra `MAX_RELAY_FEE_BPS`).
+    pub const MAX_RELAY_FEE_BPS: u16 = 500;
+}
+
+/// Depositor's opt-in to a relayed withdrawal of a deposit made on this chain (EVM
+/// `relayRequests` parity). Operators and cancelers check destination relay fees against it.
+/// Closed with its deposit by `close_deposit`.
+#[account]
+#[derive(InitSpace)]
+pub struct RelayRequest {
+    pub transfer_hash: [u8; 32],
+    /// Highest fee the depositor accepts, in the deposit's amount units.
+    pub max_relay_fee: u128,
+    pub bump: u8,
+}
+
+impl RelayRequest {
+    pub const SEED: &'static [u8] = b"relay_request";
+}
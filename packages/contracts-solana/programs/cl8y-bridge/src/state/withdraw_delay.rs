@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+/// Per-source-chain withdraw delay; replaces [`BridgeConfig::withdraw_delay`](crate::state::BridgeConfig)
+/// for withdrawals whose `src_chain` is `chain_id`. Execute always passes this address, so it cannot be skipped.
+#[account]
+#[derive(InitSpace)]
+pub struct ChainWithdrawDelay {
+    pub chain_id: [u8; 4],
+    pub withdraw_delay: i64,
+    pub bump: u8,
+}
+
+impl ChainWithdrawDelay {
+    pub const SEED: &'static [u8] = b"chain_delay";
+}
+
+/// Extended cancel window for large transfers of one token mapping.
+/// Seeded by `(src_chain, local token)` so both execute paths can derive it from `PendingWithdraw`.
+#[account]
+#[derive(InitSpace)]
+pub struct LargeTransferDelay {
+    pub token_mapping: Pubkey,
+    /// Transfers with `PendingWithdraw.amount` (hashed, source-chain decimals) at or above this
+    /// wait at least `withdraw_delay`.
+    pub threshold: u128,
+    pub withdraw_delay: i64,
+    pub bump: u8,
+}
+
+impl LargeTransferDelay {
+    pub const SEED: &'static [u8] = b"large_delay";
+}
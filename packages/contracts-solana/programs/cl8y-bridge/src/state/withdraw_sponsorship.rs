@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+/// Lamports that reimburse the operator for `PendingWithdraw` rent it pays in
+/// `withdraw_submit_and_approve` with [`RentSource::Sponsor`]. Held on top of the PDA's
+/// rent-exempt reserve, like [`CancelerRewardPool`](crate::state::CancelerRewardPool).
+#[account]
+#[derive(InitSpace)]
+pub struct RentSponsor {
+    pub total_funded: u64,
+    pub total_paid: u64,
+    pub bump: u8,
+}
+
+impl RentSponsor {
+    pub const SEED: &'static [u8] = b"rent_sponsor";
+}
+
+/// Operator-submitted withdraw whose rent is repaid by withholding the `PendingWithdraw` rent
+/// refund ([`RentSource::Withdrawal`]): that rent never goes to the recipient. When
+/// the operator executes, it gets the rent back and this record is closed; when the recipient
+/// executes, the rent is parked here until `close_withdraw_sponsorship`.
+#[account]
+#[derive(InitSpace)]
+pub struct WithdrawSponsorship {
+    pub transfer_hash: [u8; 32],
+    /// Operator that paid the `PendingWithdraw` rent.
+    pub operator: Pubkey,
+    pub bump: u8,
+    /// Relay fee owed to `operator`, in source chain decimals (0 when not relayed). Paid out of
+    /// the withdrawal on execute, whoever executes.
+    pub relay_fee: u128,
+}
+
+impl WithdrawSponsorship {
+    pub const SEED: &'static [u8] = b"sponsorship";
+}
+
+/// Who reimburses the `PendingWithdraw` rent of an operator-submitted withdraw.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RentSource {
+    /// Paid back from [`RentSponsor`] in the same instruction.
+    Sponsor,
+    /// Paid back from the `PendingWithdraw` rent refund on execute, instead of refunding it
+    /// to the recipient (see [`WithdrawSponsorship`]).
+    Withdrawal,
+}
@@ -0,0 +1,198 @@
+//! Token-2022 mint extension policy (INV-D3 in `docs/SOLANA_BRIDGE_INVARIANTS.md`).
+//!
+//! [`register_token`](crate::instructions::register_token) rejects mints whose extensions break
+//! lock/unlock custody. Transfer-fee mints stay supported: [`deposit_spl`](crate::instructions::deposit_spl)
+//! subtracts the fee withheld on the way into the bridge account via [`transfer_fee`] before the
+//! bridge fee and transfer hash are computed. Classic SPL mints have no extensions and pass through.
+
+use crate::error::BridgeError;
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{
+        transfer_fee::TransferFeeConfig, transfer_hook::TransferHook, BaseStateWithExtensions,
+        ExtensionType, StateWithExtensions,
+    },
+    state::Mint,
+};
+
+/// Mint extensions the bridge cannot custody:
+/// - confidential balances are invisible to `transfer_checked` accounting
+/// - non-transferable tokens cannot be locked
+/// - a permanent delegate can drain the bridge token account
+pub(crate) const UNSUPPORTED_MINT_EXTENSIONS: [ExtensionType; 5] = [
+    ExtensionType::ConfidentialTransferMint,
+    ExtensionType::ConfidentialTransferFeeConfig,
+    ExtensionType::ConfidentialMintBurn,
+    ExtensionType::NonTransferable,
+    ExtensionType::PermanentDelegate,
+];
+
+/// Reject Token-2022 mints with [`UNSUPPORTED_MINT_EXTENSIONS`] or a transfer hook that is set or
+/// can still be set (bridge CPIs do not forward hook accounts, so every transfer would fail).
+pub(crate) fn check_mint_extensions(mint: &AccountInfo) -> Result<()> {
+    if mint.owner != &spl_token_2022::ID {
+        return Ok(());
+    }
+    let data = mint.try_borrow_data()?;
+    let state = StateWithExtensions::<Mint>::unpack(&data)?;
+    for extension in state.get_extension_types()? {
+        require!(
+            !UNSUPPORTED_MINT_EXTENSIONS.contains(&extension),
+            BridgeError::UnsupportedMintExtension
+        );
+    }
+    if let Ok(hook) = state.get_extension::<TransferHook>() {
+        require!(
+            Option::<Pubkey>::from(hook.program_id).is_none()
+                && Option::<Pubkey>::from(hook.authority).is_none(),
+            BridgeError::UnsupportedMintExtension
+        );
+    }
+    Ok(())
+}
+
+/// Token-2022 transfer fee withheld from a transfer of `amount` in `epoch`; 0 without the extension.
+pub(crate) fn transfer_fee(mint: &AccountInfo, epoch: u64, amount: u64) -> Result<u64> {
+    if mint.owner != &spl_token_2022::ID {
+        return Ok(0);
+    }
+    let data = mint.try_borrow_data()?;
+    let state = StateWithExtensions::<Mint>::unpack(&data)?;
+    match state.get_extension::<TransferFeeConfig>() {
+        Ok(config) => Ok(config
+            .calculate_epoch_fee(epoch, amount)
+            .ok_or(BridgeError::ArithmeticOverflow)?),
+        Err(_) => Ok(0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_spl::token_2022::spl_token_2022::extension::{
+        non_transferable::NonTransferable, permanent_delegate::PermanentDelegate,
+        transfer_fee::TransferFee, BaseStateWithExtensionsMut, StateWithExtensionsMut,
+    };
+
+    /// Packed Token-2022 mint with `extensions` initialized; `configure` edits them before packing.
+    fn mint_data(
+        extensions: &[ExtensionType],
+        configure: impl FnOnce(&mut StateWithExtensionsMut<Mint>),
+    ) -> Vec<u8> {
+        let len = ExtensionType::try_calculate_account_len::<Mint>(extensions).unwrap();
+        let mut data = vec![0u8; len];
+        let mut state = StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut data).unwrap();
+        for extension in extensions {
+            match extension {
+                ExtensionType::TransferFeeConfig => {
+                    state.init_extension::<TransferFeeConfig>(true).unwrap();
+                }
+                ExtensionType::TransferHook => {
+                    state.init_extension::<TransferHook>(true).unwrap();
+                }
+                ExtensionType::PermanentDelegate => {
+                    state.init_extension::<PermanentDelegate>(true).unwrap();
+                }
+                ExtensionType::NonTransferable => {
+                    state.init_extension::<NonTransferable>(true).unwrap();
+                }
+                other => panic!("unsupported in test helper: {other:?}"),
+            }
+        }
+        configure(&mut state);
+        state.base = Mint {
+            decimals: 6,
+            is_initialized: true,
+            ..Default::default()
+        };
+        state.pack_base();
+        state.init_account_type().unwrap();
+        data
+    }
+
+    fn with_account<R>(owner: Pubkey, data: &mut [u8], f: impl FnOnce(&AccountInfo) -> R) -> R {
+        let key = Pubkey::new_unique();
+        let mut lamports = 1_000_000u64;
+        let info = AccountInfo::new(&key, false, false, &mut lamports, data, &owner, false, 0);
+        f(&info)
+    }
+
+    fn check(owner: Pubkey, mut data: Vec<u8>) -> Result<()> {
+        with_account(owner, &mut data, check_mint_extensions)
+    }
+
+    #[test]
+    fn plain_token_2022_mint_is_supported() {
+        let data = mint_data(&[], |_| {});
+        assert!(check(spl_token_2022::ID, data).is_ok());
+    }
+
+    #[test]
+    fn classic_spl_mint_is_not_inspected() {
+        assert!(check(anchor_spl::token::ID, vec![0u8; 82]).is_ok());
+    }
+
+    #[test]
+    fn rejects_permanent_delegate_and_non_transferable() {
+        for extension in [
+            ExtensionType::PermanentDelegate,
+            ExtensionType::NonTransferable,
+        ] {
+            let data = mint_data(&[extension], |_| {});
+            assert!(check(spl_token_2022::ID, data).is_err(), "{extension:?}");
+        }
+    }
+
+    #[test]
+    fn transfer_hook_rejected_unless_permanently_unset() {
+        let unset = mint_data(&[ExtensionType::TransferHook], |_| {});
+        assert!(check(spl_token_2022::ID, unset).is_ok());
+
+        let updatable = mint_data(&[ExtensionType::TransferHook], |state| {
+            state.get_extension_mut::<TransferHook>().unwrap().authority =
+                Some(Pubkey::new_unique()).try_into().unwrap();
+        });
+        assert!(check(spl_token_2022::ID, updatable).is_err());
+
+        let set = mint_data(&[ExtensionType::TransferHook], |state| {
+            state
+                .get_extension_mut::<TransferHook>()
+                .unwrap()
+                .program_id = Some(Pubkey::new_unique()).try_into().unwrap();
+        });
+        assert!(check(spl_token_2022::ID, set).is_err());
+    }
+
+    #[test]
+    fn transfer_fee_follows_epoch_schedule() {
+        let mut data = mint_data(&[ExtensionType::TransferFeeConfig], |state| {
+            let config = state.get_extension_mut::<TransferFeeConfig>().unwrap();
+            config.older_transfer_fee = TransferFee {
+                epoch: 0.into(),
+                maximum_fee: u64::MAX.into(),
+                transfer_fee_basis_points: 100.into(),
+            };
+            config.newer_transfer_fee = TransferFee {
+                epoch: 10.into(),
+                maximum_fee: 5_000.into(),
+                transfer_fee_basis_points: 200.into(),
+            };
+        });
+        assert!(check(spl_token_2022::ID, data.clone()).is_ok());
+
+        with_account(spl_token_2022::ID, &mut data, |mint| {
+            assert_eq!(transfer_fee(mint, 9, 1_000_000).unwrap(), 10_000);
+            assert_eq!(transfer_fee(mint, 10, 1_000_000).unwrap(), 5_000);
+            assert_eq!(transfer_fee(mint, 10, 100_000).unwrap(), 2_000);
+        });
+    }
+
+    #[test]
+    fn no_transfer_fee_without_extension() {
+        let mut data = mint_data(&[], |_| {});
+        with_account(spl_token_2022::ID, &mut data, |mint| {
+            assert_eq!(transfer_fee(mint, 0, 1_000_000).unwrap(), 0);
+        });
+    }
+}
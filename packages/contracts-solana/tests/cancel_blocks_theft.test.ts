@@ -10,6 +10,7 @@ import {
   findExecutedHashPda,
   findTokenPda,
   findNonceUsedPda,
+  findNonceBitmapPda,
   TestContext,
   initializeBridgeIfNeeded,
   registerChainIfNeeded,
@@ -142,6 +143,11 @@ describe("cancel blocks theft (full guarantee)", () => {
         executedHashCheck: executedHashPda,
         payer: ctx.user.publicKey,
         systemProgram: SystemProgram.programId,
+        nonceBitmapCheck: findNonceBitmapPda(
+          ctx.program.programId,
+          Buffer.from(EVM_CHAIN_ID),
+          nonce
+        )[0],
       })
       .signers([ctx.user])
       .rpc();
@@ -158,6 +164,11 @@ describe("cancel blocks theft (full guarantee)", () => {
         bridge: ctx.bridgePda,
         pendingWithdraw: withdrawPda,
         nonceUsed: nonceUsedPda,
+        nonceBitmap: findNonceBitmapPda(
+          ctx.program.programId,
+          Buffer.from(EVM_CHAIN_ID),
+          nonce
+        )[0],
         operator: ctx.operator.publicKey,
         systemProgram: SystemProgram.programId,
       })
@@ -13,6 +13,7 @@ import {
   findExecutedHashPda,
   findTokenPda,
   findNonceUsedPda,
+  findNonceBitmapPda,
   airdrop,
   TestContext,
   initializeBridgeIfNeeded,
@@ -141,6 +142,11 @@ describe("cancel flow", () => {
         executedHashCheck: executedHashPda,
         payer: ctx.user.publicKey,
         systemProgram: SystemProgram.programId,
+        nonceBitmapCheck: findNonceBitmapPda(
+          ctx.program.programId,
+          Buffer.from(EVM_CHAIN_ID),
+          nonce
+        )[0],
       })
       .signers([ctx.user])
       .rpc();
@@ -157,6 +163,11 @@ describe("cancel flow", () => {
         bridge: ctx.bridgePda,
         pendingWithdraw: withdrawPda,
         nonceUsed: nonceUsedPda,
+        nonceBitmap: findNonceBitmapPda(
+          ctx.program.programId,
+          Buffer.from(EVM_CHAIN_ID),
+          nonce
+        )[0],
         operator: ctx.operator.publicKey,
         systemProgram: SystemProgram.programId,
       })
@@ -256,6 +267,11 @@ describe("cancel flow", () => {
         executedHashCheck: eh2,
         payer: ctx.user.publicKey,
         systemProgram: SystemProgram.programId,
+        nonceBitmapCheck: findNonceBitmapPda(
+          ctx.program.programId,
+          Buffer.from(EVM_CHAIN_ID),
+          20n
+        )[0],
       })
       .signers([ctx.user])
       .rpc();
@@ -272,6 +288,11 @@ describe("cancel flow", () => {
         bridge: ctx.bridgePda,
         pendingWithdraw: wp2,
         nonceUsed: nu2,
+        nonceBitmap: findNonceBitmapPda(
+          ctx.program.programId,
+          Buffer.from(EVM_CHAIN_ID),
+          20n
+        )[0],
         operator: ctx.operator.publicKey,
         systemProgram: SystemProgram.programId,
       })
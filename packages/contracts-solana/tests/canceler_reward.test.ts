@@ -0,0 +1,403 @@
+/** Canceler bounty pool — see docs/canceler-network.md (Canceler Rewards) */
+import * as anchor from "@coral-xyz/anchor";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTest,
+  findWithdrawPda,
+  findCancelerPda,
+  findCancelerRewardPoolPda,
+  findCancelRecordPda,
+  findExecutedHashPda,
+  findTokenPda,
+  findNonceUsedPda,
+  findNonceBitmapPda,
+  TestContext,
+  initializeBridgeIfNeeded,
+  registerChainIfNeeded,
+  NATIVE_SOL_TOKEN,
+} from "./helpers/setup";
+import { computeTransferHash } from "./helpers/hash";
+
+const SOLANA_CHAIN_ID = [0x00, 0x00, 0x00, 0x05];
+const EVM_CHAIN_ID = [0x00, 0x00, 0x00, 0x01];
+const REVIEW_PERIOD_SECS = 3;
+const REWARD_LAMPORTS = 5_000_000;
+
+const EVM_REMOTE_NATIVE_TOKEN = Buffer.alloc(32);
+EVM_REMOTE_NATIVE_TOKEN[31] = 0x37;
+
+async function sleep(ms: number): Promise<void> {
+  await new Promise((r) => setTimeout(r, ms));
+}
+
+describe("canceler reward pool", () => {
+  let ctx: TestContext;
+  let cancelerPda: PublicKey;
+  let rewardPoolPda: PublicKey;
+  let evmChainPda: PublicKey;
+  let withdrawNativeTokenMappingPda: PublicKey;
+
+  /** Submit + approve a native SOL withdraw and return its transfer hash. */
+  async function approvedWithdraw(nonce: bigint): Promise<Buffer> {
+    const srcAccount = Buffer.alloc(32, 0x44);
+    const amount = 250_000n;
+    const transferHash = computeTransferHash(
+      EVM_CHAIN_ID,
+      SOLANA_CHAIN_ID,
+      srcAccount,
+      ctx.user.publicKey.toBuffer(),
+      NATIVE_SOL_TOKEN.toBuffer(),
+      amount,
+      nonce
+    );
+    const [withdrawPda] = findWithdrawPda(ctx.program.programId, transferHash);
+
+    await ctx.program.methods
+      .withdrawSubmit({
+        srcChain: EVM_CHAIN_ID,
+        srcAccount: Array.from(srcAccount),
+        srcToken: Array.from(EVM_REMOTE_NATIVE_TOKEN),
+        destToken: NATIVE_SOL_TOKEN,
+        destAccount: ctx.user.publicKey,
+        amount: new anchor.BN(amount.toString()),
+        nonce: new anchor.BN(Number(nonce)),
+        operatorGas: new anchor.BN(0),
+      })
+      .accounts({
+        bridge: ctx.bridgePda,
+        srcChainEntry: evmChainPda,
+        tokenMapping: withdrawNativeTokenMappingPda,
+        pendingWithdraw: withdrawPda,
+        executedHashCheck: findExecutedHashPda(
+          ctx.program.programId,
+          transferHash
+        )[0],
+        payer: ctx.user.publicKey,
+        systemProgram: SystemProgram.programId,
+        nonceBitmapCheck: findNonceBitmapPda(
+          ctx.program.programId,
+          Buffer.from(EVM_CHAIN_ID),
+          nonce
+        )[0],
+      })
+      .signers([ctx.user])
+      .rpc();
+
+    await ctx.program.methods
+      .withdrawApprove({ transferHash: Array.from(transferHash) })
+      .accounts({
+        bridge: ctx.bridgePda,
+        pendingWithdraw: withdrawPda,
+        nonceUsed: findNonceUsedPda(
+          ctx.program.programId,
+          Buffer.from(EVM_CHAIN_ID),
+          nonce
+        )[0],
+        nonceBitmap: findNonceBitmapPda(
+          ctx.program.programId,
+          Buffer.from(EVM_CHAIN_ID),
+          nonce
+        )[0],
+        operator: ctx.operator.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([ctx.operator])
+      .rpc();
+
+    return transferHash;
+  }
+
+  async function cancel(transferHash: Buffer): Promise<void> {
+    await ctx.program.methods
+      .withdrawCancel()
+      .accounts({
+        bridge: ctx.bridgePda,
+        pendingWithdraw: findWithdrawPda(ctx.program.programId, transferHash)[0],
+        cancelerEntry: cancelerPda,
+        rewardPool: rewardPoolPda,
+        cancelRecord: findCancelRecordPda(
+          ctx.program.programId,
+          transferHash
+        )[0],
+        canceler: ctx.canceler.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([ctx.canceler])
+      .rpc();
+  }
+
+  async function reenable(transferHash: Buffer): Promise<void> {
+    await ctx.program.methods
+      .withdrawReenable()
+      .accounts({
+        bridge: ctx.bridgePda,
+        pendingWithdraw: findWithdrawPda(ctx.program.programId, transferHash)[0],
+        authority: ctx.admin.publicKey,
+        cancelRecord: findCancelRecordPda(
+          ctx.program.programId,
+          transferHash
+        )[0],
+        rewardPool: rewardPoolPda,
+      })
+      .rpc();
+  }
+
+  async function closeRecord(transferHash: Buffer): Promise<void> {
+    await ctx.program.methods
+      .closeCancelRecord()
+      .accounts({
+        cancelRecord: findCancelRecordPda(
+          ctx.program.programId,
+          transferHash
+        )[0],
+        pendingWithdraw: findWithdrawPda(ctx.program.programId, transferHash)[0],
+        canceler: ctx.canceler.publicKey,
+      })
+      .signers([ctx.canceler])
+      .rpc();
+  }
+
+  async function claim(transferHash: Buffer): Promise<void> {
+    await ctx.program.methods
+      .claimCancelerReward()
+      .accounts({
+        rewardPool: rewardPoolPda,
+        pendingWithdraw: findWithdrawPda(ctx.program.programId, transferHash)[0],
+        cancelRecord: findCancelRecordPda(
+          ctx.program.programId,
+          transferHash
+        )[0],
+        cancelerEntry: cancelerPda,
+        canceler: ctx.canceler.publicKey,
+      })
+      .signers([ctx.canceler])
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = await setupTest();
+
+    await initializeBridgeIfNeeded(ctx, {
+      operator: ctx.operator.publicKey,
+      feeBps: 50,
+      withdrawDelay: new anchor.BN(300),
+      chainId: SOLANA_CHAIN_ID,
+    });
+    await ctx.program.methods
+      .setConfig({
+        newAdmin: null,
+        operator: ctx.operator.publicKey,
+        feeBps: null,
+        withdrawDelay: null,
+        paused: null,
+      })
+      .accounts({
+        bridge: ctx.bridgePda,
+        admin: ctx.admin.publicKey,
+      })
+      .rpc();
+
+    evmChainPda = await registerChainIfNeeded(ctx, EVM_CHAIN_ID, "evm_1");
+
+    [withdrawNativeTokenMappingPda] = findTokenPda(
+      ctx.program.programId,
+      Buffer.from(EVM_CHAIN_ID),
+      EVM_REMOTE_NATIVE_TOKEN
+    );
+    const wInfo = await ctx.provider.connection.getAccountInfo(
+      withdrawNativeTokenMappingPda
+    );
+    if (!wInfo) {
+      await ctx.program.methods
+        .registerToken({
+          localMint: PublicKey.default,
+          destChain: EVM_CHAIN_ID,
+          destToken: Array.from(EVM_REMOTE_NATIVE_TOKEN),
+          mode: { lockUnlock: {} },
+          decimals: 9,
+          srcDecimals: 18,
+        })
+        .accounts({
+          bridge: ctx.bridgePda,
+          tokenMapping: withdrawNativeTokenMappingPda,
+          mint: null,
+          admin: ctx.admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    }
+
+    [cancelerPda] = findCancelerPda(
+      ctx.program.programId,
+      ctx.canceler.publicKey
+    );
+    await ctx.program.methods
+      .addCanceler({ canceler: ctx.canceler.publicKey, active: true })
+      .accounts({
+        bridge: ctx.bridgePda,
+        cancelerEntry: cancelerPda,
+        admin: ctx.admin.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    [rewardPoolPda] = findCancelerRewardPoolPda(ctx.program.programId);
+    await ctx.program.methods
+      .setCancelerReward({
+        rewardLamports: new anchor.BN(REWARD_LAMPORTS),
+        reviewPeriod: new anchor.BN(REVIEW_PERIOD_SECS),
+      })
+      .accounts({
+        bridge: ctx.bridgePda,
+        rewardPool: rewardPoolPda,
+        admin: ctx.admin.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    await ctx.program.methods
+      .fundCancelerReward({ amount: new anchor.BN(4 * REWARD_LAMPORTS) })
+      .accounts({
+        rewardPool: rewardPoolPda,
+        funder: ctx.admin.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  });
+
+  it("non-admin cannot configure rewards", async () => {
+    try {
+      await ctx.program.methods
+        .setCancelerReward({
+          rewardLamports: new anchor.BN(1),
+          reviewPeriod: new anchor.BN(REVIEW_PERIOD_SECS),
+        })
+        .accounts({
+          bridge: ctx.bridgePda,
+          rewardPool: rewardPoolPda,
+          admin: ctx.user.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([ctx.user])
+        .rpc();
+      expect.fail("Should have thrown");
+    } catch (err) {
+      expect(err.toString()).to.contain("UnauthorizedAdmin");
+    }
+  });
+
+  it("cancel records the canceler; claim waits for review period then pays", async () => {
+    const transferHash = await approvedWithdraw(301n);
+    await cancel(transferHash);
+
+    const record = await ctx.program.account.cancelRecord.fetch(
+      findCancelRecordPda(ctx.program.programId, transferHash)[0]
+    );
+    expect(record.canceler.toString()).to.equal(
+      ctx.canceler.publicKey.toString()
+    );
+    expect(record.claimed).to.be.false;
+
+    try {
+      await claim(transferHash);
+      expect.fail("Should have thrown ReviewPeriodActive");
+    } catch (err) {
+      expect(err.toString()).to.contain("ReviewPeriodActive");
+    }
+
+    await sleep((REVIEW_PERIOD_SECS + 1) * 1000);
+
+    const before = await ctx.provider.connection.getBalance(
+      ctx.canceler.publicKey
+    );
+    await claim(transferHash);
+    const after = await ctx.provider.connection.getBalance(
+      ctx.canceler.publicKey
+    );
+    // Canceler pays the tx fee, so the gain is slightly below the reward
+    expect(after - before).to.be.greaterThan(REWARD_LAMPORTS - 100_000);
+
+    const pool = await ctx.program.account.cancelerRewardPool.fetch(
+      rewardPoolPda
+    );
+    expect(pool.totalClaims.toNumber()).to.be.greaterThanOrEqual(1);
+
+    try {
+      await claim(transferHash);
+      expect.fail("Should have thrown CancelerRewardAlreadyClaimed");
+    } catch (err) {
+      expect(err.toString()).to.contain("CancelerRewardAlreadyClaimed");
+    }
+  });
+
+  it("reenabled (reverted) cancel cannot be claimed", async () => {
+    const transferHash = await approvedWithdraw(302n);
+    await cancel(transferHash);
+
+    const poolBefore = await ctx.program.account.cancelerRewardPool.fetch(
+      rewardPoolPda
+    );
+    await reenable(transferHash);
+
+    const record = await ctx.program.account.cancelRecord.fetch(
+      findCancelRecordPda(ctx.program.programId, transferHash)[0]
+    );
+    expect(record.reverted).to.be.true;
+    const poolAfter = await ctx.program.account.cancelerRewardPool.fetch(
+      rewardPoolPda
+    );
+    expect(
+      poolAfter.totalReverted.toNumber() - poolBefore.totalReverted.toNumber()
+    ).to.equal(1);
+
+    await sleep((REVIEW_PERIOD_SECS + 1) * 1000);
+
+    try {
+      await claim(transferHash);
+      expect.fail("Should have thrown CancelReverted");
+    } catch (err) {
+      expect(err.toString()).to.contain("CancelReverted");
+    }
+  });
+
+  it("canceler closes a reverted record and a re-cancel records again", async () => {
+    const transferHash = await approvedWithdraw(303n);
+    const [recordPda] = findCancelRecordPda(
+      ctx.program.programId,
+      transferHash
+    );
+    await cancel(transferHash);
+
+    try {
+      await closeRecord(transferHash);
+      expect.fail("Should have thrown CancelRecordActive");
+    } catch (err) {
+      expect(err.toString()).to.contain("CancelRecordActive");
+    }
+
+    await reenable(transferHash);
+    const before = await ctx.provider.connection.getBalance(
+      ctx.canceler.publicKey
+    );
+    await closeRecord(transferHash);
+    const after = await ctx.provider.connection.getBalance(
+      ctx.canceler.publicKey
+    );
+    expect(after).to.be.greaterThan(before);
+    expect(await ctx.provider.connection.getAccountInfo(recordPda)).to.be.null;
+
+    const poolBefore = await ctx.program.account.cancelerRewardPool.fetch(
+      rewardPoolPda
+    );
+    await cancel(transferHash);
+    const record = await ctx.program.account.cancelRecord.fetch(recordPda);
+    expect(record.reverted).to.be.false;
+    expect(record.claimed).to.be.false;
+    const poolAfter = await ctx.program.account.cancelerRewardPool.fetch(
+      rewardPoolPda
+    );
+    expect(
+      poolAfter.totalCancels.toNumber() - poolBefore.totalCancels.toNumber()
+    ).to.equal(1);
+  });
+});
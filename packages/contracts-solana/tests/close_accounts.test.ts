@@ -0,0 +1,549 @@
+/** Rent reclamation + nonce bitmap — see docs/SOLANA_BRIDGE_INVARIANTS.md (INV-W5) */
+import * as anchor from "@coral-xyz/anchor";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTest,
+  findWithdrawPda,
+  findCancelerPda,
+  findCancelRecordPda,
+  findExecutedHashPda,
+  findTokenPda,
+  findNonceUsedPda,
+  findNonceBitmapPda,
+  findDepositPda,
+  findDepositHashesPda,
+  findWithdrawRateLimitPda,
+  TestContext,
+  initializeBridgeIfNeeded,
+  registerChainIfNeeded,
+  getNextDepositNonce,
+  NATIVE_SOL_TOKEN,
+  NONCES_PER_BITMAP_CHUNK,
+} from "./helpers/setup";
+import { computeTransferHash } from "./helpers/hash";
+
+const SOLANA_CHAIN_ID = [0x00, 0x00, 0x00, 0x05];
+const EVM_CHAIN_ID = [0x00, 0x00, 0x00, 0x01];
+const SRC_ACCOUNT = Buffer.alloc(32, 0x55);
+const AMOUNT = 250_000n;
+
+const EVM_REMOTE_NATIVE_TOKEN = Buffer.alloc(32);
+EVM_REMOTE_NATIVE_TOKEN[31] = 0x37;
+
+/** Dedicated source chain with the minimum per-chain delay, so a withdraw can execute in-test. */
+const FAST_CHAIN_ID = [0x00, 0x00, 0x00, 0x3e];
+const FAST_REMOTE_NATIVE_TOKEN = Buffer.alloc(32, 0x3e);
+const FAST_CHAIN_DELAY_SECS = 15;
+
+/** Matches `tests/fixtures/legacy_nonce_used.json` (loaded into the validator by Anchor.toml). */
+const LEGACY_NONCE = NONCES_PER_BITMAP_CHUNK * 2n + 20n;
+
+interface Route {
+  chainId: number[];
+  chainPda: PublicKey;
+  tokenMapping: PublicKey;
+  srcToken: Buffer;
+}
+
+async function sleep(ms: number): Promise<void> {
+  return new Promise((resolve) => setTimeout(resolve, ms));
+}
+
+function findChainDelayPda(programId: PublicKey, chainId: number[]): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("chain_delay"), Buffer.from(chainId)],
+    programId
+  )[0];
+}
+
+describe("close finished accounts", () => {
+  let ctx: TestContext;
+  let cancelerPda: PublicKey;
+  let evmChainPda: PublicKey;
+  let withdrawNativeTokenMappingPda: PublicKey;
+  let evmRoute: Route;
+  let fastRoute: Route;
+
+  function hashFor(
+    nonce: bigint,
+    amount: bigint = AMOUNT,
+    chainId: number[] = EVM_CHAIN_ID
+  ): Buffer {
+    return computeTransferHash(
+      chainId,
+      SOLANA_CHAIN_ID,
+      SRC_ACCOUNT,
+      ctx.user.publicKey.toBuffer(),
+      NATIVE_SOL_TOKEN.toBuffer(),
+      amount,
+      nonce
+    );
+  }
+
+  async function submit(
+    nonce: bigint,
+    amount: bigint = AMOUNT,
+    route: Route = evmRoute
+  ): Promise<Buffer> {
+    const transferHash = hashFor(nonce, amount, route.chainId);
+    await ctx.program.methods
+      .withdrawSubmit({
+        srcChain: route.chainId,
+        srcAccount: Array.from(SRC_ACCOUNT),
+        srcToken: Array.from(route.srcToken),
+        destToken: NATIVE_SOL_TOKEN,
+        destAccount: ctx.user.publicKey,
+        amount: new anchor.BN(amount.toString()),
+        nonce: new anchor.BN(Number(nonce)),
+        operatorGas: new anchor.BN(0),
+      })
+      .accounts({
+        bridge: ctx.bridgePda,
+        srcChainEntry: route.chainPda,
+        tokenMapping: route.tokenMapping,
+        pendingWithdraw: findWithdrawPda(ctx.program.programId, transferHash)[0],
+        executedHashCheck: findExecutedHashPda(
+          ctx.program.programId,
+          transferHash
+        )[0],
+        payer: ctx.user.publicKey,
+        systemProgram: SystemProgram.programId,
+        nonceBitmapCheck: findNonceBitmapPda(
+          ctx.program.programId,
+          Buffer.from(route.chainId),
+          nonce
+        )[0],
+      })
+      .signers([ctx.user])
+      .rpc();
+    return transferHash;
+  }
+
+  async function approve(
+    transferHash: Buffer,
+    nonce: bigint,
+    chainId: number[] = EVM_CHAIN_ID
+  ): Promise<void> {
+    await ctx.program.methods
+      .withdrawApprove({ transferHash: Array.from(transferHash) })
+      .accounts({
+        bridge: ctx.bridgePda,
+        pendingWithdraw: findWithdrawPda(ctx.program.programId, transferHash)[0],
+        nonceUsed: findNonceUsedPda(
+          ctx.program.programId,
+          Buffer.from(chainId),
+          nonce
+        )[0],
+        nonceBitmap: findNonceBitmapPda(
+          ctx.program.programId,
+          Buffer.from(chainId),
+          nonce
+        )[0],
+        operator: ctx.operator.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([ctx.operator])
+      .rpc();
+  }
+
+  async function closeWithdraw(
+    transferHash: Buffer,
+    nonce: bigint,
+    accounts: {
+      pendingWithdraw?: boolean;
+      executedHash?: boolean;
+      nonceUsed?: boolean;
+    },
+    amount: bigint = AMOUNT,
+    chainId: number[] = EVM_CHAIN_ID
+  ): Promise<void> {
+    const builder = ctx.program.methods
+      .closeWithdraw({
+        transferHash: Array.from(transferHash),
+        srcChain: chainId,
+        srcAccount: Array.from(SRC_ACCOUNT),
+        destAccount: ctx.user.publicKey,
+        token: NATIVE_SOL_TOKEN,
+        amount: new anchor.BN(amount.toString()),
+        nonce: new anchor.BN(Number(nonce)),
+      })
+      .accounts({
+        bridge: ctx.bridgePda,
+        nonceBitmap: findNonceBitmapPda(
+          ctx.program.programId,
+          Buffer.from(chainId),
+          nonce
+        )[0],
+        pendingWithdraw: accounts.pendingWithdraw
+          ? findWithdrawPda(ctx.program.programId, transferHash)[0]
+          : null,
+        executedHash: accounts.executedHash
+          ? findExecutedHashPda(ctx.program.programId, transferHash)[0]
+          : null,
+        nonceUsed: accounts.nonceUsed
+          ? findNonceUsedPda(ctx.program.programId, Buffer.from(chainId), nonce)[0]
+          : null,
+        recipient: ctx.user.publicKey,
+        operator: ctx.operator.publicKey,
+        payer: ctx.admin.publicKey,
+        systemProgram: SystemProgram.programId,
+      });
+    // Closing the execution receipt refunds the recipient, who must sign.
+    await (accounts.executedHash ? builder.signers([ctx.user]) : builder).rpc();
+  }
+
+  before(async () => {
+    ctx = await setupTest();
+
+    await initializeBridgeIfNeeded(ctx, {
+      operator: ctx.operator.publicKey,
+      feeBps: 50,
+      withdrawDelay: new anchor.BN(300),
+      chainId: SOLANA_CHAIN_ID,
+    });
+    await ctx.program.methods
+      .setConfig({
+        newAdmin: null,
+        operator: ctx.operator.publicKey,
+        feeBps: null,
+        withdrawDelay: null,
+        paused: null,
+      })
+      .accounts({
+        bridge: ctx.bridgePda,
+        admin: ctx.admin.publicKey,
+      })
+      .rpc();
+
+    evmChainPda = await registerChainIfNeeded(ctx, EVM_CHAIN_ID, "evm_1");
+
+    [withdrawNativeTokenMappingPda] = findTokenPda(
+      ctx.program.programId,
+      Buffer.from(EVM_CHAIN_ID),
+      EVM_REMOTE_NATIVE_TOKEN
+    );
+    if (
+      !(await ctx.provider.connection.getAccountInfo(
+        withdrawNativeTokenMappingPda
+      ))
+    ) {
+      await ctx.program.methods
+        .registerToken({
+          localMint: PublicKey.default,
+          destChain: EVM_CHAIN_ID,
+          destToken: Array.from(EVM_REMOTE_NATIVE_TOKEN),
+          mode: { lockUnlock: {} },
+          decimals: 9,
+          srcDecimals: 18,
+        })
+        .accounts({
+          bridge: ctx.bridgePda,
+          tokenMapping: withdrawNativeTokenMappingPda,
+          mint: null,
+          admin: ctx.admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    }
+
+    evmRoute = {
+      chainId: EVM_CHAIN_ID,
+      chainPda: evmChainPda,
+      tokenMapping: withdrawNativeTokenMappingPda,
+      srcToken: EVM_REMOTE_NATIVE_TOKEN,
+    };
+
+    const fastChainPda = await registerChainIfNeeded(ctx, FAST_CHAIN_ID, "evm_close");
+    const [fastTokenMappingPda] = findTokenPda(
+      ctx.program.programId,
+      Buffer.from(FAST_CHAIN_ID),
+      FAST_REMOTE_NATIVE_TOKEN
+    );
+    if (!(await ctx.provider.connection.getAccountInfo(fastTokenMappingPda))) {
+      await ctx.program.methods
+        .registerToken({
+          localMint: PublicKey.default,
+          destChain: FAST_CHAIN_ID,
+          destToken: Array.from(FAST_REMOTE_NATIVE_TOKEN),
+          mode: { lockUnlock: {} },
+          decimals: 9,
+          srcDecimals: 9,
+        })
+        .accounts({
+          bridge: ctx.bridgePda,
+          tokenMapping: fastTokenMappingPda,
+          mint: null,
+          admin: ctx.admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    }
+    await ctx.program.methods
+      .setChainWithdrawDelay({
+        withdrawDelay: new anchor.BN(FAST_CHAIN_DELAY_SECS),
+      })
+      .accounts({
+        bridge: ctx.bridgePda,
+        chainEntry: fastChainPda,
+        chainWithdrawDelay: findChainDelayPda(ctx.program.programId, FAST_CHAIN_ID),
+        admin: ctx.admin.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    fastRoute = {
+      chainId: FAST_CHAIN_ID,
+      chainPda: fastChainPda,
+      tokenMapping: fastTokenMappingPda,
+      srcToken: FAST_REMOTE_NATIVE_TOKEN,
+    };
+
+    [cancelerPda] = findCancelerPda(
+      ctx.program.programId,
+      ctx.canceler.publicKey
+    );
+    await ctx.program.methods
+      .addCanceler({ canceler: ctx.canceler.publicKey, active: true })
+      .accounts({
+        bridge: ctx.bridgePda,
+        cancelerEntry: cancelerPda,
+        admin: ctx.admin.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  });
+
+  it("approve records the nonce in the bitmap instead of a NonceUsed PDA", async () => {
+    const nonce = NONCES_PER_BITMAP_CHUNK * 2n + 11n;
+    await approve(await submit(nonce), nonce);
+
+    const bitmap = await ctx.program.account.nonceBitmap.fetch(
+      findNonceBitmapPda(
+        ctx.program.programId,
+        Buffer.from(EVM_CHAIN_ID),
+        nonce
+      )[0]
+    );
+    expect(bitmap.chunk.toNumber()).to.equal(2);
+    expect(bitmap.bits[1] & (1 << 3)).to.not.equal(0);
+    expect(
+      await ctx.provider.connection.getAccountInfo(
+        findNonceUsedPda(ctx.program.programId, Buffer.from(EVM_CHAIN_ID), nonce)[0]
+      )
+    ).to.be.null;
+  });
+
+  it("rejects a second approval of the same nonce", async () => {
+    const nonce = NONCES_PER_BITMAP_CHUNK * 2n + 12n;
+    await approve(await submit(nonce), nonce);
+
+    const replay = await submit(nonce, AMOUNT + 1n);
+    try {
+      await approve(replay, nonce);
+      expect.fail("Should have thrown");
+    } catch (err) {
+      expect(err.toString()).to.contain("NonceAlreadyApproved");
+    }
+  });
+
+  it("keeps a cancelled withdraw until its retention elapses", async () => {
+    const nonce = NONCES_PER_BITMAP_CHUNK * 2n + 13n;
+    const transferHash = await submit(nonce);
+    await approve(transferHash, nonce);
+    await ctx.program.methods
+      .withdrawCancel()
+      .accounts({
+        bridge: ctx.bridgePda,
+        pendingWithdraw: findWithdrawPda(ctx.program.programId, transferHash)[0],
+        cancelerEntry: cancelerPda,
+        cancelRecord: findCancelRecordPda(
+          ctx.program.programId,
+          transferHash
+        )[0],
+        canceler: ctx.canceler.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([ctx.canceler])
+      .rpc();
+
+    try {
+      await closeWithdraw(transferHash, nonce, { pendingWithdraw: true });
+      expect.fail("Should have thrown");
+    } catch (err) {
+      expect(err.toString()).to.contain("RetentionNotElapsed");
+    }
+  });
+
+  it("refuses to close an approved, uncancelled withdraw", async () => {
+    const nonce = NONCES_PER_BITMAP_CHUNK * 2n + 14n;
+    const transferHash = await submit(nonce);
+    await approve(transferHash, nonce);
+    try {
+      await closeWithdraw(transferHash, nonce, { pendingWithdraw: true });
+      expect.fail("Should have thrown");
+    } catch (err) {
+      expect(err.toString()).to.contain("WithdrawNotFinished");
+    }
+  });
+
+  it("rejects withdraw fields that do not match the transfer hash", async () => {
+    const nonce = NONCES_PER_BITMAP_CHUNK * 2n + 11n;
+    try {
+      await closeWithdraw(hashFor(nonce), nonce, { pendingWithdraw: true }, AMOUNT + 1n);
+      expect.fail("Should have thrown");
+    } catch (err) {
+      expect(err.toString()).to.contain("HashMismatch");
+    }
+  });
+
+  it("rejects a close with nothing to close", async () => {
+    const nonce = NONCES_PER_BITMAP_CHUNK * 2n + 11n;
+    try {
+      await closeWithdraw(hashFor(nonce), nonce, {});
+      expect.fail("Should have thrown");
+    } catch (err) {
+      expect(err.toString()).to.contain("NothingToClose");
+    }
+  });
+
+  it("closes the execution receipt after a real execute", async () => {
+    // Lock some SOL so the bridge can pay out the withdraw.
+    const depositNonce = await getNextDepositNonce(ctx);
+    await ctx.program.methods
+      .depositNative({
+        destChain: EVM_CHAIN_ID,
+        destAccount: Array.from(Buffer.alloc(32, 0xbb)),
+        amount: new anchor.BN(1_000_000),
+      })
+      .accounts({
+        bridge: ctx.bridgePda,
+        depositRecord: findDepositPda(ctx.program.programId, depositNonce)[0],
+        destChainEntry: evmChainPda,
+        tokenMapping: withdrawNativeTokenMappingPda,
+        depositor: ctx.user.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([ctx.user])
+      .rpc();
+
+    const nonce = NONCES_PER_BITMAP_CHUNK * 2n + 15n;
+    const transferHash = await submit(nonce, AMOUNT, fastRoute);
+    await approve(transferHash, nonce, FAST_CHAIN_ID);
+    await sleep((FAST_CHAIN_DELAY_SECS + 2) * 1000);
+
+    const [executedHashPda] = findExecutedHashPda(
+      ctx.program.programId,
+      transferHash
+    );
+    await ctx.program.methods
+      .withdrawExecuteNative()
+      .accounts({
+        bridge: ctx.bridgePda,
+        pendingWithdraw: findWithdrawPda(ctx.program.programId, transferHash)[0],
+        executedHash: executedHashPda,
+        withdrawRateLimit: findWithdrawRateLimitPda(
+          ctx.program.programId,
+          NATIVE_SOL_TOKEN
+        )[0],
+        recipient: ctx.user.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([ctx.user])
+      .rpc();
+    expect(await ctx.provider.connection.getAccountInfo(executedHashPda)).to.not
+      .be.null;
+
+    await closeWithdraw(
+      transferHash,
+      nonce,
+      { executedHash: true },
+      AMOUNT,
+      FAST_CHAIN_ID
+    );
+    expect(await ctx.provider.connection.getAccountInfo(executedHashPda)).to.be
+      .null;
+
+    // The bitmap still blocks the nonce once the receipt is gone.
+    try {
+      await submit(nonce, AMOUNT, fastRoute);
+      expect.fail("Should have thrown");
+    } catch (err) {
+      expect(err.toString()).to.contain("NonceAlreadyApproved");
+    }
+  });
+
+  it("migrates a legacy NonceUsed marker into the bitmap", async () => {
+    const [nonceUsedPda] = findNonceUsedPda(
+      ctx.program.programId,
+      Buffer.from(EVM_CHAIN_ID),
+      LEGACY_NONCE
+    );
+    const legacy = await ctx.provider.connection.getAccountInfo(nonceUsedPda);
+    expect(legacy, "fixture from Anchor.toml").to.not.be.null;
+    const operatorBefore = await ctx.provider.connection.getBalance(
+      ctx.operator.publicKey
+    );
+
+    await closeWithdraw(hashFor(LEGACY_NONCE), LEGACY_NONCE, { nonceUsed: true });
+
+    expect(await ctx.provider.connection.getAccountInfo(nonceUsedPda)).to.be
+      .null;
+    expect(
+      await ctx.provider.connection.getBalance(ctx.operator.publicKey)
+    ).to.equal(operatorBefore + legacy!.lamports);
+    const bitmap = await ctx.program.account.nonceBitmap.fetch(
+      findNonceBitmapPda(
+        ctx.program.programId,
+        Buffer.from(EVM_CHAIN_ID),
+        LEGACY_NONCE
+      )[0]
+    );
+    expect(bitmap.bits[2] & (1 << 4)).to.not.equal(0);
+
+    try {
+      await submit(LEGACY_NONCE);
+      expect.fail("Should have thrown");
+    } catch (err) {
+      expect(err.toString()).to.contain("NonceAlreadyApproved");
+    }
+  });
+
+  it("keeps a fresh deposit record", async () => {
+    const nonce = await getNextDepositNonce(ctx);
+    const [depositPda] = findDepositPda(ctx.program.programId, nonce);
+    await ctx.program.methods
+      .depositNative({
+        destChain: EVM_CHAIN_ID,
+        destAccount: Array.from(Buffer.alloc(32, 0xbb)),
+        amount: new anchor.BN(1_000_000),
+      })
+      .accounts({
+        bridge: ctx.bridgePda,
+        depositRecord: depositPda,
+        destChainEntry: evmChainPda,
+        tokenMapping: withdrawNativeTokenMappingPda,
+        depositor: ctx.user.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([ctx.user])
+      .rpc();
+
+    try {
+      await ctx.program.methods
+        .closeDeposit()
+        .accounts({
+          depositRecord: depositPda,
+          depositor: ctx.user.publicKey,
+          depositHashes: findDepositHashesPda(
+            ctx.program.programId,
+            BigInt(nonce)
+          )[0],
+          payer: ctx.provider.wallet.publicKey,
+        })
+        .rpc();
+      expect.fail("Should have thrown");
+    } catch (err) {
+      expect(err.toString()).to.contain("RetentionNotElapsed");
+    }
+  });
+});
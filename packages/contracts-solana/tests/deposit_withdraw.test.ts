@@ -19,6 +19,11 @@ import {
   findExecutedHashPda,
   findTokenPda,
   findNonceUsedPda,
+  findNonceBitmapPda,
+  findRentSponsorPda,
+  findWithdrawSponsorshipPda,
+  findRelayConfigPda,
+  findRelayRequestPda,
   airdrop,
   TestContext,
   initializeBridgeIfNeeded,
@@ -453,6 +458,11 @@ describe("deposit and withdraw flow", () => {
           executedHashCheck: executedHashPda,
           payer: ctx.user.publicKey,
           systemProgram: SystemProgram.programId,
+          nonceBitmapCheck: findNonceBitmapPda(
+            ctx.program.programId,
+            Buffer.from(srcChain),
+            withdrawNonce
+          )[0],
         })
         .signers([ctx.user])
         .rpc();
@@ -482,6 +492,11 @@ describe("deposit and withdraw flow", () => {
             bridge: ctx.bridgePda,
             pendingWithdraw: withdrawPda,
             nonceUsed: nonceUsedPda,
+            nonceBitmap: findNonceBitmapPda(
+              ctx.program.programId,
+              Buffer.from(EVM_CHAIN_ID),
+              withdrawNonce
+            )[0],
             operator: ctx.user.publicKey,
             systemProgram: SystemProgram.programId,
           })
@@ -510,6 +525,11 @@ describe("deposit and withdraw flow", () => {
           bridge: ctx.bridgePda,
           pendingWithdraw: withdrawPda,
           nonceUsed: nonceUsedPda,
+          nonceBitmap: findNonceBitmapPda(
+            ctx.program.programId,
+            Buffer.from(EVM_CHAIN_ID),
+            withdrawNonce
+          )[0],
           operator: ctx.operator.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -539,6 +559,11 @@ describe("deposit and withdraw flow", () => {
             bridge: ctx.bridgePda,
             pendingWithdraw: withdrawPda,
             nonceUsed: nonceUsedPda,
+            nonceBitmap: findNonceBitmapPda(
+              ctx.program.programId,
+              Buffer.from(EVM_CHAIN_ID),
+              withdrawNonce
+            )[0],
             operator: ctx.operator.publicKey,
             systemProgram: SystemProgram.programId,
           })
@@ -626,6 +651,11 @@ describe("deposit and withdraw flow", () => {
             executedHashCheck: executedHashPda,
             payer: ctx.user.publicKey,
             systemProgram: SystemProgram.programId,
+            nonceBitmapCheck: findNonceBitmapPda(
+              ctx.program.programId,
+              Buffer.from(srcChain),
+              withdrawNonce
+            )[0],
           })
           .signers([ctx.user])
           .rpc();
@@ -640,6 +670,609 @@ describe("deposit and withdraw flow", () => {
     });
   });
 
+  describe("withdraw_execute_native_by_operator", () => {
+    let transferHash: Buffer;
+    const withdrawAmount = 250_000_000_000_000_000n;
+    const withdrawNonce = 101n;
+    const srcAccount = Buffer.alloc(32, 0xab);
+
+    const executeAccounts = (operator: PublicKey) => ({
+      bridge: ctx.bridgePda,
+      pendingWithdraw: findWithdrawPda(ctx.program.programId, transferHash)[0],
+      executedHash: findExecutedHashPda(ctx.program.programId, transferHash)[0],
+      withdrawRateLimit: findWithdrawRateLimitPda(
+        ctx.program.programId,
+        NATIVE_SOL_TOKEN
+      )[0],
+      recipient: ctx.user.publicKey,
+      operator,
+      systemProgram: SystemProgram.programId,
+    });
+
+    before(async () => {
+      transferHash = computeTransferHash(
+        EVM_CHAIN_ID,
+        SOLANA_CHAIN_ID,
+        srcAccount,
+        ctx.user.publicKey.toBuffer(),
+        NATIVE_SOL_TOKEN.toBuffer(),
+        withdrawAmount,
+        withdrawNonce
+      );
+      const [withdrawPda] = findWithdrawPda(
+        ctx.program.programId,
+        transferHash
+      );
+
+      await ctx.program.methods
+        .withdrawSubmit({
+          srcChain: EVM_CHAIN_ID,
+          srcAccount: Array.from(srcAccount),
+          srcToken: Array.from(EVM_REMOTE_NATIVE_TOKEN),
+          destToken: NATIVE_SOL_TOKEN,
+          destAccount: ctx.user.publicKey,
+          amount: new anchor.BN(withdrawAmount.toString()),
+          nonce: new anchor.BN(Number(withdrawNonce)),
+          operatorGas: new anchor.BN(0),
+        })
+        .accounts({
+          bridge: ctx.bridgePda,
+          srcChainEntry: evmChainPda,
+          tokenMapping: withdrawNativeTokenMappingPda,
+          pendingWithdraw: withdrawPda,
+          executedHashCheck: findExecutedHashPda(
+            ctx.program.programId,
+            transferHash
+          )[0],
+          payer: ctx.user.publicKey,
+          systemProgram: SystemProgram.programId,
+          nonceBitmapCheck: findNonceBitmapPda(
+            ctx.program.programId,
+            Buffer.from(EVM_CHAIN_ID),
+            withdrawNonce
+          )[0],
+        })
+        .signers([ctx.user])
+        .rpc();
+
+      await ctx.program.methods
+        .withdrawApprove({ transferHash: Array.from(transferHash) })
+        .accounts({
+          bridge: ctx.bridgePda,
+          pendingWithdraw: withdrawPda,
+          nonceUsed: findNonceUsedPda(
+            ctx.program.programId,
+            Buffer.from(EVM_CHAIN_ID),
+            withdrawNonce
+          )[0],
+          nonceBitmap: findNonceBitmapPda(
+            ctx.program.programId,
+            Buffer.from(EVM_CHAIN_ID),
+            withdrawNonce
+          )[0],
+          operator: ctx.operator.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([ctx.operator])
+        .rpc();
+    });
+
+    it("rejects execution inside the cancel window", async () => {
+      try {
+        await ctx.program.methods
+          .withdrawExecuteNativeByOperator()
+          .accounts(executeAccounts(ctx.operator.publicKey))
+          .signers([ctx.operator])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err) {
+        expect(err.toString()).to.contain("DelayNotElapsed");
+      }
+    });
+
+    it("rejects a signer other than the operator", async () => {
+      const stranger = Keypair.generate();
+      await airdrop(
+        ctx.provider.connection,
+        stranger.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      try {
+        await ctx.program.methods
+          .withdrawExecuteNativeByOperator()
+          .accounts(executeAccounts(stranger.publicKey))
+          .signers([stranger])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err) {
+        expect(err.toString()).to.contain("UnauthorizedOperator");
+      }
+    });
+
+    it("operator executes to the recipient after the delay", async () => {
+      await new Promise((r) =>
+        setTimeout(r, (WITHDRAW_DELAY_SECONDS + 3) * 1000)
+      );
+
+      const balanceBefore = await ctx.provider.connection.getBalance(
+        ctx.user.publicKey
+      );
+
+      await ctx.program.methods
+        .withdrawExecuteNativeByOperator()
+        .accounts(executeAccounts(ctx.operator.publicKey))
+        .signers([ctx.operator])
+        .rpc();
+
+      const balanceAfter = await ctx.provider.connection.getBalance(
+        ctx.user.publicKey
+      );
+      // 0.25 SOL (18 → 9 decimals) plus the PendingWithdraw rent refund
+      expect(balanceAfter - balanceBefore).to.be.greaterThanOrEqual(
+        250_000_000
+      );
+
+      const executed = await ctx.program.account.executedHash.fetch(
+        findExecutedHashPda(ctx.program.programId, transferHash)[0]
+      );
+      expect(executed).to.not.be.null;
+    });
+  });
+
+  describe("withdraw_submit_and_approve", () => {
+    const withdrawAmount = 100_000_000_000_000_000n;
+    const srcAccount = Buffer.alloc(32, 0xac);
+
+    const hashFor = (nonce: bigint) =>
+      computeTransferHash(
+        EVM_CHAIN_ID,
+        SOLANA_CHAIN_ID,
+        srcAccount,
+        ctx.user.publicKey.toBuffer(),
+        NATIVE_SOL_TOKEN.toBuffer(),
+        withdrawAmount,
+        nonce
+      );
+
+    const submitAndApprove = (
+      nonce: bigint,
+      rentSource: object,
+      opts: {
+        operator?: Keypair;
+        sponsor: boolean;
+        sponsorship: boolean;
+        relayFee?: bigint;
+        relayConfig?: boolean;
+      }
+    ) => {
+      const transferHash = hashFor(nonce);
+      const operator = opts.operator ?? ctx.operator;
+      return ctx.program.methods
+        .withdrawSubmitAndApprove({
+          srcChain: EVM_CHAIN_ID,
+          srcAccount: Array.from(srcAccount),
+          srcToken: Array.from(EVM_REMOTE_NATIVE_TOKEN),
+          destToken: NATIVE_SOL_TOKEN,
+          destAccount: ctx.user.publicKey,
+          amount: new anchor.BN(withdrawAmount.toString()),
+          nonce: new anchor.BN(Number(nonce)),
+          rentSource: rentSource as any,
+          relayFee: new anchor.BN((opts.relayFee ?? 0n).toString()),
+        })
+        .accounts({
+          bridge: ctx.bridgePda,
+          srcChainEntry: evmChainPda,
+          tokenMapping: withdrawNativeTokenMappingPda,
+          pendingWithdraw: findWithdrawPda(
+            ctx.program.programId,
+            transferHash
+          )[0],
+          executedHashCheck: findExecutedHashPda(
+            ctx.program.programId,
+            transferHash
+          )[0],
+          nonceUsed: findNonceUsedPda(
+            ctx.program.programId,
+            Buffer.from(EVM_CHAIN_ID),
+            nonce
+          )[0],
+          nonceBitmap: findNonceBitmapPda(
+            ctx.program.programId,
+            Buffer.from(EVM_CHAIN_ID),
+            nonce
+          )[0],
+          rentSponsor: opts.sponsor
+            ? findRentSponsorPda(ctx.program.programId)[0]
+            : null,
+          sponsorship: opts.sponsorship
+            ? findWithdrawSponsorshipPda(ctx.program.programId, transferHash)[0]
+            : null,
+          operator: operator.publicKey,
+          systemProgram: SystemProgram.programId,
+          relayConfig: (opts.relayConfig ?? (opts.relayFee ?? 0n) > 0n)
+            ? findRelayConfigPda(ctx.program.programId)[0]
+            : null,
+        } as any)
+        .signers([operator])
+        .rpc();
+    };
+
+    it("rejects a signer other than the operator", async () => {
+      const stranger = Keypair.generate();
+      await airdrop(
+        ctx.provider.connection,
+        stranger.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      try {
+        await submitAndApprove(
+          110n,
+          { withdrawal: {} },
+          { operator: stranger, sponsor: false, sponsorship: true }
+        );
+        expect.fail("Should have thrown");
+      } catch (err) {
+        expect(err.toString()).to.contain("UnauthorizedOperator");
+      }
+    });
+
+    it("rejects a rent source without its account", async () => {
+      try {
+        await submitAndApprove(
+          110n,
+          { sponsor: {} },
+          { sponsor: false, sponsorship: false }
+        );
+        expect.fail("Should have thrown");
+      } catch (err) {
+        expect(err.toString()).to.contain("InvalidRentSource");
+      }
+    });
+
+    it("repays the operator from the rent sponsor", async () => {
+      const [sponsorPda] = findRentSponsorPda(ctx.program.programId);
+      await ctx.program.methods
+        .fundRentSponsor({ amount: new anchor.BN(LAMPORTS_PER_SOL / 10) })
+        .accounts({
+          rentSponsor: sponsorPda,
+          funder: ctx.admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .rpc();
+      const before = await ctx.program.account.rentSponsor.fetch(sponsorPda);
+
+      await submitAndApprove(
+        111n,
+        { sponsor: {} },
+        { sponsor: true, sponsorship: false }
+      );
+
+      const pending = await ctx.program.account.pendingWithdraw.fetch(
+        findWithdrawPda(ctx.program.programId, hashFor(111n))[0]
+      );
+      expect(pending.approved).to.be.true;
+      const after = await ctx.program.account.rentSponsor.fetch(sponsorPda);
+      expect(after.totalPaid.gt(before.totalPaid)).to.be.true;
+    });
+
+    it("repays the operator from the PendingWithdraw rent on execute", async () => {
+      const transferHash = hashFor(112n);
+      const [sponsorshipPda] = findWithdrawSponsorshipPda(
+        ctx.program.programId,
+        transferHash
+      );
+      await submitAndApprove(
+        112n,
+        { withdrawal: {} },
+        { sponsor: false, sponsorship: true }
+      );
+      const pending = await ctx.program.account.pendingWithdraw.fetch(
+        findWithdrawPda(ctx.program.programId, transferHash)[0]
+      );
+      expect(pending.approved).to.be.true;
+
+      await new Promise((r) =>
+        setTimeout(r, (WITHDRAW_DELAY_SECONDS + 3) * 1000)
+      );
+      const balanceBefore = await ctx.provider.connection.getBalance(
+        ctx.user.publicKey
+      );
+      await ctx.program.methods
+        .withdrawExecuteNativeByOperator()
+        .accounts({
+          bridge: ctx.bridgePda,
+          pendingWithdraw: findWithdrawPda(
+            ctx.program.programId,
+            transferHash
+          )[0],
+          executedHash: findExecutedHashPda(
+            ctx.program.programId,
+            transferHash
+          )[0],
+          withdrawRateLimit: findWithdrawRateLimitPda(
+            ctx.program.programId,
+            NATIVE_SOL_TOKEN
+          )[0],
+          recipient: ctx.user.publicKey,
+          operator: ctx.operator.publicKey,
+          systemProgram: SystemProgram.programId,
+          sponsorship: sponsorshipPda,
+        } as any)
+        .signers([ctx.operator])
+        .rpc();
+
+      const balanceAfter = await ctx.provider.connection.getBalance(
+        ctx.user.publicKey
+      );
+      // 0.1 SOL (18 → 9 decimals) and no PendingWithdraw rent
+      expect(balanceAfter - balanceBefore).to.equal(100_000_000);
+      expect(await ctx.provider.connection.getAccountInfo(sponsorshipPda)).to.be
+        .null;
+    });
+
+    it("keeps the operator's rent when the recipient executes", async () => {
+      const transferHash = hashFor(113n);
+      const [sponsorshipPda] = findWithdrawSponsorshipPda(
+        ctx.program.programId,
+        transferHash
+      );
+      const [pendingPda] = findWithdrawPda(ctx.program.programId, transferHash);
+      await submitAndApprove(
+        113n,
+        { withdrawal: {} },
+        { sponsor: false, sponsorship: true }
+      );
+      const pendingRent = await ctx.provider.connection.getBalance(pendingPda);
+
+      await new Promise((r) =>
+        setTimeout(r, (WITHDRAW_DELAY_SECONDS + 3) * 1000)
+      );
+      await ctx.program.methods
+        .withdrawExecuteNative()
+        .accounts({
+          bridge: ctx.bridgePda,
+          pendingWithdraw: pendingPda,
+          executedHash: findExecutedHashPda(
+            ctx.program.programId,
+            transferHash
+          )[0],
+          withdrawRateLimit: findWithdrawRateLimitPda(
+            ctx.program.programId,
+            NATIVE_SOL_TOKEN
+          )[0],
+          recipient: ctx.user.publicKey,
+          systemProgram: SystemProgram.programId,
+          sponsorship: sponsorshipPda,
+        } as any)
+        .signers([ctx.user])
+        .rpc();
+
+      // The PendingWithdraw rent is parked in the sponsorship, not paid to the recipient
+      const sponsorshipInfo = await ctx.provider.connection.getAccountInfo(
+        sponsorshipPda
+      );
+      const sponsorshipRent =
+        await ctx.provider.connection.getMinimumBalanceForRentExemption(
+          sponsorshipInfo.data.length
+        );
+      expect(sponsorshipInfo.lamports).to.equal(sponsorshipRent + pendingRent);
+
+      const operatorBefore = await ctx.provider.connection.getBalance(
+        ctx.operator.publicKey
+      );
+      await ctx.program.methods
+        .closeWithdrawSponsorship()
+        .accounts({
+          bridge: ctx.bridgePda,
+          sponsorship: sponsorshipPda,
+          pendingWithdraw: pendingPda,
+          operator: ctx.operator.publicKey,
+        } as any)
+        .signers([ctx.operator])
+        .rpc();
+      const operatorAfter = await ctx.provider.connection.getBalance(
+        ctx.operator.publicKey
+      );
+      expect(operatorAfter).to.be.greaterThan(
+        operatorBefore + pendingRent - 10_000
+      );
+    });
+
+    it("rejects a relay fee while relaying is disabled", async () => {
+      try {
+        await submitAndApprove(
+          114n,
+          { withdrawal: {} },
+          {
+            sponsor: false,
+            sponsorship: true,
+            relayFee: 1n,
+            relayConfig: false,
+          }
+        );
+        expect.fail("Should have thrown");
+      } catch (err) {
+        expect(err.toString()).to.contain("RelayingDisabled");
+      }
+    });
+
+    it("bounds relay fees by the admin-set cap", async () => {
+      const setCap = (relayFeeCapBps: number) =>
+        ctx.program.methods
+          .setRelayFeeCap({ relayFeeCapBps })
+          .accounts({
+            bridge: ctx.bridgePda,
+            relayConfig: findRelayConfigPda(ctx.program.programId)[0],
+            admin: ctx.admin.publicKey,
+            systemProgram: SystemProgram.programId,
+          } as any)
+          .rpc();
+
+      try {
+        await setCap(501);
+        expect.fail("Should have thrown");
+      } catch (err) {
+        expect(err.toString()).to.contain("RelayFeeCapExceedsMax");
+      }
+      await setCap(100);
+
+      // 1% is allowed, one unit more is not
+      try {
+        await submitAndApprove(
+          114n,
+          { withdrawal: {} },
+          {
+            sponsor: false,
+            sponsorship: true,
+            relayFee: withdrawAmount / 100n + 1n,
+          }
+        );
+        expect.fail("Should have thrown");
+      } catch (err) {
+        expect(err.toString()).to.contain("RelayFeeTooHigh");
+      }
+
+      // The fee is recorded on the sponsorship, so it needs the withdrawal rent source
+      try {
+        await submitAndApprove(
+          114n,
+          { sponsor: {} },
+          { sponsor: true, sponsorship: false, relayFee: 1n }
+        );
+        expect.fail("Should have thrown");
+      } catch (err) {
+        expect(err.toString()).to.contain("InvalidRentSource");
+      }
+    });
+
+    it("pays the relay fee to the operator on execute", async () => {
+      const transferHash = hashFor(114n);
+      const [sponsorshipPda] = findWithdrawSponsorshipPda(
+        ctx.program.programId,
+        transferHash
+      );
+      await submitAndApprove(
+        114n,
+        { withdrawal: {} },
+        { sponsor: false, sponsorship: true, relayFee: withdrawAmount / 100n }
+      );
+      const sponsorship = await ctx.program.account.withdrawSponsorship.fetch(
+        sponsorshipPda
+      );
+      expect(sponsorship.relayFee.toString()).to.equal(
+        (withdrawAmount / 100n).toString()
+      );
+
+      await new Promise((r) =>
+        setTimeout(r, (WITHDRAW_DELAY_SECONDS + 3) * 1000)
+      );
+      const balanceBefore = await ctx.provider.connection.getBalance(
+        ctx.user.publicKey
+      );
+      await ctx.program.methods
+        .withdrawExecuteNativeByOperator()
+        .accounts({
+          bridge: ctx.bridgePda,
+          pendingWithdraw: findWithdrawPda(
+            ctx.program.programId,
+            transferHash
+          )[0],
+          executedHash: findExecutedHashPda(
+            ctx.program.programId,
+            transferHash
+          )[0],
+          withdrawRateLimit: findWithdrawRateLimitPda(
+            ctx.program.programId,
+            NATIVE_SOL_TOKEN
+          )[0],
+          recipient: ctx.user.publicKey,
+          operator: ctx.operator.publicKey,
+          systemProgram: SystemProgram.programId,
+          sponsorship: sponsorshipPda,
+        } as any)
+        .signers([ctx.operator])
+        .rpc();
+
+      const balanceAfter = await ctx.provider.connection.getBalance(
+        ctx.user.publicKey
+      );
+      // 0.1 SOL minus the 1% relay fee
+      expect(balanceAfter - balanceBefore).to.equal(99_000_000);
+      expect(await ctx.provider.connection.getAccountInfo(sponsorshipPda)).to.be
+        .null;
+    });
+  });
+
+  describe("request_relay", () => {
+    it("lets the depositor opt a deposit in once", async () => {
+      const nonce = await getNextDepositNonce(ctx);
+      const [depositPda] = findDepositPda(ctx.program.programId, nonce);
+      await ctx.program.methods
+        .depositNative({
+          destChain: EVM_CHAIN_ID,
+          destAccount: Array.from(Buffer.alloc(32, 0xbe)),
+          amount: new anchor.BN(1_000_000),
+        })
+        .accounts({
+          bridge: ctx.bridgePda,
+          depositRecord: depositPda,
+          destChainEntry: evmChainPda,
+          tokenMapping: depositTokenMappingPda,
+          depositor: ctx.user.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([ctx.user])
+        .rpc();
+      const deposit = await ctx.program.account.depositRecord.fetch(depositPda);
+      const [relayRequestPda] = findRelayRequestPda(
+        ctx.program.programId,
+        Buffer.from(deposit.transferHash)
+      );
+
+      const requestRelay = (depositor: Keypair, maxRelayFee: number) =>
+        ctx.program.methods
+          .requestRelay({
+            nonce: new anchor.BN(nonce),
+            maxRelayFee: new anchor.BN(maxRelayFee),
+          })
+          .accounts({
+            bridge: ctx.bridgePda,
+            depositRecord: depositPda,
+            relayRequest: relayRequestPda,
+            depositor: depositor.publicKey,
+            systemProgram: SystemProgram.programId,
+          } as any)
+          .signers([depositor])
+          .rpc();
+
+      try {
+        await requestRelay(ctx.operator, 1_000);
+        expect.fail("Should have thrown");
+      } catch (err) {
+        expect(err.toString()).to.contain("NotDepositor");
+      }
+      try {
+        await requestRelay(ctx.user, deposit.amount.toNumber());
+        expect.fail("Should have thrown");
+      } catch (err) {
+        expect(err.toString()).to.contain("InvalidMaxRelayFee");
+      }
+
+      await requestRelay(ctx.user, 1_000);
+      const request = await ctx.program.account.relayRequest.fetch(
+        relayRequestPda
+      );
+      expect(request.maxRelayFee.toNumber()).to.equal(1_000);
+
+      try {
+        await requestRelay(ctx.user, 2_000);
+        expect.fail("Should have thrown");
+      } catch (err) {
+        expect(err.toString()).to.contain("already in use");
+      }
+    });
+  });
+
   describe("withdraw_approve rejects when paused", () => {
     it("operator cannot approve when bridge is paused", async () => {
       const srcAccount = Buffer.alloc(32, 0xdd);
@@ -681,6 +1314,11 @@ describe("deposit and withdraw flow", () => {
           executedHashCheck: executedHashPda,
           payer: ctx.user.publicKey,
           systemProgram: SystemProgram.programId,
+          nonceBitmapCheck: findNonceBitmapPda(
+            ctx.program.programId,
+            Buffer.from(EVM_CHAIN_ID),
+            nonce
+          )[0],
         })
         .signers([ctx.user])
         .rpc();
@@ -709,6 +1347,11 @@ describe("deposit and withdraw flow", () => {
             bridge: ctx.bridgePda,
             pendingWithdraw: withdrawPda,
             nonceUsed: nonceUsedPda,
+            nonceBitmap: findNonceBitmapPda(
+              ctx.program.programId,
+              Buffer.from(EVM_CHAIN_ID),
+              nonce
+            )[0],
             operator: ctx.operator.publicKey,
             systemProgram: SystemProgram.programId,
           })
@@ -0,0 +1,307 @@
+/** Deposit fee tiers — see docs/SOLANA_BRIDGE_DEPOSITS.md (Deposit fees) */
+import * as anchor from "@coral-xyz/anchor";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
+import {
+  createMint,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+} from "@solana/spl-token";
+import { expect } from "chai";
+import {
+  setupTest,
+  findDepositPda,
+  findTokenPda,
+  findFeeSchedulePda,
+  findCustomFeePda,
+  findTokenFeePda,
+  TestContext,
+  initializeBridgeIfNeeded,
+  registerChainIfNeeded,
+  getNextDepositNonce,
+} from "./helpers/setup";
+
+const SOLANA_CHAIN_ID = [0x00, 0x00, 0x00, 0x05];
+const EVM_CHAIN_ID = [0x00, 0x00, 0x00, 0x01];
+const AMOUNT = 1_000_000;
+const CL8Y_THRESHOLD = 100_000_000;
+
+/** Mapping dedicated to this file so the token override does not leak into other suites. */
+const FEE_TIER_DEST_TOKEN = Buffer.alloc(32, 0xfe);
+
+describe("deposit fee tiers", () => {
+  let ctx: TestContext;
+  let evmChainPda: PublicKey;
+  let tokenMappingPda: PublicKey;
+  let tokenFeePda: PublicKey;
+  let feeSchedulePda: PublicKey;
+  let customFeePda: PublicKey;
+  let cl8yAccount: PublicKey;
+  let standardBps: number;
+
+  /** Deposit native SOL with the given optional fee accounts; returns the charged fee. */
+  async function depositFee(opts: {
+    feeSchedule?: PublicKey;
+    cl8yTokenAccount?: PublicKey;
+  }): Promise<number> {
+    const nonce = await getNextDepositNonce(ctx);
+    const [depositPda] = findDepositPda(ctx.program.programId, nonce);
+    await ctx.program.methods
+      .depositNative({
+        destChain: EVM_CHAIN_ID,
+        destAccount: Array.from(Buffer.alloc(32, 0xbb)),
+        amount: new anchor.BN(AMOUNT),
+      })
+      .accounts({
+        bridge: ctx.bridgePda,
+        depositRecord: depositPda,
+        destChainEntry: evmChainPda,
+        tokenMapping: tokenMappingPda,
+        depositor: ctx.user.publicKey,
+        systemProgram: SystemProgram.programId,
+        tokenFeeOverride: tokenFeePda,
+        feeSchedule: opts.feeSchedule ?? null,
+        customAccountFee: customFeePda,
+        cl8yTokenAccount: opts.cl8yTokenAccount ?? null,
+      })
+      .signers([ctx.user])
+      .rpc();
+    const deposit = await ctx.program.account.depositRecord.fetch(depositPda);
+    return AMOUNT - Number(deposit.amount);
+  }
+
+  async function setTokenFee(feeBps: number | null): Promise<void> {
+    if (feeBps === null) {
+      if (!(await ctx.provider.connection.getAccountInfo(tokenFeePda))) return;
+      await ctx.program.methods
+        .removeTokenFee()
+        .accounts({
+          bridge: ctx.bridgePda,
+          tokenFeeOverride: tokenFeePda,
+          admin: ctx.admin.publicKey,
+        })
+        .rpc();
+      return;
+    }
+    await ctx.program.methods
+      .setTokenFee({ feeBps })
+      .accounts({
+        bridge: ctx.bridgePda,
+        tokenMapping: tokenMappingPda,
+        tokenFeeOverride: tokenFeePda,
+        admin: ctx.admin.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = await setupTest();
+
+    await initializeBridgeIfNeeded(ctx, {
+      operator: ctx.operator.publicKey,
+      feeBps: 50,
+      withdrawDelay: new anchor.BN(300),
+      chainId: SOLANA_CHAIN_ID,
+    });
+    standardBps = (await ctx.program.account.bridgeConfig.fetch(ctx.bridgePda))
+      .feeBps;
+
+    evmChainPda = await registerChainIfNeeded(ctx, EVM_CHAIN_ID, "evm_1");
+
+    [tokenMappingPda] = findTokenPda(
+      ctx.program.programId,
+      Buffer.from(EVM_CHAIN_ID),
+      FEE_TIER_DEST_TOKEN
+    );
+    if (!(await ctx.provider.connection.getAccountInfo(tokenMappingPda))) {
+      await ctx.program.methods
+        .registerToken({
+          localMint: PublicKey.default,
+          destChain: EVM_CHAIN_ID,
+          destToken: Array.from(FEE_TIER_DEST_TOKEN),
+          mode: { lockUnlock: {} },
+          decimals: 9,
+          srcDecimals: 18,
+        })
+        .accounts({
+          bridge: ctx.bridgePda,
+          tokenMapping: tokenMappingPda,
+          mint: null,
+          admin: ctx.admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    }
+    [tokenFeePda] = findTokenFeePda(ctx.program.programId, tokenMappingPda);
+    await setTokenFee(null);
+
+    const cl8yMint = await createMint(
+      ctx.provider.connection,
+      ctx.admin,
+      ctx.admin.publicKey,
+      null,
+      6
+    );
+    cl8yAccount = (
+      await getOrCreateAssociatedTokenAccount(
+        ctx.provider.connection,
+        ctx.admin,
+        cl8yMint,
+        ctx.user.publicKey
+      )
+    ).address;
+
+    [feeSchedulePda] = findFeeSchedulePda(ctx.program.programId);
+    await ctx.program.methods
+      .setFeeSchedule({
+        discountedFeeBps: 10,
+        cl8yMint,
+        cl8yThreshold: new anchor.BN(CL8Y_THRESHOLD),
+      })
+      .accounts({
+        bridge: ctx.bridgePda,
+        feeSchedule: feeSchedulePda,
+        admin: ctx.admin.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    [customFeePda] = findCustomFeePda(
+      ctx.program.programId,
+      ctx.user.publicKey
+    );
+  });
+
+  it("charges the bridge fee when no tier accounts are passed", async () => {
+    const fee = await depositFee({});
+    expect(fee).to.equal(Math.floor((AMOUNT * standardBps) / 10000));
+  });
+
+  it("token override replaces the bridge fee and cannot be skipped", async () => {
+    await setTokenFee(80);
+    expect(await depositFee({})).to.equal(8_000);
+    await setTokenFee(null);
+  });
+
+  it("rejects token override above 1%", async () => {
+    try {
+      await setTokenFee(101);
+      expect.fail("Should have thrown");
+    } catch (err) {
+      expect(err.toString()).to.contain("InvalidFeeBps");
+    }
+  });
+
+  it("CL8Y discount applies only at the threshold", async () => {
+    expect(
+      await depositFee({
+        feeSchedule: feeSchedulePda,
+        cl8yTokenAccount: cl8yAccount,
+      })
+    ).to.equal(Math.floor((AMOUNT * standardBps) / 10000));
+
+    const schedule = await ctx.program.account.feeSchedule.fetch(
+      feeSchedulePda
+    );
+    await mintTo(
+      ctx.provider.connection,
+      ctx.admin,
+      schedule.cl8yMint,
+      cl8yAccount,
+      ctx.admin,
+      CL8Y_THRESHOLD
+    );
+
+    expect(
+      await depositFee({
+        feeSchedule: feeSchedulePda,
+        cl8yTokenAccount: cl8yAccount,
+      })
+    ).to.equal(1_000);
+  });
+
+  it("rejects a CL8Y token account of the wrong mint", async () => {
+    const otherMint = await createMint(
+      ctx.provider.connection,
+      ctx.admin,
+      ctx.admin.publicKey,
+      null,
+      6
+    );
+    const other = await getOrCreateAssociatedTokenAccount(
+      ctx.provider.connection,
+      ctx.admin,
+      otherMint,
+      ctx.user.publicKey
+    );
+    try {
+      await depositFee({
+        feeSchedule: feeSchedulePda,
+        cl8yTokenAccount: other.address,
+      });
+      expect.fail("Should have thrown");
+    } catch (err) {
+      expect(err.toString()).to.contain("InvalidCl8yTokenAccount");
+    }
+  });
+
+  it("custom account fee takes priority and can be removed", async () => {
+    await ctx.program.methods
+      .setCustomAccountFee({ account: ctx.user.publicKey, feeBps: 0 })
+      .accounts({
+        bridge: ctx.bridgePda,
+        customAccountFee: customFeePda,
+        admin: ctx.admin.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    expect(
+      await depositFee({
+        feeSchedule: feeSchedulePda,
+        cl8yTokenAccount: cl8yAccount,
+      })
+    ).to.equal(0);
+
+    // A custom fee above the standard rate applies too: the PDA is always passed.
+    await ctx.program.methods
+      .setCustomAccountFee({ account: ctx.user.publicKey, feeBps: 100 })
+      .accounts({
+        bridge: ctx.bridgePda,
+        customAccountFee: customFeePda,
+        admin: ctx.admin.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    expect(await depositFee({})).to.equal((AMOUNT * 100) / 10_000);
+
+    await ctx.program.methods
+      .removeCustomAccountFee()
+      .accounts({
+        bridge: ctx.bridgePda,
+        customAccountFee: customFeePda,
+        admin: ctx.admin.publicKey,
+      })
+      .rpc();
+    expect(await ctx.provider.connection.getAccountInfo(customFeePda)).to.be
+      .null;
+  });
+
+  it("non-admin cannot set a custom account fee", async () => {
+    try {
+      await ctx.program.methods
+        .setCustomAccountFee({ account: ctx.user.publicKey, feeBps: 0 })
+        .accounts({
+          bridge: ctx.bridgePda,
+          customAccountFee: customFeePda,
+          admin: ctx.user.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([ctx.user])
+        .rpc();
+      expect.fail("Should have thrown");
+    } catch (err) {
+      expect(err.toString()).to.contain("UnauthorizedAdmin");
+    }
+  });
+});
@@ -38,6 +38,7 @@ import {
   setupTest,
   NATIVE_SOL_TOKEN,
   findNonceUsedPda,
+  findNonceBitmapPda,
 } from "./helpers/setup";
 import { computeTransferHash } from "./helpers/hash";
 
@@ -343,6 +344,11 @@ describe("FULL E2E SECURITY AUDIT", () => {
         executedHashCheck: executedHashPda,
         payer: recipient.publicKey,
         systemProgram: SystemProgram.programId,
+        nonceBitmapCheck: findNonceBitmapPda(
+          ctx.program.programId,
+          Buffer.from(EVM_CHAIN_ID),
+          nonce
+        )[0],
       })
       .signers([recipient])
       .rpc();
@@ -365,6 +371,11 @@ describe("FULL E2E SECURITY AUDIT", () => {
         bridge: ctx.bridgePda,
         pendingWithdraw: withdrawPda,
         nonceUsed: nonceUsedPda,
+        nonceBitmap: findNonceBitmapPda(
+          ctx.program.programId,
+          Buffer.from(EVM_CHAIN_ID),
+          nonce
+        )[0],
         operator: ctx.operator.publicKey,
         systemProgram: SystemProgram.programId,
       })
@@ -1148,6 +1159,11 @@ describe("FULL E2E SECURITY AUDIT", () => {
             executedHashCheck: victimExecutedPda,
             payer: attacker.publicKey,
             systemProgram: SystemProgram.programId,
+            nonceBitmapCheck: findNonceBitmapPda(
+              ctx.program.programId,
+              Buffer.from(EVM_CHAIN_ID),
+              nonce
+            )[0],
           })
           .signers([attacker])
           .rpc();
@@ -1504,6 +1520,11 @@ describe("FULL E2E SECURITY AUDIT", () => {
             executedHashCheck: replayExecutedPda,
             payer: ctx.user.publicKey,
             systemProgram: SystemProgram.programId,
+            nonceBitmapCheck: findNonceBitmapPda(
+              ctx.program.programId,
+              Buffer.from(EVM_CHAIN_ID),
+              nonce
+            )[0],
           })
           .signers([ctx.user])
           .rpc();
@@ -1978,6 +1999,11 @@ describe("FULL E2E SECURITY AUDIT", () => {
               Buffer.from(EVM_CHAIN_ID),
               0n
             )[0],
+            nonceBitmap: findNonceBitmapPda(
+              ctx.program.programId,
+              Buffer.from(EVM_CHAIN_ID),
+              0n
+            )[0],
             operator: ctx.operator.publicKey,
             systemProgram: SystemProgram.programId,
           })
@@ -2752,6 +2778,11 @@ describe("FULL E2E SECURITY AUDIT", () => {
             executedHashCheck: executedPda,
             payer: ctx.user.publicKey,
             systemProgram: SystemProgram.programId,
+            nonceBitmapCheck: findNonceBitmapPda(
+              ctx.program.programId,
+              Buffer.from(EVM_CHAIN_ID),
+              nonce
+            )[0],
           })
           .signers([ctx.user])
           .rpc();
@@ -2852,6 +2883,11 @@ describe("FULL E2E SECURITY AUDIT", () => {
             executedHashCheck: ep,
             payer: ctx.user.publicKey,
             systemProgram: SystemProgram.programId,
+            nonceBitmapCheck: findNonceBitmapPda(
+              ctx.program.programId,
+              Buffer.from(EVM_CHAIN_ID),
+              nonce
+            )[0],
           })
           .signers([ctx.user])
           .rpc();
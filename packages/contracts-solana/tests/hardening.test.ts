@@ -32,6 +32,7 @@ import {
   setupTest,
   NATIVE_SOL_TOKEN,
   findNonceUsedPda,
+  findNonceBitmapPda,
 } from "./helpers/setup";
 import { computeTransferHash } from "./helpers/hash";
 
@@ -217,6 +218,11 @@ describe("hardening tests", () => {
         executedHashCheck: executedHashPda,
         payer: recipient.publicKey,
         systemProgram: SystemProgram.programId,
+        nonceBitmapCheck: findNonceBitmapPda(
+          ctx.program.programId,
+          Buffer.from(EVM_CHAIN_ID),
+          nonce
+        )[0],
       })
       .signers([recipient])
       .rpc();
@@ -239,6 +245,11 @@ describe("hardening tests", () => {
         bridge: ctx.bridgePda,
         pendingWithdraw: withdrawPda,
         nonceUsed: nonceUsedPda,
+        nonceBitmap: findNonceBitmapPda(
+          ctx.program.programId,
+          Buffer.from(EVM_CHAIN_ID),
+          nonce
+        )[0],
         operator: ctx.operator.publicKey,
         systemProgram: SystemProgram.programId,
       })
@@ -594,6 +605,11 @@ describe("hardening tests", () => {
             executedHashCheck: executedHashPda,
             payer: ctx.user.publicKey,
             systemProgram: SystemProgram.programId,
+            nonceBitmapCheck: findNonceBitmapPda(
+              ctx.program.programId,
+              Buffer.from(EVM_CHAIN_ID),
+              9990n
+            )[0],
           })
           .signers([ctx.user])
           .rpc();
@@ -38,7 +38,22 @@ export const TOKEN_SEED = Buffer.from("token");
 export const CANCELER_SEED = Buffer.from("canceler");
 export const EXECUTED_SEED = Buffer.from("executed");
 export const NONCE_USED_SEED = Buffer.from("nonce_used");
+export const NONCE_BITMAP_SEED = Buffer.from("nonce_bitmap");
+/** Nonces per `NonceBitmap` chunk (1024 bytes * 8). */
+export const NONCES_PER_BITMAP_CHUNK = 8192n;
+export const DEPOSIT_HASHES_SEED = Buffer.from("deposit_hashes");
+/** Nonces per `DepositHashes` chunk (32 transfer hashes). */
+export const DEPOSIT_HASHES_PER_CHUNK = 32n;
 export const WITHDRAW_RATE_LIMIT_SEED = Buffer.from("w_rate_lim");
+export const CANCELER_REWARD_SEED = Buffer.from("canceler_reward");
+export const CANCEL_RECORD_SEED = Buffer.from("cancel_record");
+export const FEE_SCHEDULE_SEED = Buffer.from("fee_schedule");
+export const CUSTOM_FEE_SEED = Buffer.from("custom_fee");
+export const TOKEN_FEE_SEED = Buffer.from("token_fee");
+export const RENT_SPONSOR_SEED = Buffer.from("rent_sponsor");
+export const WITHDRAW_SPONSORSHIP_SEED = Buffer.from("sponsorship");
+export const RELAY_CONFIG_SEED = Buffer.from("relay_config");
+export const RELAY_REQUEST_SEED = Buffer.from("relay_request");
 
 /** PDA for per-mint withdraw rate limit state (matches `WithdrawRateLimit::SEED`). */
 export function findWithdrawRateLimitPda(
@@ -215,6 +230,110 @@ export function findNonceUsedPda(
   );
 }
 
+/** Approved-nonce bitmap chunk for `(srcChain, nonce)` (matches `NonceBitmap::SEED`). */
+export function findNonceBitmapPda(
+  programId: PublicKey,
+  srcChain: Buffer,
+  nonce: bigint
+): [PublicKey, number] {
+  const chunkBuf = Buffer.alloc(8);
+  chunkBuf.writeBigUInt64LE(nonce / NONCES_PER_BITMAP_CHUNK);
+  return PublicKey.findProgramAddressSync(
+    [NONCE_BITMAP_SEED, srcChain, chunkBuf],
+    programId
+  );
+}
+
+/** PDA keeping the transfer hashes of closed deposit records (matches `DepositHashes::SEED`). */
+export function findDepositHashesPda(
+  programId: PublicKey,
+  nonce: bigint
+): [PublicKey, number] {
+  const chunkBuf = Buffer.alloc(8);
+  chunkBuf.writeBigUInt64LE(nonce / DEPOSIT_HASHES_PER_CHUNK);
+  return PublicKey.findProgramAddressSync(
+    [DEPOSIT_HASHES_SEED, chunkBuf],
+    programId
+  );
+}
+
+/** PDA holding the canceler bounty pool (matches `CancelerRewardPool::SEED`). */
+export function findCancelerRewardPoolPda(
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([CANCELER_REWARD_SEED], programId);
+}
+
+/** PDA repaying operator-paid withdraw rent (matches `RentSponsor::SEED`). */
+export function findRentSponsorPda(programId: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([RENT_SPONSOR_SEED], programId);
+}
+
+/** PDA recording operator-paid withdraw rent (matches `WithdrawSponsorship::SEED`). */
+export function findWithdrawSponsorshipPda(
+  programId: PublicKey,
+  transferHash: Buffer
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [WITHDRAW_SPONSORSHIP_SEED, transferHash],
+    programId
+  );
+}
+
+/** PDA holding the relay fee cap (matches `RelayConfig::SEED`). */
+export function findRelayConfigPda(programId: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([RELAY_CONFIG_SEED], programId);
+}
+
+/** PDA recording a depositor's relay opt-in (matches `RelayRequest::SEED`). */
+export function findRelayRequestPda(
+  programId: PublicKey,
+  transferHash: Buffer
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [RELAY_REQUEST_SEED, transferHash],
+    programId
+  );
+}
+
+/** PDA recording which canceler cancelled a withdrawal (matches `CancelRecord::SEED`). */
+export function findCancelRecordPda(
+  programId: PublicKey,
+  transferHash: Buffer
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [CANCEL_RECORD_SEED, transferHash],
+    programId
+  );
+}
+
+/** PDA holding the CL8Y discount tier (matches `FeeSchedule::SEED`). */
+export function findFeeSchedulePda(programId: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([FEE_SCHEDULE_SEED], programId);
+}
+
+/** PDA holding a per-mapping fee override (matches `TokenFeeOverride::SEED`). */
+export function findTokenFeePda(
+  programId: PublicKey,
+  tokenMapping: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [TOKEN_FEE_SEED, tokenMapping.toBuffer()],
+    programId
+  );
+}
+
+/** PDA holding a per-depositor fee (matches `CustomAccountFee::SEED`). */
+export function findCustomFeePda(
+  programId: PublicKey,
+  account: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [CUSTOM_FEE_SEED, account.toBuffer()],
+    programId
+  );
+}
+
 export interface TestContext {
   provider: anchor.AnchorProvider;
   program: Program<Cl8yBridge>;
@@ -37,6 +37,7 @@ import {
   setupTest,
   NATIVE_SOL_TOKEN,
   findNonceUsedPda,
+  findNonceBitmapPda,
 } from "./helpers/setup";
 import { computeTransferHash } from "./helpers/hash";
 
@@ -321,6 +322,11 @@ describe("security audit: top-20 Solana vulnerability patterns", () => {
         executedHashCheck: executedHashPda,
         payer: recipient.publicKey,
         systemProgram: SystemProgram.programId,
+        nonceBitmapCheck: findNonceBitmapPda(
+          ctx.program.programId,
+          Buffer.from(EVM_CHAIN_ID),
+          nonce
+        )[0],
       })
       .signers([recipient])
       .rpc();
@@ -343,6 +349,11 @@ describe("security audit: top-20 Solana vulnerability patterns", () => {
         bridge: ctx.bridgePda,
         pendingWithdraw: withdrawPda,
         nonceUsed: nonceUsedPda,
+        nonceBitmap: findNonceBitmapPda(
+          ctx.program.programId,
+          Buffer.from(EVM_CHAIN_ID),
+          nonce
+        )[0],
         operator: ctx.operator.publicKey,
         systemProgram: SystemProgram.programId,
       })
@@ -435,6 +446,11 @@ describe("security audit: top-20 Solana vulnerability patterns", () => {
               Buffer.from(EVM_CHAIN_ID),
               9001n
             )[0],
+            nonceBitmap: findNonceBitmapPda(
+              ctx.program.programId,
+              Buffer.from(EVM_CHAIN_ID),
+              9001n
+            )[0],
             operator: fakeSigner.publicKey,
             systemProgram: SystemProgram.programId,
           })
@@ -559,6 +575,11 @@ describe("security audit: top-20 Solana vulnerability patterns", () => {
               Buffer.from(EVM_CHAIN_ID),
               9003n
             )[0],
+            nonceBitmap: findNonceBitmapPda(
+              ctx.program.programId,
+              Buffer.from(EVM_CHAIN_ID),
+              9003n
+            )[0],
             operator: ctx.operator.publicKey,
             systemProgram: SystemProgram.programId,
           })
@@ -734,6 +755,11 @@ describe("security audit: top-20 Solana vulnerability patterns", () => {
             executedHashCheck: replayExecutedPda,
             payer: ctx.user.publicKey,
             systemProgram: SystemProgram.programId,
+            nonceBitmapCheck: findNonceBitmapPda(
+              ctx.program.programId,
+              Buffer.from(EVM_CHAIN_ID),
+              nonce
+            )[0],
           })
           .signers([ctx.user])
           .rpc();
@@ -945,6 +971,11 @@ describe("security audit: top-20 Solana vulnerability patterns", () => {
             executedHashCheck: executedHashPda,
             payer: attacker.publicKey,
             systemProgram: SystemProgram.programId,
+            nonceBitmapCheck: findNonceBitmapPda(
+              ctx.program.programId,
+              Buffer.from(EVM_CHAIN_ID),
+              9011n
+            )[0],
           })
           .signers([attacker])
           .rpc();
@@ -1106,6 +1137,11 @@ describe("security audit: top-20 Solana vulnerability patterns", () => {
             executedHashCheck: executedHashPda,
             payer: ctx.user.publicKey,
             systemProgram: SystemProgram.programId,
+            nonceBitmapCheck: findNonceBitmapPda(
+              ctx.program.programId,
+              Buffer.from(EVM_CHAIN_ID),
+              9020n
+            )[0],
           })
           .signers([ctx.user])
           .rpc();
@@ -1550,6 +1586,11 @@ describe("security audit: top-20 Solana vulnerability patterns", () => {
               Buffer.from(EVM_CHAIN_ID),
               9050n
             )[0],
+            nonceBitmap: findNonceBitmapPda(
+              ctx.program.programId,
+              Buffer.from(EVM_CHAIN_ID),
+              9050n
+            )[0],
             operator: ctx.operator.publicKey,
             systemProgram: SystemProgram.programId,
           })
@@ -1570,6 +1611,11 @@ describe("security audit: top-20 Solana vulnerability patterns", () => {
             Buffer.from(EVM_CHAIN_ID),
             9050n
           )[0],
+          nonceBitmap: findNonceBitmapPda(
+            ctx.program.programId,
+            Buffer.from(EVM_CHAIN_ID),
+            9050n
+          )[0],
           operator: newOperator.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -28,6 +28,7 @@ import {
   findWithdrawPda,
   findWithdrawRateLimitPda,
   findNonceUsedPda,
+  findNonceBitmapPda,
   getNextDepositNonce,
   initializeBridgeIfNeeded,
   registerChainIfNeeded,
@@ -245,6 +246,11 @@ describe("bridge SPL security and multi-user coverage", () => {
         executedHashCheck: executedHashPda,
         payer: recipient.publicKey,
         systemProgram: SystemProgram.programId,
+        nonceBitmapCheck: findNonceBitmapPda(
+          ctx.program.programId,
+          Buffer.from(EVM_CHAIN_ID),
+          nonce
+        )[0],
       })
       .signers([recipient])
       .rpc();
@@ -391,6 +397,11 @@ describe("bridge SPL security and multi-user coverage", () => {
           bridge: ctx.bridgePda,
           pendingWithdraw: withdrawPda,
           nonceUsed: nonceUsedPda,
+          nonceBitmap: findNonceBitmapPda(
+            ctx.program.programId,
+            Buffer.from(EVM_CHAIN_ID),
+            5001n
+          )[0],
           operator: ctx.operator.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -493,6 +504,11 @@ describe("bridge SPL security and multi-user coverage", () => {
           bridge: ctx.bridgePda,
           pendingWithdraw: withdrawPda,
           nonceUsed: nonceUsedMb,
+          nonceBitmap: findNonceBitmapPda(
+            ctx.program.programId,
+            Buffer.from(EVM_CHAIN_ID),
+            6001n
+          )[0],
           operator: ctx.operator.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -622,6 +638,11 @@ describe("bridge SPL security and multi-user coverage", () => {
           bridge: ctx.bridgePda,
           pendingWithdraw: withdrawPda,
           nonceUsed: nonceUsed7001,
+          nonceBitmap: findNonceBitmapPda(
+            ctx.program.programId,
+            Buffer.from(EVM_CHAIN_ID),
+            7001n
+          )[0],
           operator: ctx.operator.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -986,6 +1007,11 @@ describe("bridge SPL security and multi-user coverage", () => {
             executedHashCheck: executedHashPda,
             payer: ctx.user.publicKey,
             systemProgram: SystemProgram.programId,
+            nonceBitmapCheck: findNonceBitmapPda(
+              ctx.program.programId,
+              Buffer.from(EVM_CHAIN_ID),
+              nonce
+            )[0],
           })
           .signers([ctx.user])
           .rpc();
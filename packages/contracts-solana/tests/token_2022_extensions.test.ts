@@ -0,0 +1,263 @@
+/**
+ * Token-2022 extension policy — see INV-D3 in docs/SOLANA_BRIDGE_INVARIANTS.md.
+ * Transfer-fee mints record the post-fee amount; custody-breaking extensions are rejected at register.
+ */
+import * as anchor from "@coral-xyz/anchor";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  Transaction,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import {
+  ExtensionType,
+  TOKEN_2022_PROGRAM_ID,
+  createAssociatedTokenAccountIdempotentInstruction,
+  createInitializeMintInstruction,
+  createInitializePermanentDelegateInstruction,
+  createInitializeTransferFeeConfigInstruction,
+  getAccount,
+  getAssociatedTokenAddressSync,
+  getMintLen,
+  mintTo,
+} from "@solana/spl-token";
+import { expect } from "chai";
+
+import { computeTransferHash } from "./helpers/hash";
+import {
+  TestContext,
+  airdrop,
+  findDepositPda,
+  findTokenPda,
+  getNextDepositNonce,
+  initializeBridgeIfNeeded,
+  registerChainIfNeeded,
+  setupTest,
+} from "./helpers/setup";
+
+const SOLANA_CHAIN_ID = [0x00, 0x00, 0x00, 0x05];
+const EVM_CHAIN_ID = [0x00, 0x00, 0x00, 0x01];
+const TP2022 = TOKEN_2022_PROGRAM_ID;
+const TRANSFER_FEE_BPS = 100;
+
+describe("Token-2022 extension policy", () => {
+  let ctx: TestContext;
+  let evmChainPda: PublicKey;
+  let bridgeFeeBps: bigint;
+
+  /** Create a Token-2022 mint with the given extensions initialized by `extensionIxs`. */
+  async function createExtensionMint(
+    extensions: ExtensionType[],
+    extensionIxs: (mint: PublicKey) => anchor.web3.TransactionInstruction[]
+  ): Promise<PublicKey> {
+    const mint = Keypair.generate();
+    const space = getMintLen(extensions);
+    const lamports =
+      await ctx.provider.connection.getMinimumBalanceForRentExemption(space);
+    const tx = new Transaction().add(
+      SystemProgram.createAccount({
+        fromPubkey: ctx.admin.publicKey,
+        newAccountPubkey: mint.publicKey,
+        space,
+        lamports,
+        programId: TP2022,
+      }),
+      ...extensionIxs(mint.publicKey),
+      createInitializeMintInstruction(
+        mint.publicKey,
+        6,
+        ctx.admin.publicKey,
+        null,
+        TP2022
+      )
+    );
+    await sendAndConfirmTransaction(ctx.provider.connection, tx, [
+      ctx.admin,
+      mint,
+    ]);
+    return mint.publicKey;
+  }
+
+  async function registerLockUnlock(
+    mint: PublicKey,
+    destToken: Buffer
+  ): Promise<PublicKey> {
+    const [tokenPda] = findTokenPda(
+      ctx.program.programId,
+      EVM_CHAIN_ID,
+      destToken
+    );
+    await ctx.program.methods
+      .registerToken({
+        localMint: mint,
+        destChain: EVM_CHAIN_ID,
+        destToken: Array.from(destToken),
+        mode: { lockUnlock: {} },
+        decimals: 6,
+        srcDecimals: 6,
+      })
+      .accounts({
+        bridge: ctx.bridgePda,
+        tokenMapping: tokenPda,
+        mint,
+        admin: ctx.admin.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    return tokenPda;
+  }
+
+  before(async () => {
+    ctx = await setupTest();
+    await airdrop(
+      ctx.provider.connection,
+      ctx.admin.publicKey,
+      10 * LAMPORTS_PER_SOL
+    );
+    await initializeBridgeIfNeeded(ctx, {
+      operator: ctx.operator.publicKey,
+      feeBps: 50,
+      withdrawDelay: new anchor.BN(300),
+      chainId: SOLANA_CHAIN_ID,
+    });
+    bridgeFeeBps = BigInt(
+      (await ctx.program.account.bridgeConfig.fetch(ctx.bridgePda)).feeBps
+    );
+    evmChainPda = await registerChainIfNeeded(ctx, EVM_CHAIN_ID, "evm_1");
+  });
+
+  it("rejects a mint with a permanent delegate", async () => {
+    const mint = await createExtensionMint(
+      [ExtensionType.PermanentDelegate],
+      (m) => [
+        createInitializePermanentDelegateInstruction(
+          m,
+          ctx.admin.publicKey,
+          TP2022
+        ),
+      ]
+    );
+    try {
+      await registerLockUnlock(mint, Buffer.alloc(32, 0xd1));
+      expect.fail("Should have thrown");
+    } catch (err) {
+      expect(err.toString()).to.contain("UnsupportedMintExtension");
+    }
+  });
+
+  it("records the post-transfer-fee amount in the deposit hash", async () => {
+    const mint = await createExtensionMint(
+      [ExtensionType.TransferFeeConfig],
+      (m) => [
+        createInitializeTransferFeeConfigInstruction(
+          m,
+          ctx.admin.publicKey,
+          ctx.admin.publicKey,
+          TRANSFER_FEE_BPS,
+          BigInt(Number.MAX_SAFE_INTEGER),
+          TP2022
+        ),
+      ]
+    );
+    const destToken = Buffer.alloc(32, 0xd2);
+    const tokenPda = await registerLockUnlock(mint, destToken);
+
+    const userAta = getAssociatedTokenAddressSync(
+      mint,
+      ctx.user.publicKey,
+      false,
+      TP2022
+    );
+    const bridgeAta = getAssociatedTokenAddressSync(
+      mint,
+      ctx.bridgePda,
+      true,
+      TP2022
+    );
+    await sendAndConfirmTransaction(
+      ctx.provider.connection,
+      new Transaction().add(
+        createAssociatedTokenAccountIdempotentInstruction(
+          ctx.admin.publicKey,
+          userAta,
+          ctx.user.publicKey,
+          mint,
+          TP2022
+        ),
+        createAssociatedTokenAccountIdempotentInstruction(
+          ctx.admin.publicKey,
+          bridgeAta,
+          ctx.bridgePda,
+          mint,
+          TP2022
+        )
+      ),
+      [ctx.admin]
+    );
+
+    const amount = 1_000_000n;
+    await mintTo(
+      ctx.provider.connection,
+      ctx.admin,
+      mint,
+      userAta,
+      ctx.admin,
+      amount,
+      [],
+      undefined,
+      TP2022
+    );
+
+    const nonce = await getNextDepositNonce(ctx);
+    const [depositPda] = findDepositPda(ctx.program.programId, nonce);
+    const destAccount = Buffer.alloc(32, 0x42);
+    await ctx.program.methods
+      .depositSpl({
+        destChain: EVM_CHAIN_ID,
+        destAccount: Array.from(destAccount),
+        amount: new anchor.BN(amount.toString()),
+      })
+      .accounts({
+        bridge: ctx.bridgePda,
+        depositRecord: depositPda,
+        tokenMapping: tokenPda,
+        mint,
+        depositorTokenAccount: userAta,
+        bridgeTokenAccount: bridgeAta,
+        destChainEntry: evmChainPda,
+        depositor: ctx.user.publicKey,
+        tokenProgram: TP2022,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([ctx.user])
+      .rpc();
+
+    const received = amount - (amount * BigInt(TRANSFER_FEE_BPS)) / 10000n;
+    const net = received - (received * bridgeFeeBps) / 10000n;
+
+    const bridgeBal = await getAccount(
+      ctx.provider.connection,
+      bridgeAta,
+      undefined,
+      TP2022
+    );
+    expect(bridgeBal.amount).to.equal(received);
+
+    const deposit = await ctx.program.account.depositRecord.fetch(depositPda);
+    expect(BigInt(deposit.amount.toString())).to.equal(net);
+    const expectedHash = computeTransferHash(
+      SOLANA_CHAIN_ID,
+      EVM_CHAIN_ID,
+      ctx.user.publicKey.toBuffer(),
+      destAccount,
+      destToken,
+      net,
+      BigInt(nonce)
+    );
+    expect(Buffer.from(deposit.transferHash).toString("hex")).to.equal(
+      expectedHash.toString("hex")
+    );
+  });
+});
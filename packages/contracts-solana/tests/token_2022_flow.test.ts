@@ -30,6 +30,7 @@ import {
   findWithdrawPda,
   findWithdrawRateLimitPda,
   findNonceUsedPda,
+  findNonceBitmapPda,
   getNextDepositNonce,
   initializeBridgeIfNeeded,
   registerChainIfNeeded,
@@ -269,6 +270,11 @@ describe("Token-2022 plain mint (lock/unlock deposit → withdraw execute)", ()
         executedHashCheck: executedHashPda,
         payer: ctx.user.publicKey,
         systemProgram: SystemProgram.programId,
+        nonceBitmapCheck: findNonceBitmapPda(
+          ctx.program.programId,
+          Buffer.from(EVM_CHAIN_ID),
+          withdrawNonce
+        )[0],
       })
       .signers([ctx.user])
       .rpc();
@@ -285,6 +291,11 @@ describe("Token-2022 plain mint (lock/unlock deposit → withdraw execute)", ()
         bridge: ctx.bridgePda,
         pendingWithdraw: withdrawPda,
         nonceUsed: nonceUsedPda,
+        nonceBitmap: findNonceBitmapPda(
+          ctx.program.programId,
+          Buffer.from(EVM_CHAIN_ID),
+          withdrawNonce
+        )[0],
         operator: ctx.operator.publicKey,
         systemProgram: SystemProgram.programId,
       })
@@ -0,0 +1,389 @@
+/** Per-chain and large-transfer withdraw delays — see docs/SOLANA_BRIDGE_INVARIANTS.md (INV-W6) */
+import * as anchor from "@coral-xyz/anchor";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTest,
+  findCancelerPda,
+  findChainPda,
+  findExecutedHashPda,
+  findNonceBitmapPda,
+  findNonceUsedPda,
+  findTokenPda,
+  findWithdrawPda,
+  findWithdrawRateLimitPda,
+  TestContext,
+  initializeBridgeIfNeeded,
+  registerChainIfNeeded,
+  NATIVE_SOL_TOKEN,
+} from "./helpers/setup";
+import { computeTransferHash } from "./helpers/hash";
+
+const SOLANA_CHAIN_ID = [0x00, 0x00, 0x00, 0x05];
+const DELAY_CHAIN_ID = [0x00, 0x00, 0x00, 0x3d];
+const DELAY_DEST_TOKEN = Buffer.alloc(32, 0xdd);
+const SRC_ACCOUNT = Buffer.alloc(32, 0x3d);
+
+async function sleep(ms: number): Promise<void> {
+  return new Promise((resolve) => setTimeout(resolve, ms));
+}
+
+function findChainDelayPda(
+  programId: PublicKey,
+  chainId: number[]
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("chain_delay"), Buffer.from(chainId)],
+    programId
+  )[0];
+}
+
+function findLargeDelayPda(
+  programId: PublicKey,
+  chainId: number[],
+  token: PublicKey
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("large_delay"), Buffer.from(chainId), token.toBuffer()],
+    programId
+  )[0];
+}
+
+describe("withdraw delays", () => {
+  let ctx: TestContext;
+  let chainPda: PublicKey;
+  let chainDelayPda: PublicKey;
+  let tokenMappingPda: PublicKey;
+  let largeDelayPda: PublicKey;
+
+  async function setBridgeDelay(withdrawDelay: number): Promise<void> {
+    await ctx.program.methods
+      .setConfig({
+        newAdmin: null,
+        operator: ctx.operator.publicKey,
+        feeBps: null,
+        withdrawDelay: new anchor.BN(withdrawDelay),
+        paused: null,
+      })
+      .accounts({
+        bridge: ctx.bridgePda,
+        admin: ctx.admin.publicKey,
+      })
+      .rpc();
+  }
+
+  /** Submit and approve a native withdraw from the delay chain; returns its transfer hash. */
+  async function approvedWithdraw(nonce: bigint, amount: bigint): Promise<Buffer> {
+    const transferHash = computeTransferHash(
+      DELAY_CHAIN_ID,
+      SOLANA_CHAIN_ID,
+      SRC_ACCOUNT,
+      ctx.user.publicKey.toBuffer(),
+      NATIVE_SOL_TOKEN.toBuffer(),
+      amount,
+      nonce
+    );
+    const [pendingWithdraw] = findWithdrawPda(ctx.program.programId, transferHash);
+    await ctx.program.methods
+      .withdrawSubmit({
+        srcChain: DELAY_CHAIN_ID,
+        srcAccount: Array.from(SRC_ACCOUNT),
+        srcToken: Array.from(DELAY_DEST_TOKEN),
+        destToken: NATIVE_SOL_TOKEN,
+        destAccount: ctx.user.publicKey,
+        amount: new anchor.BN(amount.toString()),
+        nonce: new anchor.BN(nonce.toString()),
+        operatorGas: new anchor.BN(0),
+      })
+      .accounts({
+        bridge: ctx.bridgePda,
+        srcChainEntry: chainPda,
+        tokenMapping: tokenMappingPda,
+        pendingWithdraw,
+        executedHashCheck: findExecutedHashPda(
+          ctx.program.programId,
+          transferHash
+        )[0],
+        payer: ctx.user.publicKey,
+        systemProgram: SystemProgram.programId,
+        nonceBitmapCheck: findNonceBitmapPda(
+          ctx.program.programId,
+          Buffer.from(DELAY_CHAIN_ID),
+          nonce
+        )[0],
+      })
+      .signers([ctx.user])
+      .rpc();
+    await ctx.program.methods
+      .withdrawApprove({ transferHash: Array.from(transferHash) })
+      .accounts({
+        bridge: ctx.bridgePda,
+        pendingWithdraw,
+        nonceUsed: findNonceUsedPda(
+          ctx.program.programId,
+          Buffer.from(DELAY_CHAIN_ID),
+          nonce
+        )[0],
+        nonceBitmap: findNonceBitmapPda(
+          ctx.program.programId,
+          Buffer.from(DELAY_CHAIN_ID),
+          nonce
+        )[0],
+        operator: ctx.operator.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([ctx.operator])
+      .rpc();
+    return transferHash;
+  }
+
+  async function setChainDelay(
+    withdrawDelay: number,
+    admin = ctx.admin
+  ): Promise<void> {
+    await ctx.program.methods
+      .setChainWithdrawDelay({ withdrawDelay: new anchor.BN(withdrawDelay) })
+      .accounts({
+        bridge: ctx.bridgePda,
+        chainEntry: chainPda,
+        chainWithdrawDelay: chainDelayPda,
+        admin: admin.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers(admin === ctx.admin ? [] : [admin])
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = await setupTest();
+    await initializeBridgeIfNeeded(ctx, {
+      operator: ctx.operator.publicKey,
+      feeBps: 50,
+      withdrawDelay: new anchor.BN(300),
+      chainId: SOLANA_CHAIN_ID,
+    });
+    chainPda = await registerChainIfNeeded(ctx, DELAY_CHAIN_ID, "evm_delay");
+    expect(chainPda.toString()).to.equal(
+      findChainPda(ctx.program.programId, Buffer.from(DELAY_CHAIN_ID))[0].toString()
+    );
+    chainDelayPda = findChainDelayPda(ctx.program.programId, DELAY_CHAIN_ID);
+
+    [tokenMappingPda] = findTokenPda(
+      ctx.program.programId,
+      Buffer.from(DELAY_CHAIN_ID),
+      DELAY_DEST_TOKEN
+    );
+    if (!(await ctx.provider.connection.getAccountInfo(tokenMappingPda))) {
+      await ctx.program.methods
+        .registerToken({
+          localMint: PublicKey.default,
+          destChain: DELAY_CHAIN_ID,
+          destToken: Array.from(DELAY_DEST_TOKEN),
+          mode: { lockUnlock: {} },
+          decimals: 9,
+          srcDecimals: 18,
+        })
+        .accounts({
+          bridge: ctx.bridgePda,
+          tokenMapping: tokenMappingPda,
+          mint: null,
+          admin: ctx.admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    }
+    largeDelayPda = findLargeDelayPda(
+      ctx.program.programId,
+      DELAY_CHAIN_ID,
+      PublicKey.default
+    );
+  });
+
+  it("admin sets and removes a per-chain delay", async () => {
+    await setChainDelay(900);
+    const delay = await ctx.program.account.chainWithdrawDelay.fetch(
+      chainDelayPda
+    );
+    expect(delay.withdrawDelay.toNumber()).to.equal(900);
+    expect(Buffer.from(delay.chainId)).to.deep.equal(Buffer.from(DELAY_CHAIN_ID));
+
+    await ctx.program.methods
+      .removeChainWithdrawDelay()
+      .accounts({
+        bridge: ctx.bridgePda,
+        chainWithdrawDelay: chainDelayPda,
+        admin: ctx.admin.publicKey,
+      })
+      .rpc();
+    expect(await ctx.provider.connection.getAccountInfo(chainDelayPda)).to.be
+      .null;
+  });
+
+  it("rejects out-of-range chain delays and non-admins", async () => {
+    for (const bad of [14, 86_401]) {
+      try {
+        await setChainDelay(bad);
+        expect.fail("Should have thrown");
+      } catch (err) {
+        expect(err.toString()).to.contain("InvalidWithdrawDelay");
+      }
+    }
+    try {
+      await setChainDelay(900, ctx.user);
+      expect.fail("Should have thrown");
+    } catch (err) {
+      expect(err.toString()).to.contain("UnauthorizedAdmin");
+    }
+  });
+
+  it("large-transfer delay is keyed by source chain and local token", async () => {
+    await ctx.program.methods
+      .setLargeTransferDelay({
+        threshold: new anchor.BN("1000000000000000000000"),
+        withdrawDelay: new anchor.BN(3_600),
+      })
+      .accounts({
+        bridge: ctx.bridgePda,
+        tokenMapping: tokenMappingPda,
+        largeTransferDelay: largeDelayPda,
+        admin: ctx.admin.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const large = await ctx.program.account.largeTransferDelay.fetch(
+      largeDelayPda
+    );
+    expect(large.tokenMapping.toString()).to.equal(tokenMappingPda.toString());
+    expect(large.withdrawDelay.toNumber()).to.equal(3_600);
+
+    try {
+      await ctx.program.methods
+        .setLargeTransferDelay({
+          threshold: new anchor.BN(0),
+          withdrawDelay: new anchor.BN(3_600),
+        })
+        .accounts({
+          bridge: ctx.bridgePda,
+          tokenMapping: tokenMappingPda,
+          largeTransferDelay: largeDelayPda,
+          admin: ctx.admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      expect.fail("Should have thrown");
+    } catch (err) {
+      expect(err.toString()).to.contain("InvalidLargeTransferThreshold");
+    }
+
+    await ctx.program.methods
+      .removeLargeTransferDelay()
+      .accounts({
+        bridge: ctx.bridgePda,
+        tokenMapping: tokenMappingPda,
+        largeTransferDelay: largeDelayPda,
+        admin: ctx.admin.publicKey,
+      })
+      .rpc();
+    expect(await ctx.provider.connection.getAccountInfo(largeDelayPda)).to.be
+      .null;
+  });
+
+  it("large-transfer delay extends both the execute delay and the cancel window", async () => {
+    const amount = 1_000_000n;
+    await setBridgeDelay(15);
+    await ctx.program.methods
+      .setLargeTransferDelay({
+        threshold: new anchor.BN(1),
+        withdrawDelay: new anchor.BN(60),
+      })
+      .accounts({
+        bridge: ctx.bridgePda,
+        tokenMapping: tokenMappingPda,
+        largeTransferDelay: largeDelayPda,
+        admin: ctx.admin.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    const [cancelerPda] = findCancelerPda(
+      ctx.program.programId,
+      ctx.canceler.publicKey
+    );
+    await ctx.program.methods
+      .addCanceler({ canceler: ctx.canceler.publicKey, active: true })
+      .accounts({
+        bridge: ctx.bridgePda,
+        cancelerEntry: cancelerPda,
+        admin: ctx.admin.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    try {
+      const transferHash = await approvedWithdraw(31n, amount);
+      const [pendingWithdraw] = findWithdrawPda(
+        ctx.program.programId,
+        transferHash
+      );
+      const execute = () =>
+        ctx.program.methods
+          .withdrawExecuteNative()
+          .accounts({
+            bridge: ctx.bridgePda,
+            pendingWithdraw,
+            executedHash: findExecutedHashPda(
+              ctx.program.programId,
+              transferHash
+            )[0],
+            withdrawRateLimit: findWithdrawRateLimitPda(
+              ctx.program.programId,
+              NATIVE_SOL_TOKEN
+            )[0],
+            recipient: ctx.user.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([ctx.user])
+          .rpc();
+
+      try {
+        await execute();
+        expect.fail("Should have thrown");
+      } catch (err) {
+        expect(err.toString()).to.contain("DelayNotElapsed");
+      }
+
+      // Past the 15s bridge delay but inside the 60s large-transfer window.
+      await sleep(17_000);
+      try {
+        await execute();
+        expect.fail("Should have thrown");
+      } catch (err) {
+        expect(err.toString()).to.contain("DelayNotElapsed");
+      }
+
+      await ctx.program.methods
+        .withdrawCancel()
+        .accounts({
+          bridge: ctx.bridgePda,
+          pendingWithdraw,
+          cancelerEntry: cancelerPda,
+          canceler: ctx.canceler.publicKey,
+        })
+        .signers([ctx.canceler])
+        .rpc();
+      const pw = await ctx.program.account.pendingWithdraw.fetch(pendingWithdraw);
+      expect(pw.cancelled).to.be.true;
+    } finally {
+      await ctx.program.methods
+        .removeLargeTransferDelay()
+        .accounts({
+          bridge: ctx.bridgePda,
+          tokenMapping: tokenMappingPda,
+          largeTransferDelay: largeDelayPda,
+          admin: ctx.admin.publicKey,
+        })
+        .rpc();
+      await setBridgeDelay(300);
+    }
+  });
+});
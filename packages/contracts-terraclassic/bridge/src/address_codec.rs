@@ -10,9 +10,10 @@
 //! | Chain Type (4 bytes) | Raw Address (20 bytes) | Reserved (8 bytes) |
 //! ```
 //!
-//! Solana addresses use all 28 remaining bytes in the 32-byte encoding:
+//! Solana addresses and Bitcoin P2WSH / P2TR witness programs use all 28
+//! remaining bytes in the 32-byte encoding:
 //! ```text
-//! | Chain Type (4 bytes) | Pubkey[0..28] (28 bytes) |
+//! | Chain Type (4 bytes) | Raw[0..28] (28 bytes) |
 //! ```
 //!
 //! ## Chain Type Codes
@@ -20,13 +21,14 @@
 //! - `0x00000001`: EVM (Ethereum, BSC, Polygon, etc.)
 //! - `0x00000002`: Cosmos/Terra (Terra Classic, Osmosis)
 //! - `0x00000003`: Solana
-//! - `0x00000004`: Bitcoin (future)
+//! - `0x00000004`: Bitcoin (P2WPKH, P2WSH, P2TR)
 //!
 //! ## Raw Address
 //!
 //! - EVM: 20-byte address directly
 //! - Cosmos: 20-byte address from bech32 decoding
 //! - Solana: 32-byte Ed25519 public key
+//! - Bitcoin: 20- or 32-byte witness program from bech32 / bech32m decoding
 
 use std::fmt;
 
@@ -45,18 +47,80 @@ pub const CHAIN_TYPE_COSMOS: u32 = 2;
 /// Chain type for Solana
 pub const CHAIN_TYPE_SOLANA: u32 = 3;
 
-/// Chain type for Bitcoin (future)
+/// Chain type for Bitcoin
 pub const CHAIN_TYPE_BITCOIN: u32 = 4;
 
+/// Human-readable prefixes accepted for Bitcoin addresses (mainnet, testnet/signet, regtest)
+pub const BITCOIN_HRPS: [&str; 3] = ["bc", "tb", "bcrt"];
+
+// ============================================================================
+// Bitcoin Address Kinds
+// ============================================================================
+
+/// Bitcoin output types that can be named as a bridge recipient
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitcoinAddressKind {
+    /// Pay-to-witness-pubkey-hash (witness v0, 20-byte program)
+    P2wpkh,
+    /// Pay-to-witness-script-hash (witness v0, 32-byte program)
+    P2wsh,
+    /// Pay-to-taproot (witness v1, 32-byte output key)
+    P2tr,
+}
+
+impl BitcoinAddressKind {
+    /// Segwit witness version
+    pub fn witness_version(&self) -> u8 {
+        match self {
+            Self::P2wpkh | Self::P2wsh => 0,
+            Self::P2tr => 1,
+        }
+    }
+
+    /// Witness program length in bytes
+    pub fn program_len(&self) -> usize {
+        match self {
+            Self::P2wpkh => 20,
+            Self::P2wsh | Self::P2tr => 32,
+        }
+    }
+
+    /// Resolve the kind from a witness version and program length
+    pub fn from_witness(version: u8, program_len: usize) -> StdResult<Self> {
+        match (version, program_len) {
+            (0, 20) => Ok(Self::P2wpkh),
+            (0, 32) => Ok(Self::P2wsh),
+            (1, 32) => Ok(Self::P2tr),
+            (v, len) => Err(StdError::generic_err(format!(
+                "Unsupported Bitcoin witness program: version {}, {} bytes",
+                v, len
+            ))),
+        }
+    }
+
+    /// Checksum constant required by BIP-350 for this witness version
+    fn checksum_const(&self) -> u32 {
+        if self.witness_version() == 0 {
+            BECH32_CONST
+        } else {
+            BECH32M_CONST
+        }
+    }
+}
+
 // ============================================================================
 // Universal Address Structure
 // ============================================================================
 
 /// Internal representation for variable-length raw addresses
+///
+/// Bitcoin P2WPKH is `Short`, P2WSH is `Full` and P2TR is `Taproot`, so the
+/// witness version survives alongside the program.
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum RawAddress {
     Short([u8; 20]),
     Full([u8; 32]),
+    Taproot([u8; 32]),
 }
 
 /// Universal address that can represent addresses from any supported chain
@@ -144,14 +208,54 @@ impl UniversalAddress {
         Self::from_solana(&pubkey)
     }
 
+    /// Create a Bitcoin address from a witness program
+    pub fn from_bitcoin_program(kind: BitcoinAddressKind, program: &[u8]) -> StdResult<Self> {
+        if program.len() != kind.program_len() {
+            return Err(StdError::generic_err(format!(
+                "Invalid {:?} program length: expected {} bytes, got {}",
+                kind,
+                kind.program_len(),
+                program.len()
+            )));
+        }
+        let raw = match kind {
+            BitcoinAddressKind::P2wpkh => {
+                let mut raw = [0u8; 20];
+                raw.copy_from_slice(program);
+                RawAddress::Short(raw)
+            }
+            BitcoinAddressKind::P2wsh => {
+                let mut raw = [0u8; 32];
+                raw.copy_from_slice(program);
+                RawAddress::Full(raw)
+            }
+            BitcoinAddressKind::P2tr => {
+                let mut raw = [0u8; 32];
+                raw.copy_from_slice(program);
+                RawAddress::Taproot(raw)
+            }
+        };
+        Ok(Self {
+            chain_type: CHAIN_TYPE_BITCOIN,
+            raw,
+            reserved: [0u8; 8],
+        })
+    }
+
+    /// Create a Bitcoin address from a segwit or taproot string (e.g., "bc1q...", "bc1p...")
+    pub fn from_bitcoin(addr: &str) -> StdResult<Self> {
+        let (kind, program, _hrp) = decode_bitcoin_address(addr)?;
+        Self::from_bitcoin_program(kind, &program)
+    }
+
     // ============================================================================
     // Serialization
     // ============================================================================
 
     /// Convert to 32-byte array
     ///
-    /// Short (EVM/Cosmos): | chain_type (4) | raw_address (20) | reserved (8) |
-    /// Full (Solana): | chain_type (4) | pubkey[0..28] (28) |
+    /// Short (EVM/Cosmos/P2WPKH): | chain_type (4) | raw_address (20) | reserved (8) |
+    /// Full (Solana/P2WSH/P2TR): | chain_type (4) | raw[0..28] (28) |
     pub fn to_bytes32(&self) -> [u8; 32] {
         let mut result = [0u8; 32];
         result[0..4].copy_from_slice(&self.chain_type.to_be_bytes());
@@ -160,7 +264,7 @@ impl UniversalAddress {
                 result[4..24].copy_from_slice(raw);
                 result[24..32].copy_from_slice(&self.reserved);
             }
-            RawAddress::Full(raw) => {
+            RawAddress::Full(raw) | RawAddress::Taproot(raw) => {
                 result[4..32].copy_from_slice(&raw[0..28]);
             }
         }
@@ -168,6 +272,10 @@ impl UniversalAddress {
     }
 
     /// Parse from 32-byte array
+    ///
+    /// Bitcoin always decodes as P2WPKH: a truncated 32-byte witness program
+    /// shows up as non-zero reserved bytes and is rejected by
+    /// [`Self::from_bytes32_strict`] and [`Self::to_bitcoin_string`].
     pub fn from_bytes32(bytes: &[u8; 32]) -> StdResult<Self> {
         let chain_type = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
         if chain_type == 0 {
@@ -221,7 +329,9 @@ impl UniversalAddress {
         }
         match &self.raw {
             RawAddress::Short(raw) => Ok(format!("0x{}", hex::encode(raw))),
-            RawAddress::Full(_) => Err(StdError::generic_err("EVM address must be 20 bytes")),
+            RawAddress::Full(_) | RawAddress::Taproot(_) => {
+                Err(StdError::generic_err("EVM address must be 20 bytes"))
+            }
         }
     }
 
@@ -237,7 +347,9 @@ impl UniversalAddress {
         }
         match &self.raw {
             RawAddress::Short(raw) => encode_bech32_address(raw, hrp),
-            RawAddress::Full(_) => Err(StdError::generic_err("Cosmos address must be 20 bytes")),
+            RawAddress::Full(_) | RawAddress::Taproot(_) => {
+                Err(StdError::generic_err("Cosmos address must be 20 bytes"))
+            }
         }
     }
 
@@ -246,6 +358,36 @@ impl UniversalAddress {
         self.to_cosmos_string("terra")
     }
 
+    /// Convert to a Bitcoin segwit (bech32) or taproot (bech32m) string with given prefix
+    ///
+    /// Returns error if chain type is not Bitcoin
+    pub fn to_bitcoin_string(&self, hrp: &str) -> StdResult<String> {
+        let kind = self.bitcoin_kind().ok_or_else(|| {
+            StdError::generic_err(format!(
+                "Expected Bitcoin chain type (4), got {}",
+                self.chain_type
+            ))
+        })?;
+        if self.reserved != [0u8; 8] {
+            return Err(StdError::generic_err(
+                "Bitcoin P2WPKH address has non-zero reserved bytes (truncated witness program?)",
+            ));
+        }
+        encode_bitcoin_address(kind, self.raw_address_bytes(), hrp)
+    }
+
+    /// Bitcoin output type, or `None` if this is not a Bitcoin address
+    pub fn bitcoin_kind(&self) -> Option<BitcoinAddressKind> {
+        if !self.is_bitcoin() {
+            return None;
+        }
+        Some(match &self.raw {
+            RawAddress::Short(_) => BitcoinAddressKind::P2wpkh,
+            RawAddress::Full(_) => BitcoinAddressKind::P2wsh,
+            RawAddress::Taproot(_) => BitcoinAddressKind::P2tr,
+        })
+    }
+
     // ============================================================================
     // Validation
     // ============================================================================
@@ -265,6 +407,11 @@ impl UniversalAddress {
         self.chain_type == CHAIN_TYPE_SOLANA
     }
 
+    /// Check if this is a Bitcoin address
+    pub fn is_bitcoin(&self) -> bool {
+        self.chain_type == CHAIN_TYPE_BITCOIN
+    }
+
     /// Check if the chain type is valid (known)
     pub fn is_valid_chain_type(&self) -> bool {
         self.chain_type >= CHAIN_TYPE_EVM && self.chain_type <= CHAIN_TYPE_BITCOIN
@@ -274,27 +421,27 @@ impl UniversalAddress {
     // Raw Address Accessors
     // ============================================================================
 
-    /// Returns the raw address bytes (20 for EVM/Cosmos, 32 for Solana)
+    /// Returns the raw address bytes (20 for EVM/Cosmos/P2WPKH, 32 for Solana/P2WSH/P2TR)
     pub fn raw_address_bytes(&self) -> &[u8] {
         match &self.raw {
             RawAddress::Short(raw) => raw,
-            RawAddress::Full(raw) => raw,
+            RawAddress::Full(raw) | RawAddress::Taproot(raw) => raw,
         }
     }
 
-    /// Returns the 20-byte raw address, or error if the address is 32 bytes (Solana)
+    /// Returns the 20-byte raw address, or error if the address is 32 bytes (Solana, P2WSH, P2TR)
     pub fn raw_address_20(&self) -> StdResult<&[u8; 20]> {
         match &self.raw {
             RawAddress::Short(raw) => Ok(raw),
-            RawAddress::Full(_) => Err(StdError::generic_err(
+            RawAddress::Full(_) | RawAddress::Taproot(_) => Err(StdError::generic_err(
                 "Address is 32 bytes (Solana), not 20",
             )),
         }
     }
 
     /// Returns 32 bytes for hash computation.
-    /// EVM/Cosmos: 12 zero bytes followed by the 20-byte address (left-padded).
-    /// Solana: full 32-byte public key.
+    /// EVM/Cosmos/P2WPKH: 12 zero bytes followed by the 20-byte address (left-padded).
+    /// Solana/P2WSH/P2TR: full 32-byte public key or witness program.
     pub fn to_hash_bytes(&self) -> [u8; 32] {
         match &self.raw {
             RawAddress::Short(raw) => {
@@ -302,26 +449,30 @@ impl UniversalAddress {
                 result[12..32].copy_from_slice(raw);
                 result
             }
-            RawAddress::Full(raw) => *raw,
+            RawAddress::Full(raw) | RawAddress::Taproot(raw) => *raw,
         }
     }
 
     /// Lossless serialization for off-chain transport.
-    /// EVM/Cosmos: 32 bytes (same as [`Self::to_bytes32`]).
+    /// EVM/Cosmos/P2WPKH: 32 bytes (same as [`Self::to_bytes32`]).
     /// Solana: 36 bytes (`chain_type` BE + raw pubkey).
+    /// P2WSH/P2TR: 37 bytes (`chain_type` BE + witness version + program).
     pub fn to_bytes(&self) -> Vec<u8> {
         match &self.raw {
             RawAddress::Short(_) => self.to_bytes32().to_vec(),
-            RawAddress::Full(raw) => {
-                let mut result = Vec::with_capacity(36);
+            RawAddress::Full(raw) | RawAddress::Taproot(raw) => {
+                let mut result = Vec::with_capacity(37);
                 result.extend_from_slice(&self.chain_type.to_be_bytes());
+                if let Some(kind) = self.bitcoin_kind() {
+                    result.push(kind.witness_version());
+                }
                 result.extend_from_slice(raw);
                 result
             }
         }
     }
 
-    /// Parse [`Self::to_bytes`] output: 32 bytes (short) or 36 bytes (Solana).
+    /// Parse [`Self::to_bytes`] output: 32 bytes (short), 36 bytes (Solana) or 37 bytes (P2WSH/P2TR).
     pub fn from_bytes(bytes: &[u8]) -> StdResult<Self> {
         match bytes.len() {
             32 => {
@@ -341,8 +492,19 @@ impl UniversalAddress {
                 pubkey.copy_from_slice(&bytes[4..36]);
                 Self::from_solana(&pubkey)
             }
+            37 => {
+                let chain_type = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+                if chain_type != CHAIN_TYPE_BITCOIN {
+                    return Err(StdError::generic_err(format!(
+                        "37-byte encoding only valid for Bitcoin (chain_type=4), got {}",
+                        chain_type
+                    )));
+                }
+                let kind = BitcoinAddressKind::from_witness(bytes[4], 32)?;
+                Self::from_bitcoin_program(kind, &bytes[5..37])
+            }
             other => Err(StdError::generic_err(format!(
-                "Invalid length: expected 32 or 36 bytes, got {}",
+                "Invalid length: expected 32, 36 or 37 bytes, got {}",
                 other
             ))),
         }
@@ -358,7 +520,9 @@ impl UniversalAddress {
         }
         match &self.raw {
             RawAddress::Full(raw) => Ok(bs58::encode(raw).into_string()),
-            RawAddress::Short(_) => Err(StdError::generic_err("Solana address must be 32 bytes")),
+            RawAddress::Short(_) | RawAddress::Taproot(_) => {
+                Err(StdError::generic_err("Solana address must be 32 bytes"))
+            }
         }
     }
 }
@@ -370,7 +534,7 @@ impl fmt::Display for UniversalAddress {
             CHAIN_TYPE_COSMOS => write!(f, "COSMOS:{}", hex::encode(self.raw_address_bytes())),
             CHAIN_TYPE_SOLANA => match &self.raw {
                 RawAddress::Full(raw) => write!(f, "SOLANA:{}", bs58::encode(raw).into_string()),
-                RawAddress::Short(raw) => write!(f, "SOLANA:{}", hex::encode(raw)),
+                _ => write!(f, "SOLANA:{}", hex::encode(self.raw_address_bytes())),
             },
             CHAIN_TYPE_BITCOIN => write!(f, "BITCOIN:{}", hex::encode(self.raw_address_bytes())),
             _ => write!(
@@ -459,7 +623,7 @@ pub fn encode_bech32_address(bytes: &[u8; 20], hrp: &str) -> StdResult<String> {
     let data_str = encode_bech32_data(&data5);
 
     // Compute checksum
-    let checksum = compute_bech32_checksum(hrp, &data5)?;
+    let checksum = compute_bech32_checksum(hrp, &data5, BECH32_CONST)?;
     let checksum_str = encode_bech32_data(&checksum);
 
     Ok(format!("{}1{}{}", hrp, data_str, checksum_str))
@@ -485,16 +649,103 @@ fn convert_bits(data: &[u8], from_bits: u32, to_bits: u32, pad: bool) -> StdResu
 
     if pad && bits > 0 {
         result.push(((acc << (to_bits - bits)) & max_v) as u8);
-    } else if !pad && bits >= from_bits {
+    } else if !pad && (bits >= from_bits || (acc << (to_bits - bits)) & max_v != 0) {
         return Err(StdError::generic_err("Invalid padding"));
     }
 
     Ok(result)
 }
 
+/// Decode a Bitcoin segwit or taproot address to its witness program
+///
+/// Returns (kind, program, hrp). Unlike [`decode_bech32_address`] the checksum
+/// is verified, and per BIP-350 witness v0 must carry a bech32 checksum and
+/// v1 a bech32m checksum.
+pub fn decode_bitcoin_address(addr: &str) -> StdResult<(BitcoinAddressKind, Vec<u8>, String)> {
+    if addr.chars().any(|c| c.is_ascii_lowercase()) && addr.chars().any(|c| c.is_ascii_uppercase())
+    {
+        return Err(StdError::generic_err("Mixed-case bech32 address"));
+    }
+    let addr = addr.to_ascii_lowercase();
+
+    let (hrp, data_part) = addr
+        .rsplit_once('1')
+        .ok_or_else(|| StdError::generic_err("Invalid bech32 format"))?;
+    if !BITCOIN_HRPS.contains(&hrp) {
+        return Err(StdError::generic_err(format!(
+            "Unknown Bitcoin address prefix: '{}'",
+            hrp
+        )));
+    }
+    // Witness version + 6-character checksum at minimum
+    if data_part.len() < 7 {
+        return Err(StdError::generic_err("Bech32 data too short"));
+    }
+
+    let values = decode_bech32_data(data_part)?;
+    let mut checked = expand_hrp(hrp);
+    checked.extend_from_slice(&values);
+    let checksum_const = bech32_polymod(&checked);
+    if checksum_const != BECH32_CONST && checksum_const != BECH32M_CONST {
+        return Err(StdError::generic_err("Invalid bech32 checksum"));
+    }
+
+    let data = &values[..values.len() - 6];
+    let program = convert_bits(&data[1..], 5, 8, false)?;
+    let kind = BitcoinAddressKind::from_witness(data[0], program.len())?;
+    if checksum_const != kind.checksum_const() {
+        return Err(StdError::generic_err(format!(
+            "Witness v{} address uses the wrong checksum variant",
+            kind.witness_version()
+        )));
+    }
+
+    Ok((kind, program, hrp.to_string()))
+}
+
+/// Encode a witness program as a Bitcoin segwit or taproot address with given prefix
+pub fn encode_bitcoin_address(
+    kind: BitcoinAddressKind,
+    program: &[u8],
+    hrp: &str,
+) -> StdResult<String> {
+    if !BITCOIN_HRPS.contains(&hrp) {
+        return Err(StdError::generic_err(format!(
+            "Unknown Bitcoin address prefix: '{}'",
+            hrp
+        )));
+    }
+    if program.len() != kind.program_len() {
+        return Err(StdError::generic_err(format!(
+            "Invalid {:?} program length: expected {} bytes, got {}",
+            kind,
+            kind.program_len(),
+            program.len()
+        )));
+    }
+
+    let mut data5 = vec![kind.witness_version()];
+    data5.extend(convert_bits(program, 8, 5, true)?);
+
+    let checksum = compute_bech32_checksum(hrp, &data5, kind.checksum_const())?;
+
+    Ok(format!(
+        "{}1{}{}",
+        hrp,
+        encode_bech32_data(&data5),
+        encode_bech32_data(&checksum)
+    ))
+}
+
 /// Bech32 character set
 const BECH32_CHARSET: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
 
+/// Final polymod XOR constant for bech32 (BIP-173)
+const BECH32_CONST: u32 = 1;
+
+/// Final polymod XOR constant for bech32m (BIP-350)
+const BECH32M_CONST: u32 = 0x2bc8_30a3;
+
 /// Decode bech32 base32 data
 fn decode_bech32_data(data: &str) -> StdResult<Vec<u8>> {
     let mut result = Vec::with_capacity(data.len());
@@ -517,13 +768,13 @@ fn encode_bech32_data(data: &[u8]) -> String {
         .collect()
 }
 
-/// Compute bech32 checksum
-fn compute_bech32_checksum(hrp: &str, data: &[u8]) -> StdResult<Vec<u8>> {
+/// Compute bech32 (`BECH32_CONST`) or bech32m (`BECH32M_CONST`) checksum
+fn compute_bech32_checksum(hrp: &str, data: &[u8], constant: u32) -> StdResult<Vec<u8>> {
     let mut values = expand_hrp(hrp);
     values.extend_from_slice(data);
     values.extend_from_slice(&[0, 0, 0, 0, 0, 0]);
 
-    let polymod = bech32_polymod(&values) ^ 1;
+    let polymod = bech32_polymod(&values) ^ constant;
 
     let mut checksum = Vec::with_capacity(6);
     for i in 0..6 {
@@ -789,4 +1040,80 @@ mod tests {
         let addr = UniversalAddress::from_cosmos(REGRESSION_TERRA_ADDR).unwrap();
         assert_eq!(hex::encode(addr.to_bytes32()), expected_hex);
     }
+
+    // ========================================================================
+    // Bitcoin (BIP-173 / BIP-350 vectors)
+    // ========================================================================
+
+    const BTC_P2WPKH: &str = "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4";
+    const BTC_P2WSH: &str = "bc1qrp33g0q5c5txsp9arysrx4k6zdkfs4nce4xj0gdcccefvpysxf3qccfmv3";
+    const BTC_P2TR: &str = "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0";
+
+    #[test]
+    fn test_bitcoin_encode_decode() {
+        let cases = [
+            (
+                BTC_P2WPKH,
+                BitcoinAddressKind::P2wpkh,
+                "000000000000000000000000751e76e8199196d454941c45d1b3a323f1433bd6",
+            ),
+            (
+                BTC_P2WSH,
+                BitcoinAddressKind::P2wsh,
+                "1863143c14c5166804bd19203356da136c985678cd4d27a1b8c6329604903262",
+            ),
+            (
+                BTC_P2TR,
+                BitcoinAddressKind::P2tr,
+                "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+            ),
+        ];
+        for (s, kind, hash_hex) in cases {
+            let addr = UniversalAddress::from_bitcoin(s).unwrap();
+            assert!(addr.is_bitcoin());
+            assert_eq!(addr.bitcoin_kind(), Some(kind));
+            assert_eq!(hex::encode(addr.to_hash_bytes()), hash_hex);
+            assert_eq!(addr.to_bitcoin_string("bc").unwrap(), s);
+            assert_eq!(
+                UniversalAddress::from_bytes(&addr.to_bytes()).unwrap(),
+                addr
+            );
+        }
+    }
+
+    #[test]
+    fn test_bitcoin_p2wpkh_bytes32_strict_roundtrip() {
+        let addr = UniversalAddress::from_bitcoin(BTC_P2WPKH).unwrap();
+        let recovered = UniversalAddress::from_bytes32_strict(&addr.to_bytes32()).unwrap();
+        assert_eq!(recovered.to_bitcoin_string("bc").unwrap(), BTC_P2WPKH);
+    }
+
+    #[test]
+    fn test_bitcoin_truncated_program_is_not_rendered() {
+        let addr = UniversalAddress::from_bitcoin(BTC_P2TR).unwrap();
+        let truncated = UniversalAddress::from_bytes32(&addr.to_bytes32()).unwrap();
+        assert!(truncated.to_bitcoin_string("bc").is_err());
+    }
+
+    #[test]
+    fn test_bitcoin_rejects_invalid_addresses() {
+        // v1 with bech32 checksum, v0 with bech32m checksum (BIP-350)
+        assert!(UniversalAddress::from_bitcoin(
+            "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqh2y7hd"
+        )
+        .is_err());
+        assert!(
+            UniversalAddress::from_bitcoin("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kemeawh").is_err()
+        );
+        // Bad checksum, mixed case, unknown prefix
+        assert!(
+            UniversalAddress::from_bitcoin("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t5").is_err()
+        );
+        assert!(
+            UniversalAddress::from_bitcoin("bc1qW508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4").is_err()
+        );
+        assert!(UniversalAddress::from_bitcoin(REGRESSION_TERRA_ADDR).is_err());
+        // Uppercase is valid bech32
+        assert!(UniversalAddress::from_bitcoin(&BTC_P2WPKH.to_uppercase()).is_ok());
+    }
 }
@@ -14,29 +14,33 @@ use cw2::set_contract_version;
 use crate::error::ContractError;
 use crate::execute::{
     execute_accept_admin, execute_add_canceler, execute_add_operator, execute_add_token,
-    execute_admin_fix_pending_decimals, execute_cancel_admin_proposal, execute_deposit_native,
+    execute_admin_fix_pending_decimals, execute_cancel_admin_proposal,
+    execute_claim_canceler_reward, execute_deposit_native, execute_fund_canceler_reward_pool,
     execute_pause, execute_propose_admin, execute_receive, execute_recover_asset,
     execute_register_chain, execute_remove_canceler, execute_remove_custom_account_fee,
-    execute_remove_incoming_token_mapping, execute_remove_operator,
-    execute_set_allowed_cw20_code_ids, execute_set_custom_account_fee, execute_set_fee_params,
-    execute_set_incoming_token_mapping, execute_set_rate_limit, execute_set_token_destination,
+    execute_remove_incoming_token_mapping, execute_remove_operator, execute_request_relay,
+    execute_set_allowed_cw20_code_ids, execute_set_canceler_reward_config,
+    execute_set_custom_account_fee, execute_set_fee_params, execute_set_incoming_token_mapping,
+    execute_set_rate_limit, execute_set_relay_fee_cap, execute_set_token_destination,
     execute_set_withdraw_delay, execute_unpause, execute_unregister_chain, execute_update_chain,
     execute_update_limits, execute_update_min_signatures, execute_update_token,
     execute_withdraw_approve, execute_withdraw_cancel, execute_withdraw_execute_mint,
-    execute_withdraw_execute_unlock, execute_withdraw_submit, execute_withdraw_uncancel,
+    execute_withdraw_execute_unlock, execute_withdraw_submit, execute_withdraw_submit_relayed,
+    execute_withdraw_uncancel,
 };
 use crate::fee_manager::{FeeConfig, FEE_CONFIG};
 use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
 use crate::query::{
     query_account_fee, query_all_custom_account_fees, query_all_rate_limits,
     query_all_token_dest_mappings, query_allowed_cw20_code_ids, query_calculate_fee,
-    query_cancelers, query_chain, query_chains, query_compute_xchain_hash_id, query_config,
-    query_current_nonce, query_deposit_by_nonce, query_fee_config, query_has_custom_fee,
-    query_incoming_token_mapping, query_incoming_token_mappings, query_is_canceler,
-    query_locked_balance, query_operators, query_pending_admin, query_pending_withdraw,
-    query_pending_withdrawals, query_period_usage, query_rate_limit, query_simulate_bridge,
-    query_stats, query_status, query_this_chain_id, query_token, query_token_dest_mapping,
-    query_token_type, query_tokens, query_transaction, query_verify_deposit, query_withdraw_delay,
+    query_cancel_record, query_cancelers, query_chain, query_chains, query_compute_xchain_hash_id,
+    query_config, query_current_nonce, query_deposit_by_nonce, query_fee_config,
+    query_has_custom_fee, query_incoming_token_mapping, query_incoming_token_mappings,
+    query_is_canceler, query_locked_balance, query_operators, query_pending_admin,
+    query_pending_withdraw, query_pending_withdrawals, query_period_usage, query_rate_limit,
+    query_relay_fee_cap, query_relay_request, query_simulate_bridge, query_stats, query_status,
+    query_this_chain_id, query_token, query_token_dest_mapping, query_token_type, query_tokens,
+    query_transaction, query_verify_deposit, query_withdraw_delay, query_withdraw_relay,
     query_xchain_hash_id,
 };
 use crate::state::{
@@ -204,6 +208,46 @@ pub fn execute(
         ExecuteMsg::AddCanceler { address } => execute_add_canceler(deps, info, address),
         ExecuteMsg::RemoveCanceler { address } => execute_remove_canceler(deps, info, address),
 
+        // Canceler rewards
+        ExecuteMsg::SetCancelerRewardConfig {
+            denom,
+            reward_amount,
+            review_period,
+        } => execute_set_canceler_reward_config(deps, info, denom, reward_amount, review_period),
+        ExecuteMsg::FundCancelerRewardPool {} => execute_fund_canceler_reward_pool(deps, info),
+        ExecuteMsg::ClaimCancelerReward { xchain_hash_id } => {
+            execute_claim_canceler_reward(deps, env, info, xchain_hash_id)
+        }
+
+        // Relayed withdrawals
+        ExecuteMsg::SetRelayFeeCap { relay_fee_cap_bps } => {
+            execute_set_relay_fee_cap(deps, info, relay_fee_cap_bps)
+        }
+        ExecuteMsg::RequestRelay {
+            xchain_hash_id,
+            max_relay_fee,
+        } => execute_request_relay(deps, info, xchain_hash_id, max_relay_fee),
+        ExecuteMsg::WithdrawSubmitRelayed {
+            src_chain,
+            src_account,
+            token,
+            recipient,
+            amount,
+            nonce,
+            relay_fee,
+        } => execute_withdraw_submit_relayed(
+            deps,
+            env,
+            info,
+            src_chain,
+            src_account,
+            token,
+            recipient,
+            amount,
+            nonce,
+            relay_fee,
+        ),
+
         // Configuration
         ExecuteMsg::SetWithdrawDelay { delay_seconds } => {
             execute_set_withdraw_delay(deps, info, delay_seconds)
@@ -413,8 +457,22 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         )?),
 
         // Canceler queries
-        QueryMsg::Cancelers {} => to_json_binary(&query_cancelers(deps)?),
+        QueryMsg::Cancelers { start_after, limit } => {
+            to_json_binary(&query_cancelers(deps, start_after, limit)?)
+        }
         QueryMsg::IsCanceler { address } => to_json_binary(&query_is_canceler(deps, address)?),
+        QueryMsg::CancelRecord { xchain_hash_id } => {
+            to_json_binary(&query_cancel_record(deps, xchain_hash_id)?)
+        }
+
+        // Relay queries
+        QueryMsg::RelayFeeCap {} => to_json_binary(&query_relay_fee_cap(deps)?),
+        QueryMsg::RelayRequest { xchain_hash_id } => {
+            to_json_binary(&query_relay_request(deps, xchain_hash_id)?)
+        }
+        QueryMsg::WithdrawRelay { xchain_hash_id } => {
+            to_json_binary(&query_withdraw_relay(deps, xchain_hash_id)?)
+        }
 
         // Configuration queries
         QueryMsg::ThisChainId {} => to_json_binary(&query_this_chain_id(deps)?),
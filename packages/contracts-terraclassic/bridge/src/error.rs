@@ -216,6 +216,63 @@ pub enum ContractError {
     #[error("Invalid withdraw delay: must be between 15 and 86400 seconds")]
     InvalidWithdrawDelay,
 
+    // ========================================================================
+    // Canceler Reward Errors
+    // ========================================================================
+    #[error("Canceler rewards are not configured")]
+    CancelerRewardsNotConfigured,
+
+    #[error("Invalid canceler review period: must be between 1 and 2592000 seconds")]
+    InvalidCancelerReviewPeriod,
+
+    #[error("Cannot change reward denom while the reward pool holds {pool} {denom}")]
+    CancelerRewardPoolNotEmpty { pool: Uint128, denom: String },
+
+    #[error("No cancellation recorded for this withdrawal")]
+    CancelRecordNotFound,
+
+    #[error("Unauthorized: caller did not cancel this withdrawal")]
+    NotRecordedCanceler,
+
+    #[error("Canceler reward already claimed")]
+    CancelerRewardAlreadyClaimed,
+
+    #[error("Cancellation was reverted or the withdrawal was executed")]
+    CancelReverted,
+
+    #[error("Canceler review period still active, ends at {ends_at}")]
+    CancelerReviewPeriodActive { ends_at: u64 },
+
+    #[error("Insufficient canceler reward pool: available {available}, required {required}")]
+    InsufficientCancelerRewardPool {
+        available: Uint128,
+        required: Uint128,
+    },
+
+    // ========================================================================
+    // Relay Errors
+    // ========================================================================
+    #[error("Relaying is disabled (relay fee cap is 0)")]
+    RelayingDisabled,
+
+    #[error("Relay fee {fee} exceeds the cap of {max}")]
+    RelayFeeTooHigh { fee: Uint128, max: Uint128 },
+
+    #[error("Relay fee cap exceeds maximum: {cap_bps} bps (max 500)")]
+    RelayFeeCapExceedsMax { cap_bps: u64 },
+
+    #[error("Deposit not found")]
+    DepositNotFound,
+
+    #[error("Unauthorized: caller did not make this deposit")]
+    NotDepositor,
+
+    #[error("Relay already requested for this deposit")]
+    RelayAlreadyRequested,
+
+    #[error("Unauthorized: only the recipient can take over a relayed withdrawal")]
+    NotWithdrawRecipient,
+
     // ========================================================================
     // Recovery Errors
     // ========================================================================
@@ -0,0 +1,278 @@
+//! Canceler reward pool handlers.
+//!
+//! Cancelers run at their own cost, so the bridge pays a bounty to the first
+//! canceler whose cancellation survives the review period:
+//! 1. `SetCancelerRewardConfig` — admin sets the denom, reward and review period
+//! 2. `FundCancelerRewardPool` — anyone tops up the pool
+//! 3. `WithdrawCancel` records the first canceler (see `withdraw.rs`)
+//! 4. `WithdrawUncancel` drops the record if the cancel was wrong
+//! 5. `ClaimCancelerReward` — canceler claims once the review period has passed
+
+use cosmwasm_std::{
+    Addr, BankMsg, Binary, Coin, DepsMut, Env, MessageInfo, Response, Storage, Uint128,
+};
+
+use crate::error::ContractError;
+use crate::hash::bytes32_to_hex;
+use crate::state::{
+    CancelRecord, CancelerRewardConfig, CANCELERS, CANCELER_REWARD_CONFIG, CANCELER_REWARD_POOL,
+    CANCELER_REWARD_STATS, CANCEL_RECORDS, CONFIG, MAX_CANCELER_REVIEW_PERIOD, PENDING_WITHDRAWS,
+};
+
+// ============================================================================
+// SetCancelerRewardConfig — Admin
+// ============================================================================
+
+/// Configure the canceler bounty.
+///
+/// The denom cannot change while the pool still holds funds, otherwise the
+/// pool balance would be accounted in the wrong denom.
+pub fn execute_set_canceler_reward_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    denom: String,
+    reward_amount: Uint128,
+    review_period: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized);
+    }
+
+    if review_period == 0 || review_period > MAX_CANCELER_REVIEW_PERIOD {
+        return Err(ContractError::InvalidCancelerReviewPeriod);
+    }
+    if denom.is_empty() {
+        return Err(ContractError::InvalidAmount {
+            reason: "Reward denom cannot be empty".to_string(),
+        });
+    }
+
+    let pool = CANCELER_REWARD_POOL
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    if let Some(existing) = CANCELER_REWARD_CONFIG.may_load(deps.storage)? {
+        if existing.denom != denom && !pool.is_zero() {
+            return Err(ContractError::CancelerRewardPoolNotEmpty {
+                pool,
+                denom: existing.denom,
+            });
+        }
+    }
+
+    CANCELER_REWARD_CONFIG.save(
+        deps.storage,
+        &CancelerRewardConfig {
+            denom: denom.clone(),
+            reward_amount,
+            review_period,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "set_canceler_reward_config")
+        .add_attribute("denom", denom)
+        .add_attribute("reward_amount", reward_amount.to_string())
+        .add_attribute("review_period", review_period.to_string()))
+}
+
+// ============================================================================
+// FundCancelerRewardPool — Anyone
+// ============================================================================
+
+/// Add funds to the canceler reward pool. Only the configured denom is accepted.
+pub fn execute_fund_canceler_reward_pool(
+    deps: DepsMut,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let reward_config = CANCELER_REWARD_CONFIG
+        .may_load(deps.storage)?
+        .ok_or(ContractError::CancelerRewardsNotConfigured)?;
+
+    if info.funds.is_empty() {
+        return Err(ContractError::NoFundsSent);
+    }
+    let mut amount = Uint128::zero();
+    for coin in &info.funds {
+        if coin.denom != reward_config.denom {
+            return Err(ContractError::InvalidAmount {
+                reason: format!(
+                    "Reward pool only accepts {}, got {}",
+                    reward_config.denom, coin.denom
+                ),
+            });
+        }
+        amount += coin.amount;
+    }
+    if amount.is_zero() {
+        return Err(ContractError::NoFundsSent);
+    }
+
+    let pool = CANCELER_REWARD_POOL
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        + amount;
+    CANCELER_REWARD_POOL.save(deps.storage, &pool)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "fund_canceler_reward_pool")
+        .add_attribute("funder", info.sender.to_string())
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("pool", pool.to_string()))
+}
+
+// ============================================================================
+// ClaimCancelerReward — Recorded canceler
+// ============================================================================
+
+/// Pay the bounty for a cancellation that was not reverted within the review period.
+pub fn execute_claim_canceler_reward(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    xchain_hash_id: Binary,
+) -> Result<Response, ContractError> {
+    let reward_config = CANCELER_REWARD_CONFIG
+        .may_load(deps.storage)?
+        .ok_or(ContractError::CancelerRewardsNotConfigured)?;
+
+    let hash_bytes: [u8; 32] =
+        xchain_hash_id
+            .to_vec()
+            .try_into()
+            .map_err(|_| ContractError::InvalidHashLength {
+                got: xchain_hash_id.len(),
+            })?;
+
+    let mut record = CANCEL_RECORDS
+        .may_load(deps.storage, &hash_bytes)?
+        .ok_or(ContractError::CancelRecordNotFound)?;
+    if record.canceler != info.sender {
+        return Err(ContractError::NotRecordedCanceler);
+    }
+    if record.claimed {
+        return Err(ContractError::CancelerRewardAlreadyClaimed);
+    }
+
+    // A canceler removed by admin (e.g. for griefing) forfeits unclaimed rewards
+    let is_canceler = CANCELERS
+        .may_load(deps.storage, &info.sender)?
+        .unwrap_or(false);
+    if !is_canceler {
+        return Err(ContractError::NotCanceler);
+    }
+
+    let pending = PENDING_WITHDRAWS
+        .may_load(deps.storage, &hash_bytes)?
+        .ok_or(ContractError::WithdrawNotFound)?;
+    if !pending.cancelled || pending.executed {
+        return Err(ContractError::CancelReverted);
+    }
+
+    let claimable_at = record.cancelled_at + reward_config.review_period;
+    if env.block.time.seconds() < claimable_at {
+        return Err(ContractError::CancelerReviewPeriodActive {
+            ends_at: claimable_at,
+        });
+    }
+
+    let pool = CANCELER_REWARD_POOL
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    if pool < reward_config.reward_amount {
+        return Err(ContractError::InsufficientCancelerRewardPool {
+            available: pool,
+            required: reward_config.reward_amount,
+        });
+    }
+    CANCELER_REWARD_POOL.save(deps.storage, &(pool - reward_config.reward_amount))?;
+
+    record.claimed = true;
+    CANCEL_RECORDS.save(deps.storage, &hash_bytes, &record)?;
+
+    let mut stats = CANCELER_REWARD_STATS
+        .may_load(deps.storage, &info.sender)?
+        .unwrap_or_default();
+    stats.rewards_claimed += 1;
+    stats.total_paid += reward_config.reward_amount;
+    CANCELER_REWARD_STATS.save(deps.storage, &info.sender, &stats)?;
+
+    let mut response = Response::new();
+    if !reward_config.reward_amount.is_zero() {
+        response = response.add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![Coin {
+                denom: reward_config.denom.clone(),
+                amount: reward_config.reward_amount,
+            }],
+        });
+    }
+
+    Ok(response
+        .add_attribute("action", "claim_canceler_reward")
+        .add_attribute("xchain_hash_id", bytes32_to_hex(&hash_bytes))
+        .add_attribute("canceler", info.sender.to_string())
+        .add_attribute("amount", reward_config.reward_amount.to_string())
+        .add_attribute("denom", reward_config.denom))
+}
+
+// ============================================================================
+// Hooks used by the withdraw flow
+// ============================================================================
+
+/// Record the first canceler of a withdrawal. Returns false without a reward
+/// pool configured, or if a record already exists (re-cancel by another
+/// canceler, or a claimed record after an uncancel).
+pub(crate) fn record_cancel(
+    storage: &mut dyn Storage,
+    env: &Env,
+    hash_bytes: &[u8; 32],
+    canceler: &Addr,
+) -> Result<bool, ContractError> {
+    if CANCELER_REWARD_CONFIG.may_load(storage)?.is_none()
+        || CANCEL_RECORDS.has(storage, hash_bytes)
+    {
+        return Ok(false);
+    }
+
+    CANCEL_RECORDS.save(
+        storage,
+        hash_bytes,
+        &CancelRecord {
+            canceler: canceler.clone(),
+            cancelled_at: env.block.time.seconds(),
+            claimed: false,
+        },
+    )?;
+
+    let mut stats = CANCELER_REWARD_STATS
+        .may_load(storage, canceler)?
+        .unwrap_or_default();
+    stats.cancels += 1;
+    CANCELER_REWARD_STATS.save(storage, canceler, &stats)?;
+
+    Ok(true)
+}
+
+/// Drop an unclaimed cancel record when an operator reverts the cancellation.
+pub(crate) fn revert_cancel_record(
+    storage: &mut dyn Storage,
+    hash_bytes: &[u8; 32],
+) -> Result<(), ContractError> {
+    let Some(record) = CANCEL_RECORDS.may_load(storage, hash_bytes)? else {
+        return Ok(());
+    };
+    if record.claimed {
+        return Ok(());
+    }
+
+    CANCEL_RECORDS.remove(storage, hash_bytes);
+
+    let mut stats = CANCELER_REWARD_STATS
+        .may_load(storage, &record.canceler)?
+        .unwrap_or_default();
+    stats.reverted += 1;
+    CANCELER_REWARD_STATS.save(storage, &record.canceler, &stats)?;
+
+    Ok(())
+}
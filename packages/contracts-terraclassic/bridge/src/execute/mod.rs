@@ -3,15 +3,21 @@
 //! This module contains all execute message handlers, organized by category:
 //! - `outgoing` - Deposit handlers for outgoing transfers (lock/burn)
 //! - `withdraw` - V2 withdrawal flow (submit, approve, cancel, uncancel, execute)
+//! - `canceler_reward` - Canceler bounty pool (configure, fund, claim)
+//! - `relay` - Relayed withdrawals (fee cap, relay requests, operator submit)
 //! - `config` - Chain, token, operator, canceler, and rate limit management
 //! - `admin` - Pause, unpause, admin transfer, and recovery operations
 
 mod admin;
+mod canceler_reward;
 mod config;
 mod outgoing;
+mod relay;
 mod withdraw;
 
 pub use admin::*;
+pub use canceler_reward::*;
 pub use config::*;
 pub use outgoing::*;
+pub use relay::*;
 pub use withdraw::*;
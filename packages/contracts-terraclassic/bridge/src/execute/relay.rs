@@ -0,0 +1,295 @@
+//! Relayed (gasless) withdrawal handlers.
+//!
+//! A depositor who has no gas on the destination chain can ask an operator to
+//! submit the withdrawal for them, paying a fee out of the withdrawn amount:
+//! 1. `SetRelayFeeCap` — admin caps relay fees in basis points (0 disables relaying)
+//! 2. `RequestRelay` — depositor opts a deposit made on this chain into relaying
+//! 3. `WithdrawSubmitRelayed` — operator submits a withdrawal on this chain and records its fee
+//! 4. `WithdrawExecuteUnlock` / `WithdrawExecuteMint` pay the fee to the relayer (see `withdraw.rs`)
+//!
+//! Whether the depositor requested the relay on the source chain is checked
+//! off-chain by operators and cancelers, as on EVM. Until execution, the recipient's
+//! own `WithdrawSubmit` of the same transfer drops the relay and its fee, so an
+//! operator cannot front-run the recipient into paying one.
+
+use cosmwasm_std::{Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response, Storage, Uint128};
+
+use super::withdraw::{normalize_decimals, store_pending_withdraw};
+use crate::error::ContractError;
+use crate::hash::{
+    bytes32_to_hex, compute_xchain_hash_id, encode_terra_address, encode_token_address,
+};
+use crate::state::{
+    PendingWithdraw, WithdrawRelay, CONFIG, DEPOSIT_HASHES, MAX_RELAY_FEE_BPS, OPERATORS,
+    PENDING_WITHDRAWS, RELAY_FEE_CAP_BPS, RELAY_REQUESTS, THIS_CHAIN_ID, WITHDRAW_RELAYS,
+};
+
+/// Basis points denominator for relay fee caps
+const BPS_DENOMINATOR: u128 = 10_000;
+
+// ============================================================================
+// SetRelayFeeCap — Admin
+// ============================================================================
+
+/// Set the cap on relay fees, in basis points of the withdrawn amount.
+pub fn execute_set_relay_fee_cap(
+    deps: DepsMut,
+    info: MessageInfo,
+    relay_fee_cap_bps: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized);
+    }
+
+    if relay_fee_cap_bps > MAX_RELAY_FEE_BPS {
+        return Err(ContractError::RelayFeeCapExceedsMax {
+            cap_bps: relay_fee_cap_bps,
+        });
+    }
+
+    RELAY_FEE_CAP_BPS.save(deps.storage, &relay_fee_cap_bps)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "set_relay_fee_cap")
+        .add_attribute("relay_fee_cap_bps", relay_fee_cap_bps.to_string()))
+}
+
+// ============================================================================
+// RequestRelay — Depositor
+// ============================================================================
+
+/// Ask operators to submit the withdrawal for a deposit made on this chain.
+///
+/// `max_relay_fee` is in the deposit's amount units and must be below the
+/// deposit amount. A deposit can only be opted in once.
+pub fn execute_request_relay(
+    deps: DepsMut,
+    info: MessageInfo,
+    xchain_hash_id: Binary,
+    max_relay_fee: Uint128,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.paused {
+        return Err(ContractError::BridgePaused);
+    }
+
+    let hash_bytes: [u8; 32] =
+        xchain_hash_id
+            .to_vec()
+            .try_into()
+            .map_err(|_| ContractError::InvalidHashLength {
+                got: xchain_hash_id.len(),
+            })?;
+
+    let deposit = DEPOSIT_HASHES
+        .may_load(deps.storage, &hash_bytes)?
+        .ok_or(ContractError::DepositNotFound)?;
+    if deposit.src_account != encode_terra_address(deps.as_ref(), &info.sender)? {
+        return Err(ContractError::NotDepositor);
+    }
+    if max_relay_fee.is_zero() || max_relay_fee >= deposit.amount {
+        return Err(ContractError::InvalidAmount {
+            reason: "Max relay fee must be non-zero and below the deposit amount".to_string(),
+        });
+    }
+    if RELAY_REQUESTS.has(deps.storage, &hash_bytes) {
+        return Err(ContractError::RelayAlreadyRequested);
+    }
+
+    RELAY_REQUESTS.save(deps.storage, &hash_bytes, &max_relay_fee)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "request_relay")
+        .add_attribute("xchain_hash_id", bytes32_to_hex(&hash_bytes))
+        .add_attribute(
+            "dest_chain",
+            format!("0x{}", hex::encode(deposit.dest_chain)),
+        )
+        .add_attribute("max_relay_fee", max_relay_fee.to_string()))
+}
+
+// ============================================================================
+// WithdrawSubmitRelayed — Operator
+// ============================================================================
+
+/// Operator submits a withdrawal on behalf of a recipient whose depositor requested relaying.
+///
+/// `relay_fee` is in source chain decimals and is paid to the operator out of the
+/// withdrawn amount on execution. A cap of 0 rejects every relay, even a free one.
+#[allow(clippy::too_many_arguments)]
+pub fn execute_withdraw_submit_relayed(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    src_chain: Binary,
+    src_account: Binary,
+    token: String,
+    recipient: String,
+    amount: Uint128,
+    nonce: u64,
+    relay_fee: Uint128,
+) -> Result<Response, ContractError> {
+    let is_operator = OPERATORS
+        .may_load(deps.storage, &info.sender)?
+        .unwrap_or(false);
+    if !is_operator {
+        return Err(ContractError::UnauthorizedOperator);
+    }
+    if !info.funds.is_empty() {
+        return Err(ContractError::InvalidAmount {
+            reason: "Relayed withdrawals do not accept funds".to_string(),
+        });
+    }
+
+    let cap_bps = RELAY_FEE_CAP_BPS.may_load(deps.storage)?.unwrap_or(0);
+    if cap_bps == 0 {
+        return Err(ContractError::RelayingDisabled);
+    }
+    let max_fee = amount.multiply_ratio(cap_bps as u128, BPS_DENOMINATOR);
+    if relay_fee.full_mul(BPS_DENOMINATOR) > amount.full_mul(cap_bps as u128) {
+        return Err(ContractError::RelayFeeTooHigh {
+            fee: relay_fee,
+            max: max_fee,
+        });
+    }
+
+    let (xchain_hash_id, recipient) = store_pending_withdraw(
+        deps.branch(),
+        &env,
+        &src_chain,
+        &src_account,
+        &token,
+        &recipient,
+        amount,
+        nonce,
+        vec![],
+    )?;
+    WITHDRAW_RELAYS.save(
+        deps.storage,
+        &xchain_hash_id,
+        &WithdrawRelay {
+            relayer: info.sender.clone(),
+            fee: relay_fee,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "withdraw_submit")
+        .add_attribute("xchain_hash_id", bytes32_to_hex(&xchain_hash_id))
+        .add_attribute("token", token)
+        .add_attribute("recipient", recipient.to_string())
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("nonce", nonce.to_string())
+        .add_attribute("relayer", info.sender.to_string())
+        .add_attribute("relay_fee", relay_fee.to_string()))
+}
+
+// ============================================================================
+// Relay Drop — Recipient (through WithdrawSubmit)
+// ============================================================================
+
+/// Recipient takes over a relayed withdrawal, dropping the operator's relay fee.
+///
+/// The withdrawal keeps its state, so a relay cancelled for an unauthorized fee can
+/// be uncancelled without it. Funds sent before approval are forwarded to the
+/// approving operator like those of a plain `WithdrawSubmit`.
+pub(crate) fn execute_drop_relay(
+    deps: DepsMut,
+    info: MessageInfo,
+    xchain_hash_id: [u8; 32],
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.paused {
+        return Err(ContractError::BridgePaused);
+    }
+
+    let mut pending = PENDING_WITHDRAWS.load(deps.storage, &xchain_hash_id)?;
+    if info.sender != pending.recipient {
+        return Err(ContractError::NotWithdrawRecipient);
+    }
+    if pending.executed {
+        return Err(ContractError::WithdrawAlreadyExecuted);
+    }
+    if pending.approved && !info.funds.is_empty() {
+        return Err(ContractError::InvalidAmount {
+            reason: "Withdrawal already approved, operator funds not accepted".to_string(),
+        });
+    }
+
+    let relay = WITHDRAW_RELAYS.load(deps.storage, &xchain_hash_id)?;
+    WITHDRAW_RELAYS.remove(deps.storage, &xchain_hash_id);
+    pending.operator_funds.extend(info.funds);
+    PENDING_WITHDRAWS.save(deps.storage, &xchain_hash_id, &pending)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "drop_relay")
+        .add_attribute("xchain_hash_id", bytes32_to_hex(&xchain_hash_id))
+        .add_attribute("relayer", relay.relayer.to_string()))
+}
+
+// ============================================================================
+// Internal Helpers
+// ============================================================================
+
+/// Relayer and relay fee (in destination decimals) to pay out of an executed withdrawal.
+///
+/// Returns `None` for withdrawals the user submitted, or relays with a zero fee.
+pub(crate) fn relay_payout(
+    storage: &dyn Storage,
+    xchain_hash_id: &[u8; 32],
+    pending: &PendingWithdraw,
+) -> Result<Option<(Addr, Uint128)>, ContractError> {
+    let Some(relay) = WITHDRAW_RELAYS.may_load(storage, xchain_hash_id)? else {
+        return Ok(None);
+    };
+    if relay.fee.is_zero() {
+        return Ok(None);
+    }
+    let fee = normalize_decimals(relay.fee, pending.src_decimals, pending.dest_decimals)?;
+    Ok(Some((relay.relayer, fee)))
+}
+
+/// Hash of the withdrawal a `WithdrawSubmit` describes, if an operator already
+/// submitted it relayed.
+///
+/// Malformed inputs return `None`; `store_pending_withdraw` reports them.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn relayed_withdraw_hash(
+    deps: Deps,
+    src_chain: &Binary,
+    src_account: &Binary,
+    token: &str,
+    recipient: &str,
+    amount: Uint128,
+    nonce: u64,
+) -> Result<Option<[u8; 32]>, ContractError> {
+    let (Ok(src_chain), Ok(src_account)) = (
+        <[u8; 4]>::try_from(src_chain.as_slice()),
+        <[u8; 32]>::try_from(src_account.as_slice()),
+    ) else {
+        return Ok(None);
+    };
+    let Ok(recipient) = deps.api.addr_validate(recipient) else {
+        return Ok(None);
+    };
+    let (Ok(dest_account), Ok(token)) = (
+        encode_terra_address(deps, &recipient),
+        encode_token_address(deps, token),
+    ) else {
+        return Ok(None);
+    };
+
+    let xchain_hash_id = compute_xchain_hash_id(
+        &src_chain,
+        &THIS_CHAIN_ID.load(deps.storage)?,
+        &src_account,
+        &dest_account,
+        &token,
+        amount.u128(),
+        nonce,
+    );
+    Ok(WITHDRAW_RELAYS
+        .has(deps.storage, &xchain_hash_id)
+        .then_some(xchain_hash_id))
+}
@@ -3,17 +3,22 @@
 //! This module implements the V2 withdrawal pattern:
 //! 1. `WithdrawSubmit` — user creates a pending withdrawal (pays gas + operator tip)
 //! 2. `WithdrawApprove` — operator verifies deposit and approves (receives gas tip)
-//! 3. `WithdrawCancel` — canceler cancels within cancel window
+//! 3. `WithdrawCancel` — canceler cancels within cancel window (records reward eligibility)
 //! 4. `WithdrawUncancel` — operator restores a cancelled withdrawal
 //! 5. `WithdrawExecuteUnlock` — anyone executes (unlock mode) after cancel window
 //! 6. `WithdrawExecuteMint` — anyone executes (mint mode) after cancel window
+//!
+//! Withdrawals an operator submitted via `WithdrawSubmitRelayed` (see `relay.rs`)
+//! pay the relay fee to that operator on execution.
 
 use cosmwasm_std::{
-    to_json_binary, BankMsg, Binary, Coin, CosmosMsg, DepsMut, Env, MessageInfo, Response, Storage,
-    Uint128, Uint256, Uint512,
+    to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, DepsMut, Env, MessageInfo, Response,
+    Storage, Uint128, Uint256, Uint512,
 };
 use cw20::{Cw20ExecuteMsg, Cw20QueryMsg};
 
+use super::canceler_reward::{record_cancel, revert_cancel_record};
+use super::relay::{execute_drop_relay, relay_payout, relayed_withdraw_hash};
 use crate::error::ContractError;
 use crate::hash::{
     bytes32_to_hex, compute_xchain_hash_id, encode_terra_address, encode_token_address,
@@ -36,6 +41,8 @@ const DEFAULT_RATE_LIMIT_IF_ZERO_SUPPLY: Uint128 = Uint128::new(100_000_000_000_
 ///
 /// The user provides parameters matching the deposit on the source chain.
 /// All native tokens sent with the message are forwarded to the operator on approval.
+/// If an operator already submitted the withdrawal relayed, the recipient's submit
+/// drops the relay instead (see `relay.rs`).
 #[allow(clippy::too_many_arguments)]
 pub fn execute_withdraw_submit(
     deps: DepsMut,
@@ -48,6 +55,56 @@ pub fn execute_withdraw_submit(
     amount: Uint128,
     nonce: u64,
 ) -> Result<Response, ContractError> {
+    if let Some(xchain_hash_id) = relayed_withdraw_hash(
+        deps.as_ref(),
+        &src_chain,
+        &src_account,
+        &token,
+        &recipient,
+        amount,
+        nonce,
+    )? {
+        return execute_drop_relay(deps, info, xchain_hash_id);
+    }
+
+    // All native tokens sent with message are forwarded to operator on approve
+    let (xchain_hash_id, recipient) = store_pending_withdraw(
+        deps,
+        &env,
+        &src_chain,
+        &src_account,
+        &token,
+        &recipient,
+        amount,
+        nonce,
+        info.funds,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "withdraw_submit")
+        .add_attribute("xchain_hash_id", bytes32_to_hex(&xchain_hash_id))
+        .add_attribute("token", token)
+        .add_attribute("recipient", recipient.to_string())
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("nonce", nonce.to_string()))
+}
+
+/// Validate a withdrawal against the registries and store it as a `PendingWithdraw`.
+///
+/// Shared by `WithdrawSubmit` and the operator's `WithdrawSubmitRelayed`. Returns the withdraw
+/// hash and the validated recipient.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn store_pending_withdraw(
+    deps: DepsMut,
+    env: &Env,
+    src_chain: &Binary,
+    src_account: &Binary,
+    token: &str,
+    recipient: &str,
+    amount: Uint128,
+    nonce: u64,
+    operator_funds: Vec<Coin>,
+) -> Result<([u8; 32], Addr), ContractError> {
     let config = CONFIG.load(deps.storage)?;
     if config.paused {
         return Err(ContractError::BridgePaused);
@@ -76,19 +133,18 @@ pub fn execute_withdraw_submit(
             })?;
 
     // Validate recipient and encode as bytes32
-    let recipient_addr = deps.api.addr_validate(&recipient)?;
+    let recipient_addr = deps.api.addr_validate(recipient)?;
     let dest_account_bytes = encode_terra_address(deps.as_ref(), &recipient_addr)?;
 
     // Validate token is supported, enabled, and get decimals
-    let token_config =
-        TOKENS
-            .may_load(deps.storage, token.clone())?
-            .ok_or(ContractError::TokenNotSupported {
-                token: token.clone(),
-            })?;
+    let token_config = TOKENS.may_load(deps.storage, token.to_string())?.ok_or(
+        ContractError::TokenNotSupported {
+            token: token.to_string(),
+        },
+    )?;
     if !token_config.enabled {
         return Err(ContractError::TokenNotSupported {
-            token: token.clone(),
+            token: token.to_string(),
         });
     }
 
@@ -108,7 +164,7 @@ pub fn execute_withdraw_submit(
     let dest_chain = THIS_CHAIN_ID.load(deps.storage)?;
 
     // Encode token for hash computation
-    let token_bytes32 = encode_token_address(deps.as_ref(), &token)?;
+    let token_bytes32 = encode_token_address(deps.as_ref(), token)?;
 
     // Validate incoming token mapping exists for this source chain
     let src_chain_key = hex::encode(src_chain_bytes);
@@ -117,12 +173,12 @@ pub fn execute_withdraw_submit(
         .may_load(deps.storage, (&src_chain_key, &src_token_key))?
         .ok_or(ContractError::TokenNotMappedForChain {
             chain_id: format!("0x{}", src_chain_key),
-            token: token.clone(),
+            token: token.to_string(),
         })?;
     if !src_mapping.enabled {
         return Err(ContractError::TokenNotMappedForChain {
             chain_id: format!("0x{}", src_chain_key),
-            token: token.clone(),
+            token: token.to_string(),
         });
     }
 
@@ -156,15 +212,12 @@ pub fn execute_withdraw_submit(
 
     let recipient = recipient_addr;
 
-    // All native tokens sent with message are forwarded to operator on approve
-    let operator_funds = info.funds.clone();
-
     // Store pending withdrawal
     let pending = PendingWithdraw {
         src_chain: src_chain_bytes,
         src_account: src_account_bytes,
         dest_account: dest_account_bytes,
-        token: token.clone(),
+        token: token.to_string(),
         recipient: recipient.clone(),
         amount,
         nonce,
@@ -179,13 +232,7 @@ pub fn execute_withdraw_submit(
     };
     PENDING_WITHDRAWS.save(deps.storage, &xchain_hash_id, &pending)?;
 
-    Ok(Response::new()
-        .add_attribute("action", "withdraw_submit")
-        .add_attribute("xchain_hash_id", bytes32_to_hex(&xchain_hash_id))
-        .add_attribute("token", token)
-        .add_attribute("recipient", recipient.to_string())
-        .add_attribute("amount", amount.to_string())
-        .add_attribute("nonce", nonce.to_string()))
+    Ok((xchain_hash_id, recipient))
 }
 
 // ============================================================================
@@ -301,10 +348,14 @@ pub fn execute_withdraw_cancel(
     pending.cancelled = true;
     PENDING_WITHDRAWS.save(deps.storage, &hash_bytes, &pending)?;
 
+    // First canceler is eligible for the reward pool bounty
+    let reward_recorded = record_cancel(deps.storage, &env, &hash_bytes, &info.sender)?;
+
     Ok(Response::new()
         .add_attribute("action", "withdraw_cancel")
         .add_attribute("xchain_hash_id", bytes32_to_hex(&hash_bytes))
-        .add_attribute("cancelled_by", info.sender.to_string()))
+        .add_attribute("cancelled_by", info.sender.to_string())
+        .add_attribute("reward_recorded", reward_recorded.to_string()))
 }
 
 // ============================================================================
@@ -345,6 +396,9 @@ pub fn execute_withdraw_uncancel(
     pending.approved_at = env.block.time.seconds();
     PENDING_WITHDRAWS.save(deps.storage, &hash_bytes, &pending)?;
 
+    // A reverted cancel is not eligible for a reward
+    revert_cancel_record(deps.storage, &hash_bytes)?;
+
     Ok(Response::new()
         .add_attribute("action", "withdraw_uncancel")
         .add_attribute("xchain_hash_id", bytes32_to_hex(&hash_bytes))
@@ -415,25 +469,27 @@ pub fn execute_withdraw_execute_unlock(
     pending.executed = true;
     PENDING_WITHDRAWS.save(deps.storage, &hash_bytes, &pending)?;
 
-    // Transfer tokens to recipient
+    // Transfer tokens to recipient, less the relay fee for operator-submitted withdrawals
     let mut messages: Vec<CosmosMsg> = vec![];
-    if token_config.is_native {
-        messages.push(CosmosMsg::Bank(BankMsg::Send {
-            to_address: pending.recipient.to_string(),
-            amount: vec![Coin {
-                denom: pending.token.clone(),
-                amount: payout_amount,
-            }],
-        }));
-    } else {
-        messages.push(CosmosMsg::Wasm(cosmwasm_std::WasmMsg::Execute {
-            contract_addr: pending.token.clone(),
-            msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
-                recipient: pending.recipient.to_string(),
-                amount: payout_amount,
-            })?,
-            funds: vec![],
-        }));
+    for (to, amount) in split_payout(deps.storage, &hash_bytes, &pending, payout_amount)? {
+        if token_config.is_native {
+            messages.push(CosmosMsg::Bank(BankMsg::Send {
+                to_address: to.to_string(),
+                amount: vec![Coin {
+                    denom: pending.token.clone(),
+                    amount,
+                }],
+            }));
+        } else {
+            messages.push(CosmosMsg::Wasm(cosmwasm_std::WasmMsg::Execute {
+                contract_addr: pending.token.clone(),
+                msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: to.to_string(),
+                    amount,
+                })?,
+                funds: vec![],
+            }));
+        }
     }
 
     // Update stats
@@ -499,15 +555,20 @@ pub fn execute_withdraw_execute_mint(
     pending.executed = true;
     PENDING_WITHDRAWS.save(deps.storage, &hash_bytes, &pending)?;
 
-    // Mint CW20 tokens to recipient
-    let messages: Vec<CosmosMsg> = vec![CosmosMsg::Wasm(cosmwasm_std::WasmMsg::Execute {
-        contract_addr: pending.token.clone(),
-        msg: to_json_binary(&Cw20ExecuteMsg::Mint {
-            recipient: pending.recipient.to_string(),
-            amount: payout_amount,
-        })?,
-        funds: vec![],
-    })];
+    // Mint CW20 tokens to recipient, less the relay fee for operator-submitted withdrawals
+    let messages = split_payout(deps.storage, &hash_bytes, &pending, payout_amount)?
+        .into_iter()
+        .map(|(to, amount)| {
+            Ok(CosmosMsg::Wasm(cosmwasm_std::WasmMsg::Execute {
+                contract_addr: pending.token.clone(),
+                msg: to_json_binary(&Cw20ExecuteMsg::Mint {
+                    recipient: to.to_string(),
+                    amount,
+                })?,
+                funds: vec![],
+            }))
+        })
+        .collect::<Result<Vec<CosmosMsg>, ContractError>>()?;
 
     // Update stats
     let mut stats = STATS.load(deps.storage)?;
@@ -527,6 +588,26 @@ pub fn execute_withdraw_execute_mint(
 // Internal Helpers
 // ============================================================================
 
+/// Split an executed withdrawal between the recipient and, if it was relayed, the relayer.
+///
+/// Zero amounts are dropped so no empty transfers are sent.
+fn split_payout(
+    storage: &dyn Storage,
+    xchain_hash_id: &[u8; 32],
+    pending: &PendingWithdraw,
+    payout_amount: Uint128,
+) -> Result<Vec<(Addr, Uint128)>, ContractError> {
+    let (relayer, fee) = match relay_payout(storage, xchain_hash_id, pending)? {
+        Some((relayer, fee)) => (Some(relayer), fee.min(payout_amount)),
+        None => (None, Uint128::zero()),
+    };
+    let mut payouts = vec![(pending.recipient.clone(), payout_amount - fee)];
+    if let Some(relayer) = relayer {
+        payouts.push((relayer, fee));
+    }
+    Ok(payouts.into_iter().filter(|(_, a)| !a.is_zero()).collect())
+}
+
 /// Parse a 32-byte hash from Binary input.
 fn parse_hash(xchain_hash_id: &Binary) -> Result<[u8; 32], ContractError> {
     xchain_hash_id
@@ -545,7 +626,7 @@ fn parse_hash(xchain_hash_id: &Binary) -> Result<[u8; 32], ContractError> {
 /// If `src_decimals == dest_decimals` (or both are 0), no conversion is needed.
 /// If `src_decimals > dest_decimals`, divide (truncate towards zero).
 /// If `src_decimals < dest_decimals`, multiply (scale up).
-fn normalize_decimals(
+pub(crate) fn normalize_decimals(
     amount: Uint128,
     src_decimals: u8,
     dest_decimals: u8,
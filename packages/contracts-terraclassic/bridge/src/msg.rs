@@ -69,6 +69,8 @@ pub enum ExecuteMsg {
     ///
     /// Creates a `PendingWithdraw` record. The user sends native token (uluna)
     /// as an operator gas tip, which is paid to the operator upon approval.
+    /// If an operator already submitted it relayed, the recipient's submit drops
+    /// the relay and its fee instead.
     WithdrawSubmit {
         /// Source chain ID (4 bytes)
         src_chain: Binary,
@@ -154,6 +156,75 @@ pub enum ExecuteMsg {
         address: String,
     },
 
+    // ========================================================================
+    // Canceler Rewards
+    // ========================================================================
+    /// Configure the canceler bounty paid for successful cancellations
+    ///
+    /// Authorization: Admin only
+    SetCancelerRewardConfig {
+        /// Native denom the reward pool is held in (e.g., "uluna")
+        denom: String,
+        /// Reward paid per successful cancellation
+        reward_amount: Uint128,
+        /// Seconds an operator has to revert a cancel before it becomes claimable (1–2592000)
+        review_period: u64,
+    },
+
+    /// Fund the canceler reward pool
+    ///
+    /// Authorization: Anyone (send funds in the configured reward denom)
+    FundCancelerRewardPool {},
+
+    /// Claim the bounty for a cancellation that was not reverted within the review period
+    ///
+    /// Authorization: The canceler that first cancelled the withdrawal
+    ClaimCancelerReward {
+        /// The 32-byte withdraw hash
+        xchain_hash_id: Binary,
+    },
+
+    // ========================================================================
+    // Relayed Withdrawals
+    // ========================================================================
+    /// Set the cap on relay fees in basis points of the withdrawn amount (0–500, 0 disables relaying)
+    ///
+    /// Authorization: Admin only
+    SetRelayFeeCap { relay_fee_cap_bps: u64 },
+
+    /// Ask operators to submit the withdrawal on the destination chain for a deposit made here
+    ///
+    /// Authorization: The depositor
+    RequestRelay {
+        /// The 32-byte deposit hash
+        xchain_hash_id: Binary,
+        /// Highest fee the depositor accepts, in deposit amount units (below the deposit amount)
+        max_relay_fee: Uint128,
+    },
+
+    /// Submit a withdrawal on behalf of a recipient whose depositor requested relaying
+    ///
+    /// Authorization: Operator only
+    ///
+    /// Same as `WithdrawSubmit`, except the relay fee is paid to the operator out of
+    /// the withdrawn amount on execution.
+    WithdrawSubmitRelayed {
+        /// Source chain ID (4 bytes)
+        src_chain: Binary,
+        /// Source account (depositor on source chain, 32 bytes)
+        src_account: Binary,
+        /// Token to withdraw (denom for native, contract for CW20)
+        token: String,
+        /// Recipient address on this chain (Terra address string)
+        recipient: String,
+        /// Amount to withdraw
+        amount: Uint128,
+        /// Nonce from source chain deposit
+        nonce: u64,
+        /// Fee in source chain decimals, bounded by the relay fee cap
+        relay_fee: Uint128,
+    },
+
     // ========================================================================
     // Configuration
     // ========================================================================
@@ -505,18 +576,37 @@ pub enum QueryMsg {
     // ========================================================================
     // Canceler Queries
     // ========================================================================
-    /// List all active cancelers
+    /// List all active cancelers and reward accounting (`rewards` paginated by canceler address)
     #[returns(CancelersResponse)]
-    Cancelers {},
+    Cancelers {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
 
     /// Check if an address is a canceler
     #[returns(IsCancelerResponse)]
     IsCanceler { address: String },
 
+    /// Get the recorded cancellation (and reward claim state) for a withdrawal
+    #[returns(Option<CancelRecordResponse>)]
+    CancelRecord { xchain_hash_id: Binary },
+
     // ========================================================================
     // Configuration Queries
     // ========================================================================
     /// Get this chain's predetermined 4-byte V2 chain ID (set at instantiation)
+    /// Get the relay fee cap in basis points (0 = relaying disabled)
+    #[returns(RelayFeeCapResponse)]
+    RelayFeeCap {},
+
+    /// Get the max relay fee a depositor accepted for a deposit made on this chain
+    #[returns(Option<RelayRequestResponse>)]
+    RelayRequest { xchain_hash_id: Binary },
+
+    /// Get the relayer and relay fee of an operator-submitted withdrawal
+    #[returns(Option<WithdrawRelayResponse>)]
+    WithdrawRelay { xchain_hash_id: Binary },
+
     #[returns(ThisChainIdResponse)]
     ThisChainId {},
 
@@ -825,6 +915,38 @@ pub struct VerifyDepositResponse {
 #[cw_serde]
 pub struct CancelersResponse {
     pub cancelers: Vec<Addr>,
+    /// Canceler bounty configuration (None if rewards are disabled)
+    pub reward_config: Option<CancelerRewardConfigResponse>,
+    /// Funds currently available in the reward pool
+    pub reward_pool: Uint128,
+    /// Reward accounting for cancelers that recorded a cancellation, after `start_after`
+    pub rewards: Vec<CancelerRewardEntry>,
+}
+
+#[cw_serde]
+pub struct CancelerRewardConfigResponse {
+    pub denom: String,
+    pub reward_amount: Uint128,
+    pub review_period: u64,
+}
+
+#[cw_serde]
+pub struct CancelerRewardEntry {
+    pub canceler: Addr,
+    pub cancels: u64,
+    pub reverted: u64,
+    pub rewards_claimed: u64,
+    pub total_paid: Uint128,
+}
+
+#[cw_serde]
+pub struct CancelRecordResponse {
+    pub xchain_hash_id: Binary,
+    pub canceler: Addr,
+    pub cancelled_at: u64,
+    /// Timestamp from which the reward can be claimed (cancelled_at + review_period)
+    pub claimable_at: u64,
+    pub claimed: bool,
 }
 
 #[cw_serde]
@@ -832,6 +954,29 @@ pub struct IsCancelerResponse {
     pub is_canceler: bool,
 }
 
+// ============================================================================
+// Response Types - Relay
+// ============================================================================
+
+#[cw_serde]
+pub struct RelayFeeCapResponse {
+    pub relay_fee_cap_bps: u64,
+}
+
+#[cw_serde]
+pub struct RelayRequestResponse {
+    pub xchain_hash_id: Binary,
+    pub max_relay_fee: Uint128,
+}
+
+#[cw_serde]
+pub struct WithdrawRelayResponse {
+    pub xchain_hash_id: Binary,
+    pub relayer: Addr,
+    /// Relay fee in source chain decimals
+    pub fee: Uint128,
+}
+
 // ============================================================================
 // Response Types - Configuration
 // ============================================================================
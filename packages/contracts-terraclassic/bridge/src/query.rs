@@ -14,21 +14,24 @@ use crate::hash::compute_xchain_hash_id;
 use crate::msg::{
     AccountFeeResponse, AllCustomAccountFeesResponse, AllRateLimitsResponse,
     AllTokenDestMappingsResponse, AllowedCw20CodeIdsResponse, CalculateFeeResponse,
-    CancelersResponse, ChainResponse, ChainsResponse, ComputeHashResponse, ConfigResponse,
-    CustomAccountFeeEntry, DepositInfoResponse, FeeConfigResponse, HasCustomFeeResponse,
-    IncomingTokenMappingResponse, IncomingTokenMappingsResponse, IsCancelerResponse,
-    LockedBalanceResponse, NonceResponse, OperatorsResponse, PendingAdminResponse,
-    PendingWithdrawResponse, PendingWithdrawalEntry, PendingWithdrawalsResponse,
-    PeriodUsageResponse, RateLimitEntry, RateLimitResponse, SimulationResponse, StatsResponse,
-    StatusResponse, ThisChainIdResponse, TokenDestMappingEntry, TokenDestMappingResponse,
-    TokenResponse, TokenTypeResponse, TokensResponse, TransactionResponse, VerifyDepositResponse,
-    WithdrawDelayResponse,
+    CancelRecordResponse, CancelerRewardConfigResponse, CancelerRewardEntry, CancelersResponse,
+    ChainResponse, ChainsResponse, ComputeHashResponse, ConfigResponse, CustomAccountFeeEntry,
+    DepositInfoResponse, FeeConfigResponse, HasCustomFeeResponse, IncomingTokenMappingResponse,
+    IncomingTokenMappingsResponse, IsCancelerResponse, LockedBalanceResponse, NonceResponse,
+    OperatorsResponse, PendingAdminResponse, PendingWithdrawResponse, PendingWithdrawalEntry,
+    PendingWithdrawalsResponse, PeriodUsageResponse, RateLimitEntry, RateLimitResponse,
+    RelayFeeCapResponse, RelayRequestResponse, SimulationResponse, StatsResponse, StatusResponse,
+    ThisChainIdResponse, TokenDestMappingEntry, TokenDestMappingResponse, TokenResponse,
+    TokenTypeResponse, TokensResponse, TransactionResponse, VerifyDepositResponse,
+    WithdrawDelayResponse, WithdrawRelayResponse,
 };
 use crate::state::{
-    ALLOWED_CW20_CODE_IDS, CANCELERS, CHAINS, CONFIG, DEPOSIT_BY_NONCE, DEPOSIT_HASHES,
+    ALLOWED_CW20_CODE_IDS, CANCELERS, CANCELER_REWARD_CONFIG, CANCELER_REWARD_POOL,
+    CANCELER_REWARD_STATS, CANCEL_RECORDS, CHAINS, CONFIG, DEPOSIT_BY_NONCE, DEPOSIT_HASHES,
     LOCKED_BALANCES, OPERATORS, OPERATOR_COUNT, OUTGOING_NONCE, PENDING_ADMIN, PENDING_WITHDRAWS,
-    RATE_LIMITS, RATE_LIMIT_PERIOD, RATE_WINDOWS, STATS, THIS_CHAIN_ID, TOKENS,
-    TOKEN_DEST_MAPPINGS, TOKEN_SRC_MAPPINGS, TRANSACTIONS, WITHDRAW_DELAY,
+    RATE_LIMITS, RATE_LIMIT_PERIOD, RATE_WINDOWS, RELAY_FEE_CAP_BPS, RELAY_REQUESTS, STATS,
+    THIS_CHAIN_ID, TOKENS, TOKEN_DEST_MAPPINGS, TOKEN_SRC_MAPPINGS, TRANSACTIONS, WITHDRAW_DELAY,
+    WITHDRAW_RELAYS,
 };
 
 // ============================================================================
@@ -565,8 +568,12 @@ pub fn query_verify_deposit(
 // Canceler Queries
 // ============================================================================
 
-/// Query all cancelers.
-pub fn query_cancelers(deps: Deps) -> StdResult<CancelersResponse> {
+/// Query all cancelers. Reward accounting rows are paginated by canceler address.
+pub fn query_cancelers(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<CancelersResponse> {
     let cancelers: Vec<Addr> = CANCELERS
         .range(deps.storage, None, None, Order::Ascending)
         .filter_map(|item| {
@@ -579,7 +586,65 @@ pub fn query_cancelers(deps: Deps) -> StdResult<CancelersResponse> {
         })
         .collect();
 
-    Ok(CancelersResponse { cancelers })
+    let reward_config =
+        CANCELER_REWARD_CONFIG
+            .may_load(deps.storage)?
+            .map(|c| CancelerRewardConfigResponse {
+                denom: c.denom,
+                reward_amount: c.reward_amount,
+                review_period: c.review_period,
+            });
+    let reward_pool = CANCELER_REWARD_POOL
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    let limit = limit.unwrap_or(ENUM_DEFAULT_LIMIT).min(ENUM_MAX_LIMIT) as usize;
+    let start_addr = start_after
+        .map(|s| deps.api.addr_validate(&s))
+        .transpose()?;
+    let start = start_addr.as_ref().map(Bound::exclusive);
+    let rewards = CANCELER_REWARD_STATS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (canceler, stats) = item?;
+            Ok(CancelerRewardEntry {
+                canceler,
+                cancels: stats.cancels,
+                reverted: stats.reverted,
+                rewards_claimed: stats.rewards_claimed,
+                total_paid: stats.total_paid,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(CancelersResponse {
+        cancelers,
+        reward_config,
+        reward_pool,
+        rewards,
+    })
+}
+
+/// Query the recorded cancellation for a withdrawal (reward claim state).
+pub fn query_cancel_record(
+    deps: Deps,
+    xchain_hash_id: Binary,
+) -> StdResult<Option<CancelRecordResponse>> {
+    let Some(record) = CANCEL_RECORDS.may_load(deps.storage, xchain_hash_id.as_slice())? else {
+        return Ok(None);
+    };
+    let review_period = CANCELER_REWARD_CONFIG
+        .may_load(deps.storage)?
+        .map(|c| c.review_period)
+        .unwrap_or(0);
+
+    Ok(Some(CancelRecordResponse {
+        xchain_hash_id,
+        canceler: record.canceler,
+        cancelled_at: record.cancelled_at,
+        claimable_at: record.cancelled_at + review_period,
+        claimed: record.claimed,
+    }))
 }
 
 /// Check if an address is a canceler.
@@ -589,6 +654,43 @@ pub fn query_is_canceler(deps: Deps, address: String) -> StdResult<IsCancelerRes
     Ok(IsCancelerResponse { is_canceler })
 }
 
+// ============================================================================
+// Relay Queries
+// ============================================================================
+
+/// Query the relay fee cap in basis points (0 = relaying disabled).
+pub fn query_relay_fee_cap(deps: Deps) -> StdResult<RelayFeeCapResponse> {
+    let relay_fee_cap_bps = RELAY_FEE_CAP_BPS.may_load(deps.storage)?.unwrap_or(0);
+    Ok(RelayFeeCapResponse { relay_fee_cap_bps })
+}
+
+/// Query the relay request for a deposit made on this chain.
+pub fn query_relay_request(
+    deps: Deps,
+    xchain_hash_id: Binary,
+) -> StdResult<Option<RelayRequestResponse>> {
+    Ok(RELAY_REQUESTS
+        .may_load(deps.storage, xchain_hash_id.as_slice())?
+        .map(|max_relay_fee| RelayRequestResponse {
+            xchain_hash_id,
+            max_relay_fee,
+        }))
+}
+
+/// Query the relayer and relay fee of an operator-submitted withdrawal.
+pub fn query_withdraw_relay(
+    deps: Deps,
+    xchain_hash_id: Binary,
+) -> StdResult<Option<WithdrawRelayResponse>> {
+    Ok(WITHDRAW_RELAYS
+        .may_load(deps.storage, xchain_hash_id.as_slice())?
+        .map(|relay| WithdrawRelayResponse {
+            xchain_hash_id,
+            relayer: relay.relayer,
+            fee: relay.fee,
+        }))
+}
+
 // ============================================================================
 // Configuration Queries
 // ============================================================================
@@ -224,6 +224,55 @@ pub struct RateLimitWindow {
     pub used: Uint128,
 }
 
+/// Canceler bounty configuration (set by admin via `SetCancelerRewardConfig`)
+///
+/// The first canceler to cancel an approved withdrawal may claim `reward_amount`
+/// from the reward pool once `review_period` has passed without the cancel being
+/// reverted by `WithdrawUncancel`.
+#[cw_serde]
+pub struct CancelerRewardConfig {
+    /// Native denom the reward pool is held in (e.g., "uluna")
+    pub denom: String,
+    /// Reward paid per successful cancellation
+    pub reward_amount: Uint128,
+    /// Seconds after a cancel during which an operator may still revert it
+    pub review_period: u64,
+}
+
+/// First cancellation of a withdrawal, used for bounty payout
+#[cw_serde]
+pub struct CancelRecord {
+    /// Canceler that cancelled the withdrawal first
+    pub canceler: Addr,
+    /// Block timestamp of the cancellation
+    pub cancelled_at: u64,
+    /// Whether the reward has been paid out
+    pub claimed: bool,
+}
+
+/// Operator that submitted a withdrawal on the recipient's behalf (`WithdrawSubmitRelayed`)
+#[cw_serde]
+pub struct WithdrawRelay {
+    /// Operator paid the relay fee on execution
+    pub relayer: Addr,
+    /// Relay fee in source chain decimals, deducted from the withdrawn amount
+    pub fee: Uint128,
+}
+
+/// Per-canceler reward accounting
+#[cw_serde]
+#[derive(Default)]
+pub struct CancelerRewardStats {
+    /// Cancellations recorded for this canceler (first canceler only)
+    pub cancels: u64,
+    /// Cancellations reverted by `WithdrawUncancel` before being claimed
+    pub reverted: u64,
+    /// Rewards claimed
+    pub rewards_claimed: u64,
+    /// Total amount paid out (in the reward pool denom)
+    pub total_paid: Uint128,
+}
+
 // ============================================================================
 // Constants
 // ============================================================================
@@ -243,6 +292,12 @@ pub const DEFAULT_WITHDRAW_DELAY: u64 = 300;
 /// Rate limit period in seconds (24 hours, matching EVM)
 pub const RATE_LIMIT_PERIOD: u64 = 86_400;
 
+/// Maximum canceler reward review period in seconds (30 days)
+pub const MAX_CANCELER_REVIEW_PERIOD: u64 = 2_592_000;
+
+/// Maximum relay fee cap in basis points (5%, matching EVM `MAX_RELAY_FEE_BPS`)
+pub const MAX_RELAY_FEE_BPS: u64 = 500;
+
 // ============================================================================
 // Core State Storage
 // ============================================================================
@@ -321,6 +376,32 @@ pub const DEPOSIT_BY_NONCE: Map<u64, [u8; 32]> = Map::new("deposit_by_nonce");
 /// Key: Address reference, Value: bool (true if active canceler)
 pub const CANCELERS: Map<&Addr, bool> = Map::new("cancelers");
 
+/// Canceler bounty configuration (None until admin enables rewards)
+pub const CANCELER_REWARD_CONFIG: Item<CancelerRewardConfig> = Item::new("canceler_reward_config");
+
+/// Funds available in the canceler reward pool (in `CancelerRewardConfig::denom`)
+pub const CANCELER_REWARD_POOL: Item<Uint128> = Item::new("canceler_reward_pool");
+
+/// First cancellation per withdrawal
+/// Key: 32-byte withdraw hash as &[u8], Value: CancelRecord
+pub const CANCEL_RECORDS: Map<&[u8], CancelRecord> = Map::new("cancel_records");
+
+/// Reward accounting per canceler
+/// Key: canceler address, Value: CancelerRewardStats
+pub const CANCELER_REWARD_STATS: Map<&Addr, CancelerRewardStats> =
+    Map::new("canceler_reward_stats");
+
+/// Cap on relay fees as basis points of the withdrawn amount (absent or 0 = relaying disabled)
+pub const RELAY_FEE_CAP_BPS: Item<u64> = Item::new("relay_fee_cap_bps");
+
+/// Highest relay fee a depositor accepts for a deposit made on this chain
+/// Key: 32-byte deposit hash as &[u8], Value: max relay fee (deposit amount units)
+pub const RELAY_REQUESTS: Map<&[u8], Uint128> = Map::new("relay_requests");
+
+/// Relayer and fee of withdrawals submitted by an operator
+/// Key: 32-byte withdraw hash as &[u8], Value: WithdrawRelay
+pub const WITHDRAW_RELAYS: Map<&[u8], WithdrawRelay> = Map::new("withdraw_relays");
+
 /// Allowed CW20 code IDs (empty = no restriction, any CW20 allowed)
 ///
 /// When non-empty, only CW20 contracts instantiated from these code IDs
@@ -0,0 +1,574 @@
+//! Canceler Reward Pool Integration Tests.
+//!
+//! Tests the canceler bounty lifecycle:
+//! - Admin configures rewards, anyone funds the pool
+//! - First canceler records eligibility on WithdrawCancel
+//! - Claim only after the review period, only if not reverted by WithdrawUncancel
+//! - Reward accounting in the Cancelers query (paginated)
+
+use cosmwasm_std::{coins, Addr, Binary, Uint128};
+use cw_multi_test::error::AnyResult;
+use cw_multi_test::{App, AppResponse, ContractWrapper, Executor};
+
+use bridge::msg::{CancelRecordResponse, CancelersResponse, ExecuteMsg, InstantiateMsg, QueryMsg};
+
+// ============================================================================
+// Test Setup
+// ============================================================================
+
+fn contract_bridge() -> Box<dyn cw_multi_test::Contract<cosmwasm_std::Empty>> {
+    let contract = ContractWrapper::new(
+        bridge::contract::execute,
+        bridge::contract::instantiate,
+        bridge::contract::query,
+    );
+    Box::new(contract)
+}
+
+struct TestEnv {
+    app: App,
+    contract_addr: Addr,
+    admin: Addr,
+    operator: Addr,
+    user: Addr,
+    canceler: Addr,
+    canceler2: Addr,
+}
+
+const REWARD: u128 = 1_000_000;
+const REVIEW_PERIOD: u64 = 3_600;
+
+fn setup() -> TestEnv {
+    let mut app = App::default();
+    let admin = Addr::unchecked("terra1admin");
+    let operator = Addr::unchecked("terra1operator");
+    let user = Addr::unchecked("terra1user");
+    let canceler = Addr::unchecked("terra1canceler");
+    let canceler2 = Addr::unchecked("terra1canceler2");
+
+    app.init_modules(|router, _, storage| {
+        router
+            .bank
+            .init_balance(storage, &admin, coins(10_000_000_000, "uluna"))
+            .unwrap();
+        router
+            .bank
+            .init_balance(storage, &user, coins(10_000_000_000, "uluna"))
+            .unwrap();
+    });
+
+    let code_id = app.store_code(contract_bridge());
+    let contract_addr = app
+        .instantiate_contract(
+            code_id,
+            admin.clone(),
+            &InstantiateMsg {
+                admin: admin.to_string(),
+                operators: vec![operator.to_string()],
+                min_signatures: 1,
+                min_bridge_amount: Uint128::from(1000u128),
+                max_bridge_amount: Uint128::from(1_000_000_000_000u128),
+                fee_bps: 30,
+                fee_collector: admin.to_string(),
+                this_chain_id: Binary::from(vec![0, 0, 0, 1]),
+            },
+            &[],
+            "cl8y-bridge",
+            Some(admin.to_string()),
+        )
+        .unwrap();
+
+    for c in [&canceler, &canceler2] {
+        app.execute_contract(
+            admin.clone(),
+            contract_addr.clone(),
+            &ExecuteMsg::AddCanceler {
+                address: c.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    app.execute_contract(
+        admin.clone(),
+        contract_addr.clone(),
+        &ExecuteMsg::RegisterChain {
+            identifier: "bsc_56".to_string(),
+            chain_id: Binary::from(vec![0, 0, 0, 2]),
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        admin.clone(),
+        contract_addr.clone(),
+        &ExecuteMsg::AddToken {
+            token: "uluna".to_string(),
+            is_native: true,
+            token_type: None,
+            terra_decimals: 6,
+            min_bridge_amount: None,
+            max_bridge_amount: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        admin.clone(),
+        contract_addr.clone(),
+        &ExecuteMsg::SetWithdrawDelay { delay_seconds: 60 },
+        &[],
+    )
+    .unwrap();
+
+    let src_token_bytes = bridge::hash::keccak256(b"uluna");
+    app.execute_contract(
+        admin.clone(),
+        contract_addr.clone(),
+        &ExecuteMsg::SetIncomingTokenMapping {
+            src_chain: Binary::from(vec![0, 0, 0, 2]),
+            src_token: Binary::from(src_token_bytes.to_vec()),
+            local_token: "uluna".to_string(),
+            src_decimals: 18,
+        },
+        &[],
+    )
+    .unwrap();
+
+    TestEnv {
+        app,
+        contract_addr,
+        admin,
+        operator,
+        user,
+        canceler,
+        canceler2,
+    }
+}
+
+fn enable_rewards(env: &mut TestEnv, pool: u128) {
+    env.app
+        .execute_contract(
+            env.admin.clone(),
+            env.contract_addr.clone(),
+            &ExecuteMsg::SetCancelerRewardConfig {
+                denom: "uluna".to_string(),
+                reward_amount: Uint128::from(REWARD),
+                review_period: REVIEW_PERIOD,
+            },
+            &[],
+        )
+        .unwrap();
+
+    if pool > 0 {
+        env.app
+            .execute_contract(
+                env.admin.clone(),
+                env.contract_addr.clone(),
+                &ExecuteMsg::FundCancelerRewardPool {},
+                &coins(pool, "uluna"),
+            )
+            .unwrap();
+    }
+}
+
+/// Submit and approve a withdrawal, returning its hash.
+fn approved_withdraw(env: &mut TestEnv, nonce: u64) -> Binary {
+    let mut src_account = [0u8; 32];
+    src_account[12..32].copy_from_slice(&[0xAB; 20]);
+
+    let res = env
+        .app
+        .execute_contract(
+            env.user.clone(),
+            env.contract_addr.clone(),
+            &ExecuteMsg::WithdrawSubmit {
+                src_chain: Binary::from(vec![0, 0, 0, 2]),
+                src_account: Binary::from(src_account.to_vec()),
+                token: "uluna".to_string(),
+                recipient: env.user.to_string(),
+                amount: Uint128::from(1_000_000_000_000_000_000u128),
+                nonce,
+            },
+            &[],
+        )
+        .unwrap();
+
+    let hex_hash = res
+        .events
+        .iter()
+        .flat_map(|e| &e.attributes)
+        .find(|a| a.key == "xchain_hash_id")
+        .map(|a| a.value.clone())
+        .expect("xchain_hash_id attribute not found");
+    let xchain_hash_id = Binary::from(hex::decode(&hex_hash[2..]).unwrap());
+
+    env.app
+        .execute_contract(
+            env.operator.clone(),
+            env.contract_addr.clone(),
+            &ExecuteMsg::WithdrawApprove {
+                xchain_hash_id: xchain_hash_id.clone(),
+            },
+            &[],
+        )
+        .unwrap();
+
+    xchain_hash_id
+}
+
+fn cancel(env: &mut TestEnv, canceler: &Addr, xchain_hash_id: &Binary) {
+    env.app
+        .execute_contract(
+            canceler.clone(),
+            env.contract_addr.clone(),
+            &ExecuteMsg::WithdrawCancel {
+                xchain_hash_id: xchain_hash_id.clone(),
+            },
+            &[],
+        )
+        .unwrap();
+}
+
+fn claim(env: &mut TestEnv, canceler: &Addr, xchain_hash_id: &Binary) -> AnyResult<AppResponse> {
+    env.app.execute_contract(
+        canceler.clone(),
+        env.contract_addr.clone(),
+        &ExecuteMsg::ClaimCancelerReward {
+            xchain_hash_id: xchain_hash_id.clone(),
+        },
+        &[],
+    )
+}
+
+fn query_cancelers(env: &TestEnv) -> CancelersResponse {
+    env.app
+        .wrap()
+        .query_wasm_smart(
+            &env.contract_addr,
+            &QueryMsg::Cancelers {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap()
+}
+
+fn balance(env: &TestEnv, addr: &Addr) -> u128 {
+    env.app
+        .wrap()
+        .query_balance(addr, "uluna")
+        .unwrap()
+        .amount
+        .u128()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[test]
+fn test_claim_after_review_period_pays_reward() {
+    let mut env = setup();
+    enable_rewards(&mut env, 5 * REWARD);
+    let xchain_hash_id = approved_withdraw(&mut env, 1);
+
+    let canceler = env.canceler.clone();
+    cancel(&mut env, &canceler, &xchain_hash_id);
+
+    env.app.update_block(|block| {
+        block.time = block.time.plus_seconds(REVIEW_PERIOD);
+    });
+
+    claim(&mut env, &canceler, &xchain_hash_id).unwrap();
+    assert_eq!(balance(&env, &canceler), REWARD);
+
+    let res = query_cancelers(&env);
+    assert_eq!(res.reward_pool, Uint128::from(4 * REWARD));
+    let entry = res.rewards.iter().find(|e| e.canceler == canceler).unwrap();
+    assert_eq!(entry.cancels, 1);
+    assert_eq!(entry.rewards_claimed, 1);
+    assert_eq!(entry.total_paid, Uint128::from(REWARD));
+
+    let record: Option<CancelRecordResponse> = env
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &env.contract_addr,
+            &QueryMsg::CancelRecord {
+                xchain_hash_id: xchain_hash_id.clone(),
+            },
+        )
+        .unwrap();
+    assert!(record.unwrap().claimed);
+
+    // Double claim rejected
+    let err = claim(&mut env, &canceler, &xchain_hash_id).unwrap_err();
+    assert!(err.root_cause().to_string().contains("already claimed"));
+}
+
+#[test]
+fn test_cancel_without_reward_pool_records_nothing() {
+    let mut env = setup();
+    let xchain_hash_id = approved_withdraw(&mut env, 1);
+
+    let res = env
+        .app
+        .execute_contract(
+            env.canceler.clone(),
+            env.contract_addr.clone(),
+            &ExecuteMsg::WithdrawCancel {
+                xchain_hash_id: xchain_hash_id.clone(),
+            },
+            &[],
+        )
+        .unwrap();
+    let recorded = res
+        .events
+        .iter()
+        .flat_map(|e| &e.attributes)
+        .find(|a| a.key == "reward_recorded")
+        .map(|a| a.value.clone());
+    assert_eq!(recorded.as_deref(), Some("false"));
+
+    let record: Option<CancelRecordResponse> = env
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &env.contract_addr,
+            &QueryMsg::CancelRecord { xchain_hash_id },
+        )
+        .unwrap();
+    assert!(record.is_none());
+    assert!(query_cancelers(&env).rewards.is_empty());
+}
+
+#[test]
+fn test_claim_during_review_period_rejected() {
+    let mut env = setup();
+    enable_rewards(&mut env, REWARD);
+    let xchain_hash_id = approved_withdraw(&mut env, 1);
+
+    let canceler = env.canceler.clone();
+    cancel(&mut env, &canceler, &xchain_hash_id);
+
+    env.app.update_block(|block| {
+        block.time = block.time.plus_seconds(REVIEW_PERIOD - 1);
+    });
+
+    let err = claim(&mut env, &canceler, &xchain_hash_id).unwrap_err();
+    assert!(err
+        .root_cause()
+        .to_string()
+        .contains("review period still active"));
+}
+
+#[test]
+fn test_uncancelled_withdraw_not_rewarded() {
+    let mut env = setup();
+    enable_rewards(&mut env, REWARD);
+    let xchain_hash_id = approved_withdraw(&mut env, 1);
+
+    let canceler = env.canceler.clone();
+    cancel(&mut env, &canceler, &xchain_hash_id);
+
+    env.app
+        .execute_contract(
+            env.operator.clone(),
+            env.contract_addr.clone(),
+            &ExecuteMsg::WithdrawUncancel {
+                xchain_hash_id: xchain_hash_id.clone(),
+            },
+            &[],
+        )
+        .unwrap();
+
+    env.app.update_block(|block| {
+        block.time = block.time.plus_seconds(REVIEW_PERIOD);
+    });
+
+    let err = claim(&mut env, &canceler, &xchain_hash_id).unwrap_err();
+    assert!(err
+        .root_cause()
+        .to_string()
+        .contains("No cancellation recorded"));
+
+    let res = query_cancelers(&env);
+    let entry = res.rewards.iter().find(|e| e.canceler == canceler).unwrap();
+    assert_eq!(entry.cancels, 1);
+    assert_eq!(entry.reverted, 1);
+    assert_eq!(entry.rewards_claimed, 0);
+}
+
+#[test]
+fn test_only_first_canceler_can_claim() {
+    let mut env = setup();
+    enable_rewards(&mut env, 2 * REWARD);
+    let xchain_hash_id = approved_withdraw(&mut env, 1);
+
+    let canceler = env.canceler.clone();
+    let canceler2 = env.canceler2.clone();
+    cancel(&mut env, &canceler, &xchain_hash_id);
+    cancel(&mut env, &canceler2, &xchain_hash_id);
+
+    env.app.update_block(|block| {
+        block.time = block.time.plus_seconds(REVIEW_PERIOD);
+    });
+
+    let err = claim(&mut env, &canceler2, &xchain_hash_id).unwrap_err();
+    assert!(err
+        .root_cause()
+        .to_string()
+        .contains("did not cancel this withdrawal"));
+
+    claim(&mut env, &canceler, &xchain_hash_id).unwrap();
+}
+
+#[test]
+fn test_claim_rejected_when_pool_insufficient() {
+    let mut env = setup();
+    enable_rewards(&mut env, REWARD - 1);
+    let xchain_hash_id = approved_withdraw(&mut env, 1);
+
+    let canceler = env.canceler.clone();
+    cancel(&mut env, &canceler, &xchain_hash_id);
+
+    env.app.update_block(|block| {
+        block.time = block.time.plus_seconds(REVIEW_PERIOD);
+    });
+
+    let err = claim(&mut env, &canceler, &xchain_hash_id).unwrap_err();
+    assert!(err
+        .root_cause()
+        .to_string()
+        .contains("Insufficient canceler reward pool"));
+
+    // Topping up the pool makes the reward claimable
+    env.app
+        .execute_contract(
+            env.user.clone(),
+            env.contract_addr.clone(),
+            &ExecuteMsg::FundCancelerRewardPool {},
+            &coins(1, "uluna"),
+        )
+        .unwrap();
+    claim(&mut env, &canceler, &xchain_hash_id).unwrap();
+    assert_eq!(query_cancelers(&env).reward_pool, Uint128::zero());
+}
+
+#[test]
+fn test_reward_config_admin_only_and_validated() {
+    let mut env = setup();
+
+    let err = env
+        .app
+        .execute_contract(
+            env.user.clone(),
+            env.contract_addr.clone(),
+            &ExecuteMsg::SetCancelerRewardConfig {
+                denom: "uluna".to_string(),
+                reward_amount: Uint128::from(REWARD),
+                review_period: REVIEW_PERIOD,
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert!(err.root_cause().to_string().contains("only admin"));
+
+    let err = env
+        .app
+        .execute_contract(
+            env.admin.clone(),
+            env.contract_addr.clone(),
+            &ExecuteMsg::SetCancelerRewardConfig {
+                denom: "uluna".to_string(),
+                reward_amount: Uint128::from(REWARD),
+                review_period: 0,
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert!(err
+        .root_cause()
+        .to_string()
+        .contains("Invalid canceler review period"));
+
+    // Funding before configuration is rejected
+    let err = env
+        .app
+        .execute_contract(
+            env.user.clone(),
+            env.contract_addr.clone(),
+            &ExecuteMsg::FundCancelerRewardPool {},
+            &coins(REWARD, "uluna"),
+        )
+        .unwrap_err();
+    assert!(err.root_cause().to_string().contains("not configured"));
+
+    enable_rewards(&mut env, REWARD);
+    let res = query_cancelers(&env);
+    let config = res.reward_config.unwrap();
+    assert_eq!(config.reward_amount, Uint128::from(REWARD));
+    assert_eq!(config.review_period, REVIEW_PERIOD);
+    assert_eq!(res.reward_pool, Uint128::from(REWARD));
+
+    // Denom cannot change while the pool holds funds
+    let err = env
+        .app
+        .execute_contract(
+            env.admin.clone(),
+            env.contract_addr.clone(),
+            &ExecuteMsg::SetCancelerRewardConfig {
+                denom: "uusd".to_string(),
+                reward_amount: Uint128::from(REWARD),
+                review_period: REVIEW_PERIOD,
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert!(err
+        .root_cause()
+        .to_string()
+        .contains("Cannot change reward denom"));
+}
+
+#[test]
+fn test_canceler_reward_stats_paginated() {
+    let mut env = setup();
+    enable_rewards(&mut env, 2 * REWARD);
+    let first = approved_withdraw(&mut env, 1);
+    let second = approved_withdraw(&mut env, 2);
+
+    let canceler = env.canceler.clone();
+    let canceler2 = env.canceler2.clone();
+    cancel(&mut env, &canceler, &first);
+    cancel(&mut env, &canceler2, &second);
+
+    let page = |env: &TestEnv, start_after: Option<String>| -> CancelersResponse {
+        env.app
+            .wrap()
+            .query_wasm_smart(
+                &env.contract_addr,
+                &QueryMsg::Cancelers {
+                    start_after,
+                    limit: Some(1),
+                },
+            )
+            .unwrap()
+    };
+
+    let res = page(&env, None);
+    assert_eq!(res.rewards.len(), 1);
+    let last = res.rewards[0].canceler.to_string();
+
+    let res = page(&env, Some(last.clone()));
+    assert_eq!(res.rewards.len(), 1);
+    assert_ne!(res.rewards[0].canceler.to_string(), last);
+
+    let res = page(&env, Some(res.rewards[0].canceler.to_string()));
+    assert!(res.rewards.is_empty());
+    assert_eq!(query_cancelers(&env).rewards.len(), 2);
+}
@@ -0,0 +1,541 @@
+//! Relayed Withdrawal Integration Tests.
+//!
+//! Tests the relay lifecycle on Terra:
+//! - Admin sets the relay fee cap (0 disables relaying, max 500 bps)
+//! - Depositor opts a deposit into relaying with RequestRelay
+//! - Operator submits with WithdrawSubmitRelayed, bounded by the cap
+//! - Execution pays the normalized fee to the relayer and the rest to the recipient
+
+use cosmwasm_std::{coins, Addr, Binary, Uint128};
+use cw_multi_test::error::AnyResult;
+use cw_multi_test::{App, AppResponse, ContractWrapper, Executor};
+
+use bridge::msg::{
+    ExecuteMsg, InstantiateMsg, QueryMsg, RelayFeeCapResponse, RelayRequestResponse,
+    WithdrawRelayResponse,
+};
+
+// ============================================================================
+// Test Setup
+// ============================================================================
+
+fn contract_bridge() -> Box<dyn cw_multi_test::Contract<cosmwasm_std::Empty>> {
+    let contract = ContractWrapper::new(
+        bridge::contract::execute,
+        bridge::contract::instantiate,
+        bridge::contract::query,
+    );
+    Box::new(contract)
+}
+
+struct TestEnv {
+    app: App,
+    contract_addr: Addr,
+    admin: Addr,
+    operator: Addr,
+    user: Addr,
+    recipient: Addr,
+}
+
+/// 1 LUNA in source (18 decimal) units
+const ONE_LUNA_SRC: u128 = 1_000_000_000_000_000_000;
+
+fn setup() -> TestEnv {
+    let mut app = App::default();
+    let admin = Addr::unchecked("terra1admin");
+    let operator = Addr::unchecked("terra1operator");
+    let user = Addr::unchecked("terra1user");
+    let recipient = Addr::unchecked("terra1recipient");
+
+    app.init_modules(|router, _, storage| {
+        router
+            .bank
+            .init_balance(storage, &user, coins(10_000_000_000, "uluna"))
+            .unwrap();
+    });
+
+    let code_id = app.store_code(contract_bridge());
+    let contract_addr = app
+        .instantiate_contract(
+            code_id,
+            admin.clone(),
+            &InstantiateMsg {
+                admin: admin.to_string(),
+                operators: vec![operator.to_string()],
+                min_signatures: 1,
+                min_bridge_amount: Uint128::from(1000u128),
+                max_bridge_amount: Uint128::from(1_000_000_000_000u128),
+                fee_bps: 30,
+                fee_collector: admin.to_string(),
+                this_chain_id: Binary::from(vec![0, 0, 0, 1]),
+            },
+            &[],
+            "cl8y-bridge",
+            Some(admin.to_string()),
+        )
+        .unwrap();
+
+    let msgs = vec![
+        ExecuteMsg::RegisterChain {
+            identifier: "bsc_56".to_string(),
+            chain_id: Binary::from(vec![0, 0, 0, 2]),
+        },
+        ExecuteMsg::AddToken {
+            token: "uluna".to_string(),
+            is_native: true,
+            token_type: None,
+            terra_decimals: 6,
+            min_bridge_amount: None,
+            max_bridge_amount: None,
+        },
+        ExecuteMsg::SetWithdrawDelay { delay_seconds: 60 },
+        ExecuteMsg::SetIncomingTokenMapping {
+            src_chain: Binary::from(vec![0, 0, 0, 2]),
+            src_token: Binary::from(bridge::hash::keccak256(b"uluna").to_vec()),
+            local_token: "uluna".to_string(),
+            src_decimals: 18,
+        },
+        ExecuteMsg::SetTokenDestination {
+            token: "uluna".to_string(),
+            dest_chain: Binary::from(vec![0, 0, 0, 2]),
+            dest_token: "000000000000000000000000a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"
+                .to_string(),
+            dest_decimals: 18,
+        },
+    ];
+    for msg in msgs {
+        app.execute_contract(admin.clone(), contract_addr.clone(), &msg, &[])
+            .unwrap();
+    }
+
+    TestEnv {
+        app,
+        contract_addr,
+        admin,
+        operator,
+        user,
+        recipient,
+    }
+}
+
+fn set_cap(env: &mut TestEnv, relay_fee_cap_bps: u64) -> AnyResult<AppResponse> {
+    env.app.execute_contract(
+        env.admin.clone(),
+        env.contract_addr.clone(),
+        &ExecuteMsg::SetRelayFeeCap { relay_fee_cap_bps },
+        &[],
+    )
+}
+
+fn hash_from(res: &AppResponse) -> Binary {
+    let hex_hash = res
+        .events
+        .iter()
+        .flat_map(|e| &e.attributes)
+        .find(|a| a.key == "xchain_hash_id")
+        .map(|a| a.value.clone())
+        .expect("xchain_hash_id attribute not found");
+    Binary::from(hex::decode(&hex_hash[2..]).unwrap())
+}
+
+/// Deposit uluna towards chain 2 (also provides unlock liquidity), returning the deposit hash.
+fn deposit(env: &mut TestEnv, amount: u128) -> Binary {
+    let res = env
+        .app
+        .execute_contract(
+            env.user.clone(),
+            env.contract_addr.clone(),
+            &ExecuteMsg::DepositNative {
+                dest_chain: Binary::from(vec![0, 0, 0, 2]),
+                dest_account: Binary::from([0xCD; 32].to_vec()),
+            },
+            &coins(amount, "uluna"),
+        )
+        .unwrap();
+    hash_from(&res)
+}
+
+fn submit_relayed(env: &mut TestEnv, nonce: u64, relay_fee: u128) -> AnyResult<AppResponse> {
+    let mut src_account = [0u8; 32];
+    src_account[12..32].copy_from_slice(&[0xAB; 20]);
+
+    env.app.execute_contract(
+        env.operator.clone(),
+        env.contract_addr.clone(),
+        &ExecuteMsg::WithdrawSubmitRelayed {
+            src_chain: Binary::from(vec![0, 0, 0, 2]),
+            src_account: Binary::from(src_account.to_vec()),
+            token: "uluna".to_string(),
+            recipient: env.recipient.to_string(),
+            amount: Uint128::from(ONE_LUNA_SRC),
+            nonce,
+            relay_fee: Uint128::from(relay_fee),
+        },
+        &[],
+    )
+}
+
+/// The recipient's own `WithdrawSubmit` of the transfer `submit_relayed` relays
+fn submit_as(env: &mut TestEnv, sender: &Addr, nonce: u64) -> AnyResult<AppResponse> {
+    let mut src_account = [0u8; 32];
+    src_account[12..32].copy_from_slice(&[0xAB; 20]);
+
+    env.app.execute_contract(
+        sender.clone(),
+        env.contract_addr.clone(),
+        &ExecuteMsg::WithdrawSubmit {
+            src_chain: Binary::from(vec![0, 0, 0, 2]),
+            src_account: Binary::from(src_account.to_vec()),
+            token: "uluna".to_string(),
+            recipient: env.recipient.to_string(),
+            amount: Uint128::from(ONE_LUNA_SRC),
+            nonce,
+        },
+        &[],
+    )
+}
+
+fn withdraw_relay(env: &TestEnv, xchain_hash_id: &Binary) -> Option<WithdrawRelayResponse> {
+    env.app
+        .wrap()
+        .query_wasm_smart(
+            &env.contract_addr,
+            &QueryMsg::WithdrawRelay {
+                xchain_hash_id: xchain_hash_id.clone(),
+            },
+        )
+        .unwrap()
+}
+
+fn approve_and_execute(env: &mut TestEnv, xchain_hash_id: &Binary) {
+    env.app
+        .execute_contract(
+            env.operator.clone(),
+            env.contract_addr.clone(),
+            &ExecuteMsg::WithdrawApprove {
+                xchain_hash_id: xchain_hash_id.clone(),
+            },
+            &[],
+        )
+        .unwrap();
+    env.app.update_block(|block| {
+        block.time = block.time.plus_seconds(61);
+    });
+    env.app
+        .execute_contract(
+            env.recipient.clone(),
+            env.contract_addr.clone(),
+            &ExecuteMsg::WithdrawExecuteUnlock {
+                xchain_hash_id: xchain_hash_id.clone(),
+            },
+            &[],
+        )
+        .unwrap();
+}
+
+fn balance(env: &TestEnv, addr: &Addr) -> u128 {
+    env.app
+        .wrap()
+        .query_balance(addr, "uluna")
+        .unwrap()
+        .amount
+        .u128()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[test]
+fn test_relayed_withdraw_pays_fee_to_relayer() {
+    let mut env = setup();
+    deposit(&mut env, 5_000_000);
+    set_cap(&mut env, 100).unwrap();
+
+    // 0.5% of 1 LUNA, in source (18 decimal) units
+    let relay_fee = ONE_LUNA_SRC / 200;
+    let res = submit_relayed(&mut env, 1, relay_fee).unwrap();
+    let xchain_hash_id = hash_from(&res);
+
+    let relay: Option<WithdrawRelayResponse> = env
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &env.contract_addr,
+            &QueryMsg::WithdrawRelay {
+                xchain_hash_id: xchain_hash_id.clone(),
+            },
+        )
+        .unwrap();
+    let relay = relay.unwrap();
+    assert_eq!(relay.relayer, env.operator);
+    assert_eq!(relay.fee, Uint128::from(relay_fee));
+
+    env.app
+        .execute_contract(
+            env.operator.clone(),
+            env.contract_addr.clone(),
+            &ExecuteMsg::WithdrawApprove {
+                xchain_hash_id: xchain_hash_id.clone(),
+            },
+            &[],
+        )
+        .unwrap();
+    env.app.update_block(|block| {
+        block.time = block.time.plus_seconds(61);
+    });
+    env.app
+        .execute_contract(
+            env.recipient.clone(),
+            env.contract_addr.clone(),
+            &ExecuteMsg::WithdrawExecuteUnlock { xchain_hash_id },
+            &[],
+        )
+        .unwrap();
+
+    // 1 LUNA = 1_000_000 uluna, of which 5_000 goes to the relayer
+    assert_eq!(balance(&env, &env.operator), 5_000);
+    assert_eq!(balance(&env, &env.recipient), 995_000);
+}
+
+#[test]
+fn test_relayed_submit_rejected_while_cap_is_zero() {
+    let mut env = setup();
+
+    let err = submit_relayed(&mut env, 1, 0).unwrap_err();
+    assert!(err
+        .root_cause()
+        .to_string()
+        .contains("Relaying is disabled"));
+
+    set_cap(&mut env, 100).unwrap();
+    set_cap(&mut env, 0).unwrap();
+    let err = submit_relayed(&mut env, 1, 1).unwrap_err();
+    assert!(err
+        .root_cause()
+        .to_string()
+        .contains("Relaying is disabled"));
+}
+
+#[test]
+fn test_relayed_submit_fee_above_cap_rejected() {
+    let mut env = setup();
+    set_cap(&mut env, 100).unwrap();
+
+    // Exactly 1% is allowed, one unit more is not
+    let err = submit_relayed(&mut env, 1, ONE_LUNA_SRC / 100 + 1).unwrap_err();
+    assert!(err.root_cause().to_string().contains("exceeds the cap"));
+    submit_relayed(&mut env, 1, ONE_LUNA_SRC / 100).unwrap();
+}
+
+#[test]
+fn test_relayed_submit_operator_only() {
+    let mut env = setup();
+    set_cap(&mut env, 100).unwrap();
+
+    let err = env
+        .app
+        .execute_contract(
+            env.user.clone(),
+            env.contract_addr.clone(),
+            &ExecuteMsg::WithdrawSubmitRelayed {
+                src_chain: Binary::from(vec![0, 0, 0, 2]),
+                src_account: Binary::from([0xAB; 32].to_vec()),
+                token: "uluna".to_string(),
+                recipient: env.user.to_string(),
+                amount: Uint128::from(ONE_LUNA_SRC),
+                nonce: 1,
+                relay_fee: Uint128::zero(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert!(err.root_cause().to_string().contains("operator"));
+}
+
+#[test]
+fn test_set_relay_fee_cap_bounds() {
+    let mut env = setup();
+
+    let err = set_cap(&mut env, 501).unwrap_err();
+    assert!(err.root_cause().to_string().contains("exceeds maximum"));
+
+    let err = env
+        .app
+        .execute_contract(
+            env.user.clone(),
+            env.contract_addr.clone(),
+            &ExecuteMsg::SetRelayFeeCap {
+                relay_fee_cap_bps: 100,
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert!(err.root_cause().to_string().contains("Unauthorized"));
+
+    set_cap(&mut env, 500).unwrap();
+    let cap: RelayFeeCapResponse = env
+        .app
+        .wrap()
+        .query_wasm_smart(&env.contract_addr, &QueryMsg::RelayFeeCap {})
+        .unwrap();
+    assert_eq!(cap.relay_fee_cap_bps, 500);
+}
+
+#[test]
+fn test_request_relay_by_depositor_once() {
+    let mut env = setup();
+    let xchain_hash_id = deposit(&mut env, 1_000_000);
+
+    let request = |env: &mut TestEnv, sender: Addr, max_relay_fee: u128| {
+        env.app.execute_contract(
+            sender,
+            env.contract_addr.clone(),
+            &ExecuteMsg::RequestRelay {
+                xchain_hash_id: xchain_hash_id.clone(),
+                max_relay_fee: Uint128::from(max_relay_fee),
+            },
+            &[],
+        )
+    };
+
+    // Only the depositor can opt in
+    let admin = env.admin.clone();
+    let err = request(&mut env, admin, 1_000).unwrap_err();
+    assert!(err
+        .root_cause()
+        .to_string()
+        .contains("did not make this deposit"));
+
+    // Fee must be non-zero and below the deposit amount
+    let user = env.user.clone();
+    assert!(request(&mut env, user.clone(), 0).is_err());
+    assert!(request(&mut env, user.clone(), u128::MAX).is_err());
+
+    let res = request(&mut env, user.clone(), 1_000).unwrap();
+    assert!(res
+        .events
+        .iter()
+        .flat_map(|e| &e.attributes)
+        .any(|a| a.key == "action" && a.value == "request_relay"));
+
+    let err = request(&mut env, user, 2_000).unwrap_err();
+    assert!(err.root_cause().to_string().contains("already requested"));
+
+    let stored: Option<RelayRequestResponse> = env
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &env.contract_addr,
+            &QueryMsg::RelayRequest { xchain_hash_id },
+        )
+        .unwrap();
+    assert_eq!(stored.unwrap().max_relay_fee, Uint128::from(1_000u128));
+}
+
+#[test]
+fn test_recipient_submit_drops_front_run_relay() {
+    let mut env = setup();
+    deposit(&mut env, 5_000_000);
+    set_cap(&mut env, 100).unwrap();
+
+    // The operator front-runs the recipient with a fee-bearing relayed submit
+    let res = submit_relayed(&mut env, 1, ONE_LUNA_SRC / 100).unwrap();
+    let xchain_hash_id = hash_from(&res);
+
+    let recipient = env.recipient.clone();
+    let res = submit_as(&mut env, &recipient, 1).unwrap();
+    assert!(res.events.iter().any(|e| e
+        .attributes
+        .iter()
+        .any(|a| a.key == "action" && a.value == "drop_relay")));
+    assert!(withdraw_relay(&env, &xchain_hash_id).is_none());
+
+    approve_and_execute(&mut env, &xchain_hash_id);
+    assert_eq!(balance(&env, &env.operator), 0);
+    assert_eq!(balance(&env, &env.recipient), 1_000_000);
+}
+
+#[test]
+fn test_recipient_submit_drops_cancelled_relay() {
+    let mut env = setup();
+    deposit(&mut env, 5_000_000);
+    set_cap(&mut env, 100).unwrap();
+    let canceler = Addr::unchecked("terra1canceler");
+    env.app
+        .execute_contract(
+            env.admin.clone(),
+            env.contract_addr.clone(),
+            &ExecuteMsg::AddCanceler {
+                address: canceler.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+    let res = submit_relayed(&mut env, 1, ONE_LUNA_SRC / 100).unwrap();
+    let xchain_hash_id = hash_from(&res);
+
+    // Approved before the recipient could act, then cancelled for the unauthorized fee
+    env.app
+        .execute_contract(
+            env.operator.clone(),
+            env.contract_addr.clone(),
+            &ExecuteMsg::WithdrawApprove {
+                xchain_hash_id: xchain_hash_id.clone(),
+            },
+            &[],
+        )
+        .unwrap();
+    env.app
+        .execute_contract(
+            canceler,
+            env.contract_addr.clone(),
+            &ExecuteMsg::WithdrawCancel {
+                xchain_hash_id: xchain_hash_id.clone(),
+            },
+            &[],
+        )
+        .unwrap();
+
+    let recipient = env.recipient.clone();
+    submit_as(&mut env, &recipient, 1).unwrap();
+    assert!(withdraw_relay(&env, &xchain_hash_id).is_none());
+
+    env.app
+        .execute_contract(
+            env.operator.clone(),
+            env.contract_addr.clone(),
+            &ExecuteMsg::WithdrawUncancel {
+                xchain_hash_id: xchain_hash_id.clone(),
+            },
+            &[],
+        )
+        .unwrap();
+    env.app.update_block(|block| {
+        block.time = block.time.plus_seconds(61);
+    });
+    env.app
+        .execute_contract(
+            env.recipient.clone(),
+            env.contract_addr.clone(),
+            &ExecuteMsg::WithdrawExecuteUnlock { xchain_hash_id },
+            &[],
+        )
+        .unwrap();
+    assert_eq!(balance(&env, &env.operator), 0);
+    assert_eq!(balance(&env, &env.recipient), 1_000_000);
+}
+
+#[test]
+fn test_only_recipient_drops_relay() {
+    let mut env = setup();
+    set_cap(&mut env, 100).unwrap();
+    let res = submit_relayed(&mut env, 1, ONE_LUNA_SRC / 100).unwrap();
+    let xchain_hash_id = hash_from(&res);
+
+    let user = env.user.clone();
+    let err = submit_as(&mut env, &user, 1).unwrap_err();
+    assert!(err.root_cause().to_string().contains("recipient"));
+    assert!(withdraw_relay(&env, &xchain_hash_id).is_some());
+}
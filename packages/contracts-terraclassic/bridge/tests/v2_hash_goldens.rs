@@ -1,4 +1,5 @@
-//! V2 digest goldens (same vectors as `HashLib.t.sol` / Solana `hash.rs` / multichain-rs).
+//! V2 digest goldens (same vectors as `HashLib.t.sol` / multichain-rs), hand-pinned next to
+//! `conformance_corpus.rs` so the crate does not rely only on the generated corpus.
 //! Standalone CosmWasm crate check — no multichain-rs dependency on the Terra workspace.
 
 use bridge::hash::compute_xchain_hash_id;
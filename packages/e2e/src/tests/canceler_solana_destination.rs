@@ -135,6 +135,8 @@ fn build_withdraw_submit_ix(
     payer: Pubkey,
     args: WithdrawSubmitArgs,
 ) -> Instruction {
+    let nonce_bitmap_check =
+        multichain_rs::solana::nonce_bitmap_pda(&program_id, &args.src_chain, args.nonce);
     let mut data = anchor_discriminator("withdraw_submit").to_vec();
     data.extend(args.try_to_vec().expect("borsh"));
     Instruction {
@@ -147,6 +149,7 @@ fn build_withdraw_submit_ix(
             AccountMeta::new_readonly(executed_hash_check, false),
             AccountMeta::new(payer, true),
             AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(nonce_bitmap_check, false),
         ],
         data,
     }
@@ -158,7 +161,14 @@ fn build_withdraw_approve_ix(
     pending_withdraw: Pubkey,
     operator: Pubkey,
     transfer_hash: [u8; 32],
+    src_chain: [u8; 4],
+    nonce: u64,
 ) -> Instruction {
+    let (nonce_used, _) = Pubkey::find_program_address(
+        &[b"nonce_used", src_chain.as_ref(), &nonce.to_le_bytes()],
+        &program_id,
+    );
+    let nonce_bitmap = multichain_rs::solana::nonce_bitmap_pda(&program_id, &src_chain, nonce);
     let mut data = anchor_discriminator("withdraw_approve").to_vec();
     data.extend(
         WithdrawApproveArgs { transfer_hash }
@@ -170,7 +180,10 @@ fn build_withdraw_approve_ix(
         accounts: vec![
             AccountMeta::new_readonly(bridge, false),
             AccountMeta::new(pending_withdraw, false),
-            AccountMeta::new_readonly(operator, true),
+            AccountMeta::new_readonly(nonce_used, false),
+            AccountMeta::new(nonce_bitmap, false),
+            AccountMeta::new(operator, true),
+            AccountMeta::new_readonly(system_program::id(), false),
         ],
         data,
     }
@@ -374,6 +387,8 @@ pub(super) async fn run_solana_destination_fraud_test(config: &E2eConfig) -> Tes
                 pending_withdraw_pda,
                 operator.pubkey(),
                 transfer_hash,
+                evm_v2,
+                withdraw_nonce,
             );
             send_tx(&client, &operator, vec![approve_ix])?;
 
@@ -0,0 +1,314 @@
+//! Chaos Scenario Tests
+//!
+//! Runs the operator and canceler behind [`ChaosProxy`] instances (latency, 429s, truncated
+//! JSON, stale heads, `eth_getLogs` range limits, dropped transactions) and drives real
+//! Terra → EVM transfers through them. Approvals land on EVM, so every scenario checks, on
+//! the un-proxied node:
+//!
+//! - **no lost transfer**: every submitted withdrawal gets approved
+//! - **no double approval**: each xchain hash ID has exactly one `WithdrawApprove` event
+//! - **no false cancel**: the canceler, reading through the same faults, cancels none of them
+//!
+//! Scenario files live in `packages/e2e/chaos/`. The services are restarted on the direct
+//! RPC/LCD URLs when a scenario finishes, so later tests are unaffected.
+
+use crate::services::{find_project_root, ServiceManager};
+use crate::terra::TerraClient;
+use crate::transfer_helpers::poll_for_approval;
+use crate::{E2eConfig, TestResult};
+use alloy::primitives::{keccak256, Address, B256};
+use eyre::{eyre, Result};
+use multichain_rs::testing::chaos::{ChaosProxy, ChaosScenario, ChaosStats};
+use std::time::{Duration, Instant};
+use tracing::{info, warn};
+use url::Url;
+
+use super::operator_helpers::{encode_terra_address, submit_withdraw_on_evm};
+
+/// Transfers pushed through each scenario
+const CHAOS_TRANSFERS: u32 = 3;
+/// Amount per Terra deposit (uluna)
+const CHAOS_TRANSFER_AMOUNT: u128 = 1_000_000;
+/// Approval deadline per transfer; generous because the operator backs off under faults
+const CHAOS_APPROVAL_TIMEOUT: Duration = Duration::from_secs(180);
+/// Time the canceler gets to (wrongly) react after the last approval
+const CHAOS_CANCELER_GRACE: Duration = Duration::from_secs(20);
+
+/// EVM reads degraded: slow and stale heads, `eth_getLogs` range limits and 429s,
+/// truncated `eth_call` bodies, intermittent 503s
+pub async fn test_chaos_evm_flaky_reads(config: &E2eConfig) -> TestResult {
+    run_scenario(
+        config,
+        "chaos_evm_flaky_reads",
+        Some("evm-flaky-reads"),
+        None,
+    )
+    .await
+}
+
+/// Approval transactions dropped or rate-limited on submission, flaky receipts:
+/// the operator must re-submit without approving twice
+pub async fn test_chaos_evm_dropped_transactions(config: &E2eConfig) -> TestResult {
+    run_scenario(
+        config,
+        "chaos_evm_dropped_transactions",
+        Some("evm-dropped-transactions"),
+        None,
+    )
+    .await
+}
+
+/// Terra LCD degraded while the operator verifies the Terra deposits: stale heights,
+/// rate-limited tx search, slow and truncated smart queries
+pub async fn test_chaos_terra_lcd_flaky(config: &E2eConfig) -> TestResult {
+    run_scenario(
+        config,
+        "chaos_terra_lcd_flaky",
+        None,
+        Some("terra-lcd-flaky"),
+    )
+    .await
+}
+
+async fn run_scenario(
+    config: &E2eConfig,
+    name: &str,
+    evm_scenario: Option<&str>,
+    terra_scenario: Option<&str>,
+) -> TestResult {
+    let start = Instant::now();
+
+    let terra_bridge = match &config.terra.bridge_address {
+        Some(addr) if !addr.is_empty() => addr.clone(),
+        _ => return TestResult::skip(name, "Terra bridge address not configured"),
+    };
+    let token = config.evm.contracts.test_token;
+    if token == Address::ZERO {
+        return TestResult::skip(name, "No test token address configured");
+    }
+
+    let project_root = find_project_root();
+    let mut manager = ServiceManager::new(&project_root);
+    if !manager.is_operator_running() {
+        return TestResult::skip(name, "Operator service is not running");
+    }
+    let canceler_was_running = manager.is_canceler_running();
+
+    let load = |file: &str| {
+        ChaosScenario::from_file(project_root.join(format!("packages/e2e/chaos/{file}.json")))
+    };
+    let mut proxied = config.clone();
+    let mut evm_proxy = None;
+    let mut terra_proxy = None;
+    if let Some(file) = evm_scenario {
+        let proxy = match start_proxy(config.evm.rpc_url.as_str(), load(file)).await {
+            Ok(p) => p,
+            Err(e) => return TestResult::fail(name, e.to_string(), start.elapsed()),
+        };
+        proxied.evm.rpc_url = proxy_url(&proxy);
+        evm_proxy = Some(proxy);
+    }
+    if let Some(file) = terra_scenario {
+        let proxy = match start_proxy(config.terra.lcd_url.as_str(), load(file)).await {
+            Ok(p) => p,
+            Err(e) => return TestResult::fail(name, e.to_string(), start.elapsed()),
+        };
+        proxied.terra.lcd_url = proxy_url(&proxy);
+        terra_proxy = Some(proxy);
+    }
+
+    if let Err(e) = restart_services(&mut manager, &proxied, canceler_was_running).await {
+        let _ = restart_services(&mut manager, config, canceler_was_running).await;
+        return TestResult::fail(
+            name,
+            format!("Failed to restart services behind the chaos proxy: {}", e),
+            start.elapsed(),
+        );
+    }
+
+    let outcome = drive_transfers(config, &terra_bridge, canceler_was_running).await;
+
+    for (label, proxy) in [("evm", &evm_proxy), ("terra", &terra_proxy)] {
+        if let Some(proxy) = proxy {
+            log_stats(name, label, &proxy.stats());
+        }
+    }
+    drop(evm_proxy);
+    drop(terra_proxy);
+    if let Err(e) = restart_services(&mut manager, config, canceler_was_running).await {
+        warn!(error = %e, "Failed to restart services on the direct endpoints after {}", name);
+    }
+
+    match outcome {
+        Ok(()) => TestResult::pass(name, start.elapsed()),
+        Err(e) => TestResult::fail(name, e.to_string(), start.elapsed()),
+    }
+}
+
+async fn start_proxy(upstream: &str, scenario: Result<ChaosScenario>) -> Result<ChaosProxy> {
+    let scenario = scenario?;
+    info!(scenario = %scenario.name, upstream = upstream, "Starting chaos proxy");
+    ChaosProxy::start(upstream, scenario).await
+}
+
+fn proxy_url(proxy: &ChaosProxy) -> Url {
+    Url::parse(proxy.url()).expect("chaos proxy URL is valid")
+}
+
+async fn restart_services(
+    manager: &mut ServiceManager,
+    config: &E2eConfig,
+    with_canceler: bool,
+) -> Result<()> {
+    manager.stop_operator().await?;
+    manager.stop_canceler().await?;
+    manager.start_operator(config).await?;
+    if with_canceler {
+        manager.start_canceler(config).await?;
+    }
+    Ok(())
+}
+
+/// Push `CHAOS_TRANSFERS` Terra → EVM transfers and check the approval invariants
+async fn drive_transfers(
+    config: &E2eConfig,
+    terra_bridge: &str,
+    check_cancels: bool,
+) -> Result<()> {
+    let terra_client = TerraClient::new(&config.terra);
+    let terra_src_chain: [u8; 4] = [0, 0, 0, 2];
+    let evm_dest_chain: [u8; 4] = [0, 0, 0, 1];
+    let src_account = encode_terra_address(&config.test_accounts.terra_address);
+    let mut dest_account = [0u8; 32];
+    dest_account[12..].copy_from_slice(config.test_accounts.evm_address.as_slice());
+
+    let mut nonces = Vec::new();
+    for i in 0..CHAOS_TRANSFERS {
+        let nonce = terra_client.get_terra_outgoing_nonce(terra_bridge).await?;
+        let tx_hash = terra_client
+            .deposit_native_tokens(
+                terra_bridge,
+                evm_dest_chain,
+                dest_account,
+                CHAOS_TRANSFER_AMOUNT,
+                "uluna",
+            )
+            .await?;
+        terra_client
+            .wait_for_tx(&tx_hash, Duration::from_secs(60))
+            .await?;
+        let net_amount = terra_client
+            .get_terra_deposit_amount_by_nonce(terra_bridge, nonce)
+            .await?
+            .ok_or_else(|| eyre!("Terra deposit nonce {} not found after confirmation", nonce))?;
+        submit_withdraw_on_evm(
+            config,
+            terra_src_chain,
+            src_account,
+            dest_account,
+            config.evm.contracts.test_token,
+            net_amount,
+            nonce,
+        )
+        .await?;
+        info!(
+            nonce = nonce,
+            transfer = i + 1,
+            total = CHAOS_TRANSFERS,
+            "Chaos transfer submitted"
+        );
+        nonces.push(nonce);
+    }
+
+    // No lost transfer
+    let mut hashes = Vec::new();
+    let mut lost = Vec::new();
+    for nonce in &nonces {
+        match poll_for_approval(config, *nonce, CHAOS_APPROVAL_TIMEOUT).await {
+            Ok(approval) => hashes.push((*nonce, approval.xchain_hash_id)),
+            Err(e) => {
+                warn!(nonce = nonce, error = %e, "Chaos transfer never approved");
+                lost.push(*nonce);
+            }
+        }
+    }
+    if !lost.is_empty() {
+        return Err(eyre!(
+            "Transfers lost under chaos (never approved): nonces {:?}",
+            lost
+        ));
+    }
+
+    // No double approval, no false cancel
+    if check_cancels {
+        tokio::time::sleep(CHAOS_CANCELER_GRACE).await;
+    }
+    let rpc = config.evm.rpc_url.as_str();
+    let bridge = config.evm.contracts.bridge;
+    for (nonce, hash) in &hashes {
+        let approvals = count_bridge_events(rpc, bridge, "WithdrawApprove(bytes32)", hash).await?;
+        if approvals != 1 {
+            return Err(eyre!(
+                "Nonce {} (0x{}) has {} WithdrawApprove events, expected exactly 1",
+                nonce,
+                hex::encode(hash),
+                approvals
+            ));
+        }
+        if check_cancels {
+            let cancels =
+                count_bridge_events(rpc, bridge, "WithdrawCancel(bytes32,address)", hash).await?;
+            if cancels != 0 {
+                return Err(eyre!(
+                    "Canceler cancelled legitimate nonce {} (0x{}) under chaos",
+                    nonce,
+                    hex::encode(hash)
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Number of bridge events `signature` whose first indexed topic is `xchain_hash_id`
+async fn count_bridge_events(
+    rpc_url: &str,
+    bridge_address: Address,
+    signature: &str,
+    xchain_hash_id: &B256,
+) -> Result<usize> {
+    let response = reqwest::Client::new()
+        .post(rpc_url)
+        .json(&serde_json::json!({
+            "jsonrpc": "2.0",
+            "method": "eth_getLogs",
+            "params": [{
+                "fromBlock": "0x0",
+                "toBlock": "latest",
+                "address": format!("{}", bridge_address),
+                "topics": [
+                    format!("0x{}", hex::encode(keccak256(signature.as_bytes()))),
+                    format!("0x{}", hex::encode(xchain_hash_id)),
+                ]
+            }],
+            "id": 1
+        }))
+        .send()
+        .await?;
+    let body: serde_json::Value = response.json().await?;
+    body["result"]
+        .as_array()
+        .map(|logs| logs.len())
+        .ok_or_else(|| eyre!("eth_getLogs failed: {}", body["error"]))
+}
+
+fn log_stats(name: &str, endpoint: &str, stats: &ChaosStats) {
+    info!(
+        test = name,
+        endpoint = endpoint,
+        requests = stats.requests,
+        injected = ?stats.injected,
+        dropped_transactions = stats.dropped_transactions.len(),
+        "Chaos proxy stats"
+    );
+}
@@ -14,6 +14,7 @@
 //! - **edge_cases**: Edge cases and observability tests (restart recovery, validation, metrics, double spend)
 //! - **watchtower**: Watchtower pattern tests (EVM time skip, delay mechanism, delay enforcement)
 //! - **cw20**: CW20 cross-chain transfer tests (deployment, balance, mint/burn, lock/unlock)
+//! - **chaos**: Operator/canceler behind a fault-injecting RPC proxy (no lost or double approvals)
 
 mod address_codec;
 mod canceler;
@@ -21,6 +22,7 @@ mod canceler_execution;
 mod canceler_helpers;
 mod canceler_solana_destination;
 mod chain_registry;
+mod chaos;
 mod configuration;
 mod connectivity;
 mod cw20;
@@ -48,6 +50,7 @@ pub use address_codec::*;
 pub use canceler::*;
 pub use canceler_execution::*;
 pub use chain_registry::*;
+pub use chaos::*;
 pub use configuration::*;
 pub use connectivity::*;
 pub use cw20::*;
@@ -276,6 +279,17 @@ pub async fn run_all_tests(config: &E2eConfig, skip_terra: bool) -> Vec<TestResu
         integration::test_full_transfer_cycle(config, token_address, default_transfer_amount).await,
     );
 
+    // ========================================
+    // Chaos Scenarios
+    // ========================================
+    // Operator and canceler are restarted behind a fault-injecting RPC proxy
+    // (scenario files in packages/e2e/chaos/) and must neither lose nor double-approve.
+    if !skip_terra {
+        results.push(chaos::test_chaos_evm_flaky_reads(config).await);
+        results.push(chaos::test_chaos_evm_dropped_transactions(config).await);
+        results.push(chaos::test_chaos_terra_lcd_flaky(config).await);
+    }
+
     results
 }
 
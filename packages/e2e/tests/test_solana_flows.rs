@@ -1,7 +1,7 @@
 //! Solana bridge **offline** checks (no RPC, no `#[ignore]`).
 //! **INV-H1** — see `docs/SOLANA_BRIDGE_INVARIANTS.md`.
 //!
-//! Full on-chain flows (deposit → withdraw_submit → withdraw_approve with `NonceUsed` → execute)
+//! Full on-chain flows (deposit → withdraw_submit → withdraw_approve into the `NonceBitmap` → execute)
 //! live in [`packages/contracts-solana/tests/deposit_withdraw.test.ts`](../../contracts-solana/tests/deposit_withdraw.test.ts)
 //! (`anchor test`). The Rust E2E binary tests live integration in
 //! [`packages/e2e/src/tests/canceler_solana_destination.rs`](../src/tests/canceler_solana_destination.rs) when services run.
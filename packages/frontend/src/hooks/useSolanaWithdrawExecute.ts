@@ -112,6 +112,7 @@ export function useSolanaWithdrawExecute() {
               programId,
               recipient,
               hashBytes,
+              srcChain4,
             ),
           ];
         } else {
@@ -8,6 +8,7 @@ import {
   looksLikeSolanaExpiredBlockhashError,
   looksLikeSolanaLocalnetRpc,
   parseTokenMappingLocalMint,
+  parseWithdrawSponsorshipRelayer,
   WSOL_MINT,
 } from './transaction'
 
@@ -89,4 +90,16 @@ describe('solana/transaction helpers', () => {
       ),
     ).toThrow(/withdraw_submit srcAccount must be 32 bytes/)
   })
+
+  it('parseWithdrawSponsorshipRelayer returns the operator only when a relay fee is owed', () => {
+    const operator = new PublicKey('11111111111111111111111111111112')
+    const data = new Uint8Array(89)
+    data.set(operator.toBytes(), 40)
+    expect(parseWithdrawSponsorshipRelayer(data)).toBeNull()
+    // relay_fee is a little-endian u128 after the bump byte
+    data[73] = 1
+    expect(parseWithdrawSponsorshipRelayer(data)?.equals(operator)).toBe(true)
+    // Truncated data has no relay_fee field
+    expect(parseWithdrawSponsorshipRelayer(data.subarray(0, 73))).toBeNull()
+  })
 })
@@ -26,6 +26,12 @@ const CHAIN_SEED = Buffer.from("chain");
 const TOKEN_MAPPING_SEED = Buffer.from("token");
 const WITHDRAW_SEED = Buffer.from("withdraw");
 const EXECUTED_SEED = Buffer.from("executed");
+const TOKEN_FEE_SEED = Buffer.from("token_fee");
+const CUSTOM_FEE_SEED = Buffer.from("custom_fee");
+const NONCE_BITMAP_SEED = Buffer.from("nonce_bitmap");
+const SPONSORSHIP_SEED = Buffer.from("sponsorship");
+/** Nonces per on-chain `NonceBitmap` account (`NonceBitmap::NONCES_PER_CHUNK`). */
+const NONCES_PER_BITMAP_CHUNK = 8192n;
 const W_RATE_LIM = Buffer.from("w_rate_lim");
 
 /** PendingWithdraw.token for native SOL mappings (32 zero bytes on-chain). */
@@ -56,7 +62,59 @@ export async function resolveSplTokenProgramForMint(
 }
 
 /**
- * `withdraw_execute` — recipient signs; closes `pending_withdraw` to recipient (rent to recipient).
+ * Per-source-chain and large-transfer delay PDAs passed to both execute instructions
+ * (`ChainWithdrawDelay` / `LargeTransferDelay`; uninitialized when unset).
+ */
+export function findWithdrawDelayPdas(
+  programId: PublicKey,
+  srcChain4: Uint8Array,
+  token: PublicKey,
+): [PublicKey, PublicKey] {
+  const srcChain = Buffer.from(srcChain4.subarray(0, 4));
+  const [chainDelayPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("chain_delay"), srcChain],
+    programId,
+  );
+  const [largeDelayPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("large_delay"), srcChain, token.toBuffer()],
+    programId,
+  );
+  return [chainDelayPda, largeDelayPda];
+}
+
+/**
+ * `WithdrawSponsorship` PDA passed to both execute instructions (uninitialized unless the
+ * operator submitted the withdraw and paid its rent).
+ */
+export function findWithdrawSponsorshipPda(
+  programId: PublicKey,
+  transferHash32: Uint8Array,
+): PublicKey {
+  const [sponsorshipPda] = PublicKey.findProgramAddressSync(
+    [SPONSORSHIP_SEED, Buffer.from(transferHash32)],
+    programId,
+  );
+  return sponsorshipPda;
+}
+
+/**
+ * Operator owed a relay fee by a relayed withdraw, from `WithdrawSponsorship` account data
+ * (`transfer_hash`, `operator`, `bump`, `relay_fee: u128`); `null` when no fee is owed.
+ */
+export function parseWithdrawSponsorshipRelayer(
+  data: Uint8Array,
+): PublicKey | null {
+  if (data.length < 89) return null;
+  const view = new DataView(data.buffer, data.byteOffset, data.byteLength);
+  const relayFee =
+    view.getBigUint64(73, true) | (view.getBigUint64(81, true) << 64n);
+  return relayFee > 0n ? new PublicKey(data.subarray(40, 72)) : null;
+}
+
+/**
+ * `withdraw_execute` — recipient signs; closes `pending_withdraw` to recipient (rent to recipient,
+ * unless the operator paid it). Pass `relayer` for a relayed withdraw so its fee goes to the
+ * relayer's token account.
  */
 export function buildWithdrawExecuteSplInstruction(
   programId: PublicKey,
@@ -66,6 +124,7 @@ export function buildWithdrawExecuteSplInstruction(
   tokenProgram: PublicKey,
   srcChain4: Uint8Array,
   mappingSrcToken32: Uint8Array,
+  relayer: PublicKey | null = null,
 ): TransactionInstruction {
   if (transferHash32.length !== 32) {
     throw new Error("transferHash32 must be 32 bytes");
@@ -95,6 +154,11 @@ export function buildWithdrawExecuteSplInstruction(
     programId,
   );
   const [wrPda] = findWithdrawRateLimitPda(programId, mint);
+  const [chainDelayPda, largeDelayPda] = findWithdrawDelayPdas(
+    programId,
+    srcChain4,
+    mint,
+  );
 
   const recipientAta = getAssociatedTokenAddressSync(
     mint,
@@ -124,6 +188,27 @@ export function buildWithdrawExecuteSplInstruction(
       { pubkey: recipient, isSigner: true, isWritable: true },
       { pubkey: tokenProgram, isSigner: false, isWritable: false },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: chainDelayPda, isSigner: false, isWritable: false },
+      { pubkey: largeDelayPda, isSigner: false, isWritable: false },
+      {
+        pubkey: findWithdrawSponsorshipPda(programId, transferHash32),
+        isSigner: false,
+        isWritable: true,
+      },
+      ...(relayer
+        ? [
+            {
+              pubkey: getAssociatedTokenAddressSync(
+                mint,
+                relayer,
+                false,
+                tokenProgram,
+              ),
+              isSigner: false,
+              isWritable: true,
+            },
+          ]
+        : []),
     ],
     data: disc,
   });
@@ -152,7 +237,15 @@ export async function buildSolanaSplWithdrawExecuteInstructions(
 
   const ixs: TransactionInstruction[] = [];
 
-  const recipientAtaInfo = await connection.getAccountInfo(recipientAta);
+  const [recipientAtaInfo, sponsorshipInfo] =
+    await connection.getMultipleAccountsInfo([
+      recipientAta,
+      findWithdrawSponsorshipPda(programId, transferHash32),
+    ]);
+  const relayer =
+    sponsorshipInfo && sponsorshipInfo.owner.equals(programId)
+      ? parseWithdrawSponsorshipRelayer(sponsorshipInfo.data)
+      : null;
   if (!recipientAtaInfo) {
     ixs.push(
       createAssociatedTokenAccountIdempotentInstructionWithDerivation(
@@ -174,6 +267,7 @@ export async function buildSolanaSplWithdrawExecuteInstructions(
       tokenProgram,
       srcChain4,
       mappingSrcToken32,
+      relayer,
     ),
   );
 
@@ -187,10 +281,14 @@ export function buildWithdrawExecuteNativeInstruction(
   programId: PublicKey,
   recipient: PublicKey,
   transferHash32: Uint8Array,
+  srcChain4: Uint8Array,
 ): TransactionInstruction {
   if (transferHash32.length !== 32) {
     throw new Error("transferHash32 must be 32 bytes");
   }
+  if (srcChain4.length < 4) {
+    throw new Error("srcChain4 must be at least 4 bytes");
+  }
   const bridgePda = findBridgeConfigPda(programId);
   const [pendingPda] = PublicKey.findProgramAddressSync(
     [WITHDRAW_SEED, Buffer.from(transferHash32)],
@@ -201,6 +299,11 @@ export function buildWithdrawExecuteNativeInstruction(
     programId,
   );
   const [wrPda] = findWithdrawRateLimitPda(programId, SOLANA_NATIVE_TOKEN_PUBKEY);
+  const [chainDelayPda, largeDelayPda] = findWithdrawDelayPdas(
+    programId,
+    srcChain4,
+    SOLANA_NATIVE_TOKEN_PUBKEY,
+  );
 
   const disc = anchorDiscriminator("withdraw_execute_native");
   return new TransactionInstruction({
@@ -212,6 +315,13 @@ export function buildWithdrawExecuteNativeInstruction(
       { pubkey: wrPda, isSigner: false, isWritable: true },
       { pubkey: recipient, isSigner: true, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: chainDelayPda, isSigner: false, isWritable: false },
+      { pubkey: largeDelayPda, isSigner: false, isWritable: false },
+      {
+        pubkey: findWithdrawSponsorshipPda(programId, transferHash32),
+        isSigner: false,
+        isWritable: true,
+      },
     ],
     data: disc,
   });
@@ -423,7 +533,8 @@ export async function fetchSplMintDecimals(
 
 /**
  * Build a deposit_native instruction for the Solana bridge program.
- * Accounts: bridge, deposit_record, dest_chain_entry, token_mapping, depositor, system_program.
+ * Accounts: bridge, deposit_record, dest_chain_entry, token_mapping, depositor, system_program,
+ * token_fee_override (optional fee tier accounts omitted → standard / per-token fee).
  */
 export async function buildDepositNativeInstruction(
   programId: PublicKey,
@@ -458,6 +569,16 @@ export async function buildDepositNativeInstruction(
     programId,
   );
 
+  const [tokenFeePda] = PublicKey.findProgramAddressSync(
+    [TOKEN_FEE_SEED, tokenMappingPda.toBuffer()],
+    programId,
+  );
+
+  const [customFeePda] = PublicKey.findProgramAddressSync(
+    [CUSTOM_FEE_SEED, depositor.toBuffer()],
+    programId,
+  );
+
   const discriminator = anchorDiscriminator("deposit_native");
 
   const data = Buffer.alloc(8 + 4 + 32 + 8);
@@ -475,6 +596,8 @@ export async function buildDepositNativeInstruction(
       { pubkey: tokenMappingPda, isSigner: false, isWritable: false },
       { pubkey: depositor, isSigner: true, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: tokenFeePda, isSigner: false, isWritable: false },
+      { pubkey: customFeePda, isSigner: false, isWritable: false },
     ],
     data,
   });
@@ -520,6 +643,16 @@ export function buildDepositSplInstruction(
     programId,
   );
 
+  const [tokenFeePda] = PublicKey.findProgramAddressSync(
+    [TOKEN_FEE_SEED, tokenMappingPda.toBuffer()],
+    programId,
+  );
+
+  const [customFeePda] = PublicKey.findProgramAddressSync(
+    [CUSTOM_FEE_SEED, depositor.toBuffer()],
+    programId,
+  );
+
   const discriminator = anchorDiscriminator("deposit_spl");
   const data = Buffer.alloc(8 + 4 + 32 + 8);
   discriminator.copy(data, 0);
@@ -540,6 +673,8 @@ export function buildDepositSplInstruction(
       { pubkey: depositor, isSigner: true, isWritable: true },
       { pubkey: tokenProgram, isSigner: false, isWritable: false },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: tokenFeePda, isSigner: false, isWritable: false },
+      { pubkey: customFeePda, isSigner: false, isWritable: false },
     ],
     data,
   });
@@ -970,6 +1105,13 @@ export function buildWithdrawSubmitInstruction(
     programId,
   );
 
+  const chunkSeed = Buffer.alloc(8);
+  chunkSeed.writeBigUInt64LE(nonce / NONCES_PER_BITMAP_CHUNK);
+  const [nonceBitmapCheck] = PublicKey.findProgramAddressSync(
+    [NONCE_BITMAP_SEED, Buffer.from(srcChain), chunkSeed],
+    programId,
+  );
+
   const discriminator = anchorDiscriminator("withdraw_submit");
 
   // WithdrawSubmitParams: src_chain(4) + src_account(32) + src_token(32) + dest_token(32)
@@ -1026,6 +1168,7 @@ export function buildWithdrawSubmitInstruction(
       { pubkey: executedHashCheck, isSigner: false, isWritable: false },
       { pubkey: payer, isSigner: true, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: nonceBitmapCheck, isSigner: false, isWritable: false },
     ],
     data,
   });
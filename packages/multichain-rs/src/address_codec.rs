@@ -10,14 +10,18 @@
 //! | Chain Type (4 bytes) | Raw Address (20 bytes) | Reserved (8 bytes) |
 //! ```
 //!
+//! 32-byte raw addresses (Solana pubkeys, Bitcoin P2WSH / P2TR witness programs)
+//! do not fit and are truncated to 28 bytes in `to_bytes32`; use `to_bytes` for a
+//! lossless encoding and `to_hash_bytes` for transfer hashes.
+//!
 //! ## Chain Type Codes
 //!
 //! - `0x00000001`: EVM (Ethereum, BSC, Polygon, etc.)
 //! - `0x00000002`: Cosmos/Terra (Terra Classic, Osmosis)
-//! - `0x00000003`: Solana (future)
-//! - `0x00000004`: Bitcoin (future)
+//! - `0x00000003`: Solana
+//! - `0x00000004`: Bitcoin (P2WPKH, P2WSH, P2TR)
 
-use bech32::{self, FromBase32, ToBase32, Variant};
+use bech32::{self, u5, FromBase32, ToBase32, Variant};
 use eyre::{eyre, Result};
 use std::fmt;
 
@@ -31,21 +35,84 @@ pub const CHAIN_TYPE_EVM: u32 = 1;
 /// Chain type for Cosmos/Terra chains
 pub const CHAIN_TYPE_COSMOS: u32 = 2;
 
-/// Chain type for Solana (future)
+/// Chain type for Solana
 pub const CHAIN_TYPE_SOLANA: u32 = 3;
 
-/// Chain type for Bitcoin (future)
+/// Chain type for Bitcoin
 pub const CHAIN_TYPE_BITCOIN: u32 = 4;
 
+/// Human-readable prefixes accepted for Bitcoin addresses (mainnet, testnet/signet, regtest)
+pub const BITCOIN_HRPS: [&str; 3] = ["bc", "tb", "bcrt"];
+
+// ============================================================================
+// Bitcoin Address Kinds
+// ============================================================================
+
+/// Bitcoin output types that can be named as a bridge recipient
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BitcoinAddressKind {
+    /// Pay-to-witness-pubkey-hash (witness v0, 20-byte program)
+    P2wpkh,
+    /// Pay-to-witness-script-hash (witness v0, 32-byte program)
+    P2wsh,
+    /// Pay-to-taproot (witness v1, 32-byte output key)
+    P2tr,
+}
+
+impl BitcoinAddressKind {
+    /// Segwit witness version
+    pub fn witness_version(&self) -> u8 {
+        match self {
+            Self::P2wpkh | Self::P2wsh => 0,
+            Self::P2tr => 1,
+        }
+    }
+
+    /// Witness program length in bytes
+    pub fn program_len(&self) -> usize {
+        match self {
+            Self::P2wpkh => 20,
+            Self::P2wsh | Self::P2tr => 32,
+        }
+    }
+
+    /// Resolve the kind from a witness version and program length
+    pub fn from_witness(version: u8, program_len: usize) -> Result<Self> {
+        match (version, program_len) {
+            (0, 20) => Ok(Self::P2wpkh),
+            (0, 32) => Ok(Self::P2wsh),
+            (1, 32) => Ok(Self::P2tr),
+            (v, len) => Err(eyre!(
+                "Unsupported Bitcoin witness program: version {}, {} bytes",
+                v,
+                len
+            )),
+        }
+    }
+
+    /// Checksum variant required by BIP-350 for this witness version
+    fn variant(&self) -> Variant {
+        if self.witness_version() == 0 {
+            Variant::Bech32
+        } else {
+            Variant::Bech32m
+        }
+    }
+}
+
 // ============================================================================
 // Universal Address Structure
 // ============================================================================
 
 /// Internal representation for variable-length raw addresses
+///
+/// Bitcoin P2WPKH is `Short`, P2WSH is `Full` and P2TR is `Taproot`, so the
+/// witness version survives alongside the program.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 enum RawAddress {
     Short([u8; 20]),
     Full([u8; 32]),
+    Taproot([u8; 32]),
 }
 
 /// Universal address that can represent addresses from any supported chain
@@ -128,14 +195,54 @@ impl UniversalAddress {
         Self::from_solana(&pubkey)
     }
 
+    /// Create a Bitcoin address from a witness program
+    pub fn from_bitcoin_program(kind: BitcoinAddressKind, program: &[u8]) -> Result<Self> {
+        if program.len() != kind.program_len() {
+            return Err(eyre!(
+                "Invalid {:?} program length: expected {} bytes, got {}",
+                kind,
+                kind.program_len(),
+                program.len()
+            ));
+        }
+        let raw = match kind {
+            BitcoinAddressKind::P2wpkh => {
+                let mut raw = [0u8; 20];
+                raw.copy_from_slice(program);
+                RawAddress::Short(raw)
+            }
+            BitcoinAddressKind::P2wsh => {
+                let mut raw = [0u8; 32];
+                raw.copy_from_slice(program);
+                RawAddress::Full(raw)
+            }
+            BitcoinAddressKind::P2tr => {
+                let mut raw = [0u8; 32];
+                raw.copy_from_slice(program);
+                RawAddress::Taproot(raw)
+            }
+        };
+        Ok(Self {
+            chain_type: CHAIN_TYPE_BITCOIN,
+            raw,
+            reserved: [0u8; 8],
+        })
+    }
+
+    /// Create a Bitcoin address from a segwit or taproot string (e.g., "bc1q...", "bc1p...")
+    pub fn from_bitcoin(addr: &str) -> Result<Self> {
+        let (kind, program, _hrp) = decode_bitcoin_address(addr)?;
+        Self::from_bitcoin_program(kind, &program)
+    }
+
     // ============================================================================
     // Serialization
     // ============================================================================
 
     /// Convert to 32-byte array
     ///
-    /// Short (EVM/Cosmos): | chain_type (4) | raw_address (20) | reserved (8) |
-    /// Full (Solana): | chain_type (4) | pubkey\[0..28\] (28) | — lossy, use to_bytes() for lossless
+    /// Short (EVM/Cosmos/P2WPKH): | chain_type (4) | raw_address (20) | reserved (8) |
+    /// Full (Solana/P2WSH/P2TR): | chain_type (4) | raw\[0..28\] (28) | — lossy, use to_bytes() for lossless
     pub fn to_bytes32(&self) -> [u8; 32] {
         let mut result = [0u8; 32];
         result[0..4].copy_from_slice(&self.chain_type.to_be_bytes());
@@ -144,7 +251,7 @@ impl UniversalAddress {
                 result[4..24].copy_from_slice(raw);
                 result[24..32].copy_from_slice(&self.reserved);
             }
-            RawAddress::Full(raw) => {
+            RawAddress::Full(raw) | RawAddress::Taproot(raw) => {
                 result[4..32].copy_from_slice(&raw[0..28]);
             }
         }
@@ -152,6 +259,10 @@ impl UniversalAddress {
     }
 
     /// Parse from 32-byte array
+    ///
+    /// Bitcoin always decodes as P2WPKH: a truncated 32-byte witness program
+    /// shows up as non-zero reserved bytes and is rejected by
+    /// [`Self::from_bytes32_strict`] and [`Self::to_bitcoin_string`].
     pub fn from_bytes32(bytes: &[u8; 32]) -> Result<Self> {
         let chain_type = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
         if chain_type == 0 {
@@ -218,7 +329,9 @@ impl UniversalAddress {
         }
         match &self.raw {
             RawAddress::Short(raw) => Ok(format!("0x{}", hex::encode(raw))),
-            RawAddress::Full(_) => Err(eyre!("EVM address must be 20 bytes")),
+            RawAddress::Full(_) | RawAddress::Taproot(_) => {
+                Err(eyre!("EVM address must be 20 bytes"))
+            }
         }
     }
 
@@ -234,7 +347,9 @@ impl UniversalAddress {
         }
         match &self.raw {
             RawAddress::Short(raw) => encode_bech32_address(raw, hrp),
-            RawAddress::Full(_) => Err(eyre!("Cosmos address must be 20 bytes")),
+            RawAddress::Full(_) | RawAddress::Taproot(_) => {
+                Err(eyre!("Cosmos address must be 20 bytes"))
+            }
         }
     }
 
@@ -243,6 +358,33 @@ impl UniversalAddress {
         self.to_cosmos_string("terra")
     }
 
+    /// Convert to a Bitcoin segwit (bech32) or taproot (bech32m) string with given prefix
+    ///
+    /// Returns error if chain type is not Bitcoin
+    pub fn to_bitcoin_string(&self, hrp: &str) -> Result<String> {
+        let kind = self
+            .bitcoin_kind()
+            .ok_or_else(|| eyre!("Expected Bitcoin chain type (4), got {}", self.chain_type))?;
+        if self.reserved != [0u8; 8] {
+            return Err(eyre!(
+                "Bitcoin P2WPKH address has non-zero reserved bytes (truncated witness program?)"
+            ));
+        }
+        encode_bitcoin_address(kind, self.raw_address_bytes(), hrp)
+    }
+
+    /// Bitcoin output type, or `None` if this is not a Bitcoin address
+    pub fn bitcoin_kind(&self) -> Option<BitcoinAddressKind> {
+        if !self.is_bitcoin() {
+            return None;
+        }
+        Some(match &self.raw {
+            RawAddress::Short(_) => BitcoinAddressKind::P2wpkh,
+            RawAddress::Full(_) => BitcoinAddressKind::P2wsh,
+            RawAddress::Taproot(_) => BitcoinAddressKind::P2tr,
+        })
+    }
+
     // ============================================================================
     // Validation
     // ============================================================================
@@ -262,6 +404,11 @@ impl UniversalAddress {
         self.chain_type == CHAIN_TYPE_SOLANA
     }
 
+    /// Check if this is a Bitcoin address
+    pub fn is_bitcoin(&self) -> bool {
+        self.chain_type == CHAIN_TYPE_BITCOIN
+    }
+
     /// Check if the chain type is valid (known)
     pub fn is_valid_chain_type(&self) -> bool {
         self.chain_type >= CHAIN_TYPE_EVM && self.chain_type <= CHAIN_TYPE_BITCOIN
@@ -271,25 +418,30 @@ impl UniversalAddress {
     // Raw Address Accessors
     // ============================================================================
 
-    /// Returns the raw address bytes (20 for EVM/Cosmos, 32 for Solana)
+    /// Returns the raw address bytes (20 for EVM/Cosmos/P2WPKH, 32 for Solana/P2WSH/P2TR)
     pub fn raw_address_bytes(&self) -> &[u8] {
         match &self.raw {
             RawAddress::Short(raw) => raw,
-            RawAddress::Full(raw) => raw,
+            RawAddress::Full(raw) | RawAddress::Taproot(raw) => raw,
         }
     }
 
-    /// Returns the 20-byte raw address, or error if the address is 32 bytes (Solana)
+    /// Returns the 20-byte raw address, or error if the address is 32 bytes (Solana, P2WSH, P2TR)
     pub fn raw_address_20(&self) -> Result<&[u8; 20]> {
         match &self.raw {
             RawAddress::Short(raw) => Ok(raw),
-            RawAddress::Full(_) => Err(eyre!("Address is 32 bytes (Solana), not 20")),
+            RawAddress::Full(_) | RawAddress::Taproot(_) => {
+                Err(eyre!("Address is 32 bytes (Solana), not 20"))
+            }
         }
     }
 
     /// Returns 32 bytes for hash computation.
-    /// EVM/Cosmos: 12 zero bytes followed by the 20-byte address (left-padded).
-    /// Solana: full 32-byte public key.
+    /// EVM/Cosmos/P2WPKH: 12 zero bytes followed by the 20-byte address (left-padded).
+    /// Solana/P2WSH/P2TR: full 32-byte public key or witness program.
+    ///
+    /// The Bitcoin witness version is not part of the hash bytes; a BTC route
+    /// must fix the output type of its recipients.
     pub fn to_hash_bytes(&self) -> [u8; 32] {
         match &self.raw {
             RawAddress::Short(raw) => {
@@ -297,19 +449,23 @@ impl UniversalAddress {
                 result[12..32].copy_from_slice(raw);
                 result
             }
-            RawAddress::Full(raw) => *raw,
+            RawAddress::Full(raw) | RawAddress::Taproot(raw) => *raw,
         }
     }
 
     /// Lossless serialization.
-    /// EVM/Cosmos: 32 bytes (same as to_bytes32).
+    /// EVM/Cosmos/P2WPKH: 32 bytes (same as to_bytes32).
     /// Solana: 36 bytes (4-byte chain_type + 32-byte pubkey).
+    /// P2WSH/P2TR: 37 bytes (4-byte chain_type + witness version + 32-byte program).
     pub fn to_bytes(&self) -> Vec<u8> {
         match &self.raw {
             RawAddress::Short(_) => self.to_bytes32().to_vec(),
-            RawAddress::Full(raw) => {
-                let mut result = Vec::with_capacity(36);
+            RawAddress::Full(raw) | RawAddress::Taproot(raw) => {
+                let mut result = Vec::with_capacity(37);
                 result.extend_from_slice(&self.chain_type.to_be_bytes());
+                if let Some(kind) = self.bitcoin_kind() {
+                    result.push(kind.witness_version());
+                }
                 result.extend_from_slice(raw);
                 result
             }
@@ -317,7 +473,7 @@ impl UniversalAddress {
     }
 
     /// Parse from lossless byte encoding (inverse of to_bytes).
-    /// Accepts 32 bytes (EVM/Cosmos) or 36 bytes (Solana).
+    /// Accepts 32 bytes (EVM/Cosmos/P2WPKH), 36 bytes (Solana) or 37 bytes (P2WSH/P2TR).
     pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
         match bytes.len() {
             32 => {
@@ -337,8 +493,19 @@ impl UniversalAddress {
                 pubkey.copy_from_slice(&bytes[4..36]);
                 Self::from_solana(&pubkey)
             }
+            37 => {
+                let chain_type = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+                if chain_type != CHAIN_TYPE_BITCOIN {
+                    return Err(eyre!(
+                        "37-byte encoding only valid for Bitcoin (chain_type=4), got {}",
+                        chain_type
+                    ));
+                }
+                let kind = BitcoinAddressKind::from_witness(bytes[4], 32)?;
+                Self::from_bitcoin_program(kind, &bytes[5..37])
+            }
             other => Err(eyre!(
-                "Invalid length: expected 32 or 36 bytes, got {}",
+                "Invalid length: expected 32, 36 or 37 bytes, got {}",
                 other
             )),
         }
@@ -354,7 +521,9 @@ impl UniversalAddress {
         }
         match &self.raw {
             RawAddress::Full(raw) => Ok(bs58::encode(raw).into_string()),
-            RawAddress::Short(_) => Err(eyre!("Solana address must be 32 bytes")),
+            RawAddress::Short(_) | RawAddress::Taproot(_) => {
+                Err(eyre!("Solana address must be 32 bytes"))
+            }
         }
     }
 }
@@ -370,7 +539,7 @@ impl fmt::Display for UniversalAddress {
                 RawAddress::Full(raw) => {
                     write!(f, "SOLANA:{}", bs58::encode(raw).into_string())
                 }
-                RawAddress::Short(raw) => write!(f, "SOLANA:{}", hex::encode(raw)),
+                _ => write!(f, "SOLANA:{}", hex::encode(self.raw_address_bytes())),
             },
             CHAIN_TYPE_BITCOIN => {
                 write!(f, "BITCOIN:{}", hex::encode(self.raw_address_bytes()))
@@ -461,6 +630,61 @@ pub fn encode_bech32_address(bytes: &[u8; 20], hrp: &str) -> Result<String> {
     Ok(encoded)
 }
 
+/// Decode a Bitcoin segwit or taproot address to its witness program
+///
+/// Returns (kind, program, hrp). Follows BIP-350: witness v0 must carry a
+/// bech32 checksum and v1 a bech32m checksum.
+pub fn decode_bitcoin_address(addr: &str) -> Result<(BitcoinAddressKind, Vec<u8>, String)> {
+    let (hrp, data, variant) =
+        bech32::decode(addr).map_err(|e| eyre!("Invalid bech32 address: {}", e))?;
+
+    if !BITCOIN_HRPS.contains(&hrp.as_str()) {
+        return Err(eyre!("Unknown Bitcoin address prefix: '{}'", hrp));
+    }
+
+    let (version, program) = data
+        .split_first()
+        .ok_or_else(|| eyre!("Missing witness version"))?;
+    let program =
+        Vec::<u8>::from_base32(program).map_err(|e| eyre!("Invalid base32 data: {}", e))?;
+
+    let kind = BitcoinAddressKind::from_witness(version.to_u8(), program.len())?;
+    if variant != kind.variant() {
+        return Err(eyre!(
+            "Witness v{} address must use {:?} checksum",
+            kind.witness_version(),
+            kind.variant()
+        ));
+    }
+
+    Ok((kind, program, hrp))
+}
+
+/// Encode a witness program as a Bitcoin segwit or taproot address with given prefix
+pub fn encode_bitcoin_address(
+    kind: BitcoinAddressKind,
+    program: &[u8],
+    hrp: &str,
+) -> Result<String> {
+    if !BITCOIN_HRPS.contains(&hrp) {
+        return Err(eyre!("Unknown Bitcoin address prefix: '{}'", hrp));
+    }
+    if program.len() != kind.program_len() {
+        return Err(eyre!(
+            "Invalid {:?} program length: expected {} bytes, got {}",
+            kind,
+            kind.program_len(),
+            program.len()
+        ));
+    }
+
+    let mut data = vec![u5::try_from_u8(kind.witness_version())
+        .map_err(|e| eyre!("Invalid witness version: {}", e))?];
+    data.extend(program.to_base32());
+
+    bech32::encode(hrp, data, kind.variant()).map_err(|e| eyre!("Failed to encode bech32: {}", e))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -936,4 +1160,140 @@ mod tests {
         let addr = UniversalAddress::from_solana(&REGRESSION_SOLANA_PUBKEY).unwrap();
         assert_eq!(hex::encode(addr.to_bytes32()), expected_hex);
     }
+
+    // ========================================================================
+    // Bitcoin (BIP-173 / BIP-350 vectors)
+    // ========================================================================
+
+    const BTC_P2WPKH: &str = "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4";
+    const BTC_P2WPKH_PROGRAM: &str = "751e76e8199196d454941c45d1b3a323f1433bd6";
+    const BTC_P2WSH: &str = "bc1qrp33g0q5c5txsp9arysrx4k6zdkfs4nce4xj0gdcccefvpysxf3qccfmv3";
+    const BTC_P2WSH_TESTNET: &str =
+        "tb1qrp33g0q5c5txsp9arysrx4k6zdkfs4nce4xj0gdcccefvpysxf3q0sl5k7";
+    const BTC_P2WSH_PROGRAM: &str =
+        "1863143c14c5166804bd19203356da136c985678cd4d27a1b8c6329604903262";
+    const BTC_P2TR: &str = "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0";
+    const BTC_P2TR_PROGRAM: &str =
+        "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+
+    #[test]
+    fn test_bitcoin_p2wpkh_encode_decode() {
+        let addr = UniversalAddress::from_bitcoin(BTC_P2WPKH).unwrap();
+        assert!(addr.is_bitcoin());
+        assert_eq!(addr.bitcoin_kind(), Some(BitcoinAddressKind::P2wpkh));
+        assert_eq!(hex::encode(addr.raw_address_bytes()), BTC_P2WPKH_PROGRAM);
+        assert_eq!(addr.to_bitcoin_string("bc").unwrap(), BTC_P2WPKH);
+
+        let bytes32 = addr.to_bytes32();
+        assert_eq!(
+            hex::encode(bytes32),
+            format!("00000004{}0000000000000000", BTC_P2WPKH_PROGRAM)
+        );
+        let recovered = UniversalAddress::from_bytes32_strict(&bytes32).unwrap();
+        assert_eq!(recovered, addr);
+        assert_eq!(
+            hex::encode(addr.to_hash_bytes()),
+            format!("000000000000000000000000{}", BTC_P2WPKH_PROGRAM)
+        );
+    }
+
+    #[test]
+    fn test_bitcoin_p2wsh_encode_decode() {
+        let addr = UniversalAddress::from_bitcoin(BTC_P2WSH).unwrap();
+        assert_eq!(addr.bitcoin_kind(), Some(BitcoinAddressKind::P2wsh));
+        assert_eq!(hex::encode(addr.to_hash_bytes()), BTC_P2WSH_PROGRAM);
+        assert_eq!(addr.to_bitcoin_string("bc").unwrap(), BTC_P2WSH);
+        assert_eq!(addr.to_bitcoin_string("tb").unwrap(), BTC_P2WSH_TESTNET);
+        assert_eq!(
+            UniversalAddress::from_bitcoin(BTC_P2WSH_TESTNET).unwrap(),
+            addr
+        );
+        assert!(addr.raw_address_20().is_err());
+    }
+
+    #[test]
+    fn test_bitcoin_p2tr_encode_decode() {
+        let addr = UniversalAddress::from_bitcoin(BTC_P2TR).unwrap();
+        assert_eq!(addr.bitcoin_kind(), Some(BitcoinAddressKind::P2tr));
+        assert_eq!(hex::encode(addr.to_hash_bytes()), BTC_P2TR_PROGRAM);
+        assert_eq!(addr.to_bitcoin_string("bc").unwrap(), BTC_P2TR);
+    }
+
+    #[test]
+    fn test_bitcoin_to_bytes_roundtrip_keeps_witness_version() {
+        for s in [BTC_P2WPKH, BTC_P2WSH, BTC_P2TR] {
+            let addr = UniversalAddress::from_bitcoin(s).unwrap();
+            let bytes = addr.to_bytes();
+            let recovered = UniversalAddress::from_bytes(&bytes).unwrap();
+            assert_eq!(recovered, addr);
+            assert_eq!(recovered.to_bitcoin_string("bc").unwrap(), s);
+        }
+
+        let p2tr = UniversalAddress::from_bitcoin(BTC_P2TR).unwrap().to_bytes();
+        assert_eq!(p2tr.len(), 37);
+        assert_eq!(&p2tr[0..5], &[0, 0, 0, 4, 1]);
+        let p2wsh = UniversalAddress::from_bitcoin(BTC_P2WSH)
+            .unwrap()
+            .to_bytes();
+        assert_eq!(&p2wsh[0..5], &[0, 0, 0, 4, 0]);
+    }
+
+    #[test]
+    fn test_bitcoin_32_byte_program_bytes32_is_lossy() {
+        let addr = UniversalAddress::from_bitcoin(BTC_P2TR).unwrap();
+        let bytes32 = addr.to_bytes32();
+        assert_eq!(&bytes32[0..4], &[0, 0, 0, 4]);
+        assert_eq!(hex::encode(&bytes32[4..]), &BTC_P2TR_PROGRAM[..56]);
+
+        // Decodes as a P2WPKH with garbage reserved bytes; never rendered as an address
+        let truncated = UniversalAddress::from_bytes32(&bytes32).unwrap();
+        assert!(truncated.to_bitcoin_string("bc").is_err());
+        assert!(UniversalAddress::from_bytes32_strict(&bytes32).is_err());
+    }
+
+    #[test]
+    fn test_bitcoin_rejects_wrong_checksum_variant() {
+        // BIP-350: v1 with bech32 checksum, v0 with bech32m checksum
+        assert!(UniversalAddress::from_bitcoin(
+            "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqh2y7hd"
+        )
+        .is_err());
+        assert!(
+            UniversalAddress::from_bitcoin("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kemeawh").is_err()
+        );
+    }
+
+    #[test]
+    fn test_bitcoin_rejects_invalid_addresses() {
+        // Bad checksum
+        assert!(
+            UniversalAddress::from_bitcoin("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t5").is_err()
+        );
+        // Not a Bitcoin prefix
+        assert!(
+            UniversalAddress::from_bitcoin("terra1x46rqay4d3cssq8gxxvqz8xt6nwlz4td20k38v").is_err()
+        );
+        // Unsupported witness version (v2)
+        assert!(UniversalAddress::from_bitcoin("bc1zw508d6qejxtdg4y5r3zarvaryvaxxpcs").is_err());
+        // Wrong program length for the kind
+        assert!(
+            UniversalAddress::from_bitcoin_program(BitcoinAddressKind::P2tr, &[0u8; 20]).is_err()
+        );
+        // Unknown prefix on encode
+        let addr = UniversalAddress::from_bitcoin(BTC_P2WPKH).unwrap();
+        assert!(addr.to_bitcoin_string("ltc").is_err());
+    }
+
+    #[test]
+    fn test_bitcoin_formatter_rejects_other_chains() {
+        let evm = UniversalAddress::from_evm("0x1234567890abcdef1234567890abcdef12345678").unwrap();
+        assert!(evm.to_bitcoin_string("bc").is_err());
+        assert_eq!(evm.bitcoin_kind(), None);
+
+        let btc = UniversalAddress::from_bitcoin(BTC_P2TR).unwrap();
+        assert!(btc.to_solana_string().is_err());
+        assert!(btc.to_evm_string().is_err());
+        assert!(btc.is_valid_chain_type());
+        assert_eq!(format!("{}", btc), format!("BITCOIN:{}", BTC_P2TR_PROGRAM));
+    }
 }
@@ -0,0 +1,1587 @@
+//! Cross-Chain Solvency Auditor
+//!
+//! Continuously measures the supply invariant from `docs/crosschain-parity.md` for every
+//! configured asset, and backs the `cl8y-audit` binary:
+//!
+//! 1. **Custody** — per leg, what the bridge holds: Terra `LockedBalance` / CW20 supply,
+//!    EVM `LockUnlock.getLockedBalance` / ERC20 `totalSupply`, Solana vault ATA balance /
+//!    mint supply
+//! 2. **Outstanding** — approved, uncancelled, unexecuted withdrawals per destination leg
+//! 3. **Flows** — per route, deposits on the source vs executions on the destination,
+//!    accumulated from events (EVM logs, Terra `deposit_by_nonce` / `pending_withdrawals`,
+//!    Solana program logs)
+//!
+//! From these, per asset:
+//!
+//! - `solvency_margin = (locked − outstanding on lock legs) − (supply + outstanding on mint legs)`:
+//!   negative means approved withdrawals would release more than the bridge holds
+//! - `drift = (locked − supply) − Σ routes (deposited − executed)`: anything the event
+//!   history does not explain (zero when every scan starts at the bridge deployment)
+//!
+//! Amounts are compared in the asset's canonical units: the largest `decimals` of its legs.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use alloy::primitives::{Address, U256};
+use alloy::providers::Provider;
+use alloy::rpc::types::Filter;
+use alloy::sol_types::SolEvent;
+use eyre::{eyre, Result, WrapErr};
+use prometheus::{Encoder, Gauge, GaugeVec, IntCounter, Opts, Registry, TextEncoder};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_transaction_status::option_serializer::OptionSerializer;
+use tracing::{debug, warn};
+
+pub use crate::chains::parse_chain_id;
+use crate::chains::{blocking, decode_bytes32, decode_chain_id, now_secs, ChainKind};
+use crate::evm::contracts::{Bridge, ERC20};
+use crate::evm::watcher::parse_deposit_log;
+use crate::evm::EvmQueryClient;
+use crate::hash::{address_to_bytes32, encode_terra_token_address};
+use crate::solana::{
+    associated_token_address, bridge_config_pda, get_signatures_for_program_range, get_transaction,
+    parse_anchor_events, parse_bridge_config, parse_mint_supply, parse_pending_withdraw,
+    parse_token_account_amount, pending_withdraw_pda, SolanaEvent,
+};
+use crate::terra::contracts::QueryMsg;
+use crate::terra::TerraQueryClient;
+use crate::types::ChainId;
+
+/// Default seconds between audit rounds
+pub const DEFAULT_AUDIT_INTERVAL_SECS: u64 = 60;
+
+/// Block range per `eth_getLogs` request while accumulating EVM flows
+pub const DEFAULT_AUDIT_LOG_CHUNK: u64 = 10_000;
+
+/// Terra `pending_withdrawals` page size (contract maximum)
+const TERRA_PAGE: u32 = 30;
+
+/// Solana accounts per `getMultipleAccounts` request
+const SOLANA_ACCOUNTS_PER_REQUEST: usize = 100;
+
+// ============================================================================
+// Configuration
+// ============================================================================
+
+/// How a leg holds the asset
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Custody {
+    /// Deposits lock into bridge custody; withdrawals unlock from it
+    Lock,
+    /// Deposits burn; withdrawals mint
+    Mint,
+}
+
+impl fmt::Display for Custody {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Custody::Lock => "lock",
+            Custody::Mint => "mint",
+        })
+    }
+}
+
+/// A bridge deployment the auditor reads
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum AuditEndpoint {
+    Evm {
+        rpc_url: String,
+        bridge: String,
+        /// `LockUnlock` contract; required when any leg on this chain uses lock custody
+        #[serde(default)]
+        lock_unlock: Option<String>,
+        /// First block scanned for flows (the bridge deployment block)
+        #[serde(default)]
+        from_block: u64,
+        /// Blocks behind head left unscanned
+        #[serde(default)]
+        confirmations: u64,
+    },
+    Terra {
+        lcd_url: String,
+        bridge: String,
+    },
+    Solana {
+        rpc_url: String,
+        program_id: String,
+    },
+}
+
+/// One chain's side of an asset
+#[derive(Debug, Clone, Deserialize)]
+pub struct LegConfig {
+    /// Bridge chain ID: 4-byte hex (`0x00000001`) or decimal
+    pub chain: String,
+    /// EVM token address, Terra denom / CW20 address, or Solana mint
+    pub token: String,
+    pub custody: Custody,
+    pub decimals: u8,
+}
+
+/// A bridged asset and its leg on every chain
+#[derive(Debug, Clone, Deserialize)]
+pub struct AssetConfig {
+    pub name: String,
+    pub legs: Vec<LegConfig>,
+    /// Drift (canonical units, decimal string) tolerated before it is reported
+    #[serde(default)]
+    pub tolerance: Option<String>,
+}
+
+/// `cl8y-audit` configuration file
+#[derive(Debug, Clone, Deserialize)]
+pub struct AuditConfig {
+    pub chains: Vec<AuditEndpoint>,
+    pub assets: Vec<AssetConfig>,
+}
+
+impl AuditConfig {
+    /// Parse a JSON configuration
+    pub fn from_json(json: &str) -> Result<Self> {
+        serde_json::from_str(json).wrap_err("invalid audit configuration")
+    }
+}
+
+// ============================================================================
+// Resolved model
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum LegToken {
+    Evm(Address),
+    Terra(String),
+    Solana(Pubkey),
+}
+
+impl fmt::Display for LegToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LegToken::Evm(a) => write!(f, "{}", a),
+            LegToken::Terra(t) => f.write_str(t),
+            LegToken::Solana(m) => write!(f, "{}", m),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Leg {
+    chain: ChainId,
+    token: LegToken,
+    /// Token as the hash encodes it when this leg is a deposit's destination
+    token_bytes32: [u8; 32],
+    custody: Custody,
+    decimals: u8,
+}
+
+#[derive(Debug, Clone)]
+struct Asset {
+    name: String,
+    legs: Vec<Leg>,
+    /// Canonical decimals: the largest of the legs'
+    decimals: u8,
+    tolerance: u128,
+}
+
+impl Asset {
+    fn leg(&self, chain: &ChainId) -> Option<&Leg> {
+        self.legs.iter().find(|l| l.chain == *chain)
+    }
+
+    /// Convert `amount` in `leg`'s units to canonical units
+    fn canonical(&self, leg: &Leg, amount: u128) -> u128 {
+        amount.saturating_mul(10u128.saturating_pow((self.decimals - leg.decimals) as u32))
+    }
+}
+
+/// A withdrawal seen submitted on Solana, kept until its execute event
+#[derive(Debug, Clone, Copy)]
+struct SolanaSubmitted {
+    src_chain: ChainId,
+    mint: Pubkey,
+}
+
+enum Backend {
+    Evm {
+        client: EvmQueryClient,
+        bridge: Address,
+        lock_unlock: Option<Address>,
+        next_block: u64,
+        confirmations: u64,
+    },
+    Terra {
+        client: TerraQueryClient,
+        next_nonce: u64,
+    },
+    Solana {
+        client: Arc<RpcClient>,
+        program_id: Pubkey,
+        newest: Option<Signature>,
+        submitted: HashMap<[u8; 32], SolanaSubmitted>,
+    },
+}
+
+struct AuditChain {
+    id: ChainId,
+    backend: Backend,
+    /// Deposits and executions that name no configured asset
+    unmatched: u64,
+    /// Solana executions whose submission predates the scan
+    unattributed: u64,
+}
+
+impl AuditChain {
+    fn kind(&self) -> ChainKind {
+        match self.backend {
+            Backend::Evm { .. } => ChainKind::Evm,
+            Backend::Terra { .. } => ChainKind::Terra,
+            Backend::Solana { .. } => ChainKind::Solana,
+        }
+    }
+}
+
+/// (asset index, source chain, destination chain)
+type RouteKey = (usize, [u8; 4], [u8; 4]);
+
+// ============================================================================
+// Report
+// ============================================================================
+
+/// One leg's custody at the time of the audit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LegReport {
+    pub chain: ChainId,
+    pub kind: ChainKind,
+    pub token: String,
+    pub custody: Custody,
+    pub decimals: u8,
+    /// Locked balance (lock custody) or total supply (mint custody), in leg units
+    pub balance: u128,
+    /// Approved, unexecuted withdrawals paying out of this leg, in leg units
+    pub outstanding: u128,
+}
+
+/// Flows on one route (canonical units)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RouteReport {
+    pub src_chain: ChainId,
+    pub dest_chain: ChainId,
+    /// Net deposits on the source chain
+    pub deposited: u128,
+    /// Executed withdrawals on the destination chain
+    pub executed: u128,
+    /// Approved withdrawals not yet executed on the destination chain
+    pub outstanding: u128,
+}
+
+impl RouteReport {
+    /// Deposits neither executed nor approved yet (submitted, unsubmitted or cancelled);
+    /// negative when the destination released more than the source received
+    pub fn unreleased(&self) -> i128 {
+        signed(
+            self.deposited,
+            self.executed.saturating_add(self.outstanding),
+        )
+    }
+}
+
+/// Invariant check for one asset (canonical units)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssetReport {
+    pub name: String,
+    pub decimals: u8,
+    pub legs: Vec<LegReport>,
+    pub routes: Vec<RouteReport>,
+    /// Σ locked balances
+    pub locked: u128,
+    /// Σ minted supplies
+    pub supply: u128,
+    /// Σ outstanding withdrawals on lock legs
+    pub outstanding_unlock: u128,
+    /// Σ outstanding withdrawals on mint legs
+    pub outstanding_mint: u128,
+    pub tolerance: u128,
+}
+
+impl AssetReport {
+    /// Custody left after every approved withdrawal executes, minus the supply it backs
+    pub fn solvency_margin(&self) -> i128 {
+        signed(
+            self.locked.saturating_sub(self.outstanding_unlock),
+            self.supply.saturating_add(self.outstanding_mint),
+        )
+    }
+
+    /// Deposited minus executed, over every route
+    pub fn in_flight(&self) -> i128 {
+        self.routes
+            .iter()
+            .map(|r| signed(r.deposited, r.executed))
+            .sum()
+    }
+
+    /// Custody surplus the event history does not explain
+    pub fn drift(&self) -> i128 {
+        signed(self.locked, self.supply) - self.in_flight()
+    }
+}
+
+/// Bridge-level figures for one chain
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainReport {
+    pub chain: ChainId,
+    pub kind: ChainKind,
+    /// Terra `stats`: outgoing and incoming transfer counts
+    pub transfers_out: Option<u64>,
+    pub transfers_in: Option<u64>,
+    pub unmatched: u64,
+    pub unattributed: u64,
+}
+
+/// Result of one audit round
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditReport {
+    /// Unix seconds
+    pub timestamp: u64,
+    pub assets: Vec<AssetReport>,
+    pub chains: Vec<ChainReport>,
+    /// Invariant violations and configuration mismatches
+    pub findings: Vec<String>,
+}
+
+impl AuditReport {
+    pub fn to_json(&self) -> Value {
+        json!({
+            "timestamp": self.timestamp,
+            "assets": self.assets.iter().map(|a| json!({
+                "name": a.name,
+                "decimals": a.decimals,
+                "locked": a.locked.to_string(),
+                "supply": a.supply.to_string(),
+                "outstanding_unlock": a.outstanding_unlock.to_string(),
+                "outstanding_mint": a.outstanding_mint.to_string(),
+                "in_flight": a.in_flight().to_string(),
+                "solvency_margin": a.solvency_margin().to_string(),
+                "drift": a.drift().to_string(),
+                "legs": a.legs.iter().map(|l| json!({
+                    "chain": l.chain.to_hex(),
+                    "kind": l.kind.to_string(),
+                    "token": l.token,
+                    "custody": l.custody.to_string(),
+                    "decimals": l.decimals,
+                    "balance": l.balance.to_string(),
+                    "outstanding": l.outstanding.to_string(),
+                })).collect::<Vec<_>>(),
+                "routes": a.routes.iter().map(|r| json!({
+                    "src_chain": r.src_chain.to_hex(),
+                    "dest_chain": r.dest_chain.to_hex(),
+                    "deposited": r.deposited.to_string(),
+                    "executed": r.executed.to_string(),
+                    "outstanding": r.outstanding.to_string(),
+                    "unreleased": r.unreleased().to_string(),
+                })).collect::<Vec<_>>(),
+            })).collect::<Vec<_>>(),
+            "chains": self.chains.iter().map(|c| json!({
+                "chain": c.chain.to_hex(),
+                "kind": c.kind.to_string(),
+                "transfers_out": c.transfers_out,
+                "transfers_in": c.transfers_in,
+                "unmatched": c.unmatched,
+                "unattributed": c.unattributed,
+            })).collect::<Vec<_>>(),
+            "findings": self.findings,
+        })
+    }
+}
+
+impl fmt::Display for AuditReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for a in &self.assets {
+            writeln!(
+                f,
+                "{:<12} locked {} supply {} in flight {} margin {} drift {}",
+                a.name,
+                a.locked,
+                a.supply,
+                a.in_flight(),
+                a.solvency_margin(),
+                a.drift()
+            )?;
+            for r in &a.routes {
+                writeln!(
+                    f,
+                    "  {} -> {}  deposited {} executed {} outstanding {} unreleased {}",
+                    r.src_chain.to_hex(),
+                    r.dest_chain.to_hex(),
+                    r.deposited,
+                    r.executed,
+                    r.outstanding,
+                    r.unreleased()
+                )?;
+            }
+        }
+        if self.findings.is_empty() {
+            writeln!(f, "No findings")
+        } else {
+            for finding in &self.findings {
+                writeln!(f, "FINDING {}", finding)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+fn signed(a: u128, b: u128) -> i128 {
+    let clamp = |v: u128| i128::try_from(v).unwrap_or(i128::MAX);
+    clamp(a) - clamp(b)
+}
+
+// ============================================================================
+// Auditor
+// ============================================================================
+
+/// Reads custody, outstanding withdrawals and flows across the configured bridges
+pub struct Auditor {
+    chains: Vec<AuditChain>,
+    assets: Vec<Asset>,
+    deposited: BTreeMap<RouteKey, u128>,
+    /// Executions per destination chain; Terra's is rebuilt every round
+    executed: HashMap<[u8; 4], BTreeMap<RouteKey, u128>>,
+    log_chunk: u64,
+}
+
+impl Auditor {
+    /// Connect to every chain, resolve the chain ID each bridge reports and the assets' legs
+    pub async fn connect(config: &AuditConfig) -> Result<Self> {
+        let mut chains = Vec::with_capacity(config.chains.len());
+        for endpoint in &config.chains {
+            chains.push(connect_chain(endpoint).await?);
+        }
+
+        let mut assets = Vec::with_capacity(config.assets.len());
+        for asset in &config.assets {
+            let mut legs = Vec::with_capacity(asset.legs.len());
+            for leg in &asset.legs {
+                let chain_id = parse_chain_id(&leg.chain)?;
+                let chain = chains.iter().find(|c| c.id == chain_id).ok_or_else(|| {
+                    eyre!(
+                        "asset {}: no configured bridge reports chain {}",
+                        asset.name,
+                        chain_id.to_hex()
+                    )
+                })?;
+                let (token, token_bytes32) = resolve_token(chain.kind(), &leg.token)
+                    .wrap_err_with(|| format!("asset {}", asset.name))?;
+                legs.push(Leg {
+                    chain: chain_id,
+                    token,
+                    token_bytes32,
+                    custody: leg.custody,
+                    decimals: leg.decimals,
+                });
+            }
+            if legs.is_empty() {
+                return Err(eyre!("asset {} has no legs", asset.name));
+            }
+            let tolerance = match &asset.tolerance {
+                Some(t) => t
+                    .parse()
+                    .wrap_err_with(|| format!("asset {}: invalid tolerance {}", asset.name, t))?,
+                None => 0,
+            };
+            assets.push(Asset {
+                name: asset.name.clone(),
+                decimals: legs.iter().map(|l| l.decimals).max().unwrap_or_default(),
+                legs,
+                tolerance,
+            });
+        }
+
+        Ok(Self {
+            chains,
+            assets,
+            deposited: BTreeMap::new(),
+            executed: HashMap::new(),
+            log_chunk: DEFAULT_AUDIT_LOG_CHUNK,
+        })
+    }
+
+    /// Block range per `eth_getLogs` request
+    pub fn with_log_chunk(mut self, chunk: u64) -> Self {
+        self.log_chunk = chunk.max(1);
+        self
+    }
+
+    /// Chain ID and family of every configured bridge
+    pub fn chains(&self) -> Vec<(ChainId, ChainKind)> {
+        self.chains.iter().map(|c| (c.id, c.kind())).collect()
+    }
+
+    /// Accumulate new flows, read custody and outstanding withdrawals, and check the invariants
+    pub async fn run_once(&mut self) -> Result<AuditReport> {
+        let mut findings = Vec::new();
+
+        for i in 0..self.chains.len() {
+            self.scan_flows(i)
+                .await
+                .wrap_err_with(|| format!("flows on {}", self.chains[i].id.to_hex()))?;
+        }
+
+        let mut outstanding: BTreeMap<RouteKey, u128> = BTreeMap::new();
+        let mut chain_reports = Vec::with_capacity(self.chains.len());
+        for chain in &self.chains {
+            self.read_outstanding(chain, &mut outstanding)
+                .await
+                .wrap_err_with(|| format!("outstanding withdrawals on {}", chain.id.to_hex()))?;
+            let mut report = ChainReport {
+                chain: chain.id,
+                kind: chain.kind(),
+                transfers_out: None,
+                transfers_in: None,
+                unmatched: chain.unmatched,
+                unattributed: chain.unattributed,
+            };
+            if let Backend::Terra { client, .. } = &chain.backend {
+                let stats = client.get_stats().await?;
+                report.transfers_out = Some(stats.total_outgoing_txs);
+                report.transfers_in = Some(stats.total_incoming_txs);
+            }
+            if chain.unattributed > 0 {
+                findings.push(format!(
+                    "chain {}: {} executions predate the scan and are not attributed to a route",
+                    chain.id.to_hex(),
+                    chain.unattributed
+                ));
+            }
+            chain_reports.push(report);
+        }
+
+        let mut assets = Vec::with_capacity(self.assets.len());
+        for (index, asset) in self.assets.iter().enumerate() {
+            let report = self
+                .asset_report(index, asset, &outstanding, &mut findings)
+                .await
+                .wrap_err_with(|| format!("asset {}", asset.name))?;
+            assets.push(report);
+        }
+
+        Ok(AuditReport {
+            timestamp: now_secs(),
+            assets,
+            chains: chain_reports,
+            findings,
+        })
+    }
+
+    async fn asset_report(
+        &self,
+        index: usize,
+        asset: &Asset,
+        outstanding: &BTreeMap<RouteKey, u128>,
+        findings: &mut Vec<String>,
+    ) -> Result<AssetReport> {
+        let mut routes: BTreeMap<([u8; 4], [u8; 4]), RouteReport> = BTreeMap::new();
+        for ((a, src, dest), amount) in &self.deposited {
+            if *a == index {
+                route_entry(&mut routes, *src, *dest).deposited += amount;
+            }
+        }
+        for ((a, src, dest), amount) in self.executed.values().flatten() {
+            if *a == index {
+                route_entry(&mut routes, *src, *dest).executed += amount;
+            }
+        }
+        for ((a, src, dest), amount) in outstanding {
+            if *a == index {
+                route_entry(&mut routes, *src, *dest).outstanding += amount;
+            }
+        }
+
+        let mut report = AssetReport {
+            name: asset.name.clone(),
+            decimals: asset.decimals,
+            legs: Vec::with_capacity(asset.legs.len()),
+            routes: routes.into_values().collect(),
+            locked: 0,
+            supply: 0,
+            outstanding_unlock: 0,
+            outstanding_mint: 0,
+            tolerance: asset.tolerance,
+        };
+
+        for leg in &asset.legs {
+            let chain = self
+                .chains
+                .iter()
+                .find(|c| c.id == leg.chain)
+                .ok_or_else(|| eyre!("no bridge for chain {}", leg.chain.to_hex()))?;
+            let balance = self.custody_balance(chain, leg, findings).await?;
+            let leg_outstanding: u128 = report
+                .routes
+                .iter()
+                .filter(|r| r.dest_chain == leg.chain)
+                .map(|r| r.outstanding)
+                .sum();
+            let canonical = asset.canonical(leg, balance);
+            match leg.custody {
+                Custody::Lock => {
+                    report.locked += canonical;
+                    report.outstanding_unlock += leg_outstanding;
+                }
+                Custody::Mint => {
+                    report.supply += canonical;
+                    report.outstanding_mint += leg_outstanding;
+                }
+            }
+            // Outstanding is kept canonical; the leg view is in leg units
+            let divisor = 10u128.pow((asset.decimals - leg.decimals) as u32);
+            report.legs.push(LegReport {
+                chain: leg.chain,
+                kind: chain.kind(),
+                token: leg.token.to_string(),
+                custody: leg.custody,
+                decimals: leg.decimals,
+                balance,
+                outstanding: leg_outstanding / divisor,
+            });
+        }
+
+        if report.solvency_margin() < 0 {
+            findings.push(format!(
+                "{}: undercollateralized by {} after outstanding withdrawals",
+                asset.name,
+                -report.solvency_margin()
+            ));
+        }
+        if report.drift().unsigned_abs() > asset.tolerance {
+            findings.push(format!(
+                "{}: custody drifts {} from the deposit/execution history",
+                asset.name,
+                report.drift()
+            ));
+        }
+        for r in &report.routes {
+            if r.unreleased() < 0 {
+                findings.push(format!(
+                    "{}: route {} -> {} released {} more than was deposited",
+                    asset.name,
+                    r.src_chain.to_hex(),
+                    r.dest_chain.to_hex(),
+                    -r.unreleased()
+                ));
+            }
+        }
+        Ok(report)
+    }
+
+    // ------------------------------------------------------------------------
+    // Custody
+    // ------------------------------------------------------------------------
+
+    async fn custody_balance(
+        &self,
+        chain: &AuditChain,
+        leg: &Leg,
+        findings: &mut Vec<String>,
+    ) -> Result<u128> {
+        match (&chain.backend, &leg.token) {
+            (
+                Backend::Evm {
+                    client,
+                    lock_unlock,
+                    ..
+                },
+                LegToken::Evm(token),
+            ) => match leg.custody {
+                Custody::Lock => {
+                    let lock_unlock = lock_unlock.ok_or_else(|| {
+                        eyre!(
+                            "chain {} has lock legs but no lock_unlock address",
+                            leg.chain.to_hex()
+                        )
+                    })?;
+                    u256_to_u128(client.get_locked_balance(lock_unlock, *token).await?)
+                }
+                Custody::Mint => {
+                    let supply = ERC20::new(*token, client.provider())
+                        .totalSupply()
+                        .call()
+                        .await
+                        .map_err(|e| eyre!("Failed to get total supply of {}: {}", token, e))?;
+                    u256_to_u128(supply._0)
+                }
+            },
+            (Backend::Terra { client, .. }, LegToken::Terra(token)) => {
+                let token_type = client.get_token_type(token).await?;
+                let expected = match leg.custody {
+                    Custody::Lock => "lock_unlock",
+                    Custody::Mint => "mint_burn",
+                };
+                if token_type != expected {
+                    findings.push(format!(
+                        "chain {}: {} is configured as {} but the bridge registers it as {}",
+                        leg.chain.to_hex(),
+                        token,
+                        leg.custody,
+                        token_type
+                    ));
+                }
+                match leg.custody {
+                    Custody::Lock => client.get_locked_balance(token).await,
+                    Custody::Mint => client.get_cw20_total_supply(token).await,
+                }
+            }
+            (
+                Backend::Solana {
+                    client, program_id, ..
+                },
+                LegToken::Solana(mint),
+            ) => {
+                let (mint, program_id, custody) = (*mint, *program_id, leg.custody);
+                blocking(client, move |c| {
+                    let mint_account = c.get_account(&mint)?;
+                    match custody {
+                        Custody::Mint => Ok(parse_mint_supply(&mint_account.data)? as u128),
+                        Custody::Lock => {
+                            let vault = associated_token_address(
+                                &bridge_config_pda(&program_id),
+                                &mint,
+                                &mint_account.owner,
+                            );
+                            match c.get_multiple_accounts(&[vault])?.remove(0) {
+                                Some(account) => {
+                                    Ok(parse_token_account_amount(&account.data)? as u128)
+                                }
+                                None => Ok(0),
+                            }
+                        }
+                    }
+                })
+                .await
+            }
+            _ => Err(eyre!(
+                "leg token {} does not match chain {}",
+                leg.token,
+                leg.chain.to_hex()
+            )),
+        }
+    }
+
+    // ------------------------------------------------------------------------
+    // Outstanding withdrawals
+    // ------------------------------------------------------------------------
+
+    async fn read_outstanding(
+        &self,
+        chain: &AuditChain,
+        out: &mut BTreeMap<RouteKey, u128>,
+    ) -> Result<()> {
+        let dest = *chain.id.as_bytes();
+        match &chain.backend {
+            Backend::Evm { client, bridge, .. } => {
+                let hashes = Bridge::new(*bridge, client.provider())
+                    .getPendingWithdrawHashes()
+                    .call()
+                    .await
+                    .map_err(|e| eyre!("Failed to get pending withdraw hashes: {}", e))?
+                    .hashes;
+                for hash in hashes {
+                    let w = client.get_pending_withdraw(hash.0).await?;
+                    if !w.approved || w.cancelled || w.executed {
+                        continue;
+                    }
+                    let amount = normalize_decimals(
+                        u256_to_u128(w.amount)?,
+                        w.src_decimals,
+                        w.dest_decimals,
+                    );
+                    let local = LegToken::Evm(w.token);
+                    self.credit_local(out, &chain.id, &local, w.src_chain, dest, amount);
+                }
+            }
+            Backend::Terra { client, .. } => {
+                for w in terra_withdrawals(client).await? {
+                    if !w.approved || w.cancelled || w.executed {
+                        continue;
+                    }
+                    let Some(src) = decode_chain_id(&w.src_chain) else {
+                        continue;
+                    };
+                    let amount =
+                        normalize_decimals(w.amount.parse()?, w.src_decimals, w.dest_decimals);
+                    let local = LegToken::Terra(w.token.clone());
+                    self.credit_local(out, &chain.id, &local, src, dest, amount);
+                }
+            }
+            Backend::Solana {
+                client,
+                program_id,
+                submitted,
+                ..
+            } => {
+                let hashes: Vec<[u8; 32]> = submitted.keys().copied().collect();
+                let program_id = *program_id;
+                for batch in hashes.chunks(SOLANA_ACCOUNTS_PER_REQUEST) {
+                    let addresses: Vec<Pubkey> = batch
+                        .iter()
+                        .map(|h| pending_withdraw_pda(&program_id, h))
+                        .collect();
+                    let accounts =
+                        blocking(client, move |c| Ok(c.get_multiple_accounts(&addresses)?)).await?;
+                    for account in accounts.into_iter().flatten() {
+                        let w = parse_pending_withdraw(&account.data)?;
+                        if !w.approved || w.cancelled || w.executed {
+                            continue;
+                        }
+                        let amount = normalize_decimals(w.amount, w.src_decimals, w.dest_decimals);
+                        let local = LegToken::Solana(Pubkey::new_from_array(w.token));
+                        let src = ChainId::from_bytes(w.src_chain);
+                        self.credit_local(out, &chain.id, &local, src, dest, amount);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // ------------------------------------------------------------------------
+    // Flows
+    // ------------------------------------------------------------------------
+
+    async fn scan_flows(&mut self, index: usize) -> Result<()> {
+        let chain_id = self.chains[index].id;
+        let this = *chain_id.as_bytes();
+        let log_chunk = self.log_chunk;
+        // Borrow the chain separately from the asset table and the flow maps
+        let (chains, assets) = (&mut self.chains, &self.assets);
+        let chain = &mut chains[index];
+        let deposited = &mut self.deposited;
+        let executed = self.executed.entry(this).or_default();
+
+        match &mut chain.backend {
+            Backend::Evm {
+                client,
+                bridge,
+                next_block,
+                confirmations,
+                ..
+            } => {
+                let head = client.get_block_number().await?;
+                let Some(safe) = head.checked_sub(*confirmations) else {
+                    return Ok(());
+                };
+                while *next_block <= safe {
+                    let to = (*next_block + log_chunk - 1).min(safe);
+                    let filter = Filter::new()
+                        .address(*bridge)
+                        .event_signature(vec![
+                            Bridge::Deposit::SIGNATURE_HASH,
+                            Bridge::WithdrawExecute::SIGNATURE_HASH,
+                            Bridge::RelayFeePaid::SIGNATURE_HASH,
+                        ])
+                        .from_block(*next_block)
+                        .to_block(to);
+                    let logs = client
+                        .provider()
+                        .get_logs(&filter)
+                        .await
+                        .wrap_err_with(|| format!("eth_getLogs {}..={}", next_block, to))?;
+                    for log in logs {
+                        if log.topic0() == Some(&Bridge::Deposit::SIGNATURE_HASH) {
+                            let Some(d) = parse_deposit_log(&log) else {
+                                warn!(chain = %chain_id.to_hex(), "Unparseable Deposit log");
+                                continue;
+                            };
+                            let local = LegToken::Evm(Address::from(d.token.0));
+                            match find_local(assets, &chain_id, &local) {
+                                Some((a, asset, leg)) => {
+                                    *deposited
+                                        .entry((a, this, *d.dest_chain.as_bytes()))
+                                        .or_default() += asset.canonical(leg, d.amount);
+                                }
+                                None => chain.unmatched += 1,
+                            }
+                            continue;
+                        }
+                        // WithdrawExecute reports the recipient's share; a relayed
+                        // withdrawal pays the rest as RelayFeePaid
+                        let (xchain_hash_id, amount) = if log.topic0()
+                            == Some(&Bridge::RelayFeePaid::SIGNATURE_HASH)
+                        {
+                            let Ok(event) = log.log_decode::<Bridge::RelayFeePaid>() else {
+                                warn!(chain = %chain_id.to_hex(), "Unparseable RelayFeePaid log");
+                                continue;
+                            };
+                            (event.inner.data.xchainHashId, event.inner.data.amount)
+                        } else {
+                            let Ok(event) = log.log_decode::<Bridge::WithdrawExecute>() else {
+                                warn!(chain = %chain_id.to_hex(), "Unparseable WithdrawExecute log");
+                                continue;
+                            };
+                            (event.inner.data.xchainHashId, event.inner.data.amount)
+                        };
+                        let w = client.get_pending_withdraw(xchain_hash_id.0).await?;
+                        let local = LegToken::Evm(w.token);
+                        match find_local(assets, &chain_id, &local) {
+                            Some((a, asset, leg)) => {
+                                *executed
+                                    .entry((a, *w.src_chain.as_bytes(), this))
+                                    .or_default() += asset.canonical(leg, u256_to_u128(amount)?);
+                            }
+                            None => chain.unmatched += 1,
+                        }
+                    }
+                    *next_block = to + 1;
+                }
+            }
+            Backend::Terra { client, next_nonce } => {
+                let stats = client.get_stats().await?;
+                while *next_nonce < stats.total_outgoing_txs {
+                    let nonce = *next_nonce;
+                    *next_nonce += 1;
+                    let Some(d) = client.get_deposit_by_nonce(nonce).await? else {
+                        warn!(chain = %chain_id.to_hex(), nonce, "Terra deposit missing");
+                        continue;
+                    };
+                    let (Some(dest), Some(dest_token)) = (
+                        decode_chain_id(&d.dest_chain),
+                        decode_bytes32(&d.dest_token_address),
+                    ) else {
+                        chain.unmatched += 1;
+                        continue;
+                    };
+                    match find_by_dest(assets, &chain_id, &dest, &dest_token) {
+                        Some((a, asset, leg)) => {
+                            *deposited.entry((a, this, *dest.as_bytes())).or_default() +=
+                                asset.canonical(leg, d.amount.parse()?);
+                        }
+                        None => chain.unmatched += 1,
+                    }
+                }
+
+                // Executed entries stay listed, so executions are recounted every round
+                executed.clear();
+                for w in terra_withdrawals(client).await? {
+                    if !w.executed {
+                        continue;
+                    }
+                    let Some(src) = decode_chain_id(&w.src_chain) else {
+                        continue;
+                    };
+                    let local = LegToken::Terra(w.token.clone());
+                    let paid =
+                        normalize_decimals(w.amount.parse()?, w.src_decimals, w.dest_decimals);
+                    if let Some((a, asset, leg)) = find_local(assets, &chain_id, &local) {
+                        *executed.entry((a, *src.as_bytes(), this)).or_default() +=
+                            asset.canonical(leg, paid);
+                    }
+                }
+            }
+            Backend::Solana {
+                client,
+                program_id,
+                newest,
+                submitted,
+            } => {
+                let program = *program_id;
+                let until = *newest;
+                // Walk back from the tip to the newest signature already processed
+                let mut signatures = Vec::new();
+                let mut before: Option<Signature> = None;
+                loop {
+                    let page = blocking(client, move |c| {
+                        Ok(get_signatures_for_program_range(
+                            c,
+                            &program,
+                            before.as_ref(),
+                            until.as_ref(),
+                            1000,
+                        )?)
+                    })
+                    .await?;
+                    let Some(oldest) = page.last() else {
+                        break;
+                    };
+                    before = Some(Signature::from_str(&oldest.signature)?);
+                    signatures.extend(page);
+                }
+                if let Some(first) = signatures.first() {
+                    *newest = Some(Signature::from_str(&first.signature)?);
+                }
+                debug!(chain = %chain_id.to_hex(), count = signatures.len(), "Scanning Solana signatures");
+
+                for info in signatures.iter().rev() {
+                    if info.err.is_some() {
+                        continue;
+                    }
+                    let signature = Signature::from_str(&info.signature)?;
+                    let tx = blocking(client, move |c| Ok(get_transaction(c, &signature)?)).await?;
+                    let logs = match tx.transaction.meta.as_ref().map(|m| &m.log_messages) {
+                        Some(OptionSerializer::Some(logs)) => logs.clone(),
+                        _ => continue,
+                    };
+                    for event in parse_anchor_events(&logs, &program) {
+                        match event {
+                            SolanaEvent::Deposit(d) => {
+                                let dest = ChainId::from_bytes(d.dest_chain);
+                                match find_by_dest(assets, &chain_id, &dest, &d.token) {
+                                    Some((a, asset, leg)) => {
+                                        *deposited.entry((a, this, d.dest_chain)).or_default() +=
+                                            asset.canonical(leg, d.amount);
+                                    }
+                                    None => chain.unmatched += 1,
+                                }
+                            }
+                            SolanaEvent::WithdrawSubmit(w) => {
+                                submitted.insert(
+                                    w.transfer_hash,
+                                    SolanaSubmitted {
+                                        src_chain: ChainId::from_bytes(w.src_chain),
+                                        mint: Pubkey::new_from_array(w.token),
+                                    },
+                                );
+                            }
+                            // Emitted before the execute event, which reports only the
+                            // recipient's share
+                            SolanaEvent::RelayFeePaid(f) => {
+                                // An unknown submit is counted once, at the execute event
+                                let Some(s) = submitted.get(&f.transfer_hash) else {
+                                    continue;
+                                };
+                                let local = LegToken::Solana(s.mint);
+                                match find_local(assets, &chain_id, &local) {
+                                    Some((a, asset, leg)) => {
+                                        *executed
+                                            .entry((a, *s.src_chain.as_bytes(), this))
+                                            .or_default() += asset.canonical(leg, f.amount);
+                                    }
+                                    None => chain.unmatched += 1,
+                                }
+                            }
+                            SolanaEvent::WithdrawExecute(e) => {
+                                let Some(s) = submitted.remove(&e.transfer_hash) else {
+                                    chain.unattributed += 1;
+                                    continue;
+                                };
+                                let local = LegToken::Solana(s.mint);
+                                match find_local(assets, &chain_id, &local) {
+                                    Some((a, asset, leg)) => {
+                                        *executed
+                                            .entry((a, *s.src_chain.as_bytes(), this))
+                                            .or_default() += asset.canonical(leg, e.amount);
+                                    }
+                                    None => chain.unmatched += 1,
+                                }
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Add an outstanding withdrawal of `amount` (units of the local token) on `chain`
+    fn credit_local(
+        &self,
+        out: &mut BTreeMap<RouteKey, u128>,
+        chain: &ChainId,
+        local: &LegToken,
+        src: ChainId,
+        dest: [u8; 4],
+        amount: u128,
+    ) {
+        if let Some((a, asset, leg)) = find_local(&self.assets, chain, local) {
+            *out.entry((a, *src.as_bytes(), dest)).or_default() += asset.canonical(leg, amount);
+        }
+    }
+}
+
+// ============================================================================
+// Metrics
+// ============================================================================
+
+/// Prometheus gauges for the latest audit round, in whole tokens
+pub struct AuditMetrics {
+    registry: Registry,
+    custody: GaugeVec,
+    outstanding: GaugeVec,
+    route_deposited: GaugeVec,
+    route_executed: GaugeVec,
+    route_outstanding: GaugeVec,
+    route_unreleased: GaugeVec,
+    solvency_margin: GaugeVec,
+    drift: GaugeVec,
+    transfers: GaugeVec,
+    findings: Gauge,
+    last_success: Gauge,
+    errors: IntCounter,
+}
+
+impl AuditMetrics {
+    pub fn new() -> Result<Self> {
+        let registry = Registry::new();
+        let gauge_vec = |name: &str, help: &str, labels: &[&str]| -> Result<GaugeVec> {
+            let g = GaugeVec::new(Opts::new(name, help), labels)?;
+            registry.register(Box::new(g.clone()))?;
+            Ok(g)
+        };
+        let route = ["asset", "src_chain", "dest_chain"];
+        let metrics = Self {
+            custody: gauge_vec(
+                "cl8y_audit_custody_balance",
+                "Locked balance (lock legs) or minted supply (mint legs)",
+                &["asset", "chain", "custody"],
+            )?,
+            outstanding: gauge_vec(
+                "cl8y_audit_outstanding_withdrawals",
+                "Approved, unexecuted withdrawals paying out of the leg",
+                &["asset", "chain"],
+            )?,
+            route_deposited: gauge_vec(
+                "cl8y_audit_route_deposited",
+                "Net deposits on the route's source chain",
+                &route,
+            )?,
+            route_executed: gauge_vec(
+                "cl8y_audit_route_executed",
+                "Executed withdrawals on the route's destination chain",
+                &route,
+            )?,
+            route_outstanding: gauge_vec(
+                "cl8y_audit_route_outstanding",
+                "Approved, unexecuted withdrawals on the route",
+                &route,
+            )?,
+            route_unreleased: gauge_vec(
+                "cl8y_audit_route_unreleased",
+                "Deposits neither executed nor approved (negative: over-released)",
+                &route,
+            )?,
+            solvency_margin: gauge_vec(
+                "cl8y_audit_solvency_margin",
+                "Custody left after outstanding withdrawals minus the supply it backs",
+                &["asset"],
+            )?,
+            drift: gauge_vec(
+                "cl8y_audit_drift",
+                "Custody surplus not explained by deposits minus executions",
+                &["asset"],
+            )?,
+            transfers: gauge_vec(
+                "cl8y_audit_bridge_transfers",
+                "Transfer counts reported by the bridge (Terra stats)",
+                &["chain", "direction"],
+            )?,
+            findings: Gauge::new("cl8y_audit_findings", "Findings in the latest round")?,
+            last_success: Gauge::new(
+                "cl8y_audit_last_success_timestamp_seconds",
+                "Unix time of the latest completed round",
+            )?,
+            errors: IntCounter::new("cl8y_audit_errors_total", "Audit rounds that failed")?,
+            registry,
+        };
+        metrics
+            .registry
+            .register(Box::new(metrics.findings.clone()))?;
+        metrics
+            .registry
+            .register(Box::new(metrics.last_success.clone()))?;
+        metrics
+            .registry
+            .register(Box::new(metrics.errors.clone()))?;
+        Ok(metrics)
+    }
+
+    /// Replace the gauges with `report`'s figures
+    pub fn observe(&self, report: &AuditReport) {
+        for g in [
+            &self.custody,
+            &self.outstanding,
+            &self.route_deposited,
+            &self.route_executed,
+            &self.route_outstanding,
+            &self.route_unreleased,
+            &self.solvency_margin,
+            &self.drift,
+            &self.transfers,
+        ] {
+            g.reset();
+        }
+        for a in &report.assets {
+            let tokens = |v: f64| v / 10f64.powi(a.decimals as i32);
+            for l in &a.legs {
+                let chain = l.chain.to_hex();
+                let custody = l.custody.to_string();
+                let leg_tokens = |v: u128| v as f64 / 10f64.powi(l.decimals as i32);
+                self.custody
+                    .with_label_values(&[&a.name, &chain, &custody])
+                    .set(leg_tokens(l.balance));
+                self.outstanding
+                    .with_label_values(&[&a.name, &chain])
+                    .set(leg_tokens(l.outstanding));
+            }
+            for r in &a.routes {
+                let labels = [
+                    a.name.as_str(),
+                    &r.src_chain.to_hex(),
+                    &r.dest_chain.to_hex(),
+                ];
+                self.route_deposited
+                    .with_label_values(&labels)
+                    .set(tokens(r.deposited as f64));
+                self.route_executed
+                    .with_label_values(&labels)
+                    .set(tokens(r.executed as f64));
+                self.route_outstanding
+                    .with_label_values(&labels)
+                    .set(tokens(r.outstanding as f64));
+                self.route_unreleased
+                    .with_label_values(&labels)
+                    .set(tokens(r.unreleased() as f64));
+            }
+            self.solvency_margin
+                .with_label_values(&[&a.name])
+                .set(tokens(a.solvency_margin() as f64));
+            self.drift
+                .with_label_values(&[&a.name])
+                .set(tokens(a.drift() as f64));
+        }
+        for c in &report.chains {
+            let chain = c.chain.to_hex();
+            if let Some(n) = c.transfers_out {
+                self.transfers
+                    .with_label_values(&[&chain, "out"])
+                    .set(n as f64);
+            }
+            if let Some(n) = c.transfers_in {
+                self.transfers
+                    .with_label_values(&[&chain, "in"])
+                    .set(n as f64);
+            }
+        }
+        self.findings.set(report.findings.len() as f64);
+        self.last_success.set(report.timestamp as f64);
+    }
+
+    /// Count a failed round
+    pub fn observe_error(&self) {
+        self.errors.inc();
+    }
+
+    /// Prometheus text exposition
+    pub fn encode(&self) -> Result<String> {
+        let mut buf = Vec::new();
+        TextEncoder::new().encode(&self.registry.gather(), &mut buf)?;
+        Ok(String::from_utf8(buf)?)
+    }
+}
+
+// ============================================================================
+// Chain helpers
+// ============================================================================
+
+async fn connect_chain(endpoint: &AuditEndpoint) -> Result<AuditChain> {
+    let (id, backend) = match endpoint {
+        AuditEndpoint::Evm {
+            rpc_url,
+            bridge,
+            lock_unlock,
+            from_block,
+            confirmations,
+        } => {
+            let bridge: Address = bridge
+                .parse()
+                .wrap_err_with(|| format!("invalid EVM bridge address {}", bridge))?;
+            let lock_unlock = match lock_unlock {
+                Some(a) => Some(
+                    a.parse::<Address>()
+                        .wrap_err_with(|| format!("invalid LockUnlock address {}", a))?,
+                ),
+                None => None,
+            };
+            let client = EvmQueryClient::new(rpc_url, bridge, 0)?;
+            let id = client
+                .get_this_chain_id()
+                .await
+                .wrap_err_with(|| format!("EVM bridge {} on {}", bridge, rpc_url))?;
+            let backend = Backend::Evm {
+                client,
+                bridge,
+                lock_unlock,
+                next_block: *from_block,
+                confirmations: *confirmations,
+            };
+            (id, backend)
+        }
+        AuditEndpoint::Terra { lcd_url, bridge } => {
+            let client = TerraQueryClient::new(lcd_url, bridge);
+            let id = client
+                .get_this_chain_id()
+                .await
+                .wrap_err_with(|| format!("Terra bridge {} on {}", bridge, lcd_url))?;
+            (
+                id,
+                Backend::Terra {
+                    client,
+                    next_nonce: 0,
+                },
+            )
+        }
+        AuditEndpoint::Solana {
+            rpc_url,
+            program_id,
+        } => {
+            let program: Pubkey = program_id
+                .parse()
+                .wrap_err_with(|| format!("invalid Solana program ID {}", program_id))?;
+            let client = Arc::new(RpcClient::new_with_commitment(
+                rpc_url.clone(),
+                CommitmentConfig::finalized(),
+            ));
+            let config = blocking(&client, move |c| {
+                let account = c.get_account(&bridge_config_pda(&program))?;
+                parse_bridge_config(&account.data)
+            })
+            .await
+            .wrap_err_with(|| format!("Solana program {} on {}", program_id, rpc_url))?;
+            let backend = Backend::Solana {
+                client,
+                program_id: program,
+                newest: None,
+                submitted: HashMap::new(),
+            };
+            (ChainId::from_bytes(config.chain_id), backend)
+        }
+    };
+    Ok(AuditChain {
+        id,
+        backend,
+        unmatched: 0,
+        unattributed: 0,
+    })
+}
+
+fn resolve_token(kind: ChainKind, token: &str) -> Result<(LegToken, [u8; 32])> {
+    Ok(match kind {
+        ChainKind::Evm => {
+            let address: Address = token
+                .parse()
+                .wrap_err_with(|| format!("invalid EVM token {}", token))?;
+            (
+                LegToken::Evm(address),
+                address_to_bytes32(&address.into_array()),
+            )
+        }
+        ChainKind::Terra => (
+            LegToken::Terra(token.to_string()),
+            encode_terra_token_address(token),
+        ),
+        ChainKind::Solana => {
+            let mint: Pubkey = token
+                .parse()
+                .wrap_err_with(|| format!("invalid Solana mint {}", token))?;
+            (LegToken::Solana(mint), mint.to_bytes())
+        }
+    })
+}
+
+fn route_entry(
+    routes: &mut BTreeMap<([u8; 4], [u8; 4]), RouteReport>,
+    src: [u8; 4],
+    dest: [u8; 4],
+) -> &mut RouteReport {
+    routes.entry((src, dest)).or_insert_with(|| RouteReport {
+        src_chain: ChainId::from_bytes(src),
+        dest_chain: ChainId::from_bytes(dest),
+        ..RouteReport::default()
+    })
+}
+
+/// Asset whose leg on `chain` is `token`
+fn find_local<'a>(
+    assets: &'a [Asset],
+    chain: &ChainId,
+    token: &LegToken,
+) -> Option<(usize, &'a Asset, &'a Leg)> {
+    assets.iter().enumerate().find_map(|(i, a)| {
+        a.leg(chain)
+            .filter(|l| l.token == *token)
+            .map(|l| (i, a, l))
+    })
+}
+
+/// Asset of a deposit on `src` that names `dest_token` on `dest`, with its `src` leg
+fn find_by_dest<'a>(
+    assets: &'a [Asset],
+    src: &ChainId,
+    dest: &ChainId,
+    dest_token: &[u8; 32],
+) -> Option<(usize, &'a Asset, &'a Leg)> {
+    assets.iter().enumerate().find_map(|(i, a)| {
+        a.leg(dest)
+            .filter(|l| l.token_bytes32 == *dest_token)
+            .and_then(|_| a.leg(src))
+            .map(|l| (i, a, l))
+    })
+}
+
+/// `pending_withdrawals` fields the auditor reads
+#[derive(Debug, Deserialize)]
+struct TerraWithdrawal {
+    xchain_hash_id: String,
+    src_chain: String,
+    token: String,
+    amount: String,
+    src_decimals: u8,
+    dest_decimals: u8,
+    approved: bool,
+    cancelled: bool,
+    executed: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct TerraWithdrawalPage {
+    withdrawals: Vec<TerraWithdrawal>,
+}
+
+/// Every entry of the Terra bridge's `pending_withdrawals` (executed ones included)
+async fn terra_withdrawals(client: &TerraQueryClient) -> Result<Vec<TerraWithdrawal>> {
+    let mut all = Vec::new();
+    let mut start_after = None;
+    loop {
+        let page: TerraWithdrawalPage = client
+            .query_contract(
+                client.bridge_address(),
+                &QueryMsg::PendingWithdrawals {
+                    start_after: start_after.clone(),
+                    limit: Some(TERRA_PAGE),
+                },
+            )
+            .await?;
+        let full = page.withdrawals.len() == TERRA_PAGE as usize;
+        start_after = page.withdrawals.last().map(|w| w.xchain_hash_id.clone());
+        all.extend(page.withdrawals);
+        if !full {
+            return Ok(all);
+        }
+    }
+}
+
+/// Convert an amount between decimal precisions, as the contracts do on execution
+pub fn normalize_decimals(amount: u128, src_decimals: u8, dest_decimals: u8) -> u128 {
+    if src_decimals >= dest_decimals {
+        amount / 10u128.pow((src_decimals - dest_decimals) as u32)
+    } else {
+        amount.saturating_mul(10u128.pow((dest_decimals - src_decimals) as u32))
+    }
+}
+
+fn u256_to_u128(v: U256) -> Result<u128> {
+    v.try_into().map_err(|_| eyre!("amount {} exceeds u128", v))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(locked: u128, supply: u128, routes: Vec<RouteReport>) -> AssetReport {
+        AssetReport {
+            name: "LUNC".into(),
+            decimals: 18,
+            legs: Vec::new(),
+            routes,
+            locked,
+            supply,
+            outstanding_unlock: 0,
+            outstanding_mint: 0,
+            tolerance: 0,
+        }
+    }
+
+    fn route(deposited: u128, executed: u128, outstanding: u128) -> RouteReport {
+        RouteReport {
+            src_chain: ChainId::from_u32(2),
+            dest_chain: ChainId::from_u32(1),
+            deposited,
+            executed,
+            outstanding,
+        }
+    }
+
+    #[test]
+    fn test_drift_and_margin() {
+        // 100 locked, 60 minted, 40 deposited but not yet executed: balanced
+        let mut r = report(100, 60, vec![route(100, 60, 30)]);
+        assert_eq!(r.in_flight(), 40);
+        assert_eq!(r.drift(), 0);
+        assert_eq!(r.solvency_margin(), 40);
+        assert_eq!(r.routes[0].unreleased(), 10);
+
+        // Minting 30 more on approval would exceed custody once 20 is gone
+        r.locked = 80;
+        r.outstanding_mint = 30;
+        assert_eq!(r.drift(), -20);
+        assert_eq!(r.solvency_margin(), -10);
+    }
+
+    #[test]
+    fn test_normalize_decimals() {
+        assert_eq!(
+            normalize_decimals(1_000_000, 6, 18),
+            1_000_000_000_000_000_000
+        );
+        assert_eq!(
+            normalize_decimals(1_500_000_000_000_000_000, 18, 6),
+            1_500_000
+        );
+        assert_eq!(normalize_decimals(42, 8, 8), 42);
+    }
+
+    #[test]
+    fn test_config_parsing() {
+        let config = AuditConfig::from_json(
+            r#"{
+                "chains": [
+                    {"kind": "evm", "rpc_url": "http://localhost:8545",
+                     "bridge": "0xb1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1b1",
+                     "lock_unlock": "0x1010101010101010101010101010101010101010",
+                     "from_block": 12},
+                    {"kind": "terra", "lcd_url": "http://localhost:1317", "bridge": "terra1xyz"}
+                ],
+                "assets": [
+                    {"name": "LUNC", "tolerance": "5", "legs": [
+                        {"chain": "2", "token": "uluna", "custody": "lock", "decimals": 6},
+                        {"chain": "0x00000001", "token": "0x4444444444444444444444444444444444444444",
+                         "custody": "mint", "decimals": 18}
+                    ]}
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            config.chains[0],
+            AuditEndpoint::Evm {
+                from_block: 12,
+                confirmations: 0,
+                ..
+            }
+        ));
+        assert_eq!(config.assets[0].legs[1].custody, Custody::Mint);
+        assert_eq!(
+            parse_chain_id(&config.assets[0].legs[0].chain).unwrap(),
+            ChainId::from_u32(2)
+        );
+        assert!(AuditConfig::from_json(r#"{"chains": [{"kind": "btc"}], "assets": []}"#).is_err());
+    }
+}
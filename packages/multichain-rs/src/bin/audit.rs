@@ -0,0 +1,158 @@
+//! Cross-chain solvency auditor CLI.
+//!
+//! Reads custody, outstanding withdrawals and deposit/execution flows for every asset in
+//! the configuration and checks the supply invariants (see `multichain_rs::audit` and
+//! `docs/crosschain-parity.md`).
+//!
+//! ```text
+//! cd packages/multichain-rs
+//! cargo run --features audit --bin cl8y-audit -- --config audit.json --once
+//! cargo run --features audit --bin cl8y-audit -- --config audit.json --listen 0.0.0.0:9192
+//! ```
+//!
+//! `--once` prints the JSON report and exits with status 2 when there are findings.
+//! Otherwise the auditor runs every `--interval-secs` and serves `/metrics` (Prometheus),
+//! `/report` (latest JSON report) and `/health`.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use clap::Parser;
+use eyre::{Result, WrapErr};
+use multichain_rs::audit::{
+    AuditConfig, AuditMetrics, AuditReport, Auditor, DEFAULT_AUDIT_INTERVAL_SECS,
+    DEFAULT_AUDIT_LOG_CHUNK,
+};
+use tokio::sync::RwLock;
+use tracing::{error, info, warn};
+use tracing_subscriber::EnvFilter;
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "cl8y-audit",
+    about = "Check CL8Y bridge custody against minted supply and transfer history"
+)]
+struct Args {
+    /// JSON file listing the bridges and the assets' legs
+    #[arg(long, env = "AUDIT_CONFIG")]
+    config: String,
+
+    /// Run one round, print the JSON report and exit (status 2 on findings)
+    #[arg(long)]
+    once: bool,
+
+    /// Address serving /metrics, /report and /health
+    #[arg(long, env = "AUDIT_LISTEN", default_value = "0.0.0.0:9192")]
+    listen: SocketAddr,
+
+    /// Seconds between rounds
+    #[arg(long, default_value_t = DEFAULT_AUDIT_INTERVAL_SECS)]
+    interval_secs: u64,
+
+    /// Block range per eth_getLogs request
+    #[arg(long, default_value_t = DEFAULT_AUDIT_LOG_CHUNK)]
+    evm_log_chunk: u64,
+}
+
+struct Shared {
+    metrics: AuditMetrics,
+    latest: RwLock<Option<AuditReport>>,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")),
+        )
+        .with_writer(std::io::stderr)
+        .init();
+
+    let args = Args::parse();
+    let raw = std::fs::read_to_string(&args.config)
+        .wrap_err_with(|| format!("reading {}", args.config))?;
+    let config = AuditConfig::from_json(&raw)?;
+
+    let mut auditor = Auditor::connect(&config)
+        .await?
+        .with_log_chunk(args.evm_log_chunk);
+    for (id, kind) in auditor.chains() {
+        info!(chain = %id.to_hex(), %kind, "Auditing bridge");
+    }
+
+    if args.once {
+        let report = auditor.run_once().await?;
+        println!("{}", serde_json::to_string_pretty(&report.to_json())?);
+        eprint!("{}", report);
+        if !report.findings.is_empty() {
+            std::process::exit(2);
+        }
+        return Ok(());
+    }
+
+    let shared = Arc::new(Shared {
+        metrics: AuditMetrics::new()?,
+        latest: RwLock::new(None),
+    });
+    let app = Router::new()
+        .route("/metrics", get(metrics))
+        .route("/report", get(report))
+        .route("/health", get(|| async { "OK" }))
+        .with_state(shared.clone());
+    let listener = tokio::net::TcpListener::bind(args.listen)
+        .await
+        .wrap_err_with(|| format!("binding {}", args.listen))?;
+    info!(listen = %args.listen, "Serving audit metrics");
+    tokio::spawn(async move {
+        if let Err(e) = axum::serve(listener, app).await {
+            error!(error = %e, "Audit HTTP server stopped");
+        }
+    });
+
+    let mut interval = tokio::time::interval(Duration::from_secs(args.interval_secs.max(1)));
+    loop {
+        interval.tick().await;
+        match auditor.run_once().await {
+            Ok(report) => {
+                for finding in &report.findings {
+                    warn!(%finding, "Audit finding");
+                }
+                info!(
+                    assets = report.assets.len(),
+                    findings = report.findings.len(),
+                    "Audit round complete"
+                );
+                shared.metrics.observe(&report);
+                *shared.latest.write().await = Some(report);
+            }
+            Err(e) => {
+                shared.metrics.observe_error();
+                error!(error = %format!("{:#}", e), "Audit round failed");
+            }
+        }
+    }
+}
+
+async fn metrics(State(shared): State<Arc<Shared>>) -> Response {
+    match shared.metrics.encode() {
+        Ok(body) => body.into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+async fn report(State(shared): State<Arc<Shared>>) -> Response {
+    match shared.latest.read().await.as_ref() {
+        Some(report) => Json(report.to_json()).into_response(),
+        None => (
+            StatusCode::SERVICE_UNAVAILABLE,
+            "no audit round completed yet",
+        )
+            .into_response(),
+    }
+}
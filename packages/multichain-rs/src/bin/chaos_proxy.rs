@@ -0,0 +1,74 @@
+//! Chaos RPC proxy for manual and E2E chaos runs.
+//!
+//! Forwards everything to `CHAOS_UPSTREAM_URL` and injects the faults described in the
+//! `CHAOS_SCENARIO` file (see `multichain_rs::testing::chaos` for the format). Point the
+//! operator's or canceler's `EVM_RPC_URL` / `TERRA_LCD_URL` / `SOLANA_RPC_URL` at it.
+//!
+//! ```text
+//! cd packages/multichain-rs
+//! export CHAOS_UPSTREAM_URL=http://localhost:8545
+//! export CHAOS_SCENARIO=../e2e/chaos/evm-flaky.json
+//! export CHAOS_LISTEN_ADDR=127.0.0.1:18545   # optional, this is the default
+//! cargo run --features testing --bin cl8y-chaos-proxy
+//! ```
+//!
+//! Injected fault counts are logged every `CHAOS_STATS_INTERVAL_SECS` (default 30) and on
+//! Ctrl-C.
+
+use eyre::{Result, WrapErr};
+use multichain_rs::testing::chaos::{ChaosProxy, ChaosScenario};
+use std::time::Duration;
+use tracing::info;
+use tracing_subscriber::EnvFilter;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")),
+        )
+        .init();
+
+    let upstream = std::env::var("CHAOS_UPSTREAM_URL")
+        .wrap_err("CHAOS_UPSTREAM_URL must be set (e.g. http://localhost:8545)")?;
+    let scenario_path =
+        std::env::var("CHAOS_SCENARIO").wrap_err("CHAOS_SCENARIO must point to a scenario file")?;
+    let listen =
+        std::env::var("CHAOS_LISTEN_ADDR").unwrap_or_else(|_| "127.0.0.1:18545".to_string());
+    let interval = std::env::var("CHAOS_STATS_INTERVAL_SECS")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(30u64);
+
+    let scenario = ChaosScenario::from_file(&scenario_path)?;
+    let proxy = ChaosProxy::bind(&listen, &upstream, scenario.clone()).await?;
+    info!(
+        url = proxy.url(),
+        upstream = %upstream,
+        scenario = %scenario.name,
+        rules = scenario.rules.len(),
+        "Chaos proxy listening"
+    );
+
+    let mut ticker = tokio::time::interval(Duration::from_secs(interval.max(1)));
+    ticker.tick().await;
+    loop {
+        tokio::select! {
+            _ = ticker.tick() => log_stats(&proxy),
+            _ = tokio::signal::ctrl_c() => {
+                log_stats(&proxy);
+                return Ok(());
+            }
+        }
+    }
+}
+
+fn log_stats(proxy: &ChaosProxy) {
+    let stats = proxy.stats();
+    info!(
+        requests = stats.requests,
+        injected = ?stats.injected,
+        dropped_transactions = stats.dropped_transactions.len(),
+        "Chaos proxy stats"
+    );
+}
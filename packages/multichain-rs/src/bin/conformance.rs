@@ -0,0 +1,95 @@
+//! Conformance corpus CLI.
+//!
+//! Regenerates or checks the shared hash/codec vectors (see `multichain_rs::conformance`)
+//! that the Rust crates, Solidity and TypeScript test suites consume.
+//!
+//! ```text
+//! cd packages/multichain-rs
+//! cargo run --features conformance --bin cl8y-conformance -- generate
+//! cargo run --features conformance --bin cl8y-conformance -- check
+//! cargo run --features conformance --bin cl8y-conformance -- fuzz-seeds \
+//!     ../contracts-solana/programs/cl8y-bridge/fuzz/corpus/transfer_hash
+//! ```
+//!
+//! `check` exits with status 2 when the committed corpus differs from what this crate
+//! generates.
+
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand};
+use eyre::{Result, WrapErr};
+use multichain_rs::conformance::{Corpus, CORPUS_PATH};
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "cl8y-conformance",
+    about = "Generate or check the CL8Y hash/codec conformance corpus"
+)]
+struct Args {
+    /// Corpus file (defaults to the committed corpus in this crate)
+    #[arg(long, global = true)]
+    corpus: Option<PathBuf>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Write the corpus generated by this crate
+    Generate,
+    /// Fail if the corpus on disk is stale or does not verify
+    Check,
+    /// Write one `transfer_hash` fuzz seed per xchain hash vector into a directory
+    FuzzSeeds { dir: PathBuf },
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let path = args
+        .corpus
+        .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(CORPUS_PATH));
+    let generated = Corpus::generate()?;
+
+    match args.command {
+        Command::Generate => {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(&path, generated.to_json()?)
+                .wrap_err_with(|| format!("writing {}", path.display()))?;
+            eprintln!(
+                "wrote {} xchain hash and {} address vectors to {}",
+                generated.xchain_hash.len(),
+                generated.universal_address.len(),
+                path.display()
+            );
+        }
+        Command::Check => {
+            let json = std::fs::read_to_string(&path)
+                .wrap_err_with(|| format!("reading {}", path.display()))?;
+            let on_disk = Corpus::from_json(&json)?;
+            on_disk.verify()?;
+            if json != generated.to_json()? {
+                eprintln!(
+                    "{} is stale: rerun `cl8y-conformance generate` and commit the result",
+                    path.display()
+                );
+                std::process::exit(2);
+            }
+            eprintln!("{} is up to date", path.display());
+        }
+        Command::FuzzSeeds { dir } => {
+            std::fs::create_dir_all(&dir)?;
+            for v in &generated.xchain_hash {
+                std::fs::write(dir.join(&v.name), v.fuzz_seed()?)?;
+            }
+            eprintln!(
+                "wrote {} seeds to {}",
+                generated.xchain_hash.len(),
+                dir.display()
+            );
+        }
+    }
+    Ok(())
+}
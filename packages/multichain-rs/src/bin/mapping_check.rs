@@ -0,0 +1,131 @@
+//! Token mapping consistency CLI.
+//!
+//! Reads every token mapping on the configured bridges and checks that both sides of each
+//! route agree on token identity, decimals, mode and rate limits (see
+//! `multichain_rs::mappings`).
+//!
+//! ```text
+//! cd packages/multichain-rs
+//! export EVM_RPC_URL=http://localhost:8545 EVM_BRIDGE_ADDRESS=0x...
+//! export TERRA_LCD_URL=http://localhost:1317 TERRA_BRIDGE_ADDRESS=terra1...
+//! export SOLANA_RPC_URL=http://localhost:8899 SOLANA_PROGRAM_ID=...
+//! cargo run --features mappings --bin cl8y-mapping-check -- --json
+//! ```
+//!
+//! Additional EVM bridges are passed as `--extra-evm <RPC_URL>=<BRIDGE_ADDRESS>`. Exits with
+//! status 2 when there are findings.
+
+use alloy::primitives::Address;
+use clap::Parser;
+use eyre::{eyre, Result, WrapErr};
+use multichain_rs::chains::TraceEndpoint;
+use multichain_rs::mappings::MappingChecker;
+use solana_sdk::pubkey::Pubkey;
+use tracing::info;
+use tracing_subscriber::EnvFilter;
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "cl8y-mapping-check",
+    about = "Check that CL8Y bridge token mappings agree across chains"
+)]
+struct Args {
+    #[arg(long, env = "EVM_RPC_URL")]
+    evm_rpc_url: Option<String>,
+
+    #[arg(long, env = "EVM_BRIDGE_ADDRESS", requires = "evm_rpc_url")]
+    evm_bridge: Option<String>,
+
+    /// Additional EVM bridge as <RPC_URL>=<BRIDGE_ADDRESS> (repeatable)
+    #[arg(long)]
+    extra_evm: Vec<String>,
+
+    #[arg(long, env = "TERRA_LCD_URL")]
+    terra_lcd_url: Option<String>,
+
+    #[arg(long, env = "TERRA_BRIDGE_ADDRESS", requires = "terra_lcd_url")]
+    terra_bridge: Option<String>,
+
+    #[arg(long, env = "SOLANA_RPC_URL")]
+    solana_rpc_url: Option<String>,
+
+    #[arg(long, env = "SOLANA_PROGRAM_ID", requires = "solana_rpc_url")]
+    solana_program_id: Option<String>,
+
+    /// Print the report as JSON on stdout
+    #[arg(long)]
+    json: bool,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")),
+        )
+        .with_writer(std::io::stderr)
+        .init();
+
+    let args = Args::parse();
+    let endpoints = endpoints(&args)?;
+    if endpoints.is_empty() {
+        return Err(eyre!(
+            "no bridge configured: set EVM_RPC_URL/EVM_BRIDGE_ADDRESS, \
+             TERRA_LCD_URL/TERRA_BRIDGE_ADDRESS or SOLANA_RPC_URL/SOLANA_PROGRAM_ID"
+        ));
+    }
+
+    let checker = MappingChecker::connect(&endpoints).await?;
+    for (id, kind) in checker.chains() {
+        info!(chain = %id.to_hex(), %kind, "Reading token mappings");
+    }
+    let report = checker.check().await?;
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&report.to_json())?);
+    }
+    eprint!("{}", report);
+    if !report.findings.is_empty() {
+        std::process::exit(2);
+    }
+    Ok(())
+}
+
+fn endpoints(args: &Args) -> Result<Vec<TraceEndpoint>> {
+    let mut endpoints = Vec::new();
+    let evm = |rpc_url: &str, bridge: &str| -> Result<TraceEndpoint> {
+        Ok(TraceEndpoint::Evm {
+            rpc_url: rpc_url.to_string(),
+            bridge: bridge
+                .parse::<Address>()
+                .wrap_err_with(|| format!("invalid EVM bridge address {}", bridge))?,
+        })
+    };
+    if let (Some(rpc), Some(bridge)) = (&args.evm_rpc_url, &args.evm_bridge) {
+        endpoints.push(evm(rpc, bridge)?);
+    }
+    for extra in &args.extra_evm {
+        let (rpc, bridge) = extra.rsplit_once('=').ok_or_else(|| {
+            eyre!(
+                "--extra-evm expects <RPC_URL>=<BRIDGE_ADDRESS>, got {}",
+                extra
+            )
+        })?;
+        endpoints.push(evm(rpc, bridge)?);
+    }
+    if let (Some(lcd), Some(bridge)) = (&args.terra_lcd_url, &args.terra_bridge) {
+        endpoints.push(TraceEndpoint::Terra {
+            lcd_url: lcd.clone(),
+            bridge: bridge.clone(),
+        });
+    }
+    if let (Some(rpc), Some(program)) = (&args.solana_rpc_url, &args.solana_program_id) {
+        endpoints.push(TraceEndpoint::Solana {
+            rpc_url: rpc.clone(),
+            program_id: program
+                .parse::<Pubkey>()
+                .wrap_err_with(|| format!("invalid Solana program ID {}", program))?,
+        });
+    }
+    Ok(endpoints)
+}
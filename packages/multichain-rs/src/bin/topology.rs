@@ -0,0 +1,117 @@
+//! Bridge topology CLI.
+//!
+//! Compares every bridge's chain and token registries with a declarative topology file and
+//! prints (or sends) the admin messages that reconcile them (see `multichain_rs::topology`
+//! and `docs/topology.md`).
+//!
+//! ```text
+//! cd packages/multichain-rs
+//! cargo run --features topology --bin cl8y-topology -- --config topology.json plan
+//! cargo run --features topology --bin cl8y-topology -- --config topology.json apply
+//! ```
+//!
+//! `plan` exits with status 2 when any bridge differs from the topology; `apply` exits with
+//! status 2 when conflicts remain that need manual action.
+
+use clap::{Parser, Subcommand};
+use eyre::{eyre, Result, WrapErr};
+use multichain_rs::topology::{AdminSigners, Topology, TopologyConfig};
+use solana_sdk::signature::read_keypair_file;
+use tracing::info;
+use tracing_subscriber::EnvFilter;
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "cl8y-topology",
+    about = "Plan and apply CL8Y bridge chain and token registrations from a topology file"
+)]
+struct Args {
+    /// JSON file listing the bridges and every token's legs
+    #[arg(long, env = "TOPOLOGY_CONFIG")]
+    config: String,
+
+    /// Print the plan as JSON on stdout
+    #[arg(long)]
+    json: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Show what differs from the topology (status 2 when anything does)
+    Plan,
+    /// Send the planned admin messages, stopping at the first failure
+    Apply {
+        /// Private key of the EVM bridges' owner
+        #[arg(long, env = "EVM_ADMIN_PRIVATE_KEY", hide_env_values = true)]
+        evm_private_key: Option<String>,
+
+        /// Mnemonic of the Terra bridge admin
+        #[arg(long, env = "TERRA_ADMIN_MNEMONIC", hide_env_values = true)]
+        terra_mnemonic: Option<String>,
+
+        /// solana-keygen JSON file of the Solana bridge admin
+        #[arg(long, env = "SOLANA_ADMIN_KEYPAIR")]
+        solana_keypair: Option<String>,
+    },
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")),
+        )
+        .with_writer(std::io::stderr)
+        .init();
+
+    let args = Args::parse();
+    let raw = std::fs::read_to_string(&args.config)
+        .wrap_err_with(|| format!("reading {}", args.config))?;
+    let config = TopologyConfig::from_json(&raw)?;
+
+    let topology = Topology::connect(&config).await?;
+    for (id, kind) in topology.chains() {
+        info!(chain = %id.to_hex(), %kind, "Reading bridge");
+    }
+
+    let plan = topology.plan().await?;
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&plan.to_json())?);
+    }
+    eprint!("{}", plan);
+
+    match args.command {
+        Command::Plan => {
+            if !plan.is_empty() {
+                std::process::exit(2);
+            }
+        }
+        Command::Apply {
+            evm_private_key,
+            terra_mnemonic,
+            solana_keypair,
+        } => {
+            let solana_keypair = match solana_keypair {
+                Some(path) => Some(
+                    read_keypair_file(&path)
+                        .map_err(|e| eyre!("reading Solana keypair {}: {}", path, e))?,
+                ),
+                None => None,
+            };
+            let signers = AdminSigners {
+                evm_private_key,
+                terra_mnemonic,
+                solana_keypair,
+            };
+            let sent = topology.apply(&plan, &signers).await?;
+            info!(transactions = sent.len(), "Topology applied");
+            if plan.conflicts().next().is_some() {
+                std::process::exit(2);
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,194 @@
+//! Transfer tracing CLI.
+//!
+//! Follows one transfer across the configured bridges: decodes the source deposit,
+//! recomputes its xchain hash ID and prints the destination's approval, cancel and
+//! execution status with the remaining cancel window (see `multichain_rs::trace`).
+//!
+//! ```text
+//! cd packages/multichain-rs
+//! export EVM_RPC_URL=http://localhost:8545 EVM_BRIDGE_ADDRESS=0x...
+//! export TERRA_LCD_URL=http://localhost:1317 TERRA_BRIDGE_ADDRESS=terra1...
+//! export SOLANA_RPC_URL=http://localhost:8899 SOLANA_PROGRAM_ID=...
+//! cargo run --features trace --bin cl8y-trace -- --tx 0x<source tx hash>
+//! cargo run --features trace --bin cl8y-trace -- --hash 0x<xchain hash id>
+//! cargo run --features trace --bin cl8y-trace -- --src-chain 0x00000002 --nonce 42 --json
+//! ```
+//!
+//! Additional EVM bridges are passed as `--extra-evm <RPC_URL>=<BRIDGE_ADDRESS>`.
+
+use alloy::primitives::Address;
+use clap::{ArgGroup, Parser};
+use eyre::{eyre, Result, WrapErr};
+use multichain_rs::trace::{
+    TraceEndpoint, TraceTarget, Tracer, DEFAULT_EVM_LOG_CHUNK, DEFAULT_EVM_LOOKBACK_BLOCKS,
+};
+use multichain_rs::ChainId;
+use solana_sdk::pubkey::Pubkey;
+use tracing_subscriber::EnvFilter;
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "cl8y-trace",
+    about = "Follow one CL8Y bridge transfer across chains",
+    group(ArgGroup::new("target").required(true).args(["tx", "hash", "nonce"]))
+)]
+struct Args {
+    /// Source transaction: EVM tx hash, Terra tx hash or Solana signature
+    #[arg(long)]
+    tx: Option<String>,
+
+    /// xchain hash ID (32 bytes hex)
+    #[arg(long)]
+    hash: Option<String>,
+
+    /// Deposit nonce on --src-chain
+    #[arg(long, requires = "src_chain")]
+    nonce: Option<u64>,
+
+    /// Source chain ID: 4-byte hex (0x00000002) or decimal
+    #[arg(long)]
+    src_chain: Option<String>,
+
+    #[arg(long, env = "EVM_RPC_URL")]
+    evm_rpc_url: Option<String>,
+
+    #[arg(long, env = "EVM_BRIDGE_ADDRESS", requires = "evm_rpc_url")]
+    evm_bridge: Option<String>,
+
+    /// Additional EVM bridge as <RPC_URL>=<BRIDGE_ADDRESS> (repeatable)
+    #[arg(long)]
+    extra_evm: Vec<String>,
+
+    #[arg(long, env = "TERRA_LCD_URL")]
+    terra_lcd_url: Option<String>,
+
+    #[arg(long, env = "TERRA_BRIDGE_ADDRESS", requires = "terra_lcd_url")]
+    terra_bridge: Option<String>,
+
+    #[arg(long, env = "SOLANA_RPC_URL")]
+    solana_rpc_url: Option<String>,
+
+    #[arg(long, env = "SOLANA_PROGRAM_ID", requires = "solana_rpc_url")]
+    solana_program_id: Option<String>,
+
+    /// EVM blocks searched for deposits by nonce and for withdraw events
+    #[arg(long, default_value_t = DEFAULT_EVM_LOOKBACK_BLOCKS)]
+    evm_lookback_blocks: u64,
+
+    /// Block range per eth_getLogs request
+    #[arg(long, default_value_t = DEFAULT_EVM_LOG_CHUNK)]
+    evm_log_chunk: u64,
+
+    /// Print the trace as JSON
+    #[arg(long)]
+    json: bool,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("warn")),
+        )
+        .with_writer(std::io::stderr)
+        .init();
+
+    let args = Args::parse();
+    let endpoints = endpoints(&args)?;
+    if endpoints.is_empty() {
+        return Err(eyre!(
+            "no bridge configured: set EVM_RPC_URL/EVM_BRIDGE_ADDRESS, \
+             TERRA_LCD_URL/TERRA_BRIDGE_ADDRESS or SOLANA_RPC_URL/SOLANA_PROGRAM_ID"
+        ));
+    }
+    let target = target(&args)?;
+
+    let tracer = Tracer::connect(&endpoints)
+        .await?
+        .with_evm_lookback(args.evm_lookback_blocks, args.evm_log_chunk);
+    let trace = tracer.trace(&target).await?;
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&trace.to_json())?);
+    } else {
+        let chains: Vec<String> = tracer
+            .chains()
+            .iter()
+            .map(|(id, kind)| format!("{} {}", kind, id.to_hex()))
+            .collect();
+        println!("Chains        {}", chains.join(", "));
+        print!("{}", trace);
+    }
+    Ok(())
+}
+
+fn endpoints(args: &Args) -> Result<Vec<TraceEndpoint>> {
+    let mut endpoints = Vec::new();
+    let evm = |rpc_url: &str, bridge: &str| -> Result<TraceEndpoint> {
+        Ok(TraceEndpoint::Evm {
+            rpc_url: rpc_url.to_string(),
+            bridge: bridge
+                .parse::<Address>()
+                .wrap_err_with(|| format!("invalid EVM bridge address {}", bridge))?,
+        })
+    };
+    if let (Some(rpc), Some(bridge)) = (&args.evm_rpc_url, &args.evm_bridge) {
+        endpoints.push(evm(rpc, bridge)?);
+    }
+    for extra in &args.extra_evm {
+        let (rpc, bridge) = extra.rsplit_once('=').ok_or_else(|| {
+            eyre!(
+                "--extra-evm expects <RPC_URL>=<BRIDGE_ADDRESS>, got {}",
+                extra
+            )
+        })?;
+        endpoints.push(evm(rpc, bridge)?);
+    }
+    if let (Some(lcd), Some(bridge)) = (&args.terra_lcd_url, &args.terra_bridge) {
+        endpoints.push(TraceEndpoint::Terra {
+            lcd_url: lcd.clone(),
+            bridge: bridge.clone(),
+        });
+    }
+    if let (Some(rpc), Some(program)) = (&args.solana_rpc_url, &args.solana_program_id) {
+        endpoints.push(TraceEndpoint::Solana {
+            rpc_url: rpc.clone(),
+            program_id: program
+                .parse::<Pubkey>()
+                .wrap_err_with(|| format!("invalid Solana program ID {}", program))?,
+        });
+    }
+    Ok(endpoints)
+}
+
+fn target(args: &Args) -> Result<TraceTarget> {
+    if let Some(tx) = &args.tx {
+        return Ok(TraceTarget::SourceTx(tx.clone()));
+    }
+    if let Some(hash) = &args.hash {
+        let bytes = hex::decode(hash.trim_start_matches("0x"))
+            .wrap_err_with(|| format!("invalid xchain hash ID {}", hash))?;
+        let hash: [u8; 32] = bytes
+            .try_into()
+            .map_err(|_| eyre!("xchain hash ID must be 32 bytes"))?;
+        return Ok(TraceTarget::XchainHashId(hash));
+    }
+    let nonce = args.nonce.ok_or_else(|| eyre!("missing --nonce"))?;
+    let src_chain = args
+        .src_chain
+        .as_deref()
+        .ok_or_else(|| eyre!("--nonce requires --src-chain"))?;
+    Ok(TraceTarget::Nonce {
+        src_chain: parse_chain_id(src_chain)?,
+        nonce,
+    })
+}
+
+fn parse_chain_id(s: &str) -> Result<ChainId> {
+    if s.starts_with("0x") {
+        return ChainId::from_hex(s).wrap_err_with(|| format!("invalid chain ID {}", s));
+    }
+    s.parse::<u32>()
+        .map(ChainId::from_u32)
+        .wrap_err_with(|| format!("invalid chain ID {}", s))
+}
@@ -0,0 +1,169 @@
+//! Bridge Connections
+//!
+//! Read-only connections to the bridge deployments of every chain family (EVM, Terra,
+//! Solana), and the small decoding helpers their readers share. The transfer tracer, the
+//! solvency auditor, the topology planner, the mapping checker and the token cache all
+//! build on these; none of it needs the CLI dependencies of their binaries.
+
+use std::fmt;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use alloy::primitives::Address;
+use base64::Engine;
+use eyre::{eyre, Result, WrapErr};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::evm::EvmQueryClient;
+use crate::solana::{bridge_config_pda, parse_bridge_config};
+use crate::terra::TerraQueryClient;
+use crate::types::ChainId;
+
+const B64: base64::engine::GeneralPurpose = base64::engine::general_purpose::STANDARD;
+
+/// A bridge deployment to read
+#[derive(Debug, Clone)]
+pub enum TraceEndpoint {
+    Evm { rpc_url: String, bridge: Address },
+    Terra { lcd_url: String, bridge: String },
+    Solana { rpc_url: String, program_id: Pubkey },
+}
+
+/// Chain family of a bridge deployment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChainKind {
+    Evm,
+    Terra,
+    Solana,
+}
+
+impl fmt::Display for ChainKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ChainKind::Evm => "evm",
+            ChainKind::Terra => "terra",
+            ChainKind::Solana => "solana",
+        })
+    }
+}
+
+/// A connected bridge, shared with the other readers built on these endpoints
+pub(crate) enum Backend {
+    Evm {
+        client: EvmQueryClient,
+        bridge: Address,
+    },
+    Terra(TerraQueryClient),
+    Solana {
+        client: Arc<RpcClient>,
+        program_id: Pubkey,
+    },
+}
+
+pub(crate) struct TraceChain {
+    pub(crate) id: ChainId,
+    pub(crate) backend: Backend,
+}
+
+impl TraceChain {
+    /// Connect to an endpoint and resolve the chain ID its bridge reports
+    pub(crate) async fn connect(endpoint: &TraceEndpoint) -> Result<Self> {
+        Ok(match endpoint {
+            TraceEndpoint::Evm { rpc_url, bridge } => {
+                let client = EvmQueryClient::new(rpc_url, *bridge, 0)?;
+                let id = client
+                    .get_this_chain_id()
+                    .await
+                    .wrap_err_with(|| format!("EVM bridge {} on {}", bridge, rpc_url))?;
+                TraceChain {
+                    id,
+                    backend: Backend::Evm {
+                        client,
+                        bridge: *bridge,
+                    },
+                }
+            }
+            TraceEndpoint::Terra { lcd_url, bridge } => {
+                let client = TerraQueryClient::new(lcd_url, bridge);
+                let id = client
+                    .get_this_chain_id()
+                    .await
+                    .wrap_err_with(|| format!("Terra bridge {} on {}", bridge, lcd_url))?;
+                TraceChain {
+                    id,
+                    backend: Backend::Terra(client),
+                }
+            }
+            TraceEndpoint::Solana {
+                rpc_url,
+                program_id,
+            } => {
+                let client = Arc::new(RpcClient::new_with_commitment(
+                    rpc_url.clone(),
+                    CommitmentConfig::confirmed(),
+                ));
+                let program = *program_id;
+                let config = blocking(&client, move |c| {
+                    let account = c.get_account(&bridge_config_pda(&program))?;
+                    parse_bridge_config(&account.data)
+                })
+                .await
+                .wrap_err_with(|| format!("Solana program {} on {}", program_id, rpc_url))?;
+                TraceChain {
+                    id: ChainId::from_bytes(config.chain_id),
+                    backend: Backend::Solana {
+                        client,
+                        program_id: *program_id,
+                    },
+                }
+            }
+        })
+    }
+
+    pub(crate) fn kind(&self) -> ChainKind {
+        match self.backend {
+            Backend::Evm { .. } => ChainKind::Evm,
+            Backend::Terra(_) => ChainKind::Terra,
+            Backend::Solana { .. } => ChainKind::Solana,
+        }
+    }
+}
+
+/// Parse a chain ID given as 4-byte hex (`0x00000002`) or decimal
+pub fn parse_chain_id(s: &str) -> Result<ChainId> {
+    if s.starts_with("0x") {
+        return ChainId::from_hex(s).wrap_err_with(|| format!("invalid chain ID {}", s));
+    }
+    s.parse::<u32>()
+        .map(ChainId::from_u32)
+        .wrap_err_with(|| format!("invalid chain ID {}", s))
+}
+
+pub(crate) fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+/// Run a blocking Solana RPC call off the async runtime
+pub(crate) async fn blocking<T: Send + 'static>(
+    client: &Arc<RpcClient>,
+    f: impl FnOnce(&RpcClient) -> Result<T> + Send + 'static,
+) -> Result<T> {
+    let client = client.clone();
+    tokio::task::spawn_blocking(move || f(&client))
+        .await
+        .map_err(|e| eyre!("Solana RPC task failed: {}", e))?
+}
+
+pub(crate) fn decode_bytes32(b64: &str) -> Option<[u8; 32]> {
+    B64.decode(b64).ok()?.try_into().ok()
+}
+
+pub(crate) fn decode_chain_id(b64: &str) -> Option<ChainId> {
+    let bytes: [u8; 4] = B64.decode(b64).ok()?.try_into().ok()?;
+    Some(ChainId::from_bytes(bytes))
+}
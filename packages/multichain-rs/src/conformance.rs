@@ -0,0 +1,510 @@
+//! Cross-implementation conformance vectors
+//!
+//! Builds the versioned JSON corpus of `compute_xchain_hash_id` and
+//! `UniversalAddress` vectors that every hash/codec implementation tests against,
+//! instead of each suite carrying hand-copied goldens:
+//!
+//! - `multichain-rs/tests/conformance_corpus.rs` (multichain-rs + CosmWasm `bridge`)
+//! - `contracts-terraclassic/bridge/tests/conformance_corpus.rs`
+//! - `contracts-solana/programs/cl8y-bridge/src/hash.rs` and `address_codec.rs` unit tests
+//! - `contracts-evm/test/Conformance.t.sol` (`HashLib` / `AddressCodecLib`)
+//! - `contracts-solana/tests/hash_parity.test.ts`
+//!
+//! The corpus is generated from this crate and committed at [`CORPUS_PATH`]:
+//!
+//! ```text
+//! cd packages/multichain-rs
+//! cargo run --features conformance --bin cl8y-conformance -- generate
+//! cargo run --features conformance --bin cl8y-conformance -- check
+//! ```
+//!
+//! Byte strings are `0x`-prefixed lowercase hex; `amount` (u128) and `nonce` (u64)
+//! are decimal strings so JSON consumers without 64-bit integers do not round them.
+//! Bump [`CORPUS_VERSION`] when a field changes meaning; adding vectors does not.
+
+use eyre::{eyre, Result, WrapErr};
+use serde::{Deserialize, Serialize};
+
+use crate::address_codec::{
+    UniversalAddress, CHAIN_TYPE_BITCOIN, CHAIN_TYPE_COSMOS, CHAIN_TYPE_EVM, CHAIN_TYPE_SOLANA,
+};
+use crate::hash::compute_xchain_hash_id;
+
+/// Corpus format version
+pub const CORPUS_VERSION: u32 = 1;
+
+/// Corpus location relative to the `multichain-rs` crate root
+pub const CORPUS_PATH: &str = "conformance/vectors.json";
+
+/// Size of one `transfer_hash` fuzz seed: chains, three accounts, amount, nonce
+pub const FUZZ_SEED_LEN: usize = 4 + 4 + 32 * 3 + 16 + 8;
+
+/// Versioned conformance corpus
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Corpus {
+    pub version: u32,
+    pub xchain_hash: Vec<XchainHashVector>,
+    pub universal_address: Vec<AddressVector>,
+}
+
+/// One `compute_xchain_hash_id` input set and its digest
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct XchainHashVector {
+    pub name: String,
+    /// bytes4
+    pub src_chain: String,
+    /// bytes4
+    pub dest_chain: String,
+    pub src_account: String,
+    pub dest_account: String,
+    pub token: String,
+    /// Decimal u128
+    pub amount: String,
+    /// Decimal u64
+    pub nonce: String,
+    pub xchain_hash_id: String,
+}
+
+/// One `UniversalAddress` and all of its encodings
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddressVector {
+    pub name: String,
+    pub chain_type: u32,
+    /// Chain-native form: 0x hex (EVM), bech32 (Cosmos), base58 (Solana), segwit (Bitcoin)
+    pub address: String,
+    /// Human-readable prefix for bech32 / segwit forms
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hrp: Option<String>,
+    /// Raw address: 20 bytes, or 32 for Solana pubkeys and P2WSH / P2TR programs
+    pub raw: String,
+    /// bytes8 (always zero for 32-byte raw addresses)
+    pub reserved: String,
+    /// `to_bytes32` (lossy for 32-byte raw addresses)
+    pub bytes32: String,
+    /// `to_hash_bytes`, the account encoding used in transfer hashes
+    pub hash_bytes: String,
+    /// `to_bytes` (lossless)
+    pub bytes: String,
+}
+
+impl XchainHashVector {
+    /// Decode the hash inputs: (src_chain, dest_chain, src_account, dest_account, token, amount, nonce)
+    #[allow(clippy::type_complexity)]
+    pub fn inputs(&self) -> Result<([u8; 4], [u8; 4], [u8; 32], [u8; 32], [u8; 32], u128, u64)> {
+        Ok((
+            hex_array(&self.src_chain)?,
+            hex_array(&self.dest_chain)?,
+            hex_array(&self.src_account)?,
+            hex_array(&self.dest_account)?,
+            hex_array(&self.token)?,
+            self.amount
+                .parse()
+                .wrap_err_with(|| format!("{}: invalid amount", self.name))?,
+            self.nonce
+                .parse()
+                .wrap_err_with(|| format!("{}: invalid nonce", self.name))?,
+        ))
+    }
+
+    /// Expected digest
+    pub fn expected(&self) -> Result<[u8; 32]> {
+        hex_array(&self.xchain_hash_id)
+    }
+
+    /// Inputs in the `transfer_hash` fuzz target layout (big-endian amount and nonce)
+    pub fn fuzz_seed(&self) -> Result<Vec<u8>> {
+        let (src_chain, dest_chain, src_account, dest_account, token, amount, nonce) =
+            self.inputs()?;
+        let mut seed = Vec::with_capacity(FUZZ_SEED_LEN);
+        seed.extend_from_slice(&src_chain);
+        seed.extend_from_slice(&dest_chain);
+        seed.extend_from_slice(&src_account);
+        seed.extend_from_slice(&dest_account);
+        seed.extend_from_slice(&token);
+        seed.extend_from_slice(&amount.to_be_bytes());
+        seed.extend_from_slice(&nonce.to_be_bytes());
+        Ok(seed)
+    }
+}
+
+impl AddressVector {
+    /// Parse the chain-native form back into a `UniversalAddress`
+    pub fn parse(&self) -> Result<UniversalAddress> {
+        let addr = match self.chain_type {
+            CHAIN_TYPE_EVM => UniversalAddress::from_evm(&self.address)?,
+            CHAIN_TYPE_COSMOS => UniversalAddress::from_cosmos(&self.address)?,
+            CHAIN_TYPE_SOLANA => UniversalAddress::from_solana_base58(&self.address)?,
+            CHAIN_TYPE_BITCOIN => UniversalAddress::from_bitcoin(&self.address)?,
+            other => return Err(eyre!("{}: unknown chain type {}", self.name, other)),
+        };
+        let reserved: [u8; 8] = hex_array(&self.reserved)?;
+        if reserved == [0u8; 8] {
+            return Ok(addr);
+        }
+        UniversalAddress::new_with_reserved(addr.chain_type, *addr.raw_address_20()?, reserved)
+    }
+}
+
+impl Corpus {
+    /// Build the corpus from this crate's implementation
+    pub fn generate() -> Result<Self> {
+        Ok(Self {
+            version: CORPUS_VERSION,
+            xchain_hash: xchain_hash_vectors()?,
+            universal_address: address_vectors()?,
+        })
+    }
+
+    /// Parse a corpus, rejecting unknown versions
+    pub fn from_json(json: &str) -> Result<Self> {
+        let corpus: Self = serde_json::from_str(json).wrap_err("invalid conformance corpus")?;
+        if corpus.version != CORPUS_VERSION {
+            return Err(eyre!(
+                "unsupported conformance corpus version {} (expected {})",
+                corpus.version,
+                CORPUS_VERSION
+            ));
+        }
+        Ok(corpus)
+    }
+
+    /// Pretty JSON with a trailing newline, as committed
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)? + "\n")
+    }
+
+    /// Check every vector against this crate's implementation
+    pub fn verify(&self) -> Result<()> {
+        for v in &self.xchain_hash {
+            let (sc, dc, sa, da, tok, amount, nonce) = v.inputs()?;
+            let got = compute_xchain_hash_id(&sc, &dc, &sa, &da, &tok, amount, nonce);
+            if got != v.expected()? {
+                return Err(eyre!(
+                    "{}: xchain_hash_id 0x{} != {}",
+                    v.name,
+                    hex::encode(got),
+                    v.xchain_hash_id
+                ));
+            }
+        }
+        for v in &self.universal_address {
+            if address_vector(&v.name, &v.parse()?, v.hrp.as_deref())? != *v {
+                return Err(eyre!("{}: universal address encodings differ", v.name));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn xchain_hash_vector(
+    name: &str,
+    src_chain: u32,
+    dest_chain: u32,
+    src_account: &str,
+    dest_account: &str,
+    token: &str,
+    amount: u128,
+    nonce: u64,
+) -> Result<XchainHashVector> {
+    let sa: [u8; 32] = hex_array(src_account)?;
+    let da: [u8; 32] = hex_array(dest_account)?;
+    let tok: [u8; 32] = hex_array(token)?;
+    let hash = compute_xchain_hash_id(
+        &src_chain.to_be_bytes(),
+        &dest_chain.to_be_bytes(),
+        &sa,
+        &da,
+        &tok,
+        amount,
+        nonce,
+    );
+    Ok(XchainHashVector {
+        name: name.to_string(),
+        src_chain: to_hex(&src_chain.to_be_bytes()),
+        dest_chain: to_hex(&dest_chain.to_be_bytes()),
+        src_account: to_hex(&sa),
+        dest_account: to_hex(&da),
+        token: to_hex(&tok),
+        amount: amount.to_string(),
+        nonce: nonce.to_string(),
+        xchain_hash_id: to_hex(&hash),
+    })
+}
+
+fn xchain_hash_vectors() -> Result<Vec<XchainHashVector>> {
+    const EVM_ALICE: &str = "000000000000000000000000f39fd6e51aad88f6f4ce6ab8827279cfffb92266";
+    const EVM_BOB: &str = "00000000000000000000000070997970c51812dc3a010c7d01b50e0d17dc79c8";
+    const ERC20: &str = "0000000000000000000000005fbdb2315678afecb367f032d93f642f64180aa3";
+    const ERC20_B: &str = "000000000000000000000000e7f1725e7734ce288f8367e1bb143e90bb3f0512";
+    const TERRA_USER: &str = "00000000000000000000000035743074956c710800e83198011ccbd4ddf1556d";
+    // keccak256("uluna")
+    const ULUNA: &str = "56fa6c6fbc36d8c245b0a852a43eb5d644e8b4c477b27bfab9537c10945939da";
+    const SOLANA_KEY: &str = "0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20";
+    const SOLANA_MINT: &str = "cdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcd";
+    const BTC_P2TR: &str = "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+    const ZERO: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+    const ONES: &str = "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff";
+
+    let v = xchain_hash_vector;
+    Ok(vec![
+        // Goldens shared with HashLib.t.sol since V2
+        v(
+            "evm_to_evm_erc20",
+            1,
+            56,
+            EVM_ALICE,
+            EVM_BOB,
+            ERC20,
+            1_000_000_000_000_000_000,
+            42,
+        )?,
+        v(
+            "evm_to_terra_uluna",
+            1,
+            2,
+            EVM_ALICE,
+            TERRA_USER,
+            ULUNA,
+            995_000,
+            1,
+        )?,
+        v(
+            "evm_to_terra_cw20",
+            1,
+            2,
+            EVM_ALICE,
+            TERRA_USER,
+            TERRA_USER,
+            1_000_000,
+            5,
+        )?,
+        v(
+            "terra_to_evm_native_to_erc20",
+            2,
+            1,
+            TERRA_USER,
+            EVM_ALICE,
+            ERC20,
+            500_000,
+            3,
+        )?,
+        v(
+            "terra_to_evm_cw20_to_erc20",
+            2,
+            1,
+            TERRA_USER,
+            EVM_BOB,
+            ERC20_B,
+            2_500_000,
+            7,
+        )?,
+        v(
+            "terra_to_solana_full_pubkey_dest",
+            2,
+            5,
+            TERRA_USER,
+            SOLANA_KEY,
+            SOLANA_MINT,
+            500_000,
+            99,
+        )?,
+        // Edge cases
+        v(
+            "solana_to_evm_full_pubkey_src",
+            5,
+            1,
+            SOLANA_KEY,
+            EVM_ALICE,
+            ERC20,
+            1,
+            0,
+        )?,
+        v("all_zero", 0, 0, ZERO, ZERO, ZERO, 0, 0)?,
+        v(
+            "native_token_zero_address",
+            56,
+            1,
+            EVM_ALICE,
+            EVM_BOB,
+            ZERO,
+            10_000_000_000_000_000,
+            1,
+        )?,
+        v(
+            "max_amount_max_nonce",
+            0x0102_0304,
+            0x0506_0708,
+            ZERO,
+            ZERO,
+            ZERO,
+            u128::MAX,
+            u64::MAX,
+        )?,
+        v(
+            "amount_above_u64",
+            1,
+            5,
+            EVM_ALICE,
+            SOLANA_KEY,
+            SOLANA_MINT,
+            u64::MAX as u128 + 1,
+            1 << 32,
+        )?,
+        v(
+            "max_chain_ids_all_ones",
+            u32::MAX,
+            u32::MAX,
+            ONES,
+            ONES,
+            ONES,
+            u128::MAX,
+            u64::MAX,
+        )?,
+        v(
+            "evm_to_bitcoin_p2tr_dest",
+            1,
+            8,
+            EVM_ALICE,
+            BTC_P2TR,
+            ZERO,
+            100_000,
+            12,
+        )?,
+    ])
+}
+
+fn address_vector(name: &str, addr: &UniversalAddress, hrp: Option<&str>) -> Result<AddressVector> {
+    let address = match addr.chain_type {
+        CHAIN_TYPE_EVM => addr.to_evm_string()?,
+        CHAIN_TYPE_COSMOS => addr.to_cosmos_string(hrp.unwrap_or("terra"))?,
+        CHAIN_TYPE_SOLANA => addr.to_solana_string()?,
+        CHAIN_TYPE_BITCOIN => addr.to_bitcoin_string(hrp.unwrap_or("bc"))?,
+        other => return Err(eyre!("{}: unknown chain type {}", name, other)),
+    };
+    Ok(AddressVector {
+        name: name.to_string(),
+        chain_type: addr.chain_type,
+        address,
+        hrp: hrp.map(str::to_string),
+        raw: to_hex(addr.raw_address_bytes()),
+        reserved: to_hex(&addr.reserved),
+        bytes32: to_hex(&addr.to_bytes32()),
+        hash_bytes: to_hex(&addr.to_hash_bytes()),
+        bytes: to_hex(&addr.to_bytes()),
+    })
+}
+
+fn address_vectors() -> Result<Vec<AddressVector>> {
+    let v = address_vector;
+    let evm = UniversalAddress::from_evm;
+    Ok(vec![
+        v(
+            "evm_hardhat_account_0",
+            &evm("0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266")?,
+            None,
+        )?,
+        v(
+            "evm_native_token_zero_address",
+            &evm("0x0000000000000000000000000000000000000000")?,
+            None,
+        )?,
+        v(
+            "evm_max_address",
+            &evm("0xffffffffffffffffffffffffffffffffffffffff")?,
+            None,
+        )?,
+        v(
+            "evm_nonzero_reserved",
+            &UniversalAddress::new_with_reserved(
+                CHAIN_TYPE_EVM,
+                *evm("0x70997970c51812dc3a010c7d01b50e0d17dc79c8")?.raw_address_20()?,
+                [1, 2, 3, 4, 5, 6, 7, 8],
+            )?,
+            None,
+        )?,
+        v(
+            "cosmos_terra_wallet",
+            &UniversalAddress::from_cosmos("terra1x46rqay4d3cssq8gxxvqz8xt6nwlz4td20k38v")?,
+            Some("terra"),
+        )?,
+        v("solana_sequential_pubkey", &solana(|i| i as u8 + 1)?, None)?,
+        v("solana_system_program_zero_key", &solana(|_| 0)?, None)?,
+        v("solana_max_pubkey", &solana(|_| 0xff)?, None)?,
+        v(
+            "bitcoin_p2wpkh",
+            &UniversalAddress::from_bitcoin("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4")?,
+            Some("bc"),
+        )?,
+        v(
+            "bitcoin_p2wsh_testnet",
+            &UniversalAddress::from_bitcoin(
+                "tb1qrp33g0q5c5txsp9arysrx4k6zdkfs4nce4xj0gdcccefvpysxf3q0sl5k7",
+            )?,
+            Some("tb"),
+        )?,
+        v(
+            "bitcoin_p2tr",
+            &UniversalAddress::from_bitcoin(
+                "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0",
+            )?,
+            Some("bc"),
+        )?,
+    ])
+}
+
+fn solana(byte: impl Fn(usize) -> u8) -> Result<UniversalAddress> {
+    let mut pubkey = [0u8; 32];
+    for (i, b) in pubkey.iter_mut().enumerate() {
+        *b = byte(i);
+    }
+    UniversalAddress::from_solana(&pubkey)
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+fn hex_array<const N: usize>(s: &str) -> Result<[u8; N]> {
+    let mut out = [0u8; N];
+    hex::decode_to_slice(s.trim_start_matches("0x"), &mut out)
+        .wrap_err_with(|| format!("expected {} hex bytes, got {}", N, s))?;
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generated_corpus_verifies_and_roundtrips_json() {
+        let corpus = Corpus::generate().unwrap();
+        corpus.verify().unwrap();
+        let parsed = Corpus::from_json(&corpus.to_json().unwrap()).unwrap();
+        assert_eq!(parsed, corpus);
+    }
+
+    #[test]
+    fn rejects_unknown_version() {
+        let mut corpus = Corpus::generate().unwrap();
+        corpus.version = CORPUS_VERSION + 1;
+        let json = serde_json::to_string(&corpus).unwrap();
+        assert!(Corpus::from_json(&json).is_err());
+    }
+
+    #[test]
+    fn verify_catches_drifted_vector() {
+        let mut corpus = Corpus::generate().unwrap();
+        corpus.xchain_hash[0].nonce = "43".to_string();
+        assert!(corpus.verify().is_err());
+    }
+
+    #[test]
+    fn fuzz_seed_matches_target_layout() {
+        let corpus = Corpus::generate().unwrap();
+        let seed = corpus.xchain_hash[0].fuzz_seed().unwrap();
+        assert_eq!(seed.len(), FUZZ_SEED_LEN);
+        assert_eq!(&seed[0..4], &1u32.to_be_bytes());
+        assert_eq!(&seed[FUZZ_SEED_LEN - 8..], &42u64.to_be_bytes());
+    }
+}
@@ -36,6 +36,13 @@ sol! {
         /// Must include srcAccount and destAccount for hash computation
         function withdrawSubmit(bytes4 srcChain, bytes32 srcAccount, bytes32 destAccount, address token, uint256 amount, uint64 nonce) external payable;
 
+        /// Depositor asks operators to submit the withdrawal on the destination chain
+        function requestRelay(bytes32 xchainHashId, uint256 maxRelayFee) external;
+
+        /// Operator submits a withdrawal for a relay-requested deposit, taking `relayFee`
+        /// (source decimals) out of the amount on execution
+        function withdrawSubmitRelayed(bytes4 srcChain, bytes32 srcAccount, bytes32 destAccount, address token, uint256 amount, uint64 nonce, uint256 relayFee) external;
+
         /// Operator approves a pending withdrawal
         function withdrawApprove(bytes32 xchainHashId) external;
 
@@ -123,6 +130,15 @@ sol! {
         /// Get this chain's registered chain ID
         function getThisChainId() external view returns (bytes4);
 
+        /// Max relay fee a depositor accepts for a deposit made here (0 = not requested)
+        function relayRequests(bytes32 xchainHashId) external view returns (uint256);
+
+        /// Relayer and relay fee of an operator-submitted withdrawal (zero if user-submitted)
+        function getWithdrawRelay(bytes32 xchainHashId) external view returns (address relayer, uint256 fee);
+
+        /// Relay fee cap in basis points of the withdrawn amount (0 = relaying disabled)
+        function relayFeeCapBps() external view returns (uint256);
+
         /// Enumerate all pending (submitted, not yet executed/cancelled) withdrawal hashes
         function getPendingWithdrawHashes() external view returns (bytes32[] memory hashes);
 
@@ -135,12 +151,24 @@ sol! {
         /// Check if address is a canceler
         function isCanceler(address account) external view returns (bool);
 
+        /// Wrapped native token that `depositNative` deposits are bridged as
+        function wrappedNative() external view returns (address);
+
         /// Get the TokenRegistry contract address
         function tokenRegistry() external view returns (address);
 
         /// Get the ChainRegistry contract address
         function chainRegistry() external view returns (address);
 
+        /// Current fee parameters
+        function feeConfig() external view returns (
+            uint256 standardFeeBps,
+            uint256 discountedFeeBps,
+            uint256 cl8yThreshold,
+            address cl8yToken,
+            address feeRecipient
+        );
+
         // ========================================================================
         // Events (V2)
         // ========================================================================
@@ -182,6 +210,15 @@ sol! {
         /// Withdraw execute - withdrawal completed
         event WithdrawExecute(bytes32 indexed xchainHashId, address recipient, uint256 amount);
 
+        /// Relay requested - depositor opts in to an operator-submitted withdrawal
+        event RelayRequested(bytes32 indexed xchainHashId, bytes4 indexed destChain, uint256 maxRelayFee);
+
+        /// Withdraw relayed - operator submitted the withdrawal on the recipient's behalf
+        event WithdrawRelayed(bytes32 indexed xchainHashId, address indexed relayer, uint256 relayFee);
+
+        /// Relay fee paid to the submitting operator on execution
+        event RelayFeePaid(bytes32 indexed xchainHashId, address indexed relayer, uint256 amount);
+
         /// Fee collected
         event FeeCollected(address indexed token, uint256 amount, address recipient);
 
@@ -389,6 +426,18 @@ sol! {
         /// Set token type
         function setTokenType(address token, uint8 tokenType) external;
 
+        /// Set incoming token mapping (decimals of the token on the source chain)
+        function setIncomingTokenMapping(bytes4 srcChain, address localToken, uint8 srcDecimals) external;
+
+        /// Set per-token rate limits (0 = unlimited / no minimum)
+        function setRateLimit(address token, uint256 minPerTransaction, uint256 maxPerTransaction, uint256 maxPerPeriod) external;
+
+        /// Get rate limit config for a token
+        function getRateLimitConfig(address token) external view returns (uint256 minPerTransaction, uint256 maxPerTransaction, uint256 maxPerPeriod);
+
+        /// Get all enabled incoming mappings from a source chain
+        function getAllIncomingMappings(bytes4 srcChain) external view returns (address[] memory tokens, uint8[] memory srcDecimals);
+
         /// Get token type
         function getTokenType(address token) external view returns (uint8 tokenType);
 
@@ -66,6 +66,18 @@ impl EvmQueryClient {
         Ok(result._0)
     }
 
+    /// Get the TokenRegistry contract address from the bridge
+    pub async fn get_token_registry_address(&self) -> Result<Address> {
+        let bridge = Bridge::new(self.bridge_address, &self.provider);
+        let result = bridge
+            .tokenRegistry()
+            .call()
+            .await
+            .map_err(|e| eyre!("Failed to get token registry address: {}", e))?;
+
+        Ok(result._0)
+    }
+
     /// Get this chain's registered 4-byte chain ID
     pub async fn get_this_chain_id(&self) -> Result<ChainId> {
         let bridge = Bridge::new(self.bridge_address, &self.provider);
@@ -156,6 +168,27 @@ impl EvmQueryClient {
         })
     }
 
+    /// Get the deposit record for a hash (`timestamp` is zero when no deposit exists)
+    pub async fn get_deposit(&self, xchain_hash_id: [u8; 32]) -> Result<DepositInfo> {
+        let bridge = Bridge::new(self.bridge_address, &self.provider);
+        let result = bridge
+            .getDeposit(FixedBytes(xchain_hash_id))
+            .call()
+            .await
+            .map_err(|e| eyre!("Failed to get deposit: {}", e))?;
+
+        Ok(DepositInfo {
+            dest_chain: ChainId::from_bytes(result.destChain.0),
+            src_account: result.srcAccount.0,
+            dest_account: result.destAccount.0,
+            token: result.token,
+            amount: result.amount,
+            nonce: result.nonce,
+            fee: result.fee,
+            timestamp: result.timestamp,
+        })
+    }
+
     /// Calculate fee for a deposit amount
     pub async fn calculate_fee(&self, depositor: Address, amount: U256) -> Result<U256> {
         let bridge = Bridge::new(self.bridge_address, &self.provider);
@@ -496,6 +529,20 @@ pub struct PendingWithdrawInfo {
     pub executed: bool,
 }
 
+/// Deposit record from the bridge contract (`getDeposit`)
+#[derive(Debug, Clone)]
+pub struct DepositInfo {
+    pub dest_chain: ChainId,
+    pub src_account: [u8; 32],
+    pub dest_account: [u8; 32],
+    /// Source-chain token that was locked or burned
+    pub token: Address,
+    pub amount: U256,
+    pub nonce: u64,
+    pub fee: U256,
+    pub timestamp: U256,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -24,8 +24,9 @@ use tracing::warn;
 
 // Re-export address codec functions for convenience
 pub use crate::address_codec::{
-    decode_bech32_address, decode_bech32_address_raw, encode_bech32_address, encode_evm_address,
-    parse_evm_address,
+    decode_bech32_address, decode_bech32_address_raw, decode_bitcoin_address,
+    encode_bech32_address, encode_bitcoin_address, encode_evm_address, parse_evm_address,
+    BitcoinAddressKind,
 };
 
 /// Compute keccak256 hash of data
@@ -194,6 +195,37 @@ pub fn decode_bytes32_to_terra_address(bytes: &[u8]) -> Result<String, String> {
     encode_bech32_address(&raw, "terra").map_err(|e| e.to_string())
 }
 
+/// Encode a Bitcoin segwit or taproot address to bytes32 for transfer hashes
+///
+/// Matches `UniversalAddress::to_hash_bytes`: P2WPKH programs are left-padded
+/// to 32 bytes, P2WSH and P2TR programs are used directly. The witness version
+/// is not encoded, so the destination route must fix the output type.
+pub fn encode_bitcoin_address_to_bytes32(addr: &str) -> Result<[u8; 32], String> {
+    crate::address_codec::UniversalAddress::from_bitcoin(addr)
+        .map(|a| a.to_hash_bytes())
+        .map_err(|e| e.to_string())
+}
+
+/// Decode bytes32 to a Bitcoin address of the given output type
+///
+/// P2WPKH requires the 12 leading bytes to be zero (left-padded program).
+pub fn decode_bytes32_to_bitcoin_address(
+    bytes: &[u8; 32],
+    kind: BitcoinAddressKind,
+    hrp: &str,
+) -> Result<String, String> {
+    let program = match kind {
+        BitcoinAddressKind::P2wpkh => {
+            if bytes[..12] != [0u8; 12] {
+                return Err("P2WPKH bytes32 must be left-padded with 12 zero bytes".to_string());
+            }
+            &bytes[12..]
+        }
+        BitcoinAddressKind::P2wsh | BitcoinAddressKind::P2tr => &bytes[..],
+    };
+    encode_bitcoin_address(kind, program, hrp).map_err(|e| e.to_string())
+}
+
 /// Convert bytes to hex string with 0x prefix
 pub fn bytes32_to_hex(bytes: &[u8; 32]) -> String {
     let mut hex = String::with_capacity(66);
@@ -337,6 +369,46 @@ mod tests {
         assert_eq!(bytes4_to_hex(&bytes2), "0x12345678");
     }
 
+    #[test]
+    fn test_bitcoin_address_bytes32_roundtrip() {
+        let cases = [
+            (
+                "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+                BitcoinAddressKind::P2wpkh,
+                "0x000000000000000000000000751e76e8199196d454941c45d1b3a323f1433bd6",
+            ),
+            (
+                "bc1qrp33g0q5c5txsp9arysrx4k6zdkfs4nce4xj0gdcccefvpysxf3qccfmv3",
+                BitcoinAddressKind::P2wsh,
+                "0x1863143c14c5166804bd19203356da136c985678cd4d27a1b8c6329604903262",
+            ),
+            (
+                "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0",
+                BitcoinAddressKind::P2tr,
+                "0x79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+            ),
+        ];
+        for (addr, kind, expected) in cases {
+            let bytes32 = encode_bitcoin_address_to_bytes32(addr).unwrap();
+            assert_eq!(bytes32_to_hex(&bytes32), expected);
+            assert_eq!(
+                decode_bytes32_to_bitcoin_address(&bytes32, kind, "bc").unwrap(),
+                addr
+            );
+        }
+    }
+
+    #[test]
+    fn test_decode_bytes32_to_bitcoin_p2wpkh_requires_left_padding() {
+        let bytes32 = [0xab; 32];
+        assert!(
+            decode_bytes32_to_bitcoin_address(&bytes32, BitcoinAddressKind::P2wpkh, "bc").is_err()
+        );
+        assert!(
+            decode_bytes32_to_bitcoin_address(&bytes32, BitcoinAddressKind::P2tr, "bc").is_ok()
+        );
+    }
+
     // ================================================================
     // Cross-Chain Token Encoding Parity Tests
     // (uluna native ↔ ERC20 and CW20 ↔ ERC20)
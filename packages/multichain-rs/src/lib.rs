@@ -4,10 +4,14 @@
 //!
 //! - **Address Encoding/Decoding** - Universal address codec for EVM and Cosmos chains
 //! - **Hash Computation** - Deposit/withdraw hash computation matching contract logic
+//! - **Conformance** - Shared hash/codec vector corpus (backs the `cl8y-conformance` binary)
 //! - **Types** - Shared types like ChainId, UniversalAddress, FeeParams, ChainRegistration
 //! - **EVM Module** - EVM client, contract bindings, event parsing, signing, watching
 //! - **Terra Module** - Terra client, contract messages, event parsing, signing, queries
 //! - **Testing Module** - Helpers for E2E tests (user EOA simulation, assertions)
+//! - **Chains Module** - Read-only bridge connections shared by the tracer, auditor and registries
+//! - **Trace Module** - Follow one transfer across chains (backs the `cl8y-trace` binary)
+//! - **Audit Module** - Cross-chain solvency and supply reconciliation (backs the `cl8y-audit` binary)
 //!
 //! ## Usage
 //!
@@ -21,10 +25,17 @@
 //! - `evm` - Enable EVM chain support (default)
 //! - `terra` - Enable Terra chain support (default)
 //! - `testing` - Enable testing utilities for E2E tests
+//! - `chains` - Enable the bridge connections and the transfer tracer library (EVM, Terra and
+//!   Solana)
+//! - `trace` - Enable the `cl8y-trace` binary (adds `chains` and the CLI dependencies)
+//! - `audit` - Enable the solvency auditor and the `cl8y-audit` binary (adds Prometheus metrics)
+//! - `token-cache` - Enable the shared token metadata cache (decimals, mode, symbol, mappings)
+//! - `conformance` - Enable the `cl8y-conformance` corpus generator binary
 //! - `full` - Enable all features
 
 // Core modules (always available)
 pub mod address_codec;
+pub mod conformance;
 pub mod discovery;
 pub mod hash;
 pub mod multi_evm;
@@ -50,6 +61,30 @@ pub mod terra;
 #[cfg(feature = "solana")]
 pub mod solana;
 
+// Read-only bridge connections across all chain families (feature-gated)
+#[cfg(feature = "chains")]
+pub mod chains;
+
+// Transfer tracing library; the `trace` feature adds the cl8y-trace CLI (feature-gated)
+#[cfg(feature = "chains")]
+pub mod trace;
+
+// Cross-chain solvency auditing (feature-gated)
+#[cfg(feature = "audit")]
+pub mod audit;
+
+// Declarative chain/token registry topology (feature-gated)
+#[cfg(feature = "topology")]
+pub mod topology;
+
+// Cross-chain token mapping consistency (feature-gated)
+#[cfg(feature = "mappings")]
+pub mod mappings;
+
+// Shared token metadata cache (feature-gated)
+#[cfg(feature = "token-cache")]
+pub mod token_cache;
+
 // Testing utilities (feature-gated)
 #[cfg(feature = "testing")]
 pub mod testing;
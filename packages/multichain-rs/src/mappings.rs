@@ -0,0 +1,1182 @@
+//! Cross-Chain Token Mapping Consistency
+//!
+//! Every bridge keeps its own half of each route: EVM `TokenRegistry` destination and incoming
+//! mappings, Terra `TOKEN_DEST_MAPPINGS` / `TOKEN_SRC_MAPPINGS`, Solana `TokenMapping` PDAs.
+//! [`MappingChecker`] enumerates all of them on every configured bridge and checks that the
+//! halves agree; it backs the `cl8y-mapping-check` binary and the operator's startup check.
+//!
+//! | Check | Finding |
+//! |-------|---------|
+//! | A maps `x` to `y` on B, and B maps `y` back to `x` on A | `unknown_remote_token`, `missing_reverse`, `round_trip_mismatch` |
+//! | Each side can both send and receive on the route | `missing_outgoing`, `missing_incoming`, `duplicate_route` |
+//! | Decimals A records for `y` equal B's own decimals for `y` | `decimals_mismatch` |
+//! | Native denoms are not mint/burn; every linked token group has a lock leg | `native_mint_burn`, `no_lock_leg` |
+//! | `min ≤ max/tx ≤ max/period`, and some amount passes both sides' limits | `rate_limit_order`, `rate_limit_disjoint` |
+//!
+//! Tokens are named as the operator's writers name a destination token: EVM `0x` + lowercase
+//! hex address, Terra denom or CW20 address, Solana base58 mint (native SOL is the all-zero
+//! key). [`MappingReport::refused_routes`] lists the (source chain, destination chain,
+//! destination token) triples no transfer should be approved on.
+
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+use std::sync::Arc;
+
+use alloy::primitives::{Address, FixedBytes, U256};
+use base64::Engine;
+use eyre::{eyre, Result, WrapErr};
+use serde_json::{json, Value};
+use solana_account_decoder_client_types::UiAccountEncoding;
+use solana_client::rpc_client::RpcClient;
+use solana_client::rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
+use solana_client::rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType};
+use solana_sdk::pubkey::Pubkey;
+use tracing::warn;
+
+use crate::chains::{
+    blocking, decode_bytes32, decode_chain_id, now_secs, Backend, ChainKind, TraceChain,
+    TraceEndpoint,
+};
+use crate::evm::contracts::{TokenRegistry, ERC20};
+use crate::evm::EvmQueryClient;
+use crate::hash::{address_to_bytes32, encode_terra_token_address};
+use crate::solana::{
+    anchor_discriminator, parse_token_mapping, parse_withdraw_rate_limit, withdraw_rate_limit_pda,
+    TOKEN_MAPPING_ACCOUNT_LEN,
+};
+use crate::terra::contracts::{
+    AllRateLimitsResponse, AllTokenDestMappingsResponse, IncomingTokenMappingsResponse, QueryMsg,
+    TokensResponse,
+};
+use crate::terra::TerraQueryClient;
+use crate::types::{ChainId, RateLimit, TokenMode};
+
+const B64: base64::engine::GeneralPurpose = base64::engine::general_purpose::STANDARD;
+
+/// Terra `tokens` page size (contract maximum)
+const TERRA_TOKENS_PAGE: u32 = 30;
+
+/// Terra enumeration page size (contract maximum)
+const TERRA_ENUM_PAGE: u32 = 100;
+
+/// Solana accounts per `getMultipleAccounts` request
+const SOLANA_ACCOUNTS_PER_REQUEST: usize = 100;
+
+// ============================================================================
+// Snapshot
+// ============================================================================
+
+/// One side of a route as the local bridge records it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenRoute {
+    pub remote_chain: ChainId,
+    /// Destination token and its decimals, for deposits to `remote_chain`
+    pub outgoing: Option<([u8; 32], u8)>,
+    /// Decimals of the source token, for withdrawals from `remote_chain`
+    pub incoming_decimals: Option<u8>,
+    pub mode: TokenMode,
+}
+
+/// A token registered on one bridge, with every route it has
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalToken {
+    /// Name the operator's writers use for it (see the module docs)
+    pub token: String,
+    /// Token ID other chains map to (hashed with [`encode_terra_token_address`] on Terra)
+    pub id: [u8; 32],
+    /// Own decimals, when readable
+    pub decimals: Option<u8>,
+    /// Terra native denom or native SOL: cannot be minted by the bridge
+    pub native: bool,
+    /// Explicitly configured limits
+    pub rate_limit: Option<RateLimit>,
+    pub routes: Vec<TokenRoute>,
+}
+
+/// Everything one bridge records about its tokens and routes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainMappings {
+    pub chain: ChainId,
+    pub kind: ChainKind,
+    pub tokens: Vec<LocalToken>,
+}
+
+impl ChainMappings {
+    fn token_by_id(&self, id: &[u8; 32]) -> Option<&LocalToken> {
+        self.tokens.iter().find(|t| t.id == *id)
+    }
+}
+
+impl LocalToken {
+    fn route_to(&self, chain: ChainId) -> Option<&TokenRoute> {
+        self.routes.iter().find(|r| r.remote_chain == chain)
+    }
+
+    /// Tokens without routes record no mode and count as possibly holding custody
+    fn may_hold_custody(&self) -> bool {
+        self.routes.is_empty() || self.routes.iter().any(|r| r.mode == TokenMode::LockUnlock)
+    }
+}
+
+// ============================================================================
+// Report
+// ============================================================================
+
+/// What a finding says is wrong
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FindingKind {
+    /// The destination token does not exist on the remote bridge
+    UnknownRemoteToken,
+    /// The remote token has no route back to this chain
+    MissingReverse,
+    /// The remote token maps back to a different token on this chain
+    RoundTripMismatch,
+    /// Withdrawals from the remote chain are accepted, deposits to it are not
+    MissingOutgoing,
+    /// Deposits to the remote chain are accepted, withdrawals from it are not
+    MissingIncoming,
+    /// The token has more than one mapping to the same chain
+    DuplicateRoute,
+    /// Recorded decimals differ from the remote token's own decimals
+    DecimalsMismatch,
+    /// A native denom registered as mint/burn
+    NativeMintBurn,
+    /// Every token linked to this one is mint/burn: nothing holds the canonical supply
+    NoLockLeg,
+    /// `min > max/tx` or `max/tx > max/period`
+    RateLimitOrder,
+    /// No amount passes the limits of both sides
+    RateLimitDisjoint,
+}
+
+impl fmt::Display for FindingKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            FindingKind::UnknownRemoteToken => "unknown_remote_token",
+            FindingKind::MissingReverse => "missing_reverse",
+            FindingKind::RoundTripMismatch => "round_trip_mismatch",
+            FindingKind::MissingOutgoing => "missing_outgoing",
+            FindingKind::MissingIncoming => "missing_incoming",
+            FindingKind::DuplicateRoute => "duplicate_route",
+            FindingKind::DecimalsMismatch => "decimals_mismatch",
+            FindingKind::NativeMintBurn => "native_mint_burn",
+            FindingKind::NoLockLeg => "no_lock_leg",
+            FindingKind::RateLimitOrder => "rate_limit_order",
+            FindingKind::RateLimitDisjoint => "rate_limit_disjoint",
+        })
+    }
+}
+
+/// One inconsistency, on a token or on one of its routes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MappingFinding {
+    pub kind: FindingKind,
+    pub chain: ChainId,
+    pub token: String,
+    /// `None` when the finding concerns every route of the token
+    pub remote_chain: Option<ChainId>,
+    pub detail: String,
+}
+
+impl fmt::Display for MappingFinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", self.kind, self.chain.to_hex(), self.token)?;
+        if let Some(remote) = self.remote_chain {
+            write!(f, " <-> {}", remote.to_hex())?;
+        }
+        write!(f, ": {}", self.detail)
+    }
+}
+
+/// A transfer direction that must not be approved
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RefusedRoute {
+    pub src_chain: ChainId,
+    pub dest_chain: ChainId,
+    /// Destination token, named as in [`LocalToken::token`]
+    pub dest_token: String,
+}
+
+/// Result of one consistency check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MappingReport {
+    /// Unix seconds
+    pub timestamp: u64,
+    pub chains: Vec<(ChainId, ChainKind)>,
+    /// Routes whose both sides were compared
+    pub routes_checked: usize,
+    /// Routes to chains outside the configuration, not compared
+    pub routes_unchecked: usize,
+    pub findings: Vec<MappingFinding>,
+    refused: Vec<RefusedRoute>,
+}
+
+impl MappingReport {
+    /// Transfer directions affected by a finding
+    pub fn refused_routes(&self) -> impl Iterator<Item = &RefusedRoute> {
+        self.refused.iter()
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "timestamp": self.timestamp,
+            "chains": self.chains.iter().map(|(id, kind)| json!({
+                "chain": id.to_hex(),
+                "kind": kind.to_string(),
+            })).collect::<Vec<_>>(),
+            "routes_checked": self.routes_checked,
+            "routes_unchecked": self.routes_unchecked,
+            "findings": self.findings.iter().map(|f| json!({
+                "kind": f.kind.to_string(),
+                "chain": f.chain.to_hex(),
+                "token": f.token,
+                "remote_chain": f.remote_chain.map(|c| c.to_hex()),
+                "detail": f.detail,
+            })).collect::<Vec<_>>(),
+            "refused_routes": self.refused.iter().map(|r| json!({
+                "src_chain": r.src_chain.to_hex(),
+                "dest_chain": r.dest_chain.to_hex(),
+                "dest_token": r.dest_token,
+            })).collect::<Vec<_>>(),
+        })
+    }
+}
+
+impl fmt::Display for MappingReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} route(s) checked, {} to unconfigured chains skipped",
+            self.routes_checked, self.routes_unchecked
+        )?;
+        if self.findings.is_empty() {
+            return writeln!(f, "No findings");
+        }
+        for finding in &self.findings {
+            writeln!(f, "FINDING {}", finding)?;
+        }
+        for r in &self.refused {
+            writeln!(
+                f,
+                "REFUSED {} -> {} {}",
+                r.src_chain.to_hex(),
+                r.dest_chain.to_hex(),
+                r.dest_token
+            )?;
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Checks
+// ============================================================================
+
+/// Compare every route recorded in `chains` with its other side
+pub fn check_mappings(chains: &[ChainMappings]) -> MappingReport {
+    let find = |id: ChainId| chains.iter().find(|c| c.chain == id);
+    let mut findings = Vec::new();
+    let mut routes_checked = 0;
+    let mut routes_unchecked = 0;
+    let finding =
+        |kind, chain: &ChainMappings, token: &LocalToken, remote, detail| MappingFinding {
+            kind,
+            chain: chain.chain,
+            token: token.token.clone(),
+            remote_chain: remote,
+            detail,
+        };
+
+    for chain in chains {
+        for token in &chain.tokens {
+            if token.native && token.routes.iter().any(|r| r.mode == TokenMode::MintBurn) {
+                findings.push(finding(
+                    FindingKind::NativeMintBurn,
+                    chain,
+                    token,
+                    None,
+                    "native denoms cannot be minted by the bridge".into(),
+                ));
+            }
+            if let Some(limit) = token.rate_limit.filter(|l| !limit_ordered(l)) {
+                findings.push(finding(
+                    FindingKind::RateLimitOrder,
+                    chain,
+                    token,
+                    None,
+                    limit.to_string(),
+                ));
+            }
+
+            for (i, route) in token.routes.iter().enumerate() {
+                let remote_id = route.remote_chain;
+                if token.routes[..i]
+                    .iter()
+                    .any(|r| r.remote_chain == remote_id)
+                {
+                    findings.push(finding(
+                        FindingKind::DuplicateRoute,
+                        chain,
+                        token,
+                        Some(remote_id),
+                        "more than one mapping to this chain".into(),
+                    ));
+                    continue;
+                }
+                let Some(remote) = find(remote_id) else {
+                    routes_unchecked += 1;
+                    continue;
+                };
+                routes_checked += 1;
+                let mut push = |kind, detail: String| {
+                    findings.push(finding(kind, chain, token, Some(remote_id), detail))
+                };
+
+                match (route.outgoing, route.incoming_decimals) {
+                    (None, Some(_)) => push(
+                        FindingKind::MissingOutgoing,
+                        "withdrawals accepted but no destination mapping".into(),
+                    ),
+                    (Some(_), None) => push(
+                        FindingKind::MissingIncoming,
+                        "destination mapped but withdrawals not accepted".into(),
+                    ),
+                    _ => {}
+                }
+
+                // The remote token: where deposits go, else whichever token maps back here
+                let remote_token = match route.outgoing {
+                    Some((id, decimals)) => match remote.token_by_id(&id) {
+                        Some(remote_token) => {
+                            if let Some(own) = remote_token.decimals.filter(|own| *own != decimals)
+                            {
+                                push(
+                                    FindingKind::DecimalsMismatch,
+                                    format!(
+                                        "destination {} recorded with {} decimals, has {}",
+                                        remote_token.token, decimals, own
+                                    ),
+                                );
+                            }
+                            match remote_token.route_to(chain.chain) {
+                                None => push(
+                                    FindingKind::MissingReverse,
+                                    format!("{} has no route back", remote_token.token),
+                                ),
+                                Some(back) => match back.outgoing {
+                                    Some((back_id, _)) if back_id != token.id => push(
+                                        FindingKind::RoundTripMismatch,
+                                        format!(
+                                            "{} maps back to {}",
+                                            remote_token.token,
+                                            name_by_id(chain, &back_id)
+                                        ),
+                                    ),
+                                    _ => {}
+                                },
+                            }
+                            Some(remote_token)
+                        }
+                        None => {
+                            push(
+                                FindingKind::UnknownRemoteToken,
+                                format!("0x{} is not registered there", hex::encode(id)),
+                            );
+                            None
+                        }
+                    },
+                    None => remote.tokens.iter().find(|t| {
+                        t.route_to(chain.chain)
+                            .and_then(|r| r.outgoing)
+                            .is_some_and(|(id, _)| id == token.id)
+                    }),
+                };
+                let Some(remote_token) = remote_token else {
+                    continue;
+                };
+
+                if let (Some(recorded), Some(own)) =
+                    (route.incoming_decimals, remote_token.decimals)
+                {
+                    if recorded != own {
+                        push(
+                            FindingKind::DecimalsMismatch,
+                            format!(
+                                "source {} recorded with {} decimals, has {}",
+                                remote_token.token, recorded, own
+                            ),
+                        );
+                    }
+                }
+
+                // Reported once per pair, from the lower chain ID
+                if chain.chain.to_u32() < remote_id.to_u32() {
+                    if let (Some(local), Some(far)) = (
+                        token.rate_limit.zip(token.decimals),
+                        remote_token.rate_limit.zip(remote_token.decimals),
+                    ) {
+                        if limits_disjoint(local, far) {
+                            push(
+                                FindingKind::RateLimitDisjoint,
+                                format!(
+                                    "{} ({}) vs {} on {} ({})",
+                                    local.0, local.1, far.0, remote_token.token, far.1
+                                ),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    findings.extend(lockless_groups(chains));
+    findings.sort_by(|a, b| {
+        (
+            a.chain.to_u32(),
+            &a.token,
+            a.remote_chain.map(|c| c.to_u32()),
+            a.kind,
+        )
+            .cmp(&(
+                b.chain.to_u32(),
+                &b.token,
+                b.remote_chain.map(|c| c.to_u32()),
+                b.kind,
+            ))
+    });
+    findings.dedup();
+
+    let refused = refused_routes(chains, &findings);
+    MappingReport {
+        timestamp: now_secs(),
+        chains: chains.iter().map(|c| (c.chain, c.kind)).collect(),
+        routes_checked,
+        routes_unchecked,
+        findings,
+        refused,
+    }
+}
+
+fn name_by_id(chain: &ChainMappings, id: &[u8; 32]) -> String {
+    chain
+        .token_by_id(id)
+        .map(|t| t.token.clone())
+        .unwrap_or_else(|| format!("0x{}", hex::encode(id)))
+}
+
+fn limit_ordered(limit: &RateLimit) -> bool {
+    let min_ok =
+        limit.max_per_transaction == 0 || limit.min_per_transaction <= limit.max_per_transaction;
+    let max_ok = limit.max_per_transaction == 0
+        || limit.max_per_period == 0
+        || limit.max_per_transaction <= limit.max_per_period;
+    min_ok && max_ok
+}
+
+/// Whether no amount lies within `[min, max/tx]` on both sides, compared at the larger
+/// precision
+fn limits_disjoint(a: (RateLimit, u8), b: (RateLimit, u8)) -> bool {
+    let decimals = a.1.max(b.1);
+    let scale = |v: u128, d: u8| {
+        10u128
+            .checked_pow(u32::from(decimals - d))
+            .and_then(|s| v.checked_mul(s))
+            .unwrap_or(u128::MAX)
+    };
+    let window = |(l, d): (RateLimit, u8)| {
+        let max = match l.max_per_transaction {
+            0 => u128::MAX,
+            max => scale(max, d),
+        };
+        (scale(l.min_per_transaction, d), max)
+    };
+    let (a_min, a_max) = window(a);
+    let (b_min, b_max) = window(b);
+    a_min.max(b_min) > a_max.min(b_max)
+}
+
+/// Groups of tokens linked by routes in which no leg holds custody
+fn lockless_groups(chains: &[ChainMappings]) -> Vec<MappingFinding> {
+    let nodes: Vec<(&ChainMappings, &LocalToken)> = chains
+        .iter()
+        .flat_map(|c| c.tokens.iter().map(move |t| (c, t)))
+        .collect();
+    let index: HashMap<(u32, [u8; 32]), usize> = nodes
+        .iter()
+        .enumerate()
+        .map(|(i, (c, t))| ((c.chain.to_u32(), t.id), i))
+        .collect();
+
+    let mut parent: Vec<usize> = (0..nodes.len()).collect();
+    fn root(parent: &mut [usize], mut i: usize) -> usize {
+        while parent[i] != i {
+            parent[i] = parent[parent[i]];
+            i = parent[i];
+        }
+        i
+    }
+    for (i, (_, token)) in nodes.iter().enumerate() {
+        for route in &token.routes {
+            let Some((id, _)) = route.outgoing else {
+                continue;
+            };
+            if let Some(&j) = index.get(&(route.remote_chain.to_u32(), id)) {
+                let (a, b) = (root(&mut parent, i), root(&mut parent, j));
+                parent[a] = b;
+            }
+        }
+    }
+
+    let mut has_lock: HashMap<usize, bool> = HashMap::new();
+    let mut size: HashMap<usize, usize> = HashMap::new();
+    for (i, (_, token)) in nodes.iter().enumerate() {
+        let r = root(&mut parent, i);
+        *has_lock.entry(r).or_default() |= token.may_hold_custody();
+        *size.entry(r).or_default() += 1;
+    }
+    let mut findings = Vec::new();
+    for (i, (chain, token)) in nodes.iter().enumerate() {
+        let r = root(&mut parent, i);
+        if size[&r] > 1 && !has_lock[&r] {
+            findings.push(MappingFinding {
+                kind: FindingKind::NoLockLeg,
+                chain: chain.chain,
+                token: token.token.clone(),
+                remote_chain: None,
+                detail: format!("all {} linked tokens are mint_burn", size[&r]),
+            });
+        }
+    }
+    findings
+}
+
+/// Both directions of every route a finding touches
+fn refused_routes(chains: &[ChainMappings], findings: &[MappingFinding]) -> Vec<RefusedRoute> {
+    let mut refused = BTreeSet::new();
+    for finding in findings {
+        let Some(chain) = chains.iter().find(|c| c.chain == finding.chain) else {
+            continue;
+        };
+        let Some(token) = chain.tokens.iter().find(|t| t.token == finding.token) else {
+            continue;
+        };
+        for route in &token.routes {
+            if finding
+                .remote_chain
+                .is_some_and(|r| r != route.remote_chain)
+            {
+                continue;
+            }
+            refused.insert((route.remote_chain.0, chain.chain.0, token.token.clone()));
+            if let Some((id, _)) = route.outgoing {
+                let dest_token = chains
+                    .iter()
+                    .find(|c| c.chain == route.remote_chain)
+                    .map(|c| name_by_id(c, &id))
+                    .unwrap_or_else(|| format!("0x{}", hex::encode(id)));
+                refused.insert((chain.chain.0, route.remote_chain.0, dest_token));
+            }
+        }
+    }
+    refused
+        .into_iter()
+        .map(|(src, dest, dest_token)| RefusedRoute {
+            src_chain: ChainId(src),
+            dest_chain: ChainId(dest),
+            dest_token,
+        })
+        .collect()
+}
+
+// ============================================================================
+// Checker
+// ============================================================================
+
+/// Reads the token registries of the configured bridges
+pub struct MappingChecker {
+    chains: Vec<TraceChain>,
+}
+
+impl MappingChecker {
+    /// Connect to every endpoint and resolve the chain ID each bridge reports
+    pub async fn connect(endpoints: &[TraceEndpoint]) -> Result<Self> {
+        let mut chains = Vec::with_capacity(endpoints.len());
+        for endpoint in endpoints {
+            chains.push(TraceChain::connect(endpoint).await?);
+        }
+        Ok(Self { chains })
+    }
+
+    /// Chain ID and family of every configured bridge
+    pub fn chains(&self) -> Vec<(ChainId, ChainKind)> {
+        self.chains.iter().map(|c| (c.id, c.kind())).collect()
+    }
+
+    /// Read every token and route of every bridge
+    pub async fn snapshot(&self) -> Result<Vec<ChainMappings>> {
+        let mut out = Vec::with_capacity(self.chains.len());
+        for chain in &self.chains {
+            out.push(read_chain(chain).await?);
+        }
+        Ok(out)
+    }
+
+    /// Read every token and route of one bridge
+    pub async fn snapshot_chain(&self, chain: ChainId) -> Result<ChainMappings> {
+        let chain = self
+            .chains
+            .iter()
+            .find(|c| c.id == chain)
+            .ok_or_else(|| eyre!("chain {} is not configured", chain.to_hex()))?;
+        read_chain(chain).await
+    }
+
+    pub(crate) fn trace_chains(&self) -> &[TraceChain] {
+        &self.chains
+    }
+
+    /// Snapshot every bridge and check it
+    pub async fn check(&self) -> Result<MappingReport> {
+        Ok(check_mappings(&self.snapshot().await?))
+    }
+}
+
+async fn read_chain(chain: &TraceChain) -> Result<ChainMappings> {
+    let tokens = match &chain.backend {
+        Backend::Evm { client, .. } => read_evm(client, chain.id).await,
+        Backend::Terra(client) => read_terra(client).await,
+        Backend::Solana { client, program_id } => read_solana(client, *program_id).await,
+    }
+    .wrap_err_with(|| format!("reading mappings on chain {}", chain.id.to_hex()))?;
+    Ok(ChainMappings {
+        chain: chain.id,
+        kind: chain.kind(),
+        tokens,
+    })
+}
+
+async fn read_evm(client: &EvmQueryClient, this_chain: ChainId) -> Result<Vec<LocalToken>> {
+    let chain_registry = client.get_chain_registry_address().await?;
+    let token_registry = client.get_token_registry_address().await?;
+    let registry = TokenRegistry::new(token_registry, client.provider());
+
+    let mut incoming: HashMap<(ChainId, Address), u8> = HashMap::new();
+    for remote in client.get_registered_chains(chain_registry).await? {
+        if remote == this_chain {
+            continue;
+        }
+        let all = registry
+            .getAllIncomingMappings(FixedBytes(*remote.as_bytes()))
+            .call()
+            .await
+            .map_err(|e| eyre!("Failed to get incoming mappings: {}", e))?;
+        for (token, decimals) in all.tokens.into_iter().zip(all.srcDecimals) {
+            incoming.insert((remote, token), decimals);
+        }
+    }
+
+    let mut tokens = Vec::new();
+    for address in client.get_all_tokens(token_registry).await? {
+        let mode = match client.get_token_type(token_registry, address).await? {
+            1 => TokenMode::MintBurn,
+            _ => TokenMode::LockUnlock,
+        };
+        let decimals = match ERC20::new(address, client.provider())
+            .decimals()
+            .call()
+            .await
+        {
+            Ok(d) => Some(d._0),
+            Err(e) => {
+                warn!(token = %address, error = %e, "Failed to read ERC20 decimals");
+                None
+            }
+        };
+        let limit = registry
+            .getRateLimitConfig(address)
+            .call()
+            .await
+            .map_err(|e| eyre!("Failed to get rate limit: {}", e))?;
+        let rate_limit = RateLimit {
+            min_per_transaction: u256_to_u128(limit.minPerTransaction)?,
+            max_per_transaction: u256_to_u128(limit.maxPerTransaction)?,
+            max_per_period: u256_to_u128(limit.maxPerPeriod)?,
+        };
+
+        let dest_chains = registry
+            .getTokenDestChains(address)
+            .call()
+            .await
+            .map_err(|e| eyre!("Failed to get destination chains: {}", e))?
+            .destChains;
+        let mut routes: Vec<TokenRoute> = Vec::new();
+        for dest in dest_chains {
+            let mapping = registry
+                .getDestTokenMapping(address, dest)
+                .call()
+                .await
+                .map_err(|e| eyre!("Failed to get destination mapping: {}", e))?;
+            if mapping.destToken == FixedBytes::ZERO {
+                continue;
+            }
+            let remote_chain = ChainId::from_bytes(dest.0);
+            routes.push(TokenRoute {
+                remote_chain,
+                outgoing: Some((mapping.destToken.0, mapping.destDecimals)),
+                incoming_decimals: incoming.get(&(remote_chain, address)).copied(),
+                mode,
+            });
+        }
+        for ((remote_chain, _), decimals) in incoming.iter().filter(|((_, t), _)| *t == address) {
+            if routes.iter().all(|r| r.remote_chain != *remote_chain) {
+                routes.push(TokenRoute {
+                    remote_chain: *remote_chain,
+                    outgoing: None,
+                    incoming_decimals: Some(*decimals),
+                    mode,
+                });
+            }
+        }
+        routes.sort_by_key(|r| r.remote_chain.to_u32());
+
+        tokens.push(LocalToken {
+            token: format!("0x{}", hex::encode(address)),
+            id: address_to_bytes32(&address.into_array()),
+            decimals,
+            native: false,
+            rate_limit: (rate_limit != RateLimit::default()).then_some(rate_limit),
+            routes,
+        });
+    }
+    Ok(tokens)
+}
+
+async fn read_terra(client: &TerraQueryClient) -> Result<Vec<LocalToken>> {
+    let bridge = client.bridge_address();
+
+    let mut registered = Vec::new();
+    let mut start_after = None;
+    loop {
+        let page: TokensResponse = client
+            .query_contract(
+                bridge,
+                &QueryMsg::Tokens {
+                    start_after: start_after.clone(),
+                    limit: Some(TERRA_TOKENS_PAGE),
+                },
+            )
+            .await?;
+        let full = page.tokens.len() == TERRA_TOKENS_PAGE as usize;
+        start_after = page.tokens.last().map(|t| t.token.clone());
+        registered.extend(page.tokens);
+        if !full {
+            break;
+        }
+    }
+
+    let mut outgoing: HashMap<(String, ChainId), ([u8; 32], u8)> = HashMap::new();
+    let mut start_after = None;
+    loop {
+        let page: AllTokenDestMappingsResponse = client
+            .query_contract(
+                bridge,
+                &QueryMsg::AllTokenDestMappings {
+                    start_after: start_after.clone(),
+                    limit: Some(TERRA_ENUM_PAGE),
+                },
+            )
+            .await?;
+        let full = page.mappings.len() == TERRA_ENUM_PAGE as usize;
+        start_after = page
+            .mappings
+            .last()
+            .map(|m| format!("{}:{}", m.token, m.dest_chain));
+        for m in page.mappings {
+            let chain = ChainId::from_hex(&m.dest_chain)
+                .wrap_err_with(|| format!("invalid dest_chain {}", m.dest_chain))?;
+            let dest_token = decode_bytes32(&m.dest_token)
+                .ok_or_else(|| eyre!("dest_token of {} is not 32 bytes", m.token))?;
+            outgoing.insert((m.token, chain), (dest_token, m.dest_decimals));
+        }
+        if !full {
+            break;
+        }
+    }
+
+    let mut incoming: HashMap<(String, ChainId), u8> = HashMap::new();
+    let mut start_after = None;
+    loop {
+        let page: IncomingTokenMappingsResponse = client
+            .query_contract(
+                bridge,
+                &QueryMsg::IncomingTokenMappings {
+                    start_after: start_after.clone(),
+                    limit: Some(TERRA_ENUM_PAGE),
+                },
+            )
+            .await?;
+        let full = page.mappings.len() == TERRA_ENUM_PAGE as usize;
+        start_after = match page.mappings.last() {
+            Some(m) => Some(format!(
+                "{}:{}",
+                hex::encode(B64.decode(&m.src_chain)?),
+                hex::encode(B64.decode(&m.src_token)?)
+            )),
+            None => None,
+        };
+        for m in page.mappings.into_iter().filter(|m| m.enabled) {
+            let chain = decode_chain_id(&m.src_chain)
+                .ok_or_else(|| eyre!("src_chain {} is not 4 bytes", m.src_chain))?;
+            incoming.insert((m.local_token, chain), m.src_decimals);
+        }
+        if !full {
+            break;
+        }
+    }
+
+    let mut limits: HashMap<String, (u128, u128)> = HashMap::new();
+    let mut start_after = None;
+    loop {
+        let page: AllRateLimitsResponse = client
+            .query_contract(
+                bridge,
+                &QueryMsg::AllRateLimits {
+                    start_after: start_after.clone(),
+                    limit: Some(TERRA_ENUM_PAGE),
+                },
+            )
+            .await?;
+        let full = page.rate_limits.len() == TERRA_ENUM_PAGE as usize;
+        start_after = page.rate_limits.last().map(|l| l.token.clone());
+        for l in page.rate_limits {
+            limits.insert(
+                l.token,
+                (amount(&l.max_per_transaction)?, amount(&l.max_per_period)?),
+            );
+        }
+        if !full {
+            break;
+        }
+    }
+
+    let mut tokens = Vec::with_capacity(registered.len());
+    for info in registered {
+        let mode = TokenMode::from_terra(&client.get_token_type(&info.token).await?);
+        let min = info.min_bridge_amount.as_deref().map(amount).transpose()?;
+        let rate_limit = match (limits.get(&info.token), min) {
+            (None, None) => None,
+            (limit, min) => {
+                let (max_per_transaction, max_per_period) = limit.copied().unwrap_or_default();
+                Some(RateLimit {
+                    min_per_transaction: min.unwrap_or_default(),
+                    max_per_transaction,
+                    max_per_period,
+                })
+            }
+        };
+        let mut remotes: Vec<ChainId> = outgoing
+            .keys()
+            .chain(incoming.keys())
+            .filter(|(t, _)| *t == info.token)
+            .map(|(_, c)| *c)
+            .collect();
+        remotes.sort_by_key(|c| c.to_u32());
+        remotes.dedup();
+        let routes = remotes
+            .into_iter()
+            .map(|remote_chain| {
+                let key = (info.token.clone(), remote_chain);
+                TokenRoute {
+                    remote_chain,
+                    outgoing: outgoing.get(&key).copied(),
+                    incoming_decimals: incoming.get(&key).copied(),
+                    mode,
+                }
+            })
+            .collect();
+        tokens.push(LocalToken {
+            id: encode_terra_token_address(&info.token),
+            native: info.is_native,
+            decimals: Some(info.terra_decimals),
+            token: info.token,
+            rate_limit,
+            routes,
+        });
+    }
+    Ok(tokens)
+}
+
+async fn read_solana(client: &Arc<RpcClient>, program_id: Pubkey) -> Result<Vec<LocalToken>> {
+    let config = RpcProgramAccountsConfig {
+        filters: Some(vec![
+            RpcFilterType::DataSize(TOKEN_MAPPING_ACCOUNT_LEN),
+            RpcFilterType::Memcmp(Memcmp::new(
+                0,
+                MemcmpEncodedBytes::Base64(
+                    B64.encode(anchor_discriminator("account:TokenMapping")),
+                ),
+            )),
+        ]),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let accounts = blocking(client, move |c| {
+        Ok(c.get_program_accounts_with_config(&program_id, config)?)
+    })
+    .await
+    .wrap_err("getProgramAccounts for TokenMapping failed")?;
+
+    let mut tokens: Vec<LocalToken> = Vec::new();
+    for (_, account) in accounts {
+        let mapping = parse_token_mapping(&account.data)?;
+        let route = TokenRoute {
+            remote_chain: ChainId::from_bytes(mapping.dest_chain),
+            outgoing: Some((mapping.dest_token, mapping.src_decimals)),
+            incoming_decimals: Some(mapping.src_decimals),
+            mode: if mapping.mint_burn {
+                TokenMode::MintBurn
+            } else {
+                TokenMode::LockUnlock
+            },
+        };
+        match tokens.iter_mut().find(|t| t.id == mapping.local_mint) {
+            Some(token) => token.routes.push(route),
+            None => tokens.push(LocalToken {
+                token: Pubkey::new_from_array(mapping.local_mint).to_string(),
+                id: mapping.local_mint,
+                decimals: Some(mapping.decimals),
+                native: mapping.local_mint == [0u8; 32],
+                rate_limit: None,
+                routes: vec![route],
+            }),
+        }
+    }
+    for token in &mut tokens {
+        token.routes.sort_by_key(|r| r.remote_chain.to_u32());
+    }
+
+    let keys: Vec<Pubkey> = tokens
+        .iter()
+        .map(|t| withdraw_rate_limit_pda(&program_id, &Pubkey::new_from_array(t.id)))
+        .collect();
+    let limits = blocking(client, move |c| {
+        let mut accounts = Vec::with_capacity(keys.len());
+        for chunk in keys.chunks(SOLANA_ACCOUNTS_PER_REQUEST) {
+            accounts.extend(c.get_multiple_accounts(chunk)?);
+        }
+        Ok(accounts)
+    })
+    .await?;
+    for (token, account) in tokens.iter_mut().zip(limits) {
+        let Some(account) = account else {
+            continue;
+        };
+        let limit = parse_withdraw_rate_limit(&account.data)?;
+        // Implicit limits follow the mint supply
+        if limit.explicit_config {
+            token.rate_limit = Some(RateLimit {
+                min_per_transaction: limit.min_per_transaction,
+                max_per_transaction: limit.max_per_transaction,
+                max_per_period: limit.max_per_period,
+            });
+        }
+    }
+    Ok(tokens)
+}
+
+fn amount(s: &str) -> Result<u128> {
+    s.parse().map_err(|e| eyre!("Invalid amount {}: {}", s, e))
+}
+
+fn u256_to_u128(v: U256) -> Result<u128> {
+    u128::try_from(v).map_err(|_| eyre!("value {} exceeds u128", v))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EVM: ChainId = ChainId([0, 0, 0, 56]);
+    const TERRA: ChainId = ChainId([0, 0, 0, 1]);
+
+    fn evm_token(decimals: u8) -> LocalToken {
+        let address = Address::repeat_byte(0xaa);
+        LocalToken {
+            token: format!("0x{}", hex::encode(address)),
+            id: address_to_bytes32(&address.into_array()),
+            decimals: Some(decimals),
+            native: false,
+            rate_limit: None,
+            routes: vec![TokenRoute {
+                remote_chain: TERRA,
+                outgoing: Some((encode_terra_token_address("uluna"), 6)),
+                incoming_decimals: Some(6),
+                mode: TokenMode::MintBurn,
+            }],
+        }
+    }
+
+    fn terra_token(mode: TokenMode) -> LocalToken {
+        LocalToken {
+            token: "uluna".into(),
+            id: encode_terra_token_address("uluna"),
+            decimals: Some(6),
+            native: true,
+            rate_limit: None,
+            routes: vec![TokenRoute {
+                remote_chain: EVM,
+                outgoing: Some((evm_token(18).id, 18)),
+                incoming_decimals: Some(18),
+                mode,
+            }],
+        }
+    }
+
+    fn snapshot(evm: LocalToken, terra: LocalToken) -> Vec<ChainMappings> {
+        vec![
+            ChainMappings {
+                chain: EVM,
+                kind: ChainKind::Evm,
+                tokens: vec![evm],
+            },
+            ChainMappings {
+                chain: TERRA,
+                kind: ChainKind::Terra,
+                tokens: vec![terra],
+            },
+        ]
+    }
+
+    fn kinds(report: &MappingReport) -> Vec<(FindingKind, ChainId)> {
+        report.findings.iter().map(|f| (f.kind, f.chain)).collect()
+    }
+
+    #[test]
+    fn test_consistent_route_has_no_findings() {
+        let report = check_mappings(&snapshot(evm_token(18), terra_token(TokenMode::LockUnlock)));
+        assert!(report.findings.is_empty(), "{}", report);
+        assert_eq!(report.routes_checked, 2);
+        assert_eq!(report.refused_routes().count(), 0);
+    }
+
+    #[test]
+    fn test_decimals_mismatch_refuses_both_directions() {
+        // EVM records uluna with 18 decimals (the local token's), not Terra's 6
+        let mut evm = evm_token(18);
+        evm.routes[0].incoming_decimals = Some(18);
+        let report = check_mappings(&snapshot(evm, terra_token(TokenMode::LockUnlock)));
+        assert_eq!(kinds(&report), vec![(FindingKind::DecimalsMismatch, EVM)]);
+        assert_eq!(
+            report.findings[0].detail,
+            "source uluna recorded with 18 decimals, has 6"
+        );
+
+        let refused: Vec<_> = report.refused_routes().cloned().collect();
+        assert_eq!(
+            refused,
+            vec![
+                RefusedRoute {
+                    src_chain: TERRA,
+                    dest_chain: EVM,
+                    dest_token: format!("0x{}", "aa".repeat(20)),
+                },
+                RefusedRoute {
+                    src_chain: EVM,
+                    dest_chain: TERRA,
+                    dest_token: "uluna".into(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_round_trip_identity() {
+        let mut terra = terra_token(TokenMode::LockUnlock);
+        terra.routes[0].outgoing = Some(([7u8; 32], 18));
+        let report = check_mappings(&snapshot(evm_token(18), terra));
+        assert_eq!(
+            kinds(&report),
+            vec![
+                (FindingKind::UnknownRemoteToken, TERRA),
+                (FindingKind::RoundTripMismatch, EVM),
+            ]
+        );
+
+        let mut terra = terra_token(TokenMode::LockUnlock);
+        terra.routes.clear();
+        let report = check_mappings(&snapshot(evm_token(18), terra));
+        assert_eq!(kinds(&report), vec![(FindingKind::MissingReverse, EVM)]);
+
+        let mut terra = terra_token(TokenMode::LockUnlock);
+        terra.routes[0].incoming_decimals = None;
+        let report = check_mappings(&snapshot(evm_token(18), terra));
+        assert_eq!(kinds(&report), vec![(FindingKind::MissingIncoming, TERRA)]);
+    }
+
+    #[test]
+    fn test_modes() {
+        let report = check_mappings(&snapshot(evm_token(18), terra_token(TokenMode::MintBurn)));
+        assert_eq!(
+            kinds(&report),
+            vec![
+                (FindingKind::NativeMintBurn, TERRA),
+                (FindingKind::NoLockLeg, TERRA),
+                (FindingKind::NoLockLeg, EVM),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rate_limits() {
+        let limit = |min, max, period| RateLimit {
+            min_per_transaction: min,
+            max_per_transaction: max,
+            max_per_period: period,
+        };
+        assert!(limit_ordered(&limit(0, 0, 0)));
+        assert!(limit_ordered(&limit(5, 0, 10)));
+        assert!(!limit_ordered(&limit(20, 10, 0)));
+        assert!(!limit_ordered(&limit(0, 10, 5)));
+
+        // 1 LUNC minimum on Terra vs at most 0.5 LUNC per transaction on EVM
+        assert!(limits_disjoint(
+            (limit(1_000_000, 0, 0), 6),
+            (limit(0, 500_000_000_000_000_000, 0), 18)
+        ));
+        assert!(!limits_disjoint(
+            (limit(1_000_000, 0, 0), 6),
+            (limit(0, 2_000_000_000_000_000_000, 0), 18)
+        ));
+
+        let mut evm = evm_token(18);
+        evm.rate_limit = Some(limit(0, 500_000_000_000_000_000, 0));
+        let mut terra = terra_token(TokenMode::LockUnlock);
+        terra.rate_limit = Some(limit(1_000_000, 0, 0));
+        let report = check_mappings(&snapshot(evm, terra));
+        assert_eq!(
+            kinds(&report),
+            vec![(FindingKind::RateLimitDisjoint, TERRA)]
+        );
+    }
+
+    #[test]
+    fn test_routes_to_unconfigured_chains_are_skipped() {
+        let mut evm = evm_token(18);
+        evm.routes[0].remote_chain = ChainId::from_u32(99);
+        let chains = vec![ChainMappings {
+            chain: EVM,
+            kind: ChainKind::Evm,
+            tokens: vec![evm],
+        }];
+        let report = check_mappings(&chains);
+        assert!(report.findings.is_empty());
+        assert_eq!(report.routes_unchecked, 1);
+        let json = report.to_json();
+        assert_eq!(json["routes_unchecked"], 1);
+        assert_eq!(json["findings"].as_array().unwrap().len(), 0);
+    }
+}
@@ -0,0 +1,519 @@
+//! Solana bridge account layouts.
+//!
+//! PDA derivation and raw-data parsers for the `cl8y-bridge` program accounts that
+//! off-chain readers need without pulling in Anchor: `BridgeConfig`, `DepositRecord`,
+//! `DepositHashes`, `PendingWithdraw`, `ExecutedHash`, `TokenMapping`, `ChainEntry` and `WithdrawRateLimit`,
+//! plus the accounts of operator-submitted and relayed withdraws (`RentSponsor`,
+//! `WithdrawSponsorship`, `RelayConfig`, `RelayRequest`).
+//! Offsets include the 8-byte Anchor discriminator.
+//! Also the SPL mint / token-account fields the bridge's custody is measured with.
+
+use eyre::{eyre, Result};
+use solana_sdk::hash::hash;
+use solana_sdk::pubkey::Pubkey;
+
+/// Seed of the singleton `BridgeConfig` PDA.
+pub const BRIDGE_CONFIG_SEED: &[u8] = b"bridge";
+
+/// Seed of the per-nonce `DepositRecord` PDA.
+pub const DEPOSIT_RECORD_SEED: &[u8] = b"deposit";
+
+/// Seed of the per-chunk `DepositHashes` PDA (transfer hashes of closed `DepositRecord`s).
+pub const DEPOSIT_HASHES_SEED: &[u8] = b"deposit_hashes";
+
+/// Deposit nonces covered by one `DepositHashes` account.
+pub const DEPOSIT_HASHES_PER_CHUNK: u64 = 32;
+
+/// Seed of the per-transfer-hash `PendingWithdraw` PDA.
+pub const PENDING_WITHDRAW_SEED: &[u8] = b"withdraw";
+
+/// Seed of the per-transfer-hash `ExecutedHash` PDA (created when a withdraw executes
+/// and its `PendingWithdraw` is closed).
+pub const EXECUTED_HASH_SEED: &[u8] = b"executed";
+
+/// Seed of the per-(remote chain, remote token) `TokenMapping` PDA.
+pub const TOKEN_MAPPING_SEED: &[u8] = b"token";
+
+/// Seed of the per-chain `ChainEntry` PDA.
+pub const CHAIN_ENTRY_SEED: &[u8] = b"chain";
+
+/// Seed of the per-mint `WithdrawRateLimit` PDA (native SOL uses the all-zero mint).
+pub const WITHDRAW_RATE_LIMIT_SEED: &[u8] = b"w_rate_lim";
+
+/// Seed of the singleton `RentSponsor` PDA that repays operator-paid withdraw rent.
+pub const RENT_SPONSOR_SEED: &[u8] = b"rent_sponsor";
+
+/// Seed of the per-transfer-hash `WithdrawSponsorship` PDA (operator-paid rent repaid on execute).
+pub const WITHDRAW_SPONSORSHIP_SEED: &[u8] = b"sponsorship";
+
+/// Seed of the singleton `RelayConfig` PDA (relay fee cap).
+pub const RELAY_CONFIG_SEED: &[u8] = b"relay_config";
+
+/// Seed of the per-transfer-hash `RelayRequest` PDA (depositor's relay opt-in).
+pub const RELAY_REQUEST_SEED: &[u8] = b"relay_request";
+
+/// Size of a `TokenMapping` account, for `getProgramAccounts` data-size filters.
+pub const TOKEN_MAPPING_ACCOUNT_LEN: u64 = 88;
+
+/// Offset of `TokenMapping.local_mint`, for `getProgramAccounts` memcmp filters.
+pub const TOKEN_MAPPING_LOCAL_MINT_OFFSET: usize = 8;
+
+/// Offset of `TokenMapping.dest_chain`, for `getProgramAccounts` memcmp filters.
+pub const TOKEN_MAPPING_DEST_CHAIN_OFFSET: usize = 40;
+
+/// SPL Token program
+pub const SPL_TOKEN_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
+/// SPL Token-2022 program
+pub const SPL_TOKEN_2022_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+/// Associated Token Account program
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWBC8N1wJ2uhhKJ8");
+
+/// Anchor discriminator: `sha256(preimage)[..8]`, with preimage `account:<Name>` for
+/// account data and `global:<name>` for instructions
+pub fn anchor_discriminator(preimage: &str) -> [u8; 8] {
+    let mut out = [0u8; 8];
+    out.copy_from_slice(&hash(preimage.as_bytes()).to_bytes()[..8]);
+    out
+}
+
+/// `BridgeConfig` PDA
+pub fn bridge_config_pda(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[BRIDGE_CONFIG_SEED], program_id).0
+}
+
+/// `DepositRecord` PDA for an outgoing deposit nonce
+pub fn deposit_record_pda(program_id: &Pubkey, nonce: u64) -> Pubkey {
+    Pubkey::find_program_address(&[DEPOSIT_RECORD_SEED, &nonce.to_le_bytes()], program_id).0
+}
+
+/// `DepositHashes` PDA holding the transfer hash of `nonce` once its record is closed
+pub fn deposit_hashes_pda(program_id: &Pubkey, nonce: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            DEPOSIT_HASHES_SEED,
+            &(nonce / DEPOSIT_HASHES_PER_CHUNK).to_le_bytes(),
+        ],
+        program_id,
+    )
+    .0
+}
+
+/// `PendingWithdraw` PDA for a transfer hash
+pub fn pending_withdraw_pda(program_id: &Pubkey, transfer_hash: &[u8; 32]) -> Pubkey {
+    Pubkey::find_program_address(&[PENDING_WITHDRAW_SEED, transfer_hash], program_id).0
+}
+
+/// `ExecutedHash` PDA for a transfer hash
+pub fn executed_hash_pda(program_id: &Pubkey, transfer_hash: &[u8; 32]) -> Pubkey {
+    Pubkey::find_program_address(&[EXECUTED_HASH_SEED, transfer_hash], program_id).0
+}
+
+/// `TokenMapping` PDA for a remote chain and the token's 32-byte ID on that chain
+pub fn token_mapping_pda(
+    program_id: &Pubkey,
+    remote_chain: &[u8; 4],
+    remote_token: &[u8; 32],
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[TOKEN_MAPPING_SEED, remote_chain, remote_token],
+        program_id,
+    )
+    .0
+}
+
+/// `ChainEntry` PDA for a registered chain
+pub fn chain_entry_pda(program_id: &Pubkey, chain_id: &[u8; 4]) -> Pubkey {
+    Pubkey::find_program_address(&[CHAIN_ENTRY_SEED, chain_id], program_id).0
+}
+
+/// `WithdrawRateLimit` PDA for a local mint
+pub fn withdraw_rate_limit_pda(program_id: &Pubkey, local_mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[WITHDRAW_RATE_LIMIT_SEED, local_mint.as_ref()], program_id).0
+}
+
+/// `RentSponsor` PDA
+pub fn rent_sponsor_pda(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[RENT_SPONSOR_SEED], program_id).0
+}
+
+/// `WithdrawSponsorship` PDA for a transfer hash
+pub fn withdraw_sponsorship_pda(program_id: &Pubkey, transfer_hash: &[u8; 32]) -> Pubkey {
+    Pubkey::find_program_address(&[WITHDRAW_SPONSORSHIP_SEED, transfer_hash], program_id).0
+}
+
+/// `RelayConfig` PDA
+pub fn relay_config_pda(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[RELAY_CONFIG_SEED], program_id).0
+}
+
+/// `RelayRequest` PDA for the transfer hash of a deposit made on Solana
+pub fn relay_request_pda(program_id: &Pubkey, transfer_hash: &[u8; 32]) -> Pubkey {
+    Pubkey::find_program_address(&[RELAY_REQUEST_SEED, transfer_hash], program_id).0
+}
+
+/// Associated token account of `owner` for `mint` under `token_program` (SPL Token or
+/// Token-2022). The bridge's lock/unlock vault is the ATA owned by the `BridgeConfig` PDA.
+pub fn associated_token_address(owner: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[owner.as_ref(), token_program.as_ref(), mint.as_ref()],
+        &ASSOCIATED_TOKEN_PROGRAM_ID,
+    )
+    .0
+}
+
+/// Fields of `BridgeConfig` that off-chain readers use
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolanaBridgeConfig {
+    pub admin: [u8; 32],
+    pub fee_bps: u16,
+    pub withdraw_delay: i64,
+    pub deposit_nonce: u64,
+    pub paused: bool,
+    pub chain_id: [u8; 4],
+}
+
+/// Parsed `DepositRecord` account
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolanaDepositRecord {
+    pub transfer_hash: [u8; 32],
+    pub src_account: [u8; 32],
+    pub dest_chain: [u8; 4],
+    pub dest_account: [u8; 32],
+    /// Destination-chain token (the value hashed into `transfer_hash`)
+    pub token: [u8; 32],
+    /// Net amount after the deposit fee
+    pub amount: u128,
+    pub nonce: u64,
+    pub timestamp: i64,
+}
+
+/// Parsed `PendingWithdraw` account
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolanaPendingWithdraw {
+    pub transfer_hash: [u8; 32],
+    pub src_chain: [u8; 4],
+    pub src_account: [u8; 32],
+    pub dest_account: [u8; 32],
+    /// Local mint (`Pubkey::default()` for native SOL)
+    pub token: [u8; 32],
+    /// Amount in source-chain units (`src_decimals`)
+    pub amount: u128,
+    pub nonce: u64,
+    pub src_decimals: u8,
+    pub dest_decimals: u8,
+    pub approved: bool,
+    pub approved_at: i64,
+    pub cancelled: bool,
+    pub executed: bool,
+}
+
+/// Parsed `TokenMapping` account
+///
+/// One mapping serves both directions of a (local mint, remote chain) route: deposits hash
+/// `dest_token`, and withdrawals from `dest_chain` are submitted against the same PDA.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolanaTokenMapping {
+    /// Local mint (`Pubkey::default()` for native SOL)
+    pub local_mint: [u8; 32],
+    /// Remote chain
+    pub dest_chain: [u8; 4],
+    /// Token ID on the remote chain
+    pub dest_token: [u8; 32],
+    /// True for mint/burn, false for lock/unlock
+    pub mint_burn: bool,
+    /// Decimals of `local_mint`
+    pub decimals: u8,
+    /// Decimals of `dest_token` on the remote chain
+    pub src_decimals: u8,
+}
+
+/// Parsed `WithdrawRateLimit` account (limits only, not the current window)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolanaWithdrawRateLimit {
+    /// False while the program derives the limits from the mint supply
+    pub explicit_config: bool,
+    pub min_per_transaction: u128,
+    pub max_per_transaction: u128,
+    pub max_per_period: u128,
+}
+
+/// Parsed `WithdrawSponsorship` account
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolanaWithdrawSponsorship {
+    pub transfer_hash: [u8; 32],
+    /// Operator that paid the `PendingWithdraw` rent (and relayed the withdraw)
+    pub operator: [u8; 32],
+    /// Relay fee in source-chain units (0 when not relayed)
+    pub relay_fee: u128,
+}
+
+fn bytes<const N: usize>(data: &[u8], offset: usize) -> Result<[u8; N]> {
+    data.get(offset..offset + N)
+        .and_then(|b| b.try_into().ok())
+        .ok_or_else(|| eyre!("account data too short: {} bytes", data.len()))
+}
+
+/// Parse `BridgeConfig` account data
+/// (`admin`, `operator`, `fee_bps: u16`, `withdraw_delay: i64`, `deposit_nonce: u64`,
+/// `accrued_native_fees: u64`, `paused: bool`, `chain_id: [u8; 4]`, `bump`).
+pub fn parse_bridge_config(data: &[u8]) -> Result<SolanaBridgeConfig> {
+    Ok(SolanaBridgeConfig {
+        admin: bytes(data, 8)?,
+        fee_bps: u16::from_le_bytes(bytes(data, 72)?),
+        withdraw_delay: i64::from_le_bytes(bytes(data, 74)?),
+        deposit_nonce: u64::from_le_bytes(bytes(data, 82)?),
+        paused: bytes::<1>(data, 98)?[0] != 0,
+        chain_id: bytes(data, 99)?,
+    })
+}
+
+/// Parse `DepositRecord` account data
+/// (`transfer_hash`, `src_account: Pubkey`, `dest_chain: [u8; 4]`, `dest_account`, `token`,
+/// `amount: u128`, `nonce: u64`, `timestamp: i64`, `bump`).
+pub fn parse_deposit_record(data: &[u8]) -> Result<SolanaDepositRecord> {
+    Ok(SolanaDepositRecord {
+        transfer_hash: bytes(data, 8)?,
+        src_account: bytes(data, 40)?,
+        dest_chain: bytes(data, 72)?,
+        dest_account: bytes(data, 76)?,
+        token: bytes(data, 108)?,
+        amount: u128::from_le_bytes(bytes(data, 140)?),
+        nonce: u64::from_le_bytes(bytes(data, 156)?),
+        timestamp: i64::from_le_bytes(bytes(data, 164)?),
+    })
+}
+
+/// Transfer hash kept for `nonce` in `DepositHashes` account data (`chunk: u64`,
+/// `hashes: [[u8; 32]; 32]`, `bump`); all zero when its record was never closed.
+pub fn parse_deposit_hash(data: &[u8], nonce: u64) -> Result<[u8; 32]> {
+    bytes(data, 16 + 32 * (nonce % DEPOSIT_HASHES_PER_CHUNK) as usize)
+}
+
+/// Parse `PendingWithdraw` account data
+/// (`transfer_hash`, `src_chain: [u8; 4]`, `src_account`, `dest_account: Pubkey`,
+/// `token: Pubkey`, `amount: u128`, `nonce: u64`, `src_decimals`, `dest_decimals`,
+/// `operator_gas: u64`, `approved`, `approved_at: i64`, `cancelled`, `executed`, `bump`).
+pub fn parse_pending_withdraw(data: &[u8]) -> Result<SolanaPendingWithdraw> {
+    Ok(SolanaPendingWithdraw {
+        transfer_hash: bytes(data, 8)?,
+        src_chain: bytes(data, 40)?,
+        src_account: bytes(data, 44)?,
+        dest_account: bytes(data, 76)?,
+        token: bytes(data, 108)?,
+        amount: u128::from_le_bytes(bytes(data, 140)?),
+        nonce: u64::from_le_bytes(bytes(data, 156)?),
+        src_decimals: bytes::<1>(data, 164)?[0],
+        dest_decimals: bytes::<1>(data, 165)?[0],
+        approved: bytes::<1>(data, 174)?[0] != 0,
+        approved_at: i64::from_le_bytes(bytes(data, 175)?),
+        cancelled: bytes::<1>(data, 183)?[0] != 0,
+        executed: bytes::<1>(data, 184)?[0] != 0,
+    })
+}
+
+/// Parse `TokenMapping` account data
+/// (`local_mint`, `dest_chain: [u8; 4]`, `dest_token`, `mode: TokenMode`, `decimals`,
+/// `src_decimals`, `accrued_fees: u64`, `bump`).
+pub fn parse_token_mapping(data: &[u8]) -> Result<SolanaTokenMapping> {
+    Ok(SolanaTokenMapping {
+        local_mint: bytes(data, 8)?,
+        dest_chain: bytes(data, 40)?,
+        dest_token: bytes(data, 44)?,
+        mint_burn: bytes::<1>(data, 76)?[0] == 1,
+        decimals: bytes::<1>(data, 77)?[0],
+        src_decimals: bytes::<1>(data, 78)?[0],
+    })
+}
+
+/// Parse `ChainEntry` account data (`chain_id: [u8; 4]`, `identifier: String`, `bump`)
+pub fn parse_chain_entry(data: &[u8]) -> Result<([u8; 4], String)> {
+    let len = u32::from_le_bytes(bytes(data, 12)?) as usize;
+    let identifier = data
+        .get(16..16 + len)
+        .ok_or_else(|| eyre!("account data too short: {} bytes", data.len()))?;
+    Ok((
+        bytes(data, 8)?,
+        String::from_utf8(identifier.to_vec())
+            .map_err(|e| eyre!("invalid chain identifier: {}", e))?,
+    ))
+}
+
+/// Parse `WithdrawRateLimit` account data (`explicit_config`, `min_per_transaction: u128`,
+/// `max_per_transaction: u128`, `max_per_period: u128`, `window_start: i64`, `used: u128`,
+/// `bump`).
+pub fn parse_withdraw_rate_limit(data: &[u8]) -> Result<SolanaWithdrawRateLimit> {
+    Ok(SolanaWithdrawRateLimit {
+        explicit_config: bytes::<1>(data, 8)?[0] != 0,
+        min_per_transaction: u128::from_le_bytes(bytes(data, 9)?),
+        max_per_transaction: u128::from_le_bytes(bytes(data, 25)?),
+        max_per_period: u128::from_le_bytes(bytes(data, 41)?),
+    })
+}
+
+/// Parse `WithdrawSponsorship` account data
+/// (`transfer_hash`, `operator: Pubkey`, `bump`, `relay_fee: u128`).
+pub fn parse_withdraw_sponsorship(data: &[u8]) -> Result<SolanaWithdrawSponsorship> {
+    Ok(SolanaWithdrawSponsorship {
+        transfer_hash: bytes(data, 8)?,
+        operator: bytes(data, 40)?,
+        relay_fee: u128::from_le_bytes(bytes(data, 73)?),
+    })
+}
+
+/// Relay fee cap in basis points from `RelayConfig` account data (`relay_fee_cap_bps: u16`, `bump`)
+pub fn parse_relay_config(data: &[u8]) -> Result<u16> {
+    Ok(u16::from_le_bytes(bytes(data, 8)?))
+}
+
+/// Depositor's maximum relay fee from `RelayRequest` account data
+/// (`transfer_hash`, `max_relay_fee: u128`, `bump`)
+pub fn parse_relay_request(data: &[u8]) -> Result<([u8; 32], u128)> {
+    Ok((bytes(data, 8)?, u128::from_le_bytes(bytes(data, 40)?)))
+}
+
+/// Decimals of an SPL / Token-2022 mint
+pub fn parse_mint_decimals(data: &[u8]) -> Result<u8> {
+    Ok(bytes::<1>(data, 44)?[0])
+}
+
+/// Total supply of an SPL / Token-2022 mint (`mint_authority: COption<Pubkey>`,
+/// `supply: u64`, `decimals: u8`, ...)
+pub fn parse_mint_supply(data: &[u8]) -> Result<u64> {
+    Ok(u64::from_le_bytes(bytes(data, 36)?))
+}
+
+/// Balance of an SPL / Token-2022 token account (`mint`, `owner`, `amount: u64`, ...)
+pub fn parse_token_account_amount(data: &[u8]) -> Result<u64> {
+    Ok(u64::from_le_bytes(bytes(data, 64)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_deposit_record_layout() {
+        let mut data = vec![0u8; 173];
+        data[8..40].copy_from_slice(&[0x11; 32]);
+        data[72..76].copy_from_slice(&[0, 0, 0, 1]);
+        data[140..156].copy_from_slice(&995u128.to_le_bytes());
+        data[156..164].copy_from_slice(&7u64.to_le_bytes());
+        data[164..172].copy_from_slice(&1_700_000_000i64.to_le_bytes());
+
+        let record = parse_deposit_record(&data).unwrap();
+        assert_eq!(record.transfer_hash, [0x11; 32]);
+        assert_eq!(record.dest_chain, [0, 0, 0, 1]);
+        assert_eq!(record.amount, 995);
+        assert_eq!(record.nonce, 7);
+        assert_eq!(record.timestamp, 1_700_000_000);
+        assert!(parse_deposit_record(&data[..170]).is_err());
+    }
+
+    #[test]
+    fn test_parse_deposit_hash_slot() {
+        // 8 discriminator + 8 chunk + 32 hashes * 32 + bump
+        let mut data = vec![0u8; 1041];
+        let nonce = DEPOSIT_HASHES_PER_CHUNK * 3 + 31;
+        data[16 + 32 * 31..16 + 32 * 32].copy_from_slice(&[0x22; 32]);
+
+        assert_eq!(parse_deposit_hash(&data, nonce).unwrap(), [0x22; 32]);
+        assert_eq!(parse_deposit_hash(&data, nonce - 1).unwrap(), [0u8; 32]);
+        assert!(parse_deposit_hash(&data[..1000], nonce).is_err());
+    }
+
+    #[test]
+    fn test_parse_pending_withdraw_and_config_layouts() {
+        let mut data = vec![0u8; 186];
+        data[40..44].copy_from_slice(&[0, 0, 0, 2]);
+        data[164] = 18;
+        data[165] = 9;
+        data[174] = 1;
+        data[175..183].copy_from_slice(&1_700_000_100i64.to_le_bytes());
+        let pw = parse_pending_withdraw(&data).unwrap();
+        assert_eq!(pw.src_chain, [0, 0, 0, 2]);
+        assert!(pw.approved && !pw.cancelled && !pw.executed);
+        assert_eq!(pw.approved_at, 1_700_000_100);
+        assert_eq!((pw.src_decimals, pw.dest_decimals), (18, 9));
+
+        let mut cfg = vec![0u8; 104];
+        cfg[74..82].copy_from_slice(&300i64.to_le_bytes());
+        cfg[99..103].copy_from_slice(&[0, 0, 0, 5]);
+        let cfg = parse_bridge_config(&cfg).unwrap();
+        assert_eq!(cfg.withdraw_delay, 300);
+        assert_eq!(cfg.chain_id, [0, 0, 0, 5]);
+    }
+
+    #[test]
+    fn test_parse_token_mapping_layout() {
+        let mut data = vec![0u8; TOKEN_MAPPING_ACCOUNT_LEN as usize];
+        data[8..40].copy_from_slice(&[0x22; 32]);
+        data[40..44].copy_from_slice(&[0, 0, 0, 1]);
+        data[44..76].copy_from_slice(&[0x33; 32]);
+        data[76] = 1;
+        data[77] = 6;
+        data[78] = 18;
+
+        let mapping = parse_token_mapping(&data).unwrap();
+        assert_eq!(mapping.local_mint, [0x22; 32]);
+        assert_eq!(mapping.dest_chain, [0, 0, 0, 1]);
+        assert_eq!(mapping.dest_token, [0x33; 32]);
+        assert!(mapping.mint_burn);
+        assert_eq!((mapping.decimals, mapping.src_decimals), (6, 18));
+        assert!(parse_token_mapping(&data[..78]).is_err());
+    }
+
+    #[test]
+    fn test_parse_chain_entry_and_rate_limit_layouts() {
+        let mut entry = vec![0u8; 8 + 4 + 4 + 6 + 1];
+        entry[8..12].copy_from_slice(&[0, 0, 0, 56]);
+        entry[12..16].copy_from_slice(&6u32.to_le_bytes());
+        entry[16..22].copy_from_slice(b"evm_56");
+        assert_eq!(
+            parse_chain_entry(&entry).unwrap(),
+            ([0, 0, 0, 56], "evm_56".to_string())
+        );
+        assert!(parse_chain_entry(&entry[..20]).is_err());
+
+        let mut limit = vec![0u8; 82];
+        limit[8] = 1;
+        limit[9..25].copy_from_slice(&5u128.to_le_bytes());
+        limit[25..41].copy_from_slice(&1_000u128.to_le_bytes());
+        limit[41..57].copy_from_slice(&10_000u128.to_le_bytes());
+        let limit = parse_withdraw_rate_limit(&limit).unwrap();
+        assert!(limit.explicit_config);
+        assert_eq!(
+            (
+                limit.min_per_transaction,
+                limit.max_per_transaction,
+                limit.max_per_period
+            ),
+            (5, 1_000, 10_000)
+        );
+    }
+
+    #[test]
+    fn test_parse_relay_layouts() {
+        let mut sponsorship = vec![0u8; 89];
+        sponsorship[8..40].copy_from_slice(&[0x11; 32]);
+        sponsorship[40..72].copy_from_slice(&[0x22; 32]);
+        sponsorship[72] = 254;
+        sponsorship[73..89].copy_from_slice(&5_000u128.to_le_bytes());
+        let sponsorship = parse_withdraw_sponsorship(&sponsorship).unwrap();
+        assert_eq!(sponsorship.operator, [0x22; 32]);
+        assert_eq!(sponsorship.relay_fee, 5_000);
+
+        let mut config = vec![0u8; 11];
+        config[8..10].copy_from_slice(&100u16.to_le_bytes());
+        assert_eq!(parse_relay_config(&config).unwrap(), 100);
+
+        let mut request = vec![0u8; 57];
+        request[8..40].copy_from_slice(&[0x33; 32]);
+        request[40..56].copy_from_slice(&7u128.to_le_bytes());
+        assert_eq!(parse_relay_request(&request).unwrap(), ([0x33; 32], 7));
+    }
+}
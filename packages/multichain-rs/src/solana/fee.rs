@@ -0,0 +1,355 @@
+//! Solana deposit fee quoting.
+//!
+//! Mirrors the on-chain `fee.rs` of the `cl8y-bridge` program so clients can
+//! quote the exact fee before sending `deposit_native` / `deposit_spl`.
+//! Unlike [`FeeCalculator`](crate::types::FeeCalculator), the standard rate can
+//! be overridden per token mapping.
+//!
+//! ## Fee Priority (highest to lowest)
+//!
+//! 1. Custom account fee (`custom_fee` PDA passed by the depositor)
+//! 2. CL8Y holder discount (CL8Y token account passed, balance ≥ threshold),
+//!    never above the standard rate
+//! 3. Token mapping override (`token_fee` PDA, always passed)
+//! 4. Bridge `fee_bps`
+//!
+//! Lock/unlock deposits of Token-2022 transfer-fee mints are netted first: the
+//! bridge fee is taken from what reaches the bridge account, so pass the mint's
+//! [`Token2022TransferFee`] for the current epoch to match `DepositRecord.amount`.
+
+use eyre::{eyre, Result};
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+/// Maximum fee for any tier (1% = 100 bps), matching the program.
+pub const SOLANA_MAX_FEE_BPS: u16 = 100;
+
+/// Seed of the `FeeSchedule` PDA (CL8Y discount tier).
+pub const FEE_SCHEDULE_SEED: &[u8] = b"fee_schedule";
+
+/// Seed of the per-mapping `TokenFeeOverride` PDA.
+pub const TOKEN_FEE_SEED: &[u8] = b"token_fee";
+
+/// Seed of the per-depositor `CustomAccountFee` PDA.
+pub const CUSTOM_ACCOUNT_FEE_SEED: &[u8] = b"custom_fee";
+
+/// Which rule produced the effective fee rate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SolanaFeeType {
+    Standard,
+    TokenOverride,
+    Discounted,
+    Custom,
+}
+
+/// Bridge-wide fee parameters (`BridgeConfig.fee_bps` + `FeeSchedule`)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SolanaFeeParams {
+    /// `BridgeConfig.fee_bps`
+    pub fee_bps: u16,
+    /// `FeeSchedule.discounted_fee_bps` (ignored when `cl8y_mint` is None)
+    pub discounted_fee_bps: u16,
+    /// `FeeSchedule.cl8y_mint`; None when the schedule is missing or disabled
+    pub cl8y_mint: Option<Pubkey>,
+    /// `FeeSchedule.cl8y_threshold`
+    pub cl8y_threshold: u64,
+}
+
+/// Token-2022 `TransferFee` in effect for the deposit's epoch
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Token2022TransferFee {
+    /// `TransferFee.transfer_fee_basis_points`
+    pub basis_points: u16,
+    /// `TransferFee.maximum_fee`
+    pub maximum_fee: u64,
+}
+
+impl Token2022TransferFee {
+    /// Fee Token-2022 withholds from a transfer of `amount`:
+    /// `min(ceil(amount * bps / 10000), maximum_fee)` (`TransferFee::calculate_fee`)
+    pub fn fee(&self, amount: u64) -> u64 {
+        if self.basis_points == 0 || amount == 0 {
+            return 0;
+        }
+        let fee = (amount as u128 * self.basis_points as u128).div_ceil(10_000);
+        fee.min(self.maximum_fee as u128) as u64
+    }
+}
+
+/// Per-deposit inputs that the depositor controls
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SolanaFeeInputs {
+    /// `TokenFeeOverride.fee_bps`, if the mapping has one
+    pub token_override_bps: Option<u16>,
+    /// `CustomAccountFee.fee_bps`, if the depositor has one and passes it
+    pub custom_fee_bps: Option<u16>,
+    /// Balance of the CL8Y token account passed, if any
+    pub cl8y_balance: Option<u64>,
+    /// Transfer fee of a Token-2022 mint on a lock/unlock mapping. Leave None for
+    /// mint/burn mappings: the burned amount is not a transfer.
+    pub transfer_fee: Option<Token2022TransferFee>,
+}
+
+/// Quoted deposit fee
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SolanaFeeQuote {
+    pub fee_bps: u16,
+    pub fee_type: SolanaFeeType,
+    pub fee: u64,
+    /// Withheld by Token-2022 before the bridge fee is taken
+    pub transfer_fee: u64,
+    /// Hashed and recorded as `DepositRecord.amount`
+    pub net_amount: u64,
+}
+
+/// Fee calculator for Solana bridge deposits
+pub struct SolanaFeeCalculator {
+    params: SolanaFeeParams,
+}
+
+impl SolanaFeeCalculator {
+    /// Create a new fee calculator
+    pub fn new(params: SolanaFeeParams) -> Self {
+        Self { params }
+    }
+
+    /// Effective fee rate and the rule that produced it
+    pub fn fee_bps(&self, inputs: &SolanaFeeInputs) -> (u16, SolanaFeeType) {
+        if let Some(custom) = inputs.custom_fee_bps {
+            return (custom, SolanaFeeType::Custom);
+        }
+
+        let (standard, standard_type) = match inputs.token_override_bps {
+            Some(bps) => (bps, SolanaFeeType::TokenOverride),
+            None => (self.params.fee_bps, SolanaFeeType::Standard),
+        };
+
+        if self.is_eligible_for_discount(inputs.cl8y_balance) {
+            let discounted = self.params.discounted_fee_bps;
+            if discounted < standard {
+                return (discounted, SolanaFeeType::Discounted);
+            }
+        }
+
+        (standard, standard_type)
+    }
+
+    /// Whether a CL8Y balance qualifies for the holder discount
+    pub fn is_eligible_for_discount(&self, cl8y_balance: Option<u64>) -> bool {
+        match (self.params.cl8y_mint, cl8y_balance) {
+            (Some(mint), Some(balance)) => {
+                mint != Pubkey::default() && balance >= self.params.cl8y_threshold
+            }
+            _ => false,
+        }
+    }
+
+    /// Quote a deposit of gross `amount`: any Token-2022 transfer fee is withheld
+    /// first, then `fee = floor(received * bps / 10000)`
+    pub fn quote(&self, amount: u64, inputs: &SolanaFeeInputs) -> SolanaFeeQuote {
+        let (fee_bps, fee_type) = self.fee_bps(inputs);
+        let transfer_fee = inputs.transfer_fee.map_or(0, |tf| tf.fee(amount));
+        let received = amount - transfer_fee;
+        let fee = (received as u128 * fee_bps as u128 / 10_000) as u64;
+        SolanaFeeQuote {
+            fee_bps,
+            fee_type,
+            fee,
+            transfer_fee,
+            net_amount: received - fee,
+        }
+    }
+
+    /// Get the fee parameters
+    pub fn params(&self) -> &SolanaFeeParams {
+        &self.params
+    }
+}
+
+/// `FeeSchedule` PDA for a bridge program
+pub fn fee_schedule_pda(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[FEE_SCHEDULE_SEED], program_id).0
+}
+
+/// `TokenFeeOverride` PDA for a `TokenMapping` account
+pub fn token_fee_override_pda(program_id: &Pubkey, token_mapping: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[TOKEN_FEE_SEED, token_mapping.as_ref()], program_id).0
+}
+
+/// `CustomAccountFee` PDA for a depositor
+pub fn custom_account_fee_pda(program_id: &Pubkey, depositor: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[CUSTOM_ACCOUNT_FEE_SEED, depositor.as_ref()], program_id).0
+}
+
+/// `(discounted_fee_bps, cl8y_mint, cl8y_threshold)` from raw `FeeSchedule` account data
+/// (8-byte discriminator, `discounted_fee_bps: u16`, `cl8y_mint: Pubkey`, `cl8y_threshold: u64`, `bump`).
+/// The mint is None when the discount is disabled (`Pubkey::default()`).
+pub fn parse_fee_schedule(data: &[u8]) -> Result<(u16, Option<Pubkey>, u64)> {
+    let field = |range: std::ops::Range<usize>| {
+        data.get(range)
+            .ok_or_else(|| eyre!("FeeSchedule data too short: {} bytes", data.len()))
+    };
+    let discounted = u16::from_le_bytes(field(8..10)?.try_into()?);
+    let mint = Pubkey::try_from(field(10..42)?)?;
+    let threshold = u64::from_le_bytes(field(42..50)?.try_into()?);
+    Ok((
+        discounted,
+        (mint != Pubkey::default()).then_some(mint),
+        threshold,
+    ))
+}
+
+/// `fee_bps` from raw `TokenFeeOverride` or `CustomAccountFee` account data
+/// (8-byte discriminator, `token_mapping` / `account: Pubkey`, `fee_bps: u16`, `bump`).
+pub fn parse_fee_bps_override(data: &[u8]) -> Option<u16> {
+    Some(u16::from_le_bytes(data.get(40..42)?.try_into().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn calc() -> SolanaFeeCalculator {
+        SolanaFeeCalculator::new(SolanaFeeParams {
+            fee_bps: 50,
+            discounted_fee_bps: 10,
+            cl8y_mint: Some(Pubkey::new_unique()),
+            cl8y_threshold: 100_000_000,
+        })
+    }
+
+    #[test]
+    fn test_standard_fee() {
+        let q = calc().quote(1_000_000, &SolanaFeeInputs::default());
+        assert_eq!(q.fee_type, SolanaFeeType::Standard);
+        assert_eq!(q.fee, 5_000);
+        assert_eq!(q.net_amount, 995_000);
+    }
+
+    #[test]
+    fn test_token_override_replaces_standard() {
+        let inputs = SolanaFeeInputs {
+            token_override_bps: Some(80),
+            ..Default::default()
+        };
+        let q = calc().quote(1_000_000, &inputs);
+        assert_eq!(q.fee_type, SolanaFeeType::TokenOverride);
+        assert_eq!(q.fee, 8_000);
+    }
+
+    #[test]
+    fn test_discount_needs_threshold() {
+        let below = SolanaFeeInputs {
+            cl8y_balance: Some(99_999_999),
+            ..Default::default()
+        };
+        assert_eq!(calc().fee_bps(&below), (50, SolanaFeeType::Standard));
+
+        let at = SolanaFeeInputs {
+            cl8y_balance: Some(100_000_000),
+            ..Default::default()
+        };
+        assert_eq!(calc().fee_bps(&at), (10, SolanaFeeType::Discounted));
+    }
+
+    #[test]
+    fn test_discount_never_raises_override() {
+        let inputs = SolanaFeeInputs {
+            token_override_bps: Some(5),
+            cl8y_balance: Some(u64::MAX),
+            ..Default::default()
+        };
+        assert_eq!(calc().fee_bps(&inputs), (5, SolanaFeeType::TokenOverride));
+    }
+
+    #[test]
+    fn test_custom_fee_wins() {
+        let inputs = SolanaFeeInputs {
+            token_override_bps: Some(5),
+            custom_fee_bps: Some(70),
+            cl8y_balance: Some(u64::MAX),
+            transfer_fee: None,
+        };
+        let q = calc().quote(1_000_000, &inputs);
+        assert_eq!(q.fee_type, SolanaFeeType::Custom);
+        assert_eq!(q.fee, 7_000);
+    }
+
+    #[test]
+    fn test_discount_disabled_without_mint() {
+        let calc = SolanaFeeCalculator::new(SolanaFeeParams {
+            fee_bps: 50,
+            discounted_fee_bps: 10,
+            cl8y_mint: None,
+            cl8y_threshold: 0,
+        });
+        assert!(!calc.is_eligible_for_discount(Some(u64::MAX)));
+    }
+
+    #[test]
+    fn test_quote_no_overflow_at_max() {
+        let q = calc().quote(u64::MAX, &SolanaFeeInputs::default());
+        assert_eq!(q.fee + q.net_amount, u64::MAX);
+
+        let inputs = SolanaFeeInputs {
+            transfer_fee: Some(Token2022TransferFee {
+                basis_points: 10_000,
+                maximum_fee: u64::MAX,
+            }),
+            ..Default::default()
+        };
+        let q = calc().quote(u64::MAX, &inputs);
+        assert_eq!(q.transfer_fee, u64::MAX);
+        assert_eq!(q.net_amount, 0);
+    }
+
+    #[test]
+    fn test_transfer_fee_netted_before_bridge_fee() {
+        let inputs = SolanaFeeInputs {
+            transfer_fee: Some(Token2022TransferFee {
+                basis_points: 100,
+                maximum_fee: u64::MAX,
+            }),
+            ..Default::default()
+        };
+        let q = calc().quote(1_000_000, &inputs);
+        assert_eq!(q.transfer_fee, 10_000);
+        assert_eq!(q.fee, 4_950);
+        assert_eq!(q.net_amount, 985_050);
+        assert_eq!(q.transfer_fee + q.fee + q.net_amount, 1_000_000);
+    }
+
+    #[test]
+    fn test_parse_fee_accounts() {
+        let mint = Pubkey::new_unique();
+        let mut schedule = vec![0u8; 51];
+        schedule[8..10].copy_from_slice(&10u16.to_le_bytes());
+        schedule[10..42].copy_from_slice(mint.as_ref());
+        schedule[42..50].copy_from_slice(&100u64.to_le_bytes());
+        assert_eq!(
+            parse_fee_schedule(&schedule).unwrap(),
+            (10, Some(mint), 100)
+        );
+
+        schedule[10..42].copy_from_slice(&[0; 32]);
+        assert_eq!(parse_fee_schedule(&schedule).unwrap().1, None);
+        assert!(parse_fee_schedule(&schedule[..49]).is_err());
+
+        let mut fee_override = vec![0u8; 43];
+        fee_override[40..42].copy_from_slice(&80u16.to_le_bytes());
+        assert_eq!(parse_fee_bps_override(&fee_override), Some(80));
+        assert_eq!(parse_fee_bps_override(&fee_override[..41]), None);
+    }
+
+    #[test]
+    fn test_transfer_fee_rounds_up_and_caps() {
+        let tf = Token2022TransferFee {
+            basis_points: 200,
+            maximum_fee: 5_000,
+        };
+        assert_eq!(tf.fee(1), 1);
+        assert_eq!(tf.fee(100_000), 2_000);
+        assert_eq!(tf.fee(1_000_000), 5_000);
+        assert_eq!(tf.fee(0), 0);
+    }
+}
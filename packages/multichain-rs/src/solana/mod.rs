@@ -1,9 +1,17 @@
+pub mod accounts;
+pub mod fee;
+pub mod nonce_bitmap;
 pub mod rpc_fallback;
 pub mod types;
 pub mod watcher;
+pub mod withdraw_delay;
 
+pub use accounts::*;
+pub use fee::*;
+pub use nonce_bitmap::*;
 pub use rpc_fallback::{
     is_transient_solana_client_error, parse_solana_rpc_urls, run_with_solana_rpc_fallback,
 };
 pub use types::*;
 pub use watcher::*;
+pub use withdraw_delay::*;
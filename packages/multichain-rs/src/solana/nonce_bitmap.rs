@@ -0,0 +1,54 @@
+//! Solana `NonceBitmap` PDAs: one bit per approved `(src_chain, nonce)`, mirroring
+//! `state/nonce_bitmap.rs` of the `cl8y-bridge` program.
+
+use solana_sdk::pubkey::Pubkey;
+
+/// Seed of the per-(source chain, chunk) `NonceBitmap` PDA.
+pub const NONCE_BITMAP_SEED: &[u8] = b"nonce_bitmap";
+
+/// Nonces covered by one `NonceBitmap` account (1024 bytes of bits).
+pub const NONCES_PER_BITMAP_CHUNK: u64 = 8192;
+
+/// Chunk seed for `nonce` (little-endian chunk index).
+pub fn nonce_bitmap_chunk_seed(nonce: u64) -> [u8; 8] {
+    (nonce / NONCES_PER_BITMAP_CHUNK).to_le_bytes()
+}
+
+/// `NonceBitmap` PDA holding `nonce` for a source chain
+pub fn nonce_bitmap_pda(program_id: &Pubkey, src_chain: &[u8; 4], nonce: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            NONCE_BITMAP_SEED,
+            src_chain,
+            &nonce_bitmap_chunk_seed(nonce),
+        ],
+        program_id,
+    )
+    .0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chunk_seed_boundaries() {
+        assert_eq!(nonce_bitmap_chunk_seed(0), 0u64.to_le_bytes());
+        assert_eq!(nonce_bitmap_chunk_seed(8191), 0u64.to_le_bytes());
+        assert_eq!(nonce_bitmap_chunk_seed(8192), 1u64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_same_chunk_shares_pda() {
+        let program_id = Pubkey::new_unique();
+        let chain = [0, 0, 0, 1];
+        assert_eq!(
+            nonce_bitmap_pda(&program_id, &chain, 1),
+            nonce_bitmap_pda(&program_id, &chain, 8191)
+        );
+        assert_ne!(
+            nonce_bitmap_pda(&program_id, &chain, 8191),
+            nonce_bitmap_pda(&program_id, &chain, 8192)
+        );
+    }
+}
@@ -16,6 +16,41 @@ pub const WITHDRAW_APPROVE_EVENT_DISCRIMINATOR: [u8; 8] =
 pub const WITHDRAW_CANCEL_EVENT_DISCRIMINATOR: [u8; 8] =
     [0xfb, 0x37, 0x0c, 0x34, 0x4d, 0xcc, 0x99, 0x78];
 
+/// Anchor event discriminator for WithdrawSubmitEvent
+/// sha256("event:WithdrawSubmitEvent")[..8]
+pub const WITHDRAW_SUBMIT_EVENT_DISCRIMINATOR: [u8; 8] =
+    [0x97, 0xea, 0x6f, 0x44, 0x30, 0x08, 0xed, 0xa9];
+
+/// Anchor event discriminator for WithdrawExecuteEvent (SPL)
+/// sha256("event:WithdrawExecuteEvent")[..8]
+pub const WITHDRAW_EXECUTE_EVENT_DISCRIMINATOR: [u8; 8] =
+    [0x5c, 0x17, 0x6e, 0xac, 0x58, 0x7b, 0x68, 0x37];
+
+/// Anchor event discriminator for WithdrawExecuteNativeEvent (native SOL)
+/// sha256("event:WithdrawExecuteNativeEvent")[..8]
+pub const WITHDRAW_EXECUTE_NATIVE_EVENT_DISCRIMINATOR: [u8; 8] =
+    [0x57, 0x7c, 0x6f, 0xa0, 0xec, 0x0d, 0x8c, 0x77];
+
+/// Anchor event discriminator for RelayRequestedEvent
+/// sha256("event:RelayRequestedEvent")[..8]
+pub const RELAY_REQUESTED_EVENT_DISCRIMINATOR: [u8; 8] =
+    [0x50, 0xec, 0x27, 0x07, 0xd7, 0x6c, 0x38, 0xe7];
+
+/// Anchor event discriminator for RelayFeePaidEvent
+/// sha256("event:RelayFeePaidEvent")[..8]
+pub const RELAY_FEE_PAID_EVENT_DISCRIMINATOR: [u8; 8] =
+    [0x6c, 0x25, 0x54, 0x17, 0x85, 0xe8, 0x36, 0x28];
+
+/// Anchor event discriminator for RegisterTokenEvent
+/// sha256("event:RegisterTokenEvent")[..8]
+pub const REGISTER_TOKEN_EVENT_DISCRIMINATOR: [u8; 8] =
+    [0x4e, 0xa9, 0x7c, 0x0f, 0xcd, 0x47, 0x25, 0x06];
+
+/// Anchor event discriminator for SetRateLimitEvent
+/// sha256("event:SetRateLimitEvent")[..8]
+pub const SET_RATE_LIMIT_EVENT_DISCRIMINATOR: [u8; 8] =
+    [0x5c, 0x0d, 0x49, 0x36, 0x02, 0xa1, 0xff, 0xed];
+
 /// Parsed deposit event from the Solana bridge program
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SolanaDepositEvent {
@@ -43,6 +78,48 @@ pub struct SolanaWithdrawCancelEvent {
     pub canceler: Pubkey,
 }
 
+/// Parsed withdraw submit event from the Solana bridge program
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SolanaWithdrawSubmitEvent {
+    pub transfer_hash: [u8; 32],
+    pub src_chain: [u8; 4],
+    pub dest_account: [u8; 32],
+    /// Local mint (`Pubkey::default()` for native SOL)
+    pub token: [u8; 32],
+    /// Amount in source-chain units
+    pub amount: u128,
+    pub nonce: u64,
+    pub operator_gas: u64,
+}
+
+/// Parsed withdraw execute event (SPL or native SOL) from the Solana bridge program
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SolanaWithdrawExecuteEvent {
+    pub transfer_hash: [u8; 32],
+    pub recipient: Pubkey,
+    /// Amount paid to the recipient, after any relay fee, in local (destination) units
+    pub amount: u128,
+}
+
+/// Parsed relay request event (depositor opting a Solana deposit into a relayed withdraw)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SolanaRelayRequestedEvent {
+    pub transfer_hash: [u8; 32],
+    pub depositor: Pubkey,
+    pub dest_chain: [u8; 4],
+    /// Highest relay fee the depositor accepts, in source (Solana) units
+    pub max_relay_fee: u128,
+}
+
+/// Parsed relay fee payment from a relayed withdraw execute (emitted with the execute event)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SolanaRelayFeePaidEvent {
+    pub transfer_hash: [u8; 32],
+    pub relayer: Pubkey,
+    /// Fee paid to the relayer, in local (destination) units
+    pub amount: u128,
+}
+
 /// Configuration for connecting to a Solana cluster
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SolanaConfig {
@@ -84,4 +161,8 @@ pub enum SolanaEvent {
     Deposit(SolanaDepositEvent),
     WithdrawApprove(SolanaWithdrawApproveEvent),
     WithdrawCancel(SolanaWithdrawCancelEvent),
+    WithdrawSubmit(SolanaWithdrawSubmitEvent),
+    WithdrawExecute(SolanaWithdrawExecuteEvent),
+    RelayRequested(SolanaRelayRequestedEvent),
+    RelayFeePaid(SolanaRelayFeePaidEvent),
 }
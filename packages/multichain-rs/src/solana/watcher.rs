@@ -19,6 +19,29 @@ const PROGRAM_DATA_PREFIX: &str = "Program data: ";
 /// "Program data: ". The first 8 bytes are the event discriminator (sha256
 /// of "event:<EventName>" truncated), followed by Borsh-serialized data.
 pub fn parse_anchor_events(log_messages: &[String], program_id: &Pubkey) -> Vec<SolanaEvent> {
+    anchor_event_data(log_messages, program_id)
+        .into_iter()
+        .filter_map(|data| {
+            let discriminator: [u8; 8] = data[..8].try_into().unwrap();
+            try_parse_event(&discriminator, &data[8..])
+        })
+        .collect()
+}
+
+/// Whether the logs carry an Anchor event of the program with one of `discriminators`,
+/// for events this crate does not decode
+pub fn has_anchor_event(
+    log_messages: &[String],
+    program_id: &Pubkey,
+    discriminators: &[[u8; 8]],
+) -> bool {
+    anchor_event_data(log_messages, program_id)
+        .iter()
+        .any(|data| discriminators.iter().any(|d| data[..8] == d[..]))
+}
+
+/// Decoded "Program data: " payloads (discriminator included) logged by `program_id`
+fn anchor_event_data(log_messages: &[String], program_id: &Pubkey) -> Vec<Vec<u8>> {
     let mut events = Vec::new();
     let mut in_program = false;
     let program_id_str = program_id.to_string();
@@ -46,12 +69,7 @@ pub fn parse_anchor_events(log_messages: &[String], program_id: &Pubkey) -> Vec<
                     if data.len() < 8 {
                         continue;
                     }
-                    let discriminator: [u8; 8] = data[..8].try_into().unwrap();
-                    let payload = &data[8..];
-
-                    if let Some(event) = try_parse_event(&discriminator, payload) {
-                        events.push(event);
-                    }
+                    events.push(data);
                 }
                 Err(e) => {
                     debug!("Failed to decode base64 event data: {}", e);
@@ -91,6 +109,44 @@ fn try_parse_event(discriminator: &[u8; 8], payload: &[u8]) -> Option<SolanaEven
         }
     }
 
+    if discriminator == &WITHDRAW_SUBMIT_EVENT_DISCRIMINATOR {
+        match parse_withdraw_submit_event(payload) {
+            Ok(event) => return Some(SolanaEvent::WithdrawSubmit(event)),
+            Err(e) => {
+                warn!("Failed to parse WithdrawSubmitEvent: {}", e);
+            }
+        }
+    }
+
+    if discriminator == &WITHDRAW_EXECUTE_EVENT_DISCRIMINATOR
+        || discriminator == &WITHDRAW_EXECUTE_NATIVE_EVENT_DISCRIMINATOR
+    {
+        match parse_withdraw_execute_event(payload) {
+            Ok(event) => return Some(SolanaEvent::WithdrawExecute(event)),
+            Err(e) => {
+                warn!("Failed to parse WithdrawExecuteEvent: {}", e);
+            }
+        }
+    }
+
+    if discriminator == &RELAY_REQUESTED_EVENT_DISCRIMINATOR {
+        match parse_relay_requested_event(payload) {
+            Ok(event) => return Some(SolanaEvent::RelayRequested(event)),
+            Err(e) => {
+                warn!("Failed to parse RelayRequestedEvent: {}", e);
+            }
+        }
+    }
+
+    if discriminator == &RELAY_FEE_PAID_EVENT_DISCRIMINATOR {
+        match parse_relay_fee_paid_event(payload) {
+            Ok(event) => return Some(SolanaEvent::RelayFeePaid(event)),
+            Err(e) => {
+                warn!("Failed to parse RelayFeePaidEvent: {}", e);
+            }
+        }
+    }
+
     None
 }
 
@@ -185,6 +241,84 @@ fn parse_withdraw_cancel_event(payload: &[u8]) -> Result<SolanaWithdrawCancelEve
     })
 }
 
+fn parse_withdraw_submit_event(payload: &[u8]) -> Result<SolanaWithdrawSubmitEvent> {
+    // 32 + 4 + 32 + 32 + 16 + 8 + 8 = 132 bytes
+    if payload.len() < 132 {
+        return Err(eyre!(
+            "WithdrawSubmitEvent payload too short: {} bytes",
+            payload.len()
+        ));
+    }
+
+    Ok(SolanaWithdrawSubmitEvent {
+        transfer_hash: payload[..32].try_into().unwrap(),
+        src_chain: payload[32..36].try_into().unwrap(),
+        dest_account: payload[36..68].try_into().unwrap(),
+        token: payload[68..100].try_into().unwrap(),
+        amount: u128::from_le_bytes(payload[100..116].try_into().unwrap()),
+        nonce: u64::from_le_bytes(payload[116..124].try_into().unwrap()),
+        operator_gas: u64::from_le_bytes(payload[124..132].try_into().unwrap()),
+    })
+}
+
+fn parse_withdraw_execute_event(payload: &[u8]) -> Result<SolanaWithdrawExecuteEvent> {
+    if payload.len() < 32 + 32 + 16 {
+        return Err(eyre!(
+            "WithdrawExecuteEvent payload too short: {} bytes",
+            payload.len()
+        ));
+    }
+
+    let mut transfer_hash = [0u8; 32];
+    transfer_hash.copy_from_slice(&payload[..32]);
+
+    let recipient =
+        Pubkey::try_from(&payload[32..64]).map_err(|e| eyre!("Invalid recipient pubkey: {}", e))?;
+
+    let amount = u128::from_le_bytes(payload[64..80].try_into().unwrap());
+
+    Ok(SolanaWithdrawExecuteEvent {
+        transfer_hash,
+        recipient,
+        amount,
+    })
+}
+
+fn parse_relay_requested_event(payload: &[u8]) -> Result<SolanaRelayRequestedEvent> {
+    // 32 + 32 + 4 + 16 = 84 bytes
+    if payload.len() < 84 {
+        return Err(eyre!(
+            "RelayRequestedEvent payload too short: {} bytes",
+            payload.len()
+        ));
+    }
+
+    Ok(SolanaRelayRequestedEvent {
+        transfer_hash: payload[..32].try_into().unwrap(),
+        depositor: Pubkey::try_from(&payload[32..64])
+            .map_err(|e| eyre!("Invalid depositor pubkey: {}", e))?,
+        dest_chain: payload[64..68].try_into().unwrap(),
+        max_relay_fee: u128::from_le_bytes(payload[68..84].try_into().unwrap()),
+    })
+}
+
+fn parse_relay_fee_paid_event(payload: &[u8]) -> Result<SolanaRelayFeePaidEvent> {
+    // 32 + 32 + 16 = 80 bytes
+    if payload.len() < 80 {
+        return Err(eyre!(
+            "RelayFeePaidEvent payload too short: {} bytes",
+            payload.len()
+        ));
+    }
+
+    Ok(SolanaRelayFeePaidEvent {
+        transfer_hash: payload[..32].try_into().unwrap(),
+        relayer: Pubkey::try_from(&payload[32..64])
+            .map_err(|e| eyre!("Invalid relayer pubkey: {}", e))?,
+        amount: u128::from_le_bytes(payload[64..80].try_into().unwrap()),
+    })
+}
+
 /// Get signatures for the bridge program, with cursor-based pagination.
 /// Returns signatures newest-first; caller should reverse for chronological processing.
 pub fn get_signatures_for_program(
@@ -192,11 +326,25 @@ pub fn get_signatures_for_program(
     program_id: &Pubkey,
     until: Option<&Signature>,
     limit: usize,
+) -> ClientResult<Vec<solana_client::rpc_response::RpcConfirmedTransactionStatusWithSignature>> {
+    get_signatures_for_program_range(client, program_id, None, until, limit)
+}
+
+/// Get up to `limit` bridge program signatures strictly older than `before` and strictly
+/// newer than `until` (either bound optional), newest-first. Page backwards by passing
+/// the oldest returned signature as the next `before`.
+#[allow(clippy::result_large_err)] // Solana ClientError
+pub fn get_signatures_for_program_range(
+    client: &RpcClient,
+    program_id: &Pubkey,
+    before: Option<&Signature>,
+    until: Option<&Signature>,
+    limit: usize,
 ) -> ClientResult<Vec<solana_client::rpc_response::RpcConfirmedTransactionStatusWithSignature>> {
     use solana_client::rpc_client::GetConfirmedSignaturesForAddress2Config;
 
     let config = GetConfirmedSignaturesForAddress2Config {
-        before: None,
+        before: before.copied(),
         until: until.copied(),
         limit: Some(limit),
         commitment: Some(CommitmentConfig::finalized()),
@@ -0,0 +1,98 @@
+//! Solana withdraw delay (cancel window) resolution.
+//!
+//! Mirrors the on-chain `delay.rs` of the `cl8y-bridge` program so watchers can
+//! compute when a pending withdraw becomes executable:
+//!
+//! 1. `ChainWithdrawDelay` for the withdraw's source chain, else `BridgeConfig.withdraw_delay`
+//! 2. raised to `LargeTransferDelay.withdraw_delay` when the amount reaches its threshold
+
+use solana_sdk::pubkey::Pubkey;
+
+/// Seed of the per-source-chain `ChainWithdrawDelay` PDA.
+pub const CHAIN_WITHDRAW_DELAY_SEED: &[u8] = b"chain_delay";
+
+/// Seed of the per-(source chain, local token) `LargeTransferDelay` PDA.
+pub const LARGE_TRANSFER_DELAY_SEED: &[u8] = b"large_delay";
+
+/// Upper bound the program accepts for a `LargeTransferDelay` (7 days), i.e. the
+/// widest cancel window any withdraw can have.
+pub const MAX_LARGE_TRANSFER_DELAY_SECS: u64 = 604_800;
+
+/// Effective delay in seconds; a large-transfer delay never shortens the window.
+pub fn effective_withdraw_delay(
+    bridge_delay: i64,
+    chain_delay: Option<i64>,
+    large_transfer: Option<(u128, i64)>,
+    amount: u128,
+) -> i64 {
+    let base = chain_delay.unwrap_or(bridge_delay);
+    match large_transfer {
+        Some((threshold, delay)) if amount >= threshold => base.max(delay),
+        _ => base,
+    }
+}
+
+/// `ChainWithdrawDelay` PDA for a source chain
+pub fn chain_withdraw_delay_pda(program_id: &Pubkey, src_chain: &[u8; 4]) -> Pubkey {
+    Pubkey::find_program_address(&[CHAIN_WITHDRAW_DELAY_SEED, src_chain], program_id).0
+}
+
+/// `LargeTransferDelay` PDA for a source chain and local token (`PendingWithdraw.token`)
+pub fn large_transfer_delay_pda(
+    program_id: &Pubkey,
+    src_chain: &[u8; 4],
+    token: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[LARGE_TRANSFER_DELAY_SEED, src_chain, token.as_ref()],
+        program_id,
+    )
+    .0
+}
+
+/// `ChainWithdrawDelay.withdraw_delay` from raw account data
+/// (8-byte discriminator, `chain_id: [u8; 4]`, `withdraw_delay: i64`, `bump`).
+pub fn parse_chain_withdraw_delay(data: &[u8]) -> Option<i64> {
+    let bytes = data.get(12..20)?;
+    Some(i64::from_le_bytes(bytes.try_into().ok()?))
+}
+
+/// `(threshold, withdraw_delay)` from raw `LargeTransferDelay` account data
+/// (8-byte discriminator, `token_mapping: Pubkey`, `threshold: u128`, `withdraw_delay: i64`, `bump`).
+pub fn parse_large_transfer_delay(data: &[u8]) -> Option<(u128, i64)> {
+    let threshold = u128::from_le_bytes(data.get(40..56)?.try_into().ok()?);
+    let delay = i64::from_le_bytes(data.get(56..64)?.try_into().ok()?);
+    Some((threshold, delay))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chain_delay_replaces_bridge_delay() {
+        assert_eq!(effective_withdraw_delay(300, None, None, 1), 300);
+        assert_eq!(effective_withdraw_delay(300, Some(60), None, 1), 60);
+    }
+
+    #[test]
+    fn test_large_transfer_extends_at_threshold() {
+        let large = Some((1_000u128, 3_600));
+        assert_eq!(effective_withdraw_delay(300, None, large, 999), 300);
+        assert_eq!(effective_withdraw_delay(300, None, large, 1_000), 3_600);
+        assert_eq!(effective_withdraw_delay(7_200, None, large, 1_000), 7_200);
+    }
+
+    #[test]
+    fn test_parse_account_layouts() {
+        let mut chain = vec![0u8; 21];
+        chain[12..20].copy_from_slice(&900i64.to_le_bytes());
+        assert_eq!(parse_chain_withdraw_delay(&chain), Some(900));
+        assert_eq!(parse_chain_withdraw_delay(&chain[..15]), None);
+
+        let mut large = vec![0u8; 65];
+        large[40..56].copy_from_slice(&5_000u128.to_le_bytes());
+        large[56..64].copy_from_slice(&3_600i64.to_le_bytes());
+        assert_eq!(parse_large_transfer_delay(&large), Some((5_000, 3_600)));
+    }
+}
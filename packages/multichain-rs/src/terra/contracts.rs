@@ -82,6 +82,24 @@ pub enum ExecuteMsgV2 {
         nonce: u64,
     },
 
+    /// Operator submits a withdrawal for a recipient whose depositor requested a relay
+    WithdrawSubmitRelayed {
+        /// Source chain ID (4 bytes as base64)
+        src_chain: String,
+        /// Depositor on the source chain (32 bytes as base64)
+        src_account: String,
+        /// Token to withdraw (denom for native, address for CW20)
+        token: String,
+        /// Recipient Terra address
+        recipient: String,
+        /// Amount to withdraw
+        amount: String,
+        /// Nonce from the source chain deposit
+        nonce: u64,
+        /// Fee paid to the operator on execution, in source chain decimals
+        relay_fee: String,
+    },
+
     /// Operator approves a pending withdrawal
     WithdrawApprove {
         /// The withdraw hash (32 bytes as base64)
@@ -219,6 +237,42 @@ pub enum QueryMsg {
 
     /// V2: Get token type ("lock_unlock" or "mint_burn")
     TokenType { token: String },
+
+    /// V2: Get an outgoing deposit by its hash
+    XchainHashId {
+        /// The deposit hash (32 bytes as base64)
+        xchain_hash_id: String,
+    },
+
+    /// V2: Get an outgoing deposit by its nonce
+    DepositByNonce { nonce: u64 },
+
+    /// Bridge-wide transfer counters and collected fees
+    Stats {},
+
+    /// Amount of `token` held in lock/unlock custody
+    LockedBalance { token: String },
+
+    /// List registered tokens (paginated, max 30)
+    Tokens {
+        /// Cursor: the last token of the previous page
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    /// List every outgoing token mapping (paginated, max 100)
+    AllTokenDestMappings {
+        /// Pagination cursor: "token:dest_chain_hex"
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    /// List every rate limit config (paginated, max 100)
+    AllRateLimits {
+        /// Cursor: the last token of the previous page
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
 }
 
 // ============================================================================
@@ -334,6 +388,33 @@ pub fn build_withdraw_submit_msg_v2(
 // V2 Withdrawal Message Builders
 // ============================================================================
 
+/// Build a WithdrawSubmitRelayed message (V2)
+///
+/// Operator submission of a withdrawal whose depositor requested a relay.
+#[allow(clippy::too_many_arguments)]
+pub fn build_withdraw_submit_relayed_msg_v2(
+    src_chain: [u8; 4],
+    src_account: [u8; 32],
+    token: &str,
+    recipient: &str,
+    amount: u128,
+    nonce: u64,
+    relay_fee: u128,
+) -> ExecuteMsgV2 {
+    use base64::Engine;
+    let encoder = base64::engine::general_purpose::STANDARD;
+
+    ExecuteMsgV2::WithdrawSubmitRelayed {
+        src_chain: encoder.encode(src_chain),
+        src_account: encoder.encode(src_account),
+        token: token.to_string(),
+        recipient: recipient.to_string(),
+        amount: amount.to_string(),
+        nonce,
+        relay_fee: relay_fee.to_string(),
+    }
+}
+
 /// Build a WithdrawApprove message (V2)
 ///
 /// In V2, operator just approves the hash. User already submitted the withdrawal.
@@ -458,6 +539,9 @@ pub struct PendingWithdrawResponse {
     pub submitted_at: Option<u64>,
     /// Timestamp when approved (0 if not yet)
     pub approved_at: Option<u64>,
+    /// Whether approved
+    #[serde(default)]
+    pub approved: bool,
     /// Whether cancelled
     pub cancelled: bool,
     /// Whether executed
@@ -466,6 +550,29 @@ pub struct PendingWithdrawResponse {
     pub cancel_window_remaining: Option<u64>,
 }
 
+/// Outgoing deposit record (V2 `xchain_hash_id` / `deposit_by_nonce` queries)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DepositInfoResponse {
+    /// Deposit hash (32 bytes, base64)
+    pub xchain_hash_id: String,
+    /// Source chain ID (4 bytes, base64)
+    pub src_chain: String,
+    /// Destination chain ID (4 bytes, base64)
+    pub dest_chain: String,
+    /// Source account (32 bytes universal address, base64)
+    pub src_account: String,
+    /// Destination token (32 bytes, base64)
+    pub dest_token_address: String,
+    /// Destination account (32 bytes universal address, base64)
+    pub dest_account: String,
+    /// Net amount after fees
+    pub amount: String,
+    /// Deposit nonce
+    pub nonce: u64,
+    /// Block time of the deposit in nanoseconds
+    pub deposited_at: String,
+}
+
 /// A single entry in the paginated pending withdrawals list (V2)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PendingWithdrawalEntry {
@@ -530,6 +637,28 @@ pub struct IsCancelerResponse {
     pub is_canceler: bool,
 }
 
+/// Response from Stats query
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatsResponse {
+    pub total_outgoing_txs: u64,
+    pub total_incoming_txs: u64,
+    pub total_fees_collected: String,
+}
+
+/// Response from LockedBalance query
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LockedBalanceResponse {
+    pub token: String,
+    pub amount: String,
+}
+
+/// Response from TokenType query (V2)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenTypeResponse {
+    /// `lock_unlock` or `mint_burn`
+    pub token_type: String,
+}
+
 /// Response for a single incoming token mapping query
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IncomingTokenMappingResponse {
@@ -551,6 +680,55 @@ pub struct IncomingTokenMappingsResponse {
     pub mappings: Vec<IncomingTokenMappingResponse>,
 }
 
+/// A registered token, from the Tokens query
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenResponse {
+    pub token: String,
+    pub is_native: bool,
+    pub terra_decimals: u8,
+    pub enabled: bool,
+    pub min_bridge_amount: Option<String>,
+    pub max_bridge_amount: Option<String>,
+}
+
+/// Response for the paginated tokens query
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokensResponse {
+    pub tokens: Vec<TokenResponse>,
+}
+
+/// One outgoing token mapping, from the AllTokenDestMappings query
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenDestMappingEntry {
+    /// Local Terra denom or CW20 address
+    pub token: String,
+    /// Destination chain ID (4 bytes as hex, no `0x`)
+    pub dest_chain: String,
+    /// Destination token bytes32 (32 bytes as base64)
+    pub dest_token: String,
+    pub dest_decimals: u8,
+}
+
+/// Response for the paginated outgoing token mappings query
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AllTokenDestMappingsResponse {
+    pub mappings: Vec<TokenDestMappingEntry>,
+}
+
+/// One rate limit config, from the AllRateLimits query
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RateLimitEntry {
+    pub token: String,
+    pub max_per_transaction: String,
+    pub max_per_period: String,
+}
+
+/// Response for the paginated rate limits query
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AllRateLimitsResponse {
+    pub rate_limits: Vec<RateLimitEntry>,
+}
+
 // ============================================================================
 // V2 Incoming Token Registry Message Builders
 // ============================================================================
@@ -603,6 +781,25 @@ mod tests {
         assert!(json.contains("xchain_hash_id"));
     }
 
+    #[test]
+    fn test_v2_withdraw_submit_relayed_serialization() {
+        let msg = build_withdraw_submit_relayed_msg_v2(
+            [0, 0, 0, 1],
+            [4u8; 32],
+            "uluna",
+            "terra1recipient",
+            1_000_000,
+            7,
+            3_000,
+        );
+        let json = serde_json::to_value(&msg).unwrap();
+        let inner = &json["withdraw_submit_relayed"];
+        assert_eq!(inner["src_chain"], "AAAAAQ==");
+        assert_eq!(inner["amount"], "1000000");
+        assert_eq!(inner["nonce"], 7);
+        assert_eq!(inner["relay_fee"], "3000");
+    }
+
     #[test]
     fn test_v2_withdraw_cancel_serialization() {
         let msg = build_withdraw_cancel_msg_v2([2u8; 32]);
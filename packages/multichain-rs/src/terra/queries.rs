@@ -10,10 +10,11 @@ use std::time::Duration;
 use url::Url;
 
 use crate::terra::contracts::{
-    CancelWindowResponse, ConfigResponse, IsCancelerResponse, IsOperatorResponse,
-    PendingWithdrawResponse, QueryMsg, ThisChainIdResponse, WithdrawDelayResponse,
+    CancelWindowResponse, ConfigResponse, DepositInfoResponse, IsCancelerResponse,
+    IsOperatorResponse, LockedBalanceResponse, PendingWithdrawResponse, QueryMsg, StatsResponse,
+    ThisChainIdResponse, TokenTypeResponse, WithdrawDelayResponse,
 };
-use crate::terra::tokens::{query_cw20_balance, query_native_balance};
+use crate::terra::tokens::{query_cw20_balance, query_cw20_token_info, query_native_balance};
 use crate::types::ChainId;
 
 /// Builds `GET /cosmos/tx/v1beta1/txs` for wasm contract activity at a single block height.
@@ -37,6 +38,25 @@ pub fn lcd_get_txs_event_url_contract_at_height(
     Ok(url.into())
 }
 
+/// Builds `GET /cosmos/tx/v1beta1/txs` for contract transactions with the given `wasm.action`
+/// above `height`.
+pub fn lcd_get_txs_event_url_contract_action_after(
+    lcd_url: &str,
+    contract_address: &str,
+    action: &str,
+    height: u64,
+) -> Result<String> {
+    let base = format!("{}/cosmos/tx/v1beta1/txs", lcd_url.trim_end_matches('/'));
+    let mut url =
+        Url::parse(&base).wrap_err_with(|| format!("invalid LCD URL for tx search: {base}"))?;
+    let tm_query = format!(
+        "wasm._contract_address='{}' AND wasm.action='{}' AND tx.height>{}",
+        contract_address, action, height
+    );
+    url.query_pairs_mut().append_pair("query", &tm_query);
+    Ok(url.into())
+}
+
 /// Terra bridge query client
 ///
 /// Provides typed query methods for the Terra bridge contract.
@@ -65,6 +85,11 @@ impl TerraQueryClient {
         }
     }
 
+    /// Bridge contract address
+    pub fn bridge_address(&self) -> &str {
+        &self.bridge_address
+    }
+
     /// Generic smart contract query
     pub async fn query_contract<Q: Serialize, R: for<'de> Deserialize<'de>>(
         &self,
@@ -197,6 +222,64 @@ impl TerraQueryClient {
         .await
     }
 
+    /// Get an outgoing deposit by hash (`None` if this bridge has no such deposit)
+    pub async fn get_deposit(
+        &self,
+        xchain_hash_id: [u8; 32],
+    ) -> Result<Option<DepositInfoResponse>> {
+        use base64::Engine;
+        let hash_b64 = base64::engine::general_purpose::STANDARD.encode(xchain_hash_id);
+
+        self.query_contract(
+            &self.bridge_address,
+            &QueryMsg::XchainHashId {
+                xchain_hash_id: hash_b64,
+            },
+        )
+        .await
+    }
+
+    /// Get an outgoing deposit by nonce
+    pub async fn get_deposit_by_nonce(&self, nonce: u64) -> Result<Option<DepositInfoResponse>> {
+        self.query_contract(&self.bridge_address, &QueryMsg::DepositByNonce { nonce })
+            .await
+    }
+
+    /// Get bridge-wide transfer counters and collected fees
+    pub async fn get_stats(&self) -> Result<StatsResponse> {
+        self.query_contract(&self.bridge_address, &QueryMsg::Stats {})
+            .await
+    }
+
+    /// Get the amount of `token` held in lock/unlock custody
+    pub async fn get_locked_balance(&self, token: &str) -> Result<u128> {
+        let response: LockedBalanceResponse = self
+            .query_contract(
+                &self.bridge_address,
+                &QueryMsg::LockedBalance {
+                    token: token.to_string(),
+                },
+            )
+            .await?;
+        response
+            .amount
+            .parse()
+            .map_err(|e| eyre!("Invalid locked balance {}: {}", response.amount, e))
+    }
+
+    /// Get the registered token type (`lock_unlock` or `mint_burn`)
+    pub async fn get_token_type(&self, token: &str) -> Result<String> {
+        let response: TokenTypeResponse = self
+            .query_contract(
+                &self.bridge_address,
+                &QueryMsg::TokenType {
+                    token: token.to_string(),
+                },
+            )
+            .await?;
+        Ok(response.token_type)
+    }
+
     /// Compute transfer hash via on-chain query (V2 unified 7-field)
     #[allow(clippy::too_many_arguments)]
     pub async fn compute_xchain_hash_id_v2(
@@ -259,6 +342,14 @@ impl TerraQueryClient {
         query_cw20_balance(&self.lcd_url, token_address, account).await
     }
 
+    /// Get the total supply of a CW20 token
+    pub async fn get_cw20_total_supply(&self, token_address: &str) -> Result<u128> {
+        let info = query_cw20_token_info(&self.lcd_url, token_address).await?;
+        info.total_supply
+            .parse()
+            .map_err(|e| eyre!("Invalid total supply {}: {}", info.total_supply, e))
+    }
+
     /// Get all native token balances for an address
     pub async fn get_all_balances(&self, address: &str) -> Result<Vec<CoinBalance>> {
         let url = format!("{}/cosmos/bank/v1beta1/balances/{}", self.lcd_url, address);
@@ -352,10 +443,28 @@ impl TerraQueryClient {
     ) -> Result<Vec<serde_json::Value>> {
         let url =
             lcd_get_txs_event_url_contract_at_height(&self.lcd_url, contract_address, height)?;
+        self.search_txs(&url).await
+    }
+
+    /// Search for bridge transactions with the given `wasm.action` above `height`
+    pub async fn search_bridge_action_txs(
+        &self,
+        action: &str,
+        height: u64,
+    ) -> Result<Vec<serde_json::Value>> {
+        let url = lcd_get_txs_event_url_contract_action_after(
+            &self.lcd_url,
+            &self.bridge_address,
+            action,
+            height,
+        )?;
+        self.search_txs(&url).await
+    }
 
+    async fn search_txs(&self, url: &str) -> Result<Vec<serde_json::Value>> {
         let response = self
             .client
-            .get(&url)
+            .get(url)
             .send()
             .await
             .wrap_err("Failed to search transactions")?;
@@ -408,6 +517,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn lcd_get_txs_event_url_filters_action_above_height() {
+        let u = lcd_get_txs_event_url_contract_action_after(
+            "https://lcd.example/",
+            "terra1testcontractaddress000000000000",
+            "set_token_destination",
+            100,
+        )
+        .unwrap();
+        let query = Url::parse(&u)
+            .unwrap()
+            .query_pairs()
+            .find(|(k, _)| k == "query")
+            .map(|(_, v)| v.into_owned())
+            .expect("query param");
+        assert_eq!(
+            query,
+            "wasm._contract_address='terra1testcontractaddress000000000000' \
+             AND wasm.action='set_token_destination' AND tx.height>100"
+        );
+    }
+
     #[test]
     fn test_query_client_creation() {
         let client = TerraQueryClient::new("http://localhost:1317", "terra1...");
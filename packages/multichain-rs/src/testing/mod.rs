@@ -9,9 +9,13 @@
 //! - `terra_user` - Simulate Terra user deposits/withdrawals as regular EOAs
 //! - `mock_deposits` - Create test deposit scenarios
 //! - `assertions` - Common test assertions
+//! - `sim` - In-process simulated EVM, Terra LCD and Solana RPC backends
+//! - `chaos` - Fault-injecting RPC proxy driven by a scenario file
 
 pub mod assertions;
+pub mod chaos;
 pub mod mock_deposits;
+pub mod sim;
 pub mod terra_user;
 pub mod user_eoa;
 
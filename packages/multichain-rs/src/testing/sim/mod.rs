@@ -0,0 +1,286 @@
+//! In-Process Simulated Chain Backends
+//!
+//! Local HTTP stand-ins for the chain endpoints the operator and canceler talk to, so their
+//! flows can be tested per-PR without Anvil, LocalTerra or a Solana validator in Docker.
+//! Each simulator binds `127.0.0.1:0`, serves the subset of calls our clients make, and is
+//! driven from the test through scripting methods (deposits, approvals, reorgs, time).
+//!
+//! ## Backends
+//!
+//! - [`SimEvm`] - Ethereum JSON-RPC serving the V2 `Bridge` contract (feature `evm`)
+//! - [`SimTerraLcd`] - Terra LCD REST serving the CosmWasm bridge queries and tx endpoints
+//! - [`SimSolanaRpc`] - Solana JSON-RPC serving accounts, program logs and tx submission
+//!   (feature `solana`)
+//!
+//! ## Faults
+//!
+//! Every backend owns a [`FaultPlan`]; rules match a JSON-RPC method name (or an LCD route
+//! name, see [`SimTerraLcd`]) and fire for a bounded number of requests:
+//!
+//! ```ignore
+//! evm.faults().add(FaultRule::new(Fault::HttpStatus(429)).method("eth_getLogs").times(2));
+//! ```
+//!
+//! Stale heads and reorgs are chain state rather than faults: see `set_head_lag` / `reorg`
+//! on each backend.
+
+#[cfg(feature = "evm")]
+pub mod evm;
+#[cfg(feature = "solana")]
+pub mod solana;
+pub mod terra;
+
+#[cfg(feature = "evm")]
+pub use evm::*;
+#[cfg(feature = "solana")]
+pub use solana::*;
+pub use terra::*;
+
+use axum::{
+    body::Bytes,
+    extract::State,
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    routing::post,
+    Router,
+};
+use eyre::Result;
+use serde_json::{json, Value};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+
+/// A failure injected into a simulated endpoint's response
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fault {
+    /// Delay the response, then answer normally
+    Latency(Duration),
+    /// Answer with this HTTP status and a short text body (e.g. 429, 503)
+    HttpStatus(u16),
+    /// Answer with a JSON-RPC error object (the LCD returns it as HTTP 500)
+    RpcError { code: i64, message: String },
+    /// Cut the response body in half so it is no longer valid JSON
+    TruncatedBody,
+}
+
+/// One fault-injection rule
+#[derive(Debug, Clone)]
+pub struct FaultRule {
+    method: Option<String>,
+    fault: Fault,
+    remaining: Option<u32>,
+}
+
+impl FaultRule {
+    /// A rule that fires on every request until removed
+    pub fn new(fault: Fault) -> Self {
+        Self {
+            method: None,
+            fault,
+            remaining: None,
+        }
+    }
+
+    /// Only fire for this JSON-RPC method / LCD route
+    pub fn method(mut self, method: &str) -> Self {
+        self.method = Some(method.to_string());
+        self
+    }
+
+    /// Fire for the next `n` matching requests, then expire
+    pub fn times(mut self, n: u32) -> Self {
+        self.remaining = Some(n);
+        self
+    }
+}
+
+/// Shared, mutable list of fault rules (first match wins)
+#[derive(Debug, Clone, Default)]
+pub struct FaultPlan {
+    rules: Arc<Mutex<Vec<FaultRule>>>,
+}
+
+impl FaultPlan {
+    /// Append a rule
+    pub fn add(&self, rule: FaultRule) {
+        lock(&self.rules).push(rule);
+    }
+
+    /// Remove all rules
+    pub fn clear(&self) {
+        lock(&self.rules).clear();
+    }
+
+    /// Consume the first rule matching `method`, if any
+    pub(crate) fn take(&self, method: &str) -> Option<Fault> {
+        let mut rules = lock(&self.rules);
+        let idx = rules
+            .iter()
+            .position(|r| r.method.as_deref().is_none_or(|m| m == method))?;
+        let fault = rules[idx].fault.clone();
+        if let Some(remaining) = rules[idx].remaining.as_mut() {
+            *remaining -= 1;
+            if *remaining == 0 {
+                rules.remove(idx);
+            }
+        }
+        Some(fault)
+    }
+}
+
+/// A running simulator HTTP server; stops when dropped
+pub struct SimServer {
+    url: String,
+    handle: JoinHandle<()>,
+}
+
+impl SimServer {
+    /// Serve `router` on an ephemeral localhost port
+    pub async fn start(router: Router) -> Result<Self> {
+        Self::bind("127.0.0.1:0", router).await
+    }
+
+    /// Serve `router` on `addr` (e.g. `0.0.0.0:18545`)
+    pub async fn bind(addr: &str, router: Router) -> Result<Self> {
+        let listener = TcpListener::bind(addr).await?;
+        let url = format!("http://{}", listener.local_addr()?);
+        let handle = tokio::spawn(async move {
+            if let Err(e) = axum::serve(listener, router).await {
+                tracing::warn!(error = %e, "Simulator server stopped");
+            }
+        });
+        Ok(Self { url, handle })
+    }
+
+    /// Base URL (`http://127.0.0.1:<port>`)
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+}
+
+impl Drop for SimServer {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+/// JSON-RPC error returned by a simulated method
+#[derive(Debug, Clone)]
+pub(crate) struct RpcError {
+    pub code: i64,
+    pub message: String,
+}
+
+impl RpcError {
+    pub fn invalid_params(message: impl Into<String>) -> Self {
+        Self {
+            code: -32602,
+            message: message.into(),
+        }
+    }
+
+    pub fn method_not_found(method: &str) -> Self {
+        Self {
+            code: -32601,
+            message: format!("the method {method} does not exist/is not available"),
+        }
+    }
+}
+
+/// A simulated JSON-RPC node
+pub(crate) trait RpcBackend: Send + Sync + 'static {
+    fn faults(&self) -> &FaultPlan;
+    fn handle(&self, method: &str, params: &Value) -> Result<Value, RpcError>;
+}
+
+/// Router answering single and batch JSON-RPC requests on `POST /`
+pub(crate) fn rpc_router<B: RpcBackend>(backend: Arc<B>) -> Router {
+    Router::new()
+        .route("/", post(rpc_handler::<B>))
+        .with_state(backend)
+}
+
+async fn rpc_handler<B: RpcBackend>(State(backend): State<Arc<B>>, body: Bytes) -> Response {
+    let parsed: Value = match serde_json::from_slice(&body) {
+        Ok(v) => v,
+        Err(e) => {
+            let err = json!({
+                "jsonrpc": "2.0",
+                "id": null,
+                "error": { "code": -32700, "message": format!("parse error: {e}") },
+            });
+            return json_response(StatusCode::OK, err.to_string());
+        }
+    };
+    let (calls, batch) = match parsed {
+        Value::Array(calls) => (calls, true),
+        call => (vec![call], false),
+    };
+
+    let fault = calls.iter().find_map(|c| {
+        backend
+            .faults()
+            .take(c["method"].as_str().unwrap_or_default())
+    });
+    let mut injected = None;
+    let mut truncate = false;
+    match fault {
+        Some(Fault::Latency(delay)) => tokio::time::sleep(delay).await,
+        Some(Fault::HttpStatus(code)) => return status_response(code),
+        Some(Fault::RpcError { code, message }) => injected = Some(RpcError { code, message }),
+        Some(Fault::TruncatedBody) => truncate = true,
+        None => {}
+    }
+
+    let mut responses: Vec<Value> = calls
+        .iter()
+        .map(|call| {
+            let method = call["method"].as_str().unwrap_or_default();
+            let outcome = match &injected {
+                Some(err) => Err(err.clone()),
+                None => backend.handle(method, &call["params"]),
+            };
+            match outcome {
+                Ok(result) => json!({ "jsonrpc": "2.0", "id": call["id"], "result": result }),
+                Err(e) => json!({
+                    "jsonrpc": "2.0",
+                    "id": call["id"],
+                    "error": { "code": e.code, "message": e.message },
+                }),
+            }
+        })
+        .collect();
+
+    let body = if batch {
+        Value::Array(responses)
+    } else {
+        responses.remove(0)
+    };
+    let mut text = body.to_string();
+    if truncate {
+        text.truncate(text.len() / 2);
+    }
+    json_response(StatusCode::OK, text)
+}
+
+pub(crate) fn json_response(status: StatusCode, body: String) -> Response {
+    (status, [(header::CONTENT_TYPE, "application/json")], body).into_response()
+}
+
+pub(crate) fn status_response(code: u16) -> Response {
+    let status = StatusCode::from_u16(code).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+    (status, format!("simulated HTTP {code}")).into_response()
+}
+
+pub(crate) fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    // A panicking test thread must not wedge the simulator for the rest of the test.
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+pub(crate) fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
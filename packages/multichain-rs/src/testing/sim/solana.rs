@@ -0,0 +1,499 @@
+//! Simulated Solana RPC
+//!
+//! JSON-RPC stand-in for the calls `RpcClient` makes on behalf of the operator and canceler:
+//! account reads (`getAccountInfo`, `getMultipleAccounts`), program history
+//! (`getSignaturesForAddress`, `getTransaction` with Anchor `Program data:` logs), and
+//! submission (`getLatestBlockhash`, `sendTransaction`, `getSignatureStatuses`).
+//!
+//! Accounts are raw bytes set by the test. Submitted transactions are decoded and recorded as
+//! [`SimSolanaInstruction`]s without executing any program logic; scripted events (deposits,
+//! approvals) are committed as program transactions carrying the Anchor event logs.
+
+use super::{lock, now_secs, rpc_router, FaultPlan, RpcBackend, RpcError, SimServer};
+use crate::hash::keccak256;
+use crate::solana::{
+    DEPOSIT_EVENT_DISCRIMINATOR, WITHDRAW_APPROVE_EVENT_DISCRIMINATOR,
+    WITHDRAW_CANCEL_EVENT_DISCRIMINATOR,
+};
+use crate::testing::MockDeposit;
+use base64::Engine;
+use eyre::Result;
+use serde_json::{json, Value};
+use solana_client::rpc_response::RpcConfirmedTransactionStatusWithSignature;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_sdk::transaction::{Transaction, TransactionError, VersionedTransaction};
+use solana_transaction_status::option_serializer::OptionSerializer;
+use solana_transaction_status::{
+    EncodedConfirmedTransactionWithStatusMeta, EncodedTransaction,
+    EncodedTransactionWithStatusMeta, TransactionBinaryEncoding, TransactionConfirmationStatus,
+    UiTransactionStatusMeta,
+};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+
+const B64: base64::engine::GeneralPurpose = base64::engine::general_purpose::STANDARD;
+const LAMPORTS: u64 = 1_000_000_000;
+
+/// Anchor instruction discriminator: `sha256("global:<name>")[..8]`
+pub fn instruction_discriminator(name: &str) -> [u8; 8] {
+    let hash = solana_sdk::hash::hash(format!("global:{name}").as_bytes());
+    let mut d = [0u8; 8];
+    d.copy_from_slice(&hash.to_bytes()[..8]);
+    d
+}
+
+/// An instruction received through `sendTransaction`
+#[derive(Debug, Clone)]
+pub struct SimSolanaInstruction {
+    pub signature: Signature,
+    pub program_id: Pubkey,
+    pub accounts: Vec<Pubkey>,
+    pub data: Vec<u8>,
+}
+
+impl SimSolanaInstruction {
+    /// Whether this is the Anchor instruction `name` (e.g. `"withdraw_cancel"`)
+    pub fn is(&self, name: &str) -> bool {
+        self.data.starts_with(&instruction_discriminator(name))
+    }
+}
+
+#[derive(Clone)]
+struct SimAccount {
+    owner: Pubkey,
+    lamports: u64,
+    data: Vec<u8>,
+}
+
+struct SimSolanaTx {
+    signature: Signature,
+    slot: u64,
+    block_time: i64,
+    accounts: Vec<Pubkey>,
+    logs: Vec<String>,
+    failed: bool,
+    raw: Vec<u8>,
+}
+
+struct SolanaState {
+    program_id: Pubkey,
+    slot: u64,
+    time: i64,
+    accounts: HashMap<Pubkey, SimAccount>,
+    txs: Vec<SimSolanaTx>,
+    instructions: Vec<SimSolanaInstruction>,
+    counter: u64,
+}
+
+struct SolanaBackend {
+    state: Mutex<SolanaState>,
+    faults: FaultPlan,
+}
+
+/// In-process Solana JSON-RPC node for one bridge program
+pub struct SimSolanaRpc {
+    backend: Arc<SolanaBackend>,
+    server: SimServer,
+}
+
+impl SimSolanaRpc {
+    /// Start a simulated cluster at slot 1 with the wall-clock time
+    pub async fn start(program_id: Pubkey) -> Result<Self> {
+        let state = SolanaState {
+            program_id,
+            slot: 1,
+            time: now_secs() as i64,
+            accounts: HashMap::new(),
+            txs: Vec::new(),
+            instructions: Vec::new(),
+            counter: 0,
+        };
+        let backend = Arc::new(SolanaBackend {
+            state: Mutex::new(state),
+            faults: FaultPlan::default(),
+        });
+        let server = SimServer::start(rpc_router(backend.clone())).await?;
+        Ok(Self { backend, server })
+    }
+
+    /// RPC URL
+    pub fn url(&self) -> &str {
+        self.server.url()
+    }
+
+    /// Fault rules applied to incoming requests
+    pub fn faults(&self) -> &FaultPlan {
+        &self.backend.faults
+    }
+
+    /// Current slot
+    pub fn slot(&self) -> u64 {
+        lock(&self.backend.state).slot
+    }
+
+    /// Advance the slot counter
+    pub fn advance_slots(&self, n: u64) {
+        lock(&self.backend.state).slot += n;
+    }
+
+    /// Move the cluster clock forward (block times of later transactions)
+    pub fn advance_time(&self, secs: u64) {
+        lock(&self.backend.state).time += secs as i64;
+    }
+
+    /// Create or replace an account owned by the bridge program
+    pub fn set_program_account(&self, address: Pubkey, data: Vec<u8>) {
+        let owner = lock(&self.backend.state).program_id;
+        self.set_account(address, owner, data);
+    }
+
+    /// Create or replace an account
+    pub fn set_account(&self, address: Pubkey, owner: Pubkey, data: Vec<u8>) {
+        let account = SimAccount {
+            owner,
+            lamports: LAMPORTS,
+            data,
+        };
+        lock(&self.backend.state).accounts.insert(address, account);
+    }
+
+    /// Delete an account (e.g. one closed by the program)
+    pub fn remove_account(&self, address: &Pubkey) {
+        lock(&self.backend.state).accounts.remove(address);
+    }
+
+    /// Commit a program transaction whose logs carry one Anchor event
+    pub fn emit_event(&self, discriminator: [u8; 8], payload: &[u8]) -> Signature {
+        let mut data = discriminator.to_vec();
+        data.extend_from_slice(payload);
+        let mut state = lock(&self.backend.state);
+        let program_id = state.program_id;
+        let logs = vec![
+            format!("Program {program_id} invoke [1]"),
+            format!("Program data: {}", B64.encode(data)),
+            format!("Program {program_id} success"),
+        ];
+        state.commit(vec![program_id], logs, false, None)
+    }
+
+    /// Commit a program transaction that failed (listed with an error, no events)
+    pub fn emit_failed_transaction(&self) -> Signature {
+        let mut state = lock(&self.backend.state);
+        let program_id = state.program_id;
+        let logs = vec![
+            format!("Program {program_id} invoke [1]"),
+            format!("Program {program_id} failed: custom program error: 0x1"),
+        ];
+        state.commit(vec![program_id], logs, true, None)
+    }
+
+    /// Emit a `DepositEvent` for `deposit` (fee 0)
+    pub fn emit_deposit(&self, deposit: &MockDeposit) -> Signature {
+        let mut payload = Vec::with_capacity(172);
+        payload.extend_from_slice(&deposit.compute_xchain_hash_id());
+        payload.extend_from_slice(&deposit.src_account);
+        payload.extend_from_slice(deposit.dest_chain.as_bytes());
+        payload.extend_from_slice(&deposit.dest_account);
+        payload.extend_from_slice(&deposit.token);
+        payload.extend_from_slice(&deposit.amount.to_le_bytes());
+        payload.extend_from_slice(&0u128.to_le_bytes());
+        payload.extend_from_slice(&deposit.nonce.to_le_bytes());
+        self.emit_event(DEPOSIT_EVENT_DISCRIMINATOR, &payload)
+    }
+
+    /// Emit a `WithdrawApproveEvent` for `transfer_hash` approved at `approved_at`
+    pub fn emit_withdraw_approve(&self, transfer_hash: &[u8; 32], approved_at: i64) -> Signature {
+        let mut payload = transfer_hash.to_vec();
+        payload.extend_from_slice(&approved_at.to_le_bytes());
+        self.emit_event(WITHDRAW_APPROVE_EVENT_DISCRIMINATOR, &payload)
+    }
+
+    /// Emit a `WithdrawCancelEvent` for `transfer_hash`
+    pub fn emit_withdraw_cancel(&self, transfer_hash: &[u8; 32], canceler: &Pubkey) -> Signature {
+        let mut payload = transfer_hash.to_vec();
+        payload.extend_from_slice(canceler.as_ref());
+        self.emit_event(WITHDRAW_CANCEL_EVENT_DISCRIMINATOR, &payload)
+    }
+
+    /// Instructions received through `sendTransaction`, oldest first
+    pub fn instructions(&self) -> Vec<SimSolanaInstruction> {
+        lock(&self.backend.state).instructions.clone()
+    }
+
+    /// Received instructions for the Anchor instruction `name`
+    pub fn instructions_named(&self, name: &str) -> Vec<SimSolanaInstruction> {
+        self.instructions()
+            .into_iter()
+            .filter(|ix| ix.is(name))
+            .collect()
+    }
+}
+
+impl SolanaState {
+    fn next_signature(&mut self) -> Signature {
+        self.counter += 1;
+        let mut bytes = [0u8; 64];
+        let first = keccak256(&self.counter.to_be_bytes());
+        bytes[..32].copy_from_slice(&first);
+        bytes[32..].copy_from_slice(&keccak256(&first));
+        Signature::from(bytes)
+    }
+
+    fn blockhash(&self) -> solana_sdk::hash::Hash {
+        solana_sdk::hash::Hash::new_from_array(keccak256(&self.slot.to_be_bytes()))
+    }
+
+    /// Record a transaction in a new slot
+    fn commit(
+        &mut self,
+        accounts: Vec<Pubkey>,
+        logs: Vec<String>,
+        failed: bool,
+        raw: Option<(Signature, Vec<u8>)>,
+    ) -> Signature {
+        self.slot += 1;
+        let (signature, raw) = match raw {
+            Some(raw) => raw,
+            None => {
+                let signature = self.next_signature();
+                let tx = Transaction {
+                    signatures: vec![signature],
+                    message: Default::default(),
+                };
+                (signature, bincode::serialize(&tx).unwrap_or_default())
+            }
+        };
+        self.txs.push(SimSolanaTx {
+            signature,
+            slot: self.slot,
+            block_time: self.time,
+            accounts,
+            logs,
+            failed,
+            raw,
+        });
+        signature
+    }
+
+    fn send_transaction(&mut self, params: &Value) -> Result<Value, RpcError> {
+        let encoded = params[0]
+            .as_str()
+            .ok_or_else(|| RpcError::invalid_params("expected encoded transaction"))?;
+        let bytes = match params[1]["encoding"].as_str() {
+            Some("base58") => bs58::decode(encoded).into_vec().ok(),
+            _ => B64.decode(encoded).ok(),
+        }
+        .ok_or_else(|| RpcError::invalid_params("invalid transaction encoding"))?;
+        let tx: VersionedTransaction = bincode::deserialize(&bytes)
+            .map_err(|e| RpcError::invalid_params(format!("failed to deserialize: {e}")))?;
+        let signature = *tx
+            .signatures
+            .first()
+            .ok_or_else(|| RpcError::invalid_params("transaction has no signatures"))?;
+
+        let keys = tx.message.static_account_keys().to_vec();
+        let mut logs = Vec::new();
+        for ix in tx.message.instructions() {
+            let Some(program_id) = keys.get(ix.program_id_index as usize).copied() else {
+                continue;
+            };
+            logs.push(format!("Program {program_id} invoke [1]"));
+            logs.push(format!("Program {program_id} success"));
+            self.instructions.push(SimSolanaInstruction {
+                signature,
+                program_id,
+                accounts: ix
+                    .accounts
+                    .iter()
+                    .filter_map(|i| keys.get(*i as usize).copied())
+                    .collect(),
+                data: ix.data.clone(),
+            });
+        }
+        self.commit(keys, logs, false, Some((signature, bytes)));
+        Ok(json!(signature.to_string()))
+    }
+
+    fn account_json(&self, address: &Pubkey) -> Value {
+        match self.accounts.get(address) {
+            Some(a) => json!({
+                "lamports": a.lamports,
+                "data": [B64.encode(&a.data), "base64"],
+                "owner": a.owner.to_string(),
+                "executable": false,
+                "rentEpoch": u64::MAX,
+                "space": a.data.len(),
+            }),
+            None => Value::Null,
+        }
+    }
+
+    fn with_context(&self, value: Value) -> Value {
+        json!({ "context": { "slot": self.slot, "apiVersion": "2.1.0" }, "value": value })
+    }
+
+    fn signatures_for_address(&self, params: &Value) -> Result<Value, RpcError> {
+        let address = parse_pubkey(&params[0])?;
+        let config = &params[1];
+        let limit = config["limit"].as_u64().unwrap_or(1000) as usize;
+        let until = config["until"].as_str();
+        let before = config["before"].as_str();
+
+        // Newest first, stopping at `until` (exclusive) and starting after `before`.
+        let mut started = before.is_none();
+        let mut out = Vec::new();
+        for tx in self.txs.iter().rev() {
+            let signature = tx.signature.to_string();
+            if !started {
+                started = Some(signature.as_str()) == before;
+                continue;
+            }
+            if Some(signature.as_str()) == until || out.len() >= limit {
+                break;
+            }
+            if !tx.accounts.contains(&address) {
+                continue;
+            }
+            out.push(RpcConfirmedTransactionStatusWithSignature {
+                signature,
+                slot: tx.slot,
+                err: tx.failed.then(failed_error),
+                memo: None,
+                block_time: Some(tx.block_time),
+                confirmation_status: Some(TransactionConfirmationStatus::Finalized),
+            });
+        }
+        serde_json::to_value(out).map_err(|e| RpcError::invalid_params(e.to_string()))
+    }
+
+    fn transaction(&self, params: &Value) -> Result<Value, RpcError> {
+        let signature = parse_signature(&params[0])?;
+        let Some(tx) = self.txs.iter().find(|t| t.signature == signature) else {
+            return Ok(Value::Null);
+        };
+        let err = tx.failed.then(failed_error);
+        let meta = UiTransactionStatusMeta {
+            err: err.clone(),
+            status: err.map_or(Ok(()), Err),
+            fee: 5000,
+            pre_balances: Vec::new(),
+            post_balances: Vec::new(),
+            inner_instructions: OptionSerializer::Some(Vec::new()),
+            log_messages: OptionSerializer::Some(tx.logs.clone()),
+            pre_token_balances: OptionSerializer::Some(Vec::new()),
+            post_token_balances: OptionSerializer::Some(Vec::new()),
+            rewards: OptionSerializer::Some(Vec::new()),
+            loaded_addresses: OptionSerializer::Skip,
+            return_data: OptionSerializer::Skip,
+            compute_units_consumed: OptionSerializer::Some(10_000),
+        };
+        let confirmed = EncodedConfirmedTransactionWithStatusMeta {
+            slot: tx.slot,
+            transaction: EncodedTransactionWithStatusMeta {
+                transaction: EncodedTransaction::Binary(
+                    B64.encode(&tx.raw),
+                    TransactionBinaryEncoding::Base64,
+                ),
+                meta: Some(meta),
+                version: None,
+            },
+            block_time: Some(tx.block_time),
+        };
+        serde_json::to_value(confirmed).map_err(|e| RpcError::invalid_params(e.to_string()))
+    }
+
+    fn signature_statuses(&self, params: &Value) -> Result<Value, RpcError> {
+        let statuses: Vec<Value> = params[0]
+            .as_array()
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+            .iter()
+            .map(|s| {
+                let tx = parse_signature(s)
+                    .ok()
+                    .and_then(|sig| self.txs.iter().find(|t| t.signature == sig));
+                match tx {
+                    Some(tx) => {
+                        let err = tx.failed.then(failed_error);
+                        json!({
+                            "slot": tx.slot,
+                            "confirmations": null,
+                            "err": err,
+                            "status": err.map_or(Ok(()), Err),
+                            "confirmationStatus": "finalized",
+                        })
+                    }
+                    None => Value::Null,
+                }
+            })
+            .collect();
+        Ok(self.with_context(json!(statuses)))
+    }
+}
+
+impl RpcBackend for SolanaBackend {
+    fn faults(&self) -> &FaultPlan {
+        &self.faults
+    }
+
+    fn handle(&self, method: &str, params: &Value) -> Result<Value, RpcError> {
+        let mut state = lock(&self.state);
+        match method {
+            "getHealth" => Ok(json!("ok")),
+            "getVersion" => Ok(json!({ "solana-core": "2.1.0", "feature-set": 0 })),
+            "getSlot" | "getBlockHeight" => Ok(json!(state.slot)),
+            "getLatestBlockhash" => {
+                let value = json!({
+                    "blockhash": state.blockhash().to_string(),
+                    "lastValidBlockHeight": state.slot + 150,
+                });
+                Ok(state.with_context(value))
+            }
+            "isBlockhashValid" => Ok(state.with_context(json!(true))),
+            "getBalance" => {
+                let address = parse_pubkey(&params[0])?;
+                let lamports = state.accounts.get(&address).map_or(0, |a| a.lamports);
+                Ok(state.with_context(json!(lamports)))
+            }
+            "getMinimumBalanceForRentExemption" => Ok(json!(890_880)),
+            "getAccountInfo" => {
+                let address = parse_pubkey(&params[0])?;
+                Ok(state.with_context(state.account_json(&address)))
+            }
+            "getMultipleAccounts" => {
+                let accounts = params[0]
+                    .as_array()
+                    .map(Vec::as_slice)
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|a| parse_pubkey(a).map(|p| state.account_json(&p)))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(state.with_context(json!(accounts)))
+            }
+            "getSignaturesForAddress" => state.signatures_for_address(params),
+            "getTransaction" => state.transaction(params),
+            "getSignatureStatuses" => state.signature_statuses(params),
+            "sendTransaction" => state.send_transaction(params),
+            _ => Err(RpcError::method_not_found(method)),
+        }
+    }
+}
+
+fn failed_error() -> TransactionError {
+    TransactionError::InstructionError(0, InstructionError::Custom(1))
+}
+
+fn parse_pubkey(v: &Value) -> Result<Pubkey, RpcError> {
+    v.as_str()
+        .and_then(|s| Pubkey::from_str(s).ok())
+        .ok_or_else(|| RpcError::invalid_params("invalid pubkey"))
+}
+
+fn parse_signature(v: &Value) -> Result<Signature, RpcError> {
+    v.as_str()
+        .and_then(|s| Signature::from_str(s).ok())
+        .ok_or_else(|| RpcError::invalid_params("invalid signature"))
+}
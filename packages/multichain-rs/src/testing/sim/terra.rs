@@ -0,0 +1,892 @@
+//! Simulated Terra LCD
+//!
+//! LCD REST stand-in serving the CosmWasm bridge queries and tx endpoints our clients use:
+//!
+//! | Route | Fault name |
+//! |-------|------------|
+//! | `GET /cosmos/base/tendermint/v1beta1/blocks/latest` | `blocks_latest` |
+//! | `GET /cosmwasm/wasm/v1/contract/{addr}/smart/{query}` | the query variant, e.g. `pending_withdrawals` |
+//! | `GET /cosmos/tx/v1beta1/txs?query=...` | `tx_search` |
+//! | `GET /cosmos/tx/v1beta1/txs/{hash}` | `get_tx` |
+//! | `POST /cosmos/tx/v1beta1/txs` | `broadcast_tx` |
+//! | `GET /cosmos/auth/v1beta1/accounts/{addr}` | `account` |
+//!
+//! Smart queries: `pending_withdrawals`, `pending_withdraw`, `xchain_hash_id`,
+//! `deposit_by_nonce`, `withdraw_delay`, `cancel_window`, `this_chain_id`, `stats`,
+//! `locked_balance` and `token_type`, plus CW20 `token_info` on tokens registered with
+//! [`SimTerraLcd::set_cw20_supply`]. Broadcast txs are decoded and their
+//! `MsgExecuteContract` messages applied to the bridge (`withdraw_approve`, `withdraw_cancel`,
+//! `withdraw_uncancel`, `withdraw_execute_unlock`, `withdraw_execute_mint`); signatures are
+//! not checked. Each tx is committed in its own block.
+
+use super::{json_response, Fault};
+use super::{lock, now_secs, status_response, FaultPlan, SimServer};
+use crate::hash::keccak256;
+use crate::testing::MockDeposit;
+use crate::types::ChainId;
+use axum::{
+    extract::{Path, RawQuery, State},
+    http::StatusCode,
+    response::Response,
+    routing::get,
+    Json, Router,
+};
+use base64::Engine;
+use cosmrs::tx::Msg;
+use eyre::{eyre, Result};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+
+const B64: base64::engine::GeneralPurpose = base64::engine::general_purpose::STANDARD;
+const EXECUTE_CONTRACT_TYPE_URL: &str = "/cosmwasm.wasm.v1.MsgExecuteContract";
+
+/// Static parameters of a simulated Terra chain
+#[derive(Debug, Clone)]
+pub struct SimTerraConfig {
+    /// Cosmos chain ID (e.g. `localterra`)
+    pub chain_id: String,
+    /// V2 bridge chain ID (`this_chain_id`)
+    pub this_chain: ChainId,
+    /// Bridge contract address
+    pub bridge: String,
+    /// Cancel window in seconds (`withdraw_delay`)
+    pub cancel_window: u64,
+}
+
+impl Default for SimTerraConfig {
+    fn default() -> Self {
+        Self {
+            chain_id: "localterra".to_string(),
+            this_chain: ChainId::from_u32(2),
+            bridge: "terra14hj2tavq8fpesdwxxcu44rty3hh90vhujrvcmstl4zr3txmfvw9ssrc8au".to_string(),
+            cancel_window: 300,
+        }
+    }
+}
+
+/// Pending withdrawal held by the simulated Terra bridge
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SimTerraWithdraw {
+    pub src_chain: [u8; 4],
+    pub src_account: [u8; 32],
+    pub dest_account: [u8; 32],
+    /// Denom or CW20 address
+    pub token: String,
+    pub recipient: String,
+    pub amount: u128,
+    pub nonce: u64,
+    pub submitted_at: u64,
+    pub approved_at: u64,
+    pub approved: bool,
+    pub cancelled: bool,
+    pub executed: bool,
+    submitted_height: u64,
+    approved_height: Option<u64>,
+    cancelled_height: Option<u64>,
+    executed_height: Option<u64>,
+}
+
+/// A committed tx (scripted deposit or broadcast execute)
+#[derive(Debug, Clone)]
+pub struct SimTerraTx {
+    pub txhash: String,
+    pub height: u64,
+    pub sender: String,
+    /// Execute message JSON (`Null` for scripted deposits)
+    pub msg: Value,
+    /// 0 on success
+    pub code: u32,
+    pub raw_log: String,
+    events: Value,
+    timestamp: u64,
+}
+
+struct TerraDeposit {
+    deposit: MockDeposit,
+    deposited_at: u64,
+    height: u64,
+}
+
+struct TerraState {
+    config: SimTerraConfig,
+    height: u64,
+    height_lag: u64,
+    time: u64,
+    txs: Vec<SimTerraTx>,
+    deposits: HashMap<[u8; 32], TerraDeposit>,
+    withdraws: BTreeMap<[u8; 32], SimTerraWithdraw>,
+    locked: HashMap<String, u128>,
+    cw20_supplies: HashMap<String, u128>,
+    sequences: HashMap<String, u64>,
+    tx_counter: u64,
+}
+
+struct TerraBackend {
+    state: Mutex<TerraState>,
+    faults: FaultPlan,
+}
+
+/// In-process Terra LCD with a simulated CosmWasm bridge
+pub struct SimTerraLcd {
+    backend: Arc<TerraBackend>,
+    server: SimServer,
+}
+
+impl SimTerraLcd {
+    /// Start a simulated chain at height 1 with the wall-clock time
+    pub async fn start(config: SimTerraConfig) -> Result<Self> {
+        let state = TerraState {
+            config,
+            height: 1,
+            height_lag: 0,
+            time: now_secs(),
+            txs: Vec::new(),
+            deposits: HashMap::new(),
+            withdraws: BTreeMap::new(),
+            locked: HashMap::new(),
+            cw20_supplies: HashMap::new(),
+            sequences: HashMap::new(),
+            tx_counter: 0,
+        };
+        let backend = Arc::new(TerraBackend {
+            state: Mutex::new(state),
+            faults: FaultPlan::default(),
+        });
+        let router = Router::new()
+            .route(
+                "/cosmos/base/tendermint/v1beta1/blocks/latest",
+                get(latest_block),
+            )
+            .route(
+                "/cosmwasm/wasm/v1/contract/{address}/smart/{query}",
+                get(smart_query),
+            )
+            .route("/cosmos/tx/v1beta1/txs", get(tx_search).post(broadcast_tx))
+            .route("/cosmos/tx/v1beta1/txs/{hash}", get(get_tx))
+            .route("/cosmos/auth/v1beta1/accounts/{address}", get(account))
+            .with_state(backend.clone());
+        let server = SimServer::start(router).await?;
+        Ok(Self { backend, server })
+    }
+
+    /// LCD URL
+    pub fn url(&self) -> &str {
+        self.server.url()
+    }
+
+    /// Bridge contract address
+    pub fn bridge_address(&self) -> String {
+        lock(&self.backend.state).config.bridge.clone()
+    }
+
+    /// Fault rules applied to incoming requests (see the module docs for route names)
+    pub fn faults(&self) -> &FaultPlan {
+        &self.backend.faults
+    }
+
+    /// Current height (ignoring any height lag)
+    pub fn height(&self) -> u64 {
+        lock(&self.backend.state).height
+    }
+
+    /// Produce `n` empty blocks
+    pub fn produce_blocks(&self, n: u64) {
+        lock(&self.backend.state).height += n;
+    }
+
+    /// Move the chain clock forward
+    pub fn advance_time(&self, secs: u64) {
+        lock(&self.backend.state).time += secs;
+    }
+
+    /// Report a latest height `lag` blocks behind the real one
+    pub fn set_height_lag(&self, lag: u64) {
+        lock(&self.backend.state).height_lag = lag;
+    }
+
+    /// Roll the chain back `blocks` heights (a chain reset): txs, deposits, submissions and
+    /// approval/cancel/execute transitions above the new height are dropped.
+    pub fn rewind(&self, blocks: u64) {
+        lock(&self.backend.state).rewind(blocks);
+    }
+
+    /// Set what `locked_balance { token }` reports
+    pub fn set_locked_balance(&self, token: &str, amount: u128) {
+        lock(&self.backend.state)
+            .locked
+            .insert(token.to_string(), amount);
+    }
+
+    /// Deploy (or update) a mint/burn CW20 at `address` whose `token_info` reports `supply`.
+    /// `token_type` answers `mint_burn` for it (`lock_unlock` for every other token).
+    pub fn set_cw20_supply(&self, address: &str, supply: u128) {
+        lock(&self.backend.state)
+            .cw20_supplies
+            .insert(address.to_string(), supply);
+    }
+
+    /// Record a deposit of `token` by `sender` matching `deposit` and commit its `wasm` event
+    /// (the attributes the operator's Terra watcher parses). Returns the xchain hash ID.
+    pub fn deposit(&self, deposit: &MockDeposit, token: &str, sender: &str) -> [u8; 32] {
+        let mut state = lock(&self.backend.state);
+        let hash = deposit.compute_xchain_hash_id();
+        state.height += 1;
+        let height = state.height;
+        let deposited_at = state.time;
+        let action = if token.starts_with("terra1") {
+            "deposit_cw20_lock"
+        } else {
+            "deposit_native"
+        };
+        let attributes = [
+            ("_contract_address", state.config.bridge.clone()),
+            ("action", action.to_string()),
+            ("nonce", deposit.nonce.to_string()),
+            ("sender", sender.to_string()),
+            (
+                "dest_chain",
+                format!("0x{}", hex::encode(deposit.dest_chain.as_bytes())),
+            ),
+            ("dest_account", B64.encode(deposit.dest_account)),
+            ("token", token.to_string()),
+            ("amount", deposit.amount.to_string()),
+            ("fee", "0".to_string()),
+            (
+                "dest_token_address",
+                format!("0x{}", hex::encode(deposit.token)),
+            ),
+            ("xchain_hash_id", format!("0x{}", hex::encode(hash))),
+        ];
+        let events = json!([{
+            "type": "wasm",
+            "attributes": attributes
+                .iter()
+                .map(|(k, v)| json!({ "key": k, "value": v, "index": true }))
+                .collect::<Vec<_>>(),
+        }]);
+        let txhash = state.next_txhash();
+        state.txs.push(SimTerraTx {
+            txhash,
+            height,
+            sender: sender.to_string(),
+            msg: Value::Null,
+            code: 0,
+            raw_log: String::new(),
+            events,
+            timestamp: deposited_at,
+        });
+        state.deposits.insert(
+            hash,
+            TerraDeposit {
+                deposit: deposit.clone(),
+                deposited_at,
+                height,
+            },
+        );
+        hash
+    }
+
+    /// Submit the withdrawal matching `deposit` (whose destination is this chain) for
+    /// `recipient`, paying out `token`. Returns the xchain hash ID.
+    pub fn submit_withdraw(&self, deposit: &MockDeposit, token: &str, recipient: &str) -> [u8; 32] {
+        let mut state = lock(&self.backend.state);
+        let hash = deposit.compute_xchain_hash_id();
+        state.height += 1;
+        let withdraw = SimTerraWithdraw {
+            src_chain: *deposit.src_chain.as_bytes(),
+            src_account: deposit.src_account,
+            dest_account: deposit.dest_account,
+            token: token.to_string(),
+            recipient: recipient.to_string(),
+            amount: deposit.amount,
+            nonce: deposit.nonce,
+            submitted_at: state.time,
+            approved_at: 0,
+            approved: false,
+            cancelled: false,
+            executed: false,
+            submitted_height: state.height,
+            approved_height: None,
+            cancelled_height: None,
+            executed_height: None,
+        };
+        state.withdraws.insert(hash, withdraw);
+        hash
+    }
+
+    /// Approve a submitted withdrawal as the operator would
+    pub fn approve_withdraw(&self, xchain_hash_id: &[u8; 32]) -> Result<()> {
+        self.execute_scripted("withdraw_approve", xchain_hash_id)
+    }
+
+    /// Cancel an approved withdrawal as a canceler would
+    pub fn cancel_withdraw(&self, xchain_hash_id: &[u8; 32]) -> Result<()> {
+        self.execute_scripted("withdraw_cancel", xchain_hash_id)
+    }
+
+    /// Execute an approved withdrawal (the cancel window must have elapsed)
+    pub fn execute_withdraw(&self, xchain_hash_id: &[u8; 32]) -> Result<()> {
+        self.execute_scripted("withdraw_execute_unlock", xchain_hash_id)
+    }
+
+    fn execute_scripted(&self, action: &str, xchain_hash_id: &[u8; 32]) -> Result<()> {
+        let msg = json!({ action: { "xchain_hash_id": B64.encode(xchain_hash_id) } });
+        let tx = lock(&self.backend.state).commit_execute("sim", msg);
+        match tx.code {
+            0 => Ok(()),
+            _ => Err(eyre!("{} failed: {}", action, tx.raw_log)),
+        }
+    }
+
+    /// Pending withdrawal for `xchain_hash_id`, if any
+    pub fn pending_withdraw(&self, xchain_hash_id: &[u8; 32]) -> Option<SimTerraWithdraw> {
+        lock(&self.backend.state)
+            .withdraws
+            .get(xchain_hash_id)
+            .cloned()
+    }
+
+    /// Committed txs (scripted and broadcast), oldest first
+    pub fn transactions(&self) -> Vec<SimTerraTx> {
+        lock(&self.backend.state).txs.clone()
+    }
+
+    /// Successful txs whose execute message is `action` (e.g. `"withdraw_cancel"`)
+    pub fn executions_of(&self, action: &str) -> Vec<SimTerraTx> {
+        self.transactions()
+            .into_iter()
+            .filter(|tx| tx.code == 0 && tx.msg.get(action).is_some())
+            .collect()
+    }
+}
+
+impl TerraState {
+    fn reported_height(&self) -> u64 {
+        self.height.saturating_sub(self.height_lag)
+    }
+
+    fn next_txhash(&mut self) -> String {
+        self.tx_counter += 1;
+        let mut seed = b"simterratx".to_vec();
+        seed.extend_from_slice(&self.tx_counter.to_be_bytes());
+        hex::encode_upper(keccak256(&seed))
+    }
+
+    fn rewind(&mut self, blocks: u64) {
+        let height = self.height.saturating_sub(blocks).max(1);
+        self.height = height;
+        self.txs.retain(|tx| tx.height <= height);
+        self.deposits.retain(|_, d| d.height <= height);
+        self.withdraws.retain(|_, w| w.submitted_height <= height);
+        for w in self.withdraws.values_mut() {
+            if w.approved_height.is_some_and(|h| h > height) {
+                w.approved = false;
+                w.approved_at = 0;
+                w.approved_height = None;
+            }
+            if w.cancelled_height.is_some_and(|h| h > height) {
+                w.cancelled = false;
+                w.cancelled_height = None;
+            }
+            if w.executed_height.is_some_and(|h| h > height) {
+                w.executed = false;
+                w.executed_height = None;
+            }
+        }
+    }
+
+    /// Apply an execute message in a new block and record the tx
+    fn commit_execute(&mut self, sender: &str, msg: Value) -> SimTerraTx {
+        self.height += 1;
+        let result = self.execute(&msg);
+        let action = msg
+            .as_object()
+            .and_then(|o| o.keys().next().cloned())
+            .unwrap_or_default();
+        let (code, raw_log, events) = match result {
+            Ok(()) => (
+                0,
+                String::new(),
+                json!([{
+                    "type": "wasm",
+                    "attributes": [
+                        { "key": "_contract_address", "value": self.config.bridge, "index": true },
+                        { "key": "action", "value": action, "index": true },
+                    ],
+                }]),
+            ),
+            Err(e) => (
+                5,
+                format!("failed to execute message; message index: 0: {e}: execute wasm contract failed"),
+                json!([]),
+            ),
+        };
+        let tx = SimTerraTx {
+            txhash: self.next_txhash(),
+            height: self.height,
+            sender: sender.to_string(),
+            msg,
+            code,
+            raw_log,
+            events,
+            timestamp: self.time,
+        };
+        *self.sequences.entry(sender.to_string()).or_default() += 1;
+        self.txs.push(tx.clone());
+        tx
+    }
+
+    /// Bridge rules for the withdraw lifecycle messages
+    fn execute(&mut self, msg: &Value) -> Result<(), String> {
+        let (action, body) = msg
+            .as_object()
+            .and_then(|o| o.iter().next())
+            .ok_or_else(|| "invalid execute message".to_string())?;
+        let hash = decode_hash(&body["xchain_hash_id"])
+            .ok_or_else(|| "invalid xchain_hash_id".to_string())?;
+        let now = self.time;
+        let height = self.height;
+        let window = self.config.cancel_window;
+        let w = self
+            .withdraws
+            .get_mut(&hash)
+            .ok_or_else(|| "Withdrawal not found".to_string())?;
+        match action.as_str() {
+            "withdraw_approve" => {
+                if w.approved {
+                    return Err("Withdrawal already approved".into());
+                }
+                if w.cancelled || w.executed {
+                    return Err("Withdrawal not pending".into());
+                }
+                w.approved = true;
+                w.approved_at = now;
+                w.approved_height = Some(height);
+            }
+            "withdraw_cancel" => {
+                if w.executed {
+                    return Err("Withdrawal already executed".into());
+                }
+                if !w.approved || w.cancelled {
+                    return Err("Withdrawal not approved".into());
+                }
+                if now >= w.approved_at + window {
+                    return Err("Cancel window expired".into());
+                }
+                w.cancelled = true;
+                w.cancelled_height = Some(height);
+            }
+            "withdraw_uncancel" => {
+                if !w.cancelled {
+                    return Err("Withdrawal not cancelled".into());
+                }
+                w.cancelled = false;
+                w.cancelled_height = None;
+                w.approved_at = now;
+            }
+            "withdraw_execute_unlock" | "withdraw_execute_mint" => {
+                if !w.approved || w.cancelled || w.executed {
+                    return Err("Withdrawal not executable".into());
+                }
+                if now < w.approved_at + window {
+                    return Err("Cancel window still active".into());
+                }
+                w.executed = true;
+                w.executed_height = Some(height);
+            }
+            other => return Err(format!("simulator does not implement {other}")),
+        }
+        Ok(())
+    }
+
+    fn withdraw_entry(&self, hash: &[u8; 32], w: &SimTerraWithdraw) -> Value {
+        let remaining = if w.approved && !w.cancelled {
+            let elapsed = self.time.saturating_sub(w.approved_at);
+            self.config.cancel_window.saturating_sub(elapsed)
+        } else {
+            0
+        };
+        json!({
+            "xchain_hash_id": B64.encode(hash),
+            "src_chain": B64.encode(w.src_chain),
+            "src_account": B64.encode(w.src_account),
+            "dest_account": B64.encode(w.dest_account),
+            "token": w.token,
+            "recipient": w.recipient,
+            "amount": w.amount.to_string(),
+            "nonce": w.nonce,
+            "src_decimals": 6,
+            "dest_decimals": 6,
+            "operator_funds": [],
+            "submitted_at": w.submitted_at,
+            "approved_at": w.approved_at,
+            "approved": w.approved,
+            "cancelled": w.cancelled,
+            "executed": w.executed,
+            "cancel_window_remaining": remaining,
+        })
+    }
+
+    /// Answer a decoded smart query: `Ok(data)` or `Err(message)` (HTTP 400)
+    fn smart_query(&self, variant: &str, args: &Value) -> Result<Value, String> {
+        match variant {
+            "pending_withdrawals" => {
+                let limit = args["limit"].as_u64().unwrap_or(10).min(30) as usize;
+                let start = match &args["start_after"] {
+                    Value::Null => None,
+                    v => Some(decode_hash(v).ok_or("invalid start_after")?),
+                };
+                let withdrawals: Vec<Value> = self
+                    .withdraws
+                    .iter()
+                    .filter(|(h, _)| start.is_none_or(|s| **h > s))
+                    .take(limit)
+                    .map(|(h, w)| self.withdraw_entry(h, w))
+                    .collect();
+                Ok(json!({ "withdrawals": withdrawals }))
+            }
+            "pending_withdraw" => {
+                let hash = decode_hash(&args["xchain_hash_id"]).ok_or("invalid xchain_hash_id")?;
+                Ok(match self.withdraws.get(&hash) {
+                    Some(w) => {
+                        let mut entry = self.withdraw_entry(&hash, w);
+                        entry["exists"] = json!(true);
+                        entry
+                    }
+                    None => json!({
+                        "exists": false, "src_chain": "", "src_account": "", "dest_account": "",
+                        "token": "", "recipient": "", "amount": "0", "nonce": 0,
+                        "src_decimals": 0, "dest_decimals": 0, "operator_funds": [],
+                        "submitted_at": 0, "approved_at": 0, "approved": false,
+                        "cancelled": false, "executed": false, "cancel_window_remaining": 0,
+                    }),
+                })
+            }
+            "xchain_hash_id" => {
+                let hash = decode_hash(&args["xchain_hash_id"]).ok_or("invalid xchain_hash_id")?;
+                Ok(match self.deposits.get(&hash) {
+                    Some(d) => deposit_info(&hash, d),
+                    None => Value::Null,
+                })
+            }
+            "deposit_by_nonce" => {
+                let nonce = args["nonce"].as_u64().ok_or("invalid nonce")?;
+                Ok(self
+                    .deposits
+                    .iter()
+                    .find(|(_, d)| d.deposit.nonce == nonce)
+                    .map(|(h, d)| deposit_info(h, d))
+                    .unwrap_or(Value::Null))
+            }
+            "stats" => Ok(json!({
+                "total_outgoing_txs": self.deposits.len() as u64,
+                "total_incoming_txs": self.withdraws.values().filter(|w| w.executed).count() as u64,
+                "total_fees_collected": "0",
+            })),
+            "locked_balance" => {
+                let token = args["token"].as_str().ok_or("invalid token")?;
+                let amount = self.locked.get(token).copied().unwrap_or_default();
+                Ok(json!({ "token": token, "amount": amount.to_string() }))
+            }
+            "token_type" => {
+                let token = args["token"].as_str().ok_or("invalid token")?;
+                let token_type = if self.cw20_supplies.contains_key(token) {
+                    "mint_burn"
+                } else {
+                    "lock_unlock"
+                };
+                Ok(json!({ "token": token, "token_type": token_type }))
+            }
+            "withdraw_delay" => Ok(json!({ "delay_seconds": self.config.cancel_window })),
+            "cancel_window" => Ok(json!({ "cancel_window_seconds": self.config.cancel_window })),
+            "this_chain_id" => {
+                Ok(json!({ "chain_id": B64.encode(self.config.this_chain.as_bytes()) }))
+            }
+            other => Err(format!(
+                "Error parsing into type bridge::msg::QueryMsg: unknown variant `{other}` \
+                 (not supported by the simulator)"
+            )),
+        }
+    }
+
+    fn tx_response(&self, tx: &SimTerraTx) -> Value {
+        json!({
+            "height": tx.height.to_string(),
+            "txhash": tx.txhash,
+            "codespace": if tx.code == 0 { "" } else { "wasm" },
+            "code": tx.code,
+            "data": "",
+            "raw_log": tx.raw_log,
+            "logs": [],
+            "info": "",
+            "gas_wanted": "500000",
+            "gas_used": "200000",
+            "tx": null,
+            "timestamp": rfc3339(tx.timestamp),
+            "events": tx.events,
+        })
+    }
+}
+
+impl TerraBackend {
+    /// Apply any fault for `route`, then answer with `f`'s status and JSON body
+    async fn respond(
+        &self,
+        route: &str,
+        f: impl FnOnce(&mut TerraState) -> (StatusCode, Value),
+    ) -> Response {
+        let mut truncate = false;
+        match self.faults.take(route) {
+            Some(Fault::Latency(delay)) => tokio::time::sleep(delay).await,
+            Some(Fault::HttpStatus(code)) => return status_response(code),
+            Some(Fault::RpcError { code, message }) => {
+                let body = json!({ "code": code, "message": message, "details": [] });
+                return json_response(StatusCode::INTERNAL_SERVER_ERROR, body.to_string());
+            }
+            Some(Fault::TruncatedBody) => truncate = true,
+            None => {}
+        }
+        let (status, body) = f(&mut lock(&self.state));
+        let mut text = body.to_string();
+        if truncate {
+            text.truncate(text.len() / 2);
+        }
+        json_response(status, text)
+    }
+}
+
+async fn latest_block(State(backend): State<Arc<TerraBackend>>) -> Response {
+    backend
+        .respond("blocks_latest", |state| {
+            let height = state.reported_height();
+            let body = json!({
+                "block_id": { "hash": B64.encode(keccak256(&height.to_be_bytes())) },
+                "block": {
+                    "header": {
+                        "chain_id": state.config.chain_id,
+                        "height": height.to_string(),
+                        "time": rfc3339(state.time),
+                    },
+                    "data": { "txs": [] },
+                },
+            });
+            (StatusCode::OK, body)
+        })
+        .await
+}
+
+async fn smart_query(
+    State(backend): State<Arc<TerraBackend>>,
+    Path((address, query)): Path<(String, String)>,
+) -> Response {
+    let decoded: Option<Value> = B64
+        .decode(&query)
+        .ok()
+        .and_then(|bytes| serde_json::from_slice(&bytes).ok());
+    let (variant, args) = match decoded
+        .as_ref()
+        .and_then(Value::as_object)
+        .and_then(|o| o.iter().next())
+    {
+        Some((variant, args)) => (variant.clone(), args.clone()),
+        None => (String::new(), Value::Null),
+    };
+    let route = if variant.is_empty() {
+        "smart"
+    } else {
+        &variant
+    };
+    backend
+        .respond(route, |state| {
+            if let Some(supply) = state.cw20_supplies.get(&address) {
+                if variant == "token_info" {
+                    let body = json!({ "data": {
+                        "name": "Sim Token", "symbol": "SIM", "decimals": 6,
+                        "total_supply": supply.to_string(),
+                    }});
+                    return (StatusCode::OK, body);
+                }
+            }
+            if address != state.config.bridge {
+                let msg = format!("no such contract: {address}");
+                return (
+                    StatusCode::BAD_REQUEST,
+                    json!({ "code": 5, "message": msg }),
+                );
+            }
+            match state.smart_query(&variant, &args) {
+                Ok(data) => (StatusCode::OK, json!({ "data": data })),
+                Err(message) => (
+                    StatusCode::BAD_REQUEST,
+                    json!({ "code": 2, "message": message, "details": [] }),
+                ),
+            }
+        })
+        .await
+}
+
+async fn tx_search(State(backend): State<Arc<TerraBackend>>, RawQuery(raw): RawQuery) -> Response {
+    let params: Vec<(String, String)> =
+        url::form_urlencoded::parse(raw.unwrap_or_default().as_bytes())
+            .into_owned()
+            .collect();
+    // Cosmos SDK >= 0.50 takes a single `query=`; older LCDs take repeated `events=`.
+    let conditions: Vec<String> = params
+        .iter()
+        .filter(|(k, _)| k == "query" || k == "events")
+        .flat_map(|(_, v)| v.split(" AND ").map(str::to_string).collect::<Vec<_>>())
+        .collect();
+    backend
+        .respond("tx_search", |state| {
+            let mut height = None;
+            let mut contract = None;
+            for condition in &conditions {
+                if let Some((key, value)) = condition.split_once('=') {
+                    let value = value.trim().trim_matches('\'').to_string();
+                    match key.trim() {
+                        "tx.height" => height = value.parse::<u64>().ok(),
+                        "wasm._contract_address" => contract = Some(value),
+                        _ => {}
+                    }
+                }
+            }
+            let txs: Vec<Value> = state
+                .txs
+                .iter()
+                .filter(|tx| height.is_none_or(|h| tx.height == h))
+                .filter(|tx| {
+                    contract.as_ref().is_none_or(|c| {
+                        *c == state.config.bridge
+                            && tx.events.as_array().is_some_and(|e| !e.is_empty())
+                    })
+                })
+                .map(|tx| state.tx_response(tx))
+                .collect();
+            let total = txs.len().to_string();
+            let body = json!({
+                "txs": [],
+                "tx_responses": txs,
+                "pagination": { "next_key": null, "total": total },
+                "total": total,
+            });
+            (StatusCode::OK, body)
+        })
+        .await
+}
+
+async fn get_tx(State(backend): State<Arc<TerraBackend>>, Path(hash): Path<String>) -> Response {
+    backend
+        .respond("get_tx", |state| {
+            match state
+                .txs
+                .iter()
+                .find(|tx| tx.txhash.eq_ignore_ascii_case(&hash))
+            {
+                Some(tx) => (
+                    StatusCode::OK,
+                    json!({ "tx": null, "tx_response": state.tx_response(tx) }),
+                ),
+                None => (
+                    StatusCode::NOT_FOUND,
+                    json!({ "code": 5, "message": format!("tx not found: {hash}"), "details": [] }),
+                ),
+            }
+        })
+        .await
+}
+
+async fn broadcast_tx(
+    State(backend): State<Arc<TerraBackend>>,
+    Json(body): Json<Value>,
+) -> Response {
+    let decoded = body["tx_bytes"]
+        .as_str()
+        .and_then(|b| B64.decode(b).ok())
+        .ok_or_else(|| "missing or invalid tx_bytes".to_string())
+        .and_then(|bytes| cosmrs::Tx::from_bytes(&bytes).map_err(|e| e.to_string()));
+    backend
+        .respond("broadcast_tx", |state| {
+            let tx = match decoded {
+                Ok(tx) => tx,
+                Err(e) => {
+                    return (
+                        StatusCode::BAD_REQUEST,
+                        json!({ "code": 3, "message": format!("invalid tx: {e}"), "details": [] }),
+                    )
+                }
+            };
+            let mut last = None;
+            for any in &tx.body.messages {
+                if any.type_url != EXECUTE_CONTRACT_TYPE_URL {
+                    continue;
+                }
+                let Ok(exec) = cosmrs::cosmwasm::MsgExecuteContract::from_any(any) else {
+                    continue;
+                };
+                if exec.contract.to_string() != state.config.bridge {
+                    continue;
+                }
+                let msg: Value = serde_json::from_slice(&exec.msg).unwrap_or_default();
+                let committed = state.commit_execute(exec.sender.as_ref(), msg);
+                let failed = committed.code != 0;
+                last = Some(committed);
+                if failed {
+                    break;
+                }
+            }
+            let response = match last {
+                Some(committed) => state.tx_response(&committed),
+                None => json!({ "txhash": "", "code": 0, "raw_log": "no bridge messages" }),
+            };
+            (StatusCode::OK, json!({ "tx_response": response }))
+        })
+        .await
+}
+
+async fn account(
+    State(backend): State<Arc<TerraBackend>>,
+    Path(address): Path<String>,
+) -> Response {
+    backend
+        .respond("account", |state| {
+            let sequence = state.sequences.get(&address).copied().unwrap_or(0);
+            let body = json!({
+                "account": {
+                    "@type": "/cosmos.auth.v1beta1.BaseAccount",
+                    "address": address,
+                    "pub_key": null,
+                    "account_number": "1",
+                    "sequence": sequence.to_string(),
+                }
+            });
+            (StatusCode::OK, body)
+        })
+        .await
+}
+
+/// `DepositInfoResponse` for a recorded deposit
+fn deposit_info(hash: &[u8; 32], d: &TerraDeposit) -> Value {
+    json!({
+        "xchain_hash_id": B64.encode(hash),
+        "src_chain": B64.encode(d.deposit.src_chain.as_bytes()),
+        "dest_chain": B64.encode(d.deposit.dest_chain.as_bytes()),
+        "src_account": B64.encode(d.deposit.src_account),
+        "dest_token_address": B64.encode(d.deposit.token),
+        "dest_account": B64.encode(d.deposit.dest_account),
+        "amount": d.deposit.amount.to_string(),
+        "nonce": d.deposit.nonce,
+        "deposited_at": (d.deposited_at * 1_000_000_000).to_string(),
+    })
+}
+
+fn decode_hash(v: &Value) -> Option<[u8; 32]> {
+    let bytes = B64.decode(v.as_str()?).ok()?;
+    bytes.try_into().ok()
+}
+
+fn rfc3339(unix_secs: u64) -> String {
+    tendermint::Time::from_unix_timestamp(unix_secs as i64, 0)
+        .map(|t| t.to_rfc3339())
+        .unwrap_or_default()
+}
@@ -0,0 +1,867 @@
+//! Shared Token Metadata Cache
+//!
+//! Writers and verifiers need a token's decimals, bridge mode and mappings to build messages
+//! and normalize amounts. [`TokenCache`] holds one typed view of every configured bridge's
+//! token registry, keyed by (chain, token bytes32), so the operator and canceler answer those
+//! questions the same way instead of each querying on demand.
+//!
+//! [`TokenCacheRefresher`] fills the cache from the registries [`MappingChecker`] reads and
+//! re-reads a chain when its registry changes:
+//!
+//! | Chain | Change signal |
+//! |-------|---------------|
+//! | EVM | any log emitted by the `TokenRegistry` since the last poll |
+//! | Terra | a bridge transaction whose `wasm.action` is one of [`TERRA_REGISTRY_ACTIONS`] |
+//! | Solana | a finalized bridge transaction emitting one of [`SOLANA_REGISTRY_EVENTS`] |
+//!
+//! Every chain is also re-read on the full refresh interval and after
+//! [`TokenCache::invalidate`]. Symbols are read once per token (ERC20 `symbol`, CW20
+//! `token_info`; Terra native denoms and Solana mints have none). With a path, the cache is
+//! saved as JSON after every refresh and loaded on startup, so a restart serves the last known
+//! view until the first refresh completes.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+use alloy::primitives::Address;
+use alloy::providers::Provider;
+use alloy::rpc::types::Filter;
+use eyre::{eyre, Result, WrapErr};
+use serde::{Deserialize, Serialize};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_transaction_status::option_serializer::OptionSerializer;
+use tracing::{debug, info, warn};
+
+use crate::chains::{blocking, now_secs, Backend, TraceChain, TraceEndpoint};
+use crate::evm::contracts::ERC20;
+use crate::mappings::{LocalToken, MappingChecker};
+use crate::solana::{
+    get_signatures_for_program_range, get_transaction, has_anchor_event,
+    REGISTER_TOKEN_EVENT_DISCRIMINATOR, SET_RATE_LIMIT_EVENT_DISCRIMINATOR,
+};
+use crate::terra::tokens::{Cw20QueryMsg, TokenInfoResponse};
+use crate::types::{ChainId, TokenMode};
+
+/// Terra bridge actions that change its token registry
+pub const TERRA_REGISTRY_ACTIONS: [&str; 5] = [
+    "add_token",
+    "update_token",
+    "set_token_destination",
+    "set_incoming_token_mapping",
+    "remove_incoming_token_mapping",
+];
+
+/// Solana bridge events that change its token registry (`register_token`, `set_rate_limit`)
+pub const SOLANA_REGISTRY_EVENTS: [[u8; 8]; 2] = [
+    REGISTER_TOKEN_EVENT_DISCRIMINATOR,
+    SET_RATE_LIMIT_EVENT_DISCRIMINATOR,
+];
+
+/// Cache file format version
+const CACHE_VERSION: u32 = 1;
+
+/// Largest EVM block range scanned for registry logs; longer gaps re-read the registry instead
+const EVM_MAX_LOG_RANGE: u64 = 10_000;
+
+/// Most Solana bridge transactions fetched for registry events per poll; busier gaps re-read
+/// the registry instead
+const SOLANA_MAX_REGISTRY_SCAN: usize = 500;
+
+// ============================================================================
+// Metadata
+// ============================================================================
+
+/// One route of a token as its bridge records it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CachedRoute {
+    #[serde(with = "chain_hex")]
+    pub remote_chain: ChainId,
+    /// Token deposits to `remote_chain` are delivered as
+    #[serde(with = "opt_hex32")]
+    pub remote_token: Option<[u8; 32]>,
+    /// Decimals recorded for `remote_token`
+    pub remote_decimals: Option<u8>,
+    /// Decimals of the source token, for withdrawals from `remote_chain`
+    pub incoming_decimals: Option<u8>,
+    pub mode: TokenMode,
+}
+
+/// Everything the cache knows about one token on one bridge
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenMetadata {
+    #[serde(with = "chain_hex")]
+    pub chain: ChainId,
+    /// Name the operator's writers use for it (see [`crate::mappings`])
+    pub token: String,
+    /// Token ID other chains map to
+    #[serde(with = "hex32")]
+    pub id: [u8; 32],
+    /// Own decimals, when readable
+    pub decimals: Option<u8>,
+    pub symbol: Option<String>,
+    /// Terra native denom or native SOL
+    pub native: bool,
+    pub routes: Vec<CachedRoute>,
+}
+
+impl TokenMetadata {
+    fn from_local(chain: ChainId, token: LocalToken, symbol: Option<String>) -> Self {
+        Self {
+            chain,
+            routes: token
+                .routes
+                .into_iter()
+                .map(|r| CachedRoute {
+                    remote_chain: r.remote_chain,
+                    remote_token: r.outgoing.map(|(t, _)| t),
+                    remote_decimals: r.outgoing.map(|(_, d)| d),
+                    incoming_decimals: r.incoming_decimals,
+                    mode: r.mode,
+                })
+                .collect(),
+            token: token.token,
+            id: token.id,
+            decimals: token.decimals,
+            symbol,
+            native: token.native,
+        }
+    }
+
+    /// Route to or from `remote_chain`
+    pub fn route(&self, remote_chain: ChainId) -> Option<&CachedRoute> {
+        self.routes.iter().find(|r| r.remote_chain == remote_chain)
+    }
+
+    /// Mode the bridge moves the token with (EVM and Terra record one per token; on Solana
+    /// each route has its own, see [`TokenMetadata::route`]). `None` without routes.
+    pub fn mode(&self) -> Option<TokenMode> {
+        self.routes.first().map(|r| r.mode)
+    }
+}
+
+// ============================================================================
+// Cache
+// ============================================================================
+
+#[derive(Debug, Default)]
+struct CacheState {
+    tokens: HashMap<(ChainId, [u8; 32]), TokenMetadata>,
+    refreshed_at: HashMap<ChainId, u64>,
+    stale: HashSet<ChainId>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheFile {
+    version: u32,
+    chains: Vec<CachedChain>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CachedChain {
+    #[serde(with = "chain_hex")]
+    chain: ChainId,
+    refreshed_at: u64,
+    tokens: Vec<TokenMetadata>,
+}
+
+/// Token metadata of every configured bridge, shared between tasks
+#[derive(Debug, Default)]
+pub struct TokenCache {
+    state: RwLock<CacheState>,
+    path: Option<PathBuf>,
+}
+
+impl TokenCache {
+    /// Empty in-memory cache
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cache persisted at `path`, loaded from it when the file exists.
+    ///
+    /// An unreadable file is logged and ignored: the first refresh replaces it.
+    pub fn open(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let mut state = CacheState::default();
+        match load_file(&path) {
+            Ok(Some(file)) => {
+                for chain in file.chains {
+                    state.refreshed_at.insert(chain.chain, chain.refreshed_at);
+                    for token in chain.tokens {
+                        state.tokens.insert((chain.chain, token.id), token);
+                    }
+                }
+                info!(
+                    path = %path.display(),
+                    tokens = state.tokens.len(),
+                    "Loaded token cache"
+                );
+            }
+            Ok(None) => {}
+            Err(e) => warn!(path = %path.display(), error = %e, "Ignoring unreadable token cache"),
+        }
+        Self {
+            state: RwLock::new(state),
+            path: Some(path),
+        }
+    }
+
+    /// Metadata of token `id` on `chain`
+    pub fn get(&self, chain: ChainId, id: &[u8; 32]) -> Option<TokenMetadata> {
+        self.read().tokens.get(&(chain, *id)).cloned()
+    }
+
+    /// Metadata of the token the writers name `token` on `chain` (EVM addresses match
+    /// case-insensitively)
+    pub fn get_by_name(&self, chain: ChainId, token: &str) -> Option<TokenMetadata> {
+        let evm = token.starts_with("0x");
+        self.read()
+            .tokens
+            .values()
+            .find(|t| {
+                t.chain == chain
+                    && if evm {
+                        t.token.eq_ignore_ascii_case(token)
+                    } else {
+                        t.token == token
+                    }
+            })
+            .cloned()
+    }
+
+    /// Token on `chain` that `remote_token` on `remote_chain` is delivered as
+    pub fn get_by_remote(
+        &self,
+        chain: ChainId,
+        remote_chain: ChainId,
+        remote_token: &[u8; 32],
+    ) -> Option<TokenMetadata> {
+        self.read()
+            .tokens
+            .values()
+            .find(|t| {
+                t.chain == chain
+                    && t.route(remote_chain)
+                        .is_some_and(|r| r.remote_token.as_ref() == Some(remote_token))
+            })
+            .cloned()
+    }
+
+    /// Own decimals of token `id` on `chain`
+    pub fn decimals(&self, chain: ChainId, id: &[u8; 32]) -> Option<u8> {
+        self.read()
+            .tokens
+            .get(&(chain, *id))
+            .and_then(|t| t.decimals)
+    }
+
+    /// Mode of token `id` on `chain` (see [`TokenMetadata::mode`])
+    pub fn mode(&self, chain: ChainId, id: &[u8; 32]) -> Option<TokenMode> {
+        self.read().tokens.get(&(chain, *id)).and_then(|t| t.mode())
+    }
+
+    /// Every cached token of `chain`
+    pub fn chain_tokens(&self, chain: ChainId) -> Vec<TokenMetadata> {
+        self.read()
+            .tokens
+            .values()
+            .filter(|t| t.chain == chain)
+            .cloned()
+            .collect()
+    }
+
+    /// Unix time `chain` was last read, if ever
+    pub fn refreshed_at(&self, chain: ChainId) -> Option<u64> {
+        self.read().refreshed_at.get(&chain).copied()
+    }
+
+    /// Whether `chain` was read and has not been invalidated since
+    pub fn is_fresh(&self, chain: ChainId) -> bool {
+        let state = self.read();
+        state.refreshed_at.contains_key(&chain) && !state.stale.contains(&chain)
+    }
+
+    /// Mark `chain` for re-reading on the next refresh; its entries keep serving until then
+    pub fn invalidate(&self, chain: ChainId) {
+        self.write().stale.insert(chain);
+    }
+
+    /// Chains invalidated since their last refresh
+    pub fn stale_chains(&self) -> Vec<ChainId> {
+        self.read().stale.iter().copied().collect()
+    }
+
+    /// Replace every entry of `chain` with `tokens` and mark it fresh
+    pub fn replace_chain(&self, chain: ChainId, tokens: Vec<TokenMetadata>) {
+        let mut state = self.write();
+        state.tokens.retain(|(c, _), _| *c != chain);
+        for token in tokens {
+            state.tokens.insert((chain, token.id), token);
+        }
+        state.refreshed_at.insert(chain, now_secs());
+        state.stale.remove(&chain);
+    }
+
+    pub fn len(&self) -> usize {
+        self.read().tokens.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.read().tokens.is_empty()
+    }
+
+    /// Write the cache to its path (no-op for in-memory caches)
+    pub fn save(&self) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let json = {
+            let state = self.read();
+            let mut chains: Vec<CachedChain> = state
+                .refreshed_at
+                .iter()
+                .map(|(chain, refreshed_at)| {
+                    let mut tokens: Vec<TokenMetadata> = state
+                        .tokens
+                        .values()
+                        .filter(|t| t.chain == *chain)
+                        .cloned()
+                        .collect();
+                    tokens.sort_by(|a, b| a.token.cmp(&b.token));
+                    CachedChain {
+                        chain: *chain,
+                        refreshed_at: *refreshed_at,
+                        tokens,
+                    }
+                })
+                .collect();
+            chains.sort_by_key(|c| c.chain.to_u32());
+            serde_json::to_vec_pretty(&CacheFile {
+                version: CACHE_VERSION,
+                chains,
+            })?
+        };
+        let tmp = path.with_extension("tmp");
+        std::fs::write(&tmp, json).wrap_err_with(|| format!("writing {}", tmp.display()))?;
+        std::fs::rename(&tmp, path).wrap_err_with(|| format!("replacing {}", path.display()))?;
+        Ok(())
+    }
+
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, CacheState> {
+        self.state.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn write(&self) -> std::sync::RwLockWriteGuard<'_, CacheState> {
+        self.state.write().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+fn load_file(path: &Path) -> Result<Option<CacheFile>> {
+    let bytes = match std::fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    let file: CacheFile = serde_json::from_slice(&bytes)?;
+    if file.version != CACHE_VERSION {
+        return Err(eyre!("unsupported token cache version {}", file.version));
+    }
+    Ok(Some(file))
+}
+
+// ============================================================================
+// Refresher
+// ============================================================================
+
+/// Keeps a [`TokenCache`] in line with the bridges' token registries
+pub struct TokenCacheRefresher {
+    checker: MappingChecker,
+    cache: Arc<TokenCache>,
+    full_refresh: Duration,
+    last_full: Option<Instant>,
+    /// Last block (EVM), height (Terra) or finalized slot (Solana) checked for registry changes
+    cursors: HashMap<ChainId, u64>,
+    evm_registries: HashMap<ChainId, Address>,
+}
+
+impl TokenCacheRefresher {
+    /// Connect to every endpoint; nothing is read until the first [`poll`](Self::poll)
+    pub async fn connect(endpoints: &[TraceEndpoint], cache: Arc<TokenCache>) -> Result<Self> {
+        Ok(Self {
+            checker: MappingChecker::connect(endpoints).await?,
+            cache,
+            full_refresh: Duration::from_secs(600),
+            last_full: None,
+            cursors: HashMap::new(),
+            evm_registries: HashMap::new(),
+        })
+    }
+
+    /// Re-read every chain this often, changed or not (default 10 minutes)
+    pub fn with_full_refresh(mut self, interval: Duration) -> Self {
+        self.full_refresh = interval;
+        self
+    }
+
+    pub fn cache(&self) -> Arc<TokenCache> {
+        self.cache.clone()
+    }
+
+    /// Re-read chains whose registry changed, were invalidated, or are due a full refresh,
+    /// then save the cache. Returns the chains re-read.
+    pub async fn poll(&mut self) -> Result<Vec<ChainId>> {
+        let full = self
+            .last_full
+            .is_none_or(|at| at.elapsed() >= self.full_refresh);
+        let mut due: HashSet<ChainId> = self.cache.stale_chains().into_iter().collect();
+        let chains: Vec<ChainId> = self.checker.trace_chains().iter().map(|c| c.id).collect();
+        for chain in chains {
+            // Advance cursors on full refreshes too so the next poll only sees newer changes
+            match self.registry_changed(chain).await {
+                Ok(true) => {
+                    info!(chain = %chain.to_hex(), "Token registry changed");
+                    due.insert(chain);
+                }
+                Ok(false) => {}
+                Err(e) => {
+                    warn!(chain = %chain.to_hex(), error = %e, "Token registry change check failed");
+                    due.insert(chain);
+                }
+            }
+            if full || !self.cache.is_fresh(chain) {
+                due.insert(chain);
+            }
+        }
+
+        let mut refreshed = Vec::new();
+        let mut failed = false;
+        for chain in due {
+            match self.refresh_chain(chain).await {
+                Ok(()) => refreshed.push(chain),
+                Err(e) => {
+                    failed = true;
+                    self.cache.invalidate(chain);
+                    warn!(chain = %chain.to_hex(), error = %e, "Token cache refresh failed");
+                }
+            }
+        }
+        if full && !failed {
+            self.last_full = Some(Instant::now());
+        }
+        if !refreshed.is_empty() {
+            self.cache.save()?;
+        }
+        Ok(refreshed)
+    }
+
+    /// Re-read one chain's registry into the cache
+    pub async fn refresh_chain(&self, chain: ChainId) -> Result<()> {
+        let trace_chain = self
+            .checker
+            .trace_chains()
+            .iter()
+            .find(|c| c.id == chain)
+            .ok_or_else(|| eyre!("chain {} is not configured", chain.to_hex()))?;
+        let mappings = self.checker.snapshot_chain(chain).await?;
+        let mut tokens = Vec::with_capacity(mappings.tokens.len());
+        for token in mappings.tokens {
+            let symbol = match self.cache.get(chain, &token.id).and_then(|t| t.symbol) {
+                Some(symbol) => Some(symbol),
+                None => read_symbol(trace_chain, &token).await,
+            };
+            tokens.push(TokenMetadata::from_local(chain, token, symbol));
+        }
+        debug!(chain = %chain.to_hex(), tokens = tokens.len(), "Token cache refreshed");
+        self.cache.replace_chain(chain, tokens);
+        Ok(())
+    }
+
+    /// Poll every `interval` until the task is dropped; failures are logged
+    pub async fn run(mut self, interval: Duration) {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            if let Err(e) = self.poll().await {
+                warn!(error = %e, "Token cache poll failed");
+            }
+        }
+    }
+
+    /// Whether `chain`'s registry emitted a change signal since the last call (the first call
+    /// only sets the cursor)
+    async fn registry_changed(&mut self, chain: ChainId) -> Result<bool> {
+        let trace_chain = self
+            .checker
+            .trace_chains()
+            .iter()
+            .find(|c| c.id == chain)
+            .ok_or_else(|| eyre!("chain {} is not configured", chain.to_hex()))?;
+        let since = self.cursors.get(&chain).copied();
+        let (changed, head) = match &trace_chain.backend {
+            Backend::Evm { client, .. } => {
+                let registry = match self.evm_registries.get(&chain) {
+                    Some(registry) => *registry,
+                    None => {
+                        let registry = client.get_token_registry_address().await?;
+                        self.evm_registries.insert(chain, registry);
+                        registry
+                    }
+                };
+                let head = client.get_block_number().await?;
+                let changed = match since {
+                    Some(since) if head > since && head - since <= EVM_MAX_LOG_RANGE => {
+                        let filter = Filter::new()
+                            .address(registry)
+                            .from_block(since + 1)
+                            .to_block(head);
+                        !client
+                            .provider()
+                            .get_logs(&filter)
+                            .await
+                            .wrap_err_with(|| format!("eth_getLogs {}..={}", since + 1, head))?
+                            .is_empty()
+                    }
+                    Some(since) => head > since,
+                    None => false,
+                };
+                (changed, head)
+            }
+            Backend::Terra(client) => {
+                let head = client.get_latest_block_height().await?;
+                let mut changed = false;
+                if let Some(since) = since.filter(|since| head > *since) {
+                    for action in TERRA_REGISTRY_ACTIONS {
+                        if !client
+                            .search_bridge_action_txs(action, since)
+                            .await?
+                            .is_empty()
+                        {
+                            changed = true;
+                            break;
+                        }
+                    }
+                }
+                (changed, head)
+            }
+            Backend::Solana { client, program_id } => {
+                let program_id = *program_id;
+                let head = blocking(client, |c| {
+                    Ok(c.get_slot_with_commitment(CommitmentConfig::finalized())?)
+                })
+                .await?;
+                let changed = match since.filter(|since| head > *since) {
+                    Some(since) => {
+                        blocking(client, move |c| {
+                            solana_registry_changed(c, &program_id, since)
+                        })
+                        .await?
+                    }
+                    None => false,
+                };
+                (changed, head)
+            }
+        };
+        self.cursors.insert(chain, head);
+        Ok(changed)
+    }
+}
+
+/// Whether a finalized bridge transaction after slot `since` emitted one of
+/// [`SOLANA_REGISTRY_EVENTS`]. More than [`SOLANA_MAX_REGISTRY_SCAN`] transactions count as a
+/// change without fetching them.
+pub fn solana_registry_changed(
+    client: &RpcClient,
+    program_id: &Pubkey,
+    since: u64,
+) -> Result<bool> {
+    let mut before: Option<Signature> = None;
+    let mut scanned = 0;
+    loop {
+        let page =
+            get_signatures_for_program_range(client, program_id, before.as_ref(), None, 1000)?;
+        let Some(oldest) = page.last() else {
+            return Ok(false);
+        };
+        before = Some(Signature::from_str(&oldest.signature)?);
+        for info in &page {
+            if info.slot <= since {
+                return Ok(false);
+            }
+            if info.err.is_some() {
+                continue;
+            }
+            scanned += 1;
+            if scanned > SOLANA_MAX_REGISTRY_SCAN {
+                return Ok(true);
+            }
+            let tx = get_transaction(client, &Signature::from_str(&info.signature)?)?;
+            if let Some(OptionSerializer::Some(logs)) =
+                tx.transaction.meta.as_ref().map(|m| &m.log_messages)
+            {
+                if has_anchor_event(logs, program_id, &SOLANA_REGISTRY_EVENTS) {
+                    return Ok(true);
+                }
+            }
+        }
+    }
+}
+
+async fn read_symbol(chain: &TraceChain, token: &LocalToken) -> Option<String> {
+    let result = match &chain.backend {
+        Backend::Evm { client, .. } => match token.token.parse::<Address>() {
+            Ok(address) => ERC20::new(address, client.provider())
+                .symbol()
+                .call()
+                .await
+                .map(|s| s._0)
+                .map_err(|e| eyre!("{}", e)),
+            Err(e) => Err(eyre!("{}", e)),
+        },
+        Backend::Terra(_) if token.native => return None,
+        Backend::Terra(client) => client
+            .query_contract::<_, TokenInfoResponse>(&token.token, &Cw20QueryMsg::TokenInfo {})
+            .await
+            .map(|info| info.symbol),
+        Backend::Solana { .. } => return None,
+    };
+    match result {
+        Ok(symbol) => Some(symbol),
+        Err(e) => {
+            warn!(chain = %chain.id.to_hex(), token = %token.token, error = %e, "Failed to read token symbol");
+            None
+        }
+    }
+}
+
+/// Open the cache (persisted at `path` when given), connect to every endpoint, read every
+/// registry once and keep polling every `poll_interval` in a background task.
+///
+/// Connection and first-read failures are logged: the cache then serves what it loaded from
+/// `path` and callers fall back to querying the chain.
+pub async fn start(
+    endpoints: &[TraceEndpoint],
+    path: Option<PathBuf>,
+    poll_interval: Duration,
+    full_refresh: Duration,
+) -> Arc<TokenCache> {
+    let cache = Arc::new(match path {
+        Some(path) => TokenCache::open(path),
+        None => TokenCache::new(),
+    });
+    let mut refresher = match TokenCacheRefresher::connect(endpoints, cache.clone()).await {
+        Ok(refresher) => refresher.with_full_refresh(full_refresh),
+        Err(e) => {
+            warn!(error = %e, "Token cache could not connect; serving persisted entries only");
+            return cache;
+        }
+    };
+    match refresher.poll().await {
+        Ok(chains) => info!(
+            chains = chains.len(),
+            tokens = cache.len(),
+            "Token cache populated"
+        ),
+        Err(e) => warn!(error = %e, "Initial token cache refresh failed"),
+    }
+    tokio::spawn(refresher.run(poll_interval));
+    cache
+}
+
+// ============================================================================
+// Serialization
+// ============================================================================
+
+mod chain_hex {
+    use super::ChainId;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(chain: &ChainId, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(&chain.to_hex())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<ChainId, D::Error> {
+        ChainId::from_hex(&String::deserialize(d)?).map_err(D::Error::custom)
+    }
+}
+
+mod hex32 {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(bytes: &[u8; 32], s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(&format!("0x{}", hex::encode(bytes)))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<[u8; 32], D::Error> {
+        let s = String::deserialize(d)?;
+        hex::decode(s.strip_prefix("0x").unwrap_or(&s))
+            .map_err(D::Error::custom)?
+            .try_into()
+            .map_err(|_| D::Error::custom("expected 32 bytes"))
+    }
+}
+
+mod opt_hex32 {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    struct Wrapper(#[serde(with = "super::hex32")] [u8; 32]);
+
+    pub fn serialize<S: Serializer>(bytes: &Option<[u8; 32]>, s: S) -> Result<S::Ok, S::Error> {
+        bytes.map(Wrapper).serialize(s)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Option<[u8; 32]>, D::Error> {
+        Ok(Option::<Wrapper>::deserialize(d)?.map(|w| w.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mappings::TokenRoute;
+
+    const EVM: ChainId = ChainId([0, 0, 0, 56]);
+    const TERRA: ChainId = ChainId([0, 0, 0, 1]);
+    const SOLANA: ChainId = ChainId([0, 0, 0, 5]);
+
+    fn evm_token() -> TokenMetadata {
+        TokenMetadata::from_local(
+            EVM,
+            LocalToken {
+                token: "0xAaAaAaAaAaAaAaAaAaAaAaAaAaAaAaAaAaAaAaAa".to_string(),
+                id: [0xaa; 32],
+                decimals: Some(18),
+                native: false,
+                rate_limit: None,
+                routes: vec![TokenRoute {
+                    remote_chain: TERRA,
+                    outgoing: Some(([0xbb; 32], 6)),
+                    incoming_decimals: Some(6),
+                    mode: TokenMode::MintBurn,
+                }],
+            },
+            Some("TKN".to_string()),
+        )
+    }
+
+    fn solana_token() -> TokenMetadata {
+        TokenMetadata::from_local(
+            SOLANA,
+            LocalToken {
+                token: "So11111111111111111111111111111111111111112".to_string(),
+                id: [0xcc; 32],
+                decimals: Some(9),
+                native: false,
+                rate_limit: None,
+                routes: vec![TokenRoute {
+                    remote_chain: TERRA,
+                    outgoing: Some(([0xdd; 32], 6)),
+                    incoming_decimals: Some(6),
+                    mode: TokenMode::LockUnlock,
+                }],
+            },
+            None,
+        )
+    }
+
+    #[test]
+    fn lookups() {
+        let cache = TokenCache::new();
+        assert!(!cache.is_fresh(EVM));
+        cache.replace_chain(EVM, vec![evm_token()]);
+        cache.replace_chain(SOLANA, vec![solana_token()]);
+
+        assert_eq!(cache.decimals(EVM, &[0xaa; 32]), Some(18));
+        assert_eq!(cache.mode(EVM, &[0xaa; 32]), Some(TokenMode::MintBurn));
+        assert_eq!(
+            cache
+                .get_by_name(EVM, "0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa")
+                .and_then(|t| t.symbol),
+            Some("TKN".to_string())
+        );
+        assert!(cache
+            .get_by_name(SOLANA, "so11111111111111111111111111111111111111112")
+            .is_none());
+        assert_eq!(
+            cache
+                .get_by_remote(SOLANA, TERRA, &[0xdd; 32])
+                .map(|t| t.id),
+            Some([0xcc; 32])
+        );
+        assert!(cache.get_by_remote(SOLANA, EVM, &[0xdd; 32]).is_none());
+        assert_eq!(
+            cache
+                .get(EVM, &[0xaa; 32])
+                .unwrap()
+                .route(TERRA)
+                .unwrap()
+                .remote_decimals,
+            Some(6)
+        );
+    }
+
+    #[test]
+    fn replace_and_invalidate() {
+        let cache = TokenCache::new();
+        cache.replace_chain(EVM, vec![evm_token()]);
+        cache.replace_chain(SOLANA, vec![solana_token()]);
+        assert!(cache.is_fresh(EVM));
+
+        cache.invalidate(EVM);
+        assert!(!cache.is_fresh(EVM));
+        assert_eq!(cache.stale_chains(), vec![EVM]);
+        // Stale entries keep serving until the chain is re-read
+        assert_eq!(cache.decimals(EVM, &[0xaa; 32]), Some(18));
+
+        cache.replace_chain(EVM, Vec::new());
+        assert!(cache.is_fresh(EVM));
+        assert!(cache.get(EVM, &[0xaa; 32]).is_none());
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn persists_across_restarts() {
+        let path =
+            std::env::temp_dir().join(format!("cl8y-token-cache-{}.json", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let cache = TokenCache::open(&path);
+        assert!(cache.is_empty());
+        cache.replace_chain(EVM, vec![evm_token()]);
+        cache.replace_chain(SOLANA, vec![solana_token()]);
+        cache.save().unwrap();
+
+        let json: serde_json::Value =
+            serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+        assert_eq!(json["chains"][0]["chain"], "0x00000005");
+        assert_eq!(
+            json["chains"][1]["tokens"][0]["routes"][0]["mode"],
+            "mint_burn"
+        );
+
+        let reopened = TokenCache::open(&path);
+        assert_eq!(reopened.get(EVM, &[0xaa; 32]), Some(evm_token()));
+        assert_eq!(reopened.get(SOLANA, &[0xcc; 32]), Some(solana_token()));
+        assert!(reopened.is_fresh(EVM));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn unreadable_file_starts_empty() {
+        let path =
+            std::env::temp_dir().join(format!("cl8y-token-cache-bad-{}.json", std::process::id()));
+        std::fs::write(&path, b"{not json").unwrap();
+        let cache = TokenCache::open(&path);
+        assert!(cache.is_empty());
+        cache.replace_chain(EVM, vec![evm_token()]);
+        cache.save().unwrap();
+        assert_eq!(TokenCache::open(&path).len(), 1);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
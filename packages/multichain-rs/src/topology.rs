@@ -0,0 +1,2246 @@
+//! Declarative Bridge Topology
+//!
+//! One JSON file describes every bridge deployment and every token leg (address, decimals,
+//! mode, rate limits) plus each bridge's standard fee. [`Topology::plan`] reads the
+//! registries through the query modules and lists the admin messages that would bring each
+//! bridge to the described state; [`Topology::apply`] sends them. Backs the `cl8y-topology`
+//! binary.
+//!
+//! | Item | EVM | Terra | Solana |
+//! |------|-----|-------|--------|
+//! | Remote chain | `ChainRegistry.registerChain` | `register_chain` / `update_chain` | `register_chain` |
+//! | Token and mode | `TokenRegistry.registerToken` / `setTokenType` | `add_token` / `update_token` | (per mapping) |
+//! | Outgoing token and decimals | `setTokenDestinationWithDecimals` | `set_token_destination` | `register_token` |
+//! | Incoming `src_decimals` | `setIncomingTokenMapping` | `set_incoming_token_mapping` | (same `TokenMapping`) |
+//! | Rate limit | `setRateLimit` | `set_rate_limit`, minimum via `update_token` | `set_rate_limit` |
+//! | Standard fee | `setFeeParams` | `set_fee_params` | `set_config` |
+//!
+//! Drift that no admin message can repair in place is reported as a conflict and never
+//! applied: a chain registered under another identifier, a Terra token registered with other
+//! decimals, and a Solana `TokenMapping` that differs from the topology (the program has no
+//! update instruction; the mapping must be closed and registered again).
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use alloy::primitives::{Address, Bytes, FixedBytes, U256};
+use alloy::providers::Provider;
+use alloy::sol_types::SolCall;
+use base64::Engine;
+use eyre::{eyre, Result, WrapErr};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::account::Account;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::system_program;
+use solana_sdk::transaction::Transaction;
+use tracing::info;
+
+use crate::chains::{blocking, decode_bytes32, decode_chain_id, parse_chain_id, ChainKind};
+use crate::evm::contracts::{Bridge, ChainRegistry, TokenRegistry};
+use crate::evm::{EvmQueryClient, EvmSigner};
+use crate::hash::{address_to_bytes32, encode_terra_token_address};
+use crate::solana::{
+    anchor_discriminator, bridge_config_pda, chain_entry_pda, parse_bridge_config,
+    parse_chain_entry, parse_token_mapping, parse_withdraw_rate_limit, token_mapping_pda,
+    withdraw_rate_limit_pda, SolanaTokenMapping,
+};
+use crate::terra::{TerraQueryClient, TerraSigner};
+pub use crate::types::TokenMode;
+use crate::types::{ChainId, RateLimit};
+
+const B64: base64::engine::GeneralPurpose = base64::engine::general_purpose::STANDARD;
+
+/// Terra `chains` / `tokens` page size (contract maximum for `tokens`)
+const TERRA_PAGE: u32 = 30;
+
+/// Solana accounts per `getMultipleAccounts` request
+const SOLANA_ACCOUNTS_PER_REQUEST: usize = 100;
+
+// ============================================================================
+// Configuration
+// ============================================================================
+
+/// A bridge deployment
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TopologyEndpoint {
+    Evm {
+        rpc_url: String,
+        bridge: String,
+    },
+    Terra {
+        lcd_url: String,
+        bridge: String,
+        /// Cosmos chain ID (`columbus-5`), needed only to sign `apply` transactions
+        #[serde(default)]
+        cosmos_chain_id: Option<String>,
+    },
+    Solana {
+        rpc_url: String,
+        program_id: String,
+    },
+}
+
+/// One bridge and how the other bridges register it
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChainConfig {
+    /// Bridge chain ID: 4-byte hex (`0x00000001`) or decimal; must match what the bridge
+    /// reports
+    pub chain: String,
+    /// Identifier the other bridges register this chain under (`evm_56`,
+    /// `terraclassic_columbus-5`)
+    pub identifier: String,
+    /// Standard deposit fee in basis points; left as deployed when absent
+    #[serde(default)]
+    pub fee_bps: Option<u64>,
+    #[serde(flatten)]
+    pub endpoint: TopologyEndpoint,
+}
+
+/// Withdrawal limits of a leg, in leg units (decimal strings; `0` = unlimited / no minimum)
+#[derive(Debug, Clone, Deserialize)]
+pub struct RateLimitConfig {
+    #[serde(default)]
+    pub min_per_transaction: Option<String>,
+    pub max_per_transaction: String,
+    pub max_per_period: String,
+}
+
+/// One chain's side of a token
+#[derive(Debug, Clone, Deserialize)]
+pub struct LegConfig {
+    /// Bridge chain ID: 4-byte hex or decimal
+    pub chain: String,
+    /// EVM token address, Terra denom / CW20 address, or Solana mint
+    pub token: String,
+    pub decimals: u8,
+    pub mode: TokenMode,
+    /// Left as deployed when absent
+    #[serde(default)]
+    pub rate_limit: Option<RateLimitConfig>,
+}
+
+/// A bridged token and its leg on every chain it is routed between
+#[derive(Debug, Clone, Deserialize)]
+pub struct TokenConfig {
+    pub name: String,
+    pub legs: Vec<LegConfig>,
+}
+
+/// `cl8y-topology` configuration file
+#[derive(Debug, Clone, Deserialize)]
+pub struct TopologyConfig {
+    pub chains: Vec<ChainConfig>,
+    pub tokens: Vec<TokenConfig>,
+}
+
+impl TopologyConfig {
+    /// Parse a JSON topology
+    pub fn from_json(json: &str) -> Result<Self> {
+        serde_json::from_str(json).wrap_err("invalid topology")
+    }
+}
+
+// ============================================================================
+// Resolved model
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum LegToken {
+    Evm(Address),
+    Terra(String),
+    Solana(Pubkey),
+}
+
+impl fmt::Display for LegToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LegToken::Evm(a) => write!(f, "{}", a),
+            LegToken::Terra(t) => f.write_str(t),
+            LegToken::Solana(m) => write!(f, "{}", m),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Leg {
+    chain: ChainId,
+    token: LegToken,
+    /// The token as other chains hash it and store it in their mappings
+    token_bytes32: [u8; 32],
+    decimals: u8,
+    mode: TokenMode,
+    rate_limit: Option<RateLimit>,
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    name: String,
+    legs: Vec<Leg>,
+}
+
+/// What the planner needs about a chain besides its registries
+#[derive(Debug, Clone)]
+struct Desired {
+    chain: ChainId,
+    /// Every other chain in the topology, with its identifier
+    remotes: Vec<(ChainId, String)>,
+    fee_bps: Option<u64>,
+}
+
+/// How admin messages reach a bridge
+#[derive(Debug, Clone)]
+enum Target {
+    Evm {
+        bridge: Address,
+        chain_registry: Address,
+        token_registry: Address,
+    },
+    Terra,
+    Solana {
+        program_id: Pubkey,
+        admin: Pubkey,
+    },
+}
+
+/// A remote chain as this bridge has it registered
+#[derive(Debug, Clone)]
+struct RegisteredChain {
+    /// Identifier (EVM: identifier hash) for display
+    current: String,
+    /// Whether it is the topology's identifier
+    matches: bool,
+    enabled: bool,
+}
+
+#[derive(Debug, Clone)]
+struct RegisteredToken {
+    mode: TokenMode,
+    /// Terra only
+    decimals: Option<u8>,
+    enabled: bool,
+    /// Terra `min_bridge_amount`
+    min_amount: Option<u128>,
+}
+
+#[derive(Debug, Clone)]
+struct CurrentFee {
+    standard_fee_bps: u64,
+    /// EVM `setFeeParams` takes every parameter: the rest are resent unchanged
+    evm_rest: Option<(U256, U256, Address, Address)>,
+}
+
+/// Registry state read from one bridge, keyed by the leg token's display form
+#[derive(Debug, Clone, Default)]
+struct Observed {
+    chains: HashMap<[u8; 4], RegisteredChain>,
+    tokens: HashMap<String, RegisteredToken>,
+    /// (token, remote chain) → (remote token, remote decimals)
+    outgoing: HashMap<(String, [u8; 4]), ([u8; 32], u8)>,
+    /// (token, remote chain) → decimals on the remote chain
+    incoming: HashMap<(String, [u8; 4]), u8>,
+    /// Solana (remote chain, remote token) → mapping
+    mappings: HashMap<([u8; 4], [u8; 32]), SolanaTokenMapping>,
+    /// Explicitly configured limits
+    rate_limits: HashMap<String, RateLimit>,
+    fee: Option<CurrentFee>,
+}
+
+// ============================================================================
+// Plan
+// ============================================================================
+
+/// An admin message, addressed and encoded for its chain
+#[derive(Debug, Clone, PartialEq)]
+pub enum AdminAction {
+    /// Call on the bridge, `ChainRegistry` or `TokenRegistry`
+    Evm { to: Address, data: Bytes },
+    /// `ExecuteMsg` on the Terra bridge
+    Terra { msg: Value },
+    /// Instruction signed by the program admin
+    Solana { instruction: Instruction },
+}
+
+/// How a planned change relates to the deployed state
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    /// Missing on the bridge
+    Add,
+    /// Present with other values
+    Update,
+    /// Different and not repairable by an admin message
+    Conflict,
+}
+
+impl fmt::Display for ChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ChangeKind::Add => "add",
+            ChangeKind::Update => "update",
+            ChangeKind::Conflict => "conflict",
+        })
+    }
+}
+
+/// One difference between the topology and a bridge
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlannedChange {
+    pub chain: ChainId,
+    pub kind: ChainKind,
+    pub change: ChangeKind,
+    /// What differs, e.g. `USDC -> 0x00000002 destination`
+    pub subject: String,
+    /// Deployed value; `None` when missing
+    pub current: Option<String>,
+    pub desired: String,
+    /// `None` for conflicts
+    pub action: Option<AdminAction>,
+}
+
+/// Every difference, grouped by chain in the order they must be applied
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Plan {
+    pub changes: Vec<PlannedChange>,
+}
+
+impl Plan {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    pub fn conflicts(&self) -> impl Iterator<Item = &PlannedChange> {
+        self.changes
+            .iter()
+            .filter(|c| c.change == ChangeKind::Conflict)
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "changes": self.changes.iter().map(|c| json!({
+                "chain": c.chain.to_hex(),
+                "kind": c.kind.to_string(),
+                "change": c.change.to_string(),
+                "subject": c.subject,
+                "current": c.current,
+                "desired": c.desired,
+                "action": c.action.as_ref().map(action_json),
+            })).collect::<Vec<_>>(),
+        })
+    }
+}
+
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.changes.is_empty() {
+            return writeln!(f, "No changes: every bridge matches the topology");
+        }
+        let mut chain = None;
+        for c in &self.changes {
+            if chain != Some(c.chain) {
+                writeln!(f, "{} ({})", c.chain.to_hex(), c.kind)?;
+                chain = Some(c.chain);
+            }
+            let sign = match c.change {
+                ChangeKind::Add => '+',
+                ChangeKind::Update => '~',
+                ChangeKind::Conflict => '!',
+            };
+            match &c.current {
+                Some(current) => {
+                    writeln!(f, "  {} {}: {} -> {}", sign, c.subject, current, c.desired)?
+                }
+                None => writeln!(f, "  {} {}: {}", sign, c.subject, c.desired)?,
+            }
+        }
+        let conflicts = self.conflicts().count();
+        writeln!(
+            f,
+            "{} change(s), {} conflict(s) to resolve by hand",
+            self.changes.len() - conflicts,
+            conflicts
+        )
+    }
+}
+
+fn action_json(action: &AdminAction) -> Value {
+    match action {
+        AdminAction::Evm { to, data } => json!({ "to": to.to_string(), "data": data.to_string() }),
+        AdminAction::Terra { msg } => msg.clone(),
+        AdminAction::Solana { instruction } => {
+            let accounts: Vec<_> = instruction
+                .accounts
+                .iter()
+                .map(|a| a.pubkey.to_string())
+                .collect();
+            json!({
+                "program_id": instruction.program_id.to_string(),
+                "accounts": accounts,
+                "data": hex::encode(&instruction.data),
+            })
+        }
+    }
+}
+
+// ============================================================================
+// Diff
+// ============================================================================
+
+struct Differ<'a> {
+    desired: &'a Desired,
+    target: &'a Target,
+    observed: &'a Observed,
+    changes: Vec<PlannedChange>,
+}
+
+impl<'a> Differ<'a> {
+    fn kind(&self) -> ChainKind {
+        match self.target {
+            Target::Evm { .. } => ChainKind::Evm,
+            Target::Terra => ChainKind::Terra,
+            Target::Solana { .. } => ChainKind::Solana,
+        }
+    }
+
+    fn push(
+        &mut self,
+        change: ChangeKind,
+        subject: String,
+        current: Option<String>,
+        desired: String,
+        action: Option<AdminAction>,
+    ) {
+        self.changes.push(PlannedChange {
+            chain: self.desired.chain,
+            kind: self.kind(),
+            change,
+            subject,
+            current,
+            desired,
+            action,
+        });
+    }
+
+    fn chains(&mut self) {
+        for (remote, identifier) in &self.desired.remotes {
+            let subject = format!("chain {}", remote.to_hex());
+            match self.observed.chains.get(remote.as_bytes()).cloned() {
+                None => {
+                    let action = register_chain_action(self.target, *remote, identifier);
+                    self.push(
+                        ChangeKind::Add,
+                        subject,
+                        None,
+                        identifier.clone(),
+                        Some(action),
+                    );
+                }
+                Some(registered) if !registered.matches => self.push(
+                    ChangeKind::Conflict,
+                    subject,
+                    Some(registered.current),
+                    identifier.clone(),
+                    None,
+                ),
+                Some(registered) if !registered.enabled => self.push(
+                    ChangeKind::Update,
+                    format!("{} enabled", subject),
+                    Some("false".into()),
+                    "true".into(),
+                    Some(terra(json!({
+                        "update_chain": {
+                            "chain_id": B64.encode(remote.as_bytes()),
+                            "enabled": true,
+                        }
+                    }))),
+                ),
+                Some(_) => {}
+            }
+        }
+    }
+
+    fn token(&mut self, name: &str, leg: &Leg) {
+        if matches!(self.target, Target::Solana { .. }) {
+            return;
+        }
+        let key = leg.token.to_string();
+        let subject = format!("token {} ({})", name, key);
+        let Some(registered) = self.observed.tokens.get(&key).cloned() else {
+            let action = register_token_action(self.target, leg);
+            self.push(
+                ChangeKind::Add,
+                subject,
+                None,
+                format!("{}, {} decimals", leg.mode, leg.decimals),
+                Some(action),
+            );
+            return;
+        };
+        if let Some(decimals) = registered.decimals.filter(|d| *d != leg.decimals) {
+            self.push(
+                ChangeKind::Conflict,
+                format!("{} decimals", subject),
+                Some(decimals.to_string()),
+                leg.decimals.to_string(),
+                None,
+            );
+        }
+        if registered.mode != leg.mode {
+            let action = match self.target {
+                Target::Evm { token_registry, .. } => evm(
+                    *token_registry,
+                    TokenRegistry::setTokenTypeCall {
+                        token: evm_token(leg),
+                        tokenType: leg.mode.evm_type(),
+                    },
+                ),
+                _ => terra(json!({
+                    "update_token": { "token": key, "token_type": leg.mode.to_string() }
+                })),
+            };
+            self.push(
+                ChangeKind::Update,
+                format!("{} mode", subject),
+                Some(registered.mode.to_string()),
+                leg.mode.to_string(),
+                Some(action),
+            );
+        }
+        if !registered.enabled {
+            self.push(
+                ChangeKind::Update,
+                format!("{} enabled", subject),
+                Some("false".into()),
+                "true".into(),
+                Some(terra(
+                    json!({ "update_token": { "token": key, "enabled": true } }),
+                )),
+            );
+        }
+    }
+
+    fn route(&mut self, name: &str, leg: &Leg, remote: &Leg) {
+        if let Target::Solana { program_id, admin } = self.target {
+            let (program_id, admin) = (*program_id, *admin);
+            return self.solana_mapping(name, leg, remote, &program_id, &admin);
+        }
+        let key = leg.token.to_string();
+        let remote_chain = remote.chain;
+        let route = (key.clone(), *remote_chain.as_bytes());
+
+        let desired = (remote.token_bytes32, remote.decimals);
+        let current = self.observed.outgoing.get(&route).copied();
+        if current != Some(desired) {
+            let action = match self.target {
+                Target::Evm { token_registry, .. } => evm(
+                    *token_registry,
+                    TokenRegistry::setTokenDestinationWithDecimalsCall {
+                        token: evm_token(leg),
+                        destChain: FixedBytes(*remote_chain.as_bytes()),
+                        destToken: FixedBytes(remote.token_bytes32),
+                        destDecimals: remote.decimals,
+                    },
+                ),
+                _ => terra(json!({
+                    "set_token_destination": {
+                        "token": key,
+                        "dest_chain": B64.encode(remote_chain.as_bytes()),
+                        "dest_token": hex::encode(remote.token_bytes32),
+                        "dest_decimals": remote.decimals,
+                    }
+                })),
+            };
+            self.push(
+                if current.is_some() {
+                    ChangeKind::Update
+                } else {
+                    ChangeKind::Add
+                },
+                format!("{} -> {} destination", name, remote_chain.to_hex()),
+                current.map(|(token, decimals)| describe_remote(&token, decimals)),
+                describe_remote(&desired.0, desired.1),
+                Some(action),
+            );
+        }
+
+        let current = self.observed.incoming.get(&route).copied();
+        if current != Some(remote.decimals) {
+            let action = match self.target {
+                Target::Evm { token_registry, .. } => evm(
+                    *token_registry,
+                    TokenRegistry::setIncomingTokenMappingCall {
+                        srcChain: FixedBytes(*remote_chain.as_bytes()),
+                        localToken: evm_token(leg),
+                        srcDecimals: remote.decimals,
+                    },
+                ),
+                // Keyed by the local token's hashed form, the token withdrawals name
+                _ => terra(json!({
+                    "set_incoming_token_mapping": {
+                        "src_chain": B64.encode(remote_chain.as_bytes()),
+                        "src_token": B64.encode(leg.token_bytes32),
+                        "local_token": key,
+                        "src_decimals": remote.decimals,
+                    }
+                })),
+            };
+            self.push(
+                if current.is_some() {
+                    ChangeKind::Update
+                } else {
+                    ChangeKind::Add
+                },
+                format!("{} <- {} source decimals", name, remote_chain.to_hex()),
+                current.map(|d| d.to_string()),
+                remote.decimals.to_string(),
+                Some(action),
+            );
+        }
+    }
+
+    fn solana_mapping(
+        &mut self,
+        name: &str,
+        leg: &Leg,
+        remote: &Leg,
+        program_id: &Pubkey,
+        admin: &Pubkey,
+    ) {
+        let LegToken::Solana(mint) = leg.token else {
+            return;
+        };
+        let desired = SolanaTokenMapping {
+            local_mint: mint.to_bytes(),
+            dest_chain: *remote.chain.as_bytes(),
+            dest_token: remote.token_bytes32,
+            mint_burn: leg.mode == TokenMode::MintBurn,
+            decimals: leg.decimals,
+            src_decimals: remote.decimals,
+        };
+        let subject = format!("{} <-> {} mapping", name, remote.chain.to_hex());
+        match self
+            .observed
+            .mappings
+            .get(&(desired.dest_chain, desired.dest_token))
+        {
+            None => {
+                let action = AdminAction::Solana {
+                    instruction: register_token_ix(program_id, admin, &desired),
+                };
+                self.push(
+                    ChangeKind::Add,
+                    subject,
+                    None,
+                    describe_mapping(&desired),
+                    Some(action),
+                );
+            }
+            Some(current) if *current != desired => {
+                let current = describe_mapping(current);
+                self.push(
+                    ChangeKind::Conflict,
+                    subject,
+                    Some(current),
+                    describe_mapping(&desired),
+                    None,
+                );
+            }
+            Some(_) => {}
+        }
+    }
+
+    fn rate_limit(&mut self, name: &str, leg: &Leg) {
+        let Some(desired) = leg.rate_limit else {
+            return;
+        };
+        let key = leg.token.to_string();
+        let subject = format!("{} rate limit", name);
+        let current = self.observed.rate_limits.get(&key).copied();
+        match self.target {
+            Target::Evm { token_registry, .. } => {
+                if current != Some(desired) {
+                    let action = evm(
+                        *token_registry,
+                        TokenRegistry::setRateLimitCall {
+                            token: evm_token(leg),
+                            minPerTransaction: U256::from(desired.min_per_transaction),
+                            maxPerTransaction: U256::from(desired.max_per_transaction),
+                            maxPerPeriod: U256::from(desired.max_per_period),
+                        },
+                    );
+                    self.push_limit(subject, current, desired, action);
+                }
+            }
+            Target::Solana { program_id, admin } => {
+                if current != Some(desired) {
+                    let LegToken::Solana(mint) = leg.token else {
+                        return;
+                    };
+                    let action = AdminAction::Solana {
+                        instruction: set_rate_limit_ix(program_id, admin, &mint, &desired),
+                    };
+                    self.push_limit(subject, current, desired, action);
+                }
+            }
+            Target::Terra => {
+                // Terra keeps the per-transaction minimum on the token, not the rate limit
+                let registered = self.observed.tokens.get(&key);
+                let min = registered.and_then(|t| t.min_amount);
+                let current = current.map(|c| RateLimit {
+                    min_per_transaction: min.unwrap_or_default(),
+                    ..c
+                });
+                let maxima = |l: &RateLimit| (l.max_per_transaction, l.max_per_period);
+                if current.as_ref().map(maxima) != Some(maxima(&desired)) {
+                    let action = terra(json!({
+                        "set_rate_limit": {
+                            "token": key,
+                            "max_per_transaction": desired.max_per_transaction.to_string(),
+                            "max_per_period": desired.max_per_period.to_string(),
+                        }
+                    }));
+                    self.push_limit(subject.clone(), current, desired, action);
+                }
+                // A token registered by this plan gets its minimum in `add_token`
+                if registered.is_some() && min != Some(desired.min_per_transaction) {
+                    let action = terra(json!({
+                        "update_token": {
+                            "token": key,
+                            "min_bridge_amount": desired.min_per_transaction.to_string(),
+                        }
+                    }));
+                    self.push(
+                        ChangeKind::Update,
+                        format!("{} minimum", subject),
+                        min.map(|m| m.to_string()),
+                        desired.min_per_transaction.to_string(),
+                        Some(action),
+                    );
+                }
+            }
+        }
+    }
+
+    fn push_limit(
+        &mut self,
+        subject: String,
+        current: Option<RateLimit>,
+        desired: RateLimit,
+        action: AdminAction,
+    ) {
+        self.push(
+            if current.is_some() {
+                ChangeKind::Update
+            } else {
+                ChangeKind::Add
+            },
+            subject,
+            current.map(|c| c.to_string()),
+            desired.to_string(),
+            Some(action),
+        );
+    }
+
+    fn fee(&mut self) {
+        let Some(fee_bps) = self.desired.fee_bps else {
+            return;
+        };
+        let Some(current) = self.observed.fee.clone() else {
+            return;
+        };
+        if current.standard_fee_bps == fee_bps {
+            return;
+        }
+        let action = match (self.target, current.evm_rest) {
+            (
+                Target::Evm { bridge, .. },
+                Some((discounted, threshold, cl8y_token, fee_recipient)),
+            ) => evm(
+                *bridge,
+                Bridge::setFeeParamsCall {
+                    standardFeeBps: U256::from(fee_bps),
+                    discountedFeeBps: discounted,
+                    cl8yThreshold: threshold,
+                    cl8yToken: cl8y_token,
+                    feeRecipient: fee_recipient,
+                },
+            ),
+            (Target::Solana { program_id, admin }, _) => AdminAction::Solana {
+                instruction: set_fee_bps_ix(program_id, admin, fee_bps as u16),
+            },
+            _ => terra(json!({ "set_fee_params": { "standard_fee_bps": fee_bps } })),
+        };
+        self.push(
+            ChangeKind::Update,
+            "standard fee".into(),
+            Some(format!("{} bps", current.standard_fee_bps)),
+            format!("{} bps", fee_bps),
+            Some(action),
+        );
+    }
+}
+
+/// Changes for one bridge: remote chains first, then tokens, mappings, limits and the fee,
+/// so that every message only depends on earlier ones
+fn diff(
+    desired: &Desired,
+    target: &Target,
+    observed: &Observed,
+    tokens: &[Token],
+) -> Vec<PlannedChange> {
+    let mut d = Differ {
+        desired,
+        target,
+        observed,
+        changes: Vec::new(),
+    };
+    d.chains();
+    let legs = local_legs(tokens, desired.chain);
+    for (token, leg) in &legs {
+        d.token(&token.name, leg);
+    }
+    for (token, leg) in &legs {
+        for remote in token.legs.iter().filter(|l| l.chain != desired.chain) {
+            d.route(&token.name, leg, remote);
+        }
+    }
+    for (token, leg) in &legs {
+        d.rate_limit(&token.name, leg);
+    }
+    d.fee();
+    d.changes
+}
+
+fn describe_remote(token: &[u8; 32], decimals: u8) -> String {
+    format!("0x{} ({} decimals)", hex::encode(token), decimals)
+}
+
+fn describe_mapping(m: &SolanaTokenMapping) -> String {
+    format!(
+        "mint {}, remote 0x{}, {}, {} local / {} remote decimals",
+        Pubkey::new_from_array(m.local_mint),
+        hex::encode(m.dest_token),
+        if m.mint_burn {
+            TokenMode::MintBurn
+        } else {
+            TokenMode::LockUnlock
+        },
+        m.decimals,
+        m.src_decimals
+    )
+}
+
+// ============================================================================
+// Admin messages
+// ============================================================================
+
+fn evm<C: SolCall>(to: Address, call: C) -> AdminAction {
+    AdminAction::Evm {
+        to,
+        data: Bytes::from(call.abi_encode()),
+    }
+}
+
+fn terra(msg: Value) -> AdminAction {
+    AdminAction::Terra { msg }
+}
+
+fn evm_token(leg: &Leg) -> Address {
+    match leg.token {
+        LegToken::Evm(address) => address,
+        _ => Address::ZERO,
+    }
+}
+
+fn register_chain_action(target: &Target, chain: ChainId, identifier: &str) -> AdminAction {
+    match target {
+        Target::Evm { chain_registry, .. } => evm(
+            *chain_registry,
+            ChainRegistry::registerChainCall {
+                identifier: identifier.to_string(),
+                chainId: FixedBytes(*chain.as_bytes()),
+            },
+        ),
+        Target::Terra => terra(json!({
+            "register_chain": {
+                "identifier": identifier,
+                "chain_id": B64.encode(chain.as_bytes()),
+            }
+        })),
+        Target::Solana { program_id, admin } => AdminAction::Solana {
+            instruction: register_chain_ix(program_id, admin, chain, identifier),
+        },
+    }
+}
+
+fn register_token_action(target: &Target, leg: &Leg) -> AdminAction {
+    match (target, &leg.token) {
+        (Target::Evm { token_registry, .. }, LegToken::Evm(token)) => evm(
+            *token_registry,
+            TokenRegistry::registerTokenCall {
+                token: *token,
+                tokenType: leg.mode.evm_type(),
+            },
+        ),
+        _ => {
+            let token = leg.token.to_string();
+            let min = leg.rate_limit.map(|l| l.min_per_transaction.to_string());
+            terra(json!({
+                "add_token": {
+                    "is_native": !is_cw20(&token),
+                    "token": token,
+                    "token_type": leg.mode.to_string(),
+                    "terra_decimals": leg.decimals,
+                    "min_bridge_amount": min,
+                    "max_bridge_amount": Value::Null,
+                }
+            }))
+        }
+    }
+}
+
+/// Anchor instruction discriminator
+fn discriminator(name: &str) -> Vec<u8> {
+    anchor_discriminator(&format!("global:{}", name)).to_vec()
+}
+
+fn register_chain_ix(
+    program_id: &Pubkey,
+    admin: &Pubkey,
+    chain: ChainId,
+    identifier: &str,
+) -> Instruction {
+    let mut data = discriminator("register_chain");
+    data.extend_from_slice(chain.as_bytes());
+    data.extend_from_slice(&(identifier.len() as u32).to_le_bytes());
+    data.extend_from_slice(identifier.as_bytes());
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(bridge_config_pda(program_id), false),
+            AccountMeta::new(chain_entry_pda(program_id, chain.as_bytes()), false),
+            AccountMeta::new(*admin, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data,
+    }
+}
+
+fn register_token_ix(
+    program_id: &Pubkey,
+    admin: &Pubkey,
+    mapping: &SolanaTokenMapping,
+) -> Instruction {
+    let mint = Pubkey::new_from_array(mapping.local_mint);
+    let mut data = discriminator("register_token");
+    data.extend_from_slice(&mapping.local_mint);
+    data.extend_from_slice(&mapping.dest_chain);
+    data.extend_from_slice(&mapping.dest_token);
+    data.push(mapping.mint_burn as u8);
+    data.push(mapping.decimals);
+    data.push(mapping.src_decimals);
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(bridge_config_pda(program_id), false),
+            AccountMeta::new(
+                token_mapping_pda(program_id, &mapping.dest_chain, &mapping.dest_token),
+                false,
+            ),
+            // Native SOL has no mint account: Anchor reads the program ID as `None`
+            AccountMeta::new_readonly(
+                if mint == Pubkey::default() {
+                    *program_id
+                } else {
+                    mint
+                },
+                false,
+            ),
+            AccountMeta::new(*admin, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data,
+    }
+}
+
+fn set_rate_limit_ix(
+    program_id: &Pubkey,
+    admin: &Pubkey,
+    mint: &Pubkey,
+    limit: &RateLimit,
+) -> Instruction {
+    let mut data = discriminator("set_rate_limit");
+    data.extend_from_slice(mint.as_ref());
+    data.extend_from_slice(&limit.min_per_transaction.to_le_bytes());
+    data.extend_from_slice(&limit.max_per_transaction.to_le_bytes());
+    data.extend_from_slice(&limit.max_per_period.to_le_bytes());
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(bridge_config_pda(program_id), false),
+            AccountMeta::new(withdraw_rate_limit_pda(program_id, mint), false),
+            AccountMeta::new(*admin, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data,
+    }
+}
+
+/// `set_config` changing only `fee_bps`
+fn set_fee_bps_ix(program_id: &Pubkey, admin: &Pubkey, fee_bps: u16) -> Instruction {
+    let mut data = discriminator("set_config");
+    // new_admin: None, operator: None, fee_bps: Some, withdraw_delay: None, paused: None
+    data.extend_from_slice(&[0, 0, 1]);
+    data.extend_from_slice(&fee_bps.to_le_bytes());
+    data.extend_from_slice(&[0, 0]);
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(bridge_config_pda(program_id), false),
+            AccountMeta::new_readonly(*admin, true),
+        ],
+        data,
+    }
+}
+
+/// CW20 contracts are `terra1…` addresses; anything else is a native denom
+fn is_cw20(token: &str) -> bool {
+    token.starts_with("terra1")
+}
+
+// ============================================================================
+// Topology
+// ============================================================================
+
+enum Backend {
+    Evm {
+        client: EvmQueryClient,
+        rpc_url: String,
+        bridge: Address,
+    },
+    Terra {
+        client: TerraQueryClient,
+        lcd_url: String,
+        cosmos_chain_id: Option<String>,
+    },
+    Solana {
+        client: Arc<RpcClient>,
+        program_id: Pubkey,
+    },
+}
+
+struct TopologyChain {
+    id: ChainId,
+    identifier: String,
+    fee_bps: Option<u64>,
+    backend: Backend,
+}
+
+impl TopologyChain {
+    fn kind(&self) -> ChainKind {
+        match self.backend {
+            Backend::Evm { .. } => ChainKind::Evm,
+            Backend::Terra { .. } => ChainKind::Terra,
+            Backend::Solana { .. } => ChainKind::Solana,
+        }
+    }
+}
+
+/// Keys that sign `apply` transactions; each must be the admin of its bridges
+#[derive(Default)]
+pub struct AdminSigners {
+    /// Hex private key of the EVM bridges' owner
+    pub evm_private_key: Option<String>,
+    pub terra_mnemonic: Option<String>,
+    pub solana_keypair: Option<Keypair>,
+}
+
+/// The configured bridges and token legs
+pub struct Topology {
+    chains: Vec<TopologyChain>,
+    tokens: Vec<Token>,
+}
+
+impl Topology {
+    /// Connect to every bridge, check the chain ID each reports, and resolve the token legs
+    pub async fn connect(config: &TopologyConfig) -> Result<Self> {
+        let mut chains = Vec::with_capacity(config.chains.len());
+        for chain in &config.chains {
+            let expected = parse_chain_id(&chain.chain)?;
+            let (id, backend) = connect_chain(&chain.endpoint).await?;
+            if id != expected {
+                return Err(eyre!(
+                    "bridge configured as chain {} reports chain {}",
+                    expected.to_hex(),
+                    id.to_hex()
+                ));
+            }
+            if chains.iter().any(|c: &TopologyChain| c.id == id) {
+                return Err(eyre!("chain {} is configured twice", id.to_hex()));
+            }
+            chains.push(TopologyChain {
+                id,
+                identifier: chain.identifier.clone(),
+                fee_bps: chain.fee_bps,
+                backend,
+            });
+        }
+        let kinds: Vec<_> = chains.iter().map(|c| (c.id, c.kind())).collect();
+        let tokens = resolve_tokens(&config.tokens, &kinds)?;
+        Ok(Self { chains, tokens })
+    }
+
+    /// Every configured chain and its kind
+    pub fn chains(&self) -> Vec<(ChainId, ChainKind)> {
+        self.chains.iter().map(|c| (c.id, c.kind())).collect()
+    }
+
+    /// Read every bridge's registries and list what differs from the topology
+    pub async fn plan(&self) -> Result<Plan> {
+        let mut changes = Vec::new();
+        for chain in &self.chains {
+            let desired = Desired {
+                chain: chain.id,
+                remotes: self
+                    .chains
+                    .iter()
+                    .filter(|c| c.id != chain.id)
+                    .map(|c| (c.id, c.identifier.clone()))
+                    .collect(),
+                fee_bps: chain.fee_bps,
+            };
+            let legs = local_legs(&self.tokens, chain.id);
+            let (target, observed) = observe(chain, &desired, &legs)
+                .await
+                .wrap_err_with(|| format!("reading chain {}", chain.id.to_hex()))?;
+            changes.extend(diff(&desired, &target, &observed, &self.tokens));
+        }
+        Ok(Plan { changes })
+    }
+
+    /// Send every applicable change in order, stopping at the first failure; returns the
+    /// transaction hashes. Conflicts are skipped.
+    pub async fn apply(&self, plan: &Plan, signers: &AdminSigners) -> Result<Vec<String>> {
+        // Check every signer before the first transaction so a run never stops half-way for
+        // a missing key
+        for change in plan.changes.iter().filter(|c| c.action.is_some()) {
+            let missing = match change.kind {
+                ChainKind::Evm => signers
+                    .evm_private_key
+                    .is_none()
+                    .then_some("an EVM admin key"),
+                ChainKind::Terra => signers
+                    .terra_mnemonic
+                    .is_none()
+                    .then_some("a Terra admin mnemonic"),
+                ChainKind::Solana => signers
+                    .solana_keypair
+                    .is_none()
+                    .then_some("a Solana admin keypair"),
+            };
+            if let Some(missing) = missing {
+                return Err(eyre!(
+                    "{} is required to apply changes on chain {}",
+                    missing,
+                    change.chain.to_hex()
+                ));
+            }
+        }
+
+        let mut sent = Vec::new();
+        let mut evm_signers: HashMap<[u8; 4], EvmSigner> = HashMap::new();
+        let mut terra_signers: HashMap<[u8; 4], TerraSigner> = HashMap::new();
+        for change in &plan.changes {
+            let Some(action) = &change.action else {
+                continue;
+            };
+            let chain = self
+                .chains
+                .iter()
+                .find(|c| c.id == change.chain)
+                .ok_or_else(|| eyre!("plan names unknown chain {}", change.chain.to_hex()))?;
+            let key = *chain.id.as_bytes();
+            let tx = match (action, &chain.backend) {
+                (
+                    AdminAction::Evm { to, data },
+                    Backend::Evm {
+                        client, rpc_url, ..
+                    },
+                ) => {
+                    let signer = match evm_signers.entry(key) {
+                        Entry::Occupied(e) => e.into_mut(),
+                        Entry::Vacant(e) => {
+                            let pk = signers
+                                .evm_private_key
+                                .as_deref()
+                                .ok_or_else(|| eyre!("an EVM admin key is required"))?;
+                            let evm_chain_id = client.provider().get_chain_id().await?;
+                            e.insert(EvmSigner::from_private_key(rpc_url, evm_chain_id, pk)?)
+                        }
+                    };
+                    let receipt = signer.send_contract_call(*to, data.clone(), None).await?;
+                    let hash = format!("{:#x}", receipt.transaction_hash);
+                    if !receipt.status() {
+                        return Err(eyre!("EVM transaction {} reverted", hash));
+                    }
+                    hash
+                }
+                (
+                    AdminAction::Terra { msg },
+                    Backend::Terra {
+                        client,
+                        lcd_url,
+                        cosmos_chain_id,
+                    },
+                ) => {
+                    let signer = match terra_signers.entry(key) {
+                        Entry::Occupied(e) => e.into_mut(),
+                        Entry::Vacant(e) => {
+                            let mnemonic = signers
+                                .terra_mnemonic
+                                .as_deref()
+                                .ok_or_else(|| eyre!("a Terra admin mnemonic is required"))?;
+                            let cosmos_chain_id = cosmos_chain_id.as_deref().ok_or_else(|| {
+                                eyre!("chain {} needs cosmos_chain_id to sign", chain.id.to_hex())
+                            })?;
+                            e.insert(TerraSigner::from_mnemonic(
+                                lcd_url,
+                                cosmos_chain_id,
+                                mnemonic,
+                            )?)
+                        }
+                    };
+                    let result = signer
+                        .sign_and_broadcast_execute(client.bridge_address(), msg, vec![])
+                        .await?;
+                    if !(result.success && result.confirmed) {
+                        return Err(eyre!(
+                            "Terra transaction {} failed: {}",
+                            result.tx_hash,
+                            result.raw_log.unwrap_or_default()
+                        ));
+                    }
+                    result.tx_hash
+                }
+                (AdminAction::Solana { instruction }, Backend::Solana { client, .. }) => {
+                    let keypair = signers
+                        .solana_keypair
+                        .as_ref()
+                        .ok_or_else(|| eyre!("a Solana admin keypair is required"))?;
+                    send_solana(client, keypair, instruction.clone()).await?
+                }
+                _ => return Err(eyre!("action does not match chain {}", chain.id.to_hex())),
+            };
+            info!(chain = %chain.id.to_hex(), subject = %change.subject, %tx, "Applied");
+            sent.push(tx);
+        }
+        Ok(sent)
+    }
+}
+
+fn resolve_tokens(configs: &[TokenConfig], chains: &[(ChainId, ChainKind)]) -> Result<Vec<Token>> {
+    let mut tokens = Vec::with_capacity(configs.len());
+    for token in configs {
+        let mut legs: Vec<Leg> = Vec::with_capacity(token.legs.len());
+        for leg in &token.legs {
+            let chain = parse_chain_id(&leg.chain)?;
+            let kind = chains
+                .iter()
+                .find(|(id, _)| *id == chain)
+                .map(|(_, kind)| *kind)
+                .ok_or_else(|| {
+                    eyre!(
+                        "token {}: chain {} is not configured",
+                        token.name,
+                        chain.to_hex()
+                    )
+                })?;
+            if legs.iter().any(|l| l.chain == chain) {
+                return Err(eyre!(
+                    "token {} has two legs on chain {}",
+                    token.name,
+                    chain.to_hex()
+                ));
+            }
+            if leg.decimals > 18 {
+                return Err(eyre!("token {}: decimals cannot exceed 18", token.name));
+            }
+            let (resolved, token_bytes32) = resolve_token(kind, &leg.token)
+                .wrap_err_with(|| format!("token {}", token.name))?;
+            let rate_limit = match &leg.rate_limit {
+                Some(limit) => Some(
+                    parse_rate_limit(limit)
+                        .wrap_err_with(|| format!("token {} rate limit", token.name))?,
+                ),
+                None => None,
+            };
+            legs.push(Leg {
+                chain,
+                token: resolved,
+                token_bytes32,
+                decimals: leg.decimals,
+                mode: leg.mode,
+                rate_limit,
+            });
+        }
+        tokens.push(Token {
+            name: token.name.clone(),
+            legs,
+        });
+    }
+    Ok(tokens)
+}
+
+fn resolve_token(kind: ChainKind, token: &str) -> Result<(LegToken, [u8; 32])> {
+    Ok(match kind {
+        ChainKind::Evm => {
+            let address: Address = token
+                .parse()
+                .wrap_err_with(|| format!("invalid EVM token {}", token))?;
+            (
+                LegToken::Evm(address),
+                address_to_bytes32(&address.into_array()),
+            )
+        }
+        ChainKind::Terra => (
+            LegToken::Terra(token.to_string()),
+            encode_terra_token_address(token),
+        ),
+        ChainKind::Solana => {
+            let mint: Pubkey = token
+                .parse()
+                .wrap_err_with(|| format!("invalid Solana mint {}", token))?;
+            (LegToken::Solana(mint), mint.to_bytes())
+        }
+    })
+}
+
+fn parse_rate_limit(config: &RateLimitConfig) -> Result<RateLimit> {
+    let amount = |s: &str| {
+        s.parse::<u128>()
+            .wrap_err_with(|| format!("invalid amount {}", s))
+    };
+    Ok(RateLimit {
+        min_per_transaction: match &config.min_per_transaction {
+            Some(min) => amount(min)?,
+            None => 0,
+        },
+        max_per_transaction: amount(&config.max_per_transaction)?,
+        max_per_period: amount(&config.max_per_period)?,
+    })
+}
+
+fn local_legs(tokens: &[Token], chain: ChainId) -> Vec<(&Token, &Leg)> {
+    tokens
+        .iter()
+        .filter_map(|t| t.legs.iter().find(|l| l.chain == chain).map(|l| (t, l)))
+        .collect()
+}
+
+async fn connect_chain(endpoint: &TopologyEndpoint) -> Result<(ChainId, Backend)> {
+    Ok(match endpoint {
+        TopologyEndpoint::Evm { rpc_url, bridge } => {
+            let bridge: Address = bridge
+                .parse()
+                .wrap_err_with(|| format!("invalid EVM bridge address {}", bridge))?;
+            let client = EvmQueryClient::new(rpc_url, bridge, 0)?;
+            let id = client
+                .get_this_chain_id()
+                .await
+                .wrap_err_with(|| format!("EVM bridge {} on {}", bridge, rpc_url))?;
+            (
+                id,
+                Backend::Evm {
+                    client,
+                    rpc_url: rpc_url.clone(),
+                    bridge,
+                },
+            )
+        }
+        TopologyEndpoint::Terra {
+            lcd_url,
+            bridge,
+            cosmos_chain_id,
+        } => {
+            let client = TerraQueryClient::new(lcd_url, bridge);
+            let id = client
+                .get_this_chain_id()
+                .await
+                .wrap_err_with(|| format!("Terra bridge {} on {}", bridge, lcd_url))?;
+            (
+                id,
+                Backend::Terra {
+                    client,
+                    lcd_url: lcd_url.clone(),
+                    cosmos_chain_id: cosmos_chain_id.clone(),
+                },
+            )
+        }
+        TopologyEndpoint::Solana {
+            rpc_url,
+            program_id,
+        } => {
+            let program_id: Pubkey = program_id
+                .parse()
+                .wrap_err_with(|| format!("invalid Solana program ID {}", program_id))?;
+            let client = Arc::new(RpcClient::new_with_commitment(
+                rpc_url.clone(),
+                CommitmentConfig::confirmed(),
+            ));
+            let config = blocking(&client, move |c| {
+                let account = c.get_account(&bridge_config_pda(&program_id))?;
+                parse_bridge_config(&account.data)
+            })
+            .await
+            .wrap_err_with(|| format!("Solana program {} on {}", program_id, rpc_url))?;
+            (
+                ChainId::from_bytes(config.chain_id),
+                Backend::Solana { client, program_id },
+            )
+        }
+    })
+}
+
+// ============================================================================
+// Reading the registries
+// ============================================================================
+
+async fn observe(
+    chain: &TopologyChain,
+    desired: &Desired,
+    legs: &[(&Token, &Leg)],
+) -> Result<(Target, Observed)> {
+    match &chain.backend {
+        Backend::Evm { client, bridge, .. } => observe_evm(client, *bridge, desired, legs).await,
+        Backend::Terra { client, .. } => {
+            Ok((Target::Terra, observe_terra(client, desired, legs).await?))
+        }
+        Backend::Solana { client, program_id } => {
+            observe_solana(client, *program_id, desired, legs).await
+        }
+    }
+}
+
+async fn observe_evm(
+    client: &EvmQueryClient,
+    bridge: Address,
+    desired: &Desired,
+    legs: &[(&Token, &Leg)],
+) -> Result<(Target, Observed)> {
+    let chain_registry = client.get_chain_registry_address().await?;
+    let token_registry = client.get_token_registry_address().await?;
+    let registry = TokenRegistry::new(token_registry, client.provider());
+    let mut observed = Observed::default();
+
+    for (remote, identifier) in &desired.remotes {
+        if !client.is_chain_registered(chain_registry, *remote).await? {
+            continue;
+        }
+        let registered = client.get_chain_hash(chain_registry, *remote).await?;
+        let expected = client
+            .compute_identifier_hash(chain_registry, identifier)
+            .await?;
+        observed.chains.insert(
+            *remote.as_bytes(),
+            RegisteredChain {
+                current: format!("identifier hash 0x{}", hex::encode(registered)),
+                matches: registered == expected,
+                enabled: true,
+            },
+        );
+    }
+
+    let mut incoming: HashMap<[u8; 4], HashMap<Address, u8>> = HashMap::new();
+    for (token, leg) in legs {
+        let address = evm_token(leg);
+        let key = leg.token.to_string();
+        if !client.is_token_registered(token_registry, address).await? {
+            continue;
+        }
+        let mode = match client.get_token_type(token_registry, address).await? {
+            1 => TokenMode::MintBurn,
+            _ => TokenMode::LockUnlock,
+        };
+        observed.tokens.insert(
+            key.clone(),
+            RegisteredToken {
+                mode,
+                decimals: None,
+                enabled: true,
+                min_amount: None,
+            },
+        );
+        for remote in token.legs.iter().filter(|l| l.chain != leg.chain) {
+            let dest_chain = FixedBytes(*remote.chain.as_bytes());
+            let mapping = registry
+                .getDestTokenMapping(address, dest_chain)
+                .call()
+                .await
+                .map_err(|e| eyre!("Failed to get destination mapping: {}", e))?;
+            if mapping.destToken != FixedBytes::ZERO {
+                observed.outgoing.insert(
+                    (key.clone(), *remote.chain.as_bytes()),
+                    (mapping.destToken.0, mapping.destDecimals),
+                );
+            }
+            if !incoming.contains_key(remote.chain.as_bytes()) {
+                let all = registry
+                    .getAllIncomingMappings(dest_chain)
+                    .call()
+                    .await
+                    .map_err(|e| eyre!("Failed to get incoming mappings: {}", e))?;
+                incoming.insert(
+                    *remote.chain.as_bytes(),
+                    all.tokens.into_iter().zip(all.srcDecimals).collect(),
+                );
+            }
+            if let Some(decimals) = incoming[remote.chain.as_bytes()].get(&address) {
+                observed
+                    .incoming
+                    .insert((key.clone(), *remote.chain.as_bytes()), *decimals);
+            }
+        }
+        if leg.rate_limit.is_some() {
+            let limit = registry
+                .getRateLimitConfig(address)
+                .call()
+                .await
+                .map_err(|e| eyre!("Failed to get rate limit: {}", e))?;
+            observed.rate_limits.insert(
+                key,
+                RateLimit {
+                    min_per_transaction: u256_to_u128(limit.minPerTransaction)?,
+                    max_per_transaction: u256_to_u128(limit.maxPerTransaction)?,
+                    max_per_period: u256_to_u128(limit.maxPerPeriod)?,
+                },
+            );
+        }
+    }
+
+    if desired.fee_bps.is_some() {
+        let fee = Bridge::new(bridge, client.provider())
+            .feeConfig()
+            .call()
+            .await
+            .map_err(|e| eyre!("Failed to get fee config: {}", e))?;
+        observed.fee = Some(CurrentFee {
+            standard_fee_bps: fee.standardFeeBps.saturating_to(),
+            evm_rest: Some((
+                fee.discountedFeeBps,
+                fee.cl8yThreshold,
+                fee.cl8yToken,
+                fee.feeRecipient,
+            )),
+        });
+    }
+
+    Ok((
+        Target::Evm {
+            bridge,
+            chain_registry,
+            token_registry,
+        },
+        observed,
+    ))
+}
+
+#[derive(Deserialize)]
+struct TerraChain {
+    chain_id: String,
+    identifier: String,
+    enabled: bool,
+}
+
+#[derive(Deserialize)]
+struct TerraChains {
+    chains: Vec<TerraChain>,
+}
+
+#[derive(Deserialize)]
+struct TerraToken {
+    token: String,
+    terra_decimals: u8,
+    enabled: bool,
+    min_bridge_amount: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct TerraTokens {
+    tokens: Vec<TerraToken>,
+}
+
+#[derive(Deserialize)]
+struct TerraDestMapping {
+    dest_token: String,
+    dest_decimals: u8,
+}
+
+#[derive(Deserialize)]
+struct TerraIncomingMapping {
+    src_decimals: u8,
+    enabled: bool,
+}
+
+#[derive(Deserialize)]
+struct TerraRateLimit {
+    max_per_transaction: String,
+    max_per_period: String,
+}
+
+#[derive(Deserialize)]
+struct TerraFeeConfig {
+    standard_fee_bps: u64,
+}
+
+async fn observe_terra(
+    client: &TerraQueryClient,
+    desired: &Desired,
+    legs: &[(&Token, &Leg)],
+) -> Result<Observed> {
+    let bridge = client.bridge_address();
+    let mut observed = Observed::default();
+
+    let mut start_after: Option<String> = None;
+    loop {
+        let page: TerraChains = client
+            .query_contract(
+                bridge,
+                &json!({ "chains": { "start_after": start_after, "limit": TERRA_PAGE } }),
+            )
+            .await?;
+        for chain in &page.chains {
+            let Some(id) = decode_chain_id(&chain.chain_id) else {
+                continue;
+            };
+            let identifier = desired
+                .remotes
+                .iter()
+                .find(|(remote, _)| *remote == id)
+                .map(|(_, identifier)| identifier);
+            observed.chains.insert(
+                *id.as_bytes(),
+                RegisteredChain {
+                    current: chain.identifier.clone(),
+                    matches: identifier.is_none_or(|i| *i == chain.identifier),
+                    enabled: chain.enabled,
+                },
+            );
+        }
+        match page.chains.last() {
+            Some(last) if page.chains.len() as u32 == TERRA_PAGE => {
+                start_after = Some(last.chain_id.clone())
+            }
+            _ => break,
+        }
+    }
+
+    let mut registered: HashMap<String, TerraToken> = HashMap::new();
+    let mut start_after: Option<String> = None;
+    loop {
+        let page: TerraTokens = client
+            .query_contract(
+                bridge,
+                &json!({ "tokens": { "start_after": start_after, "limit": TERRA_PAGE } }),
+            )
+            .await?;
+        let last = page.tokens.last().map(|t| t.token.clone());
+        let full = page.tokens.len() as u32 == TERRA_PAGE;
+        registered.extend(page.tokens.into_iter().map(|t| (t.token.clone(), t)));
+        match last {
+            Some(last) if full => start_after = Some(last),
+            _ => break,
+        }
+    }
+
+    for (token, leg) in legs {
+        let key = leg.token.to_string();
+        if let Some(info) = registered.get(&key) {
+            let mode = TokenMode::from_terra(&client.get_token_type(&key).await?);
+            let min_amount = match &info.min_bridge_amount {
+                Some(min) => Some(
+                    min.parse()
+                        .map_err(|e| eyre!("Invalid min_bridge_amount {}: {}", min, e))?,
+                ),
+                None => None,
+            };
+            observed.tokens.insert(
+                key.clone(),
+                RegisteredToken {
+                    mode,
+                    decimals: Some(info.terra_decimals),
+                    enabled: info.enabled,
+                    min_amount,
+                },
+            );
+        } else {
+            continue;
+        }
+        for remote in token.legs.iter().filter(|l| l.chain != leg.chain) {
+            let chain_b64 = B64.encode(remote.chain.as_bytes());
+            let mapping: Option<TerraDestMapping> = client
+                .query_contract(
+                    bridge,
+                    &json!({ "token_dest_mapping": { "token": key, "dest_chain": chain_b64 } }),
+                )
+                .await?;
+            if let Some(mapping) = mapping {
+                let dest_token = decode_bytes32(&mapping.dest_token)
+                    .ok_or_else(|| eyre!("dest_token of {} is not 32 bytes", key))?;
+                observed.outgoing.insert(
+                    (key.clone(), *remote.chain.as_bytes()),
+                    (dest_token, mapping.dest_decimals),
+                );
+            }
+            let incoming: Option<TerraIncomingMapping> = client
+                .query_contract(
+                    bridge,
+                    &json!({
+                        "incoming_token_mapping": {
+                            "src_chain": chain_b64,
+                            "src_token": B64.encode(leg.token_bytes32),
+                        }
+                    }),
+                )
+                .await?;
+            if let Some(incoming) = incoming.filter(|m| m.enabled) {
+                observed.incoming.insert(
+                    (key.clone(), *remote.chain.as_bytes()),
+                    incoming.src_decimals,
+                );
+            }
+        }
+        if leg.rate_limit.is_some() {
+            let limit: Option<TerraRateLimit> = client
+                .query_contract(bridge, &json!({ "rate_limit": { "token": key } }))
+                .await?;
+            if let Some(limit) = limit {
+                let amount = |s: &str| {
+                    s.parse::<u128>()
+                        .map_err(|e| eyre!("Invalid rate limit {}: {}", s, e))
+                };
+                observed.rate_limits.insert(
+                    key,
+                    RateLimit {
+                        min_per_transaction: 0,
+                        max_per_transaction: amount(&limit.max_per_transaction)?,
+                        max_per_period: amount(&limit.max_per_period)?,
+                    },
+                );
+            }
+        }
+    }
+
+    if desired.fee_bps.is_some() {
+        let fee: TerraFeeConfig = client
+            .query_contract(bridge, &json!({ "fee_config": {} }))
+            .await?;
+        observed.fee = Some(CurrentFee {
+            standard_fee_bps: fee.standard_fee_bps,
+            evm_rest: None,
+        });
+    }
+    Ok(observed)
+}
+
+async fn observe_solana(
+    client: &Arc<RpcClient>,
+    program_id: Pubkey,
+    desired: &Desired,
+    legs: &[(&Token, &Leg)],
+) -> Result<(Target, Observed)> {
+    let config = blocking(client, move |c| {
+        let account = c.get_account(&bridge_config_pda(&program_id))?;
+        parse_bridge_config(&account.data)
+    })
+    .await?;
+    let mut observed = Observed::default();
+
+    let entries = solana_accounts(
+        client,
+        desired
+            .remotes
+            .iter()
+            .map(|(remote, _)| chain_entry_pda(&program_id, remote.as_bytes()))
+            .collect(),
+    )
+    .await?;
+    for ((remote, identifier), account) in desired.remotes.iter().zip(entries) {
+        if let Some(account) = account {
+            let (_, current) = parse_chain_entry(&account.data)?;
+            observed.chains.insert(
+                *remote.as_bytes(),
+                RegisteredChain {
+                    matches: current == *identifier,
+                    current,
+                    enabled: true,
+                },
+            );
+        }
+    }
+
+    let routes: Vec<([u8; 4], [u8; 32])> = legs
+        .iter()
+        .flat_map(|(token, leg)| {
+            token
+                .legs
+                .iter()
+                .filter(|l| l.chain != leg.chain)
+                .map(|r| (*r.chain.as_bytes(), r.token_bytes32))
+        })
+        .collect();
+    let mappings = solana_accounts(
+        client,
+        routes
+            .iter()
+            .map(|(chain, token)| token_mapping_pda(&program_id, chain, token))
+            .collect(),
+    )
+    .await?;
+    for (route, account) in routes.into_iter().zip(mappings) {
+        if let Some(account) = account {
+            observed
+                .mappings
+                .insert(route, parse_token_mapping(&account.data)?);
+        }
+    }
+
+    let limited: Vec<(String, Pubkey)> = legs
+        .iter()
+        .filter(|(_, leg)| leg.rate_limit.is_some())
+        .filter_map(|(_, leg)| match leg.token {
+            LegToken::Solana(mint) => Some((leg.token.to_string(), mint)),
+            _ => None,
+        })
+        .collect();
+    let limits = solana_accounts(
+        client,
+        limited
+            .iter()
+            .map(|(_, mint)| withdraw_rate_limit_pda(&program_id, mint))
+            .collect(),
+    )
+    .await?;
+    for ((key, _), account) in limited.into_iter().zip(limits) {
+        let Some(account) = account else {
+            continue;
+        };
+        let limit = parse_withdraw_rate_limit(&account.data)?;
+        // Implicit limits follow the mint supply; only explicit ones are compared
+        if limit.explicit_config {
+            observed.rate_limits.insert(
+                key,
+                RateLimit {
+                    min_per_transaction: limit.min_per_transaction,
+                    max_per_transaction: limit.max_per_transaction,
+                    max_per_period: limit.max_per_period,
+                },
+            );
+        }
+    }
+
+    observed.fee = Some(CurrentFee {
+        standard_fee_bps: config.fee_bps as u64,
+        evm_rest: None,
+    });
+    Ok((
+        Target::Solana {
+            program_id,
+            admin: Pubkey::new_from_array(config.admin),
+        },
+        observed,
+    ))
+}
+
+async fn solana_accounts(
+    client: &Arc<RpcClient>,
+    keys: Vec<Pubkey>,
+) -> Result<Vec<Option<Account>>> {
+    blocking(client, move |c| {
+        let mut accounts = Vec::with_capacity(keys.len());
+        for chunk in keys.chunks(SOLANA_ACCOUNTS_PER_REQUEST) {
+            accounts.extend(c.get_multiple_accounts(chunk)?);
+        }
+        Ok(accounts)
+    })
+    .await
+}
+
+async fn send_solana(
+    client: &Arc<RpcClient>,
+    keypair: &Keypair,
+    ix: Instruction,
+) -> Result<String> {
+    if !ix
+        .accounts
+        .iter()
+        .any(|a| a.is_signer && a.pubkey == keypair.pubkey())
+    {
+        return Err(eyre!(
+            "Solana keypair {} is not the bridge admin",
+            keypair.pubkey()
+        ));
+    }
+    let keypair = Arc::new(keypair.insecure_clone());
+    blocking(client, move |c| {
+        let blockhash = c.get_latest_blockhash()?;
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&keypair.pubkey()),
+            &[keypair.as_ref()],
+            blockhash,
+        );
+        Ok(c.send_and_confirm_transaction(&tx)?.to_string())
+    })
+    .await
+}
+
+fn u256_to_u128(v: U256) -> Result<u128> {
+    u128::try_from(v).map_err(|_| eyre!("value {} exceeds u128", v))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EVM_TOKEN: &str = "0x5FbDB2315678afecb367f032d93F642f64180aa3";
+    const MINT: &str = "So11111111111111111111111111111111111111112";
+
+    fn chains() -> Vec<(ChainId, ChainKind)> {
+        vec![
+            (ChainId::from_u32(1), ChainKind::Evm),
+            (ChainId::from_u32(2), ChainKind::Terra),
+            (ChainId::from_u32(3), ChainKind::Solana),
+        ]
+    }
+
+    fn tokens() -> Vec<Token> {
+        let config = TopologyConfig::from_json(&format!(
+            r#"{{
+                "chains": [],
+                "tokens": [{{
+                    "name": "LUNC",
+                    "legs": [
+                        {{"chain": "0x00000001", "token": "{}", "decimals": 18, "mode": "mint_burn",
+                          "rate_limit": {{"max_per_transaction": "1000", "max_per_period": "5000"}}}},
+                        {{"chain": "2", "token": "uluna", "decimals": 6, "mode": "lock_unlock",
+                          "rate_limit": {{"min_per_transaction": "10", "max_per_transaction": "1000", "max_per_period": "5000"}}}},
+                        {{"chain": "3", "token": "{}", "decimals": 9, "mode": "mint_burn"}}
+                    ]
+                }}]
+            }}"#,
+            EVM_TOKEN, MINT
+        ))
+        .unwrap();
+        resolve_tokens(&config.tokens, &chains()).unwrap()
+    }
+
+    fn desired(chain: u32, fee_bps: Option<u64>) -> Desired {
+        let identifiers = [(1, "evm_56"), (2, "terraclassic_columbus-5"), (3, "solana")];
+        Desired {
+            chain: ChainId::from_u32(chain),
+            remotes: identifiers
+                .iter()
+                .filter(|(id, _)| *id != chain)
+                .map(|(id, identifier)| (ChainId::from_u32(*id), identifier.to_string()))
+                .collect(),
+            fee_bps,
+        }
+    }
+
+    fn evm_target() -> Target {
+        Target::Evm {
+            bridge: Address::repeat_byte(1),
+            chain_registry: Address::repeat_byte(2),
+            token_registry: Address::repeat_byte(3),
+        }
+    }
+
+    fn subjects(changes: &[PlannedChange]) -> Vec<(ChangeKind, &str)> {
+        changes
+            .iter()
+            .map(|c| (c.change, c.subject.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_empty_evm_bridge_plans_everything_in_order() {
+        let tokens = tokens();
+        let changes = diff(
+            &desired(1, None),
+            &evm_target(),
+            &Observed::default(),
+            &tokens,
+        );
+        let token = format!("token LUNC ({})", EVM_TOKEN);
+        assert_eq!(
+            subjects(&changes),
+            vec![
+                (ChangeKind::Add, "chain 0x00000002"),
+                (ChangeKind::Add, "chain 0x00000003"),
+                (ChangeKind::Add, token.as_str()),
+                (ChangeKind::Add, "LUNC -> 0x00000002 destination"),
+                (ChangeKind::Add, "LUNC <- 0x00000002 source decimals"),
+                (ChangeKind::Add, "LUNC -> 0x00000003 destination"),
+                (ChangeKind::Add, "LUNC <- 0x00000003 source decimals"),
+                (ChangeKind::Add, "LUNC rate limit"),
+            ]
+        );
+
+        let Some(AdminAction::Evm { to, data }) = &changes[2].action else {
+            panic!("expected an EVM action");
+        };
+        assert_eq!(*to, Address::repeat_byte(3));
+        let call = TokenRegistry::registerTokenCall::abi_decode(data, true).unwrap();
+        assert_eq!(call.tokenType, 1);
+
+        let Some(AdminAction::Evm { data, .. }) = &changes[4].action else {
+            panic!("expected an EVM action");
+        };
+        let call = TokenRegistry::setIncomingTokenMappingCall::abi_decode(data, true).unwrap();
+        assert_eq!(call.srcChain.0, [0, 0, 0, 2]);
+        assert_eq!(call.srcDecimals, 6);
+    }
+
+    #[test]
+    fn test_matching_state_plans_nothing_and_drift_is_updated() {
+        let tokens = tokens();
+        let key = EVM_TOKEN.parse::<Address>().unwrap().to_string();
+        let mut observed = Observed::default();
+        for (id, identifier) in [(2u32, "terraclassic_columbus-5"), (3, "solana")] {
+            observed.chains.insert(
+                *ChainId::from_u32(id).as_bytes(),
+                RegisteredChain {
+                    current: identifier.into(),
+                    matches: true,
+                    enabled: true,
+                },
+            );
+        }
+        observed.tokens.insert(
+            key.clone(),
+            RegisteredToken {
+                mode: TokenMode::MintBurn,
+                decimals: None,
+                enabled: true,
+                min_amount: None,
+            },
+        );
+        let legs = &tokens[0].legs;
+        for remote in &legs[1..] {
+            let route = (key.clone(), *remote.chain.as_bytes());
+            observed
+                .outgoing
+                .insert(route.clone(), (remote.token_bytes32, remote.decimals));
+            observed.incoming.insert(route, remote.decimals);
+        }
+        observed
+            .rate_limits
+            .insert(key.clone(), legs[0].rate_limit.unwrap());
+        observed.fee = Some(CurrentFee {
+            standard_fee_bps: 30,
+            evm_rest: Some((U256::from(10), U256::ZERO, Address::ZERO, Address::ZERO)),
+        });
+        assert!(diff(&desired(1, Some(30)), &evm_target(), &observed, &tokens).is_empty());
+
+        // The drift behind AdminFixPendingDecimals: incoming decimals set to the local ones
+        observed.incoming.insert((key.clone(), [0, 0, 0, 2]), 18);
+        let changes = diff(&desired(1, Some(25)), &evm_target(), &observed, &tokens);
+        assert_eq!(
+            subjects(&changes),
+            vec![
+                (ChangeKind::Update, "LUNC <- 0x00000002 source decimals"),
+                (ChangeKind::Update, "standard fee"),
+            ]
+        );
+        assert_eq!(changes[0].current.as_deref(), Some("18"));
+        assert_eq!(changes[0].desired, "6");
+        let Some(AdminAction::Evm { data, .. }) = &changes[1].action else {
+            panic!("expected an EVM action");
+        };
+        let call = Bridge::setFeeParamsCall::abi_decode(data, true).unwrap();
+        assert_eq!(call.standardFeeBps, U256::from(25));
+        assert_eq!(call.discountedFeeBps, U256::from(10));
+    }
+
+    #[test]
+    fn test_terra_messages_and_conflicts() {
+        let tokens = tokens();
+        let mut observed = Observed::default();
+        observed.chains.insert(
+            [0, 0, 0, 1],
+            RegisteredChain {
+                current: "evm_97".into(),
+                matches: false,
+                enabled: true,
+            },
+        );
+        observed.chains.insert(
+            [0, 0, 0, 3],
+            RegisteredChain {
+                current: "solana".into(),
+                matches: true,
+                enabled: false,
+            },
+        );
+        observed.tokens.insert(
+            "uluna".into(),
+            RegisteredToken {
+                mode: TokenMode::LockUnlock,
+                decimals: Some(8),
+                enabled: true,
+                min_amount: Some(1),
+            },
+        );
+        let changes = diff(&desired(2, None), &Target::Terra, &observed, &tokens);
+        let kinds: Vec<_> = changes.iter().map(|c| c.change).collect();
+        assert_eq!(
+            kinds[..3],
+            [
+                ChangeKind::Conflict,
+                ChangeKind::Update,
+                ChangeKind::Conflict
+            ]
+        );
+        assert!(changes[0].action.is_none());
+        assert_eq!(
+            changes[1].action,
+            Some(terra(json!({
+                "update_chain": { "chain_id": "AAAAAw==", "enabled": true }
+            })))
+        );
+
+        let incoming = changes
+            .iter()
+            .find(|c| c.subject == "LUNC <- 0x00000001 source decimals")
+            .unwrap();
+        let Some(AdminAction::Terra { msg }) = &incoming.action else {
+            panic!("expected a Terra action");
+        };
+        let msg = &msg["set_incoming_token_mapping"];
+        assert_eq!(msg["local_token"], "uluna");
+        assert_eq!(msg["src_decimals"], 18);
+        assert_eq!(
+            msg["src_token"],
+            B64.encode(encode_terra_token_address("uluna"))
+        );
+
+        let minimum = changes.last().unwrap();
+        assert_eq!(minimum.subject, "LUNC rate limit minimum");
+        assert_eq!(minimum.current.as_deref(), Some("1"));
+        assert_eq!(minimum.desired, "10");
+    }
+
+    #[test]
+    fn test_solana_mapping_is_registered_once_and_never_updated() {
+        let tokens = tokens();
+        let program_id = Pubkey::new_unique();
+        let admin = Pubkey::new_unique();
+        let target = Target::Solana { program_id, admin };
+        let changes = diff(&desired(3, None), &target, &Observed::default(), &tokens);
+        let registrations: Vec<_> = changes
+            .iter()
+            .filter_map(|c| match &c.action {
+                Some(AdminAction::Solana { instruction })
+                    if instruction.data[..8] == discriminator("register_token")[..] =>
+                {
+                    Some(instruction)
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(registrations.len(), 2);
+        let ix = registrations[0];
+        assert_eq!(ix.data.len(), 8 + 32 + 4 + 32 + 3);
+        assert_eq!(&ix.data[8..40], MINT.parse::<Pubkey>().unwrap().as_ref());
+        assert_eq!(ix.data[76..], [1, 9, 18]);
+        assert!(ix.accounts[3].is_signer && ix.accounts[3].pubkey == admin);
+
+        let legs = &tokens[0].legs;
+        let mut observed = Observed::default();
+        observed.mappings.insert(
+            ([0, 0, 0, 1], legs[0].token_bytes32),
+            SolanaTokenMapping {
+                local_mint: MINT.parse::<Pubkey>().unwrap().to_bytes(),
+                dest_chain: [0, 0, 0, 1],
+                dest_token: legs[0].token_bytes32,
+                mint_burn: true,
+                decimals: 9,
+                src_decimals: 9,
+            },
+        );
+        let changes = diff(&desired(3, None), &target, &observed, &tokens);
+        let mapping = changes
+            .iter()
+            .find(|c| c.subject == "LUNC <-> 0x00000001 mapping")
+            .unwrap();
+        assert_eq!(mapping.change, ChangeKind::Conflict);
+        assert!(mapping.action.is_none());
+    }
+
+    #[test]
+    fn test_solana_admin_instruction_layouts() {
+        let program_id = Pubkey::new_unique();
+        let admin = Pubkey::new_unique();
+        let ix = register_chain_ix(&program_id, &admin, ChainId::from_u32(56), "evm_56");
+        assert_eq!(ix.data[8..12], [0, 0, 0, 56]);
+        assert_eq!(ix.data[12..16], 6u32.to_le_bytes());
+        assert_eq!(&ix.data[16..], b"evm_56");
+
+        let ix = set_fee_bps_ix(&program_id, &admin, 25);
+        assert_eq!(ix.data[8..], [0, 0, 1, 25, 0, 0, 0]);
+
+        let limit = RateLimit {
+            min_per_transaction: 1,
+            max_per_transaction: 2,
+            max_per_period: 3,
+        };
+        let ix = set_rate_limit_ix(&program_id, &admin, &Pubkey::default(), &limit);
+        assert_eq!(ix.data.len(), 8 + 32 + 48);
+        assert_eq!(ix.data[40..56], 1u128.to_le_bytes());
+        assert_eq!(ix.data[72..88], 3u128.to_le_bytes());
+    }
+
+    #[test]
+    fn test_config_rejects_unknown_chains_and_duplicate_legs() {
+        let config = |legs: &str| {
+            TopologyConfig::from_json(&format!(
+                r#"{{"chains": [], "tokens": [{{"name": "T", "legs": [{}]}}]}}"#,
+                legs
+            ))
+            .unwrap()
+        };
+        let leg = |chain: &str| {
+            format!(
+                r#"{{"chain": "{}", "token": "uluna", "decimals": 6, "mode": "lock_unlock"}}"#,
+                chain
+            )
+        };
+        assert!(resolve_tokens(&config(&leg("9")).tokens, &chains()).is_err());
+        assert!(resolve_tokens(
+            &config(&format!("{},{}", leg("2"), leg("2"))).tokens,
+            &chains()
+        )
+        .is_err());
+
+        let parsed = TopologyConfig::from_json(
+            r#"{"chains": [{"kind": "terra", "chain": "2", "identifier": "terraclassic_columbus-5",
+                "lcd_url": "http://localhost:1317", "bridge": "terra1bridge", "fee_bps": 30}],
+                "tokens": []}"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            parsed.chains[0].endpoint,
+            TopologyEndpoint::Terra {
+                cosmos_chain_id: None,
+                ..
+            }
+        ));
+        assert_eq!(parsed.chains[0].fee_bps, Some(30));
+    }
+}
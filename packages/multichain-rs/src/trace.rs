@@ -0,0 +1,1313 @@
+//! Transfer Tracing
+//!
+//! Follows one transfer from its source deposit to the destination withdrawal, so a support
+//! question ("where is my transfer?") can be answered without stitching explorer links
+//! together. Backs the `cl8y-trace` binary.
+//!
+//! 1. Locate the deposit by source tx, xchain hash ID, or (source chain, nonce)
+//! 2. Recompute the xchain hash ID from the deposit fields ([`compute_xchain_hash_id`]) and
+//!    compare it with the hash the source chain recorded
+//! 3. Read the destination's pending withdrawal: submission, approval, cancel and execution
+//!    status with timestamps, and what remains of the cancel window
+//!
+//! Every configured bridge is identified by the 4-byte chain ID it reports on-chain, so the
+//! destination is picked from the deposit's `dest_chain` (or, for a bare hash, by asking
+//! every bridge for the pending withdrawal).
+
+use std::fmt;
+use std::sync::Arc;
+
+use alloy::primitives::{Address, B256};
+use alloy::providers::Provider;
+use alloy::rpc::types::{BlockNumberOrTag, BlockTransactionsKind, Filter, Log};
+use alloy::sol_types::SolEvent;
+use eyre::{eyre, Result, WrapErr};
+use serde_json::{json, Value};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_transaction_status::option_serializer::OptionSerializer;
+
+use crate::chains::{blocking, decode_bytes32, decode_chain_id, now_secs, Backend, TraceChain};
+pub use crate::chains::{parse_chain_id, ChainKind, TraceEndpoint};
+use crate::evm::contracts::Bridge;
+use crate::evm::watcher::parse_deposit_log;
+use crate::evm::EvmQueryClient;
+use crate::hash::{bytes32_to_hex, compute_xchain_hash_id};
+use crate::solana::{
+    bridge_config_pda, chain_withdraw_delay_pda, deposit_record_pda, effective_withdraw_delay,
+    executed_hash_pda, get_transaction, large_transfer_delay_pda, parse_anchor_events,
+    parse_bridge_config, parse_chain_withdraw_delay, parse_deposit_record,
+    parse_large_transfer_delay, parse_pending_withdraw, pending_withdraw_pda, SolanaEvent,
+};
+use crate::terra::contracts::DepositInfoResponse;
+use crate::terra::events::{TxEvent, WasmEvent};
+use crate::terra::TerraQueryClient;
+use crate::types::ChainId;
+
+/// Default EVM history searched for deposits by nonce and for withdraw events
+pub const DEFAULT_EVM_LOOKBACK_BLOCKS: u64 = 200_000;
+
+/// Block range per `eth_getLogs` request
+pub const DEFAULT_EVM_LOG_CHUNK: u64 = 10_000;
+
+// ============================================================================
+// Inputs
+// ============================================================================
+
+/// What to trace
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TraceTarget {
+    /// Source transaction: EVM tx hash (`0x…`), Terra tx hash (hex) or Solana signature
+    SourceTx(String),
+    /// Cross-chain hash ID of the transfer
+    XchainHashId([u8; 32]),
+    /// Deposit nonce on the source chain
+    Nonce { src_chain: ChainId, nonce: u64 },
+}
+
+// ============================================================================
+// Results
+// ============================================================================
+
+/// The source-chain deposit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TracedDeposit {
+    pub src_chain: ChainId,
+    pub src_kind: ChainKind,
+    pub dest_chain: ChainId,
+    pub src_account: [u8; 32],
+    pub dest_account: [u8; 32],
+    /// Destination token hashed into the xchain hash ID (`None` if the mapping is unknown)
+    pub dest_token: Option<[u8; 32]>,
+    /// Source token as the deposit event names it (EVM address, Terra denom or CW20)
+    pub src_token: Option<String>,
+    /// Net amount after the deposit fee
+    pub amount: u128,
+    pub fee: Option<u128>,
+    pub nonce: u64,
+    /// Source transaction, when the deposit was found through it
+    pub tx: Option<String>,
+    /// Unix seconds
+    pub timestamp: Option<u64>,
+    /// Hash the source chain emitted or stored for this deposit
+    pub reported_hash: Option<[u8; 32]>,
+}
+
+impl TracedDeposit {
+    /// xchain hash ID recomputed from the deposit fields
+    pub fn computed_hash(&self) -> Option<[u8; 32]> {
+        self.dest_token.map(|token| {
+            compute_xchain_hash_id(
+                self.src_chain.as_bytes(),
+                self.dest_chain.as_bytes(),
+                &self.src_account,
+                &self.dest_account,
+                &token,
+                self.amount,
+                self.nonce,
+            )
+        })
+    }
+}
+
+/// A destination-chain withdraw event (EVM only; other chains keep no per-event history)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceEvent {
+    /// Event name, e.g. `WithdrawApprove`
+    pub name: String,
+    pub tx: String,
+    pub block: u64,
+    /// Unix seconds
+    pub timestamp: Option<u64>,
+}
+
+/// The destination-chain pending withdrawal
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TracedWithdraw {
+    pub chain: ChainId,
+    pub kind: ChainKind,
+    pub src_chain: Option<ChainId>,
+    pub src_account: Option<[u8; 32]>,
+    pub nonce: Option<u64>,
+    /// Amount in destination-token units
+    pub amount: Option<u128>,
+    pub recipient: Option<String>,
+    /// Unix seconds (`None` where the chain does not record it)
+    pub submitted_at: Option<u64>,
+    pub approved: bool,
+    pub approved_at: Option<u64>,
+    pub cancelled: bool,
+    pub executed: bool,
+    /// Cancel window applied to this withdrawal, in seconds
+    pub cancel_window: Option<u64>,
+    /// Unix seconds when an approved withdrawal becomes executable
+    pub executable_at: Option<u64>,
+    pub events: Vec<TraceEvent>,
+}
+
+/// Where a transfer currently stands
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransferStatus {
+    /// No deposit and no pending withdrawal found
+    NotFound,
+    /// Deposited; the withdrawal has not been submitted on the destination yet
+    AwaitingSubmit,
+    /// Submitted on the destination; waiting for operator approval
+    AwaitingApproval,
+    /// Approved; cancelers can still cancel for `remaining` seconds
+    InCancelWindow {
+        remaining: u64,
+    },
+    /// Approved and past the cancel window; the recipient can execute
+    Executable,
+    Cancelled,
+    Executed,
+}
+
+impl fmt::Display for TransferStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TransferStatus::NotFound => f.write_str("not found"),
+            TransferStatus::AwaitingSubmit => {
+                f.write_str("deposited, withdrawal not yet submitted on the destination")
+            }
+            TransferStatus::AwaitingApproval => {
+                f.write_str("submitted, awaiting operator approval")
+            }
+            TransferStatus::InCancelWindow { remaining } => write!(
+                f,
+                "approved, in cancel window ({} remaining)",
+                format_duration(*remaining)
+            ),
+            TransferStatus::Executable => f.write_str("approved, executable"),
+            TransferStatus::Cancelled => f.write_str("cancelled"),
+            TransferStatus::Executed => f.write_str("executed"),
+        }
+    }
+}
+
+/// Everything known about one transfer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransferTrace {
+    /// Hash used to look up the destination: the requested hash, else the recomputed one,
+    /// else the one the source chain reported
+    pub xchain_hash_id: Option<[u8; 32]>,
+    pub deposit: Option<TracedDeposit>,
+    pub withdraw: Option<TracedWithdraw>,
+    /// Anything that could not be determined or did not line up
+    pub notes: Vec<String>,
+    /// Unix seconds at which the trace was taken
+    pub traced_at: u64,
+}
+
+impl TransferTrace {
+    /// Whether the recomputed hash equals the hash the source reported (or was asked for)
+    pub fn hash_matches(&self) -> Option<bool> {
+        let computed = self.deposit.as_ref()?.computed_hash()?;
+        let expected = self
+            .deposit
+            .as_ref()
+            .and_then(|d| d.reported_hash)
+            .or(self.xchain_hash_id)?;
+        Some(computed == expected)
+    }
+
+    /// Seconds left in the cancel window of an approved, live withdrawal
+    pub fn cancel_window_remaining(&self) -> Option<u64> {
+        let w = self.withdraw.as_ref()?;
+        if !w.approved || w.cancelled || w.executed {
+            return None;
+        }
+        Some(w.executable_at?.saturating_sub(self.traced_at))
+    }
+
+    pub fn status(&self) -> TransferStatus {
+        match &self.withdraw {
+            Some(w) if w.executed => TransferStatus::Executed,
+            Some(w) if w.cancelled => TransferStatus::Cancelled,
+            Some(w) if w.approved => match self.cancel_window_remaining() {
+                Some(remaining) if remaining > 0 => TransferStatus::InCancelWindow { remaining },
+                _ => TransferStatus::Executable,
+            },
+            Some(_) => TransferStatus::AwaitingApproval,
+            None if self.deposit.is_some() => TransferStatus::AwaitingSubmit,
+            None => TransferStatus::NotFound,
+        }
+    }
+
+    /// Machine-readable form of the trace (`cl8y-trace --json`)
+    pub fn to_json(&self) -> Value {
+        let hex = |h: &[u8; 32]| bytes32_to_hex(h);
+        let deposit = self.deposit.as_ref().map(|d| {
+            json!({
+                "src_chain": d.src_chain.to_hex(),
+                "src_kind": d.src_kind.to_string(),
+                "dest_chain": d.dest_chain.to_hex(),
+                "src_account": hex(&d.src_account),
+                "dest_account": hex(&d.dest_account),
+                "dest_token": d.dest_token.as_ref().map(hex),
+                "src_token": d.src_token,
+                "amount": d.amount.to_string(),
+                "fee": d.fee.map(|f| f.to_string()),
+                "nonce": d.nonce,
+                "tx": d.tx,
+                "timestamp": d.timestamp,
+                "reported_hash": d.reported_hash.as_ref().map(hex),
+                "computed_hash": d.computed_hash().as_ref().map(hex),
+            })
+        });
+        let withdraw = self.withdraw.as_ref().map(|w| {
+            json!({
+                "chain": w.chain.to_hex(),
+                "kind": w.kind.to_string(),
+                "src_chain": w.src_chain.map(|c| c.to_hex()),
+                "src_account": w.src_account.as_ref().map(hex),
+                "nonce": w.nonce,
+                "amount": w.amount.map(|a| a.to_string()),
+                "recipient": w.recipient,
+                "submitted_at": w.submitted_at,
+                "approved": w.approved,
+                "approved_at": w.approved_at,
+                "cancelled": w.cancelled,
+                "executed": w.executed,
+                "cancel_window": w.cancel_window,
+                "executable_at": w.executable_at,
+                "events": w.events.iter().map(|e| json!({
+                    "name": e.name,
+                    "tx": e.tx,
+                    "block": e.block,
+                    "timestamp": e.timestamp,
+                })).collect::<Vec<_>>(),
+            })
+        });
+        json!({
+            "xchain_hash_id": self.xchain_hash_id.as_ref().map(hex),
+            "status": self.status().to_string(),
+            "hash_matches": self.hash_matches(),
+            "cancel_window_remaining": self.cancel_window_remaining(),
+            "deposit": deposit,
+            "withdraw": withdraw,
+            "notes": self.notes,
+            "traced_at": self.traced_at,
+        })
+    }
+}
+
+impl fmt::Display for TransferTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hash = self
+            .xchain_hash_id
+            .as_ref()
+            .map(bytes32_to_hex)
+            .unwrap_or_else(|| "unknown".to_string());
+        writeln!(f, "Transfer      {}", hash)?;
+        writeln!(f, "Status        {}", self.status())?;
+
+        match &self.deposit {
+            Some(d) => {
+                writeln!(f)?;
+                writeln!(
+                    f,
+                    "Source        {} {} -> {}",
+                    d.src_kind,
+                    d.src_chain.to_hex(),
+                    d.dest_chain.to_hex()
+                )?;
+                if let Some(tx) = &d.tx {
+                    writeln!(f, "  tx          {}", tx)?;
+                }
+                writeln!(f, "  deposited   {}", format_time(d.timestamp))?;
+                writeln!(f, "  nonce       {}", d.nonce)?;
+                match d.fee {
+                    Some(fee) => writeln!(f, "  amount      {} (fee {})", d.amount, fee)?,
+                    None => writeln!(f, "  amount      {}", d.amount)?,
+                }
+                if let Some(token) = &d.src_token {
+                    writeln!(f, "  token       {}", token)?;
+                }
+                writeln!(f, "  src account {}", bytes32_to_hex(&d.src_account))?;
+                writeln!(f, "  dest account {}", bytes32_to_hex(&d.dest_account))?;
+                match &d.dest_token {
+                    Some(t) => writeln!(f, "  dest token  {}", bytes32_to_hex(t))?,
+                    None => writeln!(f, "  dest token  unknown")?,
+                }
+                match (d.computed_hash(), self.hash_matches()) {
+                    (Some(h), Some(true)) => writeln!(
+                        f,
+                        "  hash        {} (recomputed, matches)",
+                        bytes32_to_hex(&h)
+                    )?,
+                    (Some(h), Some(false)) => writeln!(
+                        f,
+                        "  hash        {} (recomputed, MISMATCH with {})",
+                        bytes32_to_hex(&h),
+                        d.reported_hash
+                            .or(self.xchain_hash_id)
+                            .as_ref()
+                            .map(bytes32_to_hex)
+                            .unwrap_or_default()
+                    )?,
+                    (Some(h), None) => {
+                        writeln!(f, "  hash        {} (recomputed)", bytes32_to_hex(&h))?
+                    }
+                    (None, _) => writeln!(f, "  hash        not recomputable")?,
+                }
+            }
+            None => {
+                writeln!(f)?;
+                writeln!(f, "Source        deposit not found")?;
+            }
+        }
+
+        writeln!(f)?;
+        match &self.withdraw {
+            Some(w) => {
+                writeln!(f, "Destination   {} {}", w.kind, w.chain.to_hex())?;
+                if let Some(r) = &w.recipient {
+                    writeln!(f, "  recipient   {}", r)?;
+                }
+                if let Some(a) = w.amount {
+                    writeln!(f, "  amount      {}", a)?;
+                }
+                writeln!(f, "  submitted   {}", format_time(w.submitted_at))?;
+                let approved = if w.approved {
+                    format_time(w.approved_at)
+                } else {
+                    "no".to_string()
+                };
+                writeln!(f, "  approved    {}", approved)?;
+                writeln!(
+                    f,
+                    "  cancelled   {}",
+                    yes_no(w.cancelled, &w.events, "WithdrawCancel")
+                )?;
+                writeln!(
+                    f,
+                    "  executed    {}",
+                    yes_no(w.executed, &w.events, "WithdrawExecute")
+                )?;
+                if let Some(window) = w.cancel_window {
+                    match self.cancel_window_remaining() {
+                        Some(r) => writeln!(
+                            f,
+                            "  cancel window {} ({} remaining, executable {})",
+                            format_duration(window),
+                            format_duration(r),
+                            format_time(w.executable_at)
+                        )?,
+                        None => writeln!(f, "  cancel window {}", format_duration(window))?,
+                    }
+                }
+                for e in &w.events {
+                    writeln!(
+                        f,
+                        "  event       {:<16} block {:<10} {}  {}",
+                        e.name,
+                        e.block,
+                        format_time(e.timestamp),
+                        e.tx
+                    )?;
+                }
+            }
+            None => writeln!(f, "Destination   no pending withdrawal found")?,
+        }
+
+        for note in &self.notes {
+            writeln!(f, "note: {}", note)?;
+        }
+        Ok(())
+    }
+}
+
+fn yes_no(flag: bool, events: &[TraceEvent], name: &str) -> String {
+    if !flag {
+        return "no".to_string();
+    }
+    match events.iter().rev().find(|e| e.name == name) {
+        Some(e) => format_time(e.timestamp),
+        None => "yes".to_string(),
+    }
+}
+
+/// `2026-01-02T03:04:05Z`, or `-` when unknown
+pub fn format_time(unix_secs: Option<u64>) -> String {
+    unix_secs
+        .filter(|t| *t > 0)
+        .and_then(|t| tendermint::Time::from_unix_timestamp(t as i64, 0).ok())
+        .map(|t| t.to_rfc3339())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/// `1h 02m 03s`
+pub fn format_duration(secs: u64) -> String {
+    let (h, m, s) = (secs / 3600, (secs / 60) % 60, secs % 60);
+    if h > 0 {
+        format!("{}h {:02}m {:02}s", h, m, s)
+    } else if m > 0 {
+        format!("{}m {:02}s", m, s)
+    } else {
+        format!("{}s", s)
+    }
+}
+
+// ============================================================================
+// Tracer
+// ============================================================================
+
+/// Reads deposits and withdrawals across the configured bridges
+pub struct Tracer {
+    chains: Vec<TraceChain>,
+    evm_lookback_blocks: u64,
+    evm_log_chunk: u64,
+}
+
+impl Tracer {
+    /// Connect to every endpoint and resolve the chain ID each bridge reports
+    pub async fn connect(endpoints: &[TraceEndpoint]) -> Result<Self> {
+        let mut chains = Vec::with_capacity(endpoints.len());
+        for endpoint in endpoints {
+            chains.push(TraceChain::connect(endpoint).await?);
+        }
+        Ok(Self {
+            chains,
+            evm_lookback_blocks: DEFAULT_EVM_LOOKBACK_BLOCKS,
+            evm_log_chunk: DEFAULT_EVM_LOG_CHUNK,
+        })
+    }
+
+    /// EVM history searched for deposits by nonce and withdraw events
+    pub fn with_evm_lookback(mut self, blocks: u64, chunk: u64) -> Self {
+        self.evm_lookback_blocks = blocks;
+        self.evm_log_chunk = chunk.max(1);
+        self
+    }
+
+    /// Chain ID and family of every configured bridge
+    pub fn chains(&self) -> Vec<(ChainId, ChainKind)> {
+        self.chains.iter().map(|c| (c.id, c.kind())).collect()
+    }
+
+    fn chain(&self, id: &ChainId) -> Option<&TraceChain> {
+        self.chains.iter().find(|c| c.id == *id)
+    }
+
+    /// Trace a transfer
+    pub async fn trace(&self, target: &TraceTarget) -> Result<TransferTrace> {
+        let mut notes = Vec::new();
+        let deposit = match target {
+            TraceTarget::SourceTx(tx) => Some(self.deposit_from_tx(tx, &mut notes).await?),
+            TraceTarget::XchainHashId(hash) => self.deposit_by_hash(hash, &mut notes).await?,
+            TraceTarget::Nonce { src_chain, nonce } => {
+                self.deposit_by_nonce(src_chain, *nonce, &mut notes).await?
+            }
+        };
+
+        let xchain_hash_id = match target {
+            TraceTarget::XchainHashId(hash) => Some(*hash),
+            _ => deposit
+                .as_ref()
+                .and_then(|d| d.computed_hash().or(d.reported_hash)),
+        };
+
+        let withdraw = match (&xchain_hash_id, &deposit) {
+            (Some(hash), Some(d)) => match self.chain(&d.dest_chain) {
+                Some(chain) => self.withdraw(chain, hash, &mut notes).await?,
+                None => {
+                    notes.push(format!(
+                        "destination chain {} is not configured",
+                        d.dest_chain.to_hex()
+                    ));
+                    None
+                }
+            },
+            (Some(hash), None) => {
+                let mut found = None;
+                for chain in &self.chains {
+                    if let Some(w) = self.withdraw(chain, hash, &mut notes).await? {
+                        found = Some(w);
+                        break;
+                    }
+                }
+                found
+            }
+            (None, _) => None,
+        };
+
+        if let (Some(d), Some(w)) = (&deposit, &withdraw) {
+            check_withdraw_against_deposit(d, w, &mut notes);
+        }
+        if let (Some(d), Some(hash)) = (&deposit, &xchain_hash_id) {
+            if let Some(reported) = d.reported_hash.filter(|r| r != hash) {
+                notes.push(format!(
+                    "source chain recorded hash {}, destination looked up by {}",
+                    bytes32_to_hex(&reported),
+                    bytes32_to_hex(hash)
+                ));
+            }
+        }
+
+        Ok(TransferTrace {
+            xchain_hash_id,
+            deposit,
+            withdraw,
+            notes,
+            traced_at: now_secs(),
+        })
+    }
+
+    // ------------------------------------------------------------------------
+    // Deposit lookup
+    // ------------------------------------------------------------------------
+
+    async fn deposit_from_tx(&self, tx: &str, notes: &mut Vec<String>) -> Result<TracedDeposit> {
+        let tx = tx.trim();
+        let bare = tx.strip_prefix("0x").unwrap_or(tx);
+        let is_hex32 = bare.len() == 64 && bare.chars().all(|c| c.is_ascii_hexdigit());
+
+        if is_hex32 {
+            // EVM hashes are usually 0x-prefixed and Terra hashes are not; try both families
+            let evm_first = tx.starts_with("0x");
+            for pass in [evm_first, !evm_first] {
+                for chain in &self.chains {
+                    let found = match (&chain.backend, pass) {
+                        (Backend::Evm { client, bridge }, true) => {
+                            self.evm_deposit_from_tx(chain.id, client, *bridge, bare, notes)
+                                .await?
+                        }
+                        (Backend::Terra(client), false) => {
+                            terra_deposit_from_tx(chain.id, client, bare, notes).await?
+                        }
+                        _ => None,
+                    };
+                    if let Some(deposit) = found {
+                        return Ok(deposit);
+                    }
+                }
+            }
+            return Err(eyre!(
+                "no bridge deposit found for tx {} on any configured EVM or Terra chain",
+                tx
+            ));
+        }
+
+        let signature: Signature = tx
+            .parse()
+            .map_err(|_| eyre!("{} is neither a 32-byte tx hash nor a Solana signature", tx))?;
+        for chain in &self.chains {
+            if let Backend::Solana { client, program_id } = &chain.backend {
+                if let Some(deposit) =
+                    solana_deposit_from_tx(chain.id, client, *program_id, signature).await?
+                {
+                    return Ok(deposit);
+                }
+            }
+        }
+        Err(eyre!(
+            "no bridge deposit found for signature {} on any configured Solana chain",
+            tx
+        ))
+    }
+
+    async fn evm_deposit_from_tx(
+        &self,
+        chain_id: ChainId,
+        client: &EvmQueryClient,
+        bridge: Address,
+        tx_hex: &str,
+        notes: &mut Vec<String>,
+    ) -> Result<Option<TracedDeposit>> {
+        let tx_hash: B256 = tx_hex
+            .parse()
+            .map_err(|e| eyre!("invalid tx hash: {}", e))?;
+        let logs = client.get_tx_logs(tx_hash).await?;
+        let Some(event) = logs
+            .iter()
+            .filter(|l| l.address() == bridge)
+            .find_map(parse_deposit_log)
+        else {
+            return Ok(None);
+        };
+        let timestamp = evm_block_timestamp(client, event.block_number).await;
+        let dest_token =
+            evm_dest_token(client, event.token.0.into(), event.dest_chain, notes).await;
+        Ok(Some(TracedDeposit {
+            src_chain: chain_id,
+            src_kind: ChainKind::Evm,
+            dest_chain: event.dest_chain,
+            src_account: event.src_account,
+            dest_account: event.dest_account,
+            dest_token,
+            src_token: Some(format!("{}", Address::from(event.token.0))),
+            amount: event.amount,
+            fee: Some(event.fee),
+            nonce: event.nonce,
+            tx: Some(format!("0x{}", hex::encode(event.tx_hash))),
+            timestamp,
+            reported_hash: None,
+        }))
+    }
+
+    async fn deposit_by_hash(
+        &self,
+        hash: &[u8; 32],
+        notes: &mut Vec<String>,
+    ) -> Result<Option<TracedDeposit>> {
+        for chain in &self.chains {
+            match &chain.backend {
+                Backend::Evm { client, .. } => {
+                    let d = client.get_deposit(*hash).await?;
+                    if d.timestamp.is_zero() {
+                        continue;
+                    }
+                    let dest_token = evm_dest_token(client, d.token, d.dest_chain, notes).await;
+                    return Ok(Some(TracedDeposit {
+                        src_chain: chain.id,
+                        src_kind: ChainKind::Evm,
+                        dest_chain: d.dest_chain,
+                        src_account: d.src_account,
+                        dest_account: d.dest_account,
+                        dest_token,
+                        src_token: Some(format!("{}", d.token)),
+                        amount: d.amount.try_into().unwrap_or(u128::MAX),
+                        fee: Some(d.fee.try_into().unwrap_or(u128::MAX)),
+                        nonce: d.nonce,
+                        tx: None,
+                        timestamp: d.timestamp.try_into().ok(),
+                        reported_hash: Some(*hash),
+                    }));
+                }
+                Backend::Terra(client) => {
+                    if let Some(info) = client.get_deposit(*hash).await? {
+                        return terra_deposit(chain.id, &info, None, None).map(Some);
+                    }
+                }
+                Backend::Solana { .. } => {}
+            }
+        }
+        if self.chains.iter().any(|c| c.kind() == ChainKind::Solana) {
+            notes.push(
+                "Solana deposits are indexed by nonce, not hash; trace a Solana-source transfer \
+                 by signature or --src-chain/--nonce"
+                    .to_string(),
+            );
+        }
+        Ok(None)
+    }
+
+    async fn deposit_by_nonce(
+        &self,
+        src_chain: &ChainId,
+        nonce: u64,
+        notes: &mut Vec<String>,
+    ) -> Result<Option<TracedDeposit>> {
+        let chain = self
+            .chain(src_chain)
+            .ok_or_else(|| eyre!("source chain {} is not configured", src_chain.to_hex()))?;
+        match &chain.backend {
+            Backend::Evm { client, bridge } => {
+                let head = client.get_block_number().await?;
+                let filter = Filter::new()
+                    .address(*bridge)
+                    .event_signature(Bridge::Deposit::SIGNATURE_HASH);
+                let mut found = None;
+                self.scan_evm_logs(client, filter, head, |logs| {
+                    found = logs
+                        .iter()
+                        .filter_map(parse_deposit_log)
+                        .find(|e| e.nonce == nonce);
+                    found.is_some()
+                })
+                .await?;
+                let Some(event) = found else {
+                    notes.push(format!(
+                        "no Deposit with nonce {} in the last {} blocks (raise --evm-lookback-blocks)",
+                        nonce, self.evm_lookback_blocks
+                    ));
+                    return Ok(None);
+                };
+                let timestamp = evm_block_timestamp(client, event.block_number).await;
+                let dest_token =
+                    evm_dest_token(client, event.token.0.into(), event.dest_chain, notes).await;
+                Ok(Some(TracedDeposit {
+                    src_chain: chain.id,
+                    src_kind: ChainKind::Evm,
+                    dest_chain: event.dest_chain,
+                    src_account: event.src_account,
+                    dest_account: event.dest_account,
+                    dest_token,
+                    src_token: Some(format!("{}", Address::from(event.token.0))),
+                    amount: event.amount,
+                    fee: Some(event.fee),
+                    nonce: event.nonce,
+                    tx: Some(format!("0x{}", hex::encode(event.tx_hash))),
+                    timestamp,
+                    reported_hash: None,
+                }))
+            }
+            Backend::Terra(client) => match client.get_deposit_by_nonce(nonce).await? {
+                Some(info) => terra_deposit(chain.id, &info, None, None).map(Some),
+                None => Ok(None),
+            },
+            Backend::Solana { client, program_id } => {
+                let pda = deposit_record_pda(program_id, nonce);
+                let account = blocking(client, move |c| {
+                    Ok(c.get_account_with_commitment(&pda, c.commitment())?.value)
+                })
+                .await?;
+                let Some(account) = account else {
+                    return Ok(None);
+                };
+                let record = parse_deposit_record(&account.data)?;
+                Ok(Some(TracedDeposit {
+                    src_chain: chain.id,
+                    src_kind: ChainKind::Solana,
+                    dest_chain: ChainId::from_bytes(record.dest_chain),
+                    src_account: record.src_account,
+                    dest_account: record.dest_account,
+                    dest_token: Some(record.token),
+                    src_token: None,
+                    amount: record.amount,
+                    fee: None,
+                    nonce: record.nonce,
+                    tx: None,
+                    timestamp: u64::try_from(record.timestamp).ok(),
+                    reported_hash: Some(record.transfer_hash),
+                }))
+            }
+        }
+    }
+
+    /// Walk bridge logs matching `filter` backwards from `head` in chunks until `done`
+    /// returns true or the lookback is exhausted
+    async fn scan_evm_logs(
+        &self,
+        client: &EvmQueryClient,
+        filter: Filter,
+        head: u64,
+        mut done: impl FnMut(&[Log]) -> bool,
+    ) -> Result<()> {
+        let floor = head.saturating_sub(self.evm_lookback_blocks);
+        let mut to = head;
+        loop {
+            let from = to.saturating_sub(self.evm_log_chunk - 1).max(floor);
+            let logs = client
+                .provider()
+                .get_logs(&filter.clone().from_block(from).to_block(to))
+                .await
+                .wrap_err_with(|| format!("eth_getLogs {}..={}", from, to))?;
+            if done(&logs) || from == floor {
+                return Ok(());
+            }
+            to = from - 1;
+        }
+    }
+
+    // ------------------------------------------------------------------------
+    // Withdraw lookup
+    // ------------------------------------------------------------------------
+
+    async fn withdraw(
+        &self,
+        chain: &TraceChain,
+        hash: &[u8; 32],
+        notes: &mut Vec<String>,
+    ) -> Result<Option<TracedWithdraw>> {
+        match &chain.backend {
+            Backend::Evm { client, bridge } => {
+                let w = client.get_pending_withdraw(*hash).await?;
+                if w.submitted_at.is_zero() {
+                    return Ok(None);
+                }
+                let cancel_window = match client.get_cancel_window().await {
+                    Ok(window) => Some(window),
+                    Err(e) => {
+                        notes.push(format!("cancel window unavailable: {}", e));
+                        None
+                    }
+                };
+                let approved_at: Option<u64> = w.approved_at.try_into().ok().filter(|t| *t > 0);
+                let events = match self.evm_withdraw_events(client, *bridge, hash).await {
+                    Ok(events) => events,
+                    Err(e) => {
+                        notes.push(format!("withdraw event history unavailable: {}", e));
+                        Vec::new()
+                    }
+                };
+                Ok(Some(TracedWithdraw {
+                    chain: chain.id,
+                    kind: ChainKind::Evm,
+                    src_chain: Some(w.src_chain),
+                    src_account: Some(w.src_account),
+                    nonce: Some(w.nonce),
+                    amount: w.amount.try_into().ok(),
+                    recipient: Some(format!("{}", w.recipient)),
+                    submitted_at: w.submitted_at.try_into().ok(),
+                    approved: w.approved,
+                    approved_at,
+                    cancelled: w.cancelled,
+                    executed: w.executed,
+                    cancel_window,
+                    executable_at: approved_at.zip(cancel_window).map(|(a, c)| a + c),
+                    events,
+                }))
+            }
+            Backend::Terra(client) => {
+                let w = client.get_pending_withdraw(*hash).await?;
+                if !w.exists {
+                    return Ok(None);
+                }
+                let approved_at = w.approved_at.filter(|t| *t > 0);
+                let approved = w.approved || approved_at.is_some();
+                let cancel_window = client.get_cancel_window().await.ok();
+                // The contract reports what is left of the window for this withdrawal
+                let executable_at = match (approved, w.cancel_window_remaining) {
+                    (true, Some(remaining)) => Some(now_secs() + remaining),
+                    _ => None,
+                };
+                Ok(Some(TracedWithdraw {
+                    chain: chain.id,
+                    kind: ChainKind::Terra,
+                    src_chain: w.src_chain.as_deref().and_then(decode_chain_id),
+                    src_account: w.src_account.as_deref().and_then(decode_bytes32),
+                    nonce: w.nonce,
+                    amount: w.amount.as_deref().and_then(|a| a.parse().ok()),
+                    recipient: None,
+                    submitted_at: w.submitted_at.filter(|t| *t > 0),
+                    approved,
+                    approved_at,
+                    cancelled: w.cancelled,
+                    executed: w.executed,
+                    cancel_window,
+                    executable_at,
+                    events: Vec::new(),
+                }))
+            }
+            Backend::Solana { client, program_id } => {
+                solana_withdraw(chain.id, client, *program_id, *hash).await
+            }
+        }
+    }
+
+    async fn evm_withdraw_events(
+        &self,
+        client: &EvmQueryClient,
+        bridge: Address,
+        hash: &[u8; 32],
+    ) -> Result<Vec<TraceEvent>> {
+        let signatures = [
+            (Bridge::WithdrawSubmit::SIGNATURE_HASH, "WithdrawSubmit"),
+            (Bridge::WithdrawApprove::SIGNATURE_HASH, "WithdrawApprove"),
+            (Bridge::WithdrawCancel::SIGNATURE_HASH, "WithdrawCancel"),
+            (Bridge::WithdrawUncancel::SIGNATURE_HASH, "WithdrawUncancel"),
+            (Bridge::WithdrawExecute::SIGNATURE_HASH, "WithdrawExecute"),
+        ];
+        let filter = Filter::new()
+            .address(bridge)
+            .event_signature(signatures.iter().map(|(s, _)| *s).collect::<Vec<_>>())
+            .topic1(B256::from(*hash));
+        let head = client.get_block_number().await?;
+        let mut logs = Vec::new();
+        self.scan_evm_logs(client, filter, head, |chunk| {
+            logs.extend_from_slice(chunk);
+            chunk
+                .iter()
+                .any(|l| l.topic0() == Some(&Bridge::WithdrawSubmit::SIGNATURE_HASH))
+        })
+        .await?;
+        logs.sort_by_key(|l| (l.block_number, l.log_index));
+
+        let mut events = Vec::with_capacity(logs.len());
+        for log in logs {
+            let Some(name) = signatures
+                .iter()
+                .find(|(s, _)| log.topic0() == Some(s))
+                .map(|(_, n)| n.to_string())
+            else {
+                continue;
+            };
+            let block = log.block_number.unwrap_or_default();
+            events.push(TraceEvent {
+                name,
+                tx: log
+                    .transaction_hash
+                    .map(|h| format!("{:#x}", h))
+                    .unwrap_or_default(),
+                block,
+                timestamp: evm_block_timestamp(client, block).await,
+            });
+        }
+        Ok(events)
+    }
+}
+
+// ============================================================================
+// Chain helpers
+// ============================================================================
+
+async fn evm_block_timestamp(client: &EvmQueryClient, block: u64) -> Option<u64> {
+    client
+        .provider()
+        .get_block_by_number(
+            BlockNumberOrTag::Number(block),
+            BlockTransactionsKind::Hashes,
+        )
+        .await
+        .ok()
+        .flatten()
+        .map(|b| b.header.timestamp)
+}
+
+/// Destination token from the source bridge's TokenRegistry (`None` with a note if unmapped)
+async fn evm_dest_token(
+    client: &EvmQueryClient,
+    src_token: Address,
+    dest_chain: ChainId,
+    notes: &mut Vec<String>,
+) -> Option<[u8; 32]> {
+    let lookup = async {
+        let registry = client.get_token_registry_address().await?;
+        client.get_dest_token(registry, src_token, dest_chain).await
+    };
+    match lookup.await {
+        Ok(token) if token != [0u8; 32] => Some(token),
+        Ok(_) => {
+            notes.push(format!(
+                "token {} has no destination mapping for chain {}; hash not recomputable",
+                src_token,
+                dest_chain.to_hex()
+            ));
+            None
+        }
+        Err(e) => {
+            notes.push(format!("destination token lookup failed: {}", e));
+            None
+        }
+    }
+}
+
+fn terra_deposit(
+    chain_id: ChainId,
+    info: &DepositInfoResponse,
+    tx: Option<String>,
+    src_token: Option<String>,
+) -> Result<TracedDeposit> {
+    let field = |name: &str, v: &str| eyre!("Terra deposit has invalid {}: {}", name, v);
+    Ok(TracedDeposit {
+        src_chain: chain_id,
+        src_kind: ChainKind::Terra,
+        dest_chain: decode_chain_id(&info.dest_chain)
+            .ok_or_else(|| field("dest_chain", &info.dest_chain))?,
+        src_account: decode_bytes32(&info.src_account)
+            .ok_or_else(|| field("src_account", &info.src_account))?,
+        dest_account: decode_bytes32(&info.dest_account)
+            .ok_or_else(|| field("dest_account", &info.dest_account))?,
+        dest_token: decode_bytes32(&info.dest_token_address),
+        src_token,
+        amount: info
+            .amount
+            .parse()
+            .map_err(|_| field("amount", &info.amount))?,
+        fee: None,
+        nonce: info.nonce,
+        tx,
+        timestamp: info
+            .deposited_at
+            .parse::<u64>()
+            .ok()
+            .map(|nanos| nanos / 1_000_000_000),
+        reported_hash: decode_bytes32(&info.xchain_hash_id),
+    })
+}
+
+/// Deposit emitted by a Terra tx: the wasm event names the nonce, the stored record
+/// (`deposit_by_nonce`) carries the hashed fields
+async fn terra_deposit_from_tx(
+    chain_id: ChainId,
+    client: &TerraQueryClient,
+    tx_hex: &str,
+    notes: &mut Vec<String>,
+) -> Result<Option<TracedDeposit>> {
+    let tx = match client.get_tx(&tx_hex.to_uppercase()).await {
+        Ok(tx) => tx,
+        Err(_) => return Ok(None),
+    };
+    let response = &tx["tx_response"];
+    let events: Vec<TxEvent> =
+        serde_json::from_value(response["events"].clone()).unwrap_or_default();
+    let bridge = client.bridge_address();
+    let Some(event) = WasmEvent::from_tx_events(&events)
+        .into_iter()
+        .filter(|e| e.contract_address == bridge)
+        .find(|e| e.action == "deposit" || e.action.starts_with("deposit_"))
+    else {
+        return Ok(None);
+    };
+    let nonce: u64 = event
+        .get("nonce")
+        .and_then(|n| n.parse().ok())
+        .ok_or_else(|| eyre!("Terra deposit event without a nonce"))?;
+    let Some(info) = client.get_deposit_by_nonce(nonce).await? else {
+        return Err(eyre!("Terra deposit nonce {} has no stored record", nonce));
+    };
+    let mut deposit = terra_deposit(
+        chain_id,
+        &info,
+        response["txhash"].as_str().map(str::to_string),
+        event.get("token").cloned(),
+    )?;
+    deposit.fee = event.get("fee").and_then(|f| f.parse().ok());
+    if let Some(emitted) = event
+        .get("xchain_hash_id")
+        .and_then(|h| hex::decode(h.trim_start_matches("0x")).ok())
+        .and_then(|b| <[u8; 32]>::try_from(b).ok())
+    {
+        if Some(emitted) != deposit.reported_hash {
+            notes.push(format!(
+                "Terra tx event hash {} differs from the stored deposit hash",
+                bytes32_to_hex(&emitted)
+            ));
+        }
+    }
+    Ok(Some(deposit))
+}
+
+async fn solana_deposit_from_tx(
+    chain_id: ChainId,
+    client: &Arc<RpcClient>,
+    program_id: Pubkey,
+    signature: Signature,
+) -> Result<Option<TracedDeposit>> {
+    let Ok(tx) = blocking(client, move |c| Ok(get_transaction(c, &signature)?)).await else {
+        return Ok(None);
+    };
+    let logs = match tx.transaction.meta.as_ref().map(|m| &m.log_messages) {
+        Some(OptionSerializer::Some(logs)) => logs.clone(),
+        _ => return Ok(None),
+    };
+    let Some(event) = parse_anchor_events(&logs, &program_id)
+        .into_iter()
+        .find_map(|e| match e {
+            SolanaEvent::Deposit(d) => Some(d),
+            _ => None,
+        })
+    else {
+        return Ok(None);
+    };
+    Ok(Some(TracedDeposit {
+        src_chain: chain_id,
+        src_kind: ChainKind::Solana,
+        dest_chain: ChainId::from_bytes(event.dest_chain),
+        src_account: event.src_account,
+        dest_account: event.dest_account,
+        dest_token: Some(event.token),
+        src_token: None,
+        amount: event.amount,
+        fee: Some(event.fee),
+        nonce: event.nonce,
+        tx: Some(signature.to_string()),
+        timestamp: tx.block_time.and_then(|t| u64::try_from(t).ok()),
+        reported_hash: Some(event.transfer_hash),
+    }))
+}
+
+/// `PendingWithdraw` (live) or `ExecutedHash` (executed and closed) for a transfer hash
+async fn solana_withdraw(
+    chain_id: ChainId,
+    client: &Arc<RpcClient>,
+    program_id: Pubkey,
+    hash: [u8; 32],
+) -> Result<Option<TracedWithdraw>> {
+    let accounts = blocking(client, move |c| {
+        Ok(c.get_multiple_accounts(&[
+            pending_withdraw_pda(&program_id, &hash),
+            executed_hash_pda(&program_id, &hash),
+            bridge_config_pda(&program_id),
+        ])?)
+    })
+    .await?;
+
+    let Some(pending) = &accounts[0] else {
+        if accounts[1].is_some() {
+            return Ok(Some(TracedWithdraw {
+                chain: chain_id,
+                kind: ChainKind::Solana,
+                src_chain: None,
+                src_account: None,
+                nonce: None,
+                amount: None,
+                recipient: None,
+                submitted_at: None,
+                approved: true,
+                approved_at: None,
+                cancelled: false,
+                executed: true,
+                cancel_window: None,
+                executable_at: None,
+                events: Vec::new(),
+            }));
+        }
+        return Ok(None);
+    };
+    let pw = parse_pending_withdraw(&pending.data)?;
+
+    let bridge_delay = accounts[2]
+        .as_ref()
+        .and_then(|a| parse_bridge_config(&a.data).ok())
+        .map(|c| c.withdraw_delay);
+    let (src_chain, token) = (pw.src_chain, Pubkey::new_from_array(pw.token));
+    let delays = blocking(client, move |c| {
+        Ok(c.get_multiple_accounts(&[
+            chain_withdraw_delay_pda(&program_id, &src_chain),
+            large_transfer_delay_pda(&program_id, &src_chain, &token),
+        ])?)
+    })
+    .await?;
+    let cancel_window = bridge_delay.map(|bridge| {
+        let chain = delays[0]
+            .as_ref()
+            .and_then(|a| parse_chain_withdraw_delay(&a.data));
+        let large = delays[1]
+            .as_ref()
+            .and_then(|a| parse_large_transfer_delay(&a.data));
+        effective_withdraw_delay(bridge, chain, large, pw.amount).max(0) as u64
+    });
+    let approved_at = u64::try_from(pw.approved_at).ok().filter(|t| *t > 0);
+
+    Ok(Some(TracedWithdraw {
+        chain: chain_id,
+        kind: ChainKind::Solana,
+        src_chain: Some(ChainId::from_bytes(pw.src_chain)),
+        src_account: Some(pw.src_account),
+        nonce: Some(pw.nonce),
+        amount: Some(pw.amount),
+        recipient: Some(Pubkey::new_from_array(pw.dest_account).to_string()),
+        submitted_at: None,
+        approved: pw.approved,
+        approved_at,
+        cancelled: pw.cancelled,
+        executed: pw.executed,
+        cancel_window,
+        executable_at: approved_at.zip(cancel_window).map(|(a, c)| a + c),
+        events: Vec::new(),
+    }))
+}
+
+/// Flag destination fields that disagree with the source deposit
+fn check_withdraw_against_deposit(d: &TracedDeposit, w: &TracedWithdraw, notes: &mut Vec<String>) {
+    if w.src_chain.is_some_and(|c| c != d.src_chain) {
+        notes.push(format!(
+            "withdrawal names source chain {}, deposit is on {}",
+            w.src_chain.map(|c| c.to_hex()).unwrap_or_default(),
+            d.src_chain.to_hex()
+        ));
+    }
+    if w.src_account.is_some_and(|a| a != d.src_account) {
+        notes.push("withdrawal source account differs from the depositor".to_string());
+    }
+    if w.nonce.is_some_and(|n| n != d.nonce) {
+        notes.push(format!(
+            "withdrawal nonce {} differs from deposit nonce {}",
+            w.nonce.unwrap_or_default(),
+            d.nonce
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deposit() -> TracedDeposit {
+        TracedDeposit {
+            src_chain: ChainId::from_u32(2),
+            src_kind: ChainKind::Terra,
+            dest_chain: ChainId::from_u32(1),
+            src_account: [1; 32],
+            dest_account: [2; 32],
+            dest_token: Some([3; 32]),
+            src_token: Some("uluna".to_string()),
+            amount: 1_000,
+            fee: None,
+            nonce: 7,
+            tx: None,
+            timestamp: Some(1_700_000_000),
+            reported_hash: None,
+        }
+    }
+
+    fn withdraw(approved_at: Option<u64>) -> TracedWithdraw {
+        TracedWithdraw {
+            chain: ChainId::from_u32(1),
+            kind: ChainKind::Evm,
+            src_chain: Some(ChainId::from_u32(2)),
+            src_account: Some([1; 32]),
+            nonce: Some(7),
+            amount: Some(1_000),
+            recipient: None,
+            submitted_at: Some(1_700_000_100),
+            approved: approved_at.is_some(),
+            approved_at,
+            cancelled: false,
+            executed: false,
+            cancel_window: Some(300),
+            executable_at: approved_at.map(|a| a + 300),
+            events: Vec::new(),
+        }
+    }
+
+    fn trace(withdraw: Option<TracedWithdraw>, traced_at: u64) -> TransferTrace {
+        let deposit = deposit();
+        TransferTrace {
+            xchain_hash_id: deposit.computed_hash(),
+            deposit: Some(deposit),
+            withdraw,
+            notes: Vec::new(),
+            traced_at,
+        }
+    }
+
+    #[test]
+    fn test_status_follows_withdraw_lifecycle() {
+        assert_eq!(trace(None, 0).status(), TransferStatus::AwaitingSubmit);
+        assert_eq!(
+            trace(Some(withdraw(None)), 0).status(),
+            TransferStatus::AwaitingApproval
+        );
+
+        let approved = trace(Some(withdraw(Some(1_700_000_200))), 1_700_000_380);
+        assert_eq!(approved.cancel_window_remaining(), Some(120));
+        assert_eq!(
+            approved.status(),
+            TransferStatus::InCancelWindow { remaining: 120 }
+        );
+        let expired = trace(Some(withdraw(Some(1_700_000_200))), 1_700_000_600);
+        assert_eq!(expired.status(), TransferStatus::Executable);
+
+        let mut cancelled = withdraw(Some(1_700_000_200));
+        cancelled.cancelled = true;
+        let cancelled = trace(Some(cancelled), 1_700_000_300);
+        assert_eq!(cancelled.status(), TransferStatus::Cancelled);
+        assert_eq!(cancelled.cancel_window_remaining(), None);
+    }
+
+    #[test]
+    fn test_hash_match_uses_reported_hash() {
+        let mut t = trace(None, 0);
+        assert_eq!(t.hash_matches(), Some(true));
+        t.deposit.as_mut().unwrap().reported_hash = Some([9; 32]);
+        assert_eq!(t.hash_matches(), Some(false));
+        assert!(t.to_string().contains("MISMATCH"));
+        t.deposit.as_mut().unwrap().dest_token = None;
+        assert_eq!(t.hash_matches(), None);
+    }
+
+    #[test]
+    fn test_format_helpers() {
+        assert_eq!(format_duration(59), "59s");
+        assert_eq!(format_duration(301), "5m 01s");
+        assert_eq!(format_duration(3_723), "1h 02m 03s");
+        assert_eq!(format_time(Some(0)), "-");
+        assert!(format_time(Some(1_700_000_000)).starts_with("2023-11-14T22:13:20"));
+    }
+}
@@ -266,6 +266,61 @@ impl fmt::Display for TokenType {
     }
 }
 
+/// How a bridge moves a token: custody of the canonical supply, or mint and burn
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenMode {
+    LockUnlock,
+    MintBurn,
+}
+
+impl TokenMode {
+    /// `TokenRegistry` token type
+    pub fn evm_type(self) -> u8 {
+        match self {
+            TokenMode::LockUnlock => 0,
+            TokenMode::MintBurn => 1,
+        }
+    }
+
+    /// Terra `token_type` query result
+    pub fn from_terra(token_type: &str) -> Self {
+        if token_type == "mint_burn" {
+            TokenMode::MintBurn
+        } else {
+            TokenMode::LockUnlock
+        }
+    }
+}
+
+impl fmt::Display for TokenMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            TokenMode::LockUnlock => "lock_unlock",
+            TokenMode::MintBurn => "mint_burn",
+        })
+    }
+}
+
+/// Per-transaction and per-period limits of a token, in its own units (`0` = no minimum /
+/// unlimited)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RateLimit {
+    pub min_per_transaction: u128,
+    pub max_per_transaction: u128,
+    pub max_per_period: u128,
+}
+
+impl fmt::Display for RateLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "min {} max/tx {} max/period {}",
+            self.min_per_transaction, self.max_per_transaction, self.max_per_period
+        )
+    }
+}
+
 // ============================================================================
 // Chain Registration Types
 // ============================================================================
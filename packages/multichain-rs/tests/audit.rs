@@ -0,0 +1,259 @@
+//! Solvency Auditor Tests
+//!
+//! Runs audit rounds against the `testing::sim` backends while transfers move between
+//! them, checking custody, outstanding withdrawals, route flows and the findings the
+//! `cl8y-audit` binary reports:
+//!
+//! ```bash
+//! cd packages/multichain-rs
+//! cargo test --features full --test audit
+//! ```
+
+#![cfg(all(feature = "testing", feature = "audit"))]
+
+use alloy::primitives::Address;
+use multichain_rs::audit::{AuditConfig, AuditMetrics, Auditor};
+use multichain_rs::solana::{
+    associated_token_address, bridge_config_pda, pending_withdraw_pda, SPL_TOKEN_PROGRAM_ID,
+    WITHDRAW_EXECUTE_EVENT_DISCRIMINATOR, WITHDRAW_SUBMIT_EVENT_DISCRIMINATOR,
+};
+use multichain_rs::testing::sim::{
+    SimEvm, SimEvmConfig, SimSolanaRpc, SimTerraConfig, SimTerraLcd,
+};
+use multichain_rs::testing::{MockDeposit, MockDepositBuilder};
+use multichain_rs::ChainId;
+use serde_json::json;
+use solana_sdk::pubkey::Pubkey;
+
+const EVM_TOKEN: [u8; 20] = [0x44; 20];
+
+fn evm_token_bytes32() -> [u8; 32] {
+    let mut token = [0u8; 32];
+    token[12..].copy_from_slice(&EVM_TOKEN);
+    token
+}
+
+fn deposit(
+    src_chain: u32,
+    dest_chain: u32,
+    token: [u8; 32],
+    amount: u128,
+    nonce: u64,
+) -> MockDeposit {
+    let mut recipient = [0u8; 32];
+    recipient[12..].copy_from_slice(&[0x55; 20]);
+    MockDepositBuilder::new()
+        .src_chain(src_chain)
+        .dest_chain(dest_chain)
+        .src_account([0x66; 32])
+        .dest_account(recipient)
+        .token(token)
+        .amount(amount)
+        .nonce(nonce)
+        .build()
+}
+
+fn mint_account(supply: u64) -> Vec<u8> {
+    let mut data = vec![0u8; 82];
+    data[36..44].copy_from_slice(&supply.to_le_bytes());
+    data[44] = 6;
+    data
+}
+
+fn token_account(mint: &Pubkey, owner: &Pubkey, amount: u64) -> Vec<u8> {
+    let mut data = vec![0u8; 165];
+    data[..32].copy_from_slice(mint.as_ref());
+    data[32..64].copy_from_slice(owner.as_ref());
+    data[64..72].copy_from_slice(&amount.to_le_bytes());
+    data
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_terra_lock_to_evm_mint() {
+    let evm = SimEvm::start(SimEvmConfig::default()).await.unwrap();
+    let terra = SimTerraLcd::start(SimTerraConfig::default()).await.unwrap();
+    let token = Address::from(EVM_TOKEN);
+    let config = AuditConfig::from_json(
+        &json!({
+            "chains": [
+                {"kind": "evm", "rpc_url": evm.url(), "bridge": evm.bridge_address().to_string()},
+                {"kind": "terra", "lcd_url": terra.url(), "bridge": terra.bridge_address()},
+            ],
+            "assets": [{"name": "LUNC", "legs": [
+                {"chain": "0x00000002", "token": "uluna", "custody": "lock", "decimals": 6},
+                {"chain": "1", "token": token.to_string(), "custody": "mint", "decimals": 6},
+            ]}],
+        })
+        .to_string(),
+    )
+    .unwrap();
+    let mut auditor = Auditor::connect(&config).await.unwrap();
+
+    // Deposited and locked on Terra, nothing minted yet: all of it is in flight
+    let d = deposit(2, 1, evm_token_bytes32(), 5_000, 0);
+    let hash = terra.deposit(&d, "uluna", "terra1sender");
+    terra.set_locked_balance("uluna", 5_000);
+    evm.set_total_supply(token, 0);
+    let report = auditor.run_once().await.unwrap();
+    assert!(report.findings.is_empty(), "{:?}", report.findings);
+    let asset = &report.assets[0];
+    assert_eq!((asset.locked, asset.supply), (5_000, 0));
+    assert_eq!(asset.in_flight(), 5_000);
+    assert_eq!(asset.drift(), 0);
+    assert_eq!(asset.routes[0].unreleased(), 5_000);
+    let terra_chain = report
+        .chains
+        .iter()
+        .find(|c| c.chain == ChainId::from_u32(2));
+    assert_eq!(terra_chain.unwrap().transfers_out, Some(1));
+
+    // Approved on EVM: the mint is outstanding and fully backed
+    evm.submit_withdraw(&d).unwrap();
+    evm.approve_withdraw(&hash).unwrap();
+    let report = auditor.run_once().await.unwrap();
+    let asset = &report.assets[0];
+    assert_eq!(asset.outstanding_mint, 5_000);
+    assert_eq!(asset.solvency_margin(), 0);
+    assert_eq!(asset.routes[0].unreleased(), 0);
+    assert!(report.findings.is_empty(), "{:?}", report.findings);
+
+    // Executed: minted supply matches custody and nothing is in flight
+    evm.advance_time(301);
+    evm.execute_withdraw(&hash).unwrap();
+    evm.set_total_supply(token, 5_000);
+    let report = auditor.run_once().await.unwrap();
+    let asset = &report.assets[0];
+    assert_eq!(
+        (asset.routes[0].deposited, asset.routes[0].executed),
+        (5_000, 5_000)
+    );
+    assert_eq!(asset.in_flight(), 0);
+    assert_eq!((asset.drift(), asset.solvency_margin()), (0, 0));
+    assert!(report.findings.is_empty(), "{:?}", report.findings);
+    assert_eq!(report.to_json()["assets"][0]["drift"], "0");
+
+    // Custody drops under the minted supply
+    terra.set_locked_balance("uluna", 4_000);
+    let report = auditor.run_once().await.unwrap();
+    assert_eq!(report.assets[0].drift(), -1_000);
+    assert_eq!(report.findings.len(), 2, "{:?}", report.findings);
+    assert!(report.findings[0].contains("undercollateralized by 1000"));
+
+    let metrics = AuditMetrics::new().unwrap();
+    metrics.observe(&report);
+    let text = metrics.encode().unwrap();
+    assert!(
+        text.contains("cl8y_audit_drift{asset=\"LUNC\"} -0.001"),
+        "{text}"
+    );
+    assert!(text.contains("cl8y_audit_findings 2"), "{text}");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_solana_vault_and_evm_mint() {
+    let program_id = Pubkey::new_unique();
+    let solana = SimSolanaRpc::start(program_id).await.unwrap();
+    let mut bridge_config = vec![0u8; 104];
+    bridge_config[99..103].copy_from_slice(&[0, 0, 0, 5]);
+    solana.set_program_account(bridge_config_pda(&program_id), bridge_config);
+    let mint = Pubkey::new_unique();
+    let vault_owner = bridge_config_pda(&program_id);
+    let vault = associated_token_address(&vault_owner, &mint, &SPL_TOKEN_PROGRAM_ID);
+    solana.set_account(mint, SPL_TOKEN_PROGRAM_ID, mint_account(1_000_000));
+    let set_vault = |amount| {
+        solana.set_account(
+            vault,
+            SPL_TOKEN_PROGRAM_ID,
+            token_account(&mint, &vault_owner, amount),
+        )
+    };
+
+    let evm = SimEvm::start(SimEvmConfig::default()).await.unwrap();
+    let token = Address::from(EVM_TOKEN);
+    let config = AuditConfig::from_json(
+        &json!({
+            "chains": [
+                {"kind": "evm", "rpc_url": evm.url(), "bridge": evm.bridge_address().to_string()},
+                {"kind": "solana", "rpc_url": solana.url(), "program_id": program_id.to_string()},
+            ],
+            "assets": [{"name": "SOL", "legs": [
+                {"chain": "5", "token": mint.to_string(), "custody": "lock", "decimals": 6},
+                {"chain": "1", "token": token.to_string(), "custody": "mint", "decimals": 6},
+            ]}],
+        })
+        .to_string(),
+    )
+    .unwrap();
+    let mut auditor = Auditor::connect(&config).await.unwrap();
+
+    // Solana -> EVM, executed
+    let out = deposit(5, 1, evm_token_bytes32(), 3_000, 0);
+    solana.emit_deposit(&out);
+    set_vault(3_000);
+    let hash = evm.submit_withdraw(&out).unwrap();
+    evm.approve_withdraw(&hash).unwrap();
+    evm.advance_time(301);
+    evm.execute_withdraw(&hash).unwrap();
+    evm.set_total_supply(token, 3_000);
+
+    // EVM -> Solana: burned on EVM, submitted and executed out of the vault
+    let back = deposit(1, 5, mint.to_bytes(), 1_000, 0);
+    evm.deposit(&back, token);
+    evm.set_total_supply(token, 2_000);
+    let back_hash = back.compute_xchain_hash_id();
+    let mut submit = back_hash.to_vec();
+    submit.extend_from_slice(back.src_chain.as_bytes());
+    submit.extend_from_slice(&back.dest_account);
+    submit.extend_from_slice(mint.as_ref());
+    submit.extend_from_slice(&back.amount.to_le_bytes());
+    submit.extend_from_slice(&back.nonce.to_le_bytes());
+    submit.extend_from_slice(&0u64.to_le_bytes());
+    solana.emit_event(WITHDRAW_SUBMIT_EVENT_DISCRIMINATOR, &submit);
+
+    // Approved but not executed yet: the unlock is outstanding against the vault
+    let mut pending = vec![0u8; 200];
+    pending[8..40].copy_from_slice(&back_hash);
+    pending[40..44].copy_from_slice(back.src_chain.as_bytes());
+    pending[108..140].copy_from_slice(mint.as_ref());
+    pending[140..156].copy_from_slice(&back.amount.to_le_bytes());
+    pending[164] = 6;
+    pending[165] = 6;
+    pending[174] = 1;
+    solana.set_program_account(pending_withdraw_pda(&program_id, &back_hash), pending);
+    let report = auditor.run_once().await.unwrap();
+    assert!(report.findings.is_empty(), "{:?}", report.findings);
+    let asset = &report.assets[0];
+    assert_eq!((asset.locked, asset.supply), (3_000, 2_000));
+    assert_eq!(asset.outstanding_unlock, 1_000);
+    assert_eq!((asset.drift(), asset.solvency_margin()), (0, 0));
+    assert_eq!(asset.routes.len(), 2);
+
+    // Executed out of the vault; the pending account is closed
+    solana.remove_account(&pending_withdraw_pda(&program_id, &back_hash));
+    let mut execute = back_hash.to_vec();
+    execute.extend_from_slice(&[0x77; 32]);
+    execute.extend_from_slice(&back.amount.to_le_bytes());
+    solana.emit_event(WITHDRAW_EXECUTE_EVENT_DISCRIMINATOR, &execute);
+    set_vault(2_000);
+    let report = auditor.run_once().await.unwrap();
+    assert!(report.findings.is_empty(), "{:?}", report.findings);
+    let asset = &report.assets[0];
+    assert_eq!(
+        (asset.locked, asset.supply, asset.in_flight()),
+        (2_000, 2_000, 0)
+    );
+    assert!(asset.routes.iter().all(|r| r.unreleased() == 0));
+
+    // An execution without a submit in the scanned history cannot be attributed
+    let mut stray = [0x99u8; 32].to_vec();
+    stray.extend_from_slice(&[0x77; 32]);
+    stray.extend_from_slice(&10u128.to_le_bytes());
+    solana.emit_event(WITHDRAW_EXECUTE_EVENT_DISCRIMINATOR, &stray);
+    let report = auditor.run_once().await.unwrap();
+    let solana_chain = report
+        .chains
+        .iter()
+        .find(|c| c.chain == ChainId::from_u32(5));
+    assert_eq!(solana_chain.unwrap().unattributed, 1);
+    assert_eq!(report.findings.len(), 1, "{:?}", report.findings);
+}
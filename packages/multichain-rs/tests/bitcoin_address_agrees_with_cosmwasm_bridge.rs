@@ -0,0 +1,127 @@
+//! Bitcoin addresses: `multichain-rs` codec must match CosmWasm `bridge::address_codec` (Terra contract).
+
+use bridge::address_codec::{
+    BitcoinAddressKind as CwKind, UniversalAddress as CwAddress, CHAIN_TYPE_BITCOIN as CW_BITCOIN,
+};
+use multichain_rs::address_codec::{
+    BitcoinAddressKind as McKind, UniversalAddress as McAddress, CHAIN_TYPE_BITCOIN as MC_BITCOIN,
+};
+use proptest::prelude::*;
+
+/// BIP-173 / BIP-350 vectors: (address, hash bytes hex, 32-byte universal hex)
+const GOLDEN: &[(&str, &str, &str)] = &[
+    (
+        "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+        "000000000000000000000000751e76e8199196d454941c45d1b3a323f1433bd6",
+        "00000004751e76e8199196d454941c45d1b3a323f1433bd60000000000000000",
+    ),
+    (
+        "bc1qrp33g0q5c5txsp9arysrx4k6zdkfs4nce4xj0gdcccefvpysxf3qccfmv3",
+        "1863143c14c5166804bd19203356da136c985678cd4d27a1b8c6329604903262",
+        "000000041863143c14c5166804bd19203356da136c985678cd4d27a1b8c63296",
+    ),
+    (
+        "tb1qrp33g0q5c5txsp9arysrx4k6zdkfs4nce4xj0gdcccefvpysxf3q0sl5k7",
+        "1863143c14c5166804bd19203356da136c985678cd4d27a1b8c6329604903262",
+        "000000041863143c14c5166804bd19203356da136c985678cd4d27a1b8c63296",
+    ),
+    (
+        "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0",
+        "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+        "0000000479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b",
+    ),
+];
+
+/// BIP-350 invalid vectors both codecs must reject
+const INVALID: &[&str] = &[
+    // v1 with bech32 checksum
+    "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqh2y7hd",
+    // v0 with bech32m checksum
+    "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kemeawh",
+    // bad checksum
+    "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t5",
+    // more than 4 bits of padding (BIP-173)
+    "bc1zw508d6qejxtdg4y5r3zarvaryvqyzf3du",
+    // unknown prefix
+    "ltc1qw508d6qejxtdg4y5r3zarvary0c5xw7kgmn4n9",
+];
+
+fn hrp_of(addr: &str) -> &str {
+    &addr[..addr.rfind('1').unwrap()]
+}
+
+#[test]
+fn golden_vectors_agree() {
+    for (addr, hash_hex, bytes32_hex) in GOLDEN {
+        let mc = McAddress::from_bitcoin(addr).unwrap();
+        let cw = CwAddress::from_bitcoin(addr).unwrap();
+
+        assert_eq!(hex::encode(mc.to_hash_bytes()), *hash_hex, "{}", addr);
+        assert_eq!(hex::encode(cw.to_hash_bytes()), *hash_hex, "{}", addr);
+        assert_eq!(hex::encode(mc.to_bytes32()), *bytes32_hex, "{}", addr);
+        assert_eq!(hex::encode(cw.to_bytes32()), *bytes32_hex, "{}", addr);
+        assert_eq!(mc.to_bytes(), cw.to_bytes(), "{}", addr);
+
+        let hrp = hrp_of(addr);
+        assert_eq!(mc.to_bitcoin_string(hrp).unwrap(), *addr);
+        assert_eq!(cw.to_bitcoin_string(hrp).unwrap(), *addr);
+        assert_eq!(
+            multichain_rs::hash::encode_bitcoin_address_to_bytes32(addr).unwrap(),
+            cw.to_hash_bytes()
+        );
+    }
+}
+
+#[test]
+fn invalid_vectors_rejected_by_both() {
+    for addr in INVALID {
+        assert!(
+            McAddress::from_bitcoin(addr).is_err(),
+            "mc accepted {}",
+            addr
+        );
+        assert!(
+            CwAddress::from_bitcoin(addr).is_err(),
+            "cw accepted {}",
+            addr
+        );
+    }
+}
+
+#[test]
+fn chain_type_constants_agree() {
+    assert_eq!(MC_BITCOIN, CW_BITCOIN);
+}
+
+fn kinds() -> impl Strategy<Value = (McKind, CwKind)> {
+    prop_oneof![
+        Just((McKind::P2wpkh, CwKind::P2wpkh)),
+        Just((McKind::P2wsh, CwKind::P2wsh)),
+        Just((McKind::P2tr, CwKind::P2tr)),
+    ]
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(256))]
+
+    #[test]
+    fn proptest_bitcoin_codec_agrees_with_cosmwasm_bridge(
+        (mc_kind, cw_kind) in kinds(),
+        program in prop::array::uniform32(any::<u8>()),
+        hrp in prop::sample::select(vec!["bc", "tb", "bcrt"]),
+    ) {
+        let program = &program[..mc_kind.program_len()];
+        let mc = McAddress::from_bitcoin_program(mc_kind, program).unwrap();
+        let cw = CwAddress::from_bitcoin_program(cw_kind, program).unwrap();
+
+        let mc_str = mc.to_bitcoin_string(hrp).unwrap();
+        prop_assert_eq!(&mc_str, &cw.to_bitcoin_string(hrp).unwrap());
+        prop_assert_eq!(mc.to_hash_bytes(), cw.to_hash_bytes());
+        prop_assert_eq!(mc.to_bytes32(), cw.to_bytes32());
+        prop_assert_eq!(mc.to_bytes(), cw.to_bytes());
+
+        // Each codec decodes the other's output
+        prop_assert_eq!(CwAddress::from_bitcoin(&mc_str).unwrap(), cw);
+        prop_assert_eq!(McAddress::from_bitcoin(&mc_str).unwrap(), mc);
+    }
+}
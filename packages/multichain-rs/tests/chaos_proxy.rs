@@ -0,0 +1,248 @@
+//! Chaos Proxy Tests
+//!
+//! Puts `testing::chaos::ChaosProxy` in front of the simulated backends and checks each
+//! fault as seen through the clients the services use. Also parses the E2E scenario files
+//! so a typo there fails CI before an E2E run:
+//!
+//! ```bash
+//! cd packages/multichain-rs
+//! cargo test --features full --test chaos_proxy
+//! ```
+
+#![cfg(all(feature = "testing", feature = "evm", feature = "terra"))]
+
+use alloy::network::EthereumWallet;
+use alloy::primitives::B256;
+use alloy::providers::{Provider, ProviderBuilder};
+use alloy::rpc::types::Filter;
+use alloy::signers::local::PrivateKeySigner;
+use multichain_rs::evm::contracts::Bridge;
+use multichain_rs::terra::TerraQueryClient;
+use multichain_rs::testing::chaos::{ChaosFault, ChaosProxy, ChaosRule, ChaosScenario};
+use multichain_rs::testing::sim::{SimEvm, SimEvmConfig, SimTerraConfig, SimTerraLcd};
+use multichain_rs::testing::MockDepositBuilder;
+use multichain_rs::verify_terra_deposit;
+use std::time::{Duration, Instant};
+
+/// Anvil's first dev account
+const DEV_KEY: &str = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+
+async fn evm_behind(scenario: ChaosScenario) -> (SimEvm, ChaosProxy) {
+    let evm = SimEvm::start(SimEvmConfig::default()).await.unwrap();
+    let proxy = ChaosProxy::start(evm.url(), scenario).await.unwrap();
+    (evm, proxy)
+}
+
+#[test]
+fn test_e2e_scenario_files_parse() {
+    for json in [
+        include_str!("../../e2e/chaos/evm-flaky-reads.json"),
+        include_str!("../../e2e/chaos/evm-dropped-transactions.json"),
+        include_str!("../../e2e/chaos/terra-lcd-flaky.json"),
+    ] {
+        let scenario = ChaosScenario::from_json(json).unwrap();
+        assert!(!scenario.name.is_empty());
+        assert!(!scenario.rules.is_empty());
+    }
+
+    let err = ChaosScenario::from_json(r#"{ "rules": [{ "fault": { "kind": "explode" } }] }"#);
+    assert!(err.is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_passthrough_and_rule_counters() {
+    let scenario = ChaosScenario::default().rule(
+        ChaosRule::new(ChaosFault::HttpStatus { status: 429 })
+            .method("eth_blockNumber")
+            .skip(1)
+            .every(2)
+            .times(2),
+    );
+    let (evm, proxy) = evm_behind(scenario).await;
+    evm.mine(3);
+    let provider = ProviderBuilder::new().on_http(proxy.url().parse().unwrap());
+
+    // skip 1, then fire on every 2nd match, twice: ok, 429, ok, 429, ok, ok
+    let outcomes: Vec<bool> = futures_util::future::join_all(
+        (0..6).map(|_| async { provider.get_block_number().await.is_ok() }),
+    )
+    .await;
+    assert_eq!(outcomes.iter().filter(|ok| !**ok).count(), 2);
+    assert_eq!(provider.get_block_number().await.unwrap(), evm.head());
+    assert_eq!(provider.get_chain_id().await.unwrap(), 31337);
+
+    let stats = proxy.stats();
+    assert_eq!(stats.injected_by("rule0"), 2);
+    assert_eq!(stats.requests, 8);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_read_faults() {
+    let scenario = ChaosScenario::default()
+        .rule(
+            ChaosRule::new(ChaosFault::TruncatedBody)
+                .method("eth_chainId")
+                .times(1),
+        )
+        .rule(
+            ChaosRule::new(ChaosFault::RpcError {
+                code: -32000,
+                message: "header not found".to_string(),
+            })
+            .method("eth_getBalance")
+            .times(1),
+        )
+        .rule(ChaosRule::new(ChaosFault::StaleHead { blocks: 4 }).method("eth_blockNumber"))
+        .rule(ChaosRule::new(ChaosFault::Latency { ms: 300 }).method("eth_gasPrice"));
+    let (evm, proxy) = evm_behind(scenario).await;
+    evm.mine(10);
+    let provider = ProviderBuilder::new().on_http(proxy.url().parse().unwrap());
+
+    assert!(provider.get_chain_id().await.is_err());
+    assert_eq!(provider.get_chain_id().await.unwrap(), 31337);
+
+    let err = provider
+        .get_balance(evm.bridge_address())
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("header not found"), "{err}");
+
+    assert_eq!(provider.get_block_number().await.unwrap(), evm.head() - 4);
+
+    let started = Instant::now();
+    provider.get_gas_price().await.unwrap();
+    assert!(started.elapsed() >= Duration::from_millis(300));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_get_logs_limit_exceeded() {
+    let scenario = ChaosScenario::default().rule(
+        ChaosRule::new(ChaosFault::EthGetLogsLimitExceeded { max_blocks: 10 })
+            .method("eth_getLogs"),
+    );
+    let (evm, proxy) = evm_behind(scenario).await;
+    evm.mine(30);
+    let provider = ProviderBuilder::new().on_http(proxy.url().parse().unwrap());
+
+    let err = provider
+        .get_logs(&Filter::new().from_block(0).to_block(20))
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("limit exceeded"), "{err}");
+
+    // `latest` is resolved against the upstream head
+    let err = provider
+        .get_logs(&Filter::new().from_block(5))
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains("-32005") || err.contains("limit exceeded"),
+        "{err}"
+    );
+
+    let head = evm.head();
+    assert!(provider
+        .get_logs(&Filter::new().from_block(head - 9))
+        .await
+        .is_ok());
+    assert!(provider
+        .get_logs(&Filter::new().from_block(0).to_block(9))
+        .await
+        .is_ok());
+    assert_eq!(proxy.stats().injected_by("rule0"), 2);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_dropped_transaction_never_reaches_upstream() {
+    let mut recipient = [0u8; 32];
+    recipient[12..].copy_from_slice(&[0x55; 20]);
+    let mut token = [0u8; 32];
+    token[12..].copy_from_slice(&[0x44; 20]);
+    let deposit = MockDepositBuilder::new()
+        .src_chain(2)
+        .dest_chain(1)
+        .dest_account(recipient)
+        .token(token)
+        .nonce(5)
+        .build();
+
+    let scenario = ChaosScenario::default().rule(
+        ChaosRule::new(ChaosFault::DropTransaction)
+            .method("eth_sendRawTransaction")
+            .times(1),
+    );
+    let (evm, proxy) = evm_behind(scenario).await;
+    let hash = evm.submit_withdraw(&deposit).unwrap();
+    evm.approve_withdraw(&hash).unwrap();
+
+    let signer: PrivateKeySigner = DEV_KEY.parse().unwrap();
+    let provider = ProviderBuilder::new()
+        .with_recommended_fillers()
+        .wallet(EthereumWallet::from(signer))
+        .on_http(proxy.url().parse().unwrap());
+    let contract = Bridge::new(evm.bridge_address(), &provider);
+
+    // First send is swallowed: the hash comes back but no receipt ever appears
+    let pending = contract
+        .withdrawCancel(B256::from(hash))
+        .send()
+        .await
+        .unwrap();
+    let dropped = *pending.tx_hash();
+    assert_eq!(
+        proxy.stats().dropped_transactions,
+        vec![format!("{dropped:#x}")]
+    );
+    assert!(provider
+        .get_transaction_receipt(dropped)
+        .await
+        .unwrap()
+        .is_none());
+    assert!(evm.transactions_calling("withdrawCancel").is_empty());
+
+    // The resubmission goes through exactly once
+    let receipt = contract
+        .withdrawCancel(B256::from(hash))
+        .send()
+        .await
+        .unwrap()
+        .get_receipt()
+        .await
+        .unwrap();
+    assert!(receipt.status());
+    assert_eq!(evm.transactions_calling("withdrawCancel").len(), 1);
+    assert!(evm.pending_withdraw(&hash).unwrap().cancelled);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_lcd_path_faults() {
+    let terra = SimTerraLcd::start(SimTerraConfig::default()).await.unwrap();
+    terra.produce_blocks(10);
+    let scenario = ChaosScenario::default()
+        .rule(ChaosRule::new(ChaosFault::StaleHead { blocks: 3 }).path("/blocks/latest"))
+        .rule(
+            ChaosRule::new(ChaosFault::HttpStatus { status: 429 })
+                .path("/smart/")
+                .times(1),
+        );
+    let proxy = ChaosProxy::start(terra.url(), scenario).await.unwrap();
+    let bridge = terra.bridge_address();
+
+    let client = TerraQueryClient::new(proxy.url(), &bridge);
+    assert_eq!(
+        client.get_latest_block_height().await.unwrap(),
+        terra.height() - 3
+    );
+
+    let deposit = MockDepositBuilder::new().nonce(8).build();
+    let hash = terra.deposit(&deposit, "uluna", "terra1sender");
+    assert!(verify_terra_deposit(proxy.url(), &bridge, &hash)
+        .await
+        .is_err());
+    assert!(verify_terra_deposit(proxy.url(), &bridge, &hash)
+        .await
+        .unwrap());
+}
@@ -0,0 +1,91 @@
+//! Shared conformance corpus (`conformance/vectors.json`): multichain-rs and CosmWasm
+//! `bridge` must reproduce every V2 xchain hash and universal address vector, and the
+//! committed corpus must match what `cl8y-conformance generate` produces.
+
+use bridge::address_codec::UniversalAddress as CwAddress;
+use bridge::hash::compute_xchain_hash_id as cw_hash;
+use multichain_rs::address_codec::{
+    CHAIN_TYPE_BITCOIN, CHAIN_TYPE_COSMOS, CHAIN_TYPE_EVM, CHAIN_TYPE_SOLANA,
+};
+use multichain_rs::conformance::{AddressVector, Corpus};
+use multichain_rs::hash::compute_xchain_hash_id as mc_hash;
+
+const CORPUS_JSON: &str = include_str!("../conformance/vectors.json");
+
+fn corpus() -> Corpus {
+    Corpus::from_json(CORPUS_JSON).expect("valid corpus")
+}
+
+fn hex(bytes: &[u8]) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+#[test]
+fn committed_corpus_is_up_to_date() {
+    let generated = Corpus::generate().unwrap();
+    assert!(
+        CORPUS_JSON == generated.to_json().unwrap(),
+        "conformance/vectors.json is stale: run `cargo run --features conformance --bin cl8y-conformance -- generate`"
+    );
+}
+
+#[test]
+fn corpus_verifies_against_multichain_rs() {
+    corpus().verify().unwrap();
+}
+
+#[test]
+fn xchain_hash_vectors_match_cosmwasm_bridge() {
+    for v in corpus().xchain_hash {
+        let (sc, dc, sa, da, tok, amount, nonce) = v.inputs().unwrap();
+        let want = v.expected().unwrap();
+        assert_eq!(
+            mc_hash(&sc, &dc, &sa, &da, &tok, amount, nonce),
+            want,
+            "multichain-rs digest mismatch: {}",
+            v.name
+        );
+        assert_eq!(
+            cw_hash(&sc, &dc, &sa, &da, &tok, amount, nonce),
+            want,
+            "CosmWasm bridge digest mismatch: {}",
+            v.name
+        );
+    }
+}
+
+fn parse_cw(v: &AddressVector) -> CwAddress {
+    let addr = match v.chain_type {
+        CHAIN_TYPE_EVM => CwAddress::from_evm(&v.address),
+        CHAIN_TYPE_COSMOS => CwAddress::from_cosmos(&v.address),
+        CHAIN_TYPE_SOLANA => CwAddress::from_solana_base58(&v.address),
+        CHAIN_TYPE_BITCOIN => CwAddress::from_bitcoin(&v.address),
+        other => panic!("{}: unknown chain type {}", v.name, other),
+    }
+    .unwrap();
+    let reserved: [u8; 8] = hex::decode(v.reserved.trim_start_matches("0x"))
+        .unwrap()
+        .try_into()
+        .unwrap();
+    if reserved == [0u8; 8] {
+        return addr;
+    }
+    CwAddress::new_with_reserved(addr.chain_type, *addr.raw_address_20().unwrap(), reserved)
+        .unwrap()
+}
+
+#[test]
+fn universal_address_vectors_match_cosmwasm_bridge() {
+    for v in corpus().universal_address {
+        let cw = parse_cw(&v);
+        assert_eq!(cw.chain_type, v.chain_type, "{}", v.name);
+        assert_eq!(hex(cw.raw_address_bytes()), v.raw, "{}", v.name);
+        assert_eq!(hex(&cw.reserved), v.reserved, "{}", v.name);
+        assert_eq!(hex(&cw.to_bytes32()), v.bytes32, "{}", v.name);
+        assert_eq!(hex(&cw.to_hash_bytes()), v.hash_bytes, "{}", v.name);
+        assert_eq!(hex(&cw.to_bytes()), v.bytes, "{}", v.name);
+
+        let lossless = hex::decode(v.bytes.trim_start_matches("0x")).unwrap();
+        assert_eq!(CwAddress::from_bytes(&lossless).unwrap(), cw, "{}", v.name);
+    }
+}
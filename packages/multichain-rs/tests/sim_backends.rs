@@ -0,0 +1,291 @@
+//! Simulated Chain Backend Tests
+//!
+//! Drives the `testing::sim` backends with the same clients the operator and canceler use
+//! (alloy providers, the Terra LCD query client, the blocking Solana `RpcClient`), so the
+//! simulators stay wire-compatible with them. Runs hermetically, no Docker required:
+//!
+//! ```bash
+//! cd packages/multichain-rs
+//! cargo test --features full --test sim_backends
+//! ```
+
+#![cfg(all(
+    feature = "testing",
+    feature = "evm",
+    feature = "terra",
+    feature = "solana"
+))]
+
+use alloy::network::EthereumWallet;
+use alloy::primitives::{Address, B256};
+use alloy::providers::{Provider, ProviderBuilder};
+use alloy::rpc::types::Filter;
+use alloy::signers::local::PrivateKeySigner;
+use multichain_rs::evm::contracts::Bridge;
+use multichain_rs::solana::SolanaEvent;
+use multichain_rs::solana::{get_signatures_for_program, get_transaction, parse_anchor_events};
+use multichain_rs::terra::TerraQueryClient;
+use multichain_rs::testing::sim::{
+    Fault, FaultRule, SimEvm, SimEvmConfig, SimSolanaRpc, SimTerraConfig, SimTerraLcd,
+};
+use multichain_rs::testing::MockDepositBuilder;
+use multichain_rs::{verify_evm_deposit, verify_terra_deposit};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::Transaction;
+use solana_transaction_status::option_serializer::OptionSerializer;
+
+/// Anvil's first dev account
+const DEV_KEY: &str = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+
+fn evm_to_terra_deposit(nonce: u64) -> multichain_rs::testing::MockDeposit {
+    MockDepositBuilder::new()
+        .src_chain(1)
+        .dest_chain(2)
+        .src_account([0x11; 32])
+        .dest_account([0x22; 32])
+        .token([0x33; 32])
+        .amount(1_000_000)
+        .nonce(nonce)
+        .build()
+}
+
+fn terra_to_evm_deposit(nonce: u64) -> multichain_rs::testing::MockDeposit {
+    let mut token = [0u8; 32];
+    token[12..].copy_from_slice(&[0x44; 20]);
+    let mut recipient = [0u8; 32];
+    recipient[12..].copy_from_slice(&[0x55; 20]);
+    MockDepositBuilder::new()
+        .src_chain(2)
+        .dest_chain(1)
+        .src_account([0x66; 32])
+        .dest_account(recipient)
+        .token(token)
+        .amount(5_000)
+        .nonce(nonce)
+        .build()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_evm_deposit_verification_and_reorg() {
+    let evm = SimEvm::start(SimEvmConfig::default()).await.unwrap();
+    let bridge = evm.bridge_address().to_string();
+    let deposit = evm_to_terra_deposit(7);
+
+    let hash = evm.deposit(&deposit, Address::repeat_byte(0x33));
+    assert!(verify_evm_deposit(evm.url(), &bridge, &hash).await.unwrap());
+    assert!(!verify_evm_deposit(evm.url(), &bridge, &[0xee; 32])
+        .await
+        .unwrap());
+
+    let provider = ProviderBuilder::new().on_http(evm.url().parse().unwrap());
+    let filter = Filter::new()
+        .address(evm.bridge_address())
+        .event_signature(<Bridge::Deposit as alloy::sol_types::SolEvent>::SIGNATURE_HASH)
+        .from_block(0)
+        .to_block(evm.head());
+    assert_eq!(provider.get_logs(&filter).await.unwrap().len(), 1);
+
+    // A reorg past the deposit block erases the record and its log
+    evm.reorg(1);
+    assert!(!verify_evm_deposit(evm.url(), &bridge, &hash).await.unwrap());
+    assert!(provider.get_logs(&filter).await.unwrap().is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_evm_signed_cancel_is_applied() {
+    let evm = SimEvm::start(SimEvmConfig::default()).await.unwrap();
+    let deposit = terra_to_evm_deposit(3);
+    let hash = evm.submit_withdraw(&deposit).unwrap();
+    evm.approve_withdraw(&hash).unwrap();
+
+    let signer: PrivateKeySigner = DEV_KEY.parse().unwrap();
+    let canceler = signer.address();
+    let provider = ProviderBuilder::new()
+        .with_recommended_fillers()
+        .wallet(EthereumWallet::from(signer))
+        .on_http(evm.url().parse().unwrap());
+    let contract = Bridge::new(evm.bridge_address(), &provider);
+    let receipt = contract
+        .withdrawCancel(B256::from(hash))
+        .send()
+        .await
+        .unwrap()
+        .get_receipt()
+        .await
+        .unwrap();
+    assert!(receipt.status());
+
+    let pending = evm.pending_withdraw(&hash).unwrap();
+    assert!(pending.cancelled);
+    let cancels = evm.transactions_calling("withdrawCancel");
+    assert_eq!(cancels.len(), 1);
+    assert_eq!(cancels[0].from, canceler);
+    assert_eq!(cancels[0].xchain_hash_id, Some(hash));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_evm_faults() {
+    let evm = SimEvm::start(SimEvmConfig::default()).await.unwrap();
+    let provider = ProviderBuilder::new().on_http(evm.url().parse().unwrap());
+    evm.mine(20);
+
+    evm.faults().add(
+        FaultRule::new(Fault::HttpStatus(429))
+            .method("eth_blockNumber")
+            .times(1),
+    );
+    assert!(provider.get_block_number().await.is_err());
+    assert_eq!(provider.get_block_number().await.unwrap(), evm.head());
+
+    evm.set_head_lag(5);
+    assert_eq!(provider.get_block_number().await.unwrap(), evm.head() - 5);
+
+    evm.set_get_logs_max_range(Some(10));
+    let wide = Filter::new().from_block(0).to_block(20);
+    let err = provider.get_logs(&wide).await.unwrap_err().to_string();
+    assert!(err.contains("limit exceeded"), "{err}");
+    let narrow = Filter::new().from_block(0).to_block(9);
+    assert!(provider.get_logs(&narrow).await.is_ok());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_terra_deposit_verification_and_tx_search() {
+    let terra = SimTerraLcd::start(SimTerraConfig::default()).await.unwrap();
+    let bridge = terra.bridge_address();
+    let deposit = terra_to_evm_deposit(9);
+
+    let hash = terra.deposit(&deposit, "uluna", "terra1sender");
+    let height = terra.height();
+    assert!(verify_terra_deposit(terra.url(), &bridge, &hash)
+        .await
+        .unwrap());
+    assert!(!verify_terra_deposit(terra.url(), &bridge, &[0xee; 32])
+        .await
+        .unwrap());
+
+    let client = TerraQueryClient::new(terra.url(), &bridge);
+    assert_eq!(client.get_latest_block_height().await.unwrap(), height);
+    let txs = client.search_contract_txs(&bridge, height).await.unwrap();
+    assert_eq!(txs.len(), 1);
+    assert!(client
+        .search_contract_txs(&bridge, height + 1)
+        .await
+        .unwrap()
+        .is_empty());
+
+    // Pending withdrawals on Terra follow submit -> approve -> cancel
+    let incoming = evm_to_terra_deposit(4);
+    let withdraw = terra.submit_withdraw(&incoming, "uluna", "terra1recipient");
+    terra.approve_withdraw(&withdraw).unwrap();
+    let pending = client.get_pending_withdraw(withdraw).await.unwrap();
+    assert!(pending.exists);
+    terra.cancel_withdraw(&withdraw).unwrap();
+    assert!(terra.pending_withdraw(&withdraw).unwrap().cancelled);
+
+    terra
+        .faults()
+        .add(FaultRule::new(Fault::HttpStatus(503)).times(1));
+    assert!(verify_terra_deposit(terra.url(), &bridge, &hash)
+        .await
+        .is_err());
+    assert!(verify_terra_deposit(terra.url(), &bridge, &hash)
+        .await
+        .unwrap());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_solana_events_and_submission() {
+    let program_id = Pubkey::new_unique();
+    let sol = SimSolanaRpc::start(program_id).await.unwrap();
+    let deposit = evm_to_terra_deposit(12);
+    let deposit_sig = sol.emit_deposit(&deposit);
+    sol.emit_failed_transaction();
+    let url = sol.url().to_string();
+
+    let (signatures, events, sent) = tokio::task::spawn_blocking(move || {
+        let client = RpcClient::new(url);
+        let signatures = get_signatures_for_program(&client, &program_id, None, 10).unwrap();
+        let tx = get_transaction(&client, &deposit_sig).unwrap();
+        let logs = match tx.transaction.meta.unwrap().log_messages {
+            OptionSerializer::Some(logs) => logs,
+            _ => Vec::new(),
+        };
+        let events = parse_anchor_events(&logs, &program_id);
+
+        let payer = Keypair::new();
+        let ix = Instruction::new_with_bytes(
+            program_id,
+            &multichain_rs::testing::sim::instruction_discriminator("withdraw_cancel"),
+            vec![AccountMeta::new(payer.pubkey(), true)],
+        );
+        let blockhash = client.get_latest_blockhash().unwrap();
+        let tx =
+            Transaction::new_signed_with_payer(&[ix], Some(&payer.pubkey()), &[&payer], blockhash);
+        let sent = client.send_and_confirm_transaction(&tx).unwrap();
+        (signatures, events, sent)
+    })
+    .await
+    .unwrap();
+
+    // Newest first; the failed transaction carries an error
+    assert_eq!(signatures.len(), 2);
+    assert!(signatures[0].err.is_some());
+    assert_eq!(signatures[1].signature, deposit_sig.to_string());
+
+    assert_eq!(events.len(), 1);
+    match &events[0] {
+        SolanaEvent::Deposit(event) => {
+            assert_eq!(event.transfer_hash, deposit.compute_xchain_hash_id());
+            assert_eq!(event.nonce, 12);
+            assert_eq!(event.amount, 1_000_000);
+        }
+        other => panic!("unexpected event {other:?}"),
+    }
+
+    let cancels = sol.instructions_named("withdraw_cancel");
+    assert_eq!(cancels.len(), 1);
+    assert_eq!(cancels[0].signature, sent);
+    assert_eq!(cancels[0].program_id, program_id);
+}
+
+#[cfg(feature = "token-cache")]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_solana_registry_change_signal() {
+    use multichain_rs::solana::REGISTER_TOKEN_EVENT_DISCRIMINATOR;
+    use multichain_rs::token_cache::solana_registry_changed;
+
+    let program_id = Pubkey::new_unique();
+    let sol = SimSolanaRpc::start(program_id).await.unwrap();
+    let since = sol.slot();
+    sol.emit_deposit(&evm_to_terra_deposit(13));
+    let url = sol.url().to_string();
+
+    // Bridge traffic alone is no registry change
+    let client_url = url.clone();
+    let changed = tokio::task::spawn_blocking(move || {
+        solana_registry_changed(&RpcClient::new(client_url), &program_id, since)
+    })
+    .await
+    .unwrap()
+    .unwrap();
+    assert!(!changed);
+
+    sol.emit_event(REGISTER_TOKEN_EVENT_DISCRIMINATOR, &[0u8; 70]);
+    let after = sol.slot();
+    sol.emit_deposit(&evm_to_terra_deposit(14));
+    let (changed, unchanged_since) = tokio::task::spawn_blocking(move || {
+        let client = RpcClient::new(url);
+        (
+            solana_registry_changed(&client, &program_id, since).unwrap(),
+            solana_registry_changed(&client, &program_id, after).unwrap(),
+        )
+    })
+    .await
+    .unwrap();
+    assert!(changed);
+    // Events at or before the cursor slot were already seen
+    assert!(!unchanged_since);
+}
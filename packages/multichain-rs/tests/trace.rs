@@ -0,0 +1,227 @@
+//! Transfer Tracer Tests
+//!
+//! Traces transfers between the `testing::sim` backends through every lookup the
+//! `cl8y-trace` binary offers (source tx, xchain hash ID, source chain + nonce):
+//!
+//! ```bash
+//! cd packages/multichain-rs
+//! cargo test --features full --test trace
+//! ```
+
+#![cfg(all(feature = "testing", feature = "trace"))]
+
+use alloy::primitives::Address;
+use alloy::providers::{Provider, ProviderBuilder};
+use alloy::rpc::types::Filter;
+use alloy::sol_types::SolEvent;
+use multichain_rs::evm::contracts::Bridge;
+use multichain_rs::solana::bridge_config_pda;
+use multichain_rs::testing::sim::{
+    SimEvm, SimEvmConfig, SimSolanaRpc, SimTerraConfig, SimTerraLcd,
+};
+use multichain_rs::testing::{MockDeposit, MockDepositBuilder};
+use multichain_rs::trace::{ChainKind, TraceEndpoint, TraceTarget, Tracer, TransferStatus};
+use multichain_rs::ChainId;
+use solana_sdk::pubkey::Pubkey;
+
+fn evm_recipient_deposit(src_chain: u32, nonce: u64) -> MockDeposit {
+    let mut token = [0u8; 32];
+    token[12..].copy_from_slice(&[0x44; 20]);
+    let mut recipient = [0u8; 32];
+    recipient[12..].copy_from_slice(&[0x55; 20]);
+    MockDepositBuilder::new()
+        .src_chain(src_chain)
+        .dest_chain(1)
+        .src_account([0x66; 32])
+        .dest_account(recipient)
+        .token(token)
+        .amount(5_000)
+        .nonce(nonce)
+        .build()
+}
+
+async fn evm_and_terra() -> (SimEvm, SimTerraLcd, Tracer) {
+    let evm = SimEvm::start(SimEvmConfig::default()).await.unwrap();
+    let terra = SimTerraLcd::start(SimTerraConfig::default()).await.unwrap();
+    let tracer = Tracer::connect(&[
+        TraceEndpoint::Evm {
+            rpc_url: evm.url().to_string(),
+            bridge: evm.bridge_address(),
+        },
+        TraceEndpoint::Terra {
+            lcd_url: terra.url().to_string(),
+            bridge: terra.bridge_address(),
+        },
+    ])
+    .await
+    .unwrap()
+    .with_evm_lookback(1_000, 100);
+    (evm, terra, tracer)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_terra_to_evm_by_nonce_and_tx() {
+    let (evm, terra, tracer) = evm_and_terra().await;
+    assert_eq!(
+        tracer.chains(),
+        vec![
+            (ChainId::from_u32(1), ChainKind::Evm),
+            (ChainId::from_u32(2), ChainKind::Terra)
+        ]
+    );
+
+    let deposit = evm_recipient_deposit(2, 4);
+    let hash = terra.deposit(&deposit, "uluna", "terra1sender");
+    let target = TraceTarget::Nonce {
+        src_chain: ChainId::from_u32(2),
+        nonce: 4,
+    };
+
+    let trace = tracer.trace(&target).await.unwrap();
+    assert_eq!(trace.xchain_hash_id, Some(hash));
+    assert_eq!(trace.hash_matches(), Some(true));
+    assert_eq!(trace.status(), TransferStatus::AwaitingSubmit);
+
+    evm.submit_withdraw(&deposit).unwrap();
+    evm.approve_withdraw(&hash).unwrap();
+    let terra_tx = terra.transactions()[0].txhash.clone();
+    let trace = tracer
+        .trace(&TraceTarget::SourceTx(terra_tx.clone()))
+        .await
+        .unwrap();
+    let d = trace.deposit.as_ref().unwrap();
+    assert_eq!(d.tx.as_deref(), Some(terra_tx.as_str()));
+    assert_eq!(d.src_token.as_deref(), Some("uluna"));
+    assert_eq!(trace.hash_matches(), Some(true));
+    assert!(trace.notes.is_empty(), "{:?}", trace.notes);
+
+    let w = trace.withdraw.as_ref().unwrap();
+    assert_eq!(w.kind, ChainKind::Evm);
+    assert!(w.approved && !w.cancelled && !w.executed);
+    assert_eq!(w.cancel_window, Some(300));
+    let names: Vec<&str> = w.events.iter().map(|e| e.name.as_str()).collect();
+    assert_eq!(names, ["WithdrawSubmit", "WithdrawApprove"]);
+    assert!(w.events.iter().all(|e| e.timestamp.is_some()));
+    match trace.status() {
+        TransferStatus::InCancelWindow { remaining } => assert!(remaining <= 300),
+        other => panic!("unexpected status {other:?}"),
+    }
+
+    evm.advance_time(301);
+    evm.execute_withdraw(&hash).unwrap();
+    let trace = tracer
+        .trace(&TraceTarget::XchainHashId(hash))
+        .await
+        .unwrap();
+    assert_eq!(trace.status(), TransferStatus::Executed);
+    let report = trace.to_string();
+    assert!(report.contains("(recomputed, matches)"), "{report}");
+    assert!(report.contains("WithdrawExecute"), "{report}");
+    assert_eq!(trace.to_json()["status"], "executed");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_evm_to_terra_by_tx_and_cancel() {
+    let (evm, terra, tracer) = evm_and_terra().await;
+    let deposit = MockDepositBuilder::new()
+        .src_chain(1)
+        .dest_chain(2)
+        .src_account([0x11; 32])
+        .dest_account([0x22; 32])
+        .token([0x33; 32])
+        .amount(1_000_000)
+        .nonce(9)
+        .build();
+    let src_token = Address::repeat_byte(0x33);
+    let hash = evm.deposit(&deposit, src_token);
+
+    let provider = ProviderBuilder::new().on_http(evm.url().parse().unwrap());
+    let logs = provider
+        .get_logs(&Filter::new().event_signature(Bridge::Deposit::SIGNATURE_HASH))
+        .await
+        .unwrap();
+    let tx = format!("{:#x}", logs[0].transaction_hash.unwrap());
+
+    // Without a registry mapping the hash cannot be recomputed
+    let trace = tracer
+        .trace(&TraceTarget::SourceTx(tx.clone()))
+        .await
+        .unwrap();
+    assert_eq!(trace.hash_matches(), None);
+    assert!(trace
+        .notes
+        .iter()
+        .any(|n| n.contains("no destination mapping")));
+
+    evm.set_dest_token(src_token, ChainId::from_u32(2), deposit.token);
+    terra.submit_withdraw(&deposit, "uluna", "terra1recipient");
+    let trace = tracer.trace(&TraceTarget::SourceTx(tx)).await.unwrap();
+    assert_eq!(trace.xchain_hash_id, Some(hash));
+    assert_eq!(trace.status(), TransferStatus::AwaitingApproval);
+    assert_eq!(trace.withdraw.as_ref().unwrap().kind, ChainKind::Terra);
+
+    terra.approve_withdraw(&hash).unwrap();
+    terra.cancel_withdraw(&hash).unwrap();
+    let trace = tracer
+        .trace(&TraceTarget::Nonce {
+            src_chain: ChainId::from_u32(1),
+            nonce: 9,
+        })
+        .await
+        .unwrap();
+    assert_eq!(trace.status(), TransferStatus::Cancelled);
+    assert_eq!(trace.cancel_window_remaining(), None);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_solana_source_by_signature() {
+    let program_id = Pubkey::new_unique();
+    let solana = SimSolanaRpc::start(program_id).await.unwrap();
+    let mut config = vec![0u8; 104];
+    config[74..82].copy_from_slice(&300i64.to_le_bytes());
+    config[99..103].copy_from_slice(&[0, 0, 0, 5]);
+    solana.set_program_account(bridge_config_pda(&program_id), config);
+    let evm = SimEvm::start(SimEvmConfig::default()).await.unwrap();
+
+    let tracer = Tracer::connect(&[
+        TraceEndpoint::Evm {
+            rpc_url: evm.url().to_string(),
+            bridge: evm.bridge_address(),
+        },
+        TraceEndpoint::Solana {
+            rpc_url: solana.url().to_string(),
+            program_id,
+        },
+    ])
+    .await
+    .unwrap();
+    assert!(tracer
+        .chains()
+        .contains(&(ChainId::from_u32(5), ChainKind::Solana)));
+
+    let deposit = evm_recipient_deposit(5, 3);
+    let signature = solana.emit_deposit(&deposit);
+    let hash = evm.submit_withdraw(&deposit).unwrap();
+
+    let trace = tracer
+        .trace(&TraceTarget::SourceTx(signature.to_string()))
+        .await
+        .unwrap();
+    assert_eq!(trace.xchain_hash_id, Some(hash));
+    assert_eq!(trace.hash_matches(), Some(true));
+    assert_eq!(trace.status(), TransferStatus::AwaitingApproval);
+
+    // Solana keeps no hash index for deposits; a bare hash still finds the withdrawal
+    let trace = tracer
+        .trace(&TraceTarget::XchainHashId(hash))
+        .await
+        .unwrap();
+    assert!(trace.deposit.is_none());
+    assert_eq!(trace.status(), TransferStatus::AwaitingApproval);
+
+    let unknown = tracer
+        .trace(&TraceTarget::XchainHashId([0xee; 32]))
+        .await
+        .unwrap();
+    assert_eq!(unknown.status(), TransferStatus::NotFound);
+}
@@ -1,4 +1,5 @@
-//! Fixed V2 xchain vectors shared with `HashLib.t.sol` and Solana `hash.rs` golden tests.
+//! Fixed V2 xchain vectors shared with `HashLib.t.sol`, pinned here independently of the
+//! generated `conformance/vectors.json` so a regenerated corpus cannot drift them silently.
 //! Confirms multichain-rs, CosmWasm `bridge`, and expected digests agree (no RPC / validator).
 
 use bridge::hash::compute_xchain_hash_id as cw_hash;
@@ -0,0 +1,27 @@
+-- Deposit-level policy decisions (see src/policy.rs)
+--
+-- One row per cross-chain transfer the operator evaluated before approving. Rows start as
+-- 'allowed' (approved automatically) or 'held' (waiting for manual review); a reviewer moves
+-- held rows to 'released' (approve on the next writer pass) or 'rejected' (never approve).
+-- 'allowed' and 'released' rows feed the per-account velocity windows.
+CREATE TABLE IF NOT EXISTS policy_transfers (
+    id BIGSERIAL PRIMARY KEY,
+    xchain_hash_id BYTEA NOT NULL UNIQUE,
+    src_chain BYTEA NOT NULL,
+    dest_chain BYTEA NOT NULL,
+    src_account BYTEA NOT NULL,
+    dest_account BYTEA NOT NULL,
+    token TEXT NOT NULL,
+    amount NUMERIC(78, 0) NOT NULL,
+    nonce BIGINT NOT NULL,
+    status VARCHAR(20) NOT NULL,
+    reasons TEXT NOT NULL DEFAULT '',
+    decided_by TEXT,
+    decision_note TEXT,
+    decided_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_policy_transfers_status ON policy_transfers(status);
+CREATE INDEX IF NOT EXISTS idx_policy_transfers_src_account ON policy_transfers(src_account, created_at);
+CREATE INDEX IF NOT EXISTS idx_policy_transfers_dest_account ON policy_transfers(dest_account, created_at);
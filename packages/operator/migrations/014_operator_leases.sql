@@ -0,0 +1,27 @@
+-- Active/passive operator HA (see src/leader.rs)
+--
+-- One row per lease. The instance named in `holder` runs the writers until `expires_at`;
+-- it renews well before then, and a standby takes the lease over once it has expired.
+-- Expiry is always compared against the database clock (NOW()), never an instance clock.
+--
+-- `generation` grows every time the lease starts a new term: another instance takes it over,
+-- or its holder re-takes it after it expired. Writers claim rows only while the lease still
+-- carries the generation they were elected with, so a deposed leader cannot claim work.
+-- Released leases are expired rather than deleted, so generations never restart.
+CREATE TABLE IF NOT EXISTS operator_leases (
+    name TEXT PRIMARY KEY,
+    holder TEXT NOT NULL,
+    generation BIGINT NOT NULL DEFAULT 1,
+    acquired_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    renewed_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    expires_at TIMESTAMPTZ NOT NULL
+);
+
+-- Writer claims on queued rows (taken with SELECT ... FOR UPDATE SKIP LOCKED). A claim
+-- older than the claim TTL is treated as abandoned so a crashed instance cannot strand rows.
+ALTER TABLE approvals ADD COLUMN IF NOT EXISTS claimed_by TEXT;
+ALTER TABLE approvals ADD COLUMN IF NOT EXISTS claimed_at TIMESTAMPTZ;
+ALTER TABLE releases ADD COLUMN IF NOT EXISTS claimed_by TEXT;
+ALTER TABLE releases ADD COLUMN IF NOT EXISTS claimed_at TIMESTAMPTZ;
+ALTER TABLE evm_deposits ADD COLUMN IF NOT EXISTS claimed_by TEXT;
+ALTER TABLE evm_deposits ADD COLUMN IF NOT EXISTS claimed_at TIMESTAMPTZ;
@@ -0,0 +1,45 @@
+-- Operator admin API (see src/api.rs, /admin/* endpoints)
+--
+-- Every control lives in the database so it applies to whichever instance holds the
+-- writer lease, whichever instance served the request.
+
+-- Routes (source chain -> destination chain) whose approvals are paused
+CREATE TABLE IF NOT EXISTS route_pauses (
+    src_chain BYTEA NOT NULL,
+    dest_chain BYTEA NOT NULL,
+    reason TEXT NOT NULL DEFAULT '',
+    paused_by TEXT NOT NULL,
+    paused_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (src_chain, dest_chain)
+);
+
+-- Transfers an operator resolved by hand; writers never approve them
+CREATE TABLE IF NOT EXISTS resolved_transfers (
+    xchain_hash_id BYTEA PRIMARY KEY,
+    resolved_by TEXT NOT NULL,
+    note TEXT,
+    resolved_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Writer circuit breakers ('evm', 'terra', 'evm_to_evm'). A writer is paused while
+-- paused_until is in the future; trips are automatic (consecutive failures) or manual.
+CREATE TABLE IF NOT EXISTS writer_breakers (
+    writer VARCHAR(32) PRIMARY KEY,
+    paused_until TIMESTAMPTZ,
+    reason TEXT NOT NULL DEFAULT '',
+    updated_by TEXT NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Who did what through the admin API
+CREATE TABLE IF NOT EXISTS admin_audit_log (
+    id BIGSERIAL PRIMARY KEY,
+    actor TEXT NOT NULL,
+    action VARCHAR(64) NOT NULL,
+    target TEXT NOT NULL,
+    details TEXT,
+    outcome TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_admin_audit_log_created_at ON admin_audit_log(created_at);
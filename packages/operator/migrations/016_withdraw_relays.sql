@@ -0,0 +1,31 @@
+-- Gasless withdrawals (see src/writers/relay.rs)
+--
+-- A depositor opts in by calling `requestRelay(xchainHashId, maxRelayFee)` on the source
+-- bridge (`request_relay` on Terra and Solana). The watchers record each request here; the
+-- destination writer then submits `withdrawSubmitRelayed` itself and recovers its gas from
+-- the bridged amount. Writers find the deposit behind a request by its transfer hash.
+CREATE TABLE IF NOT EXISTS relay_requests (
+    id BIGSERIAL PRIMARY KEY,
+    -- Native chain ID of an EVM source chain; NULL for Terra and Solana requests
+    src_chain_id BIGINT,
+    xchain_hash_id BYTEA NOT NULL UNIQUE,
+    -- V2 4-byte destination chain ID
+    dest_chain BYTEA NOT NULL,
+    -- Highest fee the depositor accepts, in the deposit's (source) decimals
+    max_relay_fee NUMERIC NOT NULL,
+    tx_hash TEXT NOT NULL,
+    block_number BIGINT NOT NULL,
+    -- 'pending', 'submitted', 'declined', 'skipped' or 'failed'
+    status TEXT NOT NULL DEFAULT 'pending',
+    relay_fee NUMERIC,
+    relay_tx_hash TEXT,
+    attempts INT NOT NULL DEFAULT 0,
+    last_error TEXT,
+    claimed_by TEXT,
+    claimed_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_relay_requests_pending
+    ON relay_requests (dest_chain, id) WHERE status = 'pending';
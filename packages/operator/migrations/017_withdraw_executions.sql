@@ -0,0 +1,29 @@
+-- Durable auto-execution of approved withdrawals (see src/writers/execution.rs)
+--
+-- A writer schedules a row when it approves a withdrawal on its chain. Once
+-- `executable_at` passes (approval + cancel window), the writer for `dest_chain`
+-- claims the row and submits the execute transaction, so approvals waiting out
+-- their cancel window survive operator restarts and leader failover.
+CREATE TABLE IF NOT EXISTS withdraw_executions (
+    id BIGSERIAL PRIMARY KEY,
+    -- 'evm', 'terra' or 'solana'
+    chain_type TEXT NOT NULL,
+    -- V2 4-byte chain ID of the chain the withdrawal executes on
+    dest_chain BYTEA NOT NULL,
+    xchain_hash_id BYTEA NOT NULL,
+    executable_at TIMESTAMPTZ NOT NULL,
+    -- 'pending', 'executed', 'cancelled' or 'failed'
+    status TEXT NOT NULL DEFAULT 'pending',
+    attempts INT NOT NULL DEFAULT 0,
+    last_error TEXT,
+    -- Execute transaction; NULL when the recipient executed it themselves
+    tx_hash TEXT,
+    claimed_by TEXT,
+    claimed_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (dest_chain, xchain_hash_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_withdraw_executions_due
+    ON withdraw_executions (dest_chain, executable_at) WHERE status = 'pending';
@@ -5,17 +5,27 @@
 //! - GET /metrics - Prometheus metrics (public)
 //! - GET /status - Queue counts, uptime, chain sync status (auth-gated when OPERATOR_API_TOKEN set)
 //! - GET /pending - List pending transactions (auth-gated when OPERATOR_API_TOKEN set)
+//! - GET /held - Transfers held for review by the deposit policy (auth-gated when OPERATOR_API_TOKEN set)
+//! - POST /held/{hash}/release, POST /held/{hash}/reject - Review decisions (admin scope)
+//! - /admin/* - Incident controls: route pauses, forced retries, manual resolution, re-scans,
+//!   writer circuit breakers and the audit log (admin scope)
+//!
+//! Two token scopes: `OPERATOR_API_TOKEN` reads; admin tokens (`OPERATOR_ADMIN_TOKENS` as
+//! `name=token,...`, or a single `OPERATOR_ADMIN_TOKEN`) also mutate. Until an admin token is
+//! set the admin-scope endpoints return 403. Every admin action is written to
+//! `admin_audit_log` under the token's name.
 
 use axum::{
-    extract::State,
+    body::Bytes,
+    extract::{Path, State},
     http::{header, HeaderMap, StatusCode},
     response::{IntoResponse, Response},
-    routing::get,
+    routing::{get, post},
     Json, Router,
 };
 use eyre::Result;
 use prometheus::{Encoder, TextEncoder};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
 use std::net::SocketAddr;
 use std::sync::Arc;
@@ -23,20 +33,29 @@ use std::time::Instant;
 use tower_governor::{governor::GovernorConfigBuilder, GovernorLayer};
 
 use crate::db;
+use crate::leader::LeaderHandle;
 use crate::metrics;
+use crate::types::ChainId;
+use crate::writers::BREAKER_WRITERS;
 
 /// Shared application state for all handlers.
 #[derive(Clone)]
 struct AppState {
     db: PgPool,
     api_token: Option<Arc<str>>,
+    /// Admin-scope tokens as (actor name, token)
+    admin_tokens: Arc<Vec<(String, String)>>,
     start_time: Instant,
+    leader: LeaderHandle,
 }
 
 /// Status response
 #[derive(Serialize)]
 struct StatusResponse {
     status: String,
+    /// `leader` or `standby` (operator HA)
+    role: String,
+    instance: String,
     uptime_seconds: u64,
     queues: QueueStatus,
 }
@@ -48,6 +67,7 @@ struct QueueStatus {
     pending_releases: i64,
     submitted_approvals: i64,
     submitted_releases: i64,
+    held_transfers: i64,
 }
 
 /// Pending transactions response
@@ -75,8 +95,87 @@ struct ReleaseInfo {
     status: String,
 }
 
+/// Transfer held by the deposit policy
+#[derive(Serialize)]
+struct HeldTransferInfo {
+    xchain_hash_id: String,
+    src_chain: String,
+    dest_chain: String,
+    src_account: String,
+    dest_account: String,
+    token: String,
+    amount: String,
+    nonce: i64,
+    reasons: String,
+    held_at: String,
+}
+
+/// Optional body of a release/reject request
+#[derive(Default, Deserialize)]
+struct ReviewRequest {
+    reviewer: Option<String>,
+    note: Option<String>,
+}
+
+/// Optional body of admin actions that take a free-form note
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct AdminNote {
+    note: Option<String>,
+}
+
+/// Body of `POST /admin/breakers/{writer}/trip`
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TripRequest {
+    /// Defaults to the automatic pause (5 minutes)
+    duration_secs: Option<u64>,
+    note: Option<String>,
+}
+
+/// Body of `POST /admin/rescan`
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RescanRequest {
+    /// "evm" or "terra"
+    chain: String,
+    /// Native EVM chain ID ("56") or Cosmos chain ID ("columbus-5")
+    chain_id: String,
+    from_block: u64,
+}
+
+#[derive(Serialize)]
+struct RoutePauseInfo {
+    src_chain: String,
+    dest_chain: String,
+    reason: String,
+    paused_by: String,
+    paused_at: String,
+}
+
+#[derive(Serialize)]
+struct BreakerInfo {
+    writer: String,
+    open: bool,
+    paused_until: Option<String>,
+    reason: String,
+    updated_by: String,
+    updated_at: String,
+}
+
+#[derive(Serialize)]
+struct AuditInfo {
+    id: i64,
+    actor: String,
+    action: String,
+    target: String,
+    details: Option<String>,
+    outcome: String,
+    at: String,
+}
+
 /// Start the API server using axum.
-pub async fn start_api_server(addr: SocketAddr, db: PgPool) -> Result<()> {
+pub async fn start_api_server(addr: SocketAddr, db: PgPool, leader: LeaderHandle) -> Result<()> {
     // Load optional bearer token for auth-gated endpoints (/status, /pending).
     // When set, requests to those endpoints must include `Authorization: Bearer <token>`.
     let api_token: Option<Arc<str>> = std::env::var("OPERATOR_API_TOKEN")
@@ -88,6 +187,21 @@ pub async fn start_api_server(addr: SocketAddr, db: PgPool) -> Result<()> {
         tracing::info!("OPERATOR_API_TOKEN set — /status and /pending require authentication");
     }
 
+    let mut admin_tokens = match std::env::var("OPERATOR_ADMIN_TOKENS") {
+        Ok(raw) if !raw.trim().is_empty() => parse_admin_tokens(&raw)?,
+        _ => Vec::new(),
+    };
+    if let Ok(token) = std::env::var("OPERATOR_ADMIN_TOKEN") {
+        if !token.is_empty() {
+            admin_tokens.push(("admin".to_string(), token));
+        }
+    }
+    if admin_tokens.is_empty() {
+        tracing::warn!("No OPERATOR_ADMIN_TOKEN(S) set — admin endpoints are disabled");
+    } else {
+        tracing::info!(tokens = admin_tokens.len(), "Admin API enabled");
+    }
+
     // Rate limiting (configurable via env)
     let rate_per_second: u64 = std::env::var("RATE_LIMIT_PER_SECOND")
         .ok()
@@ -116,7 +230,9 @@ pub async fn start_api_server(addr: SocketAddr, db: PgPool) -> Result<()> {
     let state = AppState {
         db,
         api_token,
+        admin_tokens: Arc::new(admin_tokens),
         start_time: Instant::now(),
+        leader,
     };
 
     let app = Router::new()
@@ -124,6 +240,41 @@ pub async fn start_api_server(addr: SocketAddr, db: PgPool) -> Result<()> {
         .route("/metrics", get(metrics_handler))
         .route("/status", get(status_handler))
         .route("/pending", get(pending_handler))
+        .route("/held", get(held_handler))
+        .route("/held/{hash}/release", post(release_held_handler))
+        .route("/held/{hash}/reject", post(reject_held_handler))
+        .route("/admin/routes", get(admin_routes_handler))
+        .route(
+            "/admin/routes/{src}/{dest}/pause",
+            post(admin_pause_route_handler),
+        )
+        .route(
+            "/admin/routes/{src}/{dest}/resume",
+            post(admin_resume_route_handler),
+        )
+        .route(
+            "/admin/approvals/{id}/retry",
+            post(admin_retry_approval_handler),
+        )
+        .route(
+            "/admin/releases/{id}/retry",
+            post(admin_retry_release_handler),
+        )
+        .route(
+            "/admin/transfers/{hash}/resolve",
+            post(admin_resolve_handler),
+        )
+        .route("/admin/rescan", post(admin_rescan_handler))
+        .route("/admin/breakers", get(admin_breakers_handler))
+        .route(
+            "/admin/breakers/{writer}/trip",
+            post(admin_trip_breaker_handler),
+        )
+        .route(
+            "/admin/breakers/{writer}/reset",
+            post(admin_reset_breaker_handler),
+        )
+        .route("/admin/audit", get(admin_audit_handler))
         .with_state(state)
         .layer(GovernorLayer::new(governor_conf));
 
@@ -178,7 +329,7 @@ async fn metrics_handler() -> Response {
 
 /// Status — auth-gated when OPERATOR_API_TOKEN is set.
 async fn status_handler(State(state): State<AppState>, headers: HeaderMap) -> Response {
-    if !check_auth(&headers, state.api_token.as_deref()) {
+    if !read_allowed(&state, &headers) {
         return StatusCode::UNAUTHORIZED.into_response();
     }
 
@@ -190,10 +341,15 @@ async fn status_handler(State(state): State<AppState>, headers: HeaderMap) -> Re
         pending_releases: db::count_pending_releases(&state.db).await.unwrap_or(0),
         submitted_approvals: db::count_submitted_approvals(&state.db).await.unwrap_or(0),
         submitted_releases: db::count_submitted_releases(&state.db).await.unwrap_or(0),
+        held_transfers: db::count_held_policy_transfers(&state.db)
+            .await
+            .unwrap_or(0),
     };
 
     let status = StatusResponse {
         status: "ok".to_string(),
+        role: state.leader.role().to_string(),
+        instance: crate::leader::instance_id().to_string(),
         uptime_seconds: uptime,
         queues,
     };
@@ -203,7 +359,7 @@ async fn status_handler(State(state): State<AppState>, headers: HeaderMap) -> Re
 
 /// Pending transactions — auth-gated when OPERATOR_API_TOKEN is set.
 async fn pending_handler(State(state): State<AppState>, headers: HeaderMap) -> Response {
-    if !check_auth(&headers, state.api_token.as_deref()) {
+    if !read_allowed(&state, &headers) {
         return StatusCode::UNAUTHORIZED.into_response();
     }
 
@@ -241,6 +397,552 @@ async fn pending_handler(State(state): State<AppState>, headers: HeaderMap) -> R
     Json(pending).into_response()
 }
 
+/// Transfers held for manual review — auth-gated when OPERATOR_API_TOKEN is set.
+async fn held_handler(State(state): State<AppState>, headers: HeaderMap) -> Response {
+    if !read_allowed(&state, &headers) {
+        return StatusCode::UNAUTHORIZED.into_response();
+    }
+
+    let held: Vec<HeldTransferInfo> = db::get_held_policy_transfers(&state.db, 100, 0)
+        .await
+        .unwrap_or_default()
+        .into_iter()
+        .map(|t| HeldTransferInfo {
+            xchain_hash_id: format!("0x{}", hex::encode(&t.xchain_hash_id)),
+            src_chain: format!("0x{}", hex::encode(&t.src_chain)),
+            dest_chain: format!("0x{}", hex::encode(&t.dest_chain)),
+            src_account: format!("0x{}", hex::encode(&t.src_account)),
+            dest_account: format!("0x{}", hex::encode(&t.dest_account)),
+            token: t.token,
+            amount: t.amount,
+            nonce: t.nonce,
+            reasons: t.reasons,
+            held_at: t.created_at.to_rfc3339(),
+        })
+        .collect();
+
+    Json(held).into_response()
+}
+
+/// Release a held transfer; writers approve it on their next pass.
+async fn release_held_handler(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(hash): Path<String>,
+    body: Bytes,
+) -> Response {
+    decide_held(&state, &headers, &hash, &body, "released", "pending").await
+}
+
+/// Reject a held transfer; it is never approved.
+async fn reject_held_handler(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(hash): Path<String>,
+    body: Bytes,
+) -> Response {
+    decide_held(&state, &headers, &hash, &body, "rejected", "rejected").await
+}
+
+/// Record a review decision. Requires the admin scope, so an unauthenticated API cannot
+/// release held funds.
+async fn decide_held(
+    state: &AppState,
+    headers: &HeaderMap,
+    hash: &str,
+    body: &[u8],
+    status: &str,
+    deposit_status: &str,
+) -> Response {
+    let actor = match admin_actor(state, headers) {
+        Ok(actor) => actor,
+        Err(resp) => return resp,
+    };
+
+    let Some(xchain_hash_id) = parse_hash(hash) else {
+        return (
+            StatusCode::BAD_REQUEST,
+            "hash must be 0x-prefixed bytes32 hex",
+        )
+            .into_response();
+    };
+    let review: ReviewRequest = if body.is_empty() {
+        ReviewRequest::default()
+    } else {
+        match serde_json::from_slice(body) {
+            Ok(r) => r,
+            Err(e) => return (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+        }
+    };
+    let reviewer = review.reviewer.as_deref().unwrap_or(&actor);
+
+    match db::decide_held_policy_transfer(
+        &state.db,
+        &xchain_hash_id,
+        status,
+        reviewer,
+        review.note.as_deref(),
+    )
+    .await
+    {
+        Ok(true) => {}
+        Ok(false) => {
+            return (StatusCode::NOT_FOUND, "no held transfer with this hash").into_response()
+        }
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to record held transfer decision");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    }
+
+    let action = if status == "released" {
+        "release_held"
+    } else {
+        "reject_held"
+    };
+    audit(state, &actor, action, hash, review.note.as_deref(), "ok").await;
+
+    if let Err(e) =
+        db::set_deposit_status_for_hash(&state.db, &xchain_hash_id, "held", deposit_status).await
+    {
+        tracing::warn!(error = %e, "Failed to update deposit rows after review decision");
+    }
+    metrics::record_policy_decision(status);
+    tracing::info!(
+        hash = %hash,
+        decision = status,
+        reviewer = reviewer,
+        note = review.note.as_deref().unwrap_or(""),
+        "Held transfer reviewed"
+    );
+
+    Json(serde_json::json!({ "xchain_hash_id": hash, "status": status })).into_response()
+}
+
+fn parse_hash(s: &str) -> Option<[u8; 32]> {
+    let raw = hex::decode(s.strip_prefix("0x").unwrap_or(s)).ok()?;
+    raw.try_into().ok()
+}
+
+/// V2 chain ID from `0x`-prefixed bytes4 hex or a decimal number
+fn parse_chain(s: &str) -> Option<ChainId> {
+    if s.starts_with("0x") {
+        ChainId::from_hex(s).ok()
+    } else {
+        s.parse::<u32>().ok().map(ChainId::from_u32)
+    }
+}
+
+/// Parse an optional JSON body; an empty body is the type's default
+#[allow(clippy::result_large_err)]
+fn parse_body<T: Default + serde::de::DeserializeOwned>(body: &[u8]) -> Result<T, Response> {
+    if body.is_empty() {
+        return Ok(T::default());
+    }
+    serde_json::from_slice(body)
+        .map_err(|e| (StatusCode::BAD_REQUEST, e.to_string()).into_response())
+}
+
+// ─── Admin ──────────────────────────────────────────────────────────────────
+
+/// Write an audit entry; failures are logged, the action itself has already happened
+async fn audit(
+    state: &AppState,
+    actor: &str,
+    action: &str,
+    target: &str,
+    details: Option<&str>,
+    outcome: &str,
+) {
+    metrics::record_admin_action(action);
+    tracing::info!(
+        actor = actor,
+        action = action,
+        target = target,
+        outcome = outcome,
+        "Admin action"
+    );
+    if let Err(e) = db::insert_admin_audit(&state.db, actor, action, target, details, outcome).await
+    {
+        tracing::error!(error = %e, action = action, "Failed to write admin audit log");
+    }
+}
+
+/// Map a DB result to a response, auditing the outcome
+async fn finish_admin(
+    state: &AppState,
+    actor: &str,
+    action: &str,
+    target: &str,
+    details: Option<&str>,
+    result: eyre::Result<bool>,
+) -> Response {
+    match result {
+        Ok(true) => {
+            audit(state, actor, action, target, details, "ok").await;
+            Json(serde_json::json!({ "action": action, "target": target, "status": "ok" }))
+                .into_response()
+        }
+        Ok(false) => {
+            audit(state, actor, action, target, details, "not_found").await;
+            (
+                StatusCode::NOT_FOUND,
+                format!("{}: nothing to do for {}", action, target),
+            )
+                .into_response()
+        }
+        Err(e) => {
+            audit(state, actor, action, target, details, &e.to_string()).await;
+            tracing::error!(error = %e, action = action, "Admin action failed");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+fn bad_request(msg: &str) -> Response {
+    (StatusCode::BAD_REQUEST, msg.to_string()).into_response()
+}
+
+/// List paused routes.
+async fn admin_routes_handler(State(state): State<AppState>, headers: HeaderMap) -> Response {
+    if let Err(resp) = admin_actor(&state, &headers) {
+        return resp;
+    }
+    match db::get_route_pauses(&state.db).await {
+        Ok(rows) => Json(
+            rows.into_iter()
+                .map(|r| RoutePauseInfo {
+                    src_chain: format!("0x{}", hex::encode(&r.src_chain)),
+                    dest_chain: format!("0x{}", hex::encode(&r.dest_chain)),
+                    reason: r.reason,
+                    paused_by: r.paused_by,
+                    paused_at: r.paused_at.to_rfc3339(),
+                })
+                .collect::<Vec<_>>(),
+        )
+        .into_response(),
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to list paused routes");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Pause approvals from `src` to `dest` on every writer.
+async fn admin_pause_route_handler(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path((src, dest)): Path<(String, String)>,
+    body: Bytes,
+) -> Response {
+    let actor = match admin_actor(&state, &headers) {
+        Ok(actor) => actor,
+        Err(resp) => return resp,
+    };
+    let (Some(src_chain), Some(dest_chain)) = (parse_chain(&src), parse_chain(&dest)) else {
+        return bad_request("chains must be 0x-prefixed bytes4 hex or decimal V2 chain IDs");
+    };
+    let req: AdminNote = match parse_body(&body) {
+        Ok(r) => r,
+        Err(resp) => return resp,
+    };
+    let target = format!("{}->{}", src_chain.to_hex(), dest_chain.to_hex());
+    let result = db::pause_route(
+        &state.db,
+        src_chain.as_bytes(),
+        dest_chain.as_bytes(),
+        req.note.as_deref().unwrap_or(""),
+        &actor,
+    )
+    .await
+    .map(|()| true);
+    finish_admin(
+        &state,
+        &actor,
+        "pause_route",
+        &target,
+        req.note.as_deref(),
+        result,
+    )
+    .await
+}
+
+/// Resume a paused route.
+async fn admin_resume_route_handler(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path((src, dest)): Path<(String, String)>,
+    body: Bytes,
+) -> Response {
+    let actor = match admin_actor(&state, &headers) {
+        Ok(actor) => actor,
+        Err(resp) => return resp,
+    };
+    let (Some(src_chain), Some(dest_chain)) = (parse_chain(&src), parse_chain(&dest)) else {
+        return bad_request("chains must be 0x-prefixed bytes4 hex or decimal V2 chain IDs");
+    };
+    let req: AdminNote = match parse_body(&body) {
+        Ok(r) => r,
+        Err(resp) => return resp,
+    };
+    let target = format!("{}->{}", src_chain.to_hex(), dest_chain.to_hex());
+    let result = db::resume_route(&state.db, src_chain.as_bytes(), dest_chain.as_bytes()).await;
+    finish_admin(
+        &state,
+        &actor,
+        "resume_route",
+        &target,
+        req.note.as_deref(),
+        result,
+    )
+    .await
+}
+
+/// Return a failed approval to the queue immediately, ignoring its attempt count.
+async fn admin_retry_approval_handler(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+    body: Bytes,
+) -> Response {
+    let actor = match admin_actor(&state, &headers) {
+        Ok(actor) => actor,
+        Err(resp) => return resp,
+    };
+    let req: AdminNote = match parse_body(&body) {
+        Ok(r) => r,
+        Err(resp) => return resp,
+    };
+    let result = db::force_retry_approval(&state.db, id).await;
+    finish_admin(
+        &state,
+        &actor,
+        "retry_approval",
+        &id.to_string(),
+        req.note.as_deref(),
+        result,
+    )
+    .await
+}
+
+/// Return a failed release to the queue immediately, ignoring its attempt count.
+async fn admin_retry_release_handler(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+    body: Bytes,
+) -> Response {
+    let actor = match admin_actor(&state, &headers) {
+        Ok(actor) => actor,
+        Err(resp) => return resp,
+    };
+    let req: AdminNote = match parse_body(&body) {
+        Ok(r) => r,
+        Err(resp) => return resp,
+    };
+    let result = db::force_retry_release(&state.db, id).await;
+    finish_admin(
+        &state,
+        &actor,
+        "retry_release",
+        &id.to_string(),
+        req.note.as_deref(),
+        result,
+    )
+    .await
+}
+
+/// Mark a transfer as resolved by hand: writers stop approving it and its deposit rows
+/// move to 'resolved'.
+async fn admin_resolve_handler(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(hash): Path<String>,
+    body: Bytes,
+) -> Response {
+    let actor = match admin_actor(&state, &headers) {
+        Ok(actor) => actor,
+        Err(resp) => return resp,
+    };
+    let Some(xchain_hash_id) = parse_hash(&hash) else {
+        return bad_request("hash must be 0x-prefixed bytes32 hex");
+    };
+    let req: AdminNote = match parse_body(&body) {
+        Ok(r) => r,
+        Err(resp) => return resp,
+    };
+    // Resolving an unknown hash is still recorded: the deposit may not be indexed yet
+    let result = db::resolve_transfer(&state.db, &xchain_hash_id, &actor, req.note.as_deref())
+        .await
+        .map(|_| true);
+    finish_admin(
+        &state,
+        &actor,
+        "resolve_transfer",
+        &hash,
+        req.note.as_deref(),
+        result,
+    )
+    .await
+}
+
+/// Rewind a watcher cursor so the next poll re-scans from `from_block`.
+async fn admin_rescan_handler(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response {
+    let actor = match admin_actor(&state, &headers) {
+        Ok(actor) => actor,
+        Err(resp) => return resp,
+    };
+    let req: RescanRequest = match serde_json::from_slice(&body) {
+        Ok(r) => r,
+        Err(e) => return bad_request(&e.to_string()),
+    };
+    if req.chain != "evm" && req.chain != "terra" {
+        return bad_request("chain must be \"evm\" or \"terra\"");
+    }
+    let Ok(from_block) = i64::try_from(req.from_block) else {
+        return bad_request("from_block out of range");
+    };
+    if req.chain == "evm" && req.chain_id.parse::<i64>().is_err() {
+        return bad_request("EVM chain_id must be the native numeric chain ID");
+    }
+    let target = format!("{}:{}", req.chain, req.chain_id);
+    let details = format!("from_block={}", req.from_block);
+    let result = db::rewind_block_cursor(&state.db, &req.chain, &req.chain_id, from_block).await;
+    finish_admin(&state, &actor, "rescan", &target, Some(&details), result).await
+}
+
+/// Writer circuit breaker states.
+async fn admin_breakers_handler(State(state): State<AppState>, headers: HeaderMap) -> Response {
+    if let Err(resp) = admin_actor(&state, &headers) {
+        return resp;
+    }
+    let now = chrono::Utc::now();
+    match db::get_writer_breakers(&state.db).await {
+        Ok(rows) => Json(
+            rows.into_iter()
+                .map(|b| BreakerInfo {
+                    open: b.paused_until.is_some_and(|t| t > now),
+                    paused_until: b.paused_until.map(|t| t.to_rfc3339()),
+                    writer: b.writer,
+                    reason: b.reason,
+                    updated_by: b.updated_by,
+                    updated_at: b.updated_at.to_rfc3339(),
+                })
+                .collect::<Vec<_>>(),
+        )
+        .into_response(),
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to list writer breakers");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Open a writer's circuit breaker (pause it) for `duration_secs`.
+async fn admin_trip_breaker_handler(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(writer): Path<String>,
+    body: Bytes,
+) -> Response {
+    let actor = match admin_actor(&state, &headers) {
+        Ok(actor) => actor,
+        Err(resp) => return resp,
+    };
+    if !BREAKER_WRITERS.contains(&writer.as_str()) {
+        return bad_request("writer must be one of evm, terra, evm_to_evm");
+    }
+    let req: TripRequest = match parse_body(&body) {
+        Ok(r) => r,
+        Err(resp) => return resp,
+    };
+    let secs = req.duration_secs.unwrap_or(
+        crate::writers::CircuitBreakerConfig::default()
+            .pause_duration
+            .as_secs(),
+    );
+    let Ok(secs) = i64::try_from(secs) else {
+        return bad_request("duration_secs out of range");
+    };
+    let reason = req.note.as_deref().unwrap_or("manual");
+    let details = format!("duration_secs={} note={}", secs, reason);
+    let result = db::trip_writer_breaker(&state.db, &writer, secs, reason, &actor)
+        .await
+        .map(|()| true);
+    finish_admin(
+        &state,
+        &actor,
+        "trip_breaker",
+        &writer,
+        Some(&details),
+        result,
+    )
+    .await
+}
+
+/// Close a writer's circuit breaker so it resumes on the next cycle.
+async fn admin_reset_breaker_handler(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(writer): Path<String>,
+    body: Bytes,
+) -> Response {
+    let actor = match admin_actor(&state, &headers) {
+        Ok(actor) => actor,
+        Err(resp) => return resp,
+    };
+    if !BREAKER_WRITERS.contains(&writer.as_str()) {
+        return bad_request("writer must be one of evm, terra, evm_to_evm");
+    }
+    let req: AdminNote = match parse_body(&body) {
+        Ok(r) => r,
+        Err(resp) => return resp,
+    };
+    let result = db::reset_writer_breaker(&state.db, &writer, &actor)
+        .await
+        .map(|()| true);
+    finish_admin(
+        &state,
+        &actor,
+        "reset_breaker",
+        &writer,
+        req.note.as_deref(),
+        result,
+    )
+    .await
+}
+
+/// Most recent 100 admin actions.
+async fn admin_audit_handler(State(state): State<AppState>, headers: HeaderMap) -> Response {
+    if let Err(resp) = admin_actor(&state, &headers) {
+        return resp;
+    }
+    match db::get_admin_audit(&state.db, 100).await {
+        Ok(rows) => Json(
+            rows.into_iter()
+                .map(|a| AuditInfo {
+                    id: a.id,
+                    actor: a.actor,
+                    action: a.action,
+                    target: a.target,
+                    details: a.details,
+                    outcome: a.outcome,
+                    at: a.created_at.to_rfc3339(),
+                })
+                .collect::<Vec<_>>(),
+        )
+        .into_response(),
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to read admin audit log");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
 // ─── Auth ───────────────────────────────────────────────────────────────────
 
 /// Check Authorization header using axum's typed `HeaderMap`.
@@ -254,17 +956,70 @@ fn check_auth(headers: &HeaderMap, required_token: Option<&str>) -> bool {
         _ => return true,
     };
 
-    match headers.get(header::AUTHORIZATION) {
-        Some(value) => match value.to_str() {
-            Ok(value_str) => value_str
-                .strip_prefix("Bearer ")
-                .or_else(|| value_str.strip_prefix("bearer "))
-                .map(|t| t.trim() == token)
-                .unwrap_or(false),
-            Err(_) => false,
-        },
-        None => false,
+    bearer_token(headers).is_some_and(|t| t == token)
+}
+
+/// The `Authorization: Bearer <token>` value, if present
+fn bearer_token(headers: &HeaderMap) -> Option<&str> {
+    let value = headers.get(header::AUTHORIZATION)?.to_str().ok()?;
+    value
+        .strip_prefix("Bearer ")
+        .or_else(|| value.strip_prefix("bearer "))
+        .map(str::trim)
+}
+
+/// Read scope: the read token or any admin token
+fn read_allowed(state: &AppState, headers: &HeaderMap) -> bool {
+    check_auth(headers, state.api_token.as_deref())
+        || find_admin(headers, &state.admin_tokens).is_some()
+}
+
+/// Name of the admin token presented, if any
+fn find_admin<'a>(headers: &HeaderMap, tokens: &'a [(String, String)]) -> Option<&'a str> {
+    let presented = bearer_token(headers)?;
+    tokens
+        .iter()
+        .find(|(_, token)| token == presented)
+        .map(|(name, _)| name.as_str())
+}
+
+/// Admin scope: returns the actor name for the audit log, or the error response.
+/// Refuses to run when no admin token is configured at all.
+#[allow(clippy::result_large_err)]
+fn admin_actor(state: &AppState, headers: &HeaderMap) -> Result<String, Response> {
+    if state.admin_tokens.is_empty() {
+        return Err((
+            StatusCode::FORBIDDEN,
+            "OPERATOR_ADMIN_TOKEN(S) must be set for admin endpoints",
+        )
+            .into_response());
+    }
+    find_admin(headers, &state.admin_tokens)
+        .map(str::to_string)
+        .ok_or_else(|| StatusCode::UNAUTHORIZED.into_response())
+}
+
+/// Parse `OPERATOR_ADMIN_TOKENS`: comma-separated `name=token` pairs
+fn parse_admin_tokens(raw: &str) -> Result<Vec<(String, String)>> {
+    let mut tokens = Vec::new();
+    for entry in raw.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+        let (name, token) = entry
+            .split_once('=')
+            .ok_or_else(|| eyre::eyre!("OPERATOR_ADMIN_TOKENS entries must be name=token"))?;
+        let (name, token) = (name.trim(), token.trim());
+        if name.is_empty() || token.is_empty() {
+            return Err(eyre::eyre!(
+                "OPERATOR_ADMIN_TOKENS entries need a non-empty name and token"
+            ));
+        }
+        if tokens.iter().any(|(_, t)| t == token) {
+            return Err(eyre::eyre!(
+                "OPERATOR_ADMIN_TOKENS contains the same token twice"
+            ));
+        }
+        tokens.push((name.to_string(), token.to_string()));
     }
+    Ok(tokens)
 }
 
 #[cfg(test)]
@@ -315,6 +1070,52 @@ mod tests {
         assert!(!check_auth(&headers, Some("my-secret")));
     }
 
+    #[test]
+    fn test_parse_hash() {
+        let hex_hash = format!("0x{}", "ab".repeat(32));
+        assert_eq!(parse_hash(&hex_hash), Some([0xAB; 32]));
+        assert_eq!(parse_hash(&"ab".repeat(32)), Some([0xAB; 32]));
+        assert_eq!(parse_hash("0x1234"), None);
+        assert_eq!(parse_hash("0xzz"), None);
+    }
+
+    #[test]
+    fn test_parse_chain() {
+        assert_eq!(parse_chain("0x00000038"), Some(ChainId::from_u32(56)));
+        assert_eq!(parse_chain("56"), Some(ChainId::from_u32(56)));
+        assert_eq!(parse_chain("0x38"), None);
+        assert_eq!(parse_chain("bsc"), None);
+    }
+
+    #[test]
+    fn test_parse_admin_tokens() {
+        let tokens = parse_admin_tokens("alice=t1, bob = t2,").unwrap();
+        assert_eq!(
+            tokens,
+            vec![
+                ("alice".to_string(), "t1".to_string()),
+                ("bob".to_string(), "t2".to_string())
+            ]
+        );
+        assert!(parse_admin_tokens("alice").is_err());
+        assert!(parse_admin_tokens("=t1").is_err());
+        assert!(parse_admin_tokens("alice=t1,bob=t1").is_err());
+    }
+
+    #[test]
+    fn test_find_admin() {
+        let tokens = vec![
+            ("alice".to_string(), "t1".to_string()),
+            ("bob".to_string(), "t2".to_string()),
+        ];
+        let mut headers = HeaderMap::new();
+        assert_eq!(find_admin(&headers, &tokens), None);
+        headers.insert(header::AUTHORIZATION, HeaderValue::from_static("Bearer t2"));
+        assert_eq!(find_admin(&headers, &tokens), Some("bob"));
+        headers.insert(header::AUTHORIZATION, HeaderValue::from_static("Bearer t3"));
+        assert_eq!(find_admin(&headers, &tokens), None);
+    }
+
     #[test]
     fn test_check_auth_no_bearer_prefix() {
         let mut headers = HeaderMap::new();
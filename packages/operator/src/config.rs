@@ -21,6 +21,37 @@ fn normalize_solana_secret_env(s: &str) -> &str {
     s
 }
 
+/// Split a comma-separated key list (`*_EXTRA_PRIVATE_KEYS`, `TERRA_EXTRA_MNEMONICS`).
+/// Commas inside `[...]` belong to a JSON byte-array key and do not split.
+pub fn split_key_list(raw: &str) -> Vec<String> {
+    let mut keys = Vec::new();
+    let mut depth = 0usize;
+    let mut current = String::new();
+    for c in raw.chars() {
+        match c {
+            '[' => depth += 1,
+            ']' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                keys.push(std::mem::take(&mut current));
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    keys.push(current);
+    keys.into_iter()
+        .map(|k| k.trim().to_string())
+        .filter(|k| !k.is_empty())
+        .collect()
+}
+
+fn extra_keys_from_env(var: &str) -> Vec<String> {
+    env::var(var)
+        .map(|v| split_key_list(&v))
+        .unwrap_or_default()
+}
+
 /// Parse `SOLANA_PRIVATE_KEY`: base58, JSON `[u8,...]` (`solana-keygen`/Anchor), or hex (`0x` + 128 nibbles / 64 nibbles seed).
 pub fn parse_solana_private_key(raw: &str) -> eyre::Result<solana_sdk::signature::Keypair> {
     use solana_sdk::signature::Keypair;
@@ -138,6 +169,9 @@ pub struct EvmConfig {
     pub chain_id: u64,
     pub bridge_address: String,
     pub private_key: String,
+    /// Further operator keys signing next to `private_key` (see [`crate::writers::signer_pool`])
+    #[serde(default)]
+    pub extra_private_keys: Vec<String>,
     #[serde(default = "default_finality_blocks_for_deserialize")]
     pub finality_blocks: u64,
     /// This chain's registered chain ID (4-byte V2 format)
@@ -159,6 +193,10 @@ impl fmt::Debug for EvmConfig {
             .field("chain_id", &self.chain_id)
             .field("bridge_address", &self.bridge_address)
             .field("private_key", &"<redacted>")
+            .field(
+                "extra_private_keys",
+                &format!("<{} redacted>", self.extra_private_keys.len()),
+            )
             .field("finality_blocks", &self.finality_blocks)
             .field("this_chain_id", &self.this_chain_id)
             .field("use_v2_events", &self.use_v2_events)
@@ -173,6 +211,12 @@ impl EvmConfig {
         urls.extend(self.rpc_fallback_urls.iter().cloned());
         urls
     }
+
+    /// All signer keys: `private_key` followed by `extra_private_keys`
+    pub fn signer_keys(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.private_key.as_str())
+            .chain(self.extra_private_keys.iter().map(String::as_str))
+    }
 }
 
 /// Terra configuration
@@ -183,6 +227,9 @@ pub struct TerraConfig {
     pub chain_id: String,
     pub bridge_address: String,
     pub mnemonic: String,
+    /// Further operator mnemonics signing next to `mnemonic`
+    #[serde(default)]
+    pub extra_mnemonics: Vec<String>,
     /// Optional fee recipient address for Terra withdrawals
     #[serde(default)]
     pub fee_recipient: Option<String>,
@@ -201,12 +248,24 @@ impl fmt::Debug for TerraConfig {
             .field("chain_id", &self.chain_id)
             .field("bridge_address", &self.bridge_address)
             .field("mnemonic", &"<redacted>")
+            .field(
+                "extra_mnemonics",
+                &format!("<{} redacted>", self.extra_mnemonics.len()),
+            )
             .field("fee_recipient", &self.fee_recipient)
             .field("this_chain_id", &self.this_chain_id)
             .finish()
     }
 }
 
+impl TerraConfig {
+    /// All signer mnemonics: `mnemonic` followed by `extra_mnemonics`
+    pub fn signer_mnemonics(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.mnemonic.as_str())
+            .chain(self.extra_mnemonics.iter().map(String::as_str))
+    }
+}
+
 /// Solana chain configuration (optional — operator runs without Solana if unset)
 #[derive(Clone)]
 pub struct SolanaConfig {
@@ -368,6 +427,7 @@ impl Config {
                 .map_err(|_| eyre!("EVM_BRIDGE_ADDRESS environment variable is required"))?,
             private_key: env::var("EVM_PRIVATE_KEY")
                 .map_err(|_| eyre!("EVM_PRIVATE_KEY environment variable is required"))?,
+            extra_private_keys: extra_keys_from_env("EVM_EXTRA_PRIVATE_KEYS"),
             finality_blocks: env::var("FINALITY_BLOCKS")
                 .ok()
                 .and_then(|v| v.parse().ok())
@@ -397,6 +457,7 @@ impl Config {
                 .map_err(|_| eyre!("TERRA_BRIDGE_ADDRESS environment variable is required"))?,
             mnemonic: env::var("TERRA_MNEMONIC")
                 .map_err(|_| eyre!("TERRA_MNEMONIC environment variable is required"))?,
+            extra_mnemonics: extra_keys_from_env("TERRA_EXTRA_MNEMONICS"),
             fee_recipient: env::var("TERRA_FEE_RECIPIENT").ok(),
             // V2 configuration
             this_chain_id: env::var("TERRA_THIS_CHAIN_ID")
@@ -461,6 +522,13 @@ impl Config {
                         "SOLANA_PRIVATE_KEY is required when SOLANA_RPC_URL or SOLANA_MAINNET_RPC is set"
                     )
                 })?;
+                // The Solana program authorises exactly one key (`BridgeConfig.operator`)
+                if !extra_keys_from_env("SOLANA_EXTRA_PRIVATE_KEYS").is_empty() {
+                    return Err(eyre!(
+                        "SOLANA_EXTRA_PRIVATE_KEYS is not supported: the Solana bridge has a \
+                         single operator key, so transactions signed by any other key fail"
+                    ));
+                }
                 let poll_interval_ms = env::var("SOLANA_POLL_INTERVAL_MS")
                     .ok()
                     .and_then(|v| v.parse().ok())
@@ -517,12 +585,20 @@ impl Config {
             ));
         }
 
-        // Validate EVM private key
+        // Validate EVM private keys
         if self.evm.private_key.len() != 66 || !self.evm.private_key.starts_with("0x") {
             return Err(eyre!(
                 "evm.private_key must be 66 chars (0x + 64 hex chars)"
             ));
         }
+        for (i, key) in self.evm.extra_private_keys.iter().enumerate() {
+            if key.len() != 66 || !key.starts_with("0x") {
+                return Err(eyre!(
+                    "EVM_EXTRA_PRIVATE_KEYS[{}] must be 66 chars (0x + 64 hex chars)",
+                    i
+                ));
+            }
+        }
 
         // Validate Terra RPC URL
         if self.terra.rpc_url.is_empty() {
@@ -549,6 +625,14 @@ impl Config {
         if mnemonic_words.len() < 12 {
             return Err(eyre!("terra.mnemonic must have at least 12 words"));
         }
+        for (i, mnemonic) in self.terra.extra_mnemonics.iter().enumerate() {
+            if mnemonic.split_whitespace().count() < 12 {
+                return Err(eyre!(
+                    "TERRA_EXTRA_MNEMONICS[{}] must have at least 12 words",
+                    i
+                ));
+            }
+        }
 
         // Validate fee recipient
         if self.fees.fee_recipient.len() != 42 || !self.fees.fee_recipient.starts_with("0x") {
@@ -672,6 +756,7 @@ mod tests {
                 chain_id: 1,
                 bridge_address: "0x0000000000000000000000000000000000000001".to_string(),
                 private_key: "0x0000000000000000000000000000000000000000000000000000000000000001".to_string(),
+                extra_private_keys: vec![],
                 finality_blocks: 1,
                 this_chain_id: None,
                 use_v2_events: None,
@@ -682,6 +767,7 @@ mod tests {
                 chain_id: "columbus-5".to_string(),
                 bridge_address: "terra1...".to_string(),
                 mnemonic: "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about".to_string(),
+                extra_mnemonics: vec![],
                 fee_recipient: None,
                 this_chain_id: None,
             },
@@ -715,6 +801,38 @@ mod tests {
         config.evm.bridge_address = "0x0000000000000000000000000000000000000001".to_string();
         config.fees.fee_recipient = "invalid".to_string();
         assert!(config.validate().is_err());
+
+        // Extra signer keys are validated like the primary one
+        config.fees.fee_recipient = "0x0000000000000000000000000000000000000001".to_string();
+        config.evm.extra_private_keys =
+            vec!["0x0000000000000000000000000000000000000000000000000000000000000002".to_string()];
+        assert!(config.validate().is_ok());
+        assert_eq!(config.evm.signer_keys().count(), 2);
+        config.evm.extra_private_keys.push("0x12".to_string());
+        assert!(config.validate().is_err());
+        config.evm.extra_private_keys.clear();
+
+        config.terra.extra_mnemonics = vec!["too short".to_string()];
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_split_key_list() {
+        assert_eq!(
+            split_key_list(" 0xaa, 0xbb ,,"),
+            vec!["0xaa".to_string(), "0xbb".to_string()]
+        );
+        // JSON byte-array Solana keys keep their commas
+        assert_eq!(
+            split_key_list("[1,2,3],5Kd3NBUAdUnhyzenEwVLy9pBKxSwXvE9FMPyR4UKZvpe6E3AgLr"),
+            vec![
+                "[1,2,3]".to_string(),
+                "5Kd3NBUAdUnhyzenEwVLy9pBKxSwXvE9FMPyR4UKZvpe6E3AgLr".to_string()
+            ]
+        );
+        // Mnemonic words are space separated
+        assert_eq!(split_key_list("a b c,d e f").len(), 2);
+        assert!(split_key_list("").is_empty());
     }
 
     #[test]
@@ -729,6 +847,7 @@ mod tests {
                 chain_id: 1,
                 bridge_address: "0x0000000000000000000000000000000000000001".to_string(),
                 private_key: "0x0000000000000000000000000000000000000000000000000000000000000001".to_string(),
+                extra_private_keys: vec![],
                 finality_blocks: 1,
                 this_chain_id: None,
                 use_v2_events: None,
@@ -739,6 +858,7 @@ mod tests {
                 chain_id: "columbus-5".to_string(),
                 bridge_address: "terra1...".to_string(),
                 mnemonic: "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about".to_string(),
+                extra_mnemonics: vec![],
                 fee_recipient: None,
                 this_chain_id: None,
             },
@@ -780,6 +900,7 @@ mod tests {
                 private_key:
                     "0x0000000000000000000000000000000000000000000000000000000000000001"
                         .to_string(),
+                extra_private_keys: vec![],
                 finality_blocks: 1,
                 this_chain_id: Some(2),
                 use_v2_events: None,
@@ -790,6 +911,7 @@ mod tests {
                 chain_id: "columbus-5".to_string(),
                 bridge_address: "terra1...".to_string(),
                 mnemonic: "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about".to_string(),
+                extra_mnemonics: vec![],
                 fee_recipient: None,
                 this_chain_id: None,
             },
@@ -59,8 +59,12 @@ impl ConfirmationTracker {
         })
     }
 
-    /// Run the confirmation loop
-    pub async fn run(&mut self, mut shutdown: tokio::sync::mpsc::Receiver<()>) -> Result<()> {
+    /// Run the confirmation loop (idle while `leader` is standby)
+    pub async fn run(
+        &mut self,
+        mut shutdown: tokio::sync::mpsc::Receiver<()>,
+        leader: crate::leader::LeaderHandle,
+    ) -> Result<()> {
         tracing::info!(
             poll_interval_ms = self.config.poll_interval_ms,
             evm_confirmations = self.config.evm_confirmations,
@@ -75,8 +79,18 @@ impl ConfirmationTracker {
                     break;
                 }
                 _ = tokio::time::sleep(std::time::Duration::from_millis(self.config.poll_interval_ms)) => {
-                    if let Err(err) = self.process_pending().await {
-                        tracing::error!(error = %err, "Error processing pending transactions");
+                    if !leader.is_leader() {
+                        continue;
+                    }
+                    tokio::select! {
+                        result = self.process_pending() => {
+                            if let Err(err) = result {
+                                tracing::error!(error = %err, "Error processing pending transactions");
+                            }
+                        }
+                        _ = leader.lost() => {
+                            tracing::warn!("Operator lease lost mid-cycle, stopping confirmation pass");
+                        }
                     }
                 }
             }
@@ -1,9 +1,9 @@
-#![allow(dead_code)]
-
 use eyre::{Result, WrapErr};
-use sqlx::postgres::{PgPool, PgPoolOptions};
+use sqlx::postgres::{PgConnection, PgPool, PgPoolOptions};
 use sqlx::Row;
-use tracing::error;
+use tracing::{error, warn};
+
+use crate::leader::LeaseFence;
 
 pub mod models;
 
@@ -64,65 +64,49 @@ pub async fn insert_evm_deposit(pool: &PgPool, deposit: &NewEvmDeposit) -> Resul
     Ok(row.get("id"))
 }
 
-/// Get pending EVM deposits (for creating releases on Terra)
-pub async fn get_pending_evm_deposits(pool: &PgPool) -> Result<Vec<EvmDeposit>> {
-    // Cast amount to TEXT since sqlx can't automatically convert NUMERIC to String
-    let rows = sqlx::query_as::<_, EvmDeposit>(
-        r#"SELECT id, chain_id, tx_hash, log_index, nonce, dest_chain_key, dest_token_address, 
-                  dest_account, token, amount::TEXT as amount, block_number, block_hash, status, 
-                  created_at, updated_at, dest_chain_id, dest_chain_type, src_account, src_v2_chain_id,
-                  transfer_hash
-           FROM evm_deposits WHERE status = 'pending'"#,
-    )
-    .fetch_all(pool)
-    .await
-    .map_err(|e| {
-        error!("SQL error getting pending EVM deposits: {:?}", e);
-        e
-    })
-    .wrap_err("Failed to get pending EVM deposits")?;
-
-    Ok(rows)
-}
-
-/// Get pending EVM deposits destined for Cosmos/Terra chains
-pub async fn get_pending_evm_deposits_for_cosmos(pool: &PgPool) -> Result<Vec<EvmDeposit>> {
-    // Filter by dest_chain_type = 'cosmos' to only get deposits going to Terra
-    let rows = sqlx::query_as::<_, EvmDeposit>(
-        r#"SELECT id, chain_id, tx_hash, log_index, nonce, dest_chain_key, dest_token_address, 
-                  dest_account, token, amount::TEXT as amount, block_number, block_hash, status, 
-                  created_at, updated_at, dest_chain_id, dest_chain_type, src_account, src_v2_chain_id,
-                  transfer_hash
-           FROM evm_deposits WHERE status = 'pending' AND dest_chain_type = 'cosmos'"#,
-    )
-    .fetch_all(pool)
-    .await
-    .map_err(|e| {
-        error!("SQL error getting pending EVM deposits for Cosmos: {:?}", e);
-        e
-    })
-    .wrap_err("Failed to get pending EVM deposits for Cosmos")?;
-
-    Ok(rows)
-}
-
-/// Get pending EVM deposits destined for EVM chains
-pub async fn get_pending_evm_deposits_for_evm(pool: &PgPool) -> Result<Vec<EvmDeposit>> {
+/// Claim pending EVM deposits destined for EVM chains (EVM→EVM) for `holder`.
+///
+/// Rows are locked with `FOR UPDATE SKIP LOCKED`, so concurrent instances never receive the
+/// same row. A claim older than `claim_ttl_secs` is considered abandoned; the holder's own
+/// claims are returned again so it can retry them. With a `fence`, nothing is claimed unless
+/// `holder` still holds that lease generation (see [`lease_fence_clause`]).
+pub async fn claim_pending_evm_deposits_for_evm(
+    pool: &PgPool,
+    holder: &str,
+    fence: Option<&LeaseFence>,
+    claim_ttl_secs: i64,
+    limit: i64,
+) -> Result<Vec<EvmDeposit>> {
     // Filter by dest_chain_type = 'evm' to only get deposits going to EVM chains
-    let rows = sqlx::query_as::<_, EvmDeposit>(
-        r#"SELECT id, chain_id, tx_hash, log_index, nonce, dest_chain_key, dest_token_address, 
-                  dest_account, token, amount::TEXT as amount, block_number, block_hash, status, 
-                  created_at, updated_at, dest_chain_id, dest_chain_type, src_account, src_v2_chain_id,
-                  transfer_hash
-           FROM evm_deposits WHERE status = 'pending' AND dest_chain_type = 'evm'"#,
-    )
-    .fetch_all(pool)
-    .await
-    .map_err(|e| {
-        error!("SQL error getting pending EVM deposits for EVM: {:?}", e);
-        e
-    })
-    .wrap_err("Failed to get pending EVM deposits for EVM")?;
+    let query = format!(
+        r#"UPDATE evm_deposits SET claimed_by = $1, claimed_at = NOW()
+           WHERE id IN (
+               SELECT id FROM evm_deposits
+               WHERE status = 'pending' AND dest_chain_type = 'evm'
+                 AND (claimed_at IS NULL OR claimed_by = $1
+                      OR claimed_at < NOW() - ($2::BIGINT * INTERVAL '1 second'))
+                 AND {}
+               ORDER BY id
+               LIMIT $3
+               FOR UPDATE SKIP LOCKED
+           )
+           RETURNING {}"#,
+        lease_fence_clause(1, 4),
+        EVM_DEPOSIT_SELECT
+    );
+    let rows = sqlx::query_as::<_, EvmDeposit>(&query)
+        .bind(holder)
+        .bind(claim_ttl_secs)
+        .bind(limit)
+        .bind(fence.map(|f| f.lease.as_str()))
+        .bind(fence.map(|f| f.generation))
+        .fetch_all(pool)
+        .await
+        .map_err(|e| {
+            error!("SQL error claiming pending EVM deposits for EVM: {:?}", e);
+            e
+        })
+        .wrap_err("Failed to claim pending EVM deposits for EVM")?;
 
     Ok(rows)
 }
@@ -245,25 +229,6 @@ pub async fn insert_terra_deposit(pool: &PgPool, deposit: &NewTerraDeposit) -> R
     Ok(row.get("id"))
 }
 
-/// Get pending Terra deposits (for creating approvals on EVM)
-pub async fn get_pending_terra_deposits(pool: &PgPool) -> Result<Vec<TerraDeposit>> {
-    // Cast amount to TEXT since sqlx can't automatically convert NUMERIC to String
-    let rows = sqlx::query_as::<_, TerraDeposit>(
-        r#"SELECT id, tx_hash, nonce, sender, recipient, token, amount::TEXT as amount, 
-                  dest_chain_id, block_height, status, created_at, updated_at, dest_token_address, transfer_hash
-           FROM terra_deposits WHERE status = 'pending'"#,
-    )
-    .fetch_all(pool)
-    .await
-    .map_err(|e| {
-        error!("SQL error getting pending Terra deposits: {:?}", e);
-        e
-    })
-    .wrap_err("Failed to get pending Terra deposits")?;
-
-    Ok(rows)
-}
-
 /// Update Terra deposit status
 pub async fn update_terra_deposit_status(pool: &PgPool, id: i64, status: &str) -> Result<()> {
     sqlx::query(r#"UPDATE terra_deposits SET status = $1 WHERE id = $2"#)
@@ -421,31 +386,39 @@ const RELEASE_SELECT: &str = r#"id, src_chain_key, nonce, sender, recipient, tok
     amount::TEXT as amount, source_chain_id, tx_hash, status, attempts, last_attempt_at, 
     error_message, created_at, updated_at"#;
 
-/// Get pending approvals for submission
-pub async fn get_pending_approvals(pool: &PgPool, dest_chain_id: i64) -> Result<Vec<Approval>> {
-    let query = format!(
-        "SELECT {} FROM approvals WHERE status = 'pending' AND dest_chain_id = $1",
-        APPROVAL_SELECT
-    );
-    let rows = sqlx::query_as::<_, Approval>(&query)
-        .bind(dest_chain_id)
-        .fetch_all(pool)
-        .await
-        .wrap_err("Failed to get pending approvals")?;
-
-    Ok(rows)
-}
-
 /// Update approval status with tx_hash
+///
+/// Locks the row first: if another submission was already recorded for it (e.g. by a
+/// second operator instance), the existing tx_hash is kept and a warning is logged.
 pub async fn update_approval_submitted(pool: &PgPool, id: i64, tx_hash: &str) -> Result<()> {
+    let mut tx = pool.begin().await.wrap_err("Failed to begin transaction")?;
+    let current: Option<(String, Option<String>)> =
+        sqlx::query_as(r#"SELECT status, tx_hash FROM approvals WHERE id = $1 FOR UPDATE"#)
+            .bind(id)
+            .fetch_optional(&mut *tx)
+            .await
+            .wrap_err_with(|| format!("Failed to lock approval {}", id))?;
+    if let Some((status, Some(existing))) = current {
+        if (status == "submitted" || status == "confirmed") && existing != tx_hash {
+            warn!(
+                approval_id = id,
+                existing_tx = %existing,
+                duplicate_tx = %tx_hash,
+                "Approval already has a recorded submission, keeping the existing one"
+            );
+            tx.commit().await.wrap_err("Failed to commit transaction")?;
+            return Ok(());
+        }
+    }
     sqlx::query(
         r#"UPDATE approvals SET status = 'submitted', tx_hash = $1, attempts = attempts + 1, last_attempt_at = NOW() WHERE id = $2"#,
     )
     .bind(tx_hash)
     .bind(id)
-    .execute(pool)
+    .execute(&mut *tx)
     .await
     .wrap_err_with(|| format!("Failed to update approval {} as submitted", id))?;
+    tx.commit().await.wrap_err("Failed to commit transaction")?;
 
     Ok(())
 }
@@ -503,58 +476,6 @@ pub async fn approval_exists(
     Ok(row.0)
 }
 
-/// Insert a new release
-pub async fn insert_release(pool: &PgPool, release: &NewRelease) -> Result<i64> {
-    // Note: amount is stored as NUMERIC(78,0) in the database, so we cast the text value
-    let row = sqlx::query(
-        r#"
-        INSERT INTO releases (src_chain_key, nonce, sender, recipient, token, amount, source_chain_id)
-        VALUES ($1, $2, $3, $4, $5, $6::NUMERIC, $7)
-        RETURNING id
-        "#,
-    )
-    .bind(&release.src_chain_key)
-    .bind(release.nonce)
-    .bind(&release.sender)
-    .bind(&release.recipient)
-    .bind(&release.token)
-    .bind(&release.amount)
-    .bind(release.source_chain_id)
-    .fetch_one(pool)
-    .await
-    .wrap_err("Failed to insert release")?;
-
-    Ok(row.get("id"))
-}
-
-/// Get pending releases for submission
-pub async fn get_pending_releases(pool: &PgPool) -> Result<Vec<Release>> {
-    let query = format!(
-        "SELECT {} FROM releases WHERE status = 'pending'",
-        RELEASE_SELECT
-    );
-    let rows = sqlx::query_as::<_, Release>(&query)
-        .fetch_all(pool)
-        .await
-        .wrap_err("Failed to get pending releases")?;
-
-    Ok(rows)
-}
-
-/// Update release status with tx_hash
-pub async fn update_release_submitted(pool: &PgPool, id: i64, tx_hash: &str) -> Result<()> {
-    sqlx::query(
-        r#"UPDATE releases SET status = 'submitted', tx_hash = $1, attempts = attempts + 1, last_attempt_at = NOW() WHERE id = $2"#,
-    )
-    .bind(tx_hash)
-    .bind(id)
-    .execute(pool)
-    .await
-    .wrap_err_with(|| format!("Failed to update release {} as submitted", id))?;
-
-    Ok(())
-}
-
 /// Update release status to confirmed
 pub async fn update_release_confirmed(pool: &PgPool, id: i64) -> Result<()> {
     sqlx::query(r#"UPDATE releases SET status = 'confirmed' WHERE id = $1"#)
@@ -580,20 +501,6 @@ pub async fn update_release_failed(pool: &PgPool, id: i64, error: &str) -> Resul
     Ok(())
 }
 
-/// Check if release exists for (src_chain_key, nonce)
-pub async fn release_exists(pool: &PgPool, src_chain_key: &[u8], nonce: i64) -> Result<bool> {
-    let row: (bool,) = sqlx::query_as(
-        r#"SELECT EXISTS(SELECT 1 FROM releases WHERE src_chain_key = $1 AND nonce = $2)"#,
-    )
-    .bind(src_chain_key)
-    .bind(nonce)
-    .fetch_one(pool)
-    .await
-    .wrap_err("Failed to check release existence")?;
-
-    Ok(row.0)
-}
-
 /// Get last processed EVM block
 pub async fn get_last_evm_block(pool: &PgPool, chain_id: i64) -> Result<Option<i64>> {
     let row: Option<(i64,)> =
@@ -690,111 +597,8 @@ pub async fn get_submitted_releases(pool: &PgPool) -> Result<Vec<Release>> {
     Ok(rows)
 }
 
-/// Update approval to reorged status
-pub async fn update_approval_reorged(pool: &PgPool, id: i64) -> Result<()> {
-    sqlx::query(r#"UPDATE approvals SET status = 'reorged' WHERE id = $1"#)
-        .bind(id)
-        .execute(pool)
-        .await
-        .wrap_err_with(|| format!("Failed to update approval {} as reorged", id))?;
-
-    Ok(())
-}
-
-/// Update release to reorged status
-pub async fn update_release_reorged(pool: &PgPool, id: i64) -> Result<()> {
-    sqlx::query(r#"UPDATE releases SET status = 'reorged' WHERE id = $1"#)
-        .bind(id)
-        .execute(pool)
-        .await
-        .wrap_err_with(|| format!("Failed to update release {} as reorged", id))?;
-
-    Ok(())
-}
-
 // ============ Sprint 4: Retry System ============
 
-/// Get failed approvals that are ready for retry
-pub async fn get_failed_approvals_for_retry(
-    pool: &PgPool,
-    dest_chain_id: i64,
-    max_attempts: i32,
-) -> Result<Vec<Approval>> {
-    let query = format!(
-        "SELECT {} FROM approvals 
-         WHERE status = 'failed' 
-           AND dest_chain_id = $1
-           AND attempts < $2
-           AND (retry_after IS NULL OR retry_after <= NOW())
-         ORDER BY created_at ASC
-         LIMIT 10",
-        APPROVAL_SELECT
-    );
-    let rows = sqlx::query_as::<_, Approval>(&query)
-        .bind(dest_chain_id)
-        .bind(max_attempts)
-        .fetch_all(pool)
-        .await
-        .wrap_err("Failed to get failed approvals for retry")?;
-
-    Ok(rows)
-}
-
-/// Get failed releases that are ready for retry
-pub async fn get_failed_releases_for_retry(
-    pool: &PgPool,
-    max_attempts: i32,
-) -> Result<Vec<Release>> {
-    let query = format!(
-        "SELECT {} FROM releases 
-         WHERE status = 'failed' 
-           AND attempts < $1
-           AND (retry_after IS NULL OR retry_after <= NOW())
-         ORDER BY created_at ASC
-         LIMIT 10",
-        RELEASE_SELECT
-    );
-    let rows = sqlx::query_as::<_, Release>(&query)
-        .bind(max_attempts)
-        .fetch_all(pool)
-        .await
-        .wrap_err("Failed to get failed releases for retry")?;
-
-    Ok(rows)
-}
-
-/// Update approval to pending for retry with retry_after
-pub async fn update_approval_for_retry(
-    pool: &PgPool,
-    id: i64,
-    retry_after: chrono::DateTime<chrono::Utc>,
-) -> Result<()> {
-    sqlx::query(r#"UPDATE approvals SET status = 'pending', retry_after = $1 WHERE id = $2"#)
-        .bind(retry_after)
-        .bind(id)
-        .execute(pool)
-        .await
-        .wrap_err_with(|| format!("Failed to update approval {} for retry", id))?;
-
-    Ok(())
-}
-
-/// Update release to pending for retry with retry_after
-pub async fn update_release_for_retry(
-    pool: &PgPool,
-    id: i64,
-    retry_after: chrono::DateTime<chrono::Utc>,
-) -> Result<()> {
-    sqlx::query(r#"UPDATE releases SET status = 'pending', retry_after = $1 WHERE id = $2"#)
-        .bind(retry_after)
-        .bind(id)
-        .execute(pool)
-        .await
-        .wrap_err_with(|| format!("Failed to update release {} for retry", id))?;
-
-    Ok(())
-}
-
 // ============ Sprint 4: API/Status Queries ============
 
 /// Count pending deposits across all chains (evm_deposits + terra_deposits).
@@ -903,52 +707,1215 @@ pub async fn get_pending_and_submitted_releases(
     Ok(rows)
 }
 
-/// Get approval by transaction hash
-pub async fn get_approval_by_tx_hash(pool: &PgPool, tx_hash: &str) -> Result<Option<Approval>> {
+// ============ Reindex ============
+
+/// Deposit status that parks a row inserted by `reindex` so the writers (which only pick
+/// up `pending` rows) do not create approvals for it unless `--approve` is passed.
+pub const REINDEXED_STATUS: &str = "reindexed";
+
+const EVM_DEPOSIT_SELECT: &str = r#"id, chain_id, tx_hash, log_index, nonce, dest_chain_key, dest_token_address,
+    dest_account, token, amount::TEXT as amount, block_number, block_hash, status,
+    created_at, updated_at, dest_chain_id, dest_chain_type, src_account, src_v2_chain_id,
+    transfer_hash"#;
+
+/// Get an EVM deposit by its unique (chain_id, tx_hash, log_index) key
+pub async fn get_evm_deposit_by_key(
+    pool: &PgPool,
+    chain_id: i64,
+    tx_hash: &str,
+    log_index: i32,
+) -> Result<Option<EvmDeposit>> {
     let query = format!(
-        "SELECT {} FROM approvals WHERE tx_hash = $1",
-        APPROVAL_SELECT
+        "SELECT {} FROM evm_deposits WHERE chain_id = $1 AND tx_hash = $2 AND log_index = $3",
+        EVM_DEPOSIT_SELECT
     );
-    let row = sqlx::query_as::<_, Approval>(&query)
+    sqlx::query_as::<_, EvmDeposit>(&query)
+        .bind(chain_id)
         .bind(tx_hash)
+        .bind(log_index)
         .fetch_optional(pool)
         .await
-        .wrap_err("Failed to get approval by tx_hash")?;
-
-    Ok(row)
+        .wrap_err("Failed to get EVM deposit by key")
 }
 
-/// Get release by transaction hash
-pub async fn get_release_by_tx_hash(pool: &PgPool, tx_hash: &str) -> Result<Option<Release>> {
-    let query = format!("SELECT {} FROM releases WHERE tx_hash = $1", RELEASE_SELECT);
-    let row = sqlx::query_as::<_, Release>(&query)
-        .bind(tx_hash)
-        .fetch_optional(pool)
-        .await
-        .wrap_err("Failed to get release by tx_hash")?;
+/// Insert an EVM deposit with an explicit status; no-op when the key already exists
+pub async fn insert_evm_deposit_with_status(
+    pool: &PgPool,
+    deposit: &NewEvmDeposit,
+    status: &str,
+) -> Result<Option<i64>> {
+    let row = sqlx::query(
+        r#"
+        INSERT INTO evm_deposits (chain_id, tx_hash, log_index, nonce, dest_chain_key,
+            dest_token_address, dest_account, token, amount, block_number, block_hash,
+            dest_chain_type, src_account, src_v2_chain_id, transfer_hash, status)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9::NUMERIC, $10, $11, $12, $13, $14, $15, $16)
+        ON CONFLICT (chain_id, tx_hash, log_index) DO NOTHING
+        RETURNING id
+        "#,
+    )
+    .bind(deposit.chain_id)
+    .bind(&deposit.tx_hash)
+    .bind(deposit.log_index)
+    .bind(deposit.nonce)
+    .bind(&deposit.dest_chain_key)
+    .bind(&deposit.dest_token_address)
+    .bind(&deposit.dest_account)
+    .bind(&deposit.token)
+    .bind(&deposit.amount)
+    .bind(deposit.block_number)
+    .bind(&deposit.block_hash)
+    .bind(&deposit.dest_chain_type)
+    .bind(&deposit.src_account)
+    .bind(&deposit.src_v2_chain_id)
+    .bind(&deposit.transfer_hash)
+    .bind(status)
+    .fetch_optional(pool)
+    .await
+    .wrap_err("Failed to insert EVM deposit")?;
 
-    Ok(row)
+    Ok(row.map(|r| r.get("id")))
 }
 
-/// Count total pending and submitted approvals
-pub async fn count_pending_and_submitted_approvals(pool: &PgPool) -> Result<i64> {
-    let row: (i64,) = sqlx::query_as(
-        r#"SELECT COUNT(*) FROM approvals WHERE status IN ('pending', 'submitted')"#,
+/// Overwrite the chain-derived columns of an EVM deposit (status is left untouched)
+pub async fn update_evm_deposit_fields(
+    pool: &PgPool,
+    id: i64,
+    deposit: &NewEvmDeposit,
+) -> Result<()> {
+    sqlx::query(
+        r#"
+        UPDATE evm_deposits SET nonce = $2, dest_chain_key = $3, dest_token_address = $4,
+            dest_account = $5, token = $6, amount = $7::NUMERIC, block_number = $8,
+            block_hash = $9, dest_chain_type = $10, src_account = $11, src_v2_chain_id = $12,
+            transfer_hash = $13
+        WHERE id = $1
+        "#,
     )
-    .fetch_one(pool)
+    .bind(id)
+    .bind(deposit.nonce)
+    .bind(&deposit.dest_chain_key)
+    .bind(&deposit.dest_token_address)
+    .bind(&deposit.dest_account)
+    .bind(&deposit.token)
+    .bind(&deposit.amount)
+    .bind(deposit.block_number)
+    .bind(&deposit.block_hash)
+    .bind(&deposit.dest_chain_type)
+    .bind(&deposit.src_account)
+    .bind(&deposit.src_v2_chain_id)
+    .bind(&deposit.transfer_hash)
+    .execute(pool)
     .await
-    .wrap_err("Failed to count pending/submitted approvals")?;
+    .wrap_err_with(|| format!("Failed to update EVM deposit {}", id))?;
 
-    Ok(row.0)
+    Ok(())
 }
 
-/// Count total pending and submitted releases
-pub async fn count_pending_and_submitted_releases(pool: &PgPool) -> Result<i64> {
-    let row: (i64,) =
-        sqlx::query_as(r#"SELECT COUNT(*) FROM releases WHERE status IN ('pending', 'submitted')"#)
-            .fetch_one(pool)
-            .await
-            .wrap_err("Failed to count pending/submitted releases")?;
+/// Get a Terra deposit by its unique (tx_hash, nonce) key
+pub async fn get_terra_deposit_by_key(
+    pool: &PgPool,
+    tx_hash: &str,
+    nonce: i64,
+) -> Result<Option<TerraDeposit>> {
+    sqlx::query_as::<_, TerraDeposit>(
+        r#"SELECT id, tx_hash, nonce, sender, recipient, token, amount::TEXT as amount,
+                  dest_chain_id, block_height, status, created_at, updated_at, dest_token_address, transfer_hash
+           FROM terra_deposits WHERE tx_hash = $1 AND nonce = $2"#,
+    )
+    .bind(tx_hash)
+    .bind(nonce)
+    .fetch_optional(pool)
+    .await
+    .wrap_err("Failed to get Terra deposit by key")
+}
 
-    Ok(row.0)
+/// Insert a Terra deposit with an explicit status; no-op when the key already exists
+pub async fn insert_terra_deposit_with_status(
+    pool: &PgPool,
+    deposit: &NewTerraDeposit,
+    status: &str,
+) -> Result<Option<i64>> {
+    let row = sqlx::query(
+        r#"
+        INSERT INTO terra_deposits (tx_hash, nonce, sender, recipient, token, amount, dest_chain_id, block_height, dest_token_address, transfer_hash, status)
+        VALUES ($1, $2, $3, $4, $5, $6::NUMERIC, $7, $8, $9, $10, $11)
+        ON CONFLICT (tx_hash, nonce) DO NOTHING
+        RETURNING id
+        "#,
+    )
+    .bind(&deposit.tx_hash)
+    .bind(deposit.nonce)
+    .bind(&deposit.sender)
+    .bind(&deposit.recipient)
+    .bind(&deposit.token)
+    .bind(&deposit.amount)
+    .bind(deposit.dest_chain_id)
+    .bind(deposit.block_height)
+    .bind(&deposit.dest_token_address)
+    .bind(&deposit.transfer_hash)
+    .bind(status)
+    .fetch_optional(pool)
+    .await
+    .wrap_err("Failed to insert Terra deposit")?;
+
+    Ok(row.map(|r| r.get("id")))
+}
+
+/// Overwrite the chain-derived columns of a Terra deposit (status is left untouched)
+pub async fn update_terra_deposit_fields(
+    pool: &PgPool,
+    id: i64,
+    deposit: &NewTerraDeposit,
+) -> Result<()> {
+    sqlx::query(
+        r#"
+        UPDATE terra_deposits SET sender = $2, recipient = $3, token = $4, amount = $5::NUMERIC,
+            dest_chain_id = $6, block_height = $7, dest_token_address = $8, transfer_hash = $9
+        WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .bind(&deposit.sender)
+    .bind(&deposit.recipient)
+    .bind(&deposit.token)
+    .bind(&deposit.amount)
+    .bind(deposit.dest_chain_id)
+    .bind(deposit.block_height)
+    .bind(&deposit.dest_token_address)
+    .bind(&deposit.transfer_hash)
+    .execute(pool)
+    .await
+    .wrap_err_with(|| format!("Failed to update Terra deposit {}", id))?;
+
+    Ok(())
+}
+
+/// Get a Solana deposit by nonce
+pub async fn get_solana_deposit_by_nonce(
+    pool: &PgPool,
+    nonce: i64,
+) -> Result<Option<SolanaDeposit>> {
+    sqlx::query_as::<_, SolanaDeposit>(
+        r#"SELECT id, nonce, transfer_hash, src_account, dest_chain, dest_account, token,
+                  amount::TEXT as amount, fee::TEXT as fee, slot, signature, processed
+           FROM solana_deposits WHERE nonce = $1"#,
+    )
+    .bind(nonce)
+    .fetch_optional(pool)
+    .await
+    .wrap_err("Failed to get Solana deposit by nonce")
+}
+
+/// Insert a Solana deposit, or overwrite every chain-derived column of the row with the
+/// same nonce (`processed` is left untouched)
+pub async fn upsert_solana_deposit(pool: &PgPool, deposit: &NewSolanaDeposit) -> Result<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO solana_deposits (
+            nonce, transfer_hash, src_account, dest_chain,
+            dest_account, token, amount, fee, slot, signature
+        ) VALUES ($1, $2, $3, $4, $5, $6, $7::NUMERIC, $8::NUMERIC, $9, $10)
+        ON CONFLICT (nonce) DO UPDATE SET
+            transfer_hash = EXCLUDED.transfer_hash, src_account = EXCLUDED.src_account,
+            dest_chain = EXCLUDED.dest_chain, dest_account = EXCLUDED.dest_account,
+            token = EXCLUDED.token, amount = EXCLUDED.amount, fee = EXCLUDED.fee,
+            slot = EXCLUDED.slot, signature = EXCLUDED.signature
+        "#,
+    )
+    .bind(deposit.nonce)
+    .bind(&deposit.transfer_hash)
+    .bind(&deposit.src_account)
+    .bind(&deposit.dest_chain)
+    .bind(&deposit.dest_account)
+    .bind(&deposit.token)
+    .bind(&deposit.amount)
+    .bind(&deposit.fee)
+    .bind(deposit.slot)
+    .bind(&deposit.signature)
+    .execute(pool)
+    .await
+    .wrap_err_with(|| format!("Failed to upsert Solana deposit nonce {}", deposit.nonce))?;
+
+    Ok(())
+}
+
+// ============ Policy ============
+
+const POLICY_TRANSFER_SELECT: &str = r#"id, xchain_hash_id, src_chain, dest_chain, src_account,
+    dest_account, token, amount::TEXT as amount, nonce, status, reasons, decided_by,
+    decision_note, decided_at, created_at"#;
+
+/// Get the policy decision recorded for a transfer hash
+pub async fn get_policy_transfer(
+    db: impl sqlx::PgExecutor<'_>,
+    xchain_hash_id: &[u8],
+) -> Result<Option<PolicyTransfer>> {
+    let query = format!(
+        "SELECT {} FROM policy_transfers WHERE xchain_hash_id = $1",
+        POLICY_TRANSFER_SELECT
+    );
+    sqlx::query_as::<_, PolicyTransfer>(&query)
+        .bind(xchain_hash_id)
+        .fetch_optional(db)
+        .await
+        .wrap_err("Failed to get policy transfer")
+}
+
+/// Serialize policy evaluations touching `accounts` until `conn`'s transaction ends, so
+/// velocity totals read inside it stay valid until the decision is inserted
+pub async fn lock_policy_accounts(conn: &mut PgConnection, accounts: &[[u8; 32]]) -> Result<()> {
+    let mut accounts = accounts.to_vec();
+    // A fixed order keeps two evaluations locking the same pair from deadlocking
+    accounts.sort_unstable();
+    accounts.dedup();
+    for account in &accounts {
+        sqlx::query("SELECT pg_advisory_xact_lock(hashtextextended(encode($1, 'hex'), 0))")
+            .bind(account.as_slice())
+            .execute(&mut *conn)
+            .await
+            .wrap_err("Failed to lock policy account")?;
+    }
+    Ok(())
+}
+
+/// Record a policy decision; returns the row that is stored for the hash afterwards, which
+/// is the earlier decision when another writer got there first
+pub async fn insert_policy_transfer(
+    conn: &mut PgConnection,
+    transfer: &NewPolicyTransfer,
+) -> Result<PolicyTransfer> {
+    sqlx::query(
+        r#"
+        INSERT INTO policy_transfers (
+            xchain_hash_id, src_chain, dest_chain, src_account, dest_account,
+            token, amount, nonce, status, reasons
+        ) VALUES ($1, $2, $3, $4, $5, $6, $7::NUMERIC, $8, $9, $10)
+        ON CONFLICT (xchain_hash_id) DO NOTHING
+        "#,
+    )
+    .bind(&transfer.xchain_hash_id)
+    .bind(&transfer.src_chain)
+    .bind(&transfer.dest_chain)
+    .bind(&transfer.src_account)
+    .bind(&transfer.dest_account)
+    .bind(&transfer.token)
+    .bind(&transfer.amount)
+    .bind(transfer.nonce)
+    .bind(&transfer.status)
+    .bind(&transfer.reasons)
+    .execute(&mut *conn)
+    .await
+    .wrap_err("Failed to insert policy transfer")?;
+
+    get_policy_transfer(&mut *conn, &transfer.xchain_hash_id)
+        .await?
+        .ok_or_else(|| eyre::eyre!("Policy transfer missing right after insert"))
+}
+
+/// Put an approved ('allowed' or 'released') transfer back on hold with `reasons`;
+/// returns false when it is not in an approved state
+pub async fn hold_policy_transfer(
+    pool: &PgPool,
+    xchain_hash_id: &[u8],
+    reasons: &str,
+) -> Result<bool> {
+    let result = sqlx::query(
+        r#"
+        UPDATE policy_transfers SET status = 'held', reasons = $2
+        WHERE xchain_hash_id = $1 AND status IN ('allowed', 'released')
+        "#,
+    )
+    .bind(xchain_hash_id)
+    .bind(reasons)
+    .execute(pool)
+    .await
+    .wrap_err("Failed to hold policy transfer")?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// List transfers waiting for manual review, oldest first
+pub async fn get_held_policy_transfers(
+    pool: &PgPool,
+    limit: i64,
+    offset: i64,
+) -> Result<Vec<PolicyTransfer>> {
+    let query = format!(
+        "SELECT {} FROM policy_transfers WHERE status = 'held' ORDER BY created_at ASC LIMIT $1 OFFSET $2",
+        POLICY_TRANSFER_SELECT
+    );
+    sqlx::query_as::<_, PolicyTransfer>(&query)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(pool)
+        .await
+        .wrap_err("Failed to get held policy transfers")
+}
+
+/// Count transfers waiting for manual review
+pub async fn count_held_policy_transfers(pool: &PgPool) -> Result<i64> {
+    let row: (i64,) =
+        sqlx::query_as(r#"SELECT COUNT(*) FROM policy_transfers WHERE status = 'held'"#)
+            .fetch_one(pool)
+            .await
+            .wrap_err("Failed to count held policy transfers")?;
+    Ok(row.0)
+}
+
+/// Move a held transfer to `status` ('released' or 'rejected'); returns false when the
+/// hash is unknown or no longer held
+pub async fn decide_held_policy_transfer(
+    pool: &PgPool,
+    xchain_hash_id: &[u8],
+    status: &str,
+    decided_by: &str,
+    note: Option<&str>,
+) -> Result<bool> {
+    let result = sqlx::query(
+        r#"
+        UPDATE policy_transfers
+        SET status = $2, decided_by = $3, decision_note = $4, decided_at = NOW()
+        WHERE xchain_hash_id = $1 AND status = 'held'
+        "#,
+    )
+    .bind(xchain_hash_id)
+    .bind(status)
+    .bind(decided_by)
+    .bind(note)
+    .execute(pool)
+    .await
+    .wrap_err("Failed to update held policy transfer")?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Count and total amount (as text) of approved transfers for an account since `since`.
+///
+/// `side` selects the account column ("src" or "dest"); `token` narrows to one token.
+/// Only 'allowed' and 'released' rows count — held and rejected transfers never moved funds.
+pub async fn policy_velocity(
+    conn: &mut PgConnection,
+    side: &str,
+    account: &[u8],
+    token: Option<&str>,
+    since: chrono::DateTime<chrono::Utc>,
+) -> Result<(i64, String)> {
+    let column = match side {
+        "dest" => "dest_account",
+        _ => "src_account",
+    };
+    let query = format!(
+        r#"SELECT COUNT(*), COALESCE(SUM(amount), 0)::TEXT FROM policy_transfers
+           WHERE {} = $1 AND status IN ('allowed', 'released')
+             AND COALESCE(decided_at, created_at) >= $2
+             AND ($3::TEXT IS NULL OR token = $3)"#,
+        column
+    );
+    let row: (i64, String) = sqlx::query_as(&query)
+        .bind(account)
+        .bind(since)
+        .bind(token)
+        .fetch_one(conn)
+        .await
+        .wrap_err("Failed to compute policy velocity")?;
+    Ok(row)
+}
+
+/// Set the status of the EVM/Terra deposit rows whose transfer hash matches, when they are
+/// currently in `from_status` (used to park rows as 'held' and return them to 'pending')
+pub async fn set_deposit_status_for_hash(
+    pool: &PgPool,
+    xchain_hash_id: &[u8],
+    from_status: &str,
+    to_status: &str,
+) -> Result<u64> {
+    let evm = sqlx::query(
+        "UPDATE evm_deposits SET status = $3, updated_at = NOW() WHERE transfer_hash = $1 AND status = $2",
+    )
+    .bind(xchain_hash_id)
+    .bind(from_status)
+    .bind(to_status)
+    .execute(pool)
+    .await
+    .wrap_err("Failed to update evm_deposits status by transfer hash")?;
+    let terra = sqlx::query(
+        "UPDATE terra_deposits SET status = $3, updated_at = NOW() WHERE transfer_hash = $1 AND status = $2",
+    )
+    .bind(xchain_hash_id)
+    .bind(from_status)
+    .bind(to_status)
+    .execute(pool)
+    .await
+    .wrap_err("Failed to update terra_deposits status by transfer hash")?;
+    Ok(evm.rows_affected() + terra.rows_affected())
+}
+
+/// Pending EVM and Terra deposits to `solana_chains` (V2 chain IDs) with a transfer hash,
+/// indexed at least `older_than_secs` ago, oldest first
+pub async fn get_unsubmitted_solana_deposits(
+    pool: &PgPool,
+    solana_chains: &[[u8; 4]],
+    older_than_secs: i64,
+    limit: i64,
+) -> Result<Vec<UnsubmittedSolanaDeposit>> {
+    let evm_keys: Vec<Vec<u8>> = solana_chains.iter().map(|c| c.to_vec()).collect();
+    let terra_ids: Vec<i64> = solana_chains
+        .iter()
+        .map(|c| u32::from_be_bytes(*c) as i64)
+        .collect();
+    sqlx::query_as::<_, UnsubmittedSolanaDeposit>(
+        r#"
+        SELECT source, transfer_hash, src_v2_chain_id, dest_token_address FROM (
+            SELECT 'evm' AS source, transfer_hash, src_v2_chain_id, dest_token_address, created_at
+            FROM evm_deposits
+            WHERE status = 'pending' AND transfer_hash IS NOT NULL
+              AND substring(dest_chain_key FROM 1 FOR 4) = ANY($1)
+            UNION ALL
+            SELECT 'terra' AS source, transfer_hash, NULL::BYTEA, NULL::BYTEA, created_at
+            FROM terra_deposits
+            WHERE status = 'pending' AND transfer_hash IS NOT NULL
+              AND dest_chain_id = ANY($2)
+        ) d
+        WHERE created_at < NOW() - make_interval(secs => $3)
+        ORDER BY created_at ASC
+        LIMIT $4
+        "#,
+    )
+    .bind(&evm_keys)
+    .bind(&terra_ids)
+    .bind(older_than_secs as f64)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+    .wrap_err("Failed to get unsubmitted Solana deposits")
+}
+
+// ============ Leader election ============
+
+/// SQL condition that `$holder` still holds the unexpired lease `$name` at generation
+/// `$name + 1`, or true when `$name` is NULL (HA disabled)
+fn lease_fence_clause(holder: usize, name: usize) -> String {
+    format!(
+        "(${name}::TEXT IS NULL OR EXISTS (SELECT 1 FROM operator_leases \
+         WHERE name = ${name} AND holder = ${holder} AND generation = ${generation} \
+         AND expires_at > NOW()))",
+        name = name,
+        holder = holder,
+        generation = name + 1
+    )
+}
+
+/// Take or renew the lease `name` for `holder` for `ttl_secs` (database clock).
+///
+/// Succeeds when the lease is free, expired, or already held by `holder`, and returns the
+/// lease generation: unchanged on a renewal, bumped when a new term starts.
+pub async fn try_acquire_lease(
+    pool: &PgPool,
+    name: &str,
+    holder: &str,
+    ttl_secs: i64,
+) -> Result<Option<i64>> {
+    let row: Option<(i64,)> = sqlx::query_as(
+        r#"
+        INSERT INTO operator_leases (name, holder, acquired_at, renewed_at, expires_at)
+        VALUES ($1, $2, NOW(), NOW(), NOW() + ($3::BIGINT * INTERVAL '1 second'))
+        ON CONFLICT (name) DO UPDATE SET
+            holder = EXCLUDED.holder,
+            acquired_at = CASE WHEN operator_leases.holder = EXCLUDED.holder
+                               THEN operator_leases.acquired_at ELSE NOW() END,
+            renewed_at = NOW(),
+            expires_at = EXCLUDED.expires_at,
+            generation = CASE WHEN operator_leases.holder = EXCLUDED.holder
+                                   AND operator_leases.expires_at >= NOW()
+                              THEN operator_leases.generation
+                              ELSE operator_leases.generation + 1 END
+        WHERE operator_leases.holder = EXCLUDED.holder OR operator_leases.expires_at < NOW()
+        RETURNING generation
+        "#,
+    )
+    .bind(name)
+    .bind(holder)
+    .bind(ttl_secs)
+    .fetch_optional(pool)
+    .await
+    .wrap_err_with(|| format!("Failed to acquire lease {}", name))?;
+
+    Ok(row.map(|(generation,)| generation))
+}
+
+/// Expire the lease if `holder` still holds it; returns whether it was held. The row is kept
+/// so the next holder continues its generation.
+pub async fn release_lease(pool: &PgPool, name: &str, holder: &str) -> Result<bool> {
+    let result = sqlx::query(
+        r#"UPDATE operator_leases SET expires_at = NOW() - INTERVAL '1 second'
+           WHERE name = $1 AND holder = $2 AND expires_at >= NOW()"#,
+    )
+    .bind(name)
+    .bind(holder)
+    .execute(pool)
+    .await
+    .wrap_err_with(|| format!("Failed to release lease {}", name))?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Current unexpired holder of the lease, if any
+pub async fn get_lease_holder(pool: &PgPool, name: &str) -> Result<Option<String>> {
+    let row: Option<(String,)> = sqlx::query_as(
+        r#"SELECT holder FROM operator_leases WHERE name = $1 AND expires_at >= NOW()"#,
+    )
+    .bind(name)
+    .fetch_optional(pool)
+    .await
+    .wrap_err_with(|| format!("Failed to get lease {}", name))?;
+
+    Ok(row.map(|(h,)| h))
+}
+
+// ============ Admin controls ============
+
+/// Pause approvals for a route; re-pausing updates the reason
+pub async fn pause_route(
+    pool: &PgPool,
+    src_chain: &[u8],
+    dest_chain: &[u8],
+    reason: &str,
+    paused_by: &str,
+) -> Result<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO route_pauses (src_chain, dest_chain, reason, paused_by)
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT (src_chain, dest_chain)
+        DO UPDATE SET reason = EXCLUDED.reason, paused_by = EXCLUDED.paused_by, paused_at = NOW()
+        "#,
+    )
+    .bind(src_chain)
+    .bind(dest_chain)
+    .bind(reason)
+    .bind(paused_by)
+    .execute(pool)
+    .await
+    .wrap_err("Failed to pause route")?;
+    Ok(())
+}
+
+/// Resume a paused route; returns false when it was not paused
+pub async fn resume_route(pool: &PgPool, src_chain: &[u8], dest_chain: &[u8]) -> Result<bool> {
+    let result = sqlx::query("DELETE FROM route_pauses WHERE src_chain = $1 AND dest_chain = $2")
+        .bind(src_chain)
+        .bind(dest_chain)
+        .execute(pool)
+        .await
+        .wrap_err("Failed to resume route")?;
+    Ok(result.rows_affected() > 0)
+}
+
+/// List paused routes
+pub async fn get_route_pauses(pool: &PgPool) -> Result<Vec<RoutePause>> {
+    sqlx::query_as::<_, RoutePause>(
+        "SELECT src_chain, dest_chain, reason, paused_by, paused_at FROM route_pauses ORDER BY paused_at",
+    )
+    .fetch_all(pool)
+    .await
+    .wrap_err("Failed to get paused routes")
+}
+
+/// Whether approvals from `src_chain` to `dest_chain` are paused
+pub async fn is_route_paused(pool: &PgPool, src_chain: &[u8], dest_chain: &[u8]) -> Result<bool> {
+    let row: (bool,) = sqlx::query_as(
+        "SELECT EXISTS(SELECT 1 FROM route_pauses WHERE src_chain = $1 AND dest_chain = $2)",
+    )
+    .bind(src_chain)
+    .bind(dest_chain)
+    .fetch_one(pool)
+    .await
+    .wrap_err("Failed to check route pause")?;
+    Ok(row.0)
+}
+
+/// Record a transfer as manually resolved and move its EVM/Terra deposit rows to 'resolved'.
+///
+/// Returns the number of deposit rows updated.
+pub async fn resolve_transfer(
+    pool: &PgPool,
+    xchain_hash_id: &[u8],
+    resolved_by: &str,
+    note: Option<&str>,
+) -> Result<u64> {
+    let mut tx = pool.begin().await.wrap_err("Failed to begin transaction")?;
+    sqlx::query(
+        r#"
+        INSERT INTO resolved_transfers (xchain_hash_id, resolved_by, note)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (xchain_hash_id)
+        DO UPDATE SET resolved_by = EXCLUDED.resolved_by, note = EXCLUDED.note, resolved_at = NOW()
+        "#,
+    )
+    .bind(xchain_hash_id)
+    .bind(resolved_by)
+    .bind(note)
+    .execute(&mut *tx)
+    .await
+    .wrap_err("Failed to record resolved transfer")?;
+    let mut updated = 0;
+    for table in ["evm_deposits", "terra_deposits"] {
+        let query = format!(
+            "UPDATE {} SET status = 'resolved', updated_at = NOW() WHERE transfer_hash = $1 AND status <> 'resolved'",
+            table
+        );
+        updated += sqlx::query(&query)
+            .bind(xchain_hash_id)
+            .execute(&mut *tx)
+            .await
+            .wrap_err_with(|| format!("Failed to resolve {} rows", table))?
+            .rows_affected();
+    }
+    tx.commit().await.wrap_err("Failed to commit transaction")?;
+    Ok(updated)
+}
+
+/// Whether a transfer was resolved by hand
+pub async fn is_transfer_resolved(pool: &PgPool, xchain_hash_id: &[u8]) -> Result<bool> {
+    let row: (bool,) =
+        sqlx::query_as("SELECT EXISTS(SELECT 1 FROM resolved_transfers WHERE xchain_hash_id = $1)")
+            .bind(xchain_hash_id)
+            .fetch_one(pool)
+            .await
+            .wrap_err("Failed to check resolved transfer")?;
+    Ok(row.0)
+}
+
+/// Return a failed approval to 'pending' for immediate retry, regardless of attempts.
+///
+/// Returns false when the approval does not exist or is not failed.
+pub async fn force_retry_approval(pool: &PgPool, id: i64) -> Result<bool> {
+    let result = sqlx::query(
+        r#"
+        UPDATE approvals
+        SET status = 'pending', retry_after = NULL, claimed_by = NULL, claimed_at = NULL,
+            updated_at = NOW()
+        WHERE id = $1 AND status = 'failed'
+        "#,
+    )
+    .bind(id)
+    .execute(pool)
+    .await
+    .wrap_err_with(|| format!("Failed to force retry of approval {}", id))?;
+    Ok(result.rows_affected() > 0)
+}
+
+/// Return a failed release to 'pending' for immediate retry, regardless of attempts.
+///
+/// Returns false when the release does not exist or is not failed.
+pub async fn force_retry_release(pool: &PgPool, id: i64) -> Result<bool> {
+    let result = sqlx::query(
+        r#"
+        UPDATE releases
+        SET status = 'pending', retry_after = NULL, claimed_by = NULL, claimed_at = NULL,
+            updated_at = NOW()
+        WHERE id = $1 AND status = 'failed'
+        "#,
+    )
+    .bind(id)
+    .execute(pool)
+    .await
+    .wrap_err_with(|| format!("Failed to force retry of release {}", id))?;
+    Ok(result.rows_affected() > 0)
+}
+
+/// Pause `writer` for `pause_secs` (database clock)
+pub async fn trip_writer_breaker(
+    pool: &PgPool,
+    writer: &str,
+    pause_secs: i64,
+    reason: &str,
+    updated_by: &str,
+) -> Result<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO writer_breakers (writer, paused_until, reason, updated_by)
+        VALUES ($1, NOW() + make_interval(secs => $2), $3, $4)
+        ON CONFLICT (writer) DO UPDATE SET
+            paused_until = EXCLUDED.paused_until,
+            reason = EXCLUDED.reason,
+            updated_by = EXCLUDED.updated_by,
+            updated_at = NOW()
+        "#,
+    )
+    .bind(writer)
+    .bind(pause_secs as f64)
+    .bind(reason)
+    .bind(updated_by)
+    .execute(pool)
+    .await
+    .wrap_err_with(|| format!("Failed to trip {} writer breaker", writer))?;
+    Ok(())
+}
+
+/// Close `writer`'s breaker so it resumes on the next cycle
+pub async fn reset_writer_breaker(pool: &PgPool, writer: &str, updated_by: &str) -> Result<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO writer_breakers (writer, paused_until, reason, updated_by)
+        VALUES ($1, NULL, 'reset', $2)
+        ON CONFLICT (writer) DO UPDATE SET
+            paused_until = NULL, reason = 'reset', updated_by = EXCLUDED.updated_by, updated_at = NOW()
+        "#,
+    )
+    .bind(writer)
+    .bind(updated_by)
+    .execute(pool)
+    .await
+    .wrap_err_with(|| format!("Failed to reset {} writer breaker", writer))?;
+    Ok(())
+}
+
+/// Writers whose breaker is currently open (paused)
+pub async fn get_open_writer_breakers(pool: &PgPool) -> Result<Vec<String>> {
+    let rows: Vec<(String,)> =
+        sqlx::query_as("SELECT writer FROM writer_breakers WHERE paused_until > NOW()")
+            .fetch_all(pool)
+            .await
+            .wrap_err("Failed to get open writer breakers")?;
+    Ok(rows.into_iter().map(|r| r.0).collect())
+}
+
+/// All recorded writer breaker states
+pub async fn get_writer_breakers(pool: &PgPool) -> Result<Vec<WriterBreaker>> {
+    sqlx::query_as::<_, WriterBreaker>(
+        "SELECT writer, paused_until, reason, updated_by, updated_at FROM writer_breakers ORDER BY writer",
+    )
+    .fetch_all(pool)
+    .await
+    .wrap_err("Failed to get writer breakers")
+}
+
+/// Move a chain's watcher cursor so the next poll starts at `from_block`.
+///
+/// `chain_type` is "evm" (`chain_id` is the native chain ID) or "terra" (`chain_id` is the
+/// Cosmos chain ID). Returns false when the chain has no cursor yet.
+pub async fn rewind_block_cursor(
+    pool: &PgPool,
+    chain_type: &str,
+    chain_id: &str,
+    from_block: i64,
+) -> Result<bool> {
+    let target = (from_block - 1).max(0);
+    let result = match chain_type {
+        "evm" => {
+            let chain_id: i64 = chain_id
+                .parse()
+                .map_err(|_| eyre::eyre!("EVM chain_id must be numeric, got {:?}", chain_id))?;
+            sqlx::query(
+                "UPDATE evm_blocks SET last_processed_block = $2, updated_at = NOW() WHERE chain_id = $1",
+            )
+            .bind(chain_id)
+            .bind(target)
+            .execute(pool)
+            .await
+        }
+        "terra" => {
+            sqlx::query(
+                "UPDATE terra_blocks SET last_processed_height = $2, updated_at = NOW() WHERE chain_id = $1",
+            )
+            .bind(chain_id)
+            .bind(target)
+            .execute(pool)
+            .await
+        }
+        other => return Err(eyre::eyre!("Unknown chain type {:?}", other)),
+    }
+    .wrap_err("Failed to rewind block cursor")?;
+    Ok(result.rows_affected() > 0)
+}
+
+/// Append an admin API action to the audit log
+pub async fn insert_admin_audit(
+    pool: &PgPool,
+    actor: &str,
+    action: &str,
+    target: &str,
+    details: Option<&str>,
+    outcome: &str,
+) -> Result<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO admin_audit_log (actor, action, target, details, outcome)
+        VALUES ($1, $2, $3, $4, $5)
+        "#,
+    )
+    .bind(actor)
+    .bind(action)
+    .bind(target)
+    .bind(details)
+    .bind(outcome)
+    .execute(pool)
+    .await
+    .wrap_err("Failed to write admin audit log")?;
+    Ok(())
+}
+
+/// Most recent admin API actions, newest first
+pub async fn get_admin_audit(pool: &PgPool, limit: i64) -> Result<Vec<AdminAuditEntry>> {
+    sqlx::query_as::<_, AdminAuditEntry>(
+        r#"
+        SELECT id, actor, action, target, details, outcome, created_at
+        FROM admin_audit_log ORDER BY id DESC LIMIT $1
+        "#,
+    )
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+    .wrap_err("Failed to get admin audit log")
+}
+
+// ============================================================================
+// Relay requests (gasless withdrawals)
+// ============================================================================
+
+const RELAY_REQUEST_SELECT: &str = r#"id, src_chain_id, xchain_hash_id, dest_chain,
+    max_relay_fee::TEXT as max_relay_fee, tx_hash, block_number, status,
+    relay_fee::TEXT as relay_fee, relay_tx_hash, attempts, last_error, created_at, updated_at"#;
+
+/// Record a RelayRequested event; returns false when the request was already known
+pub async fn insert_relay_request(pool: &PgPool, request: &NewRelayRequest) -> Result<bool> {
+    let result = sqlx::query(
+        r#"
+        INSERT INTO relay_requests (src_chain_id, xchain_hash_id, dest_chain, max_relay_fee,
+            tx_hash, block_number)
+        VALUES ($1, $2, $3, $4::NUMERIC, $5, $6)
+        ON CONFLICT (xchain_hash_id) DO NOTHING
+        "#,
+    )
+    .bind(request.src_chain_id)
+    .bind(&request.xchain_hash_id)
+    .bind(&request.dest_chain)
+    .bind(&request.max_relay_fee)
+    .bind(&request.tx_hash)
+    .bind(request.block_number)
+    .execute(pool)
+    .await
+    .wrap_err("Failed to insert relay request")?;
+    Ok(result.rows_affected() > 0)
+}
+
+/// Claim pending relay requests bound for `dest_chain` for this writer instance
+///
+/// Same claim semantics as [`claim_pending_evm_deposits_for_evm`].
+pub async fn claim_pending_relay_requests(
+    pool: &PgPool,
+    dest_chain: &[u8; 4],
+    holder: &str,
+    fence: Option<&LeaseFence>,
+    claim_ttl_secs: i64,
+    limit: i64,
+) -> Result<Vec<RelayRequest>> {
+    let query = format!(
+        r#"UPDATE relay_requests SET claimed_by = $2, claimed_at = NOW()
+           WHERE id IN (
+               SELECT id FROM relay_requests
+               WHERE status = 'pending' AND dest_chain = $1
+                 AND (claimed_at IS NULL OR claimed_by = $2
+                      OR claimed_at < NOW() - ($3::BIGINT * INTERVAL '1 second'))
+                 AND {}
+               ORDER BY id
+               LIMIT $4
+               FOR UPDATE SKIP LOCKED
+           )
+           RETURNING {}"#,
+        lease_fence_clause(2, 5),
+        RELAY_REQUEST_SELECT
+    );
+    sqlx::query_as::<_, RelayRequest>(&query)
+        .bind(dest_chain.as_slice())
+        .bind(holder)
+        .bind(claim_ttl_secs)
+        .bind(limit)
+        .bind(fence.map(|f| f.lease.as_str()))
+        .bind(fence.map(|f| f.generation))
+        .fetch_all(pool)
+        .await
+        .wrap_err("Failed to claim pending relay requests")
+}
+
+/// Source-chain EVM deposit with the given transfer hash
+pub async fn get_evm_deposit_by_transfer_hash(
+    pool: &PgPool,
+    transfer_hash: &[u8],
+) -> Result<Option<EvmDeposit>> {
+    let query = format!(
+        "SELECT {} FROM evm_deposits WHERE transfer_hash = $1 ORDER BY id LIMIT 1",
+        EVM_DEPOSIT_SELECT
+    );
+    sqlx::query_as::<_, EvmDeposit>(&query)
+        .bind(transfer_hash)
+        .fetch_optional(pool)
+        .await
+        .wrap_err("Failed to get EVM deposit by transfer hash")
+}
+
+/// Source-chain Terra deposit with the given transfer hash
+pub async fn get_terra_deposit_by_transfer_hash(
+    pool: &PgPool,
+    transfer_hash: &[u8],
+) -> Result<Option<TerraDeposit>> {
+    sqlx::query_as::<_, TerraDeposit>(
+        r#"SELECT id, tx_hash, nonce, sender, recipient, token, amount::TEXT as amount,
+                  dest_chain_id, block_height, status, created_at, updated_at, dest_token_address, transfer_hash
+           FROM terra_deposits WHERE transfer_hash = $1 ORDER BY id LIMIT 1"#,
+    )
+    .bind(transfer_hash)
+    .fetch_optional(pool)
+    .await
+    .wrap_err("Failed to get Terra deposit by transfer hash")
+}
+
+/// Source-chain Solana deposit with the given transfer hash
+pub async fn get_solana_deposit_by_transfer_hash(
+    pool: &PgPool,
+    transfer_hash: &[u8],
+) -> Result<Option<SolanaDeposit>> {
+    sqlx::query_as::<_, SolanaDeposit>(
+        r#"SELECT id, nonce, transfer_hash, src_account, dest_chain, dest_account, token,
+                  amount::TEXT as amount, fee::TEXT as fee, slot, signature, processed
+           FROM solana_deposits WHERE transfer_hash = $1"#,
+    )
+    .bind(transfer_hash)
+    .fetch_optional(pool)
+    .await
+    .wrap_err("Failed to get Solana deposit by transfer hash")
+}
+
+/// Record that this operator submitted the withdrawal for a relay request
+pub async fn update_relay_request_submitted(
+    pool: &PgPool,
+    id: i64,
+    relay_fee: &str,
+    tx_hash: &str,
+) -> Result<()> {
+    sqlx::query(
+        r#"
+        UPDATE relay_requests
+        SET status = 'submitted', relay_fee = $2::NUMERIC, relay_tx_hash = $3,
+            attempts = attempts + 1, last_error = NULL, claimed_by = NULL, claimed_at = NULL,
+            updated_at = NOW()
+        WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .bind(relay_fee)
+    .bind(tx_hash)
+    .execute(pool)
+    .await
+    .wrap_err("Failed to mark relay request submitted")?;
+    Ok(())
+}
+
+/// Close a relay request without submitting ('declined', 'skipped' or 'failed')
+pub async fn close_relay_request(pool: &PgPool, id: i64, status: &str, reason: &str) -> Result<()> {
+    sqlx::query(
+        r#"
+        UPDATE relay_requests
+        SET status = $2, last_error = $3, claimed_by = NULL, claimed_at = NULL, updated_at = NOW()
+        WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .bind(status)
+    .bind(reason)
+    .execute(pool)
+    .await
+    .wrap_err("Failed to close relay request")?;
+    Ok(())
+}
+
+/// Record a failed relay attempt; the request gives up as 'failed' after `max_attempts`
+pub async fn record_relay_request_error(
+    pool: &PgPool,
+    id: i64,
+    error: &str,
+    max_attempts: i32,
+) -> Result<()> {
+    sqlx::query(
+        r#"
+        UPDATE relay_requests
+        SET attempts = attempts + 1, last_error = $2,
+            status = CASE WHEN attempts + 1 >= $3 THEN 'failed' ELSE status END,
+            claimed_by = NULL, claimed_at = NULL, updated_at = NOW()
+        WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .bind(error)
+    .bind(max_attempts)
+    .execute(pool)
+    .await
+    .wrap_err("Failed to record relay request error")?;
+    Ok(())
+}
+
+// ============================================================================
+// Withdraw executions (durable auto-execution)
+// ============================================================================
+
+const WITHDRAW_EXECUTION_SELECT: &str = r#"id, chain_type, dest_chain, xchain_hash_id, executable_at,
+    status, attempts, last_error, tx_hash, created_at, updated_at"#;
+
+/// Schedule an approved withdrawal for execution `delay_secs` from now
+///
+/// A withdrawal that was cancelled, reenabled and approved again is rescheduled;
+/// otherwise an existing row is left untouched. Returns true when a row was written.
+pub async fn schedule_withdraw_execution(
+    pool: &PgPool,
+    chain_type: &str,
+    dest_chain: &[u8; 4],
+    xchain_hash_id: &[u8; 32],
+    delay_secs: i64,
+) -> Result<bool> {
+    let result = sqlx::query(
+        r#"
+        INSERT INTO withdraw_executions (chain_type, dest_chain, xchain_hash_id, executable_at)
+        VALUES ($1, $2, $3, NOW() + ($4::BIGINT * INTERVAL '1 second'))
+        ON CONFLICT (dest_chain, xchain_hash_id) DO UPDATE
+        SET executable_at = EXCLUDED.executable_at, status = 'pending', attempts = 0,
+            last_error = NULL, claimed_by = NULL, claimed_at = NULL, updated_at = NOW()
+        WHERE withdraw_executions.status = 'cancelled'
+        "#,
+    )
+    .bind(chain_type)
+    .bind(dest_chain.as_slice())
+    .bind(xchain_hash_id.as_slice())
+    .bind(delay_secs)
+    .execute(pool)
+    .await
+    .wrap_err("Failed to schedule withdraw execution")?;
+    Ok(result.rows_affected() > 0)
+}
+
+/// Claim withdrawals on `dest_chain` whose cancel window has passed
+///
+/// Same claim semantics as [`claim_pending_evm_deposits_for_evm`].
+pub async fn claim_due_withdraw_executions(
+    pool: &PgPool,
+    dest_chain: &[u8; 4],
+    holder: &str,
+    fence: Option<&LeaseFence>,
+    claim_ttl_secs: i64,
+    limit: i64,
+) -> Result<Vec<WithdrawExecution>> {
+    let query = format!(
+        r#"UPDATE withdraw_executions SET claimed_by = $2, claimed_at = NOW()
+           WHERE id IN (
+               SELECT id FROM withdraw_executions
+               WHERE status = 'pending' AND dest_chain = $1 AND executable_at <= NOW()
+                 AND (claimed_at IS NULL OR claimed_by = $2
+                      OR claimed_at < NOW() - ($3::BIGINT * INTERVAL '1 second'))
+                 AND {}
+               ORDER BY executable_at
+               LIMIT $4
+               FOR UPDATE SKIP LOCKED
+           )
+           RETURNING {}"#,
+        lease_fence_clause(2, 5),
+        WITHDRAW_EXECUTION_SELECT
+    );
+    sqlx::query_as::<_, WithdrawExecution>(&query)
+        .bind(dest_chain.as_slice())
+        .bind(holder)
+        .bind(claim_ttl_secs)
+        .bind(limit)
+        .bind(fence.map(|f| f.lease.as_str()))
+        .bind(fence.map(|f| f.generation))
+        .fetch_all(pool)
+        .await
+        .wrap_err("Failed to claim due withdraw executions")
+}
+
+/// Close a withdraw execution as 'executed', 'cancelled' or 'failed'
+pub async fn close_withdraw_execution(
+    pool: &PgPool,
+    id: i64,
+    status: &str,
+    tx_hash: Option<&str>,
+    reason: Option<&str>,
+) -> Result<()> {
+    sqlx::query(
+        r#"
+        UPDATE withdraw_executions
+        SET status = $2, tx_hash = $3, last_error = $4, claimed_by = NULL, claimed_at = NULL,
+            updated_at = NOW()
+        WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .bind(status)
+    .bind(tx_hash)
+    .bind(reason)
+    .execute(pool)
+    .await
+    .wrap_err("Failed to close withdraw execution")?;
+    Ok(())
+}
+
+/// Move a withdraw execution to the on-chain window end (unix seconds) without counting an attempt
+pub async fn defer_withdraw_execution(pool: &PgPool, id: i64, executable_at: i64) -> Result<()> {
+    sqlx::query(
+        r#"
+        UPDATE withdraw_executions
+        SET executable_at = TO_TIMESTAMP($2), claimed_by = NULL, claimed_at = NULL,
+            updated_at = NOW()
+        WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .bind(executable_at as f64)
+    .execute(pool)
+    .await
+    .wrap_err("Failed to defer withdraw execution")?;
+    Ok(())
+}
+
+/// Record a failed execute attempt and retry after `retry_secs`; the row gives up as
+/// 'failed' after `max_attempts`
+pub async fn record_withdraw_execution_error(
+    pool: &PgPool,
+    id: i64,
+    error: &str,
+    retry_secs: i64,
+    max_attempts: i32,
+) -> Result<()> {
+    sqlx::query(
+        r#"
+        UPDATE withdraw_executions
+        SET attempts = attempts + 1, last_error = $2,
+            executable_at = NOW() + ($3::BIGINT * INTERVAL '1 second'),
+            status = CASE WHEN attempts + 1 >= $4 THEN 'failed' ELSE status END,
+            claimed_by = NULL, claimed_at = NULL, updated_at = NOW()
+        WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .bind(error)
+    .bind(retry_secs)
+    .bind(max_attempts)
+    .execute(pool)
+    .await
+    .wrap_err("Failed to record withdraw execution error")?;
+    Ok(())
+}
+
+/// Approved withdrawals still unexecuted past their cancel window, per chain type
+pub async fn count_overdue_withdraw_executions(pool: &PgPool) -> Result<Vec<(String, i64)>> {
+    sqlx::query_as::<_, (String, i64)>(
+        r#"
+        SELECT chain_type, COUNT(*) FROM withdraw_executions
+        WHERE status IN ('pending', 'failed') AND executable_at <= NOW()
+        GROUP BY chain_type
+        "#,
+    )
+    .fetch_all(pool)
+    .await
+    .wrap_err("Failed to count overdue withdraw executions")
 }
@@ -110,6 +110,39 @@ pub struct NewTerraDeposit {
     pub transfer_hash: Option<Vec<u8>>,
 }
 
+/// Represents a deposit from Solana (`solana_deposits`, written by the Solana watcher)
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct SolanaDeposit {
+    pub id: i64,
+    pub nonce: i64,
+    pub transfer_hash: Vec<u8>,
+    pub src_account: Vec<u8>,
+    pub dest_chain: Vec<u8>,
+    pub dest_account: Vec<u8>,
+    /// Destination-chain token (the value hashed into `transfer_hash`)
+    pub token: Vec<u8>,
+    pub amount: String,
+    pub fee: String,
+    pub slot: i64,
+    pub signature: String,
+    pub processed: Option<bool>,
+}
+
+/// For inserting new Solana deposits
+#[derive(Debug, Clone)]
+pub struct NewSolanaDeposit {
+    pub nonce: i64,
+    pub transfer_hash: Vec<u8>,
+    pub src_account: Vec<u8>,
+    pub dest_chain: Vec<u8>,
+    pub dest_account: Vec<u8>,
+    pub token: Vec<u8>,
+    pub amount: String,
+    pub fee: String,
+    pub slot: i64,
+    pub signature: String,
+}
+
 /// Represents a withdrawal approval submitted to an EVM chain
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
 pub struct Approval {
@@ -195,3 +228,141 @@ pub struct TerraBlock {
     pub last_processed_height: i64,
     pub updated_at: DateTime<Utc>,
 }
+
+/// Policy decision for a cross-chain transfer (`policy_transfers`, see `crate::policy`)
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct PolicyTransfer {
+    pub id: i64,
+    pub xchain_hash_id: Vec<u8>,
+    pub src_chain: Vec<u8>,
+    pub dest_chain: Vec<u8>,
+    pub src_account: Vec<u8>,
+    pub dest_account: Vec<u8>,
+    pub token: String,
+    pub amount: String,
+    pub nonce: i64,
+    /// 'allowed', 'held', 'released' or 'rejected'
+    pub status: String,
+    /// Rule violations that put the transfer on hold, separated by "; "
+    pub reasons: String,
+    pub decided_by: Option<String>,
+    pub decision_note: Option<String>,
+    pub decided_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// For inserting new policy decisions
+#[derive(Debug, Clone)]
+pub struct NewPolicyTransfer {
+    pub xchain_hash_id: Vec<u8>,
+    pub src_chain: Vec<u8>,
+    pub dest_chain: Vec<u8>,
+    pub src_account: Vec<u8>,
+    pub dest_account: Vec<u8>,
+    pub token: String,
+    pub amount: String,
+    pub nonce: i64,
+    pub status: String,
+    pub reasons: String,
+}
+
+/// Paused route (`route_pauses`); approvals from `src_chain` to `dest_chain` are skipped
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct RoutePause {
+    pub src_chain: Vec<u8>,
+    pub dest_chain: Vec<u8>,
+    pub reason: String,
+    pub paused_by: String,
+    pub paused_at: DateTime<Utc>,
+}
+
+/// Writer circuit breaker state (`writer_breakers`)
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct WriterBreaker {
+    /// 'evm', 'terra' or 'evm_to_evm'
+    pub writer: String,
+    /// Writer is paused while this is in the future
+    pub paused_until: Option<DateTime<Utc>>,
+    pub reason: String,
+    pub updated_by: String,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Admin API audit entry (`admin_audit_log`)
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct AdminAuditEntry {
+    pub id: i64,
+    pub actor: String,
+    pub action: String,
+    pub target: String,
+    pub details: Option<String>,
+    /// 'ok', 'not_found' or the error message
+    pub outcome: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Depositor's request for an operator-submitted withdrawal (`relay_requests`)
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct RelayRequest {
+    pub id: i64,
+    /// Native chain ID of an EVM source (`None` for Terra and Solana)
+    pub src_chain_id: Option<i64>,
+    pub xchain_hash_id: Vec<u8>,
+    pub dest_chain: Vec<u8>,
+    /// Source decimals
+    pub max_relay_fee: String,
+    pub tx_hash: String,
+    pub block_number: i64,
+    /// 'pending', 'submitted', 'declined', 'skipped' or 'failed'
+    pub status: String,
+    pub relay_fee: Option<String>,
+    pub relay_tx_hash: Option<String>,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// New relay request parsed from a RelayRequested event (EVM), `request_relay` action
+/// (Terra) or `RelayRequestedEvent` (Solana)
+#[derive(Debug, Clone)]
+pub struct NewRelayRequest {
+    /// Native chain ID of an EVM source (`None` for Terra and Solana)
+    pub src_chain_id: Option<i64>,
+    pub xchain_hash_id: Vec<u8>,
+    pub dest_chain: Vec<u8>,
+    pub max_relay_fee: String,
+    pub tx_hash: String,
+    pub block_number: i64,
+}
+
+/// Approved withdrawal awaiting auto-execution (`withdraw_executions`)
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct WithdrawExecution {
+    pub id: i64,
+    /// 'evm', 'terra' or 'solana'
+    pub chain_type: String,
+    pub dest_chain: Vec<u8>,
+    pub xchain_hash_id: Vec<u8>,
+    pub executable_at: DateTime<Utc>,
+    /// 'pending', 'executed', 'cancelled' or 'failed'
+    pub status: String,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub tx_hash: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Pending EVM/Terra deposit bound for Solana, a candidate for an operator
+/// `withdraw_submit_and_approve` when the recipient never submitted it
+#[derive(Debug, Clone, FromRow)]
+pub struct UnsubmittedSolanaDeposit {
+    /// 'evm' or 'terra'
+    pub source: String,
+    pub transfer_hash: Vec<u8>,
+    /// V2 source chain ID (EVM rows only; Terra rows take it from the bridge's deposit record)
+    pub src_v2_chain_id: Option<Vec<u8>>,
+    /// Solana mint the depositor chose (EVM rows only; `getDeposit` does not return it)
+    pub dest_token_address: Option<Vec<u8>>,
+}
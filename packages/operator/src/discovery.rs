@@ -118,6 +118,7 @@ mod tests {
                 bridge_address: "0x0000000000000000000000000000000000000001".to_string(),
                 private_key: "0x0000000000000000000000000000000000000000000000000000000000000001"
                     .to_string(),
+                extra_private_keys: vec![],
                 finality_blocks: 1,
                 this_chain_id: None,
                 use_v2_events: None,
@@ -128,6 +129,7 @@ mod tests {
                 chain_id: "localterra".to_string(),
                 bridge_address: "terra1xxx".to_string(),
                 mnemonic: "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about".to_string(),
+                extra_mnemonics: vec![],
                 fee_recipient: None,
                 this_chain_id: None,
             },
@@ -0,0 +1,368 @@
+//! Active/passive leader election between redundant operator instances
+//!
+//! Several operator processes may share one key and one database. Only the instance holding
+//! the `operator_leases` row submits transactions; the others keep their watchers running so
+//! they can take over with a warm database as soon as the lease expires.
+//!
+//! The lease is taken and renewed with a single conditional upsert evaluated against the
+//! database clock, so instance clock skew does not matter. A leader that fails to renew
+//! steps down immediately (it will re-take its own lease on the next successful renewal),
+//! which keeps it from writing after a standby could have taken over. A renewal that hangs
+//! cannot extend leadership either: the handle also expires one TTL after the renewal was
+//! sent, and writers abandon their in-flight cycle as soon as [`LeaderHandle::lost`] fires.
+//!
+//! Every change of holder bumps the lease `generation`. Row claims are fenced with it
+//! ([`fence`]), so a deposed leader cannot claim work even before it notices the loss.
+//!
+//! Configuration (all optional):
+//!
+//! - `OPERATOR_HA_ENABLED` — `true` to elect a leader; otherwise this instance always writes
+//! - `OPERATOR_INSTANCE_ID` — lease holder name (default `<HOSTNAME>-<pid>`)
+//! - `OPERATOR_LEASE_NAME` — lease row shared by the redundant set (default `operator-writer`)
+//! - `OPERATOR_LEASE_TTL_SECS` — lease lifetime (default 15)
+//! - `OPERATOR_LEASE_RENEW_SECS` — renewal interval, must be below the TTL (default 5)
+
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
+use eyre::{eyre, Result};
+use sqlx::PgPool;
+use tokio::sync::watch;
+use tokio::time::Instant;
+use tracing::{info, warn};
+
+use crate::db;
+use crate::metrics;
+
+const DEFAULT_LEASE_NAME: &str = "operator-writer";
+const DEFAULT_LEASE_TTL_SECS: u64 = 15;
+const DEFAULT_LEASE_RENEW_SECS: u64 = 5;
+
+/// Age after which another instance may take over a writer's row claim
+pub const CLAIM_TTL_SECS: i64 = 120;
+/// Rows claimed per writer pass
+pub const CLAIM_BATCH_SIZE: i64 = 50;
+
+/// Name this process uses for leases and row claims
+pub fn instance_id() -> &'static str {
+    static ID: OnceLock<String> = OnceLock::new();
+    ID.get_or_init(|| {
+        std::env::var("OPERATOR_INSTANCE_ID")
+            .ok()
+            .filter(|s| !s.is_empty())
+            .unwrap_or_else(|| {
+                let host = std::env::var("HOSTNAME").unwrap_or_else(|_| "operator".to_string());
+                format!("{}-{}", host, std::process::id())
+            })
+    })
+}
+
+/// Lease generation held by this process (0 while standby); see [`fence`]
+static FENCE_GENERATION: AtomicI64 = AtomicI64::new(0);
+/// Lease name, set only when HA is enabled
+static FENCE_LEASE: OnceLock<String> = OnceLock::new();
+
+/// Lease a row claim must still hold to succeed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeaseFence {
+    pub lease: String,
+    pub generation: i64,
+}
+
+/// Fence for this process's row claims, or `None` when HA is disabled and it always writes
+pub fn fence() -> Option<LeaseFence> {
+    FENCE_LEASE.get().map(|lease| LeaseFence {
+        lease: lease.clone(),
+        generation: FENCE_GENERATION.load(Ordering::SeqCst),
+    })
+}
+
+/// The lease as this instance last renewed it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Held {
+    generation: i64,
+    /// Local deadline after which a standby may have taken over (`None` without HA)
+    valid_until: Option<Instant>,
+}
+
+impl Held {
+    fn valid(&self) -> bool {
+        self.valid_until.is_none_or(|t| Instant::now() < t)
+    }
+}
+
+/// Leader election settings
+#[derive(Debug, Clone)]
+pub struct LeaderConfig {
+    pub enabled: bool,
+    pub lease_name: String,
+    pub ttl: Duration,
+    pub renew_interval: Duration,
+}
+
+impl LeaderConfig {
+    pub fn from_env() -> Result<Self> {
+        let enabled = std::env::var("OPERATOR_HA_ENABLED")
+            .is_ok_and(|v| v == "1" || v.eq_ignore_ascii_case("true"));
+        let secs = |name: &str, default: u64| -> Result<u64> {
+            match std::env::var(name) {
+                Ok(v) => v
+                    .parse()
+                    .map_err(|_| eyre!("{} must be a number of seconds, got {:?}", name, v)),
+                Err(_) => Ok(default),
+            }
+        };
+        let ttl = secs("OPERATOR_LEASE_TTL_SECS", DEFAULT_LEASE_TTL_SECS)?;
+        let renew = secs("OPERATOR_LEASE_RENEW_SECS", DEFAULT_LEASE_RENEW_SECS)?;
+        if renew == 0 || renew >= ttl {
+            return Err(eyre!(
+                "OPERATOR_LEASE_RENEW_SECS ({}) must be between 1 and OPERATOR_LEASE_TTL_SECS ({})",
+                renew,
+                ttl
+            ));
+        }
+        Ok(Self {
+            enabled,
+            lease_name: std::env::var("OPERATOR_LEASE_NAME")
+                .ok()
+                .filter(|s| !s.is_empty())
+                .unwrap_or_else(|| DEFAULT_LEASE_NAME.to_string()),
+            ttl: Duration::from_secs(ttl),
+            renew_interval: Duration::from_secs(renew),
+        })
+    }
+}
+
+/// Cheap, clonable view of whether this instance may write
+#[derive(Clone)]
+pub struct LeaderHandle {
+    rx: watch::Receiver<Option<Held>>,
+}
+
+impl LeaderHandle {
+    /// Handle for a single-instance deployment: always the leader
+    pub fn always() -> Self {
+        let (_tx, rx) = watch::channel(Some(Held {
+            generation: 0,
+            valid_until: None,
+        }));
+        Self { rx }
+    }
+
+    pub fn is_leader(&self) -> bool {
+        self.rx.borrow().is_some_and(|held| held.valid())
+    }
+
+    /// Resolves once this instance is no longer the leader of the current term: the lease was
+    /// lost, released or re-taken under a new generation, or its last renewal is a full TTL
+    /// old. Writers race their cycle against it.
+    pub async fn lost(&self) {
+        let mut rx = self.rx.clone();
+        let term = rx.borrow().map(|held| held.generation);
+        loop {
+            let held = *rx.borrow_and_update();
+            let Some(held) = held.filter(|h| h.valid() && Some(h.generation) == term) else {
+                return;
+            };
+            match held.valid_until {
+                Some(deadline) => {
+                    tokio::select! {
+                        changed = rx.changed() => {
+                            if changed.is_err() {
+                                tokio::time::sleep_until(deadline).await;
+                                return;
+                            }
+                        }
+                        _ = tokio::time::sleep_until(deadline) => return,
+                    }
+                }
+                None => {
+                    if rx.changed().await.is_err() {
+                        return std::future::pending().await;
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn role(&self) -> &'static str {
+        if self.is_leader() {
+            "leader"
+        } else {
+            "standby"
+        }
+    }
+}
+
+/// Background lease keeper
+pub struct LeaderElection {
+    db: PgPool,
+    config: LeaderConfig,
+    tx: watch::Sender<Option<Held>>,
+    stopping: AtomicBool,
+}
+
+impl LeaderElection {
+    /// Start electing when HA is enabled; otherwise return an always-leader handle
+    pub fn start(db: PgPool, config: LeaderConfig) -> (LeaderHandle, Option<Arc<Self>>) {
+        if !config.enabled {
+            metrics::set_leader(true);
+            return (LeaderHandle::always(), None);
+        }
+        let (tx, rx) = watch::channel(None);
+        metrics::set_leader(false);
+        let _ = FENCE_LEASE.set(config.lease_name.clone());
+        info!(
+            instance = instance_id(),
+            lease = %config.lease_name,
+            ttl_secs = config.ttl.as_secs(),
+            renew_secs = config.renew_interval.as_secs(),
+            "Operator HA enabled, starting as standby until the lease is acquired"
+        );
+        let election = Arc::new(Self {
+            db,
+            config,
+            tx,
+            stopping: AtomicBool::new(false),
+        });
+        let task = election.clone();
+        tokio::spawn(async move { task.run().await });
+        (LeaderHandle { rx }, Some(election))
+    }
+
+    async fn run(&self) {
+        let mut interval = tokio::time::interval(self.config.renew_interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            interval.tick().await;
+            if self.stopping.load(Ordering::SeqCst) {
+                return;
+            }
+            // The database measures the TTL from when it ran the upsert, which is after this
+            let sent_at = Instant::now();
+            let held = match db::try_acquire_lease(
+                &self.db,
+                &self.config.lease_name,
+                instance_id(),
+                self.config.ttl.as_secs() as i64,
+            )
+            .await
+            {
+                Ok(generation) => generation.map(|generation| Held {
+                    generation,
+                    valid_until: Some(sent_at + self.config.ttl),
+                }),
+                Err(e) => {
+                    warn!(error = %e, "Lease renewal failed, stepping down until it succeeds");
+                    None
+                }
+            };
+            if self.stopping.load(Ordering::SeqCst) {
+                return;
+            }
+            self.set_leader(held).await;
+        }
+    }
+
+    async fn set_leader(&self, held: Option<Held>) {
+        let was = self.tx.send_replace(held);
+        FENCE_GENERATION.store(held.map_or(0, |h| h.generation), Ordering::SeqCst);
+        let (was_leader, leader) = (was.is_some_and(|h| h.valid()), held.is_some());
+        let new_term = was.map(|h| h.generation) != held.map(|h| h.generation);
+        if was_leader == leader && !new_term {
+            return;
+        }
+        metrics::set_leader(leader);
+        if let Some(held) = held {
+            info!(
+                instance = instance_id(),
+                lease = %self.config.lease_name,
+                generation = held.generation,
+                "Acquired operator lease, this instance is now the leader"
+            );
+        } else {
+            let holder = db::get_lease_holder(&self.db, &self.config.lease_name)
+                .await
+                .ok()
+                .flatten();
+            warn!(
+                instance = instance_id(),
+                lease = %self.config.lease_name,
+                holder = holder.as_deref().unwrap_or("unknown"),
+                "Lost operator lease, this instance is now standby"
+            );
+        }
+    }
+
+    /// Give the lease up on shutdown so a standby takes over without waiting for expiry
+    pub async fn release(&self) {
+        self.stopping.store(true, Ordering::SeqCst);
+        self.tx.send_replace(None);
+        FENCE_GENERATION.store(0, Ordering::SeqCst);
+        metrics::set_leader(false);
+        match db::release_lease(&self.db, &self.config.lease_name, instance_id()).await {
+            Ok(true) => info!(lease = %self.config.lease_name, "Released operator lease"),
+            Ok(false) => {}
+            Err(e) => warn!(error = %e, "Failed to release operator lease, it will expire"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_always_handle_is_leader() {
+        let handle = LeaderHandle::always();
+        assert!(handle.is_leader());
+        assert_eq!(handle.role(), "leader");
+    }
+
+    #[test]
+    fn test_handle_follows_sender() {
+        let (tx, rx) = watch::channel(None);
+        let handle = LeaderHandle { rx };
+        assert_eq!(handle.role(), "standby");
+        tx.send_replace(Some(Held {
+            generation: 1,
+            valid_until: None,
+        }));
+        assert!(handle.clone().is_leader());
+    }
+
+    #[tokio::test]
+    async fn test_handle_expires_without_renewal() {
+        let ttl = Duration::from_millis(50);
+        let start = Instant::now();
+        let (_tx, rx) = watch::channel(Some(Held {
+            generation: 1,
+            valid_until: Some(start + ttl),
+        }));
+        let handle = LeaderHandle { rx };
+        assert!(handle.is_leader());
+
+        tokio::time::timeout(Duration::from_secs(5), handle.lost())
+            .await
+            .expect("lease never lapsed");
+        assert!(start.elapsed() >= ttl);
+        assert!(!handle.is_leader());
+    }
+
+    #[tokio::test]
+    async fn test_lost_fires_on_step_down() {
+        let (tx, rx) = watch::channel(Some(Held {
+            generation: 2,
+            valid_until: None,
+        }));
+        let handle = LeaderHandle { rx };
+        let lost = tokio::spawn({
+            let handle = handle.clone();
+            async move { handle.lost().await }
+        });
+        tx.send_replace(None);
+        lost.await.unwrap();
+        assert_eq!(handle.role(), "standby");
+    }
+}
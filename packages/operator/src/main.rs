@@ -7,10 +7,15 @@ mod contracts;
 mod db;
 mod discovery;
 pub mod hash;
+mod leader;
 mod liveness;
+mod mapping_check;
 mod metrics;
 mod multi_evm;
+mod policy;
+mod reindex;
 mod terra_client;
+mod token_cache;
 mod types;
 mod watchers;
 mod writers;
@@ -60,6 +65,14 @@ async fn async_main() -> eyre::Result<()> {
     // Initialize logging
     init_logging();
 
+    // `cl8y-operator reindex ...` rebuilds deposit rows instead of starting the relayer
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().map(String::as_str) == Some("reindex") {
+        let config = Config::load()?;
+        let db = db::create_pool(&config.database.url).await?;
+        return reindex::run(&config, db, &args[1..]).await;
+    }
+
     tracing::info!("Starting CL8Y Bridge Relayer");
 
     // Load configuration
@@ -111,9 +124,22 @@ async fn async_main() -> eyre::Result<()> {
         let _ = shutdown_tx5.send(()).await;
     });
 
+    // Deposit policy shared by every writer (disabled unless OPERATOR_POLICY_FILE is set);
+    // routes whose token mappings disagree across chains are never approved
+    let refused_routes = mapping_check::run(&config).await?;
+    let policy = std::sync::Arc::new(
+        policy::PolicyEngine::from_env(db.clone())?.with_refused_routes(refused_routes),
+    );
+
+    // Leader election between redundant instances (always the leader unless OPERATOR_HA_ENABLED)
+    let (leader, election) =
+        leader::LeaderElection::start(db.clone(), leader::LeaderConfig::from_env()?);
+
     // Create managers
     let watcher_manager = WatcherManager::new(&config, db.clone()).await?;
-    let mut writer_manager = WriterManager::new(&config, db.clone()).await?;
+    let token_cache = token_cache::start(&config).await?;
+    let mut writer_manager =
+        WriterManager::new(&config, db.clone(), policy.clone(), token_cache.clone()).await?;
     let mut confirmation_tracker = ConfirmationTracker::new(&config, db.clone()).await?;
 
     // Create optional Solana writer (runs as a standalone task with its own loop)
@@ -159,10 +185,11 @@ async fn async_main() -> eyre::Result<()> {
             Some(config.terra.bridge_address.clone()),
             sol_cfg.bytes4_chain_ids.clone(),
             sol_cfg.poll_interval_ms,
+            policy.clone(),
         ) {
             Ok(w) => {
                 tracing::info!("Solana writer created with EVM source verification");
-                Some(w)
+                Some(w.with_token_cache(token_cache.clone()))
             }
             Err(e) => {
                 tracing::warn!(error = %e, "Failed to create Solana writer; continuing without it");
@@ -188,8 +215,9 @@ async fn async_main() -> eyre::Result<()> {
     let api_addr = std::net::SocketAddr::from((api_bind, api_port));
     tracing::info!(port = api_port, "Starting API server");
     let api_db = db.clone();
+    let api_leader = leader.clone();
     tokio::spawn(async move {
-        if let Err(e) = api::start_api_server(api_addr, api_db).await {
+        if let Err(e) = api::start_api_server(api_addr, api_db, api_leader).await {
             tracing::error!(error = %e, "API server error");
         }
     });
@@ -206,9 +234,15 @@ async fn async_main() -> eyre::Result<()> {
     // Each branch is wrapped in spawn_blocking(catch_unwind) so a panic in one
     // task is logged and surfaced rather than silently killing the process.
     let watcher_handle = tokio::spawn(async move { watcher_manager.run(shutdown_rx).await });
-    let writer_handle = tokio::spawn(async move { writer_manager.run(shutdown_rx2).await });
-    let confirmation_handle =
-        tokio::spawn(async move { confirmation_tracker.run(shutdown_rx3).await });
+    let writer_leader = leader.clone();
+    let writer_handle =
+        tokio::spawn(async move { writer_manager.run(shutdown_rx2, writer_leader).await });
+    let confirmation_leader = leader.clone();
+    let confirmation_handle = tokio::spawn(async move {
+        confirmation_tracker
+            .run(shutdown_rx3, confirmation_leader)
+            .await
+    });
 
     // Solana writer runs as a separate task (its own polling loop).
     // Shutdown is handled by shutdown_rx5; we abort it if the main select exits first.
@@ -216,7 +250,7 @@ async fn async_main() -> eyre::Result<()> {
         let mut shutdown = shutdown_rx5;
         Some(tokio::spawn(async move {
             tokio::select! {
-                result = sw.run() => result,
+                result = sw.run(leader) => result,
                 _ = shutdown.recv() => {
                     tracing::info!("Solana writer received shutdown signal");
                     Ok(())
@@ -256,6 +290,11 @@ async fn async_main() -> eyre::Result<()> {
         h.abort();
     }
 
+    // Hand the lease to a standby right away instead of letting it expire
+    if let Some(election) = election {
+        election.release().await;
+    }
+
     tracing::info!("CL8Y Bridge Relayer stopped");
 
     // Flush stderr to ensure all log output is visible in cloud log collectors
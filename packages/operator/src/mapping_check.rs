@@ -0,0 +1,95 @@
+//! Startup token mapping consistency check
+//!
+//! Before the writers start, the operator reads every token mapping on the bridges it is
+//! configured for (see `multichain_rs::mappings`) and refuses to approve transfers on routes
+//! whose two sides disagree. Findings are logged; refused routes are handed to the
+//! [`PolicyEngine`](crate::policy::PolicyEngine) and stay refused until the mappings are
+//! fixed and the operator is restarted.
+//!
+//! A failure to run the check aborts startup unless `OPERATOR_SKIP_MAPPING_CHECK` is set, in
+//! which case no route is refused.
+
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use alloy::primitives::Address;
+use eyre::{Result, WrapErr};
+use multichain_rs::mappings::MappingChecker;
+use multichain_rs::trace::TraceEndpoint;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::config::Config;
+use crate::policy::RefusedRoute;
+
+const SKIP_ENV: &str = "OPERATOR_SKIP_MAPPING_CHECK";
+
+/// Run the check against every configured bridge and return the routes to refuse
+pub async fn run(config: &Config) -> Result<HashSet<RefusedRoute>> {
+    if std::env::var(SKIP_ENV).is_ok_and(|v| v == "1" || v.to_lowercase() == "true") {
+        tracing::warn!("Skipping token mapping check ({}=true)", SKIP_ENV);
+        return Ok(HashSet::new());
+    }
+
+    let report = async {
+        let checker = MappingChecker::connect(&endpoints(config)?).await?;
+        checker.check().await
+    }
+    .await
+    .wrap_err_with(|| format!("Token mapping check failed (set {}=true to skip)", SKIP_ENV))?;
+
+    for finding in &report.findings {
+        tracing::error!(
+            kind = %finding.kind,
+            chain = %finding.chain.to_hex(),
+            token = %finding.token,
+            remote_chain = ?finding.remote_chain.map(|c| c.to_hex()),
+            detail = %finding.detail,
+            "Token mapping inconsistency"
+        );
+    }
+    let refused: HashSet<RefusedRoute> = report
+        .refused_routes()
+        .map(|r| RefusedRoute::new(r.src_chain.0, r.dest_chain.0, &r.dest_token))
+        .collect();
+    tracing::info!(
+        routes_checked = report.routes_checked,
+        findings = report.findings.len(),
+        refused_routes = refused.len(),
+        "Token mapping check complete"
+    );
+    Ok(refused)
+}
+
+pub(crate) fn endpoints(config: &Config) -> Result<Vec<TraceEndpoint>> {
+    let mut evm_bridges = vec![(
+        config.evm.rpc_url.clone(),
+        config.evm.bridge_address.clone(),
+    )];
+    if let Some(multi) = &config.multi_evm {
+        for chain in multi.enabled_chains() {
+            evm_bridges.push((chain.rpc_url.clone(), chain.bridge_address.clone()));
+        }
+    }
+
+    let mut seen = HashSet::new();
+    let mut endpoints = Vec::new();
+    for (rpc_url, bridge) in evm_bridges {
+        let bridge = Address::from_str(&bridge)
+            .wrap_err_with(|| format!("Invalid EVM bridge address {}", bridge))?;
+        if seen.insert(bridge) {
+            endpoints.push(TraceEndpoint::Evm { rpc_url, bridge });
+        }
+    }
+    endpoints.push(TraceEndpoint::Terra {
+        lcd_url: config.terra.lcd_url.clone(),
+        bridge: config.terra.bridge_address.clone(),
+    });
+    if let Some(solana) = &config.solana {
+        endpoints.push(TraceEndpoint::Solana {
+            rpc_url: solana.rpc_url.clone(),
+            program_id: Pubkey::from_str(&solana.program_id)
+                .wrap_err_with(|| format!("Invalid Solana program ID {}", solana.program_id))?,
+        });
+    }
+    Ok(endpoints)
+}
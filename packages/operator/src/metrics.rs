@@ -101,6 +101,67 @@ lazy_static! {
         "Total volume bridged (in base units)",
         &["direction", "token"]
     ).unwrap();
+
+    pub static ref ADMIN_ACTIONS: CounterVec = register_counter_vec!(
+        "relayer_admin_actions_total",
+        "Actions taken through the admin API",
+        &["action"]
+    ).unwrap();
+
+    pub static ref WITHDRAW_RELAYS: CounterVec = register_counter_vec!(
+        "relayer_withdraw_relays_total",
+        "Relay requests handled (submitted, declined, skipped, error)",
+        &["outcome"]
+    ).unwrap();
+
+    pub static ref SOLANA_OPERATOR_SUBMITS: CounterVec = register_counter_vec!(
+        "relayer_solana_operator_submits_total",
+        "Solana withdrawals submitted by the operator for recipients (submitted, skipped, error)",
+        &["outcome"]
+    ).unwrap();
+
+    pub static ref WITHDRAW_EXECUTIONS: CounterVec = register_counter_vec!(
+        "relayer_withdraw_executions_total",
+        "Withdraw executions after the cancel window (executed, already_executed, cancelled, error)",
+        &["chain", "outcome"]
+    ).unwrap();
+
+    pub static ref WITHDRAW_EXECUTIONS_OVERDUE: GaugeVec = register_gauge_vec!(
+        "relayer_withdraw_executions_overdue",
+        "Approved withdrawals still unexecuted after their cancel window passed",
+        &["chain"]
+    ).unwrap();
+
+    pub static ref IS_LEADER: Gauge = register_gauge!(
+        "relayer_is_leader",
+        "Whether this instance holds the operator lease and runs the writers (1) or is standby (0)"
+    ).unwrap();
+
+    // Deposit policy metrics
+    pub static ref POLICY_DECISIONS: CounterVec = register_counter_vec!(
+        "relayer_policy_decisions_total",
+        "Deposit policy decisions (allowed, held, released, rejected)",
+        &["decision"]
+    ).unwrap();
+
+    // Signer pool metrics
+    pub static ref SIGNER_BALANCE: GaugeVec = register_gauge_vec!(
+        "relayer_signer_balance",
+        "Native balance of each operator signer key, in the chain's base unit",
+        &["chain", "address"]
+    ).unwrap();
+
+    pub static ref SIGNER_LOW_BALANCE: GaugeVec = register_gauge_vec!(
+        "relayer_signer_low_balance",
+        "Whether a signer key is below its low-balance threshold (1) or not (0)",
+        &["chain", "address"]
+    ).unwrap();
+
+    pub static ref SIGNER_RETIRED: GaugeVec = register_gauge_vec!(
+        "relayer_signer_retired",
+        "Whether a signer key is retired from its pool for lack of gas (1) or active (0)",
+        &["chain", "address"]
+    ).unwrap();
 }
 
 /// Record a block processed
@@ -151,6 +212,20 @@ pub fn set_pending_approvals(chain: &str, count: i64) {
         .set(count as f64);
 }
 
+/// Record the outcome of a withdraw execution submitted after the cancel window
+pub fn record_execution_submitted(chain: &str, outcome: &str) {
+    WITHDRAW_EXECUTIONS
+        .with_label_values(&[chain, outcome])
+        .inc();
+}
+
+/// Update approved-but-unexecuted withdrawals past their cancel window
+pub fn set_withdraw_executions_overdue(chain: &str, count: i64) {
+    WITHDRAW_EXECUTIONS_OVERDUE
+        .with_label_values(&[chain])
+        .set(count as f64);
+}
+
 /// Record an error
 pub fn record_error(chain: &str, error_type: &str) {
     ERRORS.with_label_values(&[chain, error_type]).inc();
@@ -188,3 +263,49 @@ pub fn record_volume(direction: &str, token: &str, amount: f64) {
         .with_label_values(&[direction, token])
         .inc_by(amount);
 }
+
+/// Record a deposit policy decision
+pub fn record_policy_decision(decision: &str) {
+    POLICY_DECISIONS.with_label_values(&[decision]).inc();
+}
+
+/// Record whether this instance is the HA leader
+pub fn set_leader(leader: bool) {
+    IS_LEADER.set(if leader { 1.0 } else { 0.0 });
+}
+
+/// Record an admin API action
+pub fn record_admin_action(action: &str) {
+    ADMIN_ACTIONS.with_label_values(&[action]).inc();
+}
+
+/// Record the outcome of a gasless withdrawal relay request
+pub fn record_withdraw_relay(outcome: &str) {
+    WITHDRAW_RELAYS.with_label_values(&[outcome]).inc();
+}
+
+/// Record the outcome of an operator `withdraw_submit_and_approve` on Solana
+pub fn record_solana_operator_submit(outcome: &str) {
+    SOLANA_OPERATOR_SUBMITS.with_label_values(&[outcome]).inc();
+}
+
+/// Update a signer key's balance
+pub fn set_signer_balance(chain: &str, address: &str, balance: f64) {
+    SIGNER_BALANCE
+        .with_label_values(&[chain, address])
+        .set(balance);
+}
+
+/// Record whether a signer key is below its low-balance threshold
+pub fn set_signer_low_balance(chain: &str, address: &str, low: bool) {
+    SIGNER_LOW_BALANCE
+        .with_label_values(&[chain, address])
+        .set(if low { 1.0 } else { 0.0 });
+}
+
+/// Record whether a signer key is retired from its pool
+pub fn set_signer_retired(chain: &str, address: &str, retired: bool) {
+    SIGNER_RETIRED
+        .with_label_values(&[chain, address])
+        .set(if retired { 1.0 } else { 0.0 });
+}
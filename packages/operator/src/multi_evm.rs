@@ -25,6 +25,7 @@ impl EvmChainConfigExt for EvmChainConfig {
             chain_id: self.chain_id,
             bridge_address: self.bridge_address.clone(),
             private_key: private_key.to_string(),
+            extra_private_keys: vec![],
             finality_blocks: self.finality_blocks,
             this_chain_id: Some(self.this_chain_id.to_u32()),
             use_v2_events: Some(true),
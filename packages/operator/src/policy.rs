@@ -0,0 +1,738 @@
+//! Deposit-level policy engine
+//!
+//! Every writer asks the engine about a transfer after it has been verified on the source
+//! chain and before it submits the approval. Transfers that trip a rule are recorded with
+//! status `held` in `policy_transfers` and are not approved until a reviewer releases them
+//! through the API (`POST /held/{hash}/release`); rejected transfers are never approved.
+//!
+//! Rules (loaded from the JSON file named by `OPERATOR_POLICY_FILE`):
+//!
+//! ```json
+//! {
+//!   "denylist_file": "/etc/cl8y/denylist.txt",
+//!   "thresholds": [
+//!     {"token": "0xabc...", "max_amount": "1000000000000000000000"},
+//!     {"token": "uluna", "src_chain": "0x00000001", "max_amount": 5000000000}
+//!   ],
+//!   "velocity": [
+//!     {"window_secs": 3600, "max_transfers": 10, "account": "src"},
+//!     {"window_secs": 86400, "max_amount": "50000000000", "token": "uluna", "account": "dest"}
+//!   ]
+//! }
+//! ```
+//!
+//! - **Denylist:** one account per line (`#` starts a comment). EVM addresses (`0x` + 20 or
+//!   32 bytes), Terra `terra1...` addresses and Solana base58 keys are normalized to the
+//!   bytes32 form used in transfer hashes. The file is re-read when its mtime changes, so
+//!   screening lists can be updated without a restart. It is checked before every
+//!   approval, including transfers already allowed or released.
+//! - **Thresholds:** a transfer of `token` above `max_amount` is held, optionally only on one
+//!   route. `token` is the destination token as the approving bridge names it (`0x` address
+//!   on EVM, denom or CW20 address on Terra, mint on Solana).
+//! - **Velocity:** holds a transfer that would take an account past `max_transfers` or
+//!   `max_amount` within `window_secs`. Only approved (`allowed`/`released`) transfers count.
+//!   Evaluations touching the same account are serialized, so concurrent writers cannot
+//!   both fit under a limit.
+//!
+//! Without `OPERATOR_POLICY_FILE` the engine is disabled and approves everything.
+//!
+//! Independently of the rules, [`PolicyEngine::permits`] skips transfers on routes paused
+//! through the admin API, transfers an admin marked as manually resolved, and transfers on
+//! routes refused by the startup token mapping check (see [`crate::mapping_check`]).
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::RwLock;
+use std::time::SystemTime;
+
+use eyre::{eyre, Result, WrapErr};
+use serde::{Deserialize, Deserializer};
+use sqlx::PgPool;
+use tracing::{debug, info, warn};
+
+use crate::db::{self, NewPolicyTransfer};
+use crate::hash::{bytes32_to_hex, encode_terra_address_to_bytes32};
+use crate::metrics;
+use crate::types::ChainId;
+
+/// Environment variable naming the policy JSON file
+pub const POLICY_FILE_ENV: &str = "OPERATOR_POLICY_FILE";
+
+/// Policy rules file
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PolicyConfig {
+    /// Path to the account denylist (one account per line)
+    #[serde(default)]
+    pub denylist_file: Option<String>,
+    #[serde(default)]
+    pub thresholds: Vec<ThresholdRule>,
+    #[serde(default)]
+    pub velocity: Vec<VelocityRule>,
+}
+
+/// Manual sign-off above an amount for a token, optionally on one route
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ThresholdRule {
+    pub token: String,
+    #[serde(default, deserialize_with = "de_opt_chain")]
+    pub src_chain: Option<ChainId>,
+    #[serde(default, deserialize_with = "de_opt_chain")]
+    pub dest_chain: Option<ChainId>,
+    /// Largest amount approved without review (destination-chain base units)
+    #[serde(deserialize_with = "de_amount")]
+    pub max_amount: u128,
+}
+
+/// Which account of a transfer a velocity rule tracks
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AccountSide {
+    #[default]
+    Src,
+    Dest,
+}
+
+impl AccountSide {
+    fn as_str(self) -> &'static str {
+        match self {
+            AccountSide::Src => "src",
+            AccountSide::Dest => "dest",
+        }
+    }
+}
+
+/// Per-account limits over a sliding window
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct VelocityRule {
+    pub window_secs: u64,
+    #[serde(default)]
+    pub max_transfers: Option<i64>,
+    #[serde(default, deserialize_with = "de_opt_amount")]
+    pub max_amount: Option<u128>,
+    /// Only count transfers of this token (all tokens when unset)
+    #[serde(default)]
+    pub token: Option<String>,
+    #[serde(default)]
+    pub account: AccountSide,
+}
+
+impl PolicyConfig {
+    pub fn from_json(raw: &str) -> Result<Self> {
+        let config: PolicyConfig =
+            serde_json::from_str(raw).wrap_err("Invalid policy configuration")?;
+        for rule in &config.velocity {
+            if rule.window_secs == 0 {
+                return Err(eyre!("velocity rule window_secs must be > 0"));
+            }
+            if rule.max_transfers.is_none() && rule.max_amount.is_none() {
+                return Err(eyre!("velocity rule needs max_transfers and/or max_amount"));
+            }
+        }
+        Ok(config)
+    }
+}
+
+/// The fields of a transfer the rules look at, as read from the destination chain
+#[derive(Debug, Clone)]
+pub struct TransferFacts {
+    pub xchain_hash_id: [u8; 32],
+    pub src_chain: [u8; 4],
+    pub dest_chain: [u8; 4],
+    pub src_account: [u8; 32],
+    pub dest_account: [u8; 32],
+    /// Destination token (`0x` address, Terra denom/CW20, or Solana mint)
+    pub token: String,
+    pub amount: u128,
+    pub nonce: u64,
+}
+
+/// Direction of a route refused by the startup mapping check
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RefusedRoute {
+    src_chain: [u8; 4],
+    dest_chain: [u8; 4],
+    /// Destination token, `0x` addresses lowercased
+    dest_token: String,
+}
+
+impl RefusedRoute {
+    pub fn new(src_chain: [u8; 4], dest_chain: [u8; 4], dest_token: &str) -> Self {
+        Self {
+            src_chain,
+            dest_chain,
+            dest_token: normalize_token(dest_token),
+        }
+    }
+
+    fn of(facts: &TransferFacts) -> Self {
+        Self::new(facts.src_chain, facts.dest_chain, &facts.token)
+    }
+}
+
+fn normalize_token(token: &str) -> String {
+    if token.starts_with("0x") {
+        token.to_lowercase()
+    } else {
+        token.to_string()
+    }
+}
+
+/// Outcome of a policy check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolicyDecision {
+    /// Submit the approval
+    Approve,
+    /// Waiting for manual review; skip for now
+    Hold,
+    /// Rejected by a reviewer; never approve
+    Reject,
+}
+
+struct Denylist {
+    path: PathBuf,
+    modified: Option<SystemTime>,
+    accounts: HashSet<[u8; 32]>,
+}
+
+struct Rules {
+    config: PolicyConfig,
+    denylist: Option<RwLock<Denylist>>,
+}
+
+/// Policy engine shared by all writers
+pub struct PolicyEngine {
+    db: PgPool,
+    rules: Option<Rules>,
+    refused_routes: HashSet<RefusedRoute>,
+}
+
+impl PolicyEngine {
+    /// Engine without rules: approves every transfer that admins have not paused or resolved
+    pub fn disabled(db: PgPool) -> Self {
+        Self {
+            db,
+            rules: None,
+            refused_routes: HashSet::new(),
+        }
+    }
+
+    /// Load the rules named by `OPERATOR_POLICY_FILE`; disabled when unset
+    pub fn from_env(db: PgPool) -> Result<Self> {
+        match std::env::var(POLICY_FILE_ENV) {
+            Ok(path) if !path.is_empty() => {
+                let raw = std::fs::read_to_string(&path)
+                    .wrap_err_with(|| format!("Failed to read policy file {}", path))?;
+                let config = PolicyConfig::from_json(&raw)
+                    .wrap_err_with(|| format!("Failed to load policy file {}", path))?;
+                Self::new(db, config)
+            }
+            _ => Ok(Self::disabled(db)),
+        }
+    }
+
+    pub fn new(db: PgPool, config: PolicyConfig) -> Result<Self> {
+        let denylist = match &config.denylist_file {
+            Some(path) => {
+                let mut list = Denylist {
+                    path: PathBuf::from(path),
+                    modified: None,
+                    accounts: HashSet::new(),
+                };
+                reload_denylist(&mut list)?;
+                Some(RwLock::new(list))
+            }
+            None => None,
+        };
+        info!(
+            denylist = denylist.is_some(),
+            thresholds = config.thresholds.len(),
+            velocity_rules = config.velocity.len(),
+            "Deposit policy engine enabled"
+        );
+        Ok(Self {
+            db,
+            rules: Some(Rules { config, denylist }),
+            refused_routes: HashSet::new(),
+        })
+    }
+
+    /// Never approve transfers on these routes
+    pub fn with_refused_routes(mut self, routes: HashSet<RefusedRoute>) -> Self {
+        self.refused_routes = routes;
+        self
+    }
+
+    /// Decide whether the transfer may be approved now.
+    ///
+    /// The first evaluation is recorded; later calls return the stored decision, so a held
+    /// transfer stays held until a reviewer releases or rejects it. The denylist is checked
+    /// on every call: an approved transfer whose account was denylisted since is held again.
+    /// Errors mean "do not approve yet" — callers skip the transfer and retry on the next
+    /// cycle.
+    pub async fn check(&self, facts: &TransferFacts) -> Result<PolicyDecision> {
+        let Some(rules) = &self.rules else {
+            return Ok(PolicyDecision::Approve);
+        };
+
+        let mut reasons = Vec::new();
+        if let Some(denylist) = &rules.denylist {
+            let mut list = denylist
+                .write()
+                .map_err(|_| eyre!("policy denylist lock poisoned"))?;
+            if let Err(e) = reload_denylist(&mut list) {
+                warn!(error = %e, "Failed to reload policy denylist, keeping previous entries");
+            }
+            reasons.extend(denylist_reasons(&list.accounts, facts));
+        }
+
+        if let Some(existing) = db::get_policy_transfer(&self.db, &facts.xchain_hash_id).await? {
+            let decision = decision_for_status(&existing.status);
+            if decision != PolicyDecision::Approve || reasons.is_empty() {
+                return Ok(decision);
+            }
+            let reasons = reasons.join("; ");
+            if db::hold_policy_transfer(&self.db, &facts.xchain_hash_id, &reasons).await? {
+                self.record_hold(facts, &reasons).await;
+            }
+            return Ok(PolicyDecision::Hold);
+        }
+
+        reasons.extend(threshold_reasons(&rules.config.thresholds, facts));
+
+        // Velocity totals must not change between reading them and recording this transfer
+        let mut tx = self
+            .db
+            .begin()
+            .await
+            .wrap_err("Failed to begin policy transaction")?;
+        if !rules.config.velocity.is_empty() {
+            db::lock_policy_accounts(&mut tx, &[facts.src_account, facts.dest_account]).await?;
+        }
+        for rule in &rules.config.velocity {
+            let since = chrono::Utc::now() - chrono::Duration::seconds(rule.window_secs as i64);
+            let account = match rule.account {
+                AccountSide::Src => &facts.src_account,
+                AccountSide::Dest => &facts.dest_account,
+            };
+            let (count, total) = db::policy_velocity(
+                &mut tx,
+                rule.account.as_str(),
+                account,
+                rule.token.as_deref(),
+                since,
+            )
+            .await?;
+            let total: u128 = total
+                .parse()
+                .wrap_err_with(|| format!("Invalid policy velocity total {}", total))?;
+            if let Some(reason) = velocity_reason(rule, facts, count, total) {
+                reasons.push(reason);
+            }
+        }
+
+        let status = if reasons.is_empty() {
+            "allowed"
+        } else {
+            "held"
+        };
+        let stored = db::insert_policy_transfer(
+            &mut tx,
+            &NewPolicyTransfer {
+                xchain_hash_id: facts.xchain_hash_id.to_vec(),
+                src_chain: facts.src_chain.to_vec(),
+                dest_chain: facts.dest_chain.to_vec(),
+                src_account: facts.src_account.to_vec(),
+                dest_account: facts.dest_account.to_vec(),
+                token: facts.token.clone(),
+                amount: facts.amount.to_string(),
+                nonce: facts.nonce as i64,
+                status: status.to_string(),
+                reasons: reasons.join("; "),
+            },
+        )
+        .await?;
+        tx.commit()
+            .await
+            .wrap_err("Failed to commit policy decision")?;
+
+        if stored.status == "held" && !reasons.is_empty() {
+            self.record_hold(facts, &stored.reasons).await;
+        } else if stored.status == "allowed" {
+            metrics::record_policy_decision("allowed");
+        }
+
+        Ok(decision_for_status(&stored.status))
+    }
+
+    /// Log and count a transfer the rules just put on hold, and park its deposit rows
+    async fn record_hold(&self, facts: &TransferFacts, reasons: &str) {
+        metrics::record_policy_decision("held");
+        warn!(
+            hash = %bytes32_to_hex(&facts.xchain_hash_id),
+            src_chain = %format!("0x{}", hex::encode(facts.src_chain)),
+            nonce = facts.nonce,
+            amount = facts.amount,
+            token = %facts.token,
+            reasons = %reasons,
+            "Transfer held for manual review by deposit policy"
+        );
+        if let Err(e) =
+            db::set_deposit_status_for_hash(&self.db, &facts.xchain_hash_id, "pending", "held")
+                .await
+        {
+            warn!(error = %e, "Failed to mark deposit rows held");
+        }
+    }
+
+    /// [`check`](Self::check) for writers, after the admin controls (paused routes and
+    /// manually resolved transfers): logs holds, rejections and errors and returns whether
+    /// the approval may be submitted now
+    pub async fn permits(&self, facts: &TransferFacts) -> bool {
+        let hash = bytes32_to_hex(&facts.xchain_hash_id);
+        match self.admin_block(facts).await {
+            Ok(None) => {}
+            Ok(Some(reason)) => {
+                debug!(hash = %hash, nonce = facts.nonce, reason, "Transfer blocked by admin control, not approving");
+                return false;
+            }
+            Err(e) => {
+                warn!(hash = %hash, error = %e, "Admin control check failed, will retry");
+                return false;
+            }
+        }
+        match self.check(facts).await {
+            Ok(PolicyDecision::Approve) => true,
+            Ok(PolicyDecision::Hold) => {
+                debug!(hash = %hash, nonce = facts.nonce, "Transfer held by deposit policy, not approving");
+                false
+            }
+            Ok(PolicyDecision::Reject) => {
+                debug!(hash = %hash, nonce = facts.nonce, "Transfer rejected in policy review, not approving");
+                false
+            }
+            Err(e) => {
+                warn!(hash = %hash, error = %e, "Deposit policy check failed, will retry");
+                false
+            }
+        }
+    }
+
+    /// Why an admin control blocks the transfer, if one does
+    async fn admin_block(&self, facts: &TransferFacts) -> Result<Option<&'static str>> {
+        if db::is_transfer_resolved(&self.db, &facts.xchain_hash_id).await? {
+            return Ok(Some("manually resolved"));
+        }
+        if db::is_route_paused(&self.db, &facts.src_chain, &facts.dest_chain).await? {
+            return Ok(Some("route paused"));
+        }
+        if self.refused_routes.contains(&RefusedRoute::of(facts)) {
+            return Ok(Some("mapping check failed"));
+        }
+        Ok(None)
+    }
+}
+
+fn decision_for_status(status: &str) -> PolicyDecision {
+    match status {
+        "allowed" | "released" => PolicyDecision::Approve,
+        "rejected" => PolicyDecision::Reject,
+        _ => PolicyDecision::Hold,
+    }
+}
+
+fn reload_denylist(list: &mut Denylist) -> Result<()> {
+    let modified = std::fs::metadata(&list.path)
+        .and_then(|m| m.modified())
+        .wrap_err_with(|| format!("Failed to stat denylist {}", list.path.display()))?;
+    if list.modified == Some(modified) {
+        return Ok(());
+    }
+    let raw = std::fs::read_to_string(&list.path)
+        .wrap_err_with(|| format!("Failed to read denylist {}", list.path.display()))?;
+    list.accounts = parse_denylist(&raw)?;
+    list.modified = Some(modified);
+    info!(
+        path = %list.path.display(),
+        accounts = list.accounts.len(),
+        "Loaded policy denylist"
+    );
+    Ok(())
+}
+
+/// Parse denylist file contents into normalized bytes32 accounts
+pub fn parse_denylist(raw: &str) -> Result<HashSet<[u8; 32]>> {
+    let mut accounts = HashSet::new();
+    for (i, line) in raw.lines().enumerate() {
+        let entry = line.split('#').next().unwrap_or("").trim();
+        if entry.is_empty() {
+            continue;
+        }
+        let account = parse_account(entry)
+            .wrap_err_with(|| format!("denylist line {}: {:?}", i + 1, entry))?;
+        accounts.insert(account);
+    }
+    Ok(accounts)
+}
+
+/// Normalize an EVM, Terra or Solana account to the bytes32 form used in transfer hashes
+pub fn parse_account(s: &str) -> Result<[u8; 32]> {
+    if let Some(hex_str) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        let raw = hex::decode(hex_str).wrap_err("invalid hex account")?;
+        let mut out = [0u8; 32];
+        match raw.len() {
+            20 => out[12..].copy_from_slice(&raw),
+            32 => out.copy_from_slice(&raw),
+            n => return Err(eyre!("hex account must be 20 or 32 bytes, got {}", n)),
+        }
+        return Ok(out);
+    }
+    if s.starts_with("terra1") {
+        return encode_terra_address_to_bytes32(s).map_err(|e| eyre!(e));
+    }
+    let raw = bs58::decode(s)
+        .into_vec()
+        .map_err(|e| eyre!("invalid base58 account: {}", e))?;
+    raw.try_into()
+        .map_err(|v: Vec<u8>| eyre!("base58 account must be 32 bytes, got {}", v.len()))
+}
+
+fn denylist_reasons(accounts: &HashSet<[u8; 32]>, facts: &TransferFacts) -> Vec<String> {
+    let mut reasons = Vec::new();
+    if accounts.contains(&facts.src_account) {
+        reasons.push(format!(
+            "src account {} is denylisted",
+            bytes32_to_hex(&facts.src_account)
+        ));
+    }
+    if accounts.contains(&facts.dest_account) {
+        reasons.push(format!(
+            "dest account {} is denylisted",
+            bytes32_to_hex(&facts.dest_account)
+        ));
+    }
+    reasons
+}
+
+fn threshold_reasons(rules: &[ThresholdRule], facts: &TransferFacts) -> Vec<String> {
+    rules
+        .iter()
+        .filter(|r| r.token.eq_ignore_ascii_case(&facts.token))
+        .filter(|r| r.src_chain.is_none_or(|c| c.as_bytes() == &facts.src_chain))
+        .filter(|r| {
+            r.dest_chain
+                .is_none_or(|c| c.as_bytes() == &facts.dest_chain)
+        })
+        .filter(|r| facts.amount > r.max_amount)
+        .map(|r| {
+            format!(
+                "amount {} of {} exceeds review threshold {}",
+                facts.amount, facts.token, r.max_amount
+            )
+        })
+        .collect()
+}
+
+/// `count`/`total` are the account's approved transfers already in the window
+fn velocity_reason(
+    rule: &VelocityRule,
+    facts: &TransferFacts,
+    count: i64,
+    total: u128,
+) -> Option<String> {
+    if rule
+        .token
+        .as_ref()
+        .is_some_and(|t| !t.eq_ignore_ascii_case(&facts.token))
+    {
+        return None;
+    }
+    let account = match rule.account {
+        AccountSide::Src => &facts.src_account,
+        AccountSide::Dest => &facts.dest_account,
+    };
+    if let Some(max) = rule.max_transfers {
+        if count + 1 > max {
+            return Some(format!(
+                "{} account {} exceeds {} transfers per {}s",
+                rule.account.as_str(),
+                bytes32_to_hex(account),
+                max,
+                rule.window_secs
+            ));
+        }
+    }
+    if let Some(max) = rule.max_amount {
+        if total.saturating_add(facts.amount) > max {
+            return Some(format!(
+                "{} account {} exceeds amount {} per {}s",
+                rule.account.as_str(),
+                bytes32_to_hex(account),
+                max,
+                rule.window_secs
+            ));
+        }
+    }
+    None
+}
+
+/// Chain IDs as `0x`-prefixed bytes4 hex or a decimal u32
+fn de_opt_chain<'de, D: Deserializer<'de>>(d: D) -> Result<Option<ChainId>, D::Error> {
+    let raw: Option<serde_json::Value> = Option::deserialize(d)?;
+    let Some(value) = raw else {
+        return Ok(None);
+    };
+    let id = match &value {
+        serde_json::Value::Number(n) => n
+            .as_u64()
+            .and_then(|n| u32::try_from(n).ok())
+            .map(ChainId::from_u32),
+        serde_json::Value::String(s) if s.starts_with("0x") => ChainId::from_hex(s).ok(),
+        serde_json::Value::String(s) => s.parse::<u32>().ok().map(ChainId::from_u32),
+        _ => None,
+    };
+    id.map(Some)
+        .ok_or_else(|| serde::de::Error::custom(format!("invalid chain id {}", value)))
+}
+
+/// Amounts as JSON numbers or decimal strings (for values above u64)
+fn de_amount<'de, D: Deserializer<'de>>(d: D) -> Result<u128, D::Error> {
+    let value = serde_json::Value::deserialize(d)?;
+    let parsed = match &value {
+        serde_json::Value::Number(n) => n.as_u64().map(u128::from),
+        serde_json::Value::String(s) => s.parse().ok(),
+        _ => None,
+    };
+    parsed.ok_or_else(|| serde::de::Error::custom(format!("invalid amount {}", value)))
+}
+
+fn de_opt_amount<'de, D: Deserializer<'de>>(d: D) -> Result<Option<u128>, D::Error> {
+    let raw: Option<serde_json::Value> = Option::deserialize(d)?;
+    match raw {
+        None => Ok(None),
+        Some(v) => de_amount(v).map(Some).map_err(serde::de::Error::custom),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn facts(amount: u128) -> TransferFacts {
+        let mut src_account = [0u8; 32];
+        src_account[12..].copy_from_slice(&[0x11; 20]);
+        TransferFacts {
+            xchain_hash_id: [0xAA; 32],
+            src_chain: [0, 0, 0, 1],
+            dest_chain: [0, 0, 0, 2],
+            src_account,
+            dest_account: [0x22; 32],
+            token: "uluna".to_string(),
+            amount,
+            nonce: 7,
+        }
+    }
+
+    #[test]
+    fn test_parse_account_formats() {
+        let evm = parse_account("0x1111111111111111111111111111111111111111").unwrap();
+        assert_eq!(evm, facts(0).src_account);
+
+        let full = format!("0x{}", "22".repeat(32));
+        assert_eq!(parse_account(&full).unwrap(), [0x22; 32]);
+
+        let sol = bs58::encode([0x33u8; 32]).into_string();
+        assert_eq!(parse_account(&sol).unwrap(), [0x33; 32]);
+
+        assert!(parse_account("0x1234").is_err());
+        assert!(parse_account("not-an-account").is_err());
+    }
+
+    #[test]
+    fn test_refused_route_matches_evm_tokens_case_insensitively() {
+        let mut evm = facts(0);
+        evm.token = "0xAbCd000000000000000000000000000000000001".to_string();
+        let refused = RefusedRoute::new(
+            [0, 0, 0, 1],
+            [0, 0, 0, 2],
+            "0xabcd000000000000000000000000000000000001",
+        );
+        assert_eq!(RefusedRoute::of(&evm), refused);
+        assert_ne!(
+            RefusedRoute::of(&facts(0)),
+            RefusedRoute::new([0, 0, 0, 1], [0, 0, 0, 2], "ULUNA")
+        );
+    }
+
+    #[test]
+    fn test_parse_denylist_skips_comments() {
+        let raw = format!(
+            "# sanctions list\n\n0x1111111111111111111111111111111111111111  # evm\n{}\n",
+            bs58::encode([0x22u8; 32]).into_string()
+        );
+        let list = parse_denylist(&raw).unwrap();
+        assert_eq!(list.len(), 2);
+        let reasons = denylist_reasons(&list, &facts(1));
+        assert_eq!(reasons.len(), 2);
+        assert!(reasons[0].starts_with("src account"));
+
+        assert!(parse_denylist("0xzz\n").is_err());
+    }
+
+    #[test]
+    fn test_threshold_matches_token_and_route() {
+        let config = PolicyConfig::from_json(
+            r#"{"thresholds": [
+                {"token": "ULUNA", "max_amount": 1000},
+                {"token": "uluna", "src_chain": "0x00000005", "max_amount": 10},
+                {"token": "0xabc", "max_amount": "340282366920938463463374607431768211455"}
+            ]}"#,
+        )
+        .unwrap();
+        assert!(threshold_reasons(&config.thresholds, &facts(1000)).is_empty());
+        let reasons = threshold_reasons(&config.thresholds, &facts(1001));
+        assert_eq!(reasons.len(), 1);
+        assert!(reasons[0].contains("exceeds review threshold 1000"));
+        assert_eq!(config.thresholds[2].max_amount, u128::MAX);
+    }
+
+    #[test]
+    fn test_velocity_counts_current_transfer() {
+        let config = PolicyConfig::from_json(
+            r#"{"velocity": [
+                {"window_secs": 3600, "max_transfers": 3},
+                {"window_secs": 60, "max_amount": 500, "token": "uluna", "account": "dest"}
+            ]}"#,
+        )
+        .unwrap();
+        let count_rule = &config.velocity[0];
+        assert!(velocity_reason(count_rule, &facts(1), 2, 0).is_none());
+        assert!(velocity_reason(count_rule, &facts(1), 3, 0).is_some());
+
+        let amount_rule = &config.velocity[1];
+        assert_eq!(amount_rule.account, AccountSide::Dest);
+        assert!(velocity_reason(amount_rule, &facts(100), 5, 400).is_none());
+        let reason = velocity_reason(amount_rule, &facts(101), 5, 400).unwrap();
+        assert!(reason.starts_with("dest account"));
+
+        let mut other = facts(10_000);
+        other.token = "uusd".to_string();
+        assert!(velocity_reason(amount_rule, &other, 0, 0).is_none());
+    }
+
+    #[test]
+    fn test_config_validation() {
+        assert!(PolicyConfig::from_json(r#"{"velocity": [{"window_secs": 60}]}"#).is_err());
+        assert!(PolicyConfig::from_json(
+            r#"{"velocity": [{"window_secs": 0, "max_transfers": 1}]}"#
+        )
+        .is_err());
+        assert!(PolicyConfig::from_json(r#"{"thresholds": [{"token": "x"}]}"#).is_err());
+        assert!(PolicyConfig::from_json(r#"{"unknown": 1}"#).is_err());
+        assert_eq!(decision_for_status("released"), PolicyDecision::Approve);
+        assert_eq!(decision_for_status("rejected"), PolicyDecision::Reject);
+        assert_eq!(decision_for_status("held"), PolicyDecision::Hold);
+    }
+}
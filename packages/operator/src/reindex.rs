@@ -0,0 +1,852 @@
+//! `cl8y-operator reindex` — rebuild deposit rows over a chain range.
+//!
+//! Re-scans an EVM block range, a Terra height range or a Solana signature range through
+//! the same parsers the live watchers use, diffs every decoded deposit against the stored
+//! row and upserts it (`evm_deposits`, `terra_deposits`, `solana_deposits`). Block cursors
+//! (`evm_blocks`, `terra_blocks`, `solana_blocks`) are never moved.
+//!
+//! ```text
+//! cl8y-operator reindex evm [--chain-id 56] --from 41000000 [--to 41005000] [--dry-run] [--approve]
+//! cl8y-operator reindex terra --from 2931000 [--to 2932000] [--dry-run] [--approve]
+//! cl8y-operator reindex solana [--after <sig>] [--before <sig>] [--limit 5000] [--dry-run]
+//! ```
+//!
+//! `--chain-id` is the native EVM chain ID (defaults to `EVM_CHAIN_ID`; `EVM_CHAINS_*`
+//! peers are accepted too). `--to` defaults to the finalized EVM block / latest Terra
+//! height. Solana bounds are exclusive: `--after` is the oldest signature *not* to scan,
+//! `--before` the newest.
+//!
+//! Existing rows keep their status; only their chain-derived columns are rewritten.
+//! Rows that did not exist are inserted as `reindexed`, which the writers ignore, so a
+//! reindex never creates approvals by itself. `--approve` inserts them as `pending`
+//! instead and releases rows parked as `reindexed` by an earlier run. `solana_deposits`
+//! has no DB-driven approval path, so `--approve` does not apply there. `--dry-run`
+//! reports the same diffs without writing anything.
+
+use eyre::{eyre, Result, WrapErr};
+use solana_sdk::signature::Signature;
+use sqlx::PgPool;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::config::Config;
+use crate::db::{
+    self, EvmDeposit, NewEvmDeposit, NewSolanaDeposit, NewTerraDeposit, SolanaDeposit,
+    TerraDeposit, REINDEXED_STATUS,
+};
+use crate::multi_evm::EvmChainConfigExt;
+use crate::watchers::{EvmWatcher, SolanaWatcher, TerraWatcher};
+
+/// Default number of Solana signatures scanned when no bound is given
+const DEFAULT_SOLANA_LIMIT: usize = 1_000;
+
+const USAGE: &str = "usage: cl8y-operator reindex <evm|terra|solana> [options]
+  evm     [--chain-id <native id>] --from <block> [--to <block>]
+  terra   --from <height> [--to <height>]
+  solana  [--after <signature>] [--before <signature>] [--limit <n>]
+common:   [--dry-run] [--approve]";
+
+/// Range to re-scan
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReindexTarget {
+    Evm {
+        chain_id: Option<u64>,
+        from: u64,
+        to: Option<u64>,
+    },
+    Terra {
+        from: u64,
+        to: Option<u64>,
+    },
+    Solana {
+        after: Option<Signature>,
+        before: Option<Signature>,
+        limit: usize,
+    },
+}
+
+/// Parsed `reindex` arguments
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReindexArgs {
+    pub target: ReindexTarget,
+    /// Report diffs without writing
+    pub dry_run: bool,
+    /// Insert missing rows as `pending` so the writers approve them
+    pub approve: bool,
+}
+
+impl ReindexArgs {
+    /// Parse the arguments following `reindex`
+    pub fn parse(args: &[String]) -> Result<Self> {
+        let (chain, rest) = args.split_first().ok_or_else(|| eyre!("{}", USAGE))?;
+
+        let mut dry_run = false;
+        let mut approve = false;
+        let mut chain_id = None;
+        let mut from = None;
+        let mut to = None;
+        let mut after = None;
+        let mut before = None;
+        let mut limit = None;
+
+        let mut it = rest.iter();
+        while let Some(flag) = it.next() {
+            let mut value = || {
+                it.next()
+                    .map(String::as_str)
+                    .ok_or_else(|| eyre!("{} requires a value\n{}", flag, USAGE))
+            };
+            match flag.as_str() {
+                "--dry-run" => dry_run = true,
+                "--approve" => approve = true,
+                "--chain-id" => chain_id = Some(parse_number(flag, value()?)?),
+                "--from" => from = Some(parse_number(flag, value()?)?),
+                "--to" => to = Some(parse_number(flag, value()?)?),
+                "--after" => after = Some(parse_signature(flag, value()?)?),
+                "--before" => before = Some(parse_signature(flag, value()?)?),
+                "--limit" => limit = Some(parse_number(flag, value()?)? as usize),
+                other => return Err(eyre!("unknown reindex option {}\n{}", other, USAGE)),
+            }
+        }
+
+        let from_required = || from.ok_or_else(|| eyre!("--from is required\n{}", USAGE));
+        let target = match chain.as_str() {
+            "evm" => ReindexTarget::Evm {
+                chain_id,
+                from: from_required()?,
+                to,
+            },
+            "terra" => ReindexTarget::Terra {
+                from: from_required()?,
+                to,
+            },
+            "solana" => ReindexTarget::Solana {
+                after,
+                before,
+                limit: limit.unwrap_or(DEFAULT_SOLANA_LIMIT),
+            },
+            other => return Err(eyre!("unknown reindex chain {}\n{}", other, USAGE)),
+        };
+
+        match &target {
+            ReindexTarget::Evm { from, to, .. } | ReindexTarget::Terra { from, to } => {
+                if to.is_some_and(|to| to < *from) {
+                    return Err(eyre!("--to must not be below --from"));
+                }
+                if after.is_some() || before.is_some() || limit.is_some() {
+                    return Err(eyre!(
+                        "--after/--before/--limit only apply to solana\n{}",
+                        USAGE
+                    ));
+                }
+            }
+            ReindexTarget::Solana { .. } => {
+                if from.is_some() || to.is_some() || chain_id.is_some() {
+                    return Err(eyre!(
+                        "--from/--to/--chain-id do not apply to solana\n{}",
+                        USAGE
+                    ));
+                }
+            }
+        }
+        if matches!(target, ReindexTarget::Terra { .. }) && chain_id.is_some() {
+            return Err(eyre!("--chain-id only applies to evm\n{}", USAGE));
+        }
+
+        Ok(Self {
+            target,
+            dry_run,
+            approve,
+        })
+    }
+}
+
+fn parse_number(flag: &str, value: &str) -> Result<u64> {
+    value
+        .parse()
+        .wrap_err_with(|| format!("{} expects a number, got {}", flag, value))
+}
+
+fn parse_signature(flag: &str, value: &str) -> Result<Signature> {
+    Signature::from_str(value)
+        .map_err(|e| eyre!("{} expects a Solana signature, got {}: {}", flag, value, e))
+}
+
+/// One column whose stored value differs from the chain
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldDiff {
+    pub field: &'static str,
+    pub stored: String,
+    pub chain: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    /// Deposit found on chain with no stored row
+    Missing,
+    /// Stored row differs from the chain
+    Changed,
+}
+
+/// A row that was (or, in a dry run, would be) inserted or rewritten
+#[derive(Debug, Clone)]
+pub struct RowChange {
+    pub kind: ChangeKind,
+    /// Unique key of the row, e.g. `evm:56 0xabc…#3`
+    pub key: String,
+    /// Status of the existing row (`None` for missing rows)
+    pub status: Option<String>,
+    pub diffs: Vec<FieldDiff>,
+}
+
+/// Outcome of one reindex run
+#[derive(Debug, Default)]
+pub struct ReindexReport {
+    pub dry_run: bool,
+    /// Deposits decoded from the scanned range
+    pub scanned: usize,
+    pub unchanged: usize,
+    /// Rows parked as `reindexed` by an earlier run and now set to `pending` (`--approve`)
+    pub released: usize,
+    /// Deposit events that failed to parse (see the watcher error logs)
+    pub parse_failures: usize,
+    pub changes: Vec<RowChange>,
+}
+
+impl ReindexReport {
+    pub fn missing(&self) -> usize {
+        self.changes
+            .iter()
+            .filter(|c| c.kind == ChangeKind::Missing)
+            .count()
+    }
+
+    pub fn changed(&self) -> usize {
+        self.changes
+            .iter()
+            .filter(|c| c.kind == ChangeKind::Changed)
+            .count()
+    }
+}
+
+impl fmt::Display for ReindexReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (insert, update) = if self.dry_run {
+            ("would insert", "would update")
+        } else {
+            ("inserted", "updated")
+        };
+        writeln!(
+            f,
+            "{}scanned {} deposits: {} unchanged, {} {}, {} {}, {} released, {} parse failures",
+            if self.dry_run { "[dry run] " } else { "" },
+            self.scanned,
+            self.unchanged,
+            insert,
+            self.missing(),
+            update,
+            self.changed(),
+            self.released,
+            self.parse_failures
+        )?;
+        for change in &self.changes {
+            match change.kind {
+                ChangeKind::Missing => writeln!(f, "  + {} (missing)", change.key)?,
+                ChangeKind::Changed => writeln!(
+                    f,
+                    "  ~ {} (status {})",
+                    change.key,
+                    change.status.as_deref().unwrap_or("-")
+                )?,
+            }
+            for d in &change.diffs {
+                writeln!(f, "      {}: {} -> {}", d.field, d.stored, d.chain)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Run `cl8y-operator reindex <args>` against the configured chains and database
+pub async fn run(config: &Config, db: PgPool, args: &[String]) -> Result<()> {
+    let args = ReindexArgs::parse(args)?;
+    tracing::info!(?args, "Starting reindex");
+
+    let mut report = ReindexReport {
+        dry_run: args.dry_run,
+        ..Default::default()
+    };
+    match &args.target {
+        ReindexTarget::Evm { chain_id, from, to } => {
+            let evm_config = evm_config_for(config, *chain_id)?;
+            let watcher = EvmWatcher::new(&evm_config, db.clone()).await?;
+            let to = match to {
+                Some(to) => *to,
+                None => watcher.get_finalized_block().await?,
+            };
+            reindex_evm(&watcher, &db, &args, *from, to, &mut report).await?;
+        }
+        ReindexTarget::Terra { from, to } => {
+            let watcher = TerraWatcher::new(&config.terra, db.clone()).await?;
+            let to = match to {
+                Some(to) => *to,
+                None => watcher.get_current_height().await?,
+            };
+            reindex_terra(&watcher, &db, &args, *from, to, &mut report).await?;
+        }
+        ReindexTarget::Solana {
+            after,
+            before,
+            limit,
+        } => {
+            let sol_cfg = config
+                .solana
+                .as_ref()
+                .ok_or_else(|| eyre!("Solana is not configured (SOLANA_RPC_URL)"))?;
+            let program_id: solana_sdk::pubkey::Pubkey = sol_cfg
+                .program_id
+                .parse()
+                .map_err(|e| eyre!("Invalid SOLANA_PROGRAM_ID: {}", e))?;
+            let mut sol_rpc_urls = vec![sol_cfg.rpc_url.clone()];
+            sol_rpc_urls.extend(sol_cfg.rpc_fallback_urls.iter().cloned());
+            let watcher = SolanaWatcher::new(
+                &sol_rpc_urls,
+                &sol_cfg.commitment,
+                program_id,
+                db.clone(),
+                sol_cfg.poll_interval_ms,
+                *sol_cfg
+                    .bytes4_chain_ids
+                    .first()
+                    .unwrap_or(&[0x00, 0x00, 0x00, 0x05]),
+            )?;
+            reindex_solana(&watcher, &db, &args, *after, *before, *limit, &mut report).await?;
+        }
+    }
+
+    tracing::info!(
+        scanned = report.scanned,
+        unchanged = report.unchanged,
+        missing = report.missing(),
+        changed = report.changed(),
+        released = report.released,
+        parse_failures = report.parse_failures,
+        dry_run = report.dry_run,
+        "Reindex complete"
+    );
+    print!("{}", report);
+    Ok(())
+}
+
+fn evm_config_for(config: &Config, chain_id: Option<u64>) -> Result<crate::config::EvmConfig> {
+    let Some(chain_id) = chain_id else {
+        return Ok(config.evm.clone());
+    };
+    if config.evm.chain_id == chain_id {
+        return Ok(config.evm.clone());
+    }
+    config
+        .multi_evm
+        .as_ref()
+        .and_then(|multi| {
+            multi
+                .enabled_chains()
+                .find(|c| c.chain_id == chain_id)
+                .map(|c| c.to_operator_evm_config(multi.private_key()))
+        })
+        .ok_or_else(|| {
+            eyre!(
+                "EVM chain {} is neither EVM_CHAIN_ID nor an enabled EVM_CHAINS entry",
+                chain_id
+            )
+        })
+}
+
+/// Status for rows inserted by this run
+fn insert_status(args: &ReindexArgs) -> &'static str {
+    if args.approve {
+        "pending"
+    } else {
+        REINDEXED_STATUS
+    }
+}
+
+async fn reindex_evm(
+    watcher: &EvmWatcher,
+    db: &PgPool,
+    args: &ReindexArgs,
+    from: u64,
+    to: u64,
+    report: &mut ReindexReport,
+) -> Result<()> {
+    let chunk_size = watcher.chunk_size();
+    let mut chunk_start = from;
+    while chunk_start <= to {
+        let chunk_end = (chunk_start + chunk_size - 1).min(to);
+        tracing::info!(
+            chain_id = watcher.chain_id(),
+            from = chunk_start,
+            to = chunk_end,
+            "Reindexing EVM blocks"
+        );
+        let (deposits, parse_failures) = watcher
+            .scan_deposits(chunk_start, chunk_end)
+            .await
+            .wrap_err_with(|| format!("Failed to scan EVM blocks {}-{}", chunk_start, chunk_end))?;
+        report.parse_failures += parse_failures;
+
+        for deposit in deposits {
+            report.scanned += 1;
+            let key = format!(
+                "evm:{} {}#{}",
+                deposit.chain_id, deposit.tx_hash, deposit.log_index
+            );
+            let stored = db::get_evm_deposit_by_key(
+                db,
+                deposit.chain_id,
+                &deposit.tx_hash,
+                deposit.log_index,
+            )
+            .await?;
+            match stored {
+                None => {
+                    if !args.dry_run {
+                        db::insert_evm_deposit_with_status(db, &deposit, insert_status(args))
+                            .await?;
+                    }
+                    record_missing(report, key);
+                }
+                Some(row) => {
+                    let diffs = evm_diffs(&row, &deposit);
+                    if !diffs.is_empty() && !args.dry_run {
+                        db::update_evm_deposit_fields(db, row.id, &deposit).await?;
+                    }
+                    if args.approve && row.status == REINDEXED_STATUS {
+                        if !args.dry_run {
+                            db::update_evm_deposit_status(db, row.id, "pending").await?;
+                        }
+                        report.released += 1;
+                    }
+                    record_existing(report, key, row.status, diffs);
+                }
+            }
+        }
+        chunk_start = chunk_end + 1;
+    }
+    Ok(())
+}
+
+async fn reindex_terra(
+    watcher: &TerraWatcher,
+    db: &PgPool,
+    args: &ReindexArgs,
+    from: u64,
+    to: u64,
+    report: &mut ReindexReport,
+) -> Result<()> {
+    for height in from..=to {
+        let deposits = watcher
+            .scan_deposits(height)
+            .await
+            .wrap_err_with(|| format!("Failed to scan Terra height {}", height))?;
+
+        for deposit in deposits {
+            report.scanned += 1;
+            let key = format!("terra {}#{}", deposit.tx_hash, deposit.nonce);
+            let stored = db::get_terra_deposit_by_key(db, &deposit.tx_hash, deposit.nonce).await?;
+            match stored {
+                None => {
+                    if !args.dry_run {
+                        db::insert_terra_deposit_with_status(db, &deposit, insert_status(args))
+                            .await?;
+                    }
+                    record_missing(report, key);
+                }
+                Some(row) => {
+                    let diffs = terra_diffs(&row, &deposit);
+                    if !diffs.is_empty() && !args.dry_run {
+                        db::update_terra_deposit_fields(db, row.id, &deposit).await?;
+                    }
+                    if args.approve && row.status == REINDEXED_STATUS {
+                        if !args.dry_run {
+                            db::update_terra_deposit_status(db, row.id, "pending").await?;
+                        }
+                        report.released += 1;
+                    }
+                    record_existing(report, key, row.status, diffs);
+                }
+            }
+        }
+
+        if height % 100 == 0 {
+            tracing::info!(height, to, "Reindexing Terra heights");
+        }
+    }
+    Ok(())
+}
+
+async fn reindex_solana(
+    watcher: &SolanaWatcher,
+    db: &PgPool,
+    args: &ReindexArgs,
+    after: Option<Signature>,
+    before: Option<Signature>,
+    limit: usize,
+    report: &mut ReindexReport,
+) -> Result<()> {
+    if args.approve {
+        tracing::warn!("--approve has no effect on solana_deposits (no DB-driven approval path)");
+    }
+    let signatures = watcher.signatures_in_range(before, after, limit)?;
+    tracing::info!(
+        signatures = signatures.len(),
+        "Reindexing Solana program transactions"
+    );
+
+    // Oldest first, like the live watcher
+    for sig_info in signatures.iter().rev() {
+        if sig_info.err.is_some() {
+            continue;
+        }
+        let signature = parse_signature("signature", &sig_info.signature)?;
+        for event in watcher.deposits_in_transaction(&signature)? {
+            report.scanned += 1;
+            let deposit = NewSolanaDeposit {
+                nonce: event.nonce as i64,
+                transfer_hash: event.transfer_hash.to_vec(),
+                src_account: event.src_account.to_vec(),
+                dest_chain: event.dest_chain.to_vec(),
+                dest_account: event.dest_account.to_vec(),
+                token: event.token.to_vec(),
+                amount: event.amount.to_string(),
+                fee: event.fee.to_string(),
+                slot: sig_info.slot as i64,
+                signature: signature.to_string(),
+            };
+            let key = format!("solana nonce {}", deposit.nonce);
+            match db::get_solana_deposit_by_nonce(db, deposit.nonce).await? {
+                None => {
+                    if !args.dry_run {
+                        db::upsert_solana_deposit(db, &deposit).await?;
+                    }
+                    record_missing(report, key);
+                }
+                Some(row) => {
+                    let diffs = solana_diffs(&row, &deposit);
+                    if !diffs.is_empty() && !args.dry_run {
+                        db::upsert_solana_deposit(db, &deposit).await?;
+                    }
+                    let status = if row.processed.unwrap_or(false) {
+                        "processed"
+                    } else {
+                        "unprocessed"
+                    };
+                    record_existing(report, key, status.to_string(), diffs);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn record_missing(report: &mut ReindexReport, key: String) {
+    tracing::warn!(row = %key, dry_run = report.dry_run, "Reindex: deposit missing from database");
+    report.changes.push(RowChange {
+        kind: ChangeKind::Missing,
+        key,
+        status: None,
+        diffs: Vec::new(),
+    });
+}
+
+fn record_existing(report: &mut ReindexReport, key: String, status: String, diffs: Vec<FieldDiff>) {
+    if diffs.is_empty() {
+        report.unchanged += 1;
+        return;
+    }
+    tracing::warn!(
+        row = %key,
+        status = %status,
+        fields = ?diffs.iter().map(|d| d.field).collect::<Vec<_>>(),
+        dry_run = report.dry_run,
+        "Reindex: stored deposit differs from chain"
+    );
+    report.changes.push(RowChange {
+        kind: ChangeKind::Changed,
+        key,
+        status: Some(status),
+        diffs,
+    });
+}
+
+/// Collects differing columns; byte columns are rendered as 0x-hex
+#[derive(Default)]
+struct Diffs(Vec<FieldDiff>);
+
+impl Diffs {
+    fn text(&mut self, field: &'static str, stored: &str, chain: &str) {
+        if stored != chain {
+            self.0.push(FieldDiff {
+                field,
+                stored: stored.to_string(),
+                chain: chain.to_string(),
+            });
+        }
+    }
+
+    fn opt_text(&mut self, field: &'static str, stored: Option<&str>, chain: Option<&str>) {
+        self.text(field, stored.unwrap_or("NULL"), chain.unwrap_or("NULL"));
+    }
+
+    fn int(&mut self, field: &'static str, stored: i64, chain: i64) {
+        self.text(field, &stored.to_string(), &chain.to_string());
+    }
+
+    fn bytes(&mut self, field: &'static str, stored: Option<&[u8]>, chain: Option<&[u8]>) {
+        let render =
+            |b: Option<&[u8]>| b.map_or("NULL".to_string(), |b| format!("0x{}", hex::encode(b)));
+        if stored != chain {
+            self.0.push(FieldDiff {
+                field,
+                stored: render(stored),
+                chain: render(chain),
+            });
+        }
+    }
+}
+
+/// Columns of a stored EVM deposit that differ from the re-parsed event
+pub fn evm_diffs(stored: &EvmDeposit, chain: &NewEvmDeposit) -> Vec<FieldDiff> {
+    let mut d = Diffs::default();
+    d.int("nonce", stored.nonce, chain.nonce);
+    d.bytes(
+        "dest_chain_key",
+        Some(stored.dest_chain_key.as_slice()),
+        Some(chain.dest_chain_key.as_slice()),
+    );
+    d.bytes(
+        "dest_token_address",
+        Some(stored.dest_token_address.as_slice()),
+        Some(chain.dest_token_address.as_slice()),
+    );
+    d.bytes(
+        "dest_account",
+        Some(stored.dest_account.as_slice()),
+        Some(chain.dest_account.as_slice()),
+    );
+    d.text("token", &stored.token, &chain.token);
+    d.text("amount", &stored.amount, &chain.amount);
+    d.int("block_number", stored.block_number, chain.block_number);
+    d.text("block_hash", &stored.block_hash, &chain.block_hash);
+    d.opt_text(
+        "dest_chain_type",
+        stored.dest_chain_type.as_deref(),
+        Some(chain.dest_chain_type.as_str()),
+    );
+    d.bytes(
+        "src_account",
+        stored.src_account.as_deref(),
+        Some(chain.src_account.as_slice()),
+    );
+    d.bytes(
+        "src_v2_chain_id",
+        stored.src_v2_chain_id.as_deref(),
+        Some(&chain.src_v2_chain_id),
+    );
+    d.bytes(
+        "transfer_hash",
+        stored.transfer_hash.as_deref(),
+        chain.transfer_hash.as_deref(),
+    );
+    d.0
+}
+
+/// Columns of a stored Terra deposit that differ from the re-parsed transaction
+pub fn terra_diffs(stored: &TerraDeposit, chain: &NewTerraDeposit) -> Vec<FieldDiff> {
+    let mut d = Diffs::default();
+    d.text("sender", &stored.sender, &chain.sender);
+    d.text("recipient", &stored.recipient, &chain.recipient);
+    d.text("token", &stored.token, &chain.token);
+    d.text("amount", &stored.amount, &chain.amount);
+    d.int("dest_chain_id", stored.dest_chain_id, chain.dest_chain_id);
+    d.int("block_height", stored.block_height, chain.block_height);
+    d.opt_text(
+        "dest_token_address",
+        stored.dest_token_address.as_deref(),
+        chain.dest_token_address.as_deref(),
+    );
+    d.bytes(
+        "transfer_hash",
+        stored.transfer_hash.as_deref(),
+        chain.transfer_hash.as_deref(),
+    );
+    d.0
+}
+
+/// Columns of a stored Solana deposit that differ from the re-parsed event
+pub fn solana_diffs(stored: &SolanaDeposit, chain: &NewSolanaDeposit) -> Vec<FieldDiff> {
+    let mut d = Diffs::default();
+    d.bytes(
+        "transfer_hash",
+        Some(stored.transfer_hash.as_slice()),
+        Some(chain.transfer_hash.as_slice()),
+    );
+    d.bytes(
+        "src_account",
+        Some(stored.src_account.as_slice()),
+        Some(chain.src_account.as_slice()),
+    );
+    d.bytes(
+        "dest_chain",
+        Some(stored.dest_chain.as_slice()),
+        Some(chain.dest_chain.as_slice()),
+    );
+    d.bytes(
+        "dest_account",
+        Some(stored.dest_account.as_slice()),
+        Some(chain.dest_account.as_slice()),
+    );
+    d.bytes(
+        "token",
+        Some(stored.token.as_slice()),
+        Some(chain.token.as_slice()),
+    );
+    d.text("amount", &stored.amount, &chain.amount);
+    d.text("fee", &stored.fee, &chain.fee);
+    d.int("slot", stored.slot, chain.slot);
+    d.text("signature", &stored.signature, &chain.signature);
+    d.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_parse_evm_args() {
+        let parsed =
+            ReindexArgs::parse(&args("evm --chain-id 56 --from 100 --to 200 --dry-run")).unwrap();
+        assert_eq!(
+            parsed.target,
+            ReindexTarget::Evm {
+                chain_id: Some(56),
+                from: 100,
+                to: Some(200)
+            }
+        );
+        assert!(parsed.dry_run);
+        assert!(!parsed.approve);
+
+        assert!(ReindexArgs::parse(&args("evm --to 200")).is_err());
+        assert!(ReindexArgs::parse(&args("evm --from 300 --to 200")).is_err());
+        assert!(ReindexArgs::parse(&args("evm --from 1 --limit 5")).is_err());
+        assert!(ReindexArgs::parse(&args("evm --from")).is_err());
+    }
+
+    #[test]
+    fn test_parse_terra_and_solana_args() {
+        let parsed = ReindexArgs::parse(&args("terra --from 10 --approve")).unwrap();
+        assert_eq!(parsed.target, ReindexTarget::Terra { from: 10, to: None });
+        assert!(parsed.approve);
+        assert!(ReindexArgs::parse(&args("terra --from 10 --chain-id 1")).is_err());
+
+        let sig = Signature::default().to_string();
+        let parsed =
+            ReindexArgs::parse(&args(&format!("solana --after {} --limit 50", sig))).unwrap();
+        assert_eq!(
+            parsed.target,
+            ReindexTarget::Solana {
+                after: Some(Signature::default()),
+                before: None,
+                limit: 50
+            }
+        );
+        assert!(ReindexArgs::parse(&args("solana --from 1")).is_err());
+        assert!(ReindexArgs::parse(&args("bitcoin --from 1")).is_err());
+        assert!(ReindexArgs::parse(&[]).is_err());
+    }
+
+    fn terra_row() -> (TerraDeposit, NewTerraDeposit) {
+        let chain = NewTerraDeposit {
+            tx_hash: "ABCDEF".to_string(),
+            nonce: 7,
+            sender: "terra1sender".to_string(),
+            recipient: "AAAAAQ==".to_string(),
+            token: "uluna".to_string(),
+            amount: "1000".to_string(),
+            dest_chain_id: 1,
+            block_height: 42,
+            dest_token_address: Some("0xtoken".to_string()),
+            transfer_hash: Some(vec![0x11; 32]),
+        };
+        let stored = TerraDeposit {
+            id: 1,
+            tx_hash: chain.tx_hash.clone(),
+            nonce: chain.nonce,
+            sender: chain.sender.clone(),
+            recipient: chain.recipient.clone(),
+            token: chain.token.clone(),
+            amount: chain.amount.clone(),
+            dest_chain_id: chain.dest_chain_id,
+            block_height: chain.block_height,
+            status: "processed".to_string(),
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+            dest_token_address: chain.dest_token_address.clone(),
+            transfer_hash: chain.transfer_hash.clone(),
+        };
+        (stored, chain)
+    }
+
+    #[test]
+    fn test_terra_diffs() {
+        let (mut stored, chain) = terra_row();
+        assert!(terra_diffs(&stored, &chain).is_empty());
+
+        stored.transfer_hash = None;
+        stored.amount = "999".to_string();
+        let diffs = terra_diffs(&stored, &chain);
+        assert_eq!(
+            diffs.iter().map(|d| d.field).collect::<Vec<_>>(),
+            ["amount", "transfer_hash"]
+        );
+        assert_eq!(diffs[1].stored, "NULL");
+        assert_eq!(diffs[1].chain, format!("0x{}", "11".repeat(32)));
+    }
+
+    #[test]
+    fn test_report_display() {
+        let (mut stored, chain) = terra_row();
+        stored.block_height = 41;
+        let mut report = ReindexReport {
+            dry_run: true,
+            scanned: 3,
+            ..Default::default()
+        };
+        record_missing(&mut report, "terra AAA#1".to_string());
+        record_existing(
+            &mut report,
+            "terra ABCDEF#7".to_string(),
+            stored.status.clone(),
+            terra_diffs(&stored, &chain),
+        );
+        record_existing(
+            &mut report,
+            "terra BBB#2".to_string(),
+            "pending".into(),
+            vec![],
+        );
+
+        let text = report.to_string();
+        assert!(text.starts_with(
+            "[dry run] scanned 3 deposits: 1 unchanged, would insert 1, would update 1"
+        ));
+        assert!(text.contains("  + terra AAA#1 (missing)"));
+        assert!(text.contains("  ~ terra ABCDEF#7 (status processed)"));
+        assert!(text.contains("      block_height: 41 -> 42"));
+    }
+}
@@ -0,0 +1,44 @@
+//! Shared token metadata cache
+//!
+//! One [`TokenCache`] (see `multichain_rs::token_cache`) covers every bridge the operator is
+//! configured for. It is read once at startup and re-read when a registry changes; the Terra
+//! writer takes token types from it and the Solana writer its `TokenMapping` preflight, both
+//! falling back to querying the chain when the cache has no fresh entry.
+//!
+//! | Variable | Default | |
+//! |----------|---------|-|
+//! | `OPERATOR_TOKEN_CACHE_PATH` | unset (memory only) | JSON file the cache is saved to and loaded from |
+//! | `OPERATOR_TOKEN_CACHE_POLL_SECS` | 30 | How often registries are checked for changes |
+//! | `OPERATOR_TOKEN_CACHE_FULL_REFRESH_SECS` | 600 | How often every registry is re-read regardless |
+
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use eyre::{eyre, Result};
+use multichain_rs::token_cache::{self, TokenCache};
+
+use crate::config::Config;
+use crate::mapping_check;
+
+/// Open the cache and start refreshing it in the background
+pub async fn start(config: &Config) -> Result<Arc<TokenCache>> {
+    let path = std::env::var("OPERATOR_TOKEN_CACHE_PATH")
+        .ok()
+        .filter(|p| !p.is_empty())
+        .map(PathBuf::from);
+    let poll = secs_from_env("OPERATOR_TOKEN_CACHE_POLL_SECS", 30)?;
+    let full_refresh = secs_from_env("OPERATOR_TOKEN_CACHE_FULL_REFRESH_SECS", 600)?;
+    let endpoints = mapping_check::endpoints(config)?;
+    Ok(token_cache::start(&endpoints, path, poll, full_refresh).await)
+}
+
+fn secs_from_env(name: &str, default: u64) -> Result<Duration> {
+    match std::env::var(name) {
+        Ok(v) => match v.parse::<u64>() {
+            Ok(n) if n > 0 => Ok(Duration::from_secs(n)),
+            _ => Err(eyre!("{} must be a positive integer, got {:?}", name, v)),
+        },
+        Err(_) => Ok(Duration::from_secs(default)),
+    }
+}
@@ -8,7 +8,7 @@ use std::str::FromStr;
 use std::time::Duration;
 
 use crate::contracts::evm_bridge::{Bridge, TokenRegistry};
-use crate::db::models::NewEvmDeposit;
+use crate::db::models::{NewEvmDeposit, NewRelayRequest};
 use crate::db::{get_last_evm_block, update_last_evm_block};
 use crate::hash::compute_xchain_hash_id;
 use crate::types::ChainId;
@@ -49,6 +49,8 @@ pub struct EvmWatcher {
     use_v2_events: bool,
     lookback_blocks: u64,
     chunk_size: u64,
+    /// Record `RelayRequested` events for gasless withdrawals (`OPERATOR_RELAY_ENABLED`)
+    relay_enabled: bool,
 }
 
 impl EvmWatcher {
@@ -160,6 +162,7 @@ impl EvmWatcher {
             use_v2_events,
             lookback_blocks,
             chunk_size,
+            relay_enabled: crate::writers::RelayConfig::from_env()?.enabled,
         })
     }
 
@@ -168,6 +171,11 @@ impl EvmWatcher {
         self.chain_id
     }
 
+    /// Max block range per eth_getLogs query (`EVM_POLL_CHUNK_SIZE`)
+    pub(crate) fn chunk_size(&self) -> u64 {
+        self.chunk_size.max(1)
+    }
+
     /// Run the watcher loop
     pub async fn run(&self) -> Result<()> {
         let poll_interval = Duration::from_millis(1000);
@@ -296,6 +304,112 @@ impl EvmWatcher {
 
     /// Process logs from a single block range (must be within RPC limits)
     async fn process_block_range(&self, from_block: u64, to_block: u64) -> Result<()> {
+        let (deposits, _parse_failures) = self.scan_deposits(from_block, to_block).await?;
+
+        for deposit in deposits {
+            // Check if deposit already exists
+            let exists = crate::db::evm_deposit_exists(
+                &self.db,
+                deposit.chain_id,
+                &deposit.tx_hash,
+                deposit.log_index,
+            )
+            .await?;
+
+            if !exists {
+                if let Err(e) = crate::db::insert_evm_deposit(&self.db, &deposit).await {
+                    tracing::error!(
+                        tx_hash = %deposit.tx_hash,
+                        log_index = deposit.log_index,
+                        error = %e,
+                        "Failed to insert EVM deposit"
+                    );
+                    continue;
+                }
+
+                tracing::info!(
+                    chain_id = self.chain_id,
+                    tx_hash = %deposit.tx_hash,
+                    log_index = deposit.log_index,
+                    dest_chain = %hex::encode(&deposit.dest_chain_key),
+                    token = %deposit.token,
+                    amount = %deposit.amount,
+                    nonce = deposit.nonce,
+                    "New EVM deposit detected"
+                );
+            }
+        }
+
+        // After the deposits, so a relay request always finds its deposit indexed
+        if self.relay_enabled {
+            self.record_relay_requests(from_block, to_block).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Store the RelayRequested events of a single block range (see `writers::relay`)
+    ///
+    /// ```solidity
+    /// event RelayRequested(bytes32 indexed xchainHashId, bytes4 indexed destChain, uint256 maxRelayFee);
+    /// ```
+    async fn record_relay_requests(&self, from_block: u64, to_block: u64) -> Result<()> {
+        let filter = Filter::new()
+            .address(self.bridge_address)
+            .event_signature(Self::relay_requested_signature())
+            .from_block(from_block)
+            .to_block(to_block);
+
+        for log in self.get_logs_with_fallback(&filter).await? {
+            let topics = log.topics();
+            let data = log.data().data.as_ref();
+            if topics.len() < 3 || data.len() < 32 {
+                tracing::error!(
+                    tx_hash = ?log.transaction_hash,
+                    "Malformed RelayRequested log, skipping"
+                );
+                continue;
+            }
+            let request = NewRelayRequest {
+                src_chain_id: Some(self.chain_id as i64),
+                xchain_hash_id: topics[1].as_slice().to_vec(),
+                // bytes4 topics are left-aligned
+                dest_chain: topics[2].as_slice()[..4].to_vec(),
+                max_relay_fee: U256::from_be_slice(&data[..32]).to_string(),
+                tx_hash: format!(
+                    "{:?}",
+                    log.transaction_hash
+                        .ok_or_else(|| eyre::eyre!("Missing transaction hash"))?
+                ),
+                block_number: log
+                    .block_number
+                    .ok_or_else(|| eyre::eyre!("Missing block number"))?
+                    as i64,
+            };
+            if crate::db::insert_relay_request(&self.db, &request).await? {
+                tracing::info!(
+                    chain_id = self.chain_id,
+                    xchain_hash_id = %format!("0x{}", hex::encode(&request.xchain_hash_id)),
+                    dest_chain = %format!("0x{}", hex::encode(&request.dest_chain)),
+                    max_relay_fee = %request.max_relay_fee,
+                    "Relay requested for EVM deposit"
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Fetch and decode the Deposit events of a single block range (must be within RPC
+    /// limits) without touching the database.
+    ///
+    /// Returns the decoded deposits and the number of deposit logs that failed to parse
+    /// (each failure is logged). Shared by the live poll loop and `reindex`.
+    pub(crate) async fn scan_deposits(
+        &self,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<(Vec<NewEvmDeposit>, usize)> {
         let filter = Filter::new()
             .address(self.bridge_address)
             .from_block(from_block)
@@ -325,6 +439,8 @@ impl EvmWatcher {
             );
         }
 
+        let mut deposits = Vec::new();
+        let mut parse_failures = 0usize;
         for log in logs {
             // Check if this is a Deposit/DepositRequest event
             let topics = log.topics();
@@ -350,40 +466,9 @@ impl EvmWatcher {
             };
 
             match parse_result {
-                Ok(deposit) => {
-                    // Check if deposit already exists
-                    let exists = crate::db::evm_deposit_exists(
-                        &self.db,
-                        deposit.chain_id,
-                        &deposit.tx_hash,
-                        deposit.log_index,
-                    )
-                    .await?;
-
-                    if !exists {
-                        if let Err(e) = crate::db::insert_evm_deposit(&self.db, &deposit).await {
-                            tracing::error!(
-                                tx_hash = %deposit.tx_hash,
-                                log_index = deposit.log_index,
-                                error = %e,
-                                "Failed to insert EVM deposit"
-                            );
-                            continue;
-                        }
-
-                        tracing::info!(
-                            chain_id = self.chain_id,
-                            tx_hash = %deposit.tx_hash,
-                            log_index = deposit.log_index,
-                            dest_chain = %hex::encode(&deposit.dest_chain_key),
-                            token = %deposit.token,
-                            amount = %deposit.amount,
-                            nonce = deposit.nonce,
-                            "New EVM deposit detected"
-                        );
-                    }
-                }
+                Ok(deposit) => deposits.push(deposit),
                 Err(e) => {
+                    parse_failures += 1;
                     tracing::error!(
                         tx_hash = ?log.transaction_hash,
                         log_index = ?log.log_index,
@@ -394,7 +479,7 @@ impl EvmWatcher {
             }
         }
 
-        Ok(())
+        Ok((deposits, parse_failures))
     }
 
     /// Parse a DepositRequest log (V1 format)
@@ -712,7 +797,7 @@ impl EvmWatcher {
     }
 
     /// Finalized block number (`eth_blockNumber` quorum minus `finality_blocks`).
-    async fn get_finalized_block(&self) -> Result<u64> {
+    pub(crate) async fn get_finalized_block(&self) -> Result<u64> {
         let head =
             multichain_rs::evm_consensus_latest_block(&self.rpc_urls, &self.read_policy).await?;
         Ok(head.latest_block.saturating_sub(self.finality_blocks))
@@ -767,6 +852,11 @@ impl EvmWatcher {
         )
     }
 
+    /// Compute the RelayRequested event signature hash
+    fn relay_requested_signature() -> B256 {
+        alloy::primitives::keccak256(b"RelayRequested(bytes32,bytes4,uint256)")
+    }
+
     /// Compute the V2 Deposit event signature hash
     ///
     /// V2 event includes srcAccount (bytes32) as a non-indexed parameter:
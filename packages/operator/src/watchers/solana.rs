@@ -2,10 +2,12 @@
 
 use eyre::Result;
 use multichain_rs::solana::{
-    get_signatures_for_program, get_transaction, parse_anchor_events, run_with_solana_rpc_fallback,
-    SolanaEvent,
+    get_signatures_for_program, get_signatures_for_program_range, get_transaction,
+    parse_anchor_events, run_with_solana_rpc_fallback, SolanaDepositEvent, SolanaEvent,
+    SolanaRelayRequestedEvent,
 };
 use solana_client::rpc_client::RpcClient;
+use solana_client::rpc_response::RpcConfirmedTransactionStatusWithSignature;
 use solana_sdk::commitment_config::CommitmentConfig;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::Signature;
@@ -22,6 +24,8 @@ pub struct SolanaWatcher {
     poll_interval: Duration,
     #[allow(dead_code)]
     bytes4_chain_id: [u8; 4],
+    /// Record relay requests (see `writers::relay`)
+    relay_enabled: bool,
 }
 
 fn solana_commitment_config(s: &str) -> CommitmentConfig {
@@ -56,6 +60,7 @@ impl SolanaWatcher {
             last_signature: None,
             poll_interval: Duration::from_millis(poll_interval_ms),
             bytes4_chain_id,
+            relay_enabled: crate::writers::RelayConfig::from_env()?.enabled,
         })
     }
 
@@ -112,38 +117,30 @@ impl SolanaWatcher {
                 continue;
             }
 
-            let tx = match run_with_solana_rpc_fallback(&self.rpc_clients, |c| {
-                get_transaction(c, &signature)
-            }) {
-                Ok(tx) => tx,
+            let events = match self.events_in_transaction(&signature) {
+                Ok(events) => events,
                 Err(e) => {
                     warn!(signature = %signature, error = %e, "Failed to fetch transaction, stopping batch");
                     break;
                 }
             };
 
-            let log_messages: Vec<String> = tx
-                .transaction
-                .meta
-                .as_ref()
-                .and_then(|m| {
-                    use solana_transaction_status::option_serializer::OptionSerializer;
-                    match &m.log_messages {
-                        OptionSerializer::Some(logs) => Some(logs.clone()),
-                        _ => None,
-                    }
-                })
-                .unwrap_or_default();
-
-            let events = parse_anchor_events(&log_messages, &self.program_id);
-
-            for event in events {
+            for event in &events {
                 if let SolanaEvent::Deposit(deposit) = event {
-                    self.store_deposit(&deposit, &signature, sig_info.slot)
+                    self.store_deposit(deposit, &signature, sig_info.slot)
                         .await?;
                     new_deposits += 1;
                 }
             }
+            // After the deposits, so a relay request always finds its deposit indexed
+            if self.relay_enabled {
+                for event in &events {
+                    if let SolanaEvent::RelayRequested(request) = event {
+                        self.store_relay_request(request, &signature, sig_info.slot)
+                            .await?;
+                    }
+                }
+            }
 
             last_success = Some(signature);
         }
@@ -165,9 +162,79 @@ impl SolanaWatcher {
         Ok(())
     }
 
+    /// Program signatures strictly between `before` and `until` (either bound optional),
+    /// newest-first, paging backwards until `limit` signatures or the range is exhausted.
+    pub(crate) fn signatures_in_range(
+        &self,
+        before: Option<Signature>,
+        until: Option<Signature>,
+        limit: usize,
+    ) -> Result<Vec<RpcConfirmedTransactionStatusWithSignature>> {
+        let mut out = Vec::new();
+        let mut cursor = before;
+        while out.len() < limit {
+            let page = run_with_solana_rpc_fallback(&self.rpc_clients, |c| {
+                get_signatures_for_program_range(
+                    c,
+                    &self.program_id,
+                    cursor.as_ref(),
+                    until.as_ref(),
+                    (limit - out.len()).min(1000),
+                )
+            })
+            .map_err(|e| eyre::eyre!("Failed to get signatures: {}", e))?;
+            let Some(oldest) = page.last() else {
+                break;
+            };
+            cursor = Some(
+                Signature::from_str(&oldest.signature)
+                    .map_err(|e| eyre::eyre!("Invalid signature: {}", e))?,
+            );
+            out.extend(page);
+        }
+        Ok(out)
+    }
+
+    /// Fetch one program transaction and decode its bridge deposits without touching the
+    /// database. Used by `reindex`.
+    pub(crate) fn deposits_in_transaction(
+        &self,
+        signature: &Signature,
+    ) -> Result<Vec<SolanaDepositEvent>> {
+        Ok(self
+            .events_in_transaction(signature)?
+            .into_iter()
+            .filter_map(|event| match event {
+                SolanaEvent::Deposit(deposit) => Some(deposit),
+                _ => None,
+            })
+            .collect())
+    }
+
+    /// Fetch one program transaction and decode all of its bridge events
+    fn events_in_transaction(&self, signature: &Signature) -> Result<Vec<SolanaEvent>> {
+        let tx = run_with_solana_rpc_fallback(&self.rpc_clients, |c| get_transaction(c, signature))
+            .map_err(|e| eyre::eyre!("Failed to fetch transaction {}: {}", signature, e))?;
+
+        let log_messages: Vec<String> = tx
+            .transaction
+            .meta
+            .as_ref()
+            .and_then(|m| {
+                use solana_transaction_status::option_serializer::OptionSerializer;
+                match &m.log_messages {
+                    OptionSerializer::Some(logs) => Some(logs.clone()),
+                    _ => None,
+                }
+            })
+            .unwrap_or_default();
+
+        Ok(parse_anchor_events(&log_messages, &self.program_id))
+    }
+
     async fn store_deposit(
         &self,
-        deposit: &multichain_rs::solana::SolanaDepositEvent,
+        deposit: &SolanaDepositEvent,
         signature: &Signature,
         slot: u64,
     ) -> Result<()> {
@@ -204,6 +271,32 @@ impl SolanaWatcher {
         Ok(())
     }
 
+    async fn store_relay_request(
+        &self,
+        request: &SolanaRelayRequestedEvent,
+        signature: &Signature,
+        slot: u64,
+    ) -> Result<()> {
+        let request = crate::db::NewRelayRequest {
+            src_chain_id: None,
+            xchain_hash_id: request.transfer_hash.to_vec(),
+            dest_chain: request.dest_chain.to_vec(),
+            max_relay_fee: request.max_relay_fee.to_string(),
+            tx_hash: signature.to_string(),
+            block_number: slot as i64,
+        };
+        if crate::db::insert_relay_request(&self.db, &request).await? {
+            info!(
+                hash = hex::encode(&request.xchain_hash_id),
+                dest_chain = %format!("0x{}", hex::encode(&request.dest_chain)),
+                max_relay_fee = %request.max_relay_fee,
+                "Relay requested for Solana deposit"
+            );
+        }
+
+        Ok(())
+    }
+
     async fn load_last_signature(&self) -> Result<Option<Signature>> {
         let row: Option<(String,)> =
             sqlx::query_as("SELECT signature FROM solana_deposits ORDER BY nonce DESC LIMIT 1")
@@ -10,14 +10,18 @@
 //! 2. Operator calls `withdrawApprove(xchainHashId)` to approve
 //! 3. After cancel window, anyone can call `withdrawExecuteUnlock/Mint`
 //!
-//! The operator only needs to approve pending withdrawals, not create them.
+//! The operator only needs to approve pending withdrawals, not create them. It also
+//! executes them itself once the window passes (see [`super::execution`]).
+//!
+//! Transactions are signed by a [`SignerPool`]: verified withdrawals found in one pass are
+//! approved concurrently, one in flight per operator key.
 
 #![allow(dead_code)]
 
 use std::collections::HashMap;
-use std::time::Instant;
+use std::sync::Arc;
 
-use crate::bounded_cache::{BoundedHashCache, BoundedPendingCache};
+use crate::bounded_cache::BoundedHashCache;
 
 use alloy::network::EthereumWallet;
 use alloy::primitives::{Address, FixedBytes, U256};
@@ -33,20 +37,12 @@ use crate::config::{EvmConfig, FeeConfig, TerraConfig};
 use crate::contracts::evm_bridge::{Bridge, TokenRegistry};
 use crate::db::{self, EvmDeposit, NewApproval, TerraDeposit};
 use crate::hash::{address_to_bytes32, bytes32_to_hex, compute_xchain_hash_id};
+use crate::policy::{PolicyEngine, TransferFacts};
 use crate::types::{ChainId, EvmAddress};
 
-/// Pending approval tracking for auto-execution
-#[derive(Debug, Clone)]
-struct PendingExecution {
-    /// The cross-chain hash identifier
-    xchain_hash_id: [u8; 32],
-    /// When the approval was submitted
-    approved_at: Instant,
-    /// The delay required before execution
-    delay_seconds: u64,
-    /// Number of execution attempts
-    attempts: u32,
-}
+use super::execution::ExecutionOutcome;
+use super::signer_pool::{BalanceThresholds, SignerPool};
+use super::VerifiedWithdraw;
 
 /// EVM transaction writer for submitting withdrawal approvals
 ///
@@ -70,14 +66,15 @@ pub struct EvmWriter {
     terra_bridge_address: Option<String>,
     /// Terra V2 4-byte chain ID (None when Terra is not configured)
     terra_chain_id: Option<ChainId>,
-    signer: PrivateKeySigner,
+    /// Operator keys; every transaction leases one
+    signers: SignerPool<PrivateKeySigner>,
     default_fee_bps: u32,
     fee_recipient: Address,
     db: PgPool,
     /// Cancel window in seconds (queried from contract)
     cancel_window: u64,
-    /// Pending approvals awaiting execution (bounded to prevent unbounded growth)
-    pending_executions: BoundedPendingCache<PendingExecution>,
+    /// Auto-execution retry settings
+    execution: super::ExecutionConfig,
     /// Last block polled for WithdrawSubmit events
     last_polled_block: u64,
     /// Hashes already approved by this operator (bounded to prevent unbounded growth)
@@ -89,6 +86,10 @@ pub struct EvmWriter {
     http: reqwest::Client,
     /// Optional Solana source config for verifying Solana-origin deposits
     solana_source_config: Option<super::SolanaSourceConfig>,
+    /// Deposit policy consulted before every approval
+    policy: Arc<PolicyEngine>,
+    /// Gasless withdrawal relay settings
+    relay: super::RelayConfig,
 }
 
 impl EvmWriter {
@@ -103,17 +104,32 @@ impl EvmWriter {
         db: PgPool,
         source_chain_endpoints: HashMap<[u8; 4], (String, Address)>,
         solana_source_config: Option<super::SolanaSourceConfig>,
+        policy: Arc<PolicyEngine>,
     ) -> Result<Self> {
         let bridge_address =
             Address::from_str(&evm_config.bridge_address).wrap_err("Invalid bridge address")?;
         let fee_recipient =
             Address::from_str(&fee_config.fee_recipient).wrap_err("Invalid fee recipient")?;
 
-        // Parse the private key
-        let signer: PrivateKeySigner = evm_config
-            .private_key
-            .parse()
-            .wrap_err("Invalid private key")?;
+        // Parse the operator keys
+        let mut keys = Vec::new();
+        for (i, key) in evm_config.signer_keys().enumerate() {
+            let signer: PrivateKeySigner = key
+                .parse()
+                .wrap_err_with(|| format!("Invalid private key #{}", i))?;
+            let address = signer.address().to_string();
+            keys.push((signer, address));
+        }
+        let signers = SignerPool::new(
+            format!("evm_{}", evm_config.chain_id),
+            keys,
+            BalanceThresholds::from_env(
+                "EVM_SIGNER_MIN_BALANCE_WEI",
+                "EVM_SIGNER_LOW_BALANCE_WEI",
+                super::signer_pool::DEFAULT_EVM_MIN_BALANCE_WEI,
+                super::signer_pool::DEFAULT_EVM_LOW_BALANCE_WEI,
+            )?,
+        )?;
 
         // V2 chain ID — query from bridge contract, fall back to config
         let provider =
@@ -152,7 +168,8 @@ impl EvmWriter {
         };
 
         info!(
-            operator_address = %signer.address(),
+            operator_address = %signers.primary().address(),
+            signer_keys = ?signers.addresses(),
             native_chain_id = evm_config.chain_id,
             v2_chain_id = %this_chain_id,
             v2_hex = %format!("0x{}", hex::encode(this_chain_id.as_bytes())),
@@ -281,15 +298,12 @@ impl EvmWriter {
             terra_lcd_url,
             terra_bridge_address,
             terra_chain_id,
-            signer,
+            signers,
             default_fee_bps: fee_config.default_fee_bps,
             fee_recipient,
             db,
             cancel_window,
-            pending_executions: {
-                let cc = crate::bounded_cache::CacheConfig::from_env();
-                BoundedPendingCache::new(cc.pending_execution_size, cc.ttl_secs)
-            },
+            execution: super::ExecutionConfig::from_env()?,
             last_polled_block: 0,
             approved_hashes: {
                 let cc = crate::bounded_cache::CacheConfig::from_env();
@@ -302,6 +316,8 @@ impl EvmWriter {
                 .build()
                 .wrap_err("Failed to build HTTP client for EVM writer")?,
             solana_source_config,
+            policy,
+            relay: super::RelayConfig::from_env()?,
         })
     }
 
@@ -327,8 +343,16 @@ impl EvmWriter {
     /// This handles BOTH Terra→EVM and EVM→EVM transfers uniformly —
     /// any pending withdrawal on this chain gets verified and approved.
     pub async fn process_pending(&mut self) -> Result<()> {
+        self.refresh_signer_balances().await;
         self.process_pending_executions().await?;
 
+        // Relayed withdrawals are submitted first so this pass can already approve them
+        if self.relay.enabled {
+            if let Err(e) = self.process_relay_requests().await {
+                warn!(error = %e, "Relay request processing failed");
+            }
+        }
+
         // Primary: enumerate pending withdrawals from contract state
         if let Err(e) = self.enumerate_and_approve().await {
             warn!(error = %e, "Enumeration-based approval poll failed");
@@ -386,6 +410,7 @@ impl EvmWriter {
         }
 
         let mut new_count: u64 = 0;
+        let mut verified = Vec::new();
         for hash_fb in pending_hashes {
             let xchain_hash_id: [u8; 32] = hash_fb.0;
 
@@ -456,46 +481,34 @@ impl EvmWriter {
                 continue;
             }
 
+            let facts = TransferFacts {
+                xchain_hash_id,
+                src_chain: src_chain_id,
+                dest_chain: *self.this_chain_id.as_bytes(),
+                src_account: pending.srcAccount.0,
+                dest_account: pending.destAccount.0,
+                token: format!("0x{}", hex::encode(pending.token)),
+                amount,
+                nonce,
+            };
+            if !self.policy.permits(&facts).await {
+                continue;
+            }
+
             info!(
                 hash = %bytes32_to_hex(&xchain_hash_id),
                 nonce = nonce,
-                "Deposit verified on source chain, submitting withdrawApprove"
+                "Deposit verified on source chain, queueing withdrawApprove"
             );
-
-            match self.submit_withdraw_approve(&xchain_hash_id).await {
-                Ok(tx_hash) => {
-                    info!(
-                        tx_hash = %tx_hash,
-                        hash = %bytes32_to_hex(&xchain_hash_id),
-                        nonce = nonce,
-                        "WithdrawApprove submitted successfully (via enumeration)"
-                    );
-
-                    self.approved_hashes.insert(xchain_hash_id);
-
-                    self.pending_executions.insert(
-                        xchain_hash_id,
-                        PendingExecution {
-                            xchain_hash_id,
-                            approved_at: Instant::now(),
-                            delay_seconds: self.cancel_window,
-                            attempts: 0,
-                        },
-                    );
-
-                    self.sync_deposit_status_after_approval(&src_chain_id, nonce)
-                        .await;
-                }
-                Err(e) => {
-                    warn!(
-                        error = %e,
-                        hash = %bytes32_to_hex(&xchain_hash_id),
-                        "Failed to submit withdrawApprove, will retry next cycle"
-                    );
-                }
-            }
+            verified.push(VerifiedWithdraw {
+                xchain_hash_id,
+                src_chain_id,
+                nonce,
+            });
         }
 
+        self.approve_verified(verified, "enumeration").await;
+
         if new_count > 0 {
             info!(
                 total_pending = pending_hashes.len(),
@@ -624,6 +637,7 @@ impl EvmWriter {
 
         let logs = all_logs;
 
+        let mut verified = Vec::new();
         for (event, _log) in &logs {
             let xchain_hash_id: [u8; 32] = event.xchainHashId.0;
 
@@ -702,55 +716,106 @@ impl EvmWriter {
                 continue;
             }
 
-            // Deposit verified — submit withdrawApprove
+            let facts = TransferFacts {
+                xchain_hash_id,
+                src_chain: src_chain_id,
+                dest_chain: *self.this_chain_id.as_bytes(),
+                src_account: pending.srcAccount.0,
+                dest_account: pending.destAccount.0,
+                token: format!("0x{}", hex::encode(pending.token)),
+                amount,
+                nonce,
+            };
+            if !self.policy.permits(&facts).await {
+                continue;
+            }
+
+            // Deposit verified — queue withdrawApprove
             info!(
                 hash = %bytes32_to_hex(&xchain_hash_id),
                 nonce = nonce,
-                "Deposit verified on source chain, submitting withdrawApprove"
+                "Deposit verified on source chain, queueing withdrawApprove"
             );
+            if verified
+                .iter()
+                .all(|w: &VerifiedWithdraw| w.xchain_hash_id != xchain_hash_id)
+            {
+                verified.push(VerifiedWithdraw {
+                    xchain_hash_id,
+                    src_chain_id,
+                    nonce,
+                });
+            }
+        }
 
-            match self.submit_withdraw_approve(&xchain_hash_id).await {
-                Ok(tx_hash) => {
-                    info!(
-                        tx_hash = %tx_hash,
-                        hash = %bytes32_to_hex(&xchain_hash_id),
-                        nonce = nonce,
-                        "WithdrawApprove submitted successfully"
-                    );
+        self.approve_verified(verified, "events").await;
 
-                    self.approved_hashes.insert(xchain_hash_id);
-
-                    // Track for auto-execution after cancel window
-                    self.pending_executions.insert(
-                        xchain_hash_id,
-                        PendingExecution {
-                            xchain_hash_id,
-                            approved_at: Instant::now(),
-                            delay_seconds: self.cancel_window,
-                            attempts: 0,
-                        },
-                    );
+        self.last_polled_block = last_successful_block;
 
-                    // Sync DB: mark corresponding evm_deposit or terra_deposit as processed
-                    // so pending_deposits count stays accurate. The V2 poll-and-approve path
-                    // works from on-chain events, but the DB is the shared data source for
-                    // /status reporting and the legacy DB-driven paths.
-                    self.sync_deposit_status_after_approval(&src_chain_id, nonce)
-                        .await;
-                }
-                Err(e) => {
-                    warn!(
-                        error = %e,
-                        hash = %bytes32_to_hex(&xchain_hash_id),
-                        "Failed to submit withdrawApprove, will retry next cycle"
-                    );
+        Ok(())
+    }
+
+    /// Submit `withdrawApprove` for every verified withdrawal, as many at once as there are
+    /// active signer keys
+    async fn approve_verified(&mut self, verified: Vec<VerifiedWithdraw>, via: &str) {
+        let width = self.signers.active().max(1);
+        for chunk in verified.chunks(width) {
+            let results = futures::future::join_all(
+                chunk
+                    .iter()
+                    .map(|w| self.submit_withdraw_approve(&w.xchain_hash_id)),
+            )
+            .await;
+
+            for (w, result) in chunk.iter().zip(results) {
+                match result {
+                    Ok(tx_hash) => {
+                        info!(
+                            tx_hash = %tx_hash,
+                            hash = %bytes32_to_hex(&w.xchain_hash_id),
+                            nonce = w.nonce,
+                            via = via,
+                            "WithdrawApprove submitted successfully"
+                        );
+
+                        self.approved_hashes.insert(w.xchain_hash_id);
+
+                        self.schedule_execution(w.xchain_hash_id).await;
+
+                        // Sync DB: mark corresponding evm_deposit or terra_deposit as processed
+                        // so pending_deposits count stays accurate. The V2 poll-and-approve path
+                        // works from on-chain events, but the DB is the shared data source for
+                        // /status reporting and the legacy DB-driven paths.
+                        self.sync_deposit_status_after_approval(&w.src_chain_id, w.nonce)
+                            .await;
+                    }
+                    Err(e) => {
+                        warn!(
+                            error = %e,
+                            hash = %bytes32_to_hex(&w.xchain_hash_id),
+                            "Failed to submit withdrawApprove, will retry next cycle"
+                        );
+                    }
                 }
             }
         }
+    }
 
-        self.last_polled_block = last_successful_block;
-
-        Ok(())
+    /// Re-read signer balances when due (retires keys that ran dry, alerts on low ones)
+    async fn refresh_signer_balances(&self) {
+        let rpc_url = self.rpc_url.clone();
+        self.signers
+            .refresh_balances(|signer| {
+                let address = signer.address();
+                let rpc_url = rpc_url.clone();
+                async move {
+                    let provider = ProviderBuilder::new()
+                        .on_http(rpc_url.parse().wrap_err("Invalid RPC URL")?);
+                    let balance = provider.get_balance(address).await?;
+                    Ok::<_, eyre::Report>(u128::try_from(balance).unwrap_or(u128::MAX))
+                }
+            })
+            .await;
     }
 
     /// Verify a deposit exists on the source chain.
@@ -1035,7 +1100,8 @@ impl EvmWriter {
 
     /// Submit a withdrawApprove transaction
     async fn submit_withdraw_approve(&self, xchain_hash_id: &[u8; 32]) -> Result<String> {
-        let wallet = EthereumWallet::from(self.signer.clone());
+        let signer = self.signers.acquire()?;
+        let wallet = EthereumWallet::from((*signer).clone());
         let provider = ProviderBuilder::new()
             .with_recommended_fillers()
             .wallet(wallet)
@@ -1049,7 +1115,7 @@ impl EvmWriter {
         let pending_tx = call
             .send()
             .await
-            .map_err(|e| eyre!("Failed to send withdrawApprove tx: {}", e))?;
+            .map_err(|e| signer.observe_error(eyre!("Failed to send withdrawApprove tx: {}", e)))?;
 
         let tx_hash = *pending_tx.tx_hash();
         info!(tx_hash = %tx_hash, "withdrawApprove tx sent, waiting for confirmation");
@@ -1066,40 +1132,43 @@ impl EvmWriter {
         Ok(format!("0x{:x}", tx_hash))
     }
 
-    /// Process pending executions (after cancel window has elapsed)
-    async fn process_pending_executions(&mut self) -> Result<()> {
-        let now = Instant::now();
-        let mut to_remove = Vec::new();
-
-        for (hash, pending) in self.pending_executions.iter() {
-            let elapsed = now.duration_since(pending.approved_at);
-
-            if elapsed.as_secs() >= pending.delay_seconds {
-                // Delay has elapsed, try to execute
-                match self.submit_execute_withdraw(*hash).await {
-                    Ok(tx_hash) => {
-                        info!(
-                            xchain_hash_id = %bytes32_to_hex(hash),
-                            tx_hash = %tx_hash,
-                            "Successfully executed EVM withdrawal"
-                        );
-                        to_remove.push(*hash);
-                    }
-                    Err(e) => {
-                        warn!(
-                            xchain_hash_id = %bytes32_to_hex(hash),
-                            error = %e,
-                            attempt = pending.attempts + 1,
-                            "Failed to execute EVM withdrawal, will retry"
-                        );
-                    }
-                }
-            }
+    /// Schedule auto-execution of an approved withdrawal once the cancel window has passed
+    async fn schedule_execution(&self, xchain_hash_id: [u8; 32]) {
+        if let Err(e) = db::schedule_withdraw_execution(
+            &self.db,
+            "evm",
+            self.this_chain_id.as_bytes(),
+            &xchain_hash_id,
+            self.cancel_window as i64,
+        )
+        .await
+        {
+            warn!(
+                xchain_hash_id = %bytes32_to_hex(&xchain_hash_id),
+                error = %e,
+                "Failed to schedule withdrawal execution"
+            );
         }
+    }
+
+    /// Execute approved withdrawals on this chain whose cancel window has elapsed
+    async fn process_pending_executions(&mut self) -> Result<()> {
+        let due = db::claim_due_withdraw_executions(
+            &self.db,
+            self.this_chain_id.as_bytes(),
+            crate::leader::instance_id(),
+            crate::leader::fence().as_ref(),
+            crate::leader::CLAIM_TTL_SECS,
+            crate::leader::CLAIM_BATCH_SIZE,
+        )
+        .await?;
 
-        // Remove successfully executed
-        for hash in to_remove {
-            self.pending_executions.remove(&hash);
+        for row in due {
+            let result = match super::execution::xchain_hash_id(&row) {
+                Some(hash) => self.submit_execute_withdraw(hash).await,
+                None => Err(eyre!("malformed xchain_hash_id")),
+            };
+            super::execution::finish(&self.db, &self.execution, &row, result).await?;
         }
 
         Ok(())
@@ -1193,6 +1262,20 @@ impl EvmWriter {
 
         // Format addresses as standard EVM format (0x + 40 hex chars)
         let token_for_approval = format!("0x{}", hex::encode(token.0));
+
+        let facts = TransferFacts {
+            xchain_hash_id,
+            src_chain: *src_chain_id.as_bytes(),
+            dest_chain: *self.this_chain_id.as_bytes(),
+            src_account,
+            dest_account,
+            token: token_for_approval.clone(),
+            amount,
+            nonce: deposit.nonce as u64,
+        };
+        if !self.policy.permits(&facts).await {
+            return Ok(());
+        }
         let recipient_for_approval = format!("0x{}", hex::encode(recipient.0));
 
         let new_approval = NewApproval {
@@ -1228,16 +1311,7 @@ impl EvmWriter {
                     "Submitted approval transaction"
                 );
 
-                // Track for auto-execution
-                self.pending_executions.insert(
-                    xchain_hash_id,
-                    PendingExecution {
-                        xchain_hash_id,
-                        approved_at: Instant::now(),
-                        delay_seconds: self.cancel_window,
-                        attempts: 0,
-                    },
-                );
+                self.schedule_execution(xchain_hash_id).await;
 
                 db::update_terra_deposit_status(&self.db, deposit.id, "approved").await?;
                 db::update_approval_submitted(&self.db, approval_id, &tx_hash).await?;
@@ -1271,8 +1345,9 @@ impl EvmWriter {
         _src_chain_id: &ChainId,
         xchain_hash_id: &[u8; 32],
     ) -> Result<String> {
-        // Build provider with signer and recommended fillers (gas, nonce, fees)
-        let wallet = EthereumWallet::from(self.signer.clone());
+        // Build provider with a pool key and recommended fillers (gas, nonce, fees)
+        let signer = self.signers.acquire()?;
+        let wallet = EthereumWallet::from((*signer).clone());
         let provider = ProviderBuilder::new()
             .with_recommended_fillers()
             .wallet(wallet)
@@ -1332,7 +1407,7 @@ impl EvmWriter {
         let pending_tx = call
             .send()
             .await
-            .map_err(|e| eyre!("Failed to send transaction: {}", e))?;
+            .map_err(|e| signer.observe_error(eyre!("Failed to send transaction: {}", e)))?;
 
         let tx_hash = *pending_tx.tx_hash();
         info!(tx_hash = %tx_hash, "Transaction sent, waiting for confirmation");
@@ -1353,10 +1428,12 @@ impl EvmWriter {
     /// Submit an ExecuteWithdraw transaction (V2)
     ///
     /// In V2, we call withdrawExecuteUnlock for lock/unlock tokens
-    /// or withdrawExecuteMint for mintable tokens.
-    async fn submit_execute_withdraw(&self, xchain_hash_id: [u8; 32]) -> Result<String> {
-        // Build provider with signer and recommended fillers (gas, nonce, fees)
-        let wallet = EthereumWallet::from(self.signer.clone());
+    /// or withdrawExecuteMint for mintable tokens. Cancelled, already executed and
+    /// still-in-window withdrawals are reported without sending a transaction.
+    async fn submit_execute_withdraw(&self, xchain_hash_id: [u8; 32]) -> Result<ExecutionOutcome> {
+        // Build provider with a pool key and recommended fillers (gas, nonce, fees)
+        let signer = self.signers.acquire()?;
+        let wallet = EthereumWallet::from((*signer).clone());
         let provider = ProviderBuilder::new()
             .with_recommended_fillers()
             .wallet(wallet)
@@ -1379,17 +1456,29 @@ impl EvmWriter {
             ));
         }
         if pending.executed {
-            return Err(eyre!(
-                "Withdrawal {} already executed",
-                bytes32_to_hex(&xchain_hash_id)
-            ));
+            return Ok(ExecutionOutcome::AlreadyExecuted);
         }
         if pending.cancelled {
+            return Ok(ExecutionOutcome::Cancelled);
+        }
+        if !pending.approved {
             return Err(eyre!(
-                "Withdrawal {} was cancelled",
+                "Withdrawal {} is not approved",
                 bytes32_to_hex(&xchain_hash_id)
             ));
         }
+        let cancel_window = contract
+            .getCancelWindow()
+            .call()
+            .await
+            .map_err(|e| eyre!("Failed to get cancel window: {}", e))?
+            ._0;
+        let window_end: i64 = (pending.approvedAt + cancel_window)
+            .try_into()
+            .unwrap_or(i64::MAX);
+        if window_end >= super::execution::unix_now() {
+            return Ok(ExecutionOutcome::NotReady(window_end));
+        }
 
         let token_addr = pending.token;
 
@@ -1430,7 +1519,7 @@ impl EvmWriter {
                 .send()
                 .await
         }
-        .map_err(|e| eyre!("Failed to send withdraw tx: {}", e))?;
+        .map_err(|e| signer.observe_error(eyre!("Failed to send withdraw tx: {}", e)))?;
 
         let tx_hash = *pending_tx.tx_hash();
         info!(tx_hash = %tx_hash, "Withdraw transaction sent (V2)");
@@ -1445,7 +1534,7 @@ impl EvmWriter {
             return Err(eyre!("Withdraw transaction reverted"));
         }
 
-        Ok(format!("0x{:x}", tx_hash))
+        Ok(ExecutionOutcome::Executed(format!("0x{:x}", tx_hash)))
     }
 
     /// Process pending EVM deposits destined for this EVM chain (EVM→EVM path).
@@ -1455,7 +1544,8 @@ impl EvmWriter {
     /// with `dest_chain_type = 'evm'` in the database.
     ///
     /// Flow:
-    /// 1. Query DB for pending EVM deposits with dest_chain_type = 'evm'
+    /// 1. Claim pending EVM deposits with dest_chain_type = 'evm' (SKIP LOCKED, so a second
+    ///    operator instance never processes the same rows)
     /// 2. For each deposit, verify it exists on the source EVM chain
     /// 3. Compute the transfer hash using the source chain's 4-byte ID
     /// 4. Submit withdrawApprove(hash) on this (destination) chain's bridge contract
@@ -1464,8 +1554,15 @@ impl EvmWriter {
         // First, check if any pending executions are ready
         self.process_pending_executions().await?;
 
-        // Query EVM deposits destined for EVM chains
-        let deposits = db::get_pending_evm_deposits_for_evm(&self.db).await?;
+        // Claim EVM deposits destined for EVM chains
+        let deposits = db::claim_pending_evm_deposits_for_evm(
+            &self.db,
+            crate::leader::instance_id(),
+            crate::leader::fence().as_ref(),
+            crate::leader::CLAIM_TTL_SECS,
+            crate::leader::CLAIM_BATCH_SIZE,
+        )
+        .await?;
 
         if !deposits.is_empty() {
             info!(count = deposits.len(), "Processing EVM→EVM deposits");
@@ -1606,6 +1703,20 @@ impl EvmWriter {
         // (universal address format). Either way, the EVM address is in the last 20 bytes.
         let token_hex = format!("0x{}", hex::encode(&token_bytes32[12..32]));
 
+        let facts = TransferFacts {
+            xchain_hash_id,
+            src_chain: *src_chain_id.as_bytes(),
+            dest_chain: *self.this_chain_id.as_bytes(),
+            src_account,
+            dest_account,
+            token: token_hex.clone(),
+            amount,
+            nonce: deposit.nonce as u64,
+        };
+        if !self.policy.permits(&facts).await {
+            return Ok(());
+        }
+
         // Extract recipient from dest_account (last 20 bytes for EVM)
         let recipient_hex = format!("0x{}", hex::encode(&dest_account[12..32]));
 
@@ -1641,16 +1752,7 @@ impl EvmWriter {
                     "Submitted EVM→EVM approval transaction"
                 );
 
-                // Track for auto-execution after cancel window
-                self.pending_executions.insert(
-                    xchain_hash_id,
-                    PendingExecution {
-                        xchain_hash_id,
-                        approved_at: Instant::now(),
-                        delay_seconds: self.cancel_window,
-                        attempts: 0,
-                    },
-                );
+                self.schedule_execution(xchain_hash_id).await;
 
                 db::update_evm_deposit_status(&self.db, deposit.id, "approved").await?;
                 db::update_approval_submitted(&self.db, approval_id, &tx_hash).await?;
@@ -1674,8 +1776,9 @@ impl EvmWriter {
     /// this hash (i.e., `submittedAt != 0`). If not, the approval would revert
     /// on-chain, so we bail early with a retriable error.
     async fn submit_evm_to_evm_approval(&self, xchain_hash_id: &[u8; 32]) -> Result<String> {
-        // Build provider with signer and recommended fillers (gas, nonce, fees)
-        let wallet = EthereumWallet::from(self.signer.clone());
+        // Build provider with a pool key and recommended fillers (gas, nonce, fees)
+        let signer = self.signers.acquire()?;
+        let wallet = EthereumWallet::from((*signer).clone());
         let provider = ProviderBuilder::new()
             .with_recommended_fillers()
             .wallet(wallet)
@@ -1724,10 +1827,9 @@ impl EvmWriter {
 
         let call = contract.withdrawApprove(xchain_hash_id_fixed);
 
-        let pending_tx = call
-            .send()
-            .await
-            .map_err(|e| eyre!("Failed to send EVM→EVM approval tx: {}", e))?;
+        let pending_tx = call.send().await.map_err(|e| {
+            signer.observe_error(eyre!("Failed to send EVM→EVM approval tx: {}", e))
+        })?;
 
         let tx_hash = *pending_tx.tx_hash();
         info!(tx_hash = %tx_hash, "EVM→EVM approval tx sent, waiting for confirmation");
@@ -1744,6 +1846,165 @@ impl EvmWriter {
         Ok(format!("0x{:x}", tx_hash))
     }
 
+    /// Submit `withdrawSubmitRelayed` for deposits whose depositor asked for a relay to
+    /// this chain (see [`super::relay`])
+    ///
+    /// Once submitted, the withdrawal is approved and executed by the normal enumerate/poll
+    /// path exactly like a user-submitted one.
+    async fn process_relay_requests(&self) -> Result<()> {
+        let requests = db::claim_pending_relay_requests(
+            &self.db,
+            self.this_chain_id.as_bytes(),
+            crate::leader::instance_id(),
+            crate::leader::fence().as_ref(),
+            crate::leader::CLAIM_TTL_SECS,
+            crate::leader::CLAIM_BATCH_SIZE,
+        )
+        .await?;
+        if requests.is_empty() {
+            return Ok(());
+        }
+
+        let provider =
+            ProviderBuilder::new().on_http(self.rpc_url.parse().wrap_err("Invalid RPC URL")?);
+        let cap_bps: u64 = Bridge::new(self.bridge_address, &provider)
+            .relayFeeCapBps()
+            .call()
+            .await
+            .wrap_err("Failed to query relayFeeCapBps")?
+            ._0
+            .try_into()
+            .unwrap_or(u64::MAX);
+
+        info!(count = requests.len(), cap_bps, "Processing relay requests");
+        for request in requests {
+            if let Err(e) = self.relay_withdraw(&request, cap_bps).await {
+                warn!(
+                    request_id = request.id,
+                    xchain_hash_id = %format!("0x{}", hex::encode(&request.xchain_hash_id)),
+                    error = %e,
+                    "Relay submission failed, will retry"
+                );
+                crate::metrics::record_withdraw_relay("error");
+                db::record_relay_request_error(
+                    &self.db,
+                    request.id,
+                    &e.to_string(),
+                    self.relay.max_attempts,
+                )
+                .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Submit the withdrawal for one relay request, or close the request when it should not
+    /// be relayed. Errors are retriable.
+    async fn relay_withdraw(&self, request: &db::RelayRequest, cap_bps: u64) -> Result<()> {
+        let xchain_hash_id: [u8; 32] = request
+            .xchain_hash_id
+            .as_slice()
+            .try_into()
+            .map_err(|_| eyre!("Relay request {} has a malformed hash", request.id))?;
+        let sources = super::RelaySources {
+            terra: self.terra_chain_id.as_ref().map(|id| *id.as_bytes()),
+            solana: self
+                .solana_source_config
+                .as_ref()
+                .and_then(|c| c.chain_ids.first().copied()),
+        };
+        let Some(withdraw) = super::relay::load_relayed_withdraw(
+            &self.db,
+            &xchain_hash_id,
+            self.this_chain_id.as_bytes(),
+            sources,
+        )
+        .await?
+        else {
+            crate::metrics::record_withdraw_relay("declined");
+            return db::close_relay_request(
+                &self.db,
+                request.id,
+                "declined",
+                "indexed deposit does not hash to the relay request",
+            )
+            .await;
+        };
+        let amount = withdraw.amount;
+
+        let max_relay_fee: u128 = request
+            .max_relay_fee
+            .parse()
+            .map_err(|_| eyre!("Invalid max relay fee: {}", request.max_relay_fee))?;
+        let relay_fee = match self.relay.relay_fee(amount, max_relay_fee, cap_bps) {
+            Ok(fee) => fee,
+            Err(reason) => {
+                info!(
+                    xchain_hash_id = %bytes32_to_hex(&xchain_hash_id),
+                    reason = %reason,
+                    "Declining relay request"
+                );
+                crate::metrics::record_withdraw_relay("declined");
+                return db::close_relay_request(&self.db, request.id, "declined", &reason).await;
+            }
+        };
+
+        let signer = self.signers.acquire()?;
+        let wallet = EthereumWallet::from((*signer).clone());
+        let provider = ProviderBuilder::new()
+            .with_recommended_fillers()
+            .wallet(wallet)
+            .on_http(self.rpc_url.parse().wrap_err("Invalid RPC URL")?);
+        let contract = Bridge::new(self.bridge_address, &provider);
+
+        // The recipient (or anyone) may have submitted it in the meantime
+        let pending = contract
+            .getPendingWithdraw(FixedBytes::from(xchain_hash_id))
+            .call()
+            .await
+            .wrap_err("Pre-flight getPendingWithdraw failed")?;
+        if !pending.submittedAt.is_zero() {
+            crate::metrics::record_withdraw_relay("skipped");
+            return db::close_relay_request(&self.db, request.id, "skipped", "already submitted")
+                .await;
+        }
+
+        let pending_tx = contract
+            .withdrawSubmitRelayed(
+                FixedBytes::from(withdraw.src_chain),
+                FixedBytes::from(withdraw.src_account),
+                FixedBytes::from(withdraw.dest_account),
+                Address::from_slice(&withdraw.dest_token[12..32]),
+                U256::from(amount),
+                withdraw.nonce,
+                U256::from(relay_fee),
+            )
+            .send()
+            .await
+            .map_err(|e| {
+                signer.observe_error(eyre!("Failed to send withdrawSubmitRelayed tx: {}", e))
+            })?;
+        let tx_hash = *pending_tx.tx_hash();
+        let receipt = pending_tx
+            .get_receipt()
+            .await
+            .map_err(|e| eyre!("Failed to get receipt: {}", e))?;
+        if !receipt.status() {
+            return Err(eyre!("withdrawSubmitRelayed transaction reverted"));
+        }
+
+        let tx_hash = format!("0x{:x}", tx_hash);
+        info!(
+            xchain_hash_id = %bytes32_to_hex(&xchain_hash_id),
+            relay_fee = %relay_fee,
+            tx_hash = %tx_hash,
+            "Submitted relayed withdrawal"
+        );
+        crate::metrics::record_withdraw_relay("submitted");
+        db::update_relay_request_submitted(&self.db, request.id, &relay_fee.to_string(), &tx_hash)
+            .await
+    }
+
     /// Calculate fee based on amount
     fn calculate_fee(&self, amount: &str) -> U256 {
         let amount_u256 = U256::from_str(amount).unwrap_or_else(|e| {
@@ -1757,14 +2018,9 @@ impl EvmWriter {
         amount_u256 * U256::from(self.default_fee_bps) / U256::from(10000u64)
     }
 
-    /// Get the operator's address
+    /// Get the operator's primary address
     pub fn operator_address(&self) -> Address {
-        self.signer.address()
-    }
-
-    /// Get count of pending executions
-    pub fn pending_execution_count(&self) -> usize {
-        self.pending_executions.len()
+        self.signers.primary().address()
     }
 }
 
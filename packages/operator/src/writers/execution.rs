@@ -0,0 +1,179 @@
+//! Durable auto-execution of approved withdrawals
+//!
+//! When a writer approves a withdrawal it schedules a `withdraw_executions` row due at
+//! approval + cancel window. Every pass, each writer claims the due rows for its own chain,
+//! re-reads the withdrawal on chain and submits the execute transaction. Because the schedule
+//! lives in Postgres, withdrawals waiting out their cancel window survive restarts and leader
+//! failover.
+//!
+//! A withdrawal cancelled in the meantime is closed without a transaction, and one the
+//! recipient already executed is closed as executed. If the on-chain window ends later than
+//! scheduled, the row is moved to the real window end without counting an attempt.
+//!
+//! Configuration (all optional):
+//!
+//! - `OPERATOR_EXECUTION_MAX_ATTEMPTS` — failed execute attempts before a row is failed (default 10)
+//! - `OPERATOR_EXECUTION_RETRY_SECS` — delay before retrying a failed attempt (default 60)
+
+use eyre::{eyre, Result};
+use sqlx::PgPool;
+use tracing::{info, warn};
+
+use crate::db::{self, WithdrawExecution};
+use crate::hash::bytes32_to_hex;
+
+const DEFAULT_EXECUTION_MAX_ATTEMPTS: i32 = 10;
+const DEFAULT_EXECUTION_RETRY_SECS: i64 = 60;
+
+/// Chain types stored in `withdraw_executions.chain_type`
+pub const CHAIN_TYPES: [&str; 3] = ["evm", "terra", "solana"];
+
+/// What the chain reported for a due withdrawal
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExecutionOutcome {
+    /// This operator's execute transaction confirmed
+    Executed(String),
+    /// Executed before this operator got to it (usually by the recipient)
+    AlreadyExecuted,
+    /// Cancelled during the cancel window
+    Cancelled,
+    /// Still inside the cancel window; executable after this unix timestamp
+    NotReady(i64),
+}
+
+/// Auto-execution retry settings
+#[derive(Debug, Clone)]
+pub struct ExecutionConfig {
+    pub max_attempts: i32,
+    pub retry_secs: i64,
+}
+
+impl Default for ExecutionConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: DEFAULT_EXECUTION_MAX_ATTEMPTS,
+            retry_secs: DEFAULT_EXECUTION_RETRY_SECS,
+        }
+    }
+}
+
+impl ExecutionConfig {
+    pub fn from_env() -> Result<Self> {
+        let max_attempts = match std::env::var("OPERATOR_EXECUTION_MAX_ATTEMPTS") {
+            Ok(v) => v.parse().ok().filter(|n| *n > 0).ok_or_else(|| {
+                eyre!(
+                    "OPERATOR_EXECUTION_MAX_ATTEMPTS must be positive, got {:?}",
+                    v
+                )
+            })?,
+            Err(_) => DEFAULT_EXECUTION_MAX_ATTEMPTS,
+        };
+        let retry_secs = match std::env::var("OPERATOR_EXECUTION_RETRY_SECS") {
+            Ok(v) => v.parse().ok().filter(|n| *n > 0).ok_or_else(|| {
+                eyre!(
+                    "OPERATOR_EXECUTION_RETRY_SECS must be positive, got {:?}",
+                    v
+                )
+            })?,
+            Err(_) => DEFAULT_EXECUTION_RETRY_SECS,
+        };
+        Ok(Self {
+            max_attempts,
+            retry_secs,
+        })
+    }
+}
+
+/// The row's transfer hash, or None when the stored value is malformed
+pub fn xchain_hash_id(row: &WithdrawExecution) -> Option<[u8; 32]> {
+    row.xchain_hash_id.as_slice().try_into().ok()
+}
+
+/// Current unix time in seconds
+pub fn unix_now() -> i64 {
+    chrono::Utc::now().timestamp()
+}
+
+/// Record the result of one execute attempt for `row`
+pub async fn finish(
+    pool: &PgPool,
+    config: &ExecutionConfig,
+    row: &WithdrawExecution,
+    result: Result<ExecutionOutcome>,
+) -> Result<()> {
+    let hash = bytes32_to_hex(&xchain_hash_id(row).unwrap_or_default());
+    match result {
+        Ok(ExecutionOutcome::Executed(tx_hash)) => {
+            info!(
+                chain = %row.chain_type,
+                xchain_hash_id = %hash,
+                tx_hash = %tx_hash,
+                "Executed withdrawal after cancel window"
+            );
+            crate::metrics::record_execution_submitted(&row.chain_type, "executed");
+            db::close_withdraw_execution(pool, row.id, "executed", Some(&tx_hash), None).await
+        }
+        Ok(ExecutionOutcome::AlreadyExecuted) => {
+            info!(
+                chain = %row.chain_type,
+                xchain_hash_id = %hash,
+                "Withdrawal was already executed on chain"
+            );
+            crate::metrics::record_execution_submitted(&row.chain_type, "already_executed");
+            db::close_withdraw_execution(pool, row.id, "executed", None, None).await
+        }
+        Ok(ExecutionOutcome::Cancelled) => {
+            info!(
+                chain = %row.chain_type,
+                xchain_hash_id = %hash,
+                "Withdrawal was cancelled during the cancel window, not executing"
+            );
+            crate::metrics::record_execution_submitted(&row.chain_type, "cancelled");
+            db::close_withdraw_execution(
+                pool,
+                row.id,
+                "cancelled",
+                None,
+                Some("cancelled on chain"),
+            )
+            .await
+        }
+        Ok(ExecutionOutcome::NotReady(executable_at)) => {
+            // Execution requires block time strictly past the window end
+            db::defer_withdraw_execution(pool, row.id, executable_at + 1).await
+        }
+        Err(e) => {
+            let attempt = row.attempts + 1;
+            warn!(
+                chain = %row.chain_type,
+                xchain_hash_id = %hash,
+                error = %e,
+                attempt = attempt,
+                max_attempts = config.max_attempts,
+                "Failed to execute withdrawal"
+            );
+            crate::metrics::record_execution_submitted(&row.chain_type, "error");
+            db::record_withdraw_execution_error(
+                pool,
+                row.id,
+                &e.to_string(),
+                config.retry_secs,
+                config.max_attempts,
+            )
+            .await
+        }
+    }
+}
+
+/// Refresh the overdue-execution gauge from the database
+pub async fn refresh_overdue_metric(pool: &PgPool) -> Result<()> {
+    let counts = db::count_overdue_withdraw_executions(pool).await?;
+    for chain in CHAIN_TYPES {
+        let count = counts
+            .iter()
+            .find(|(c, _)| c == chain)
+            .map_or(0, |(_, n)| *n);
+        crate::metrics::set_withdraw_executions_overdue(chain, count);
+    }
+    Ok(())
+}
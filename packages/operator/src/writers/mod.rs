@@ -1,23 +1,41 @@
 use alloy::primitives::Address;
 use eyre::{eyre, Result, WrapErr};
+use multichain_rs::token_cache::TokenCache;
 use sqlx::PgPool;
 use std::collections::HashMap;
 use std::str::FromStr;
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::mpsc;
 
+use crate::db;
+use crate::leader::LeaderHandle;
+use crate::policy::PolicyEngine;
 use crate::types::ChainId;
 
 pub mod evm;
+pub mod execution;
+pub mod relay;
 pub mod retry;
+pub mod signer_pool;
 pub mod solana;
 pub mod terra;
 
 pub use evm::EvmWriter;
+pub use execution::ExecutionConfig;
+pub use relay::{RelayConfig, RelaySources};
 pub use retry::{classify_error, RetryConfig};
 pub use solana::SolanaWriter;
 pub use terra::TerraWriter;
 
+/// Withdrawal verified on its source chain and permitted by policy, queued for approval
+#[derive(Debug, Clone)]
+pub(crate) struct VerifiedWithdraw {
+    pub xchain_hash_id: [u8; 32],
+    pub src_chain_id: [u8; 4],
+    pub nonce: u64,
+}
+
 /// Solana source chain configuration for deposit verification.
 /// Used by EVM and Terra writers to verify deposits originating from Solana.
 #[derive(Debug, Clone)]
@@ -172,6 +190,9 @@ pub(crate) async fn verify_deposit_on_solana_source(
         .unwrap_or_else(|| eyre!("All Solana RPC endpoints failed for deposit verification")))
 }
 
+/// Writer names used for circuit breakers (`writer_breakers` table, admin API)
+pub const BREAKER_WRITERS: [&str; 3] = ["evm", "terra", "evm_to_evm"];
+
 /// Circuit breaker configuration for writer managers
 #[derive(Debug, Clone)]
 pub struct CircuitBreakerConfig {
@@ -212,6 +233,7 @@ pub struct WriterManager {
     consecutive_evm_failures: u32,
     consecutive_terra_failures: u32,
     consecutive_evm_to_evm_failures: u32,
+    db: PgPool,
 }
 
 impl WriterManager {
@@ -220,7 +242,15 @@ impl WriterManager {
     /// If `config.multi_evm` is set, creates additional `EvmWriter` instances
     /// for each enabled chain in the multi-EVM configuration. These writers
     /// handle EVM→EVM transfers by submitting approvals on the destination chain.
-    pub async fn new(config: &crate::config::Config, db: PgPool) -> Result<Self> {
+    ///
+    /// Every writer consults `policy` before submitting an approval; the Terra writer reads
+    /// token types from `token_cache`.
+    pub async fn new(
+        config: &crate::config::Config,
+        db: PgPool,
+        policy: Arc<PolicyEngine>,
+        token_cache: Arc<TokenCache>,
+    ) -> Result<Self> {
         // Build source chain endpoints for cross-chain deposit verification routing (O1).
         // Each EvmWriter gets this map so it can verify deposits on any known source chain,
         // routing to the correct RPC/bridge instead of always using its own.
@@ -276,6 +306,7 @@ impl WriterManager {
             db.clone(),
             source_chain_endpoints.clone(),
             solana_source_config.clone(),
+            policy.clone(),
         )
         .await?;
         let terra_writer = TerraWriter::new(
@@ -283,15 +314,19 @@ impl WriterManager {
             source_chain_endpoints.clone(),
             solana_source_config.clone(),
             db.clone(),
+            policy.clone(),
         )
-        .await?;
+        .await?
+        .with_token_cache(token_cache);
 
         // Create per-chain EVM writers from MultiEvmConfig
         let mut evm_chain_writers = HashMap::new();
         if let Some(ref multi) = config.multi_evm {
             for chain in multi.enabled_chains() {
                 use crate::multi_evm::EvmChainConfigExt;
-                let chain_evm_config = chain.to_operator_evm_config(multi.private_key());
+                let mut chain_evm_config = chain.to_operator_evm_config(multi.private_key());
+                // The extra operator keys are authorised on every EVM bridge
+                chain_evm_config.extra_private_keys = config.evm.extra_private_keys.clone();
                 match EvmWriter::new(
                     &chain_evm_config,
                     Some(&config.terra),
@@ -299,6 +334,7 @@ impl WriterManager {
                     db.clone(),
                     source_chain_endpoints.clone(),
                     solana_source_config.clone(),
+                    policy.clone(),
                 )
                 .await
                 {
@@ -336,12 +372,17 @@ impl WriterManager {
             consecutive_evm_failures: 0,
             consecutive_terra_failures: 0,
             consecutive_evm_to_evm_failures: 0,
+            db,
         })
     }
 
     /// Run all writers concurrently
-    /// Processes pending approvals and releases
-    pub async fn run(&mut self, mut shutdown: mpsc::Receiver<()>) -> Result<()> {
+    /// Processes pending approvals and releases while `leader` holds the operator lease
+    pub async fn run(
+        &mut self,
+        mut shutdown: mpsc::Receiver<()>,
+        leader: LeaderHandle,
+    ) -> Result<()> {
         let poll_interval = Duration::from_millis(5000);
         let mut cycle_count = 0u64;
 
@@ -355,26 +396,39 @@ impl WriterManager {
 
             // Log every 12 cycles (~60 seconds) to show the writer is alive
             if cycle_count % 12 == 1 {
-                let multi_evm_pending: usize = self
-                    .evm_chain_writers
-                    .values()
-                    .map(|w| w.pending_execution_count())
-                    .sum();
                 tracing::info!(
                     cycle = cycle_count,
                     evm_failures = self.consecutive_evm_failures,
                     terra_failures = self.consecutive_terra_failures,
                     evm_to_evm_failures = self.consecutive_evm_to_evm_failures,
-                    evm_pending_executions = self.evm_writer.pending_execution_count(),
-                    terra_pending_executions = self.terra_writer.pending_execution_count(),
                     multi_evm_chains = self.evm_chain_writers.len(),
-                    multi_evm_pending = multi_evm_pending,
+                    role = leader.role(),
                     "Writer manager heartbeat"
                 );
+                // Every instance reports the gauge so standbys alert too
+                if let Err(e) = execution::refresh_overdue_metric(&self.db).await {
+                    tracing::warn!(error = %e, "Failed to refresh overdue execution metric");
+                }
+            }
+
+            // Standby: watchers keep the database warm, writers wait for the lease
+            if !leader.is_leader() {
+                tokio::select! {
+                    _ = tokio::time::sleep(poll_interval) => {}
+                    _ = shutdown.recv() => {
+                        tracing::info!("Shutdown signal received, stopping writers");
+                        return Ok(());
+                    }
+                }
+                continue;
             }
 
+            // A cycle can outlive the lease TTL, so drop it as soon as the lease is lost
             tokio::select! {
                 _ = self.process_pending() => {}
+                _ = leader.lost() => {
+                    tracing::warn!("Operator lease lost mid-cycle, abandoning in-flight submissions");
+                }
                 _ = shutdown.recv() => {
                     tracing::info!("Shutdown signal received, stopping writers");
                     return Ok(());
@@ -386,20 +440,86 @@ impl WriterManager {
     }
 
     async fn process_pending(&mut self) -> Result<()> {
+        // Breakers are kept in the database so the admin API can open and close them
+        let open = match db::get_open_writer_breakers(&self.db).await {
+            Ok(open) => open,
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to read writer circuit breakers, assuming closed");
+                Vec::new()
+            }
+        };
+
         // Check EVM circuit breaker
-        if self.consecutive_evm_failures >= self.circuit_breaker.threshold {
+        if self
+            .breaker_open(&open, "evm", self.consecutive_evm_failures)
+            .await
+        {
+            self.consecutive_evm_failures = 0;
+        } else {
+            // Process pending withdrawals on EVM (V2 poll-and-approve)
+            // This handles BOTH Terra→EVM and EVM→EVM transfers:
+            // polls WithdrawSubmit events, verifies deposits on source chain, approves.
+            self.process_evm().await;
+        }
+
+        // Check Terra circuit breaker
+        if self
+            .breaker_open(&open, "terra", self.consecutive_terra_failures)
+            .await
+        {
+            self.consecutive_terra_failures = 0;
+        } else {
+            // Process pending withdrawals on Terra (poll-and-approve)
+            // Handles EVM→Terra transfers: polls Terra PendingWithdrawals,
+            // verifies deposits on EVM, approves on Terra.
+            self.process_terra().await;
+        }
+
+        // Multi-chain EVM writers: each per-chain writer polls its own chain
+        // for WithdrawSubmit events and approves verified ones
+        if !self.evm_chain_writers.is_empty() {
+            if self
+                .breaker_open(&open, "evm_to_evm", self.consecutive_evm_to_evm_failures)
+                .await
+            {
+                self.consecutive_evm_to_evm_failures = 0;
+            } else {
+                self.process_evm_to_evm().await;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Whether `writer` sits out this cycle: its breaker is open (tripped earlier or through
+    /// the admin API), or `failures` just reached the threshold and it is tripped now
+    async fn breaker_open(&self, open: &[String], writer: &str, failures: u32) -> bool {
+        if failures >= self.circuit_breaker.threshold {
+            let pause = self.circuit_breaker.pause_duration;
             tracing::warn!(
-                failures = self.consecutive_evm_failures,
-                pause_secs = self.circuit_breaker.pause_duration.as_secs(),
-                "EVM circuit breaker tripped, pausing EVM writer"
+                writer = writer,
+                failures = failures,
+                pause_secs = pause.as_secs(),
+                "Writer circuit breaker tripped, pausing writer"
             );
-            tokio::time::sleep(self.circuit_breaker.pause_duration).await;
-            self.consecutive_evm_failures = 0;
+            let reason = format!("{} consecutive failures", failures);
+            if let Err(e) =
+                db::trip_writer_breaker(&self.db, writer, pause.as_secs() as i64, &reason, "auto")
+                    .await
+            {
+                tracing::warn!(error = %e, "Failed to record circuit breaker trip, pausing in-process");
+                tokio::time::sleep(pause).await;
+            }
+            return true;
         }
+        if open.iter().any(|w| w == writer) {
+            tracing::debug!(writer = writer, "Writer circuit breaker open, skipping");
+            return true;
+        }
+        false
+    }
 
-        // Process pending withdrawals on EVM (V2 poll-and-approve)
-        // This handles BOTH Terra→EVM and EVM→EVM transfers:
-        // polls WithdrawSubmit events, verifies deposits on source chain, approves.
+    async fn process_evm(&mut self) {
         match self.evm_writer.process_pending().await {
             Ok(()) => {
                 self.consecutive_evm_failures = 0;
@@ -420,21 +540,9 @@ impl WriterManager {
                 tokio::time::sleep(backoff).await;
             }
         }
+    }
 
-        // Check Terra circuit breaker
-        if self.consecutive_terra_failures >= self.circuit_breaker.threshold {
-            tracing::warn!(
-                failures = self.consecutive_terra_failures,
-                pause_secs = self.circuit_breaker.pause_duration.as_secs(),
-                "Terra circuit breaker tripped, pausing Terra writer"
-            );
-            tokio::time::sleep(self.circuit_breaker.pause_duration).await;
-            self.consecutive_terra_failures = 0;
-        }
-
-        // Process pending withdrawals on Terra (poll-and-approve)
-        // Handles EVM→Terra transfers: polls Terra PendingWithdrawals,
-        // verifies deposits on EVM, approves on Terra.
+    async fn process_terra(&mut self) {
         match self.terra_writer.process_pending().await {
             Ok(()) => {
                 self.consecutive_terra_failures = 0;
@@ -455,36 +563,32 @@ impl WriterManager {
                 tokio::time::sleep(backoff).await;
             }
         }
+    }
 
-        // Multi-chain EVM writers: each per-chain writer polls its own chain
-        // for WithdrawSubmit events and approves verified ones
-        if !self.evm_chain_writers.is_empty() {
-            let mut had_failure = false;
-            for (chain_id, writer) in self.evm_chain_writers.iter_mut() {
-                match writer.process_pending().await {
-                    Ok(()) => {}
-                    Err(e) => {
-                        had_failure = true;
-                        tracing::error!(
-                            chain_id = chain_id,
-                            error = %e,
-                            "Error processing EVM approvals for chain"
-                        );
-                    }
+    async fn process_evm_to_evm(&mut self) {
+        let mut had_failure = false;
+        for (chain_id, writer) in self.evm_chain_writers.iter_mut() {
+            match writer.process_pending().await {
+                Ok(()) => {}
+                Err(e) => {
+                    had_failure = true;
+                    tracing::error!(
+                        chain_id = chain_id,
+                        error = %e,
+                        "Error processing EVM approvals for chain"
+                    );
                 }
             }
-            if had_failure {
-                self.consecutive_evm_to_evm_failures += 1;
-                let backoff = self
-                    .retry_config
-                    .backoff_for_attempt(self.consecutive_evm_to_evm_failures);
-                tokio::time::sleep(backoff).await;
-            } else {
-                self.consecutive_evm_to_evm_failures = 0;
-            }
         }
-
-        Ok(())
+        if had_failure {
+            self.consecutive_evm_to_evm_failures += 1;
+            let backoff = self
+                .retry_config
+                .backoff_for_attempt(self.consecutive_evm_to_evm_failures);
+            tokio::time::sleep(backoff).await;
+        } else {
+            self.consecutive_evm_to_evm_failures = 0;
+        }
     }
 
     /// Get health status
@@ -495,8 +599,6 @@ impl WriterManager {
             terra_healthy: self.consecutive_terra_failures < self.circuit_breaker.threshold,
             evm_to_evm_healthy: self.consecutive_evm_to_evm_failures
                 < self.circuit_breaker.threshold,
-            evm_pending_executions: self.evm_writer.pending_execution_count(),
-            terra_pending_executions: self.terra_writer.pending_execution_count(),
             multi_evm_chains: self.evm_chain_writers.len(),
         }
     }
@@ -509,7 +611,5 @@ pub struct HealthStatus {
     pub evm_healthy: bool,
     pub terra_healthy: bool,
     pub evm_to_evm_healthy: bool,
-    pub evm_pending_executions: usize,
-    pub terra_pending_executions: usize,
     pub multi_evm_chains: usize,
 }
@@ -0,0 +1,283 @@
+//! Gasless withdrawals: the operator submits the withdrawal on the recipient's behalf
+//!
+//! A depositor opts in on the source bridge: `requestRelay(xchainHashId, maxRelayFee)` on EVM,
+//! `RequestRelay` on Terra or `request_relay` on Solana. The source chain's watcher records the
+//! request in `relay_requests`, and the destination chain's writer submits the withdrawal with
+//! this operator's relay fee: `withdrawSubmitRelayed` on EVM, `WithdrawSubmitRelayed` on Terra
+//! and `withdraw_submit_and_approve` with a `relay_fee` on Solana. From there the transfer
+//! follows the normal path: the writer approves it, auto-executes it after the cancel window,
+//! and the bridge pays the fee to the operator out of the withdrawn amount. The recipient never
+//! needs gas on the destination chain.
+//!
+//! Each destination bridge bounds the fee with its admin-set relay fee cap; cancelers and
+//! operators are responsible for only relaying deposits whose depositor asked for it.
+//!
+//! Configuration (all optional):
+//!
+//! - `OPERATOR_RELAY_ENABLED` — `true` to record relay requests and submit withdrawals
+//! - `OPERATOR_RELAY_FEE_BPS` — fee charged, in basis points of the amount (default 30)
+//! - `OPERATOR_RELAY_MAX_ATTEMPTS` — submissions tried before a request is failed (default 5)
+
+use eyre::{eyre, Result};
+use sqlx::PgPool;
+
+use crate::db;
+use crate::hash::{bytes32_to_hex, compute_xchain_hash_id, decode_bech32_address};
+
+const DEFAULT_RELAY_FEE_BPS: u32 = 30;
+const DEFAULT_RELAY_MAX_ATTEMPTS: i32 = 5;
+/// Highest relay fee cap any bridge accepts (`MAX_RELAY_FEE_BPS` on EVM, Terra and Solana)
+const MAX_RELAY_FEE_BPS: u32 = 500;
+
+/// Relay mode settings
+#[derive(Debug, Clone, Default)]
+pub struct RelayConfig {
+    pub enabled: bool,
+    pub fee_bps: u32,
+    pub max_attempts: i32,
+}
+
+impl RelayConfig {
+    pub fn from_env() -> Result<Self> {
+        let enabled = std::env::var("OPERATOR_RELAY_ENABLED")
+            .is_ok_and(|v| v == "1" || v.eq_ignore_ascii_case("true"));
+        let fee_bps = match std::env::var("OPERATOR_RELAY_FEE_BPS") {
+            Ok(v) => v
+                .parse()
+                .map_err(|_| eyre!("OPERATOR_RELAY_FEE_BPS must be a number, got {:?}", v))?,
+            Err(_) => DEFAULT_RELAY_FEE_BPS,
+        };
+        if fee_bps > MAX_RELAY_FEE_BPS {
+            return Err(eyre!(
+                "OPERATOR_RELAY_FEE_BPS ({}) exceeds the bridge maximum of {}",
+                fee_bps,
+                MAX_RELAY_FEE_BPS
+            ));
+        }
+        let max_attempts = match std::env::var("OPERATOR_RELAY_MAX_ATTEMPTS") {
+            Ok(v) => v.parse().ok().filter(|n| *n > 0).ok_or_else(|| {
+                eyre!("OPERATOR_RELAY_MAX_ATTEMPTS must be positive, got {:?}", v)
+            })?,
+            Err(_) => DEFAULT_RELAY_MAX_ATTEMPTS,
+        };
+        Ok(Self {
+            enabled,
+            fee_bps,
+            max_attempts,
+        })
+    }
+
+    /// Fee to charge for relaying `amount`, or why the request is declined
+    ///
+    /// `max_relay_fee` is the depositor's limit and `cap_bps` the destination bridge's
+    /// `relayFeeCapBps`; all amounts are in source decimals.
+    pub fn relay_fee(
+        &self,
+        amount: u128,
+        max_relay_fee: u128,
+        cap_bps: u64,
+    ) -> Result<u128, String> {
+        if cap_bps == 0 {
+            return Err("relaying is disabled on the destination bridge".to_string());
+        }
+        if u64::from(self.fee_bps) > cap_bps {
+            return Err(format!(
+                "relay fee of {} bps exceeds the destination bridge cap of {} bps",
+                self.fee_bps, cap_bps
+            ));
+        }
+        let fee = amount
+            .checked_mul(u128::from(self.fee_bps))
+            .map(|v| v / 10_000)
+            .ok_or_else(|| format!("amount {} overflows the relay fee calculation", amount))?;
+        if fee > max_relay_fee {
+            return Err(format!(
+                "relay fee {} exceeds the depositor's maximum of {}",
+                fee, max_relay_fee
+            ));
+        }
+        Ok(fee)
+    }
+}
+
+/// Withdrawal a relay request asks for, rebuilt from the indexed source deposit
+#[derive(Debug, Clone)]
+pub struct RelayedWithdraw {
+    pub src_chain: [u8; 4],
+    pub src_account: [u8; 32],
+    pub dest_account: [u8; 32],
+    pub dest_token: [u8; 32],
+    /// Source decimals
+    pub amount: u128,
+    pub nonce: u64,
+}
+
+/// V2 chain IDs of the Terra and Solana sources, which their deposit rows do not record
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RelaySources {
+    pub terra: Option<[u8; 4]>,
+    pub solana: Option<[u8; 4]>,
+}
+
+/// Rebuild the withdrawal behind `xchain_hash_id` from the indexed EVM, Terra or Solana
+/// deposit with that hash
+///
+/// Errors while the deposit is not indexed yet, so the request is retried. `Ok(None)` when
+/// the indexed deposit does not hash to the request with `dest_chain` as destination; the
+/// hash check proves every field matches what the depositor flagged.
+pub async fn load_relayed_withdraw(
+    pool: &PgPool,
+    xchain_hash_id: &[u8; 32],
+    dest_chain: &[u8; 4],
+    sources: RelaySources,
+) -> Result<Option<RelayedWithdraw>> {
+    let withdraw = if let Some(d) =
+        db::get_evm_deposit_by_transfer_hash(pool, xchain_hash_id).await?
+    {
+        RelayedWithdraw {
+            src_chain: fixed(
+                "EVM deposit",
+                "src_v2_chain_id",
+                d.src_v2_chain_id.as_deref(),
+            )?,
+            src_account: fixed("EVM deposit", "src_account", d.src_account.as_deref())?,
+            dest_account: fixed(
+                "EVM deposit",
+                "dest_account",
+                Some(d.dest_account.as_slice()),
+            )?,
+            dest_token: fixed(
+                "EVM deposit",
+                "dest_token_address",
+                Some(d.dest_token_address.as_slice()),
+            )?,
+            amount: parse_amount(&d.amount)?,
+            nonce: d.nonce as u64,
+        }
+    } else if let Some(d) = db::get_terra_deposit_by_transfer_hash(pool, xchain_hash_id).await? {
+        // Same encodings as the Terra bridge: canonical sender left-padded to 32 bytes,
+        // hex `dest_account` / `dest_token_address` attributes
+        let (raw, _) = decode_bech32_address(&d.sender)
+            .map_err(|e| eyre!("Invalid Terra sender {}: {}", d.sender, e))?;
+        let mut src_account = [0u8; 32];
+        src_account[32 - raw.len()..].copy_from_slice(&raw);
+        RelayedWithdraw {
+            src_chain: sources
+                .terra
+                .ok_or_else(|| eyre!("Terra chain ID not configured for relay requests"))?,
+            src_account,
+            dest_account: hex_bytes32("Terra deposit", "recipient", Some(d.recipient.as_str()))?,
+            dest_token: hex_bytes32(
+                "Terra deposit",
+                "dest_token_address",
+                d.dest_token_address.as_deref(),
+            )?,
+            amount: parse_amount(&d.amount)?,
+            nonce: d.nonce as u64,
+        }
+    } else if let Some(d) = db::get_solana_deposit_by_transfer_hash(pool, xchain_hash_id).await? {
+        RelayedWithdraw {
+            src_chain: sources
+                .solana
+                .ok_or_else(|| eyre!("Solana chain ID not configured for relay requests"))?,
+            src_account: fixed(
+                "Solana deposit",
+                "src_account",
+                Some(d.src_account.as_slice()),
+            )?,
+            dest_account: fixed(
+                "Solana deposit",
+                "dest_account",
+                Some(d.dest_account.as_slice()),
+            )?,
+            dest_token: fixed("Solana deposit", "token", Some(d.token.as_slice()))?,
+            amount: parse_amount(&d.amount)?,
+            nonce: d.nonce as u64,
+        }
+    } else {
+        return Err(eyre!(
+            "Source deposit {} is not indexed yet",
+            bytes32_to_hex(xchain_hash_id)
+        ));
+    };
+
+    let computed = compute_xchain_hash_id(
+        &withdraw.src_chain,
+        dest_chain,
+        &withdraw.src_account,
+        &withdraw.dest_account,
+        &withdraw.dest_token,
+        withdraw.amount,
+        withdraw.nonce,
+    );
+    Ok((computed == *xchain_hash_id).then_some(withdraw))
+}
+
+fn fixed<const N: usize>(source: &str, field: &str, bytes: Option<&[u8]>) -> Result<[u8; N]> {
+    bytes
+        .and_then(|b| b.try_into().ok())
+        .ok_or_else(|| eyre!("{} has a malformed {}", source, field))
+}
+
+/// `0x`-prefixed 20- or 32-byte hex, left-padded to 32 bytes
+fn hex_bytes32(source: &str, field: &str, value: Option<&str>) -> Result<[u8; 32]> {
+    let raw = value
+        .and_then(|v| hex::decode(v.trim_start_matches("0x")).ok())
+        .filter(|b| b.len() == 20 || b.len() == 32)
+        .ok_or_else(|| eyre!("{} has a malformed {}", source, field))?;
+    let mut out = [0u8; 32];
+    out[32 - raw.len()..].copy_from_slice(&raw);
+    Ok(out)
+}
+
+fn parse_amount(amount: &str) -> Result<u128> {
+    amount
+        .parse()
+        .map_err(|_| eyre!("Invalid amount: {}", amount))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(fee_bps: u32) -> RelayConfig {
+        RelayConfig {
+            enabled: true,
+            fee_bps,
+            max_attempts: DEFAULT_RELAY_MAX_ATTEMPTS,
+        }
+    }
+
+    #[test]
+    fn test_relay_fee_within_limits() {
+        assert_eq!(config(30).relay_fee(1_000_000, 5_000, 100), Ok(3_000));
+    }
+
+    #[test]
+    fn test_relay_fee_declined_above_depositor_max() {
+        let err = config(30).relay_fee(1_000_000, 2_999, 100).unwrap_err();
+        assert!(err.contains("depositor's maximum"), "{}", err);
+    }
+
+    #[test]
+    fn test_hex_bytes32_left_pads_addresses() {
+        let padded =
+            hex_bytes32("d", "f", Some("0x1111111111111111111111111111111111111111")).unwrap();
+        assert_eq!(padded[..12], [0u8; 12]);
+        assert_eq!(padded[12..], [0x11; 20]);
+        let full = format!("0x{}", "22".repeat(32));
+        assert_eq!(hex_bytes32("d", "f", Some(&full)).unwrap(), [0x22; 32]);
+        assert!(hex_bytes32("d", "f", Some("0x1234")).is_err());
+        assert!(hex_bytes32("d", "f", None).is_err());
+    }
+
+    #[test]
+    fn test_relay_fee_declined_above_bridge_cap() {
+        let err = config(150)
+            .relay_fee(1_000_000, 1_000_000, 100)
+            .unwrap_err();
+        assert!(err.contains("bridge cap"), "{}", err);
+        let err = config(30).relay_fee(1_000_000, 1_000_000, 0).unwrap_err();
+        assert!(err.contains("disabled"), "{}", err);
+    }
+}
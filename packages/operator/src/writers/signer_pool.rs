@@ -0,0 +1,437 @@
+//! Signer pools: several authorised operator keys per chain
+//!
+//! With a single key every approval waits behind the one before it: its nonce on EVM, its
+//! account sequence on Terra. Each writer therefore holds a [`SignerPool`] of keys that are all
+//! registered as operators (`AddOperator` on Terra, the operator role in the EVM AccessManager)
+//! and leases one key per transaction. The Solana program authorises a single
+//! `BridgeConfig.operator`, so its pool only ever holds `SOLANA_PRIVATE_KEY`. A lease goes to
+//! the active key with the fewest transactions in flight, then the highest balance, so
+//! concurrent submissions never share a key.
+//!
+//! Balances are refreshed every `OPERATOR_SIGNER_BALANCE_REFRESH_SECS`. A key whose balance
+//! drops below the chain's minimum, or whose transaction fails for lack of gas, is retired
+//! until a later refresh sees it topped up again. Keys below the low-balance threshold are
+//! reported through the `relayer_signer_low_balance` gauge.
+//!
+//! Configuration (all optional; balances in the chain's base unit):
+//!
+//! - `EVM_EXTRA_PRIVATE_KEYS`, `TERRA_EXTRA_MNEMONICS` — comma-separated keys added to the
+//!   pool next to the primary key
+//! - `EVM_SIGNER_MIN_BALANCE_WEI` / `EVM_SIGNER_LOW_BALANCE_WEI` (default 0.001 / 0.05 native)
+//! - `TERRA_SIGNER_MIN_BALANCE_ULUNA` / `TERRA_SIGNER_LOW_BALANCE_ULUNA` (default 10 / 200 LUNC)
+//! - `SOLANA_SIGNER_MIN_BALANCE_LAMPORTS` / `SOLANA_SIGNER_LOW_BALANCE_LAMPORTS`
+//!   (default 0.005 / 0.1 SOL)
+//! - `OPERATOR_SIGNER_BALANCE_REFRESH_SECS` — seconds between balance refreshes (default 60)
+
+use std::future::Future;
+use std::ops::Deref;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use eyre::{eyre, Result};
+use tracing::{error, info, warn};
+
+const DEFAULT_BALANCE_REFRESH_SECS: u64 = 60;
+
+pub const DEFAULT_EVM_MIN_BALANCE_WEI: u128 = 1_000_000_000_000_000;
+pub const DEFAULT_EVM_LOW_BALANCE_WEI: u128 = 50_000_000_000_000_000;
+pub const DEFAULT_TERRA_MIN_BALANCE_ULUNA: u128 = 10_000_000;
+pub const DEFAULT_TERRA_LOW_BALANCE_ULUNA: u128 = 200_000_000;
+pub const DEFAULT_SOLANA_MIN_BALANCE_LAMPORTS: u128 = 5_000_000;
+pub const DEFAULT_SOLANA_LOW_BALANCE_LAMPORTS: u128 = 100_000_000;
+
+/// Balance limits for one chain's signer pool
+#[derive(Debug, Clone)]
+pub struct BalanceThresholds {
+    /// Keys below this balance are retired
+    pub min: u128,
+    /// Keys below this balance raise the low-balance alert
+    pub low: u128,
+    /// How often balances are re-read
+    pub refresh: Duration,
+}
+
+impl BalanceThresholds {
+    /// Read `min_var` / `low_var`, falling back to the given defaults
+    pub fn from_env(
+        min_var: &str,
+        low_var: &str,
+        default_min: u128,
+        default_low: u128,
+    ) -> Result<Self> {
+        let read = |var: &str, default: u128| -> Result<u128> {
+            match std::env::var(var) {
+                Ok(v) => v
+                    .parse()
+                    .map_err(|_| eyre!("{} must be a non-negative integer, got {:?}", var, v)),
+                Err(_) => Ok(default),
+            }
+        };
+        let min = read(min_var, default_min)?;
+        let low = read(low_var, default_low)?;
+        if low < min {
+            return Err(eyre!(
+                "{} ({}) must not be below {} ({})",
+                low_var,
+                low,
+                min_var,
+                min
+            ));
+        }
+        let refresh_secs = match std::env::var("OPERATOR_SIGNER_BALANCE_REFRESH_SECS") {
+            Ok(v) => v.parse().ok().filter(|n| *n > 0).ok_or_else(|| {
+                eyre!(
+                    "OPERATOR_SIGNER_BALANCE_REFRESH_SECS must be positive, got {:?}",
+                    v
+                )
+            })?,
+            Err(_) => DEFAULT_BALANCE_REFRESH_SECS,
+        };
+        Ok(Self {
+            min,
+            low,
+            refresh: Duration::from_secs(refresh_secs),
+        })
+    }
+}
+
+/// Whether a transaction error means the paying key could not cover gas or fees
+pub fn is_out_of_gas_error(error: &str) -> bool {
+    let error = error.to_lowercase();
+    error.contains("insufficient funds")
+        || error.contains("insufficient lamports")
+        || error.contains("found no record of a prior credit")
+}
+
+#[derive(Debug, Default)]
+struct SignerState {
+    balance: Option<u128>,
+    in_flight: usize,
+    retired: bool,
+}
+
+/// Operator keys for one chain, leased one transaction at a time
+pub struct SignerPool<S> {
+    /// Metrics and log label (`evm_56`, `terra`, `solana`)
+    chain: String,
+    thresholds: BalanceThresholds,
+    signers: Vec<(S, String)>,
+    state: Mutex<Vec<SignerState>>,
+    last_refresh: Mutex<Option<Instant>>,
+}
+
+impl<S> SignerPool<S> {
+    /// Pool of `(signer, address)` pairs; the first one is the primary key
+    pub fn new(
+        chain: impl Into<String>,
+        signers: Vec<(S, String)>,
+        thresholds: BalanceThresholds,
+    ) -> Result<Self> {
+        let chain = chain.into();
+        if signers.is_empty() {
+            return Err(eyre!("{} signer pool needs at least one key", chain));
+        }
+        for (i, (_, address)) in signers.iter().enumerate() {
+            if signers[..i].iter().any(|(_, a)| a == address) {
+                return Err(eyre!(
+                    "{} signer pool lists {} more than once",
+                    chain,
+                    address
+                ));
+            }
+            crate::metrics::set_signer_retired(&chain, address, false);
+        }
+        let state = signers.iter().map(|_| SignerState::default()).collect();
+        Ok(Self {
+            chain,
+            thresholds,
+            signers,
+            state: Mutex::new(state),
+            last_refresh: Mutex::new(None),
+        })
+    }
+
+    /// The primary key (first configured)
+    pub fn primary(&self) -> &S {
+        &self.signers[0].0
+    }
+
+    /// Addresses of every key, retired or not
+    pub fn addresses(&self) -> Vec<&str> {
+        self.signers.iter().map(|(_, a)| a.as_str()).collect()
+    }
+
+    /// Number of keys not retired
+    pub fn active(&self) -> usize {
+        self.state
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|s| !s.retired)
+            .count()
+    }
+
+    /// Lease the active key with the fewest transactions in flight, then the highest balance
+    /// (keys whose balance is not known yet come last)
+    pub fn acquire(&self) -> Result<SignerLease<'_, S>> {
+        let mut state = self.state.lock().unwrap();
+        let index = state
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| !s.retired)
+            .min_by_key(|(_, s)| (s.in_flight, std::cmp::Reverse(s.balance)))
+            .map(|(i, _)| i)
+            .ok_or_else(|| {
+                eyre!(
+                    "All {} {} signer keys are retired (out of gas); top them up",
+                    self.signers.len(),
+                    self.chain
+                )
+            })?;
+        state[index].in_flight += 1;
+        Ok(SignerLease { pool: self, index })
+    }
+
+    /// Record a fresh balance: retire keys below the minimum, reinstate topped-up ones and
+    /// raise the low-balance alert
+    pub fn record_balance(&self, index: usize, balance: u128) {
+        let address = &self.signers[index].1;
+        let mut state = self.state.lock().unwrap();
+        let s = &mut state[index];
+        s.balance = Some(balance);
+
+        let below_min = balance < self.thresholds.min;
+        if below_min && !s.retired {
+            s.retired = true;
+            error!(
+                chain = %self.chain,
+                address = %address,
+                balance = %balance,
+                min_balance = %self.thresholds.min,
+                "Signer balance below minimum, retiring key"
+            );
+        } else if !below_min && s.retired {
+            s.retired = false;
+            info!(
+                chain = %self.chain,
+                address = %address,
+                balance = %balance,
+                "Signer topped up, returning key to the pool"
+            );
+        }
+
+        let low = balance < self.thresholds.low;
+        if low {
+            warn!(
+                chain = %self.chain,
+                address = %address,
+                balance = %balance,
+                low_balance = %self.thresholds.low,
+                "Signer balance low"
+            );
+        }
+        crate::metrics::set_signer_balance(&self.chain, address, balance as f64);
+        crate::metrics::set_signer_low_balance(&self.chain, address, low);
+        crate::metrics::set_signer_retired(&self.chain, address, s.retired);
+    }
+
+    /// Retire a key whose transaction failed for lack of gas
+    fn retire(&self, index: usize, reason: &str) {
+        let address = &self.signers[index].1;
+        let mut state = self.state.lock().unwrap();
+        if !state[index].retired {
+            state[index].retired = true;
+            error!(
+                chain = %self.chain,
+                address = %address,
+                reason = %reason,
+                "Signer ran out of gas, retiring key"
+            );
+            crate::metrics::set_signer_retired(&self.chain, address, true);
+        }
+    }
+
+    /// Re-read every key's balance with `fetch` once the refresh interval has passed.
+    /// A failed read keeps the previous balance.
+    pub async fn refresh_balances<F, Fut>(&self, fetch: F)
+    where
+        F: Fn(&S) -> Fut,
+        Fut: Future<Output = Result<u128>>,
+    {
+        {
+            let mut last = self.last_refresh.lock().unwrap();
+            if last.is_some_and(|t| t.elapsed() < self.thresholds.refresh) {
+                return;
+            }
+            *last = Some(Instant::now());
+        }
+        let balances =
+            futures::future::join_all(self.signers.iter().map(|(signer, _)| fetch(signer))).await;
+        for (index, balance) in balances.into_iter().enumerate() {
+            match balance {
+                Ok(balance) => self.record_balance(index, balance),
+                Err(e) => warn!(
+                    chain = %self.chain,
+                    address = %self.signers[index].1,
+                    error = %e,
+                    "Failed to read signer balance"
+                ),
+            }
+        }
+    }
+}
+
+/// A key leased from a [`SignerPool`] for one transaction; released on drop
+pub struct SignerLease<'a, S> {
+    pool: &'a SignerPool<S>,
+    index: usize,
+}
+
+impl<S> SignerLease<'_, S> {
+    pub fn address(&self) -> &str {
+        &self.pool.signers[self.index].1
+    }
+
+    /// Pass a transaction error through, retiring this key when it ran out of gas
+    pub fn observe_error(&self, error: eyre::Report) -> eyre::Report {
+        let message = format!("{:#}", error);
+        if is_out_of_gas_error(&message) {
+            self.pool.retire(self.index, &message);
+        }
+        error
+    }
+}
+
+impl<S> Deref for SignerLease<'_, S> {
+    type Target = S;
+
+    fn deref(&self) -> &S {
+        &self.pool.signers[self.index].0
+    }
+}
+
+impl<S> Drop for SignerLease<'_, S> {
+    fn drop(&mut self) {
+        self.pool.state.lock().unwrap()[self.index].in_flight -= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pool(n: usize) -> SignerPool<usize> {
+        let thresholds = BalanceThresholds {
+            min: 10,
+            low: 100,
+            refresh: Duration::from_secs(60),
+        };
+        SignerPool::new(
+            "test",
+            (0..n).map(|i| (i, format!("addr{}", i))).collect(),
+            thresholds,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_concurrent_leases_use_distinct_keys() {
+        let pool = pool(3);
+        let a = pool.acquire().unwrap();
+        let b = pool.acquire().unwrap();
+        let c = pool.acquire().unwrap();
+        let mut used = vec![*a, *b, *c];
+        used.sort();
+        assert_eq!(used, vec![0, 1, 2]);
+
+        // Released keys are handed out again
+        drop(b);
+        assert_eq!(*pool.acquire().unwrap(), used[1]);
+    }
+
+    #[test]
+    fn test_prefers_highest_balance() {
+        let pool = pool(3);
+        pool.record_balance(0, 500);
+        pool.record_balance(1, 2_000);
+        pool.record_balance(2, 1_000);
+        assert_eq!(*pool.acquire().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_low_balance_retires_and_top_up_reinstates() {
+        let pool = pool(2);
+        pool.record_balance(0, 5);
+        pool.record_balance(1, 50);
+        assert_eq!(pool.active(), 1);
+        for _ in 0..3 {
+            assert_eq!(*pool.acquire().unwrap(), 1);
+        }
+
+        pool.record_balance(0, 1_000);
+        assert_eq!(pool.active(), 2);
+        assert_eq!(*pool.acquire().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_out_of_gas_error_retires_key() {
+        let pool = pool(2);
+        let lease = pool.acquire().unwrap();
+        let retired = *lease;
+        let err = lease.observe_error(eyre!(
+            "Failed to send withdrawApprove tx: insufficient funds for gas * price + value"
+        ));
+        assert!(err.to_string().contains("insufficient funds"));
+        drop(lease);
+        assert_eq!(pool.active(), 1);
+        assert_ne!(*pool.acquire().unwrap(), retired);
+
+        // Other failures leave the key in the pool
+        let lease = pool.acquire().unwrap();
+        let _ = lease.observe_error(eyre!("execution reverted"));
+        drop(lease);
+        assert_eq!(pool.active(), 1);
+    }
+
+    #[test]
+    fn test_all_retired_is_an_error() {
+        let pool = pool(1);
+        pool.record_balance(0, 0);
+        let err = pool.acquire().err().unwrap();
+        assert!(err.to_string().contains("retired"));
+    }
+
+    #[test]
+    fn test_rejects_empty_and_duplicate_keys() {
+        let thresholds = BalanceThresholds {
+            min: 0,
+            low: 0,
+            refresh: Duration::from_secs(60),
+        };
+        assert!(SignerPool::<u8>::new("test", vec![], thresholds.clone()).is_err());
+        assert!(SignerPool::new(
+            "test",
+            vec![(1u8, "a".to_string()), (2u8, "a".to_string())],
+            thresholds
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_out_of_gas_classification() {
+        assert!(is_out_of_gas_error(
+            "insufficient funds for gas * price + value: have 0 want 1"
+        ));
+        assert!(is_out_of_gas_error(
+            "broadcast failed: code 5: 100uluna is smaller than 200uluna: insufficient funds"
+        ));
+        assert!(is_out_of_gas_error(
+            "Attempt to debit an account but found no record of a prior credit."
+        ));
+        assert!(is_out_of_gas_error(
+            "Transfer: insufficient lamports 10, need 20"
+        ));
+        assert!(!is_out_of_gas_error("nonce too low"));
+        assert!(!is_out_of_gas_error("execution reverted: not operator"));
+    }
+}
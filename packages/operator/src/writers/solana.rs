@@ -12,19 +12,52 @@
 //! After a successful approve, matching `evm_deposits` / `terra_deposits` rows (if any) are marked
 //! `processed` so DB metrics stay aligned with the EVM/Terra watchers.
 //!
+//! Each approval is also scheduled in `withdraw_executions`; once the effective withdraw delay
+//! has passed the writer submits `withdraw_execute_by_operator` / `withdraw_execute_native_by_operator`
+//! so recipients do not have to execute themselves (see [`super::execution`]).
+//!
+//! **Operator submit:** recipients without SOL cannot pay for `withdraw_submit`. When an EVM or
+//! Terra deposit to Solana is still `pending` in Postgres `SOLANA_OPERATOR_SUBMIT_AFTER_SECS`
+//! after it was indexed and no `PendingWithdraw` exists, the writer reads the deposit back from
+//! the source bridge, checks it hashes to the indexed transfer hash, and submits
+//! `withdraw_submit_and_approve` itself. The operator pays the `PendingWithdraw` rent and is repaid
+//! per `SOLANA_OPERATOR_SUBMIT_RENT_SOURCE`: on execute (`withdrawal`, the default) or from the
+//! program's rent sponsor vault (`sponsor`). When the recipient executed first, the execution
+//! pass closes the leftover `WithdrawSponsorship` to reclaim the rent parked there.
+//!
+//! **Relayed withdrawals:** with relaying enabled, deposits whose depositor requested a relay
+//! (see [`super::relay`]) are submitted the same way right after they are indexed, with a
+//! `relay_fee` the program pays to the operator on execution. Relays always use the
+//! `withdrawal` rent source, which the program requires for a non-zero fee.
+//!
+//! Transactions are paid and signed by `SOLANA_PRIVATE_KEY`, the program's single
+//! `BridgeConfig.operator`. It sits in a one-key [`SignerPool`] for balance tracking: the
+//! low-balance alert fires before it runs dry, and it is retired (sending stops) once it has.
+//!
 //! Outbound Solana deposits (`solana_deposits` from the Solana watcher) are **not** handled here —
 //! approvals for those occur on destination chains (EVM/Terra), not on Solana.
 
 #![allow(clippy::result_large_err)] // Solana ClientError in RpcClient callbacks
 
 use std::collections::HashMap;
+use std::sync::Arc;
 
 use alloy::primitives::{Address, FixedBytes, U256};
 use alloy::providers::ProviderBuilder;
 use base64::{engine::general_purpose::STANDARD as B64, Engine};
 use eyre::Result;
-use multichain_rs::hash::{encode_terra_address_to_bytes32, keccak256};
-use multichain_rs::solana::run_with_solana_rpc_fallback;
+use multichain_rs::hash::{compute_xchain_hash_id, encode_terra_address_to_bytes32, keccak256};
+use multichain_rs::solana::{
+    associated_token_address, bridge_config_pda, chain_entry_pda, chain_withdraw_delay_pda,
+    effective_withdraw_delay, executed_hash_pda, large_transfer_delay_pda, nonce_bitmap_pda,
+    parse_bridge_config, parse_chain_withdraw_delay, parse_large_transfer_delay,
+    parse_pending_withdraw, parse_relay_config, parse_token_mapping, pending_withdraw_pda,
+    relay_config_pda, rent_sponsor_pda, run_with_solana_rpc_fallback, withdraw_sponsorship_pda,
+    SolanaPendingWithdraw, SolanaTokenMapping, ASSOCIATED_TOKEN_PROGRAM_ID,
+    TOKEN_MAPPING_ACCOUNT_LEN, TOKEN_MAPPING_DEST_CHAIN_OFFSET, TOKEN_MAPPING_LOCAL_MINT_OFFSET,
+};
+use multichain_rs::token_cache::TokenCache;
+use multichain_rs::types::ChainId;
 use solana_account_decoder_client_types::UiAccountEncoding;
 use solana_client::client_error::{ClientError, ClientErrorKind};
 use solana_client::rpc_client::RpcClient;
@@ -44,6 +77,12 @@ use std::time::Duration;
 use tracing::{debug, error, info, warn};
 
 use crate::contracts::evm_bridge::Bridge;
+use crate::db;
+use crate::db::UnsubmittedSolanaDeposit;
+use crate::policy::{PolicyEngine, TransferFacts};
+
+use super::execution::{ExecutionConfig, ExecutionOutcome};
+use super::signer_pool::{BalanceThresholds, SignerPool};
 
 /// 32-byte Solana `TokenMapping` / `withdraw_submit` seed for a Terra local token id (CosmWasm `encode_token_address`).
 fn terra_local_token_id_to_mapping_key_bytes(token: &str) -> Option<[u8; 32]> {
@@ -62,6 +101,10 @@ fn terra_local_token_id_to_mapping_key_bytes(token: &str) -> Option<[u8; 32]> {
 struct ParsedOnChainPendingWithdraw {
     transfer_hash: [u8; 32],
     src_chain: [u8; 4],
+    src_account: [u8; 32],
+    dest_account: [u8; 32],
+    /// Local SPL mint
+    token: [u8; 32],
     amount: u128,
     nonce: u64,
     approved: bool,
@@ -84,6 +127,9 @@ fn parse_pending_withdraw_account(data: &[u8]) -> Option<ParsedOnChainPendingWit
     transfer_hash.copy_from_slice(&b[0..32]);
     let mut src_chain = [0u8; 4];
     src_chain.copy_from_slice(&b[32..36]);
+    let src_account: [u8; 32] = b[36..68].try_into().ok()?;
+    let dest_account: [u8; 32] = b[68..100].try_into().ok()?;
+    let token: [u8; 32] = b[100..132].try_into().ok()?;
     let amount = u128::from_le_bytes(b[132..148].try_into().ok()?);
     let nonce = u64::from_le_bytes(b[148..156].try_into().ok()?);
     let approved = b[166] != 0;
@@ -92,6 +138,9 @@ fn parse_pending_withdraw_account(data: &[u8]) -> Option<ParsedOnChainPendingWit
     Some(ParsedOnChainPendingWithdraw {
         transfer_hash,
         src_chain,
+        src_account,
+        dest_account,
+        token,
         amount,
         nonce,
         approved,
@@ -100,11 +149,78 @@ fn parse_pending_withdraw_account(data: &[u8]) -> Option<ParsedOnChainPendingWit
     })
 }
 
+/// Default wait for the recipient's own `withdraw_submit` before the operator submits
+const DEFAULT_OPERATOR_SUBMIT_AFTER_SECS: i64 = 900;
+
+/// Who repays the operator for `PendingWithdraw` rent (program `RentSource`, Borsh variant order)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RentSource {
+    Sponsor = 0,
+    Withdrawal = 1,
+}
+
+/// Settings for `withdraw_submit_and_approve` on deposits no recipient submitted
+#[derive(Debug, Clone, Copy)]
+struct OperatorSubmitConfig {
+    /// Seconds a deposit waits for the recipient's `withdraw_submit`; `None` disables
+    after_secs: Option<i64>,
+    rent_source: RentSource,
+}
+
+impl OperatorSubmitConfig {
+    fn from_env() -> Result<Self> {
+        let after_secs = match std::env::var("SOLANA_OPERATOR_SUBMIT_AFTER_SECS") {
+            Ok(v) => match v.parse::<i64>() {
+                Ok(0) => None,
+                Ok(n) if n > 0 => Some(n),
+                _ => {
+                    return Err(eyre::eyre!(
+                    "SOLANA_OPERATOR_SUBMIT_AFTER_SECS must be a non-negative integer, got {:?}",
+                    v
+                ))
+                }
+            },
+            Err(_) => Some(DEFAULT_OPERATOR_SUBMIT_AFTER_SECS),
+        };
+        let rent_source = match std::env::var("SOLANA_OPERATOR_SUBMIT_RENT_SOURCE") {
+            Ok(v) => match v.to_lowercase().as_str() {
+                "withdrawal" => RentSource::Withdrawal,
+                "sponsor" => RentSource::Sponsor,
+                _ => {
+                    return Err(eyre::eyre!(
+                        "SOLANA_OPERATOR_SUBMIT_RENT_SOURCE must be 'withdrawal' or 'sponsor', got {:?}",
+                        v
+                    ))
+                }
+            },
+            Err(_) => RentSource::Withdrawal,
+        };
+        Ok(Self {
+            after_secs,
+            rent_source,
+        })
+    }
+}
+
+/// Withdraw fields for `withdraw_submit_and_approve`, read from the source bridge's deposit record
+#[derive(Debug, Clone)]
+struct SourceDeposit {
+    transfer_hash: [u8; 32],
+    src_chain: [u8; 4],
+    src_account: [u8; 32],
+    dest_account: [u8; 32],
+    /// Local mint (all zero for native SOL)
+    dest_token: [u8; 32],
+    amount: u128,
+    nonce: u64,
+}
+
 pub struct SolanaWriter {
     rpc_clients: Vec<RpcClient>,
     http: reqwest::Client,
     program_id: Pubkey,
-    keypair: Keypair,
+    /// The operator key, leased by every transaction as fee payer and signer
+    signers: SignerPool<Keypair>,
     db: PgPool,
     poll_interval: Duration,
     /// Source chain endpoints for EVM deposit verification, keyed by V2 4-byte chain ID
@@ -113,6 +229,16 @@ pub struct SolanaWriter {
     terra_lcd: Option<(String, String)>,
     /// Configured SVM V2 chain IDs (from `SOLANA_V2_CHAIN_IDS`) — logged at startup for ops visibility.
     configured_solana_v2_chain_ids: Vec<[u8; 4]>,
+    /// Deposit policy consulted before every approval
+    policy: Arc<PolicyEngine>,
+    /// Auto-execution retry settings
+    execution: ExecutionConfig,
+    /// Operator submit for deposits no recipient submitted
+    operator_submit: OperatorSubmitConfig,
+    /// Shared token metadata, consulted before querying `TokenMapping` PDAs
+    token_cache: Option<Arc<TokenCache>>,
+    /// Gasless withdrawal relay settings
+    relay: super::RelayConfig,
 }
 
 impl SolanaWriter {
@@ -127,6 +253,7 @@ impl SolanaWriter {
         terra_bridge_address: Option<String>,
         solana_v2_chain_ids: Vec<[u8; 4]>,
         poll_interval_ms: u64,
+        policy: Arc<PolicyEngine>,
     ) -> Result<Self> {
         if solana_v2_chain_ids.is_empty() {
             return Err(eyre::eyre!("solana_v2_chain_ids must be non-empty"));
@@ -144,25 +271,67 @@ impl SolanaWriter {
             }
             _ => None,
         };
+        let address = keypair.pubkey().to_string();
+        let signers = SignerPool::new(
+            "solana",
+            vec![(keypair, address)],
+            BalanceThresholds::from_env(
+                "SOLANA_SIGNER_MIN_BALANCE_LAMPORTS",
+                "SOLANA_SIGNER_LOW_BALANCE_LAMPORTS",
+                super::signer_pool::DEFAULT_SOLANA_MIN_BALANCE_LAMPORTS,
+                super::signer_pool::DEFAULT_SOLANA_LOW_BALANCE_LAMPORTS,
+            )?,
+        )?;
         Ok(Self {
             rpc_clients,
             http: reqwest::Client::new(),
             program_id,
-            keypair,
+            signers,
             db,
             poll_interval: Duration::from_millis(poll_interval_ms.max(1)),
             source_chain_endpoints,
             terra_lcd,
             configured_solana_v2_chain_ids: solana_v2_chain_ids,
+            policy,
+            execution: ExecutionConfig::from_env()?,
+            operator_submit: OperatorSubmitConfig::from_env()?,
+            token_cache: None,
+            relay: super::RelayConfig::from_env()?,
+        })
+    }
+
+    /// Answer `TokenMapping` lookups from `cache` when it has a fresh entry
+    pub fn with_token_cache(mut self, cache: Arc<TokenCache>) -> Self {
+        self.token_cache = Some(cache);
+        self
+    }
+
+    /// Whether the token cache has a fresh `TokenMapping` for `(src_chain, src_token)` on
+    /// any configured Solana chain. A miss proves nothing: the registry may have changed since.
+    fn cached_token_mapping(&self, src_chain: [u8; 4], src_token: &[u8; 32]) -> bool {
+        let Some(cache) = &self.token_cache else {
+            return false;
+        };
+        self.configured_solana_v2_chain_ids.iter().any(|id| {
+            let chain = ChainId::from_bytes(*id);
+            cache.is_fresh(chain)
+                && cache
+                    .get_by_remote(chain, ChainId::from_bytes(src_chain), src_token)
+                    .is_some()
         })
     }
 
-    pub async fn run(self) -> Result<()> {
+    /// Poll, approve and execute while `leader` holds the operator lease
+    pub async fn run(self, leader: crate::leader::LeaderHandle) -> Result<()> {
         info!(
             program_id = %self.program_id,
-            operator = %self.keypair.pubkey(),
+            operator = %self.signers.primary().pubkey(),
+            signer_keys = ?self.signers.addresses(),
             evm_source_chains = self.source_chain_endpoints.len(),
             terra_verify = self.terra_lcd.is_some(),
+            operator_submit_after_secs = ?self.operator_submit.after_secs,
+            operator_submit_rent_source = ?self.operator_submit.rent_source,
+            relay_enabled = self.relay.enabled,
             configured_solana_v2_chain_ids = ?self
                 .configured_solana_v2_chain_ids
                 .iter()
@@ -172,16 +341,52 @@ impl SolanaWriter {
         );
 
         loop {
-            match self.process_pending_approvals().await {
-                Ok(()) => {}
-                Err(e) => {
-                    error!(error = %e, "Error processing Solana approvals");
+            if leader.is_leader() {
+                // A cycle can outlive the lease TTL, so drop it as soon as the lease is lost
+                tokio::select! {
+                    _ = self.process_cycle() => {}
+                    _ = leader.lost() => {
+                        warn!("Operator lease lost mid-cycle, abandoning in-flight Solana submissions");
+                    }
                 }
             }
             tokio::time::sleep(self.poll_interval).await;
         }
     }
 
+    async fn process_cycle(&self) {
+        self.refresh_signer_balances().await;
+        if let Err(e) = self.process_pending_approvals().await {
+            error!(error = %e, "Error processing Solana approvals");
+        }
+        if self.relay.enabled {
+            if let Err(e) = self.process_relay_requests().await {
+                error!(error = %e, "Error submitting relayed Solana withdrawals");
+            }
+        }
+        if let Err(e) = self.process_unsubmitted_deposits().await {
+            error!(error = %e, "Error submitting Solana withdrawals for recipients");
+        }
+        if let Err(e) = self.process_pending_executions().await {
+            error!(error = %e, "Error processing Solana executions");
+        }
+    }
+
+    /// Re-read signer lamport balances when due (retires keys that ran dry, alerts on low ones)
+    async fn refresh_signer_balances(&self) {
+        self.signers
+            .refresh_balances(|keypair| {
+                let pubkey = keypair.pubkey();
+                let balance = run_with_solana_rpc_fallback(&self.rpc_clients, |client| {
+                    client.get_balance(&pubkey)
+                })
+                .map(u128::from)
+                .map_err(|e| eyre::eyre!("getBalance failed: {}", e));
+                async move { balance }
+            })
+            .await;
+    }
+
     /// Scans on-chain `PendingWithdraw` PDAs; always emits one INFO summary per poll (parity with EVM / Terra watchers).
     async fn process_pending_approvals(&self) -> Result<()> {
         const PENDING_WITHDRAW_DATA_LEN: u64 = 186;
@@ -287,8 +492,23 @@ impl SolanaWriter {
                 }
             }
 
+            let facts = TransferFacts {
+                xchain_hash_id: pending.transfer_hash,
+                src_chain: pending.src_chain,
+                dest_chain: self.configured_solana_v2_chain_ids[0],
+                src_account: pending.src_account,
+                dest_account: pending.dest_account,
+                token: Pubkey::new_from_array(pending.token).to_string(),
+                amount: pending.amount,
+                nonce,
+            };
+            if !self.policy.permits(&facts).await {
+                continue;
+            }
+
             match self.submit_approval(&pending.transfer_hash).await {
                 Ok(sig) => {
+                    self.schedule_execution(&pending.transfer_hash).await;
                     if let Err(e) = self
                         .mark_deposits_processed_for_hash(&pending.transfer_hash)
                         .await
@@ -419,15 +639,11 @@ impl SolanaWriter {
             &[b"token", pending.src_chain.as_ref(), mapping_key.as_ref()],
             &self.program_id,
         );
-        let mut exists = false;
-        for c in &self.rpc_clients {
-            if let Ok(acc) = c.get_account(&pda) {
-                if !acc.data.is_empty() {
-                    exists = true;
-                    break;
-                }
-            }
-        }
+        let exists = self.cached_token_mapping(pending.src_chain, &mapping_key)
+            || self
+                .rpc_clients
+                .iter()
+                .any(|c| c.get_account(&pda).is_ok_and(|acc| !acc.data.is_empty()));
         if !exists {
             warn!(
                 hash = %transfer_hash_hex,
@@ -614,7 +830,8 @@ impl SolanaWriter {
         Ok(false)
     }
 
-    /// `withdraw_approve` with `NonceUsed` init (Anchor account order).
+    /// `withdraw_approve`: records the nonce in its `NonceBitmap` chunk; the legacy `NonceUsed`
+    /// PDA is passed only to prove it does not exist (Anchor account order).
     async fn submit_approval(
         &self,
         transfer_hash: &[u8; 32],
@@ -622,6 +839,7 @@ impl SolanaWriter {
         let bridge_pda = Pubkey::find_program_address(&[b"bridge"], &self.program_id).0;
         let pending_withdraw_pda =
             Pubkey::find_program_address(&[b"withdraw", transfer_hash], &self.program_id).0;
+        let signer = self.signers.acquire()?;
 
         run_with_solana_rpc_fallback(&self.rpc_clients, |client| {
             let pending = client.get_account(&pending_withdraw_pda)?;
@@ -641,6 +859,7 @@ impl SolanaWriter {
                 &[b"nonce_used", src_chain.as_ref(), &nonce.to_le_bytes()],
                 &self.program_id,
             );
+            let nonce_bitmap_pda = nonce_bitmap_pda(&self.program_id, &src_chain, nonce);
 
             let mut ix_data = Vec::with_capacity(8 + 32);
             ix_data.extend_from_slice(&anchor_discriminator("global:withdraw_approve"));
@@ -651,8 +870,9 @@ impl SolanaWriter {
                 accounts: vec![
                     AccountMeta::new(bridge_pda, false),
                     AccountMeta::new(pending_withdraw_pda, false),
-                    AccountMeta::new(nonce_used_pda, false),
-                    AccountMeta::new(self.keypair.pubkey(), true),
+                    AccountMeta::new_readonly(nonce_used_pda, false),
+                    AccountMeta::new(nonce_bitmap_pda, false),
+                    AccountMeta::new(signer.pubkey(), true),
                     AccountMeta::new_readonly(system_program::id(), false),
                 ],
                 data: ix_data,
@@ -661,14 +881,921 @@ impl SolanaWriter {
             let recent_blockhash = client.get_latest_blockhash()?;
             let tx = Transaction::new_signed_with_payer(
                 &[instruction],
-                Some(&self.keypair.pubkey()),
-                &[&self.keypair],
+                Some(&signer.pubkey()),
+                &[&*signer],
                 recent_blockhash,
             );
 
             client.send_and_confirm_transaction(&tx)
         })
-        .map_err(|e| eyre::eyre!("Failed to submit Solana withdraw_approve: {}", e))
+        .map_err(|e| {
+            signer.observe_error(eyre::eyre!(
+                "Failed to submit Solana withdraw_approve from {}: {}",
+                signer.address(),
+                e
+            ))
+        })
+    }
+
+    /// Submit `withdraw_submit_and_approve` for EVM/Terra deposits to Solana that nobody
+    /// submitted within `SOLANA_OPERATOR_SUBMIT_AFTER_SECS`
+    async fn process_unsubmitted_deposits(&self) -> Result<()> {
+        const MAX_OPERATOR_SUBMITS_PER_TICK: i64 = 10;
+
+        let Some(after_secs) = self.operator_submit.after_secs else {
+            return Ok(());
+        };
+        let rows = db::get_unsubmitted_solana_deposits(
+            &self.db,
+            &self.configured_solana_v2_chain_ids,
+            after_secs,
+            MAX_OPERATOR_SUBMITS_PER_TICK,
+        )
+        .await?;
+
+        for row in rows {
+            let Ok(transfer_hash) = <[u8; 32]>::try_from(row.transfer_hash.as_slice()) else {
+                continue;
+            };
+            let hash_hex = hex::encode(transfer_hash);
+
+            // A user submit (or an earlier operator submit) leaves the PDAs behind; those
+            // go through `process_pending_approvals` / `process_pending_executions`
+            let keys = [
+                pending_withdraw_pda(&self.program_id, &transfer_hash),
+                executed_hash_pda(&self.program_id, &transfer_hash),
+            ];
+            let existing = run_with_solana_rpc_fallback(&self.rpc_clients, |client| {
+                client.get_multiple_accounts(&keys)
+            })
+            .map_err(|e| eyre::eyre!("Failed to read PendingWithdraw: {}", e))?;
+            if existing.iter().any(Option::is_some) {
+                continue;
+            }
+
+            let deposit = match self.read_source_deposit(&row, &transfer_hash).await {
+                Ok(Some(d)) => d,
+                Ok(None) => {
+                    crate::metrics::record_solana_operator_submit("skipped");
+                    continue;
+                }
+                Err(e) => {
+                    warn!(
+                        hash = %hash_hex,
+                        source = %row.source,
+                        error = %e,
+                        "Failed to read source deposit for operator submit, will retry"
+                    );
+                    crate::metrics::record_solana_operator_submit("error");
+                    continue;
+                }
+            };
+
+            let facts = TransferFacts {
+                xchain_hash_id: transfer_hash,
+                src_chain: deposit.src_chain,
+                dest_chain: self.dest_chain(),
+                src_account: deposit.src_account,
+                dest_account: deposit.dest_account,
+                token: Pubkey::new_from_array(deposit.dest_token).to_string(),
+                amount: deposit.amount,
+                nonce: deposit.nonce,
+            };
+            if !self.policy.permits(&facts).await {
+                continue;
+            }
+
+            match self.submit_withdraw_and_approve(&deposit, 0) {
+                Ok(sig) => {
+                    crate::metrics::record_solana_operator_submit("submitted");
+                    self.schedule_execution(&transfer_hash).await;
+                    if let Err(e) = self.mark_deposits_processed_for_hash(&transfer_hash).await {
+                        warn!(
+                            hash = %hash_hex,
+                            error = %e,
+                            "Failed to mark matching DB deposits processed (tx already submitted)"
+                        );
+                    }
+                    info!(
+                        nonce = deposit.nonce,
+                        hash = %hash_hex,
+                        tx = %sig,
+                        rent_source = ?self.operator_submit.rent_source,
+                        "Submitted Solana withdraw_submit_and_approve for recipient"
+                    );
+                }
+                Err(e) => {
+                    crate::metrics::record_solana_operator_submit("error");
+                    warn!(
+                        nonce = deposit.nonce,
+                        hash = %hash_hex,
+                        error = %e,
+                        "Failed to submit Solana withdraw_submit_and_approve"
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Submit `withdraw_submit_and_approve` with a relay fee for deposits whose depositor
+    /// asked for a relay to Solana (see [`super::relay`])
+    async fn process_relay_requests(&self) -> Result<()> {
+        let requests = db::claim_pending_relay_requests(
+            &self.db,
+            &self.dest_chain(),
+            crate::leader::instance_id(),
+            crate::leader::fence().as_ref(),
+            crate::leader::CLAIM_TTL_SECS,
+            crate::leader::CLAIM_BATCH_SIZE,
+        )
+        .await?;
+        if requests.is_empty() {
+            return Ok(());
+        }
+
+        // No RelayConfig account means the cap was never set, which disables relaying
+        let relay_config = relay_config_pda(&self.program_id);
+        let accounts = run_with_solana_rpc_fallback(&self.rpc_clients, |client| {
+            client.get_multiple_accounts(&[relay_config])
+        })
+        .map_err(|e| eyre::eyre!("Failed to read RelayConfig: {}", e))?;
+        let cap_bps = match accounts.into_iter().next().flatten() {
+            Some(account) => u64::from(parse_relay_config(&account.data)?),
+            None => 0,
+        };
+
+        info!(count = requests.len(), cap_bps, "Processing relay requests");
+        for request in requests {
+            if let Err(e) = self.relay_withdraw(&request, cap_bps).await {
+                warn!(
+                    request_id = request.id,
+                    hash = %hex::encode(&request.xchain_hash_id),
+                    error = %e,
+                    "Relay submission failed, will retry"
+                );
+                crate::metrics::record_withdraw_relay("error");
+                db::record_relay_request_error(
+                    &self.db,
+                    request.id,
+                    &e.to_string(),
+                    self.relay.max_attempts,
+                )
+                .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Submit the withdrawal for one relay request, or close the request when it should not
+    /// be relayed. Errors are retriable; a policy hold leaves the request pending.
+    async fn relay_withdraw(&self, request: &db::RelayRequest, cap_bps: u64) -> Result<()> {
+        let transfer_hash: [u8; 32] = request
+            .xchain_hash_id
+            .as_slice()
+            .try_into()
+            .map_err(|_| eyre::eyre!("Relay request {} has a malformed hash", request.id))?;
+        let hash_hex = hex::encode(transfer_hash);
+
+        // The recipient (or anyone) may have submitted it in the meantime
+        let keys = [
+            pending_withdraw_pda(&self.program_id, &transfer_hash),
+            executed_hash_pda(&self.program_id, &transfer_hash),
+        ];
+        let existing = run_with_solana_rpc_fallback(&self.rpc_clients, |client| {
+            client.get_multiple_accounts(&keys)
+        })
+        .map_err(|e| eyre::eyre!("Failed to read PendingWithdraw: {}", e))?;
+        if existing.iter().any(Option::is_some) {
+            crate::metrics::record_withdraw_relay("skipped");
+            return db::close_relay_request(&self.db, request.id, "skipped", "already submitted")
+                .await;
+        }
+
+        // Read the deposit back from its source bridge, exactly like an operator submit
+        let row = if let Some(d) =
+            db::get_evm_deposit_by_transfer_hash(&self.db, &transfer_hash).await?
+        {
+            UnsubmittedSolanaDeposit {
+                source: "evm".to_string(),
+                transfer_hash: transfer_hash.to_vec(),
+                src_v2_chain_id: d.src_v2_chain_id,
+                dest_token_address: Some(d.dest_token_address),
+            }
+        } else if db::get_terra_deposit_by_transfer_hash(&self.db, &transfer_hash)
+            .await?
+            .is_some()
+        {
+            UnsubmittedSolanaDeposit {
+                source: "terra".to_string(),
+                transfer_hash: transfer_hash.to_vec(),
+                src_v2_chain_id: None,
+                dest_token_address: None,
+            }
+        } else {
+            return Err(eyre::eyre!(
+                "Source deposit {} is not indexed yet",
+                hash_hex
+            ));
+        };
+        let Some(deposit) = self.read_source_deposit(&row, &transfer_hash).await? else {
+            crate::metrics::record_withdraw_relay("declined");
+            return db::close_relay_request(
+                &self.db,
+                request.id,
+                "declined",
+                "source deposit does not hash to the relay request",
+            )
+            .await;
+        };
+
+        let max_relay_fee: u128 = request
+            .max_relay_fee
+            .parse()
+            .map_err(|_| eyre::eyre!("Invalid max relay fee: {}", request.max_relay_fee))?;
+        let relay_fee = match self.relay.relay_fee(deposit.amount, max_relay_fee, cap_bps) {
+            Ok(fee) => fee,
+            Err(reason) => {
+                info!(hash = %hash_hex, reason = %reason, "Declining relay request");
+                crate::metrics::record_withdraw_relay("declined");
+                return db::close_relay_request(&self.db, request.id, "declined", &reason).await;
+            }
+        };
+
+        // withdraw_submit_and_approve approves at once, so the policy is consulted here
+        let facts = TransferFacts {
+            xchain_hash_id: transfer_hash,
+            src_chain: deposit.src_chain,
+            dest_chain: self.dest_chain(),
+            src_account: deposit.src_account,
+            dest_account: deposit.dest_account,
+            token: Pubkey::new_from_array(deposit.dest_token).to_string(),
+            amount: deposit.amount,
+            nonce: deposit.nonce,
+        };
+        if !self.policy.permits(&facts).await {
+            return Ok(());
+        }
+
+        let sig = self.submit_withdraw_and_approve(&deposit, relay_fee)?;
+        self.schedule_execution(&transfer_hash).await;
+        if let Err(e) = self.mark_deposits_processed_for_hash(&transfer_hash).await {
+            warn!(
+                hash = %hash_hex,
+                error = %e,
+                "Failed to mark matching DB deposits processed (tx already submitted)"
+            );
+        }
+        info!(
+            nonce = deposit.nonce,
+            hash = %hash_hex,
+            relay_fee = %relay_fee,
+            tx = %sig,
+            "Submitted relayed Solana withdrawal"
+        );
+        crate::metrics::record_withdraw_relay("submitted");
+        db::update_relay_request_submitted(
+            &self.db,
+            request.id,
+            &relay_fee.to_string(),
+            &sig.to_string(),
+        )
+        .await
+    }
+
+    /// Read `row`'s deposit back from its source bridge; `None` when it is missing or does not
+    /// hash to `transfer_hash` for this chain
+    async fn read_source_deposit(
+        &self,
+        row: &UnsubmittedSolanaDeposit,
+        transfer_hash: &[u8; 32],
+    ) -> Result<Option<SourceDeposit>> {
+        let deposit = match row.source.as_str() {
+            "evm" => {
+                let src_chain = row
+                    .src_v2_chain_id
+                    .as_deref()
+                    .and_then(|c| <[u8; 4]>::try_from(c).ok());
+                let dest_token = row
+                    .dest_token_address
+                    .as_deref()
+                    .and_then(|t| <[u8; 32]>::try_from(t).ok());
+                match (src_chain, dest_token) {
+                    (Some(src_chain), Some(dest_token)) => {
+                        self.read_evm_source_deposit(transfer_hash, src_chain, dest_token)
+                            .await?
+                    }
+                    _ => None,
+                }
+            }
+            "terra" => self.read_terra_source_deposit(transfer_hash).await?,
+            _ => None,
+        };
+        let Some(deposit) = deposit else {
+            info!(
+                hash = %hex::encode(transfer_hash),
+                source = %row.source,
+                "No matching source deposit for Solana operator submit"
+            );
+            return Ok(None);
+        };
+
+        let recomputed = compute_xchain_hash_id(
+            &deposit.src_chain,
+            &self.dest_chain(),
+            &deposit.src_account,
+            &deposit.dest_account,
+            &deposit.dest_token,
+            deposit.amount,
+            deposit.nonce,
+        );
+        if recomputed != *transfer_hash {
+            warn!(
+                hash = %hex::encode(transfer_hash),
+                recomputed = %hex::encode(recomputed),
+                source = %row.source,
+                "Source deposit does not hash to the indexed transfer hash, not submitting"
+            );
+            return Ok(None);
+        }
+        Ok(Some(deposit))
+    }
+
+    /// EVM `getDeposit`; the record has no destination token, so `dest_token` comes from the
+    /// indexed event and is checked through the transfer hash
+    async fn read_evm_source_deposit(
+        &self,
+        transfer_hash: &[u8; 32],
+        src_chain: [u8; 4],
+        dest_token: [u8; 32],
+    ) -> Result<Option<SourceDeposit>> {
+        let Some((rpc_url, bridge_address)) = self.source_chain_endpoints.get(&src_chain) else {
+            warn!(
+                hash = %hex::encode(transfer_hash),
+                src_chain = %format!("0x{}", hex::encode(src_chain)),
+                "No EVM endpoint for source chain of Solana deposit"
+            );
+            return Ok(None);
+        };
+        let provider = ProviderBuilder::new().on_http(rpc_url.parse()?);
+        let record = Bridge::new(*bridge_address, &provider)
+            .getDeposit(FixedBytes::from(*transfer_hash))
+            .call()
+            .await?;
+        if record.timestamp.is_zero() || record.destChain.0 != self.dest_chain() {
+            return Ok(None);
+        }
+        let Ok(amount) = u128::try_from(record.amount) else {
+            return Ok(None);
+        };
+        Ok(Some(SourceDeposit {
+            transfer_hash: *transfer_hash,
+            src_chain,
+            src_account: record.srcAccount.0,
+            dest_account: record.destAccount.0,
+            dest_token,
+            amount,
+            nonce: record.nonce,
+        }))
+    }
+
+    /// Terra `xchain_hash_id` query (`DepositInfoResponse` carries every hashed field)
+    async fn read_terra_source_deposit(
+        &self,
+        transfer_hash: &[u8; 32],
+    ) -> Result<Option<SourceDeposit>> {
+        let Some((lcd_url, bridge_addr)) = &self.terra_lcd else {
+            warn!("Terra LCD not configured — cannot submit Terra→Solana withdrawals");
+            return Ok(None);
+        };
+        let query = serde_json::json!({
+            "xchain_hash_id": { "xchain_hash_id": B64.encode(transfer_hash) }
+        });
+        let url = format!(
+            "{}/cosmwasm/wasm/v1/contract/{}/smart/{}",
+            lcd_url.trim_end_matches('/'),
+            bridge_addr,
+            B64.encode(serde_json::to_string(&query)?)
+        );
+        let resp = self.http.get(&url).send().await?;
+        if !resp.status().is_success() {
+            return Err(eyre::eyre!(
+                "Terra xchain_hash_id query HTTP {}",
+                resp.status()
+            ));
+        }
+        let json: serde_json::Value = resp.json().await?;
+        let data = &json["data"];
+        if data.is_null() {
+            return Ok(None);
+        }
+
+        fn binary<const N: usize>(v: &serde_json::Value) -> Option<[u8; N]> {
+            B64.decode(v.as_str()?).ok()?.try_into().ok()
+        }
+        let fields = (
+            binary::<4>(&data["src_chain"]),
+            binary::<4>(&data["dest_chain"]),
+            binary::<32>(&data["src_account"]),
+            binary::<32>(&data["dest_account"]),
+            binary::<32>(&data["dest_token_address"]),
+            data["amount"].as_str().and_then(|a| a.parse::<u128>().ok()),
+            data["nonce"].as_u64(),
+        );
+        let (
+            Some(src_chain),
+            Some(dest_chain),
+            Some(src_account),
+            Some(dest_account),
+            Some(dest_token),
+            Some(amount),
+            Some(nonce),
+        ) = fields
+        else {
+            return Err(eyre::eyre!(
+                "Unexpected Terra deposit record for {}",
+                hex::encode(transfer_hash)
+            ));
+        };
+        if dest_chain != self.dest_chain() {
+            return Ok(None);
+        }
+        Ok(Some(SourceDeposit {
+            transfer_hash: *transfer_hash,
+            src_chain,
+            src_account,
+            dest_account,
+            dest_token,
+            amount,
+            nonce,
+        }))
+    }
+
+    /// `withdraw_submit_and_approve` (Anchor account order). The unused rent-source account
+    /// is passed as the program ID (Anchor's `None`); `relay_config` is only passed with a
+    /// relay fee, which requires the `withdrawal` rent source and so overrides the configured one.
+    fn submit_withdraw_and_approve(
+        &self,
+        deposit: &SourceDeposit,
+        relay_fee: u128,
+    ) -> Result<solana_sdk::signature::Signature> {
+        let (token_mapping_pda, mapping) =
+            self.find_token_mapping(&deposit.dest_token, &deposit.src_chain)?;
+        let rent_source = if relay_fee > 0 {
+            RentSource::Withdrawal
+        } else {
+            self.operator_submit.rent_source
+        };
+        let none = AccountMeta::new_readonly(self.program_id, false);
+        let (rent_sponsor, sponsorship) = match rent_source {
+            RentSource::Sponsor => (
+                AccountMeta::new(rent_sponsor_pda(&self.program_id), false),
+                none,
+            ),
+            RentSource::Withdrawal => (
+                none,
+                AccountMeta::new(
+                    withdraw_sponsorship_pda(&self.program_id, &deposit.transfer_hash),
+                    false,
+                ),
+            ),
+        };
+        let (nonce_used_pda, _) = Pubkey::find_program_address(
+            &[
+                b"nonce_used",
+                deposit.src_chain.as_ref(),
+                &deposit.nonce.to_le_bytes(),
+            ],
+            &self.program_id,
+        );
+
+        let mut data = Vec::with_capacity(8 + 4 + 32 * 4 + 16 + 8 + 1 + 16);
+        data.extend_from_slice(&anchor_discriminator("global:withdraw_submit_and_approve"));
+        data.extend_from_slice(&deposit.src_chain);
+        data.extend_from_slice(&deposit.src_account);
+        data.extend_from_slice(&mapping.dest_token);
+        data.extend_from_slice(&deposit.dest_token);
+        data.extend_from_slice(&deposit.dest_account);
+        data.extend_from_slice(&deposit.amount.to_le_bytes());
+        data.extend_from_slice(&deposit.nonce.to_le_bytes());
+        data.push(rent_source as u8);
+        data.extend_from_slice(&relay_fee.to_le_bytes());
+
+        let signer = self.signers.acquire()?;
+        let mut instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(bridge_config_pda(&self.program_id), false),
+                AccountMeta::new_readonly(
+                    chain_entry_pda(&self.program_id, &deposit.src_chain),
+                    false,
+                ),
+                AccountMeta::new_readonly(token_mapping_pda, false),
+                AccountMeta::new(
+                    pending_withdraw_pda(&self.program_id, &deposit.transfer_hash),
+                    false,
+                ),
+                AccountMeta::new_readonly(
+                    executed_hash_pda(&self.program_id, &deposit.transfer_hash),
+                    false,
+                ),
+                AccountMeta::new_readonly(nonce_used_pda, false),
+                AccountMeta::new(
+                    nonce_bitmap_pda(&self.program_id, &deposit.src_chain, deposit.nonce),
+                    false,
+                ),
+                rent_sponsor,
+                sponsorship,
+                AccountMeta::new(signer.pubkey(), true),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            data,
+        };
+        if relay_fee > 0 {
+            instruction.accounts.push(AccountMeta::new_readonly(
+                relay_config_pda(&self.program_id),
+                false,
+            ));
+        }
+
+        run_with_solana_rpc_fallback(&self.rpc_clients, |client| {
+            let recent_blockhash = client.get_latest_blockhash()?;
+            let tx = Transaction::new_signed_with_payer(
+                &[instruction.clone()],
+                Some(&signer.pubkey()),
+                &[&*signer],
+                recent_blockhash,
+            );
+            client.send_and_confirm_transaction(&tx)
+        })
+        .map_err(|e| {
+            signer.observe_error(eyre::eyre!(
+                "Failed to submit Solana withdraw_submit_and_approve: {}",
+                e
+            ))
+        })
+    }
+
+    /// V2 chain ID withdrawals on this program execute on
+    fn dest_chain(&self) -> [u8; 4] {
+        self.configured_solana_v2_chain_ids[0]
+    }
+
+    /// Effective withdraw delay (seconds) for `pw`, mirroring the program's `delay.rs`
+    fn read_effective_withdraw_delay(&self, pw: &SolanaPendingWithdraw) -> Result<i64> {
+        let token = Pubkey::new_from_array(pw.token);
+        let keys = [
+            bridge_config_pda(&self.program_id),
+            chain_withdraw_delay_pda(&self.program_id, &pw.src_chain),
+            large_transfer_delay_pda(&self.program_id, &pw.src_chain, &token),
+        ];
+        let accounts = run_with_solana_rpc_fallback(&self.rpc_clients, |client| {
+            client.get_multiple_accounts(&keys)
+        })
+        .map_err(|e| eyre::eyre!("Failed to read withdraw delay accounts: {}", e))?;
+
+        let bridge = accounts[0]
+            .as_ref()
+            .ok_or_else(|| eyre::eyre!("Bridge config account missing"))?;
+        let config = parse_bridge_config(&bridge.data)?;
+        let chain = accounts[1]
+            .as_ref()
+            .and_then(|a| parse_chain_withdraw_delay(&a.data));
+        let large = accounts[2]
+            .as_ref()
+            .and_then(|a| parse_large_transfer_delay(&a.data));
+        Ok(effective_withdraw_delay(
+            config.withdraw_delay,
+            chain,
+            large,
+            pw.amount,
+        ))
+    }
+
+    /// Schedule auto-execution of a just-approved withdrawal once its delay has passed
+    async fn schedule_execution(&self, transfer_hash: &[u8; 32]) {
+        let pda = pending_withdraw_pda(&self.program_id, transfer_hash);
+        let delay =
+            run_with_solana_rpc_fallback(&self.rpc_clients, |client| client.get_account(&pda))
+                .map_err(|e| eyre::eyre!("Failed to read PendingWithdraw: {}", e))
+                .and_then(|account| parse_pending_withdraw(&account.data))
+                .and_then(|pw| self.read_effective_withdraw_delay(&pw));
+        // The execution pass re-reads the window; fall back to executing as early as possible
+        let delay = delay.unwrap_or_else(|e| {
+            warn!(
+                hash = %hex::encode(transfer_hash),
+                error = %e,
+                "Could not resolve Solana withdraw delay, scheduling execution without it"
+            );
+            0
+        });
+        if let Err(e) = db::schedule_withdraw_execution(
+            &self.db,
+            "solana",
+            &self.dest_chain(),
+            transfer_hash,
+            delay,
+        )
+        .await
+        {
+            warn!(
+                hash = %hex::encode(transfer_hash),
+                error = %e,
+                "Failed to schedule withdrawal execution"
+            );
+        }
+    }
+
+    /// Execute approved withdrawals whose delay has elapsed
+    async fn process_pending_executions(&self) -> Result<()> {
+        let due = db::claim_due_withdraw_executions(
+            &self.db,
+            &self.dest_chain(),
+            crate::leader::instance_id(),
+            crate::leader::fence().as_ref(),
+            crate::leader::CLAIM_TTL_SECS,
+            crate::leader::CLAIM_BATCH_SIZE,
+        )
+        .await?;
+
+        for row in due {
+            let result = match super::execution::xchain_hash_id(&row) {
+                Some(hash) => self.submit_execute(&hash),
+                None => Err(eyre::eyre!("malformed xchain_hash_id")),
+            };
+            super::execution::finish(&self.db, &self.execution, &row, result).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Re-read the withdraw and submit the operator execute instruction when it is due
+    ///
+    /// Executing closes the `PendingWithdraw` and creates the `ExecutedHash`, so a missing
+    /// `PendingWithdraw` means either the recipient executed it or `close_withdraw` removed
+    /// a cancelled one.
+    fn submit_execute(&self, transfer_hash: &[u8; 32]) -> Result<ExecutionOutcome> {
+        let pending_pda = pending_withdraw_pda(&self.program_id, transfer_hash);
+        let executed_pda = executed_hash_pda(&self.program_id, transfer_hash);
+        let sponsorship_pda = withdraw_sponsorship_pda(&self.program_id, transfer_hash);
+        let accounts = run_with_solana_rpc_fallback(&self.rpc_clients, |client| {
+            client.get_multiple_accounts(&[pending_pda, executed_pda, sponsorship_pda])
+        })
+        .map_err(|e| eyre::eyre!("Failed to read PendingWithdraw: {}", e))?;
+
+        let Some(pending) = &accounts[0] else {
+            // Executed by the recipient or cancelled and closed: the rent we sponsored (and a
+            // native relay fee) is parked in the sponsorship until we close it
+            if accounts[2].is_some() {
+                self.close_withdraw_sponsorship(transfer_hash, sponsorship_pda, pending_pda)?;
+            }
+            return Ok(if accounts[1].is_some() {
+                ExecutionOutcome::AlreadyExecuted
+            } else {
+                ExecutionOutcome::Cancelled
+            });
+        };
+        let pw = parse_pending_withdraw(&pending.data)?;
+        if pw.executed {
+            return Ok(ExecutionOutcome::AlreadyExecuted);
+        }
+        if pw.cancelled {
+            return Ok(ExecutionOutcome::Cancelled);
+        }
+        if !pw.approved {
+            return Err(eyre::eyre!(
+                "Withdrawal {} is not approved",
+                hex::encode(transfer_hash)
+            ));
+        }
+        let window_end = pw
+            .approved_at
+            .saturating_add(self.read_effective_withdraw_delay(&pw)?);
+        if window_end >= super::execution::unix_now() {
+            return Ok(ExecutionOutcome::NotReady(window_end));
+        }
+
+        let signer = self.signers.acquire()?;
+        let payer = signer.pubkey();
+        let instruction = if pw.token == [0u8; 32] {
+            self.execute_native_instruction(&pw, pending_pda, executed_pda, payer)
+        } else {
+            self.execute_spl_instruction(&pw, pending_pda, executed_pda, payer)?
+        };
+
+        let sig = run_with_solana_rpc_fallback(&self.rpc_clients, |client| {
+            let recent_blockhash = client.get_latest_blockhash()?;
+            let tx = Transaction::new_signed_with_payer(
+                std::slice::from_ref(&instruction),
+                Some(&payer),
+                &[&*signer],
+                recent_blockhash,
+            );
+            client.send_and_confirm_transaction(&tx)
+        })
+        .map_err(|e| {
+            signer.observe_error(eyre::eyre!(
+                "Failed to submit Solana withdraw execute: {}",
+                e
+            ))
+        })?;
+
+        Ok(ExecutionOutcome::Executed(sig.to_string()))
+    }
+
+    /// `close_withdraw_sponsorship` (Anchor account order), refunding the sponsorship to us
+    fn close_withdraw_sponsorship(
+        &self,
+        transfer_hash: &[u8; 32],
+        sponsorship_pda: Pubkey,
+        pending_pda: Pubkey,
+    ) -> Result<()> {
+        let signer = self.signers.acquire()?;
+        let payer = signer.pubkey();
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(bridge_config_pda(&self.program_id), false),
+                AccountMeta::new(sponsorship_pda, false),
+                AccountMeta::new_readonly(pending_pda, false),
+                AccountMeta::new(payer, true),
+            ],
+            data: anchor_discriminator("global:close_withdraw_sponsorship").to_vec(),
+        };
+
+        let sig = run_with_solana_rpc_fallback(&self.rpc_clients, |client| {
+            let recent_blockhash = client.get_latest_blockhash()?;
+            let tx = Transaction::new_signed_with_payer(
+                std::slice::from_ref(&instruction),
+                Some(&payer),
+                &[&*signer],
+                recent_blockhash,
+            );
+            client.send_and_confirm_transaction(&tx)
+        })
+        .map_err(|e| {
+            signer.observe_error(eyre::eyre!(
+                "Failed to submit Solana close_withdraw_sponsorship: {}",
+                e
+            ))
+        })?;
+        info!(
+            hash = %hex::encode(transfer_hash),
+            tx = %sig,
+            "Closed WithdrawSponsorship of a withdraw we did not execute"
+        );
+        Ok(())
+    }
+
+    /// `withdraw_execute_native_by_operator` (Anchor account order)
+    ///
+    /// The `WithdrawSponsorship` is always passed; when we paid this withdraw's rent
+    /// (`withdraw_submit_and_approve`), executing repays it.
+    fn execute_native_instruction(
+        &self,
+        pw: &SolanaPendingWithdraw,
+        pending_pda: Pubkey,
+        executed_pda: Pubkey,
+        payer: Pubkey,
+    ) -> Instruction {
+        let token = Pubkey::new_from_array(pw.token);
+        let rate_limit_pda =
+            Pubkey::find_program_address(&[b"w_rate_lim", token.as_ref()], &self.program_id).0;
+        Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(bridge_config_pda(&self.program_id), false),
+                AccountMeta::new(pending_pda, false),
+                AccountMeta::new(executed_pda, false),
+                AccountMeta::new(rate_limit_pda, false),
+                AccountMeta::new(Pubkey::new_from_array(pw.dest_account), false),
+                AccountMeta::new(payer, true),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(
+                    chain_withdraw_delay_pda(&self.program_id, &pw.src_chain),
+                    false,
+                ),
+                AccountMeta::new_readonly(
+                    large_transfer_delay_pda(&self.program_id, &pw.src_chain, &token),
+                    false,
+                ),
+                AccountMeta::new(
+                    withdraw_sponsorship_pda(&self.program_id, &pw.transfer_hash),
+                    false,
+                ),
+            ],
+            data: anchor_discriminator("global:withdraw_execute_native_by_operator").to_vec(),
+        }
+    }
+
+    /// `withdraw_execute_by_operator` (Anchor account order)
+    ///
+    /// The token program is the mint's owner (SPL Token or Token-2022), and the `TokenMapping`
+    /// is the one registered for this mint and the withdraw's source chain.
+    fn execute_spl_instruction(
+        &self,
+        pw: &SolanaPendingWithdraw,
+        pending_pda: Pubkey,
+        executed_pda: Pubkey,
+        payer: Pubkey,
+    ) -> Result<Instruction> {
+        let mint = Pubkey::new_from_array(pw.token);
+        let recipient = Pubkey::new_from_array(pw.dest_account);
+        let bridge_pda = bridge_config_pda(&self.program_id);
+
+        let token_program =
+            run_with_solana_rpc_fallback(&self.rpc_clients, |client| client.get_account(&mint))
+                .map_err(|e| eyre::eyre!("Failed to read mint {}: {}", mint, e))?
+                .owner;
+        let (token_mapping_pda, _) = self.find_token_mapping(&pw.token, &pw.src_chain)?;
+
+        let rate_limit_pda =
+            Pubkey::find_program_address(&[b"w_rate_lim", mint.as_ref()], &self.program_id).0;
+
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(bridge_pda, false),
+                AccountMeta::new(pending_pda, false),
+                AccountMeta::new(executed_pda, false),
+                AccountMeta::new(mint, false),
+                AccountMeta::new(
+                    associated_token_address(&recipient, &mint, &token_program),
+                    false,
+                ),
+                AccountMeta::new(
+                    associated_token_address(&bridge_pda, &mint, &token_program),
+                    false,
+                ),
+                AccountMeta::new_readonly(token_mapping_pda, false),
+                AccountMeta::new(rate_limit_pda, false),
+                AccountMeta::new(recipient, false),
+                AccountMeta::new(payer, true),
+                AccountMeta::new_readonly(token_program, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(
+                    chain_withdraw_delay_pda(&self.program_id, &pw.src_chain),
+                    false,
+                ),
+                AccountMeta::new_readonly(
+                    large_transfer_delay_pda(&self.program_id, &pw.src_chain, &mint),
+                    false,
+                ),
+                AccountMeta::new(
+                    withdraw_sponsorship_pda(&self.program_id, &pw.transfer_hash),
+                    false,
+                ),
+            ],
+            data: anchor_discriminator("global:withdraw_execute_by_operator").to_vec(),
+        })
+    }
+    /// `TokenMapping` registered for local `mint` (all zero for native SOL) and `src_chain`
+    fn find_token_mapping(
+        &self,
+        mint: &[u8; 32],
+        src_chain: &[u8; 4],
+    ) -> Result<(Pubkey, SolanaTokenMapping)> {
+        let cfg = RpcProgramAccountsConfig {
+            filters: Some(vec![
+                RpcFilterType::DataSize(TOKEN_MAPPING_ACCOUNT_LEN),
+                RpcFilterType::Memcmp(Memcmp::new(
+                    0,
+                    MemcmpEncodedBytes::Base64(
+                        B64.encode(anchor_account_discriminator("TokenMapping")),
+                    ),
+                )),
+                RpcFilterType::Memcmp(Memcmp::new(
+                    TOKEN_MAPPING_LOCAL_MINT_OFFSET,
+                    MemcmpEncodedBytes::Base64(B64.encode(mint)),
+                )),
+                RpcFilterType::Memcmp(Memcmp::new(
+                    TOKEN_MAPPING_DEST_CHAIN_OFFSET,
+                    MemcmpEncodedBytes::Base64(B64.encode(src_chain)),
+                )),
+            ]),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let program_id = self.program_id;
+        let (pda, account) = run_with_solana_rpc_fallback(&self.rpc_clients, |client| {
+            client.get_program_accounts_with_config(&program_id, cfg.clone())
+        })
+        .map_err(|e| eyre::eyre!("getProgramAccounts for TokenMapping failed: {}", e))?
+        .into_iter()
+        .next()
+        .ok_or_else(|| {
+            eyre::eyre!(
+                "No TokenMapping for mint {} from source chain 0x{}",
+                Pubkey::new_from_array(*mint),
+                hex::encode(src_chain)
+            )
+        })?;
+        Ok((pda, parse_token_mapping(&account.data)?))
     }
 }
 
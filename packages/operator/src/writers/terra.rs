@@ -8,10 +8,18 @@
 //! 3. Operator verifies each hash against EVM Bridge's getDeposit(hash)
 //! 4. If deposit exists on EVM, operator calls WithdrawApprove(hash) on Terra
 //! 5. Cancelers can cancel during the cancel window
-//! 6. Anyone can call WithdrawExecuteUnlock/Mint after window
+//! 6. Anyone can call WithdrawExecuteUnlock/Mint after window; the operator does so
+//!    itself for every approval it scheduled (see [`super::execution`])
+//!
+//! With relaying enabled, step 1 is done by the operator (`WithdrawSubmitRelayed`) for deposits
+//! whose depositor requested it (see [`super::relay`]).
+//!
+//! Each operator mnemonic has its own account sequence, so verified withdrawals on a page are
+//! approved concurrently, one broadcast in flight per key of the [`SignerPool`].
 
 use std::collections::HashMap;
-use std::time::{Duration, Instant};
+use std::sync::Arc;
+use std::time::Duration;
 
 use base64::Engine as _;
 
@@ -20,6 +28,7 @@ use crate::bounded_cache::BoundedHashCache;
 use alloy::primitives::{Address, FixedBytes};
 use alloy::providers::ProviderBuilder;
 use eyre::{eyre, Result, WrapErr};
+use multichain_rs::token_cache::TokenCache;
 use reqwest::Client;
 use sqlx::PgPool;
 use tracing::{debug, info, warn};
@@ -28,27 +37,27 @@ use crate::config::TerraConfig;
 use crate::contracts::evm_bridge::Bridge as EvmBridge;
 use crate::contracts::terra_bridge::{
     build_withdraw_approve_msg_v2, build_withdraw_execute_mint_msg_v2,
-    build_withdraw_execute_unlock_msg_v2,
+    build_withdraw_execute_unlock_msg_v2, build_withdraw_submit_relayed_msg_v2,
 };
 use crate::db;
-use crate::hash::bytes32_to_hex;
+use crate::hash::{bytes32_to_hex, decode_bytes32_to_terra_address, encode_terra_token_address};
+use crate::policy::{PolicyEngine, TransferFacts};
 use crate::terra_client::TerraClient;
 use crate::types::ChainId;
 
-/// Pending approval tracking for auto-execution
-#[allow(dead_code)]
-#[derive(Debug, Clone)]
-struct PendingExecution {
-    /// The cross-chain hash identifier
-    xchain_hash_id: [u8; 32],
-    /// When the approval was submitted
-    approved_at: Instant,
-    /// The delay required before execution
-    delay_seconds: u64,
-    /// Number of execution attempts
-    attempts: u32,
-    /// Terra token denom or CW20 address for token type lookup
-    token: String,
+use super::execution::ExecutionOutcome;
+use super::signer_pool::{BalanceThresholds, SignerPool};
+use super::VerifiedWithdraw;
+
+/// Decode a base64 `Binary` account field of a PendingWithdrawals entry (zero when absent)
+fn entry_bytes32(value: &serde_json::Value) -> [u8; 32] {
+    value
+        .as_str()
+        .and_then(|b64| {
+            base64::Engine::decode(&base64::engine::general_purpose::STANDARD, b64).ok()
+        })
+        .and_then(|b| b.try_into().ok())
+        .unwrap_or([0u8; 32])
 }
 
 /// Terra transaction writer for submitting approvals and executions
@@ -61,18 +70,17 @@ pub struct TerraWriter {
     chain_id: String,
     contract_address: String,
     client: Client,
-    terra_client: TerraClient,
-    #[allow(dead_code)]
+    /// Operator keys; every transaction leases one
+    signers: SignerPool<TerraClient>,
     db: PgPool,
     /// Cancel window in seconds
     cancel_window: u64,
     /// Fee recipient for withdrawals
     #[allow(dead_code)]
     fee_recipient: String,
-    /// Pending approvals awaiting execution (bounded to prevent unbounded growth)
-    pending_executions: crate::bounded_cache::BoundedPendingCache<PendingExecution>,
+    /// Auto-execution retry settings
+    execution: super::ExecutionConfig,
     /// This chain's 4-byte chain ID (V2)
-    #[allow(dead_code)]
     this_chain_id: ChainId,
     /// Source chain endpoints for cross-chain deposit verification routing.
     /// Maps V2 4-byte chain ID → (rpc_url, bridge_address).
@@ -82,6 +90,12 @@ pub struct TerraWriter {
     approved_hashes: BoundedHashCache,
     /// Optional Solana source config for verifying Solana-origin deposits
     solana_source_config: Option<super::SolanaSourceConfig>,
+    /// Deposit policy consulted before every approval
+    policy: Arc<PolicyEngine>,
+    /// Shared token metadata, consulted before querying the bridge
+    token_cache: Option<Arc<TokenCache>>,
+    /// Gasless withdrawal relay settings
+    relay: super::RelayConfig,
 }
 
 impl TerraWriter {
@@ -98,17 +112,31 @@ impl TerraWriter {
         source_chain_endpoints: HashMap<[u8; 4], (String, Address)>,
         solana_source_config: Option<super::SolanaSourceConfig>,
         db: PgPool,
+        policy: Arc<PolicyEngine>,
     ) -> Result<Self> {
         let client = Client::builder()
             .timeout(Duration::from_secs(30))
             .build()
             .wrap_err("Failed to create HTTP client")?;
 
-        // Create Terra client for transaction signing
-        let terra_client = TerraClient::new(
-            &terra_config.lcd_url,
-            &terra_config.chain_id,
-            &terra_config.mnemonic,
+        // Create one Terra client per operator key for transaction signing
+        let mut keys = Vec::new();
+        for (i, mnemonic) in terra_config.signer_mnemonics().enumerate() {
+            let terra_client =
+                TerraClient::new(&terra_config.lcd_url, &terra_config.chain_id, mnemonic)
+                    .wrap_err_with(|| format!("Invalid Terra mnemonic #{}", i))?;
+            let address = terra_client.address.to_string();
+            keys.push((terra_client, address));
+        }
+        let signers = SignerPool::new(
+            "terra",
+            keys,
+            BalanceThresholds::from_env(
+                "TERRA_SIGNER_MIN_BALANCE_ULUNA",
+                "TERRA_SIGNER_LOW_BALANCE_ULUNA",
+                super::signer_pool::DEFAULT_TERRA_MIN_BALANCE_ULUNA,
+                super::signer_pool::DEFAULT_TERRA_LOW_BALANCE_ULUNA,
+            )?,
         )?;
 
         // Get this chain's V2 ID
@@ -152,7 +180,8 @@ impl TerraWriter {
 
         info!(
             delay_seconds = cancel_window,
-            operator_address = %terra_client.address,
+            operator_address = %signers.primary().address,
+            signer_keys = ?signers.addresses(),
             this_chain_id = %this_chain_id.to_hex(),
             source_chains = source_chain_endpoints.len(),
             "Terra writer initialized (V2 hash-matching, multi-EVM verification)"
@@ -163,17 +192,11 @@ impl TerraWriter {
             chain_id: terra_config.chain_id.clone(),
             contract_address: terra_config.bridge_address.clone(),
             client,
-            terra_client,
+            signers,
             db,
             cancel_window,
             fee_recipient: terra_config.fee_recipient.clone().unwrap_or_default(),
-            pending_executions: {
-                let cc = crate::bounded_cache::CacheConfig::from_env();
-                crate::bounded_cache::BoundedPendingCache::new(
-                    cc.pending_execution_size,
-                    cc.ttl_secs,
-                )
-            },
+            execution: super::ExecutionConfig::from_env()?,
             this_chain_id,
             source_chain_endpoints,
             approved_hashes: {
@@ -181,9 +204,18 @@ impl TerraWriter {
                 BoundedHashCache::new(cc.approved_hash_size, cc.ttl_secs)
             },
             solana_source_config,
+            policy,
+            token_cache: None,
+            relay: super::RelayConfig::from_env()?,
         })
     }
 
+    /// Answer token type lookups from `cache` when it has a fresh entry
+    pub fn with_token_cache(mut self, cache: Arc<TokenCache>) -> Self {
+        self.token_cache = Some(cache);
+        self
+    }
+
     // ========================================================================
     // Main Processing Loop
     // ========================================================================
@@ -195,9 +227,18 @@ impl TerraWriter {
     /// 3. For each unapproved entry, verify the deposit exists on EVM
     /// 4. If verified, call WithdrawApprove(hash) on Terra
     pub async fn process_pending(&mut self) -> Result<()> {
+        self.refresh_signer_balances().await;
+
         // First, check if any pending executions are ready
         self.process_pending_executions().await?;
 
+        // Relayed withdrawals are submitted first so this pass can already approve them
+        if self.relay.enabled {
+            if let Err(e) = self.process_relay_requests().await {
+                warn!(error = %e, "Relay request processing failed");
+            }
+        }
+
         // Then poll Terra for unapproved withdrawals and verify against EVM
         self.poll_and_approve().await?;
 
@@ -323,6 +364,7 @@ impl TerraWriter {
             );
 
             let mut last_hash: Option<String> = None;
+            let mut verified = Vec::new();
 
             for entry in &withdrawals {
                 total_processed += 1;
@@ -442,102 +484,36 @@ impl TerraWriter {
                     .await
                 {
                     Ok(true) => {
-                        // Deposit verified — approve on Terra
+                        let facts = TransferFacts {
+                            xchain_hash_id: hash_bytes,
+                            src_chain: src_chain_id,
+                            dest_chain: *self.this_chain_id.as_bytes(),
+                            src_account: entry_bytes32(&entry["src_account"]),
+                            dest_account: entry_bytes32(&entry["dest_account"]),
+                            token: token.to_string(),
+                            amount: amount.parse().unwrap_or(u128::MAX),
+                            nonce,
+                        };
+                        if !self.policy.permits(&facts).await {
+                            continue;
+                        }
+
+                        // Deposit verified — queue WithdrawApprove on Terra
                         info!(
                             xchain_hash_id = %bytes32_to_hex(&hash_bytes),
                             nonce = nonce,
                             src_chain = %format!("0x{}", hex::encode(src_chain_id)),
-                            "EVM deposit verified, submitting WithdrawApprove on Terra"
+                            "EVM deposit verified, queueing WithdrawApprove on Terra"
                         );
-
-                        match self.submit_approve(&hash_bytes).await {
-                            Ok(tx_hash) => {
-                                total_approved += 1;
-                                info!(
-                                    tx_hash = %tx_hash,
-                                    xchain_hash_id = %bytes32_to_hex(&hash_bytes),
-                                    nonce = nonce,
-                                    "WithdrawApprove submitted successfully on Terra"
-                                );
-
-                                // Update shared DB: mark evm_deposit as processed so both writers
-                                // see consistent state (pending_deposits count decreases).
-                                // Use src_chain from the withdrawal entry (V2 4-byte) for multi-EVM support.
-                                let src_v2_chain_id: Option<[u8; 4]> = entry["src_chain"]
-                                    .as_str()
-                                    .and_then(|b64| {
-                                        base64::Engine::decode(
-                                            &base64::engine::general_purpose::STANDARD,
-                                            b64,
-                                        )
-                                        .ok()
-                                    })
-                                    .and_then(|b| b.try_into().ok());
-                                if let Some(src) = src_v2_chain_id {
-                                    if let Ok(Some(deposit_id)) =
-                                        db::find_evm_deposit_id_by_src_v2_chain_nonce_for_cosmos(
-                                            &self.db,
-                                            &src,
-                                            nonce as i64,
-                                        )
-                                        .await
-                                    {
-                                        if let Err(e) = db::update_evm_deposit_status(
-                                            &self.db,
-                                            deposit_id,
-                                            "processed",
-                                        )
-                                        .await
-                                        {
-                                            warn!(
-                                                deposit_id = deposit_id,
-                                                nonce = nonce,
-                                                error = %e,
-                                                "Failed to update evm_deposit status after Terra approval"
-                                            );
-                                        } else {
-                                            debug!(
-                                                deposit_id = deposit_id,
-                                                nonce = nonce,
-                                                "Marked evm_deposit as processed (shared data source)"
-                                            );
-                                        }
-                                    }
-                                } else {
-                                    debug!(
-                                        nonce = nonce,
-                                        "Could not parse src_chain from withdrawal entry, skipping evm_deposit update"
-                                    );
-                                }
-
-                                // Track for auto-execution after cancel window
-                                self.pending_executions.insert(
-                                    hash_bytes,
-                                    PendingExecution {
-                                        xchain_hash_id: hash_bytes,
-                                        approved_at: Instant::now(),
-                                        delay_seconds: self.cancel_window,
-                                        attempts: 0,
-                                        token: token.to_string(),
-                                    },
-                                );
-
-                                // Remember we approved this hash
-                                self.approved_hashes.insert(hash_bytes);
-                            }
-                            Err(e) => {
-                                warn!(
-                                    xchain_hash_id = %bytes32_to_hex(&hash_bytes),
-                                    nonce = nonce,
-                                    error = %e,
-                                    operator_address = %self.terra_client.address,
-                                    contract = %self.contract_address,
-                                    "Failed to submit WithdrawApprove on Terra. \
-                                     Check: (1) operator is registered on Terra bridge, \
-                                     (2) account has sufficient gas, \
-                                     (3) withdrawal not already approved."
-                                );
-                            }
+                        if verified
+                            .iter()
+                            .all(|w: &VerifiedWithdraw| w.xchain_hash_id != hash_bytes)
+                        {
+                            verified.push(VerifiedWithdraw {
+                                xchain_hash_id: hash_bytes,
+                                src_chain_id,
+                                nonce,
+                            });
                         }
                     }
                     Ok(false) => {
@@ -568,6 +544,8 @@ impl TerraWriter {
                 }
             }
 
+            total_approved += self.approve_verified(verified).await;
+
             // If we got fewer than page_limit results, we're done
             if withdrawals.len() < page_limit as usize {
                 break;
@@ -588,7 +566,6 @@ impl TerraWriter {
                 skipped_already_approved = total_skipped_already_approved,
                 no_evm_deposit = total_no_evm_deposit,
                 evm_errors = total_evm_errors,
-                pending_executions = self.pending_executions.len(),
                 "Terra poll cycle complete"
             );
         }
@@ -596,6 +573,108 @@ impl TerraWriter {
         Ok(())
     }
 
+    /// Submit WithdrawApprove for every verified withdrawal, as many at once as there are
+    /// active signer keys. Returns how many were approved.
+    async fn approve_verified(&mut self, verified: Vec<VerifiedWithdraw>) -> u32 {
+        let mut approved = 0;
+        let width = self.signers.active().max(1);
+        for chunk in verified.chunks(width) {
+            let results = futures::future::join_all(
+                chunk.iter().map(|w| self.submit_approve(&w.xchain_hash_id)),
+            )
+            .await;
+
+            for (w, result) in chunk.iter().zip(results) {
+                match result {
+                    Ok(tx_hash) => {
+                        approved += 1;
+                        info!(
+                            tx_hash = %tx_hash,
+                            xchain_hash_id = %bytes32_to_hex(&w.xchain_hash_id),
+                            nonce = w.nonce,
+                            "WithdrawApprove submitted successfully on Terra"
+                        );
+
+                        // Update shared DB: mark evm_deposit as processed so both writers
+                        // see consistent state (pending_deposits count decreases).
+                        // Use src_chain from the withdrawal entry (V2 4-byte) for multi-EVM support.
+                        if let Ok(Some(deposit_id)) =
+                            db::find_evm_deposit_id_by_src_v2_chain_nonce_for_cosmos(
+                                &self.db,
+                                &w.src_chain_id,
+                                w.nonce as i64,
+                            )
+                            .await
+                        {
+                            if let Err(e) =
+                                db::update_evm_deposit_status(&self.db, deposit_id, "processed")
+                                    .await
+                            {
+                                warn!(
+                                    deposit_id = deposit_id,
+                                    nonce = w.nonce,
+                                    error = %e,
+                                    "Failed to update evm_deposit status after Terra approval"
+                                );
+                            } else {
+                                debug!(
+                                    deposit_id = deposit_id,
+                                    nonce = w.nonce,
+                                    "Marked evm_deposit as processed (shared data source)"
+                                );
+                            }
+                        }
+
+                        // Track for auto-execution after cancel window
+                        self.schedule_execution(w.xchain_hash_id).await;
+
+                        // Remember we approved this hash
+                        self.approved_hashes.insert(w.xchain_hash_id);
+                    }
+                    Err(e) => {
+                        warn!(
+                            xchain_hash_id = %bytes32_to_hex(&w.xchain_hash_id),
+                            nonce = w.nonce,
+                            error = %e,
+                            contract = %self.contract_address,
+                            "Failed to submit WithdrawApprove on Terra. \
+                             Check: (1) operator is registered on Terra bridge, \
+                             (2) account has sufficient gas, \
+                             (3) withdrawal not already approved."
+                        );
+                    }
+                }
+            }
+        }
+        approved
+    }
+
+    /// Re-read signer uluna balances when due (retires keys that ran dry, alerts on low ones)
+    async fn refresh_signer_balances(&self) {
+        self.signers
+            .refresh_balances(|terra_client| {
+                let url = format!(
+                    "{}/cosmos/bank/v1beta1/balances/{}/by_denom?denom=uluna",
+                    self.lcd_url, terra_client.address
+                );
+                let client = self.client.clone();
+                async move {
+                    let body: serde_json::Value = client
+                        .get(&url)
+                        .send()
+                        .await?
+                        .error_for_status()?
+                        .json()
+                        .await?;
+                    body["balance"]["amount"]
+                        .as_str()
+                        .and_then(|a| a.parse::<u128>().ok())
+                        .ok_or_else(|| eyre!("Unexpected bank balance response: {}", body))
+                }
+            })
+            .await;
+    }
+
     // ========================================================================
     // EVM Deposit Verification
     // ========================================================================
@@ -728,6 +807,202 @@ impl TerraWriter {
         Ok(exists)
     }
 
+    // ========================================================================
+    // Relayed Withdrawals
+    // ========================================================================
+
+    /// Submit `WithdrawSubmitRelayed` for deposits whose depositor asked for a relay to
+    /// Terra (see [`super::relay`])
+    ///
+    /// Once submitted, the withdrawal is approved and executed by `poll_and_approve` exactly
+    /// like a user-submitted one.
+    async fn process_relay_requests(&self) -> Result<()> {
+        let requests = db::claim_pending_relay_requests(
+            &self.db,
+            self.this_chain_id.as_bytes(),
+            crate::leader::instance_id(),
+            crate::leader::fence().as_ref(),
+            crate::leader::CLAIM_TTL_SECS,
+            crate::leader::CLAIM_BATCH_SIZE,
+        )
+        .await?;
+        if requests.is_empty() {
+            return Ok(());
+        }
+
+        let cap = self
+            .query_contract_json(&serde_json::json!({ "relay_fee_cap": {} }))
+            .await
+            .wrap_err("Failed to query relay_fee_cap")?;
+        let cap_bps = cap["relay_fee_cap_bps"]
+            .as_u64()
+            .ok_or_else(|| eyre!("Unexpected relay_fee_cap response: {}", cap))?;
+
+        info!(count = requests.len(), cap_bps, "Processing relay requests");
+        for request in requests {
+            if let Err(e) = self.relay_withdraw(&request, cap_bps).await {
+                warn!(
+                    request_id = request.id,
+                    xchain_hash_id = %format!("0x{}", hex::encode(&request.xchain_hash_id)),
+                    error = %e,
+                    "Relay submission failed, will retry"
+                );
+                crate::metrics::record_withdraw_relay("error");
+                db::record_relay_request_error(
+                    &self.db,
+                    request.id,
+                    &e.to_string(),
+                    self.relay.max_attempts,
+                )
+                .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Submit the withdrawal for one relay request, or close the request when it should not
+    /// be relayed. Errors are retriable.
+    async fn relay_withdraw(&self, request: &db::RelayRequest, cap_bps: u64) -> Result<()> {
+        let xchain_hash_id: [u8; 32] = request
+            .xchain_hash_id
+            .as_slice()
+            .try_into()
+            .map_err(|_| eyre!("Relay request {} has a malformed hash", request.id))?;
+        let sources = super::RelaySources {
+            terra: None,
+            solana: self
+                .solana_source_config
+                .as_ref()
+                .and_then(|c| c.chain_ids.first().copied()),
+        };
+        let Some(withdraw) = super::relay::load_relayed_withdraw(
+            &self.db,
+            &xchain_hash_id,
+            self.this_chain_id.as_bytes(),
+            sources,
+        )
+        .await?
+        else {
+            crate::metrics::record_withdraw_relay("declined");
+            return db::close_relay_request(
+                &self.db,
+                request.id,
+                "declined",
+                "indexed deposit does not hash to the relay request",
+            )
+            .await;
+        };
+
+        let max_relay_fee: u128 = request
+            .max_relay_fee
+            .parse()
+            .map_err(|_| eyre!("Invalid max relay fee: {}", request.max_relay_fee))?;
+        let relay_fee = match self
+            .relay
+            .relay_fee(withdraw.amount, max_relay_fee, cap_bps)
+        {
+            Ok(fee) => fee,
+            Err(reason) => {
+                info!(
+                    xchain_hash_id = %bytes32_to_hex(&xchain_hash_id),
+                    reason = %reason,
+                    "Declining relay request"
+                );
+                crate::metrics::record_withdraw_relay("declined");
+                return db::close_relay_request(&self.db, request.id, "declined", &reason).await;
+            }
+        };
+
+        // The recipient (or anyone) may have submitted it in the meantime
+        let pending = self.query_pending_withdraw(&xchain_hash_id).await?;
+        if pending["exists"].as_bool().unwrap_or(false) {
+            crate::metrics::record_withdraw_relay("skipped");
+            return db::close_relay_request(&self.db, request.id, "skipped", "already submitted")
+                .await;
+        }
+
+        // The transfer hash carries token ID and recipient as bytes32; the message takes names
+        let Some(token) = self.local_token(&withdraw.dest_token).await? else {
+            crate::metrics::record_withdraw_relay("declined");
+            return db::close_relay_request(
+                &self.db,
+                request.id,
+                "declined",
+                "destination token is not registered on the Terra bridge",
+            )
+            .await;
+        };
+        let recipient = decode_bytes32_to_terra_address(&withdraw.dest_account)
+            .map_err(|e| eyre!("Invalid Terra recipient: {}", e))?;
+
+        let msg = build_withdraw_submit_relayed_msg_v2(
+            withdraw.src_chain,
+            withdraw.src_account,
+            &token,
+            &recipient,
+            withdraw.amount,
+            withdraw.nonce,
+            relay_fee,
+        );
+        let signer = self.signers.acquire()?;
+        let tx_hash = signer
+            .execute_contract(&self.contract_address, &msg, vec![])
+            .await
+            .map_err(|e| {
+                signer.observe_error(eyre!(
+                    "Failed to execute WithdrawSubmitRelayed from {}: {}",
+                    signer.address(),
+                    e
+                ))
+            })?;
+
+        info!(
+            xchain_hash_id = %bytes32_to_hex(&xchain_hash_id),
+            relay_fee = %relay_fee,
+            tx_hash = %tx_hash,
+            "Submitted relayed withdrawal"
+        );
+        crate::metrics::record_withdraw_relay("submitted");
+        db::update_relay_request_submitted(&self.db, request.id, &relay_fee.to_string(), &tx_hash)
+            .await
+    }
+
+    /// Terra token (denom or CW20 address) whose bridge token ID is `id`, from the token
+    /// cache when it is fresh, else by listing the bridge's tokens
+    async fn local_token(&self, id: &[u8; 32]) -> Result<Option<String>> {
+        const PAGE: u32 = 30;
+
+        if let Some(token) = self
+            .token_cache
+            .as_ref()
+            .filter(|cache| cache.is_fresh(self.this_chain_id))
+            .and_then(|cache| cache.get(self.this_chain_id, id))
+        {
+            return Ok(Some(token.token));
+        }
+
+        let mut start_after: Option<String> = None;
+        loop {
+            let page = self
+                .query_contract_json(&serde_json::json!({
+                    "tokens": { "start_after": start_after, "limit": PAGE }
+                }))
+                .await
+                .wrap_err("Failed to query Terra bridge tokens")?;
+            let tokens: Vec<&str> = page["tokens"]
+                .as_array()
+                .map(|tokens| tokens.iter().filter_map(|t| t["token"].as_str()).collect())
+                .unwrap_or_default();
+            if let Some(token) = tokens.iter().find(|t| encode_terra_token_address(t) == *id) {
+                return Ok(Some(token.to_string()));
+            }
+            match tokens.last() {
+                Some(last) if tokens.len() as u32 == PAGE => start_after = Some(last.to_string()),
+                _ => return Ok(None),
+            }
+        }
+    }
+
     // ========================================================================
     // Terra Transaction Submission
     // ========================================================================
@@ -739,15 +1014,36 @@ impl TerraWriter {
         let msg_json = serde_json::to_string(&msg)?;
         debug!(msg = %msg_json, "WithdrawApprove message (V2)");
 
-        let tx_hash = self
-            .terra_client
+        let signer = self.signers.acquire()?;
+        let tx_hash = signer
             .execute_contract(&self.contract_address, &msg, vec![])
             .await
-            .map_err(|e| eyre!("Failed to execute WithdrawApprove: {}", e))?;
+            .map_err(|e| {
+                signer.observe_error(eyre!(
+                    "Failed to execute WithdrawApprove from {}: {}",
+                    signer.address(),
+                    e
+                ))
+            })?;
 
         Ok(tx_hash)
     }
 
+    /// Token type of `token` (`"mint_burn"` or `"lock_unlock"`), from the token cache when it
+    /// has a fresh entry with routes, else from the bridge
+    async fn token_type(&self, token: &str) -> Result<String> {
+        if let Some(mode) = self
+            .token_cache
+            .as_ref()
+            .filter(|cache| cache.is_fresh(self.this_chain_id))
+            .and_then(|cache| cache.get_by_name(self.this_chain_id, token))
+            .and_then(|t| t.mode())
+        {
+            return Ok(mode.to_string());
+        }
+        self.query_token_type(token).await
+    }
+
     /// Query the token type from the Terra bridge contract.
     /// Returns "lock_unlock" or "mint_burn".
     async fn query_token_type(&self, token: &str) -> Result<String> {
@@ -802,14 +1098,73 @@ impl TerraWriter {
         Ok(token_type)
     }
 
+    /// Query a withdrawal's on-chain state (`PendingWithdraw { xchain_hash_id }`)
+    async fn query_pending_withdraw(&self, xchain_hash_id: &[u8; 32]) -> Result<serde_json::Value> {
+        let query = serde_json::json!({
+            "pending_withdraw": {
+                "xchain_hash_id": base64::engine::general_purpose::STANDARD.encode(xchain_hash_id)
+            }
+        });
+        self.query_contract_json(&query)
+            .await
+            .wrap_err("pending_withdraw query failed")
+    }
+
+    /// Smart-query the bridge and return its `data`
+    async fn query_contract_json(&self, query: &serde_json::Value) -> Result<serde_json::Value> {
+        let query_b64 = base64::Engine::encode(
+            &base64::engine::general_purpose::STANDARD,
+            serde_json::to_string(query)?,
+        );
+        let url = format!(
+            "{}/cosmwasm/wasm/v1/contract/{}/smart/{}",
+            self.lcd_url, self.contract_address, query_b64
+        );
+        let response: serde_json::Value = self.client.get(&url).send().await?.json().await?;
+        // gRPC-gateway may return `data` as a JSON object or as a base64-encoded JSON string.
+        match response["data"].as_str() {
+            Some(b64) => {
+                let bytes = base64::engine::general_purpose::STANDARD
+                    .decode(b64.trim())
+                    .map_err(|e| eyre!("base64-decode data: {}", e))?;
+                serde_json::from_slice(&bytes).map_err(|e| eyre!("parse decoded data: {}", e))
+            }
+            None => Ok(response["data"].clone()),
+        }
+    }
+
     /// Submit WithdrawExecuteUnlock or WithdrawExecuteMint on Terra (after cancel window),
-    /// choosing the correct message based on on-chain token type.
-    async fn submit_execute_withdraw(
-        &self,
-        xchain_hash_id: [u8; 32],
-        token: &str,
-    ) -> Result<String> {
-        let token_type = self.query_token_type(token).await
+    /// choosing the correct message based on on-chain token type. Cancelled, already
+    /// executed and still-in-window withdrawals are reported without sending a transaction.
+    async fn submit_execute_withdraw(&self, xchain_hash_id: [u8; 32]) -> Result<ExecutionOutcome> {
+        let pending = self.query_pending_withdraw(&xchain_hash_id).await?;
+        if !pending["exists"].as_bool().unwrap_or(false) {
+            return Err(eyre!(
+                "Withdrawal {} not found",
+                bytes32_to_hex(&xchain_hash_id)
+            ));
+        }
+        if pending["executed"].as_bool().unwrap_or(false) {
+            return Ok(ExecutionOutcome::AlreadyExecuted);
+        }
+        if pending["cancelled"].as_bool().unwrap_or(false) {
+            return Ok(ExecutionOutcome::Cancelled);
+        }
+        if !pending["approved"].as_bool().unwrap_or(false) {
+            return Err(eyre!(
+                "Withdrawal {} is not approved",
+                bytes32_to_hex(&xchain_hash_id)
+            ));
+        }
+        let remaining = pending["cancel_window_remaining"].as_u64().unwrap_or(0);
+        if remaining > 0 {
+            return Ok(ExecutionOutcome::NotReady(
+                super::execution::unix_now() + remaining as i64,
+            ));
+        }
+        let token = pending["token"].as_str().unwrap_or_default();
+
+        let token_type = self.token_type(token).await
             .unwrap_or_else(|e| {
                 warn!(error = %e, token = %token, "Failed to query token type, defaulting to lock_unlock");
                 "lock_unlock".to_string()
@@ -824,51 +1179,58 @@ impl TerraWriter {
         let msg_json = serde_json::to_string(&msg)?;
         debug!(msg = %msg_json, token = %token, token_type = %token_type, "Submitting withdraw execution (V2)");
 
-        let tx_hash = self
-            .terra_client
+        let signer = self.signers.acquire()?;
+        let tx_hash = signer
             .execute_contract(&self.contract_address, &msg, vec![])
             .await
-            .map_err(|e| eyre!("Failed to execute withdraw ({}): {}", token_type, e))?;
+            .map_err(|e| {
+                signer.observe_error(eyre!("Failed to execute withdraw ({}): {}", token_type, e))
+            })?;
 
-        Ok(tx_hash)
+        Ok(ExecutionOutcome::Executed(tx_hash))
     }
 
     // ========================================================================
     // Pending Execution Management
     // ========================================================================
 
-    /// Process pending executions (after cancel window has elapsed)
-    async fn process_pending_executions(&mut self) -> Result<()> {
-        let now = Instant::now();
-        let mut to_remove = Vec::new();
-
-        for (hash, pending) in self.pending_executions.iter() {
-            let elapsed = now.duration_since(pending.approved_at);
-
-            if elapsed.as_secs() >= pending.delay_seconds {
-                match self.submit_execute_withdraw(*hash, &pending.token).await {
-                    Ok(tx_hash) => {
-                        info!(
-                            xchain_hash_id = %bytes32_to_hex(hash),
-                            tx_hash = %tx_hash,
-                            "Successfully executed withdrawal"
-                        );
-                        to_remove.push(*hash);
-                    }
-                    Err(e) => {
-                        warn!(
-                            xchain_hash_id = %bytes32_to_hex(hash),
-                            error = %e,
-                            attempt = pending.attempts + 1,
-                            "Failed to execute withdrawal, will retry"
-                        );
-                    }
-                }
-            }
+    /// Schedule auto-execution of an approved withdrawal once the cancel window has passed
+    async fn schedule_execution(&self, xchain_hash_id: [u8; 32]) {
+        if let Err(e) = db::schedule_withdraw_execution(
+            &self.db,
+            "terra",
+            self.this_chain_id.as_bytes(),
+            &xchain_hash_id,
+            self.cancel_window as i64,
+        )
+        .await
+        {
+            warn!(
+                xchain_hash_id = %bytes32_to_hex(&xchain_hash_id),
+                error = %e,
+                "Failed to schedule withdrawal execution"
+            );
         }
+    }
 
-        for hash in to_remove {
-            self.pending_executions.remove(&hash);
+    /// Execute approved withdrawals on Terra whose cancel window has elapsed
+    async fn process_pending_executions(&mut self) -> Result<()> {
+        let due = db::claim_due_withdraw_executions(
+            &self.db,
+            self.this_chain_id.as_bytes(),
+            crate::leader::instance_id(),
+            crate::leader::fence().as_ref(),
+            crate::leader::CLAIM_TTL_SECS,
+            crate::leader::CLAIM_BATCH_SIZE,
+        )
+        .await?;
+
+        for row in due {
+            let result = match super::execution::xchain_hash_id(&row) {
+                Some(hash) => self.submit_execute_withdraw(hash).await,
+                None => Err(eyre!("malformed xchain_hash_id")),
+            };
+            super::execution::finish(&self.db, &self.execution, &row, result).await?;
         }
 
         Ok(())
@@ -950,15 +1312,9 @@ impl TerraWriter {
     // Utility
     // ========================================================================
 
-    /// Get count of pending executions
-    #[allow(dead_code)]
-    pub fn pending_execution_count(&self) -> usize {
-        self.pending_executions.len()
-    }
-
-    /// Get the operator's Terra address
+    /// Get the operator's primary Terra address
     #[allow(dead_code)]
     pub fn operator_address(&self) -> String {
-        self.terra_client.address.to_string()
+        self.signers.primary().address.to_string()
     }
 }
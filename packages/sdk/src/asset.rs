@@ -0,0 +1,117 @@
+//! Tokens and accounts as integrators name them
+//!
+//! Accounts are passed in each chain's native format (EVM `0x…`, Terra `terra1…`, Solana
+//! base58) and converted to the 32-byte universal form the bridge hashes.
+
+use std::str::FromStr;
+
+use eyre::{eyre, Result};
+use multichain_rs::address_codec::{encode_evm_address, parse_evm_address};
+use multichain_rs::hash::{
+    address_to_bytes32, bytes32_to_address, decode_bytes32_to_terra_address,
+    encode_terra_address_to_bytes32,
+};
+use multichain_rs::trace::ChainKind;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+/// Native denom deposited by [`Asset::Native`] on Terra
+pub const TERRA_NATIVE_DENOM: &str = "uluna";
+
+/// Token being bridged, named on its source chain
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Asset {
+    /// The chain's gas token: ETH/BNB (bridged as the bridge's `wrappedNative`), LUNC (`uluna`)
+    /// or SOL
+    Native,
+    /// ERC20 address, Terra denom or CW20 address, or SPL mint
+    Token(String),
+}
+
+impl Asset {
+    /// Terra token identifier: `uluna` for [`Asset::Native`], otherwise the denom or CW20 address
+    pub(crate) fn terra_token(&self) -> &str {
+        match self {
+            Asset::Native => TERRA_NATIVE_DENOM,
+            Asset::Token(token) => token,
+        }
+    }
+
+    /// Solana mint; native SOL is `Pubkey::default()` like the program's `NATIVE_SOL_TOKEN`
+    pub(crate) fn solana_mint(&self) -> Result<Pubkey> {
+        match self {
+            Asset::Native => Ok(Pubkey::default()),
+            Asset::Token(mint) => {
+                Pubkey::from_str(mint).map_err(|e| eyre!("Invalid Solana mint {}: {}", mint, e))
+            }
+        }
+    }
+}
+
+/// Whether a Terra token identifier is a CW20 contract rather than a native denom
+pub(crate) fn is_cw20(token: &str) -> bool {
+    token.starts_with("terra1") && encode_terra_address_to_bytes32(token).is_ok()
+}
+
+/// Encode an account in its chain's native format as the 32-byte universal address
+pub fn encode_account(kind: ChainKind, account: &str) -> Result<[u8; 32]> {
+    match kind {
+        ChainKind::Evm => Ok(address_to_bytes32(&parse_evm_address(account)?)),
+        ChainKind::Terra => encode_terra_address_to_bytes32(account)
+            .map_err(|e| eyre!("Invalid Terra account {}: {}", account, e)),
+        ChainKind::Solana => Pubkey::from_str(account)
+            .map(|pubkey| pubkey.to_bytes())
+            .map_err(|e| eyre!("Invalid Solana account {}: {}", account, e)),
+    }
+}
+
+/// Decode a 32-byte universal address into its chain's native format
+pub fn decode_account(kind: ChainKind, account: &[u8; 32]) -> Result<String> {
+    match kind {
+        ChainKind::Evm => Ok(encode_evm_address(&bytes32_to_address(account))),
+        ChainKind::Terra => decode_bytes32_to_terra_address(account).map_err(|e| eyre!(e)),
+        ChainKind::Solana => Ok(Pubkey::new_from_array(*account).to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_account_roundtrip() {
+        let evm = "0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266";
+        let bytes = encode_account(ChainKind::Evm, evm).unwrap();
+        assert_eq!(bytes[..12], [0u8; 12]);
+        assert_eq!(decode_account(ChainKind::Evm, &bytes).unwrap(), evm);
+
+        let terra = "terra1x46rqay4d3cssq8gxxvqz8xt6nwlz4td20k38v";
+        let bytes = encode_account(ChainKind::Terra, terra).unwrap();
+        assert_eq!(decode_account(ChainKind::Terra, &bytes).unwrap(), terra);
+
+        let solana = Pubkey::new_unique().to_string();
+        let bytes = encode_account(ChainKind::Solana, &solana).unwrap();
+        assert_eq!(decode_account(ChainKind::Solana, &bytes).unwrap(), solana);
+
+        assert!(encode_account(ChainKind::Evm, terra).is_err());
+        assert!(encode_account(ChainKind::Terra, evm).is_err());
+    }
+
+    #[test]
+    fn test_terra_token_kind() {
+        assert_eq!(Asset::Native.terra_token(), "uluna");
+        assert!(!is_cw20("uluna"));
+        assert!(!is_cw20(
+            "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+        ));
+        assert!(is_cw20(
+            "terra14hj2tavq8fpesdwxxcu44rty3hh90vhujrvcmstl4zr3txmfvw9ssrc8au"
+        ));
+    }
+
+    #[test]
+    fn test_solana_native_mint() {
+        assert_eq!(Asset::Native.solana_mint().unwrap(), Pubkey::default());
+        assert!(Asset::Token("not-a-mint".into()).solana_mint().is_err());
+    }
+}
@@ -0,0 +1,274 @@
+//! Bridge client
+//!
+//! One [`BridgeClient`] covers every deployment passed to [`BridgeClient::connect`]. Chains are
+//! addressed by the 4-byte bridge chain ID each deployment reports.
+
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use eyre::{eyre, Result};
+use multichain_rs::trace::{
+    ChainKind, TraceEndpoint, TraceTarget, Tracer, TransferStatus, TransferTrace,
+};
+use multichain_rs::types::ChainId;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::asset::{encode_account, Asset};
+use crate::evm::EvmBackend;
+use crate::quote::{Quote, Route};
+use crate::signer::{send_all, TxSigner, UnsignedTx};
+use crate::solana::SolanaBackend;
+use crate::terra::TerraBackend;
+use crate::transfer::Transfer;
+
+enum Backend {
+    Evm(EvmBackend),
+    Terra(TerraBackend),
+    Solana(SolanaBackend),
+}
+
+struct Chain {
+    id: ChainId,
+    kind: ChainKind,
+    backend: Backend,
+}
+
+/// Deposit transactions for the depositor to sign, in order, and what they will deliver
+#[derive(Debug, Clone)]
+pub struct PreparedDeposit {
+    pub quote: Quote,
+    pub txs: Vec<UnsignedTx>,
+}
+
+/// Quotes, deposits, tracking and withdrawals across the configured bridges
+pub struct BridgeClient {
+    tracer: Tracer,
+    chains: Vec<Chain>,
+}
+
+impl BridgeClient {
+    /// Connect to every bridge deployment and resolve the chain ID it reports
+    pub async fn connect(endpoints: &[TraceEndpoint]) -> Result<Self> {
+        let tracer = Tracer::connect(endpoints).await?;
+        let mut chains = Vec::with_capacity(endpoints.len());
+        for ((id, kind), endpoint) in tracer.chains().into_iter().zip(endpoints) {
+            let backend = match endpoint {
+                TraceEndpoint::Evm { rpc_url, bridge } => {
+                    Backend::Evm(EvmBackend::new(rpc_url, *bridge)?)
+                }
+                TraceEndpoint::Terra { lcd_url, bridge } => {
+                    Backend::Terra(TerraBackend::new(lcd_url, bridge))
+                }
+                TraceEndpoint::Solana {
+                    rpc_url,
+                    program_id,
+                } => Backend::Solana(SolanaBackend::new(rpc_url, *program_id)),
+            };
+            chains.push(Chain { id, kind, backend });
+        }
+        Ok(Self { tracer, chains })
+    }
+
+    /// Chain ID and family of every configured bridge
+    pub fn chains(&self) -> Vec<(ChainId, ChainKind)> {
+        self.chains.iter().map(|c| (c.id, c.kind)).collect()
+    }
+
+    fn chain(&self, id: &ChainId) -> Result<&Chain> {
+        self.chains
+            .iter()
+            .find(|c| c.id == *id)
+            .ok_or_else(|| eyre!("Chain {} is not configured", id.to_hex()))
+    }
+
+    /// Quote a deposit of `amount` (source decimals) of `token` at the standard fee rate
+    pub async fn quote(&self, route: Route, token: &Asset, amount: u128) -> Result<Quote> {
+        self.quote_for(route, token, amount, None).await
+    }
+
+    /// Quote a deposit made by `depositor`, whose custom fee (and on EVM and Terra, CL8Y holder
+    /// discount) applies
+    pub async fn quote_for(
+        &self,
+        route: Route,
+        token: &Asset,
+        amount: u128,
+        depositor: Option<&str>,
+    ) -> Result<Quote> {
+        if route.src_chain == route.dest_chain {
+            return Err(eyre!("Source and destination chain are the same"));
+        }
+        match &self.chain(&route.src_chain)?.backend {
+            Backend::Evm(evm) => evm.quote(route, token, amount, depositor).await,
+            Backend::Terra(terra) => terra.quote(route, token, amount, depositor).await,
+            Backend::Solana(solana) => {
+                let depositor = depositor.map(solana_account).transpose()?;
+                solana.quote(route, token, amount, depositor.as_ref()).await
+            }
+        }
+    }
+
+    /// Build the deposit transactions for `depositor` sending `amount` of `token` to
+    /// `dest_account` (in the destination chain's native format)
+    pub async fn deposit(
+        &self,
+        route: Route,
+        token: &Asset,
+        amount: u128,
+        depositor: &str,
+        dest_account: &str,
+    ) -> Result<PreparedDeposit> {
+        let dest_kind = self.chain(&route.dest_chain)?.kind;
+        let dest_account = encode_account(dest_kind, dest_account)?;
+        let quote = self
+            .quote_for(route, token, amount, Some(depositor))
+            .await?;
+
+        let dest_chain = route.dest_chain;
+        let txs = match &self.chain(&route.src_chain)?.backend {
+            Backend::Evm(evm) => evm.deposit(dest_chain, token, amount, dest_account).await?,
+            Backend::Terra(terra) => {
+                terra
+                    .deposit(dest_chain, token, amount, dest_account)
+                    .await?
+            }
+            Backend::Solana(solana) => {
+                let depositor = solana_account(depositor)?;
+                solana
+                    .deposit(dest_chain, token, amount, dest_account, &depositor)
+                    .await?
+            }
+        };
+        Ok(PreparedDeposit { quote, txs })
+    }
+
+    /// The transfer created by a source-chain deposit transaction (EVM tx hash, Terra tx hash
+    /// or Solana signature)
+    pub async fn transfer_from_tx(&self, src_tx: &str) -> Result<Transfer> {
+        let trace = self
+            .tracer
+            .trace(&TraceTarget::SourceTx(src_tx.to_string()))
+            .await?;
+        let deposit = trace
+            .deposit
+            .as_ref()
+            .ok_or_else(|| eyre!("No deposit found in {}", src_tx))?;
+        Transfer::try_from(deposit)
+    }
+
+    /// Current state of a transfer, including the destination `PendingWithdraw`
+    pub async fn track(&self, xchain_hash_id: [u8; 32]) -> Result<TransferTrace> {
+        self.tracer
+            .trace(&TraceTarget::XchainHashId(xchain_hash_id))
+            .await
+    }
+
+    /// Poll [`track`](Self::track) every `poll_interval` until `until` accepts the status, or
+    /// fail after `timeout`
+    pub async fn wait_for(
+        &self,
+        xchain_hash_id: [u8; 32],
+        until: impl Fn(TransferStatus) -> bool,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<TransferTrace> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let trace = self.track(xchain_hash_id).await?;
+            let status = trace.status();
+            if until(status) {
+                return Ok(trace);
+            }
+            if Instant::now() + poll_interval > deadline {
+                return Err(eyre!(
+                    "Transfer 0x{} still {} after {:?}",
+                    hex::encode(xchain_hash_id),
+                    status,
+                    timeout
+                ));
+            }
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+
+    /// Build `withdraw_submit` on the destination, sent by `sender` with `operator_tip` (wei,
+    /// uluna or lamports) attached for the operator
+    pub async fn withdraw_submit_tx(
+        &self,
+        transfer: &Transfer,
+        operator_tip: u128,
+        sender: &str,
+    ) -> Result<UnsignedTx> {
+        match &self.chain(&transfer.dest_chain)?.backend {
+            Backend::Evm(evm) => Ok(evm.withdraw_submit(transfer, operator_tip)),
+            Backend::Terra(terra) => terra.withdraw_submit(transfer, operator_tip).await,
+            Backend::Solana(solana) => {
+                solana
+                    .withdraw_submit(transfer, operator_tip, &solana_account(sender)?)
+                    .await
+            }
+        }
+    }
+
+    /// Build the destination execute for an approved withdrawal past its cancel window.
+    /// On Solana `sender` must be the recipient.
+    pub async fn withdraw_execute_tx(
+        &self,
+        transfer: &Transfer,
+        sender: &str,
+    ) -> Result<Vec<UnsignedTx>> {
+        let hash = transfer.xchain_hash_id();
+        let tx = match &self.chain(&transfer.dest_chain)?.backend {
+            Backend::Evm(evm) => evm.withdraw_execute(hash).await?,
+            Backend::Terra(terra) => terra.withdraw_execute(hash).await?,
+            Backend::Solana(solana) => {
+                solana
+                    .withdraw_execute(hash, &solana_account(sender)?)
+                    .await?
+            }
+        };
+        Ok(vec![tx])
+    }
+
+    /// Submit the withdrawal on the destination with `signer`; returns the transaction hash
+    pub async fn submit_withdraw(
+        &self,
+        signer: &dyn TxSigner,
+        transfer: &Transfer,
+        operator_tip: u128,
+    ) -> Result<String> {
+        let tx = self
+            .withdraw_submit_tx(transfer, operator_tip, &signer.account())
+            .await?;
+        signer.send(tx).await
+    }
+
+    /// Execute the withdrawal on the destination with `signer` once it is
+    /// [`Executable`](TransferStatus::Executable); returns the transaction hash
+    pub async fn execute_withdraw(
+        &self,
+        signer: &dyn TxSigner,
+        transfer: &Transfer,
+    ) -> Result<String> {
+        let trace = self.track(transfer.xchain_hash_id()).await?;
+        let status = trace.status();
+        if status != TransferStatus::Executable {
+            return Err(eyre!(
+                "Transfer 0x{} is not executable: {}",
+                hex::encode(transfer.xchain_hash_id()),
+                status
+            ));
+        }
+        let txs = self
+            .withdraw_execute_tx(transfer, &signer.account())
+            .await?;
+        send_all(signer, txs)
+            .await?
+            .pop()
+            .ok_or_else(|| eyre!("No execute transaction was built"))
+    }
+}
+
+fn solana_account(account: &str) -> Result<Pubkey> {
+    Pubkey::from_str(account).map_err(|e| eyre!("Invalid Solana account {}: {}", account, e))
+}
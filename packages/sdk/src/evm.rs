@@ -0,0 +1,262 @@
+//! EVM bridge backend
+//!
+//! Native deposits are bridged as the bridge's `wrappedNative` token, so quotes for
+//! [`Asset::Native`] read that token's mapping and decimals.
+
+use alloy::primitives::{Address, FixedBytes, U256};
+use alloy::sol_types::SolCall;
+use eyre::{eyre, Result};
+use multichain_rs::address_codec::parse_evm_address;
+use multichain_rs::evm::contracts::{Bridge, TokenRegistry, ERC20};
+use multichain_rs::evm::EvmQueryClient;
+use multichain_rs::hash::bytes32_to_address;
+use multichain_rs::types::ChainId;
+
+use crate::asset::Asset;
+use crate::quote::{Quote, Route};
+use crate::signer::UnsignedTx;
+use crate::transfer::Transfer;
+
+/// `TokenRegistry` token type of mint/burn tokens (lock/unlock is 0)
+const TOKEN_TYPE_MINT_BURN: u8 = 1;
+
+pub(crate) struct EvmBackend {
+    bridge: Address,
+    client: EvmQueryClient,
+}
+
+impl EvmBackend {
+    pub(crate) fn new(rpc_url: &str, bridge: Address) -> Result<Self> {
+        Ok(Self {
+            bridge,
+            client: EvmQueryClient::new(rpc_url, bridge, 0)?,
+        })
+    }
+
+    /// ERC20 the bridge locks or burns for `asset`
+    async fn local_token(&self, asset: &Asset) -> Result<Address> {
+        match asset {
+            Asset::Native => {
+                let bridge = Bridge::new(self.bridge, self.client.provider());
+                let token = bridge
+                    .wrappedNative()
+                    .call()
+                    .await
+                    .map_err(|e| eyre!("Failed to get wrapped native token: {}", e))?
+                    ._0;
+                if token == Address::ZERO {
+                    return Err(eyre!("Bridge {} has no wrapped native token", self.bridge));
+                }
+                Ok(token)
+            }
+            Asset::Token(token) => Ok(Address::from(parse_evm_address(token)?)),
+        }
+    }
+
+    pub(crate) async fn quote(
+        &self,
+        route: Route,
+        asset: &Asset,
+        amount: u128,
+        depositor: Option<&str>,
+    ) -> Result<Quote> {
+        let token = self.local_token(asset).await?;
+        let registry = TokenRegistry::new(
+            self.client.get_token_registry_address().await?,
+            self.client.provider(),
+        );
+        let mapping = registry
+            .getDestTokenMapping(token, FixedBytes(*route.dest_chain.as_bytes()))
+            .call()
+            .await
+            .map_err(|e| eyre!("Failed to get dest token mapping: {}", e))?;
+        if mapping.destToken == FixedBytes::ZERO {
+            return Err(eyre!(
+                "Token {} has no mapping to chain {}",
+                token,
+                route.dest_chain.to_hex()
+            ));
+        }
+
+        let src_decimals = ERC20::new(token, self.client.provider())
+            .decimals()
+            .call()
+            .await
+            .map_err(|e| eyre!("Failed to get decimals of {}: {}", token, e))?
+            ._0;
+
+        let depositor = match depositor {
+            Some(account) => Address::from(parse_evm_address(account)?),
+            None => Address::ZERO,
+        };
+        let fee = self
+            .client
+            .calculate_fee(depositor, U256::from(amount))
+            .await?;
+
+        Quote::new(
+            route,
+            amount,
+            fee.try_into()
+                .map_err(|_| eyre!("Fee {} does not fit in u128", fee))?,
+            mapping.destToken.0,
+            src_decimals,
+            mapping.destDecimals,
+        )
+    }
+
+    /// `depositNative`, or `approve` followed by `depositERC20` / `depositERC20Mintable`
+    pub(crate) async fn deposit(
+        &self,
+        dest_chain: ChainId,
+        asset: &Asset,
+        amount: u128,
+        dest_account: [u8; 32],
+    ) -> Result<Vec<UnsignedTx>> {
+        let dest_chain = FixedBytes(*dest_chain.as_bytes());
+        let dest_account = FixedBytes(dest_account);
+        let amount = U256::from(amount);
+
+        let Asset::Token(_) = asset else {
+            return Ok(vec![UnsignedTx::Evm {
+                to: self.bridge,
+                data: Bridge::depositNativeCall {
+                    destChain: dest_chain,
+                    destAccount: dest_account,
+                }
+                .abi_encode()
+                .into(),
+                value: amount,
+            }]);
+        };
+
+        let token = self.local_token(asset).await?;
+        let registry = self.client.get_token_registry_address().await?;
+        let data = if self.client.get_token_type(registry, token).await? == TOKEN_TYPE_MINT_BURN {
+            Bridge::depositERC20MintableCall {
+                token,
+                amount,
+                destChain: dest_chain,
+                destAccount: dest_account,
+            }
+            .abi_encode()
+        } else {
+            Bridge::depositERC20Call {
+                token,
+                amount,
+                destChain: dest_chain,
+                destAccount: dest_account,
+            }
+            .abi_encode()
+        };
+
+        Ok(vec![
+            approve_tx(token, self.bridge, amount),
+            UnsignedTx::Evm {
+                to: self.bridge,
+                data: data.into(),
+                value: U256::ZERO,
+            },
+        ])
+    }
+
+    /// `withdrawSubmit` with `operator_tip` wei attached as operator gas
+    pub(crate) fn withdraw_submit(&self, transfer: &Transfer, operator_tip: u128) -> UnsignedTx {
+        withdraw_submit_tx(self.bridge, transfer, operator_tip)
+    }
+
+    /// `withdrawExecuteUnlock` or `withdrawExecuteMint`, by the pending withdrawal's token type
+    pub(crate) async fn withdraw_execute(&self, xchain_hash_id: [u8; 32]) -> Result<UnsignedTx> {
+        let pending = self.client.get_pending_withdraw(xchain_hash_id).await?;
+        if pending.submitted_at.is_zero() {
+            return Err(eyre!(
+                "No pending withdrawal 0x{} on this bridge",
+                hex::encode(xchain_hash_id)
+            ));
+        }
+        let registry = self.client.get_token_registry_address().await?;
+        let hash = FixedBytes(xchain_hash_id);
+        let data =
+            if self.client.get_token_type(registry, pending.token).await? == TOKEN_TYPE_MINT_BURN {
+                Bridge::withdrawExecuteMintCall { xchainHashId: hash }.abi_encode()
+            } else {
+                Bridge::withdrawExecuteUnlockCall { xchainHashId: hash }.abi_encode()
+            };
+        Ok(UnsignedTx::Evm {
+            to: self.bridge,
+            data: data.into(),
+            value: U256::ZERO,
+        })
+    }
+}
+
+fn approve_tx(token: Address, spender: Address, amount: U256) -> UnsignedTx {
+    UnsignedTx::Evm {
+        to: token,
+        data: ERC20::approveCall { spender, amount }.abi_encode().into(),
+        value: U256::ZERO,
+    }
+}
+
+fn withdraw_submit_tx(bridge: Address, transfer: &Transfer, operator_tip: u128) -> UnsignedTx {
+    UnsignedTx::Evm {
+        to: bridge,
+        data: Bridge::withdrawSubmitCall {
+            srcChain: FixedBytes(*transfer.src_chain.as_bytes()),
+            srcAccount: FixedBytes(transfer.src_account),
+            destAccount: FixedBytes(transfer.dest_account),
+            token: Address::from(bytes32_to_address(&transfer.dest_token)),
+            amount: U256::from(transfer.amount),
+            nonce: transfer.nonce,
+        }
+        .abi_encode()
+        .into(),
+        value: U256::from(operator_tip),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use multichain_rs::hash::address_to_bytes32;
+
+    #[test]
+    fn test_withdraw_submit_calldata() {
+        let token = Address::repeat_byte(0xaa);
+        let transfer = Transfer {
+            src_chain: ChainId::from_u32(2),
+            dest_chain: ChainId::from_u32(1),
+            src_account: [1u8; 32],
+            dest_account: address_to_bytes32(&[2u8; 20]),
+            dest_token: address_to_bytes32(&token.0 .0),
+            amount: 5_000,
+            nonce: 9,
+        };
+        let bridge = Address::repeat_byte(0xbb);
+        let UnsignedTx::Evm { to, data, value } = withdraw_submit_tx(bridge, &transfer, 100) else {
+            panic!("expected an EVM transaction");
+        };
+        assert_eq!(to, bridge);
+        assert_eq!(value, U256::from(100u64));
+
+        let call = Bridge::withdrawSubmitCall::abi_decode(&data, true).unwrap();
+        assert_eq!(call.srcChain.0, *transfer.src_chain.as_bytes());
+        assert_eq!(call.token, token);
+        assert_eq!(call.destAccount.0, transfer.dest_account);
+        assert_eq!(call.amount, U256::from(5_000u64));
+        assert_eq!(call.nonce, 9);
+    }
+
+    #[test]
+    fn test_approve_targets_token() {
+        let token = Address::repeat_byte(1);
+        let spender = Address::repeat_byte(2);
+        let UnsignedTx::Evm { to, data, .. } = approve_tx(token, spender, U256::from(7u64)) else {
+            panic!("expected an EVM transaction");
+        };
+        assert_eq!(to, token);
+        let call = ERC20::approveCall::abi_decode(&data, true).unwrap();
+        assert_eq!(call.spender, spender);
+        assert_eq!(call.amount, U256::from(7u64));
+    }
+}
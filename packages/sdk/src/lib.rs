@@ -0,0 +1,46 @@
+//! CL8Y Bridge client SDK
+//!
+//! Supported entry point for wallets and dApps that move tokens over the bridge:
+//!
+//! - **Quote** - [`BridgeClient::quote`] reads the source chain's fee configuration and token
+//!   mapping and returns the fee, the hashed net amount and what the recipient receives after
+//!   decimal normalization
+//! - **Deposit** - [`BridgeClient::deposit`] builds the unsigned source-chain transactions
+//!   (approval + deposit on EVM, `deposit_native` / CW20 `send` on Terra, `deposit_native` /
+//!   `deposit_spl` on Solana)
+//! - **Track** - [`BridgeClient::track`] and [`BridgeClient::wait_for`] poll the destination
+//!   `PendingWithdraw` through the transfer tracer
+//! - **Withdraw** - [`BridgeClient::submit_withdraw`] and [`BridgeClient::execute_withdraw`]
+//!   complete the transfer on the destination chain
+//!
+//! The SDK never holds keys and never reads environment variables: every endpoint is passed to
+//! [`BridgeClient::connect`], and transactions are signed and broadcast by the integrator's
+//! [`TxSigner`].
+//!
+//! ## Usage
+//!
+//! ```toml
+//! [dependencies]
+//! cl8y-sdk = { path = "../sdk" }
+//! ```
+
+pub mod asset;
+pub mod client;
+pub mod quote;
+pub mod signer;
+pub mod transfer;
+
+mod evm;
+mod solana;
+mod terra;
+
+pub use asset::{decode_account, encode_account, Asset};
+pub use client::{BridgeClient, PreparedDeposit};
+pub use quote::{normalize_decimals, Quote, Route};
+pub use signer::{send_all, TxSigner, UnsignedTx};
+pub use transfer::Transfer;
+
+pub use multichain_rs::trace::{
+    ChainKind, TraceEndpoint as Endpoint, TransferStatus, TransferTrace,
+};
+pub use multichain_rs::types::ChainId;
@@ -0,0 +1,141 @@
+//! Deposit quotes
+//!
+//! The source chain takes its fee from the deposited amount and hashes the remainder in source
+//! decimals. The destination converts that amount to its own decimals when the withdrawal is
+//! executed (`_normalizeDecimals` on EVM), so the recipient receives
+//! `normalize_decimals(net_amount, src_decimals, dest_decimals)`.
+
+use eyre::{eyre, Result};
+use multichain_rs::types::ChainId;
+use serde::{Deserialize, Serialize};
+
+/// Source and destination chain of a transfer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Route {
+    pub src_chain: ChainId,
+    pub dest_chain: ChainId,
+}
+
+impl Route {
+    pub fn new(src_chain: ChainId, dest_chain: ChainId) -> Self {
+        Self {
+            src_chain,
+            dest_chain,
+        }
+    }
+}
+
+/// What a deposit of `amount` costs and delivers
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Quote {
+    pub route: Route,
+    /// Gross amount deposited, in source decimals
+    pub amount: u128,
+    /// Bridge fee taken on the source chain, in source decimals
+    pub fee: u128,
+    /// `amount - fee`: the amount recorded and hashed by the source chain
+    pub net_amount: u128,
+    /// Destination token as the bridge hashes it
+    pub dest_token: [u8; 32],
+    pub src_decimals: u8,
+    pub dest_decimals: u8,
+    /// Amount the recipient receives on the destination, in destination decimals
+    pub received: u128,
+}
+
+impl Quote {
+    pub(crate) fn new(
+        route: Route,
+        amount: u128,
+        fee: u128,
+        dest_token: [u8; 32],
+        src_decimals: u8,
+        dest_decimals: u8,
+    ) -> Result<Self> {
+        let net_amount = amount
+            .checked_sub(fee)
+            .ok_or_else(|| eyre!("Fee {} exceeds amount {}", fee, amount))?;
+        let received =
+            normalize_decimals(net_amount, src_decimals, dest_decimals).ok_or_else(|| {
+                eyre!(
+                    "Amount {} overflows when scaled from {} to {} decimals",
+                    net_amount,
+                    src_decimals,
+                    dest_decimals
+                )
+            })?;
+        Ok(Self {
+            route,
+            amount,
+            fee,
+            net_amount,
+            dest_token,
+            src_decimals,
+            dest_decimals,
+            received,
+        })
+    }
+}
+
+/// Convert `amount` from `src_decimals` to `dest_decimals` the way the destination does at
+/// execution: truncating division when scaling down, multiplication when scaling up.
+/// None if the result does not fit in a `u128`.
+pub fn normalize_decimals(amount: u128, src_decimals: u8, dest_decimals: u8) -> Option<u128> {
+    if src_decimals > dest_decimals {
+        let factor = 10u128.checked_pow((src_decimals - dest_decimals) as u32);
+        // A divisor beyond u128 truncates everything
+        Some(factor.map_or(0, |f| amount / f))
+    } else {
+        let factor = 10u128.checked_pow((dest_decimals - src_decimals) as u32)?;
+        amount.checked_mul(factor)
+    }
+}
+
+/// `floor(amount * fee_bps / 10000)`, the fee formula shared by all three bridges
+/// (split so that amounts near `u128::MAX` do not overflow)
+pub(crate) fn fee_from_bps(amount: u128, fee_bps: u64) -> u128 {
+    let bps = fee_bps as u128;
+    (amount / 10_000) * bps + (amount % 10_000) * bps / 10_000
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn route() -> Route {
+        Route::new(ChainId::from_u32(1), ChainId::from_u32(2))
+    }
+
+    #[test]
+    fn test_normalize_decimals() {
+        assert_eq!(normalize_decimals(1_500_000, 6, 6), Some(1_500_000));
+        assert_eq!(
+            normalize_decimals(1_500_000, 6, 18),
+            Some(1_500_000_000_000_000_000)
+        );
+        assert_eq!(
+            normalize_decimals(1_500_000_000_000_000_123, 18, 6),
+            Some(1_500_000)
+        );
+        assert_eq!(normalize_decimals(u128::MAX, 0, 18), None);
+        assert_eq!(normalize_decimals(u128::MAX, 255, 0), Some(0));
+    }
+
+    #[test]
+    fn test_quote_nets_fee_then_normalizes() {
+        let q = Quote::new(
+            route(),
+            1_000_000,
+            fee_from_bps(1_000_000, 30),
+            [7u8; 32],
+            6,
+            18,
+        )
+        .unwrap();
+        assert_eq!(q.fee, 3_000);
+        assert_eq!(q.net_amount, 997_000);
+        assert_eq!(q.received, 997_000_000_000_000_000);
+
+        assert!(Quote::new(route(), 10, 11, [0u8; 32], 6, 6).is_err());
+    }
+}
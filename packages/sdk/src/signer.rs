@@ -0,0 +1,49 @@
+//! Signing is the integrator's job
+//!
+//! The SDK only builds [`UnsignedTx`]s. A wallet, browser extension bridge or custody service
+//! implements [`TxSigner`] for each chain it signs on.
+
+use alloy::primitives::{Address, Bytes, U256};
+use async_trait::async_trait;
+use eyre::Result;
+use serde_json::Value;
+use solana_sdk::instruction::Instruction;
+
+/// A transaction ready to be signed by the account that will send it
+#[derive(Debug, Clone, PartialEq)]
+pub enum UnsignedTx {
+    /// Contract call; `value` is the native amount attached (wei)
+    Evm {
+        to: Address,
+        data: Bytes,
+        value: U256,
+    },
+    /// `MsgExecuteContract` with JSON `msg` and attached `(denom, amount)` funds
+    Terra {
+        contract: String,
+        msg: Value,
+        funds: Vec<(String, u128)>,
+    },
+    /// Instructions to send in one transaction, paid and signed by the signer
+    Solana { instructions: Vec<Instruction> },
+}
+
+/// Signs and broadcasts transactions for one account on one chain
+#[async_trait]
+pub trait TxSigner: Send + Sync {
+    /// The signing account in its chain's native format (`0x…`, `terra1…` or base58)
+    fn account(&self) -> String;
+
+    /// Sign and broadcast `tx`, returning the transaction hash or signature once it has been
+    /// included successfully
+    async fn send(&self, tx: UnsignedTx) -> Result<String>;
+}
+
+/// Send `txs` in order, stopping at the first failure; returns each transaction's hash
+pub async fn send_all(signer: &dyn TxSigner, txs: Vec<UnsignedTx>) -> Result<Vec<String>> {
+    let mut hashes = Vec::with_capacity(txs.len());
+    for tx in txs {
+        hashes.push(signer.send(tx).await?);
+    }
+    Ok(hashes)
+}
@@ -0,0 +1,601 @@
+//! Solana bridge backend
+//!
+//! Instructions are built by hand in the program's Anchor account order. Trailing optional
+//! accounts (`fee_schedule`, `cl8y_token_account`) are omitted, so deposits built here never
+//! claim the CL8Y holder discount and quotes ignore it too. Token-2022 transfer fees are not
+//! included in quotes.
+
+use std::sync::Arc;
+
+use base64::Engine;
+use eyre::{eyre, Result};
+use multichain_rs::solana::{
+    associated_token_address, bridge_config_pda, chain_entry_pda, chain_withdraw_delay_pda,
+    custom_account_fee_pda, deposit_record_pda, executed_hash_pda, large_transfer_delay_pda,
+    nonce_bitmap_pda, parse_bridge_config, parse_fee_bps_override, parse_pending_withdraw,
+    parse_token_mapping, parse_withdraw_sponsorship, pending_withdraw_pda, token_fee_override_pda,
+    withdraw_sponsorship_pda, SolanaFeeCalculator, SolanaFeeInputs, SolanaFeeParams,
+    SolanaPendingWithdraw, SolanaTokenMapping, ASSOCIATED_TOKEN_PROGRAM_ID,
+    TOKEN_MAPPING_ACCOUNT_LEN, TOKEN_MAPPING_DEST_CHAIN_OFFSET, TOKEN_MAPPING_LOCAL_MINT_OFFSET,
+};
+use multichain_rs::types::ChainId;
+use solana_account_decoder_client_types::UiAccountEncoding;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
+use solana_client::rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType};
+use solana_sdk::account::Account;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::hash::hash;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::system_program;
+
+use crate::asset::Asset;
+use crate::quote::{Quote, Route};
+use crate::signer::UnsignedTx;
+use crate::transfer::Transfer;
+
+const B64: base64::engine::GeneralPurpose = base64::engine::general_purpose::STANDARD;
+
+/// Seed of the per-mint `WithdrawRateLimit` PDA (native SOL uses the all-zero mint)
+const WITHDRAW_RATE_LIMIT_SEED: &[u8] = b"w_rate_lim";
+
+pub(crate) struct SolanaBackend {
+    client: Arc<RpcClient>,
+    program_id: Pubkey,
+}
+
+impl SolanaBackend {
+    pub(crate) fn new(rpc_url: &str, program_id: Pubkey) -> Self {
+        Self {
+            client: Arc::new(RpcClient::new_with_commitment(
+                rpc_url.to_string(),
+                CommitmentConfig::confirmed(),
+            )),
+            program_id,
+        }
+    }
+
+    /// The `TokenMapping` pairing `local_mint` with `remote_chain`; it serves deposits to and
+    /// withdrawals from that chain
+    async fn token_mapping(
+        &self,
+        local_mint: &Pubkey,
+        remote_chain: &[u8; 4],
+    ) -> Result<(Pubkey, SolanaTokenMapping)> {
+        let memcmp = |offset: usize, bytes: &[u8]| {
+            RpcFilterType::Memcmp(Memcmp::new(
+                offset,
+                MemcmpEncodedBytes::Base64(B64.encode(bytes)),
+            ))
+        };
+        let config = RpcProgramAccountsConfig {
+            filters: Some(vec![
+                RpcFilterType::DataSize(TOKEN_MAPPING_ACCOUNT_LEN),
+                memcmp(0, &discriminator("account:TokenMapping")),
+                memcmp(TOKEN_MAPPING_LOCAL_MINT_OFFSET, local_mint.as_ref()),
+                memcmp(TOKEN_MAPPING_DEST_CHAIN_OFFSET, remote_chain),
+            ]),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let (pubkey, account) = self
+            .client
+            .get_program_accounts_with_config(&self.program_id, config)
+            .await
+            .map_err(|e| eyre!("getProgramAccounts for TokenMapping failed: {}", e))?
+            .into_iter()
+            .next()
+            .ok_or_else(|| {
+                eyre!(
+                    "Mint {} has no mapping for chain 0x{}",
+                    local_mint,
+                    hex::encode(remote_chain)
+                )
+            })?;
+        Ok((pubkey, parse_token_mapping(&account.data)?))
+    }
+
+    async fn accounts(&self, keys: &[Pubkey]) -> Result<Vec<Option<Account>>> {
+        self.client
+            .get_multiple_accounts(keys)
+            .await
+            .map_err(|e| eyre!("Failed to read Solana accounts: {}", e))
+    }
+
+    /// Owner program of `mint` (SPL Token or Token-2022)
+    async fn token_program(&self, mint: &Pubkey) -> Result<Pubkey> {
+        Ok(self
+            .client
+            .get_account(mint)
+            .await
+            .map_err(|e| eyre!("Failed to read mint {}: {}", mint, e))?
+            .owner)
+    }
+
+    /// `fee_bps` of a fee override PDA, if it exists and belongs to the program
+    fn fee_override(&self, account: Option<&Account>) -> Option<u16> {
+        account
+            .filter(|a| a.owner == self.program_id)
+            .and_then(|a| parse_fee_bps_override(&a.data))
+    }
+
+    /// Quote with the bridge rate, the mapping's override and the depositor's custom fee
+    pub(crate) async fn quote(
+        &self,
+        route: Route,
+        asset: &Asset,
+        amount: u128,
+        depositor: Option<&Pubkey>,
+    ) -> Result<Quote> {
+        let mint = asset.solana_mint()?;
+        let (mapping_pda, mapping) = self
+            .token_mapping(&mint, route.dest_chain.as_bytes())
+            .await?;
+        let amount_u64: u64 = amount
+            .try_into()
+            .map_err(|_| eyre!("Amount {} exceeds a Solana u64 amount", amount))?;
+
+        let mut keys = vec![
+            bridge_config_pda(&self.program_id),
+            token_fee_override_pda(&self.program_id, &mapping_pda),
+        ];
+        if let Some(depositor) = depositor {
+            keys.push(custom_account_fee_pda(&self.program_id, depositor));
+        }
+        let accounts = self.accounts(&keys).await?;
+        let bridge = accounts[0]
+            .as_ref()
+            .ok_or_else(|| eyre!("Bridge config {} not found", keys[0]))?;
+        let config = parse_bridge_config(&bridge.data)?;
+
+        let calculator = SolanaFeeCalculator::new(SolanaFeeParams {
+            fee_bps: config.fee_bps,
+            ..Default::default()
+        });
+        let fee = calculator.quote(
+            amount_u64,
+            &SolanaFeeInputs {
+                token_override_bps: self.fee_override(accounts[1].as_ref()),
+                custom_fee_bps: self.fee_override(accounts.get(2).and_then(Option::as_ref)),
+                ..Default::default()
+            },
+        );
+
+        Quote::new(
+            route,
+            amount,
+            fee.fee as u128,
+            mapping.dest_token,
+            mapping.decimals,
+            mapping.src_decimals,
+        )
+    }
+
+    /// `deposit_native` or `deposit_spl`. The deposit record PDA is derived from the bridge's
+    /// current nonce, so a deposit by someone else landing first makes this one fail; rebuild
+    /// and resend it.
+    pub(crate) async fn deposit(
+        &self,
+        dest_chain: ChainId,
+        asset: &Asset,
+        amount: u128,
+        dest_account: [u8; 32],
+        depositor: &Pubkey,
+    ) -> Result<Vec<UnsignedTx>> {
+        let amount: u64 = amount
+            .try_into()
+            .map_err(|_| eyre!("Amount {} exceeds a Solana u64 amount", amount))?;
+        let mint = asset.solana_mint()?;
+        let (mapping_pda, _) = self.token_mapping(&mint, dest_chain.as_bytes()).await?;
+
+        let bridge_pda = bridge_config_pda(&self.program_id);
+        let bridge = self
+            .client
+            .get_account(&bridge_pda)
+            .await
+            .map_err(|e| eyre!("Failed to read bridge config {}: {}", bridge_pda, e))?;
+        let nonce = parse_bridge_config(&bridge.data)?.deposit_nonce + 1;
+
+        let keys = DepositKeys {
+            program_id: self.program_id,
+            mapping: mapping_pda,
+            deposit_record: deposit_record_pda(&self.program_id, nonce),
+            depositor: *depositor,
+        };
+        let instruction = match asset {
+            Asset::Native => keys.native(dest_chain, dest_account, amount),
+            Asset::Token(_) => {
+                let token_program = self.token_program(&mint).await?;
+                keys.spl(dest_chain, dest_account, amount, &mint, &token_program)
+            }
+        };
+        Ok(vec![UnsignedTx::Solana {
+            instructions: vec![instruction],
+        }])
+    }
+
+    /// `withdraw_submit` paid by `payer`, escrowing `operator_tip` lamports for the operator
+    pub(crate) async fn withdraw_submit(
+        &self,
+        transfer: &Transfer,
+        operator_tip: u128,
+        payer: &Pubkey,
+    ) -> Result<UnsignedTx> {
+        let operator_gas: u64 = operator_tip
+            .try_into()
+            .map_err(|_| eyre!("Operator tip {} exceeds u64 lamports", operator_tip))?;
+        let dest_token = Pubkey::new_from_array(transfer.dest_token);
+        let (mapping_pda, mapping) = self
+            .token_mapping(&dest_token, transfer.src_chain.as_bytes())
+            .await?;
+        Ok(UnsignedTx::Solana {
+            instructions: vec![withdraw_submit_ix(
+                &self.program_id,
+                transfer,
+                &mapping.dest_token,
+                &mapping_pda,
+                payer,
+                operator_gas,
+            )],
+        })
+    }
+
+    /// `withdraw_execute_native` or `withdraw_execute` (after creating the recipient's token
+    /// account if needed). The program requires the recipient to sign.
+    pub(crate) async fn withdraw_execute(
+        &self,
+        xchain_hash_id: [u8; 32],
+        recipient: &Pubkey,
+    ) -> Result<UnsignedTx> {
+        let pending_pda = pending_withdraw_pda(&self.program_id, &xchain_hash_id);
+        let sponsorship_pda = withdraw_sponsorship_pda(&self.program_id, &xchain_hash_id);
+        let mut accounts = self.accounts(&[pending_pda, sponsorship_pda]).await?;
+        let pending = accounts[0].take().ok_or_else(|| {
+            eyre!(
+                "No pending withdrawal 0x{} on this bridge",
+                hex::encode(xchain_hash_id)
+            )
+        })?;
+        let pw = parse_pending_withdraw(&pending.data)?;
+        if pw.dest_account != recipient.to_bytes() {
+            return Err(eyre!(
+                "Only the recipient {} can execute this withdrawal",
+                Pubkey::new_from_array(pw.dest_account)
+            ));
+        }
+
+        let keys = ExecuteKeys {
+            program_id: self.program_id,
+            pending: pending_pda,
+            executed: executed_hash_pda(&self.program_id, &xchain_hash_id),
+            recipient: *recipient,
+        };
+        let instructions = if pw.token == [0u8; 32] {
+            vec![keys.native(&pw)]
+        } else {
+            // A relayed withdraw pays its SPL relay fee to the relaying operator's token account
+            let relayer = match &accounts[1] {
+                Some(account) if account.owner == self.program_id => {
+                    Some(parse_withdraw_sponsorship(&account.data)?)
+                        .filter(|s| s.relay_fee > 0)
+                        .map(|s| Pubkey::new_from_array(s.operator))
+                }
+                _ => None,
+            };
+            let mint = Pubkey::new_from_array(pw.token);
+            let (mapping_pda, _) = self.token_mapping(&mint, &pw.src_chain).await?;
+            let token_program = self.token_program(&mint).await?;
+            vec![
+                create_ata_idempotent_ix(recipient, &mint, &token_program),
+                keys.spl(&pw, &mint, &mapping_pda, &token_program, relayer.as_ref()),
+            ]
+        };
+        Ok(UnsignedTx::Solana { instructions })
+    }
+}
+
+/// Anchor discriminator: first 8 bytes of `sha256("global:<ix>")` or `sha256("account:<Type>")`
+fn discriminator(preimage: &str) -> [u8; 8] {
+    let mut disc = [0u8; 8];
+    disc.copy_from_slice(&hash(preimage.as_bytes()).to_bytes()[..8]);
+    disc
+}
+
+fn rate_limit_pda(program_id: &Pubkey, mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[WITHDRAW_RATE_LIMIT_SEED, mint.as_ref()], program_id).0
+}
+
+/// Accounts shared by `deposit_native` and `deposit_spl`
+struct DepositKeys {
+    program_id: Pubkey,
+    mapping: Pubkey,
+    deposit_record: Pubkey,
+    depositor: Pubkey,
+}
+
+impl DepositKeys {
+    fn data(name: &str, dest_chain: ChainId, dest_account: [u8; 32], amount: u64) -> Vec<u8> {
+        let mut data = discriminator(&format!("global:{}", name)).to_vec();
+        data.extend_from_slice(dest_chain.as_bytes());
+        data.extend_from_slice(&dest_account);
+        data.extend_from_slice(&amount.to_le_bytes());
+        data
+    }
+
+    fn native(&self, dest_chain: ChainId, dest_account: [u8; 32], amount: u64) -> Instruction {
+        let p = &self.program_id;
+        Instruction {
+            program_id: *p,
+            accounts: vec![
+                AccountMeta::new(bridge_config_pda(p), false),
+                AccountMeta::new(self.deposit_record, false),
+                AccountMeta::new_readonly(chain_entry_pda(p, dest_chain.as_bytes()), false),
+                AccountMeta::new_readonly(self.mapping, false),
+                AccountMeta::new(self.depositor, true),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(token_fee_override_pda(p, &self.mapping), false),
+                AccountMeta::new_readonly(custom_account_fee_pda(p, &self.depositor), false),
+            ],
+            data: Self::data("deposit_native", dest_chain, dest_account, amount),
+        }
+    }
+
+    fn spl(
+        &self,
+        dest_chain: ChainId,
+        dest_account: [u8; 32],
+        amount: u64,
+        mint: &Pubkey,
+        token_program: &Pubkey,
+    ) -> Instruction {
+        let p = &self.program_id;
+        let bridge = bridge_config_pda(p);
+        Instruction {
+            program_id: *p,
+            accounts: vec![
+                AccountMeta::new(bridge, false),
+                AccountMeta::new(self.deposit_record, false),
+                AccountMeta::new(self.mapping, false),
+                AccountMeta::new(*mint, false),
+                AccountMeta::new(
+                    associated_token_address(&self.depositor, mint, token_program),
+                    false,
+                ),
+                AccountMeta::new(
+                    associated_token_address(&bridge, mint, token_program),
+                    false,
+                ),
+                AccountMeta::new_readonly(chain_entry_pda(p, dest_chain.as_bytes()), false),
+                AccountMeta::new(self.depositor, true),
+                AccountMeta::new_readonly(*token_program, false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(token_fee_override_pda(p, &self.mapping), false),
+                AccountMeta::new_readonly(custom_account_fee_pda(p, &self.depositor), false),
+            ],
+            data: Self::data("deposit_spl", dest_chain, dest_account, amount),
+        }
+    }
+}
+
+fn withdraw_submit_ix(
+    program_id: &Pubkey,
+    transfer: &Transfer,
+    src_token: &[u8; 32],
+    mapping: &Pubkey,
+    payer: &Pubkey,
+    operator_gas: u64,
+) -> Instruction {
+    let hash = transfer.xchain_hash_id();
+    let src_chain = transfer.src_chain.as_bytes();
+
+    let mut data = discriminator("global:withdraw_submit").to_vec();
+    data.extend_from_slice(src_chain);
+    data.extend_from_slice(&transfer.src_account);
+    data.extend_from_slice(src_token);
+    data.extend_from_slice(&transfer.dest_token);
+    data.extend_from_slice(&transfer.dest_account);
+    data.extend_from_slice(&transfer.amount.to_le_bytes());
+    data.extend_from_slice(&transfer.nonce.to_le_bytes());
+    data.extend_from_slice(&operator_gas.to_le_bytes());
+
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(bridge_config_pda(program_id), false),
+            AccountMeta::new_readonly(chain_entry_pda(program_id, src_chain), false),
+            AccountMeta::new_readonly(*mapping, false),
+            AccountMeta::new(pending_withdraw_pda(program_id, &hash), false),
+            AccountMeta::new_readonly(executed_hash_pda(program_id, &hash), false),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(
+                nonce_bitmap_pda(program_id, src_chain, transfer.nonce),
+                false,
+            ),
+        ],
+        data,
+    }
+}
+
+/// Accounts shared by `withdraw_execute_native` and `withdraw_execute`
+struct ExecuteKeys {
+    program_id: Pubkey,
+    pending: Pubkey,
+    executed: Pubkey,
+    recipient: Pubkey,
+}
+
+impl ExecuteKeys {
+    fn native(&self, pw: &SolanaPendingWithdraw) -> Instruction {
+        let p = &self.program_id;
+        let native = Pubkey::default();
+        Instruction {
+            program_id: *p,
+            accounts: vec![
+                AccountMeta::new(bridge_config_pda(p), false),
+                AccountMeta::new(self.pending, false),
+                AccountMeta::new(self.executed, false),
+                AccountMeta::new(rate_limit_pda(p, &native), false),
+                AccountMeta::new(self.recipient, true),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(chain_withdraw_delay_pda(p, &pw.src_chain), false),
+                AccountMeta::new_readonly(
+                    large_transfer_delay_pda(p, &pw.src_chain, &native),
+                    false,
+                ),
+                AccountMeta::new(withdraw_sponsorship_pda(p, &pw.transfer_hash), false),
+            ],
+            data: discriminator("global:withdraw_execute_native").to_vec(),
+        }
+    }
+
+    fn spl(
+        &self,
+        pw: &SolanaPendingWithdraw,
+        mint: &Pubkey,
+        mapping: &Pubkey,
+        token_program: &Pubkey,
+        relayer: Option<&Pubkey>,
+    ) -> Instruction {
+        let p = &self.program_id;
+        let bridge = bridge_config_pda(p);
+        let mut ix = Instruction {
+            program_id: *p,
+            accounts: vec![
+                AccountMeta::new_readonly(bridge, false),
+                AccountMeta::new(self.pending, false),
+                AccountMeta::new(self.executed, false),
+                AccountMeta::new(*mint, false),
+                AccountMeta::new(
+                    associated_token_address(&self.recipient, mint, token_program),
+                    false,
+                ),
+                AccountMeta::new(
+                    associated_token_address(&bridge, mint, token_program),
+                    false,
+                ),
+                AccountMeta::new_readonly(*mapping, false),
+                AccountMeta::new(rate_limit_pda(p, mint), false),
+                AccountMeta::new(self.recipient, true),
+                AccountMeta::new_readonly(*token_program, false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(chain_withdraw_delay_pda(p, &pw.src_chain), false),
+                AccountMeta::new_readonly(large_transfer_delay_pda(p, &pw.src_chain, mint), false),
+                AccountMeta::new(withdraw_sponsorship_pda(p, &pw.transfer_hash), false),
+            ],
+            data: discriminator("global:withdraw_execute").to_vec(),
+        };
+        if let Some(relayer) = relayer {
+            ix.accounts.push(AccountMeta::new(
+                associated_token_address(relayer, mint, token_program),
+                false,
+            ));
+        }
+        ix
+    }
+}
+
+/// Associated Token Account program `CreateIdempotent` (instruction 1)
+fn create_ata_idempotent_ix(owner: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: ASSOCIATED_TOKEN_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*owner, true),
+            AccountMeta::new(associated_token_address(owner, mint, token_program), false),
+            AccountMeta::new_readonly(*owner, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(*token_program, false),
+        ],
+        data: vec![1],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_withdraw_submit_layout() {
+        let program_id = Pubkey::new_unique();
+        let transfer = Transfer {
+            src_chain: ChainId::from_u32(1),
+            dest_chain: ChainId::from_u32(3),
+            src_account: [1u8; 32],
+            dest_account: Pubkey::new_unique().to_bytes(),
+            dest_token: Pubkey::new_unique().to_bytes(),
+            amount: 10u128.pow(20),
+            nonce: 7,
+        };
+        let payer = Pubkey::new_unique();
+        let ix = withdraw_submit_ix(
+            &program_id,
+            &transfer,
+            &[9u8; 32],
+            &Pubkey::new_unique(),
+            &payer,
+            5_000,
+        );
+
+        // disc + src_chain + 4 x 32 bytes + u128 + u64 + u64
+        assert_eq!(ix.data.len(), 8 + 4 + 128 + 16 + 8 + 8);
+        assert_eq!(&ix.data[..8], &discriminator("global:withdraw_submit"));
+        assert_eq!(&ix.data[44..76], &[9u8; 32]);
+        assert_eq!(&ix.data[140..156], &transfer.amount.to_le_bytes());
+        assert_eq!(&ix.data[164..172], &5_000u64.to_le_bytes());
+
+        assert_eq!(
+            ix.accounts[3].pubkey,
+            pending_withdraw_pda(&program_id, &transfer.xchain_hash_id())
+        );
+        assert!(ix.accounts[5].is_signer && ix.accounts[5].pubkey == payer);
+        assert_eq!(
+            ix.accounts[7].pubkey,
+            nonce_bitmap_pda(&program_id, transfer.src_chain.as_bytes(), 7)
+        );
+    }
+
+    #[test]
+    fn test_deposit_data_layout() {
+        let data = DepositKeys::data("deposit_spl", ChainId::from_u32(2), [4u8; 32], 1_000);
+        assert_eq!(data.len(), 8 + 4 + 32 + 8);
+        assert_eq!(&data[8..12], ChainId::from_u32(2).as_bytes());
+        assert_eq!(&data[44..], &1_000u64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_native_execute_uses_native_rate_limit() {
+        let program_id = Pubkey::new_unique();
+        let keys = ExecuteKeys {
+            program_id,
+            pending: Pubkey::new_unique(),
+            executed: Pubkey::new_unique(),
+            recipient: Pubkey::new_unique(),
+        };
+        let pw = SolanaPendingWithdraw {
+            transfer_hash: [0u8; 32],
+            src_chain: [0, 0, 0, 1],
+            src_account: [0u8; 32],
+            dest_account: keys.recipient.to_bytes(),
+            token: [0u8; 32],
+            amount: 1,
+            nonce: 1,
+            src_decimals: 18,
+            dest_decimals: 9,
+            approved: true,
+            approved_at: 0,
+            cancelled: false,
+            executed: false,
+        };
+        let ix = keys.native(&pw);
+        assert_eq!(
+            ix.accounts[3].pubkey,
+            rate_limit_pda(&program_id, &Pubkey::default())
+        );
+        assert!(ix.accounts[4].is_signer);
+    }
+}
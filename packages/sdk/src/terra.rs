@@ -0,0 +1,352 @@
+//! Terra Classic bridge backend
+//!
+//! Messages follow the bridge contract's `ExecuteMsg` / `ReceiveMsg`. CW20 deposits go through
+//! the token's `send` with a `deposit_cw20_lock` or `deposit_cw20_mintable_burn` hook.
+
+use base64::Engine;
+use eyre::{eyre, Result};
+use multichain_rs::hash::{decode_bytes32_to_terra_address, encode_terra_token_address};
+use multichain_rs::terra::tokens::build_cw20_send_msg;
+use multichain_rs::terra::TerraQueryClient;
+use multichain_rs::types::ChainId;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::asset::{is_cw20, Asset, TERRA_NATIVE_DENOM};
+use crate::quote::{fee_from_bps, Quote, Route};
+use crate::signer::UnsignedTx;
+use crate::transfer::Transfer;
+
+const B64: base64::engine::GeneralPurpose = base64::engine::general_purpose::STANDARD;
+
+/// Page size for `incoming_token_mappings` (the contract caps it at 100)
+const MAPPINGS_PAGE: u32 = 100;
+
+#[derive(Deserialize)]
+struct TokenDestMapping {
+    dest_token: String,
+    dest_decimals: u8,
+}
+
+#[derive(Deserialize)]
+struct TokenInfo {
+    terra_decimals: u8,
+}
+
+#[derive(Deserialize)]
+struct CalculateFee {
+    fee_amount: String,
+}
+
+#[derive(Deserialize)]
+struct FeeConfig {
+    standard_fee_bps: u64,
+}
+
+#[derive(Deserialize)]
+struct IncomingTokenMapping {
+    src_chain: String,
+    src_token: String,
+    local_token: String,
+    enabled: bool,
+}
+
+#[derive(Deserialize)]
+struct IncomingTokenMappings {
+    mappings: Vec<IncomingTokenMapping>,
+}
+
+pub(crate) struct TerraBackend {
+    client: TerraQueryClient,
+}
+
+impl TerraBackend {
+    pub(crate) fn new(lcd_url: &str, bridge: &str) -> Self {
+        Self {
+            client: TerraQueryClient::new(lcd_url, bridge),
+        }
+    }
+
+    fn bridge(&self) -> &str {
+        self.client.bridge_address()
+    }
+
+    async fn query<R: for<'de> Deserialize<'de>>(&self, msg: Value) -> Result<R> {
+        self.client.query_contract(self.bridge(), &msg).await
+    }
+
+    /// Quote from `token_dest_mapping` and `calculate_fee`. Without a depositor the standard
+    /// rate applies (custom fees and the CL8Y discount are per account).
+    pub(crate) async fn quote(
+        &self,
+        route: Route,
+        asset: &Asset,
+        amount: u128,
+        depositor: Option<&str>,
+    ) -> Result<Quote> {
+        let token = asset.terra_token();
+        let mapping: Option<TokenDestMapping> = self
+            .query(json!({
+                "token_dest_mapping": {
+                    "token": token,
+                    "dest_chain": B64.encode(route.dest_chain.as_bytes()),
+                }
+            }))
+            .await?;
+        let mapping = mapping.ok_or_else(|| {
+            eyre!(
+                "Token {} has no mapping to chain {}",
+                token,
+                route.dest_chain.to_hex()
+            )
+        })?;
+        let dest_token: [u8; 32] = B64
+            .decode(&mapping.dest_token)?
+            .try_into()
+            .map_err(|_| eyre!("dest_token of {} is not 32 bytes", token))?;
+
+        let info: TokenInfo = self.query(json!({ "token": { "token": token } })).await?;
+
+        let fee = match depositor {
+            Some(depositor) => {
+                let response: CalculateFee = self
+                    .query(json!({
+                        "calculate_fee": { "depositor": depositor, "amount": amount.to_string() }
+                    }))
+                    .await?;
+                response
+                    .fee_amount
+                    .parse()
+                    .map_err(|e| eyre!("Invalid fee amount {}: {}", response.fee_amount, e))?
+            }
+            None => {
+                let config: FeeConfig = self.query(json!({ "fee_config": {} })).await?;
+                fee_from_bps(amount, config.standard_fee_bps)
+            }
+        };
+
+        Quote::new(
+            route,
+            amount,
+            fee,
+            dest_token,
+            info.terra_decimals,
+            mapping.dest_decimals,
+        )
+    }
+
+    /// `deposit_native` with the funds attached, or the CW20's `send` to the bridge
+    pub(crate) async fn deposit(
+        &self,
+        dest_chain: ChainId,
+        asset: &Asset,
+        amount: u128,
+        dest_account: [u8; 32],
+    ) -> Result<Vec<UnsignedTx>> {
+        let token = asset.terra_token();
+        let target = json!({
+            "dest_chain": B64.encode(dest_chain.as_bytes()),
+            "dest_account": B64.encode(dest_account),
+        });
+        if !is_cw20(token) {
+            return Ok(vec![UnsignedTx::Terra {
+                contract: self.bridge().to_string(),
+                msg: json!({ "deposit_native": target }),
+                funds: vec![(token.to_string(), amount)],
+            }]);
+        }
+
+        let hook = if self.client.get_token_type(token).await? == "mint_burn" {
+            "deposit_cw20_mintable_burn"
+        } else {
+            "deposit_cw20_lock"
+        };
+        Ok(vec![cw20_deposit_tx(
+            self.bridge(),
+            token,
+            amount,
+            hook,
+            target,
+        )?])
+    }
+
+    /// `withdraw_submit` for the local token registered for the transfer, with `operator_tip`
+    /// uluna attached
+    pub(crate) async fn withdraw_submit(
+        &self,
+        transfer: &Transfer,
+        operator_tip: u128,
+    ) -> Result<UnsignedTx> {
+        let token = self.incoming_token(transfer).await?;
+        withdraw_submit_tx(self.bridge(), transfer, &token, operator_tip)
+    }
+
+    /// Local token whose hashed form is the transfer's `dest_token`, among the mappings enabled
+    /// for its source chain
+    async fn incoming_token(&self, transfer: &Transfer) -> Result<String> {
+        let mut start_after: Option<String> = None;
+        loop {
+            let page: IncomingTokenMappings = self
+                .query(json!({
+                    "incoming_token_mappings": { "start_after": start_after, "limit": MAPPINGS_PAGE }
+                }))
+                .await?;
+            for mapping in &page.mappings {
+                if !mapping.enabled
+                    || B64.decode(&mapping.src_chain)? != transfer.src_chain.as_bytes()
+                {
+                    continue;
+                }
+                if encode_terra_token_address(&mapping.local_token) == transfer.dest_token {
+                    return Ok(mapping.local_token.clone());
+                }
+            }
+            match page.mappings.last() {
+                Some(last) if page.mappings.len() as u32 == MAPPINGS_PAGE => {
+                    start_after = Some(format!(
+                        "{}:{}",
+                        hex::encode(B64.decode(&last.src_chain)?),
+                        hex::encode(B64.decode(&last.src_token)?)
+                    ));
+                }
+                _ => break,
+            }
+        }
+        Err(eyre!(
+            "No enabled Terra token from chain {} hashes to 0x{}",
+            transfer.src_chain.to_hex(),
+            hex::encode(transfer.dest_token)
+        ))
+    }
+
+    /// `withdraw_execute_unlock` or `withdraw_execute_mint`, by the pending withdrawal's token
+    pub(crate) async fn withdraw_execute(&self, xchain_hash_id: [u8; 32]) -> Result<UnsignedTx> {
+        let pending = self.client.get_pending_withdraw(xchain_hash_id).await?;
+        let token = match (pending.exists, pending.token) {
+            (true, Some(token)) => token,
+            _ => {
+                return Err(eyre!(
+                    "No pending withdrawal 0x{} on this bridge",
+                    hex::encode(xchain_hash_id)
+                ))
+            }
+        };
+        let action = if self.client.get_token_type(&token).await? == "mint_burn" {
+            "withdraw_execute_mint"
+        } else {
+            "withdraw_execute_unlock"
+        };
+        Ok(UnsignedTx::Terra {
+            contract: self.bridge().to_string(),
+            msg: json!({ action: { "xchain_hash_id": B64.encode(xchain_hash_id) } }),
+            funds: vec![],
+        })
+    }
+}
+
+fn cw20_deposit_tx(
+    bridge: &str,
+    token: &str,
+    amount: u128,
+    hook: &str,
+    target: Value,
+) -> Result<UnsignedTx> {
+    let inner = json!({ hook: target }).to_string();
+    Ok(UnsignedTx::Terra {
+        contract: token.to_string(),
+        msg: serde_json::to_value(build_cw20_send_msg(bridge, amount, &inner))?,
+        funds: vec![],
+    })
+}
+
+fn withdraw_submit_tx(
+    bridge: &str,
+    transfer: &Transfer,
+    token: &str,
+    operator_tip: u128,
+) -> Result<UnsignedTx> {
+    let recipient = decode_bytes32_to_terra_address(&transfer.dest_account)
+        .map_err(|e| eyre!("Recipient is not a Terra account: {}", e))?;
+    let funds = if operator_tip > 0 {
+        vec![(TERRA_NATIVE_DENOM.to_string(), operator_tip)]
+    } else {
+        vec![]
+    };
+    Ok(UnsignedTx::Terra {
+        contract: bridge.to_string(),
+        msg: json!({
+            "withdraw_submit": {
+                "src_chain": B64.encode(transfer.src_chain.as_bytes()),
+                "src_account": B64.encode(transfer.src_account),
+                "token": token,
+                "recipient": recipient,
+                "amount": transfer.amount.to_string(),
+                "nonce": transfer.nonce,
+            }
+        }),
+        funds,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asset::encode_account;
+    use multichain_rs::trace::ChainKind;
+
+    const BRIDGE: &str = "terra14hj2tavq8fpesdwxxcu44rty3hh90vhujrvcmstl4zr3txmfvw9ssrc8au";
+    const USER: &str = "terra1x46rqay4d3cssq8gxxvqz8xt6nwlz4td20k38v";
+
+    #[test]
+    fn test_cw20_deposit_wraps_hook_in_send() {
+        let tx =
+            cw20_deposit_tx(BRIDGE, "terra1token", 500, "deposit_cw20_lock", json!({})).unwrap();
+        let UnsignedTx::Terra {
+            contract,
+            msg,
+            funds,
+        } = tx
+        else {
+            panic!("expected a Terra transaction");
+        };
+        assert_eq!(contract, "terra1token");
+        assert!(funds.is_empty());
+        assert_eq!(msg["send"]["contract"], BRIDGE);
+        assert_eq!(msg["send"]["amount"], "500");
+        let hook: Value =
+            serde_json::from_slice(&B64.decode(msg["send"]["msg"].as_str().unwrap()).unwrap())
+                .unwrap();
+        assert!(hook.get("deposit_cw20_lock").is_some());
+    }
+
+    #[test]
+    fn test_withdraw_submit_msg() {
+        let transfer = Transfer {
+            src_chain: ChainId::from_u32(1),
+            dest_chain: ChainId::from_u32(2),
+            src_account: [1u8; 32],
+            dest_account: encode_account(ChainKind::Terra, USER).unwrap(),
+            dest_token: encode_terra_token_address("uluna"),
+            amount: 1_234,
+            nonce: 5,
+        };
+        let UnsignedTx::Terra { msg, funds, .. } =
+            withdraw_submit_tx(BRIDGE, &transfer, "uluna", 10).unwrap()
+        else {
+            panic!("expected a Terra transaction");
+        };
+        let submit = &msg["withdraw_submit"];
+        assert_eq!(submit["recipient"], USER);
+        assert_eq!(submit["token"], "uluna");
+        assert_eq!(submit["amount"], "1234");
+        assert_eq!(submit["nonce"], 5);
+        assert_eq!(funds, vec![("uluna".to_string(), 10)]);
+
+        let UnsignedTx::Terra { funds, .. } =
+            withdraw_submit_tx(BRIDGE, &transfer, "uluna", 0).unwrap()
+        else {
+            panic!("expected a Terra transaction");
+        };
+        assert!(funds.is_empty());
+    }
+}
@@ -0,0 +1,102 @@
+//! Transfer identity
+//!
+//! The fields hashed into the cross-chain hash ID, which is all the destination needs to submit
+//! and execute the withdrawal.
+
+use eyre::{eyre, Result};
+use multichain_rs::hash::compute_xchain_hash_id;
+use multichain_rs::trace::TracedDeposit;
+use multichain_rs::types::ChainId;
+use serde::{Deserialize, Serialize};
+
+/// One deposit as the destination sees it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Transfer {
+    pub src_chain: ChainId,
+    pub dest_chain: ChainId,
+    /// Depositor (universal address)
+    pub src_account: [u8; 32],
+    /// Recipient (universal address)
+    pub dest_account: [u8; 32],
+    /// Destination token as hashed by the source chain
+    pub dest_token: [u8; 32],
+    /// Net amount in source decimals
+    pub amount: u128,
+    /// Source-chain deposit nonce
+    pub nonce: u64,
+}
+
+impl Transfer {
+    /// Cross-chain hash ID keying the deposit and the destination `PendingWithdraw`
+    pub fn xchain_hash_id(&self) -> [u8; 32] {
+        compute_xchain_hash_id(
+            self.src_chain.as_bytes(),
+            self.dest_chain.as_bytes(),
+            &self.src_account,
+            &self.dest_account,
+            &self.dest_token,
+            self.amount,
+            self.nonce,
+        )
+    }
+}
+
+impl TryFrom<&TracedDeposit> for Transfer {
+    type Error = eyre::Report;
+
+    fn try_from(deposit: &TracedDeposit) -> Result<Self> {
+        let dest_token = deposit.dest_token.ok_or_else(|| {
+            eyre!(
+                "Deposit nonce {} on {} did not record its destination token",
+                deposit.nonce,
+                deposit.src_chain.to_hex()
+            )
+        })?;
+        Ok(Self {
+            src_chain: deposit.src_chain,
+            dest_chain: deposit.dest_chain,
+            src_account: deposit.src_account,
+            dest_account: deposit.dest_account,
+            dest_token,
+            amount: deposit.amount,
+            nonce: deposit.nonce,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use multichain_rs::trace::ChainKind;
+
+    fn deposit() -> TracedDeposit {
+        TracedDeposit {
+            src_chain: ChainId::from_u32(1),
+            src_kind: ChainKind::Evm,
+            dest_chain: ChainId::from_u32(2),
+            src_account: [1u8; 32],
+            dest_account: [2u8; 32],
+            dest_token: Some([3u8; 32]),
+            src_token: None,
+            amount: 997_000,
+            fee: Some(3_000),
+            nonce: 42,
+            tx: None,
+            timestamp: None,
+            reported_hash: None,
+        }
+    }
+
+    #[test]
+    fn test_transfer_from_traced_deposit() {
+        let d = deposit();
+        let transfer = Transfer::try_from(&d).unwrap();
+        assert_eq!(Some(transfer.xchain_hash_id()), d.computed_hash());
+
+        let d = TracedDeposit {
+            dest_token: None,
+            ..deposit()
+        };
+        assert!(Transfer::try_from(&d).is_err());
+    }
+}